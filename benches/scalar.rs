@@ -0,0 +1,56 @@
+//! Times rounding single numbers to a spread of precisions, which looks up a different power of
+//! ten for each call
+//!
+//! Run with `cargo bench --bench scalar`
+
+use std::hint::black_box;
+
+use common_math::rounding::*;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+const LENGTH: usize = 1 << 18;
+
+fn values() -> Vec<(f64, u32)> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    (0..LENGTH)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let number = ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 2000.0;
+            (number, (state % 12) as u32)
+        })
+        .collect()
+}
+
+fn time(c: &mut Criterion, name: &str, values: &[(f64, u32)], round: impl Fn(f64, u32) -> f64) {
+    let mut group = c.benchmark_group("scalar");
+    group.throughput(Throughput::Elements(values.len() as u64));
+    group.bench_function(name, |b| {
+        b.iter(|| {
+            for &(number, precision) in values {
+                black_box(round(black_box(number), black_box(precision)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn scalar(c: &mut Criterion) {
+    let values = values();
+    time(c, "round", &values, round);
+    time(c, "round_zeros", &values, round_zeros);
+    time(c, "round_sf", &values, |number, precision| {
+        round_sf(number, precision + 1)
+    });
+    let values: Vec<(f64, u32)> = values
+        .into_iter()
+        .map(|(number, precision)| (number as f32 as f64, precision.min(8)))
+        .collect();
+    time(c, "round f32", &values, |number, precision| {
+        round(number as f32, precision) as f64
+    });
+}
+
+criterion_group!(benches, scalar);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+//! Compares rounding a slice in place against rounding each number in a loop
+//!
+//! Run with `cargo bench --bench slice`, adding `--features simd` for the vectorized rounding
+
+use std::hint::black_box;
+
+use common_math::rounding::*;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+
+const LENGTH: usize = 1 << 20;
+
+fn values() -> Vec<f64> {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    (0..LENGTH)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 2000.0
+        })
+        .collect()
+}
+
+fn compare(
+    c: &mut Criterion,
+    name: &str,
+    values: &[f64],
+    naive: impl Fn(&mut [f64]),
+    slice: impl Fn(&mut [f64]),
+) {
+    let mut group = c.benchmark_group(name);
+    group.throughput(Throughput::Elements(values.len() as u64));
+    group.bench_function("loop", |b| {
+        b.iter_batched_ref(
+            || values.to_vec(),
+            |values| naive(black_box(values)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("slice", |b| {
+        b.iter_batched_ref(
+            || values.to_vec(),
+            |values| slice(black_box(values)),
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+fn slice(c: &mut Criterion) {
+    let values = values();
+    compare(
+        c,
+        "round 2 dp",
+        &values,
+        |values| {
+            values
+                .iter_mut()
+                .for_each(|value| *value = round(*value, 2))
+        },
+        |values| round_slice_dp(values, 2),
+    );
+    compare(
+        c,
+        "round 2 dp simd",
+        &values,
+        |values| {
+            values
+                .iter_mut()
+                .for_each(|value| *value = round(*value, 2))
+        },
+        |values| round_slice_dp_simd(values, 2),
+    );
+    compare(
+        c,
+        "ceil 2 dp",
+        &values,
+        |values| values.iter_mut().for_each(|value| *value = ceil(*value, 2)),
+        |values| ceil_slice_dp(values, 2),
+    );
+    compare(
+        c,
+        "round 30 dp",
+        &values,
+        |values| {
+            values
+                .iter_mut()
+                .for_each(|value| *value = round(*value, 30))
+        },
+        |values| round_slice_dp(values, 30),
+    );
+    compare(
+        c,
+        "round 2 zeros",
+        &values,
+        |values| {
+            values
+                .iter_mut()
+                .for_each(|value| *value = round_zeros(*value, 2))
+        },
+        |values| round_slice_zeros(values, 2),
+    );
+    compare(
+        c,
+        "round 3 sf",
+        &values,
+        |values| {
+            values
+                .iter_mut()
+                .for_each(|value| *value = round_sf(*value, 3))
+        },
+        |values| round_slice_sf(values, 3),
+    );
+}
+
+criterion_group!(benches, slice);
+criterion_main!(benches);
@@ -3,17 +3,42 @@
 //! Common Math is a library providing some common math functions 
 //! not provided by the standard library
 //! 
-//! Currently this library only provides functions for rounding 
+//! Currently this library provides functions for rounding and for descriptive statistics
 //! however more may be added in the future
 //! 
+//! Builds without the standard library when the default `std` feature is disabled, the float
+//! functions then come from `libm` and formatting to a `String` needs the `alloc` feature
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 /// Functions for rounding
 /// 
 /// Provides functions for rounding to a number of decimal places, zeros or significant figures
 /// 
 /// All functions will try to adhere to the same rounding rules as the standard library
+/// and the `*_half_away` functions guarantee that ties move away from zero for negative numbers as well
 /// 
 /// ```rust
 /// use common_math::rounding::*;
 /// ```
 pub mod rounding;
+
+/// Descriptive statistics of slices of numbers
+/// 
+/// Provides the mean, median, mode, variance and standard deviation of slices of integers or floats,
+/// along with weighted means and variances, percentiles with a choice of interpolation,
+/// simple and exponential moving averages, z-scores, five-number summaries, outlier detection, linear regression and histograms with automatic bin widths
+/// 
+/// ```rust
+/// use common_math::statistics::{mean, median, mode, variance_population};
+/// 
+/// let mut scores = [7, 3, 9, 3, 8];
+/// assert_eq!(mean(&scores), Some(6.0));
+/// assert_eq!(median(&mut scores), Some(7.0));
+/// assert_eq!(mode(&scores), Some(3));
+/// assert_eq!(variance_population(&scores), Some(6.4));
+/// ```
+pub mod statistics;
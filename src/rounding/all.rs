@@ -0,0 +1,160 @@
+use super::{Float, Roundable, RoundingMode};
+
+/// Rounds every number in a fixed size array or a tuple of two or three numbers in one call
+///
+/// Implemented for `[T; N]` of any length, `(T, T)` and `(T, T, T)`, with the decimal place
+/// methods available when the numbers are a [`Float`] and the significant figure methods for any
+/// [`Roundable`] number. Each number gives the same result as the matching scalar function, and
+/// a new array or tuple is returned without allocating
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::RoundAll;
+///
+/// assert_eq!([1.234, 5.678].round_dp_all(1), [1.2, 5.7]);
+/// assert_eq!((0.125_f32, -2.5_f32).floor_dp_all(1), (0.1, -2.5));
+/// assert_eq!((1234_u32, 5678, 91).round_sf_all(2), (1200, 5700, 91));
+/// ```
+pub trait RoundAll: Sized {
+    /// The type of the numbers
+    type Elem;
+
+    /// Rounds each number to the given number of decimal places like [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundAll;
+    ///
+    /// assert_eq!([1.005, 2.675, -0.125].round_dp_all(2), [1.0, 2.68, -0.13]);
+    /// ```
+    #[inline]
+    fn round_dp_all(self, decimal_places: u32) -> Self
+    where
+        Self::Elem: Float,
+    {
+        self.map_elements(|number| number.round_dp(decimal_places))
+    }
+
+    /// Rounds each number up to the given number of decimal places like [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundAll;
+    ///
+    /// assert_eq!([1.201_f32, -1.209].ceil_dp_all(2), [1.21, -1.2]);
+    /// ```
+    #[inline]
+    fn ceil_dp_all(self, decimal_places: u32) -> Self
+    where
+        Self::Elem: Float,
+    {
+        self.map_elements(|number| number.ceil_dp(decimal_places))
+    }
+
+    /// Rounds each number down to the given number of decimal places like [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundAll;
+    ///
+    /// assert_eq!((1.209, -1.201).floor_dp_all(2), (1.2, -1.21));
+    /// ```
+    #[inline]
+    fn floor_dp_all(self, decimal_places: u32) -> Self
+    where
+        Self::Elem: Float,
+    {
+        self.map_elements(|number| number.floor_dp(decimal_places))
+    }
+
+    /// Rounds each number to the given number of decimal places using the given rounding mode
+    /// like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundAll, RoundingMode};
+    ///
+    /// assert_eq!([0.125, 0.135].round_dp_mode_all(2, RoundingMode::HalfEven), [0.12, 0.14]);
+    /// ```
+    #[inline]
+    fn round_dp_mode_all(self, decimal_places: u32, mode: RoundingMode) -> Self
+    where
+        Self::Elem: Float,
+    {
+        self.map_elements(|number| number.round_dp_mode(decimal_places, mode))
+    }
+
+    /// Rounds each number to the given number of significant figures like
+    /// [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundAll;
+    ///
+    /// assert_eq!([0.004567, 1234.5].round_sf_all(2), [0.0046, 1200.0]);
+    /// assert_eq!((1250_i32, -1250).round_sf_all(2), (1300, -1300));
+    /// ```
+    #[inline]
+    fn round_sf_all(self, sig_figs: u32) -> Self
+    where
+        Self::Elem: Roundable,
+    {
+        self.map_elements(|number| number.round_sf(sig_figs))
+    }
+
+    /// Rounds each number to the given number of significant figures using the given rounding
+    /// mode like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundAll, RoundingMode};
+    ///
+    /// assert_eq!([1250_u32, 1350].round_sf_mode_all(2, RoundingMode::HalfEven), [1200, 1400]);
+    /// ```
+    #[inline]
+    fn round_sf_mode_all(self, sig_figs: u32, mode: RoundingMode) -> Self
+    where
+        Self::Elem: Roundable,
+    {
+        self.map_elements(|number| number.round_sf_mode(sig_figs, mode))
+    }
+
+    #[doc(hidden)]
+    fn map_elements(self, round: impl FnMut(Self::Elem) -> Self::Elem) -> Self;
+}
+
+impl<T, const N: usize> RoundAll for [T; N] {
+    type Elem = T;
+
+    #[inline]
+    fn map_elements(self, round: impl FnMut(T) -> T) -> [T; N] {
+        self.map(round)
+    }
+}
+
+impl<T> RoundAll for (T, T) {
+    type Elem = T;
+
+    #[inline]
+    fn map_elements(self, mut round: impl FnMut(T) -> T) -> (T, T) {
+        (round(self.0), round(self.1))
+    }
+}
+
+impl<T> RoundAll for (T, T, T) {
+    type Elem = T;
+
+    #[inline]
+    fn map_elements(self, mut round: impl FnMut(T) -> T) -> (T, T, T) {
+        (round(self.0), round(self.1), round(self.2))
+    }
+}
+
+mod tests;
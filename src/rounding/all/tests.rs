@@ -0,0 +1,87 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{ceil, floor, round, round_sf};
+
+#[cfg(test)]
+fn random_floats<const N: usize>(seed: u64) -> [f64; N] {
+    let mut state = seed;
+    core::array::from_fn(|_| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let exponent = (state % 12) as i32 - 6;
+        ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+    })
+}
+
+#[cfg(test)]
+fn assert_all<const N: usize>(values: [f64; N]) {
+    for decimal_places in 0..6 {
+        let rounded = values.round_dp_all(decimal_places);
+        let ceiled = values.ceil_dp_all(decimal_places);
+        let floored = values.floor_dp_all(decimal_places);
+        let even = values.round_dp_mode_all(decimal_places, RoundingMode::HalfEven);
+        let sig_figs = values.round_sf_all(decimal_places + 1);
+        for (index, &number) in values.iter().enumerate() {
+            assert_eq!(
+                rounded[index].to_bits(),
+                round(number, decimal_places).to_bits()
+            );
+            assert_eq!(
+                ceiled[index].to_bits(),
+                ceil(number, decimal_places).to_bits()
+            );
+            assert_eq!(
+                floored[index].to_bits(),
+                floor(number, decimal_places).to_bits()
+            );
+            let expected = number.round_dp_mode(decimal_places, RoundingMode::HalfEven);
+            assert_eq!(even[index].to_bits(), expected.to_bits());
+            let expected = round_sf(number, decimal_places + 1);
+            assert_eq!(sig_figs[index].to_bits(), expected.to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_arrays() {
+    assert_all::<0>([]);
+    assert_all([2.5]);
+    assert_all([-2.675, f64::NAN, f64::INFINITY]);
+    assert_all(random_floats::<3>(0x9e37_79b9_7f4a_7c15));
+    assert_all(random_floats::<32>(0x2545_f491_4f6c_dd1d));
+
+    let empty: [u8; 0] = [];
+    assert_eq!(empty.round_sf_all(1), empty);
+    assert_eq!([1.25_f32].round_dp_all(1), [1.3]);
+    assert_eq!([249_u8, 250, 251].round_sf_all(1), [200, u8::MAX, u8::MAX]);
+    assert_eq!(
+        [1250_i64, -1350, 1450].round_sf_mode_all(2, RoundingMode::HalfEven),
+        [1200, -1400, 1400]
+    );
+}
+
+#[test]
+fn test_tuples() {
+    let [x, y, z] = random_floats::<3>(0xdead_beef);
+    let (rounded_x, rounded_y) = (x, y).round_dp_all(2);
+    assert_eq!(rounded_x.to_bits(), round(x, 2).to_bits());
+    assert_eq!(rounded_y.to_bits(), round(y, 2).to_bits());
+
+    let (ceiled_x, ceiled_y, ceiled_z) = (x, y, z).ceil_dp_all(3);
+    assert_eq!(ceiled_x.to_bits(), ceil(x, 3).to_bits());
+    assert_eq!(ceiled_y.to_bits(), ceil(y, 3).to_bits());
+    assert_eq!(ceiled_z.to_bits(), ceil(z, 3).to_bits());
+
+    assert_eq!(
+        (1.5_f32, -1.5_f32, 0.25_f32).floor_dp_all(0),
+        (1.0, -2.0, 0.0)
+    );
+    assert_eq!((12_345_u32, 678).round_sf_all(2), (12_000, 680));
+    assert_eq!(
+        (125_i16, -135, 145).round_sf_mode_all(2, RoundingMode::HalfOdd),
+        (130, -130, 150)
+    );
+}
@@ -0,0 +1,151 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::RoundingError;
+
+/// Splits an amount of minor currency units, such as pennies, into equal parts that add up to it
+/// exactly
+///
+/// The parts differ by at most one unit, and the first ones get the units left over.
+/// A negative amount such as a refund is split the same way as the positive amount, with every
+/// part negated
+///
+/// # Panics
+///
+/// Panics if there are zero parts, see [`try_allocate`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::allocate;
+///
+/// assert_eq!(allocate(10_000, 3), [3_334, 3_333, 3_333]);
+/// assert_eq!(allocate(-500, 3), [-167, -167, -166]);
+/// assert_eq!(allocate(2, 4), [1, 1, 0, 0]);
+/// ```
+#[inline]
+pub fn allocate(amount: i64, parts: usize) -> Vec<i64> {
+    match try_allocate(amount, parts) {
+        Ok(allocated) => allocated,
+        Err(_) => panic!("an amount can't be split into zero parts"),
+    }
+}
+
+/// Splits an amount of minor currency units in proportion to the weights, so the parts add up to
+/// it exactly
+///
+/// Every part gets the whole units of its exact share and the units left over go to the parts
+/// with the largest fractions of a unit left, the first of them when they are equal. A part with
+/// a weight of zero always gets nothing. A negative amount is split the same way as the positive
+/// amount, with every part negated
+///
+/// # Panics
+///
+/// Panics if there are no weights above zero, see [`try_allocate_weighted`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::allocate_weighted;
+///
+/// assert_eq!(allocate_weighted(10_000, &[1, 1, 1]), [3_334, 3_333, 3_333]);
+/// assert_eq!(allocate_weighted(1_000, &[50, 30, 0, 20]), [500, 300, 0, 200]);
+/// assert_eq!(allocate_weighted(-7, &[2, 1]), [-5, -2]);
+/// ```
+#[inline]
+pub fn allocate_weighted(amount: i64, weights: &[u64]) -> Vec<i64> {
+    match try_allocate_weighted(amount, weights) {
+        Ok(allocated) => allocated,
+        Err(_) => panic!("an amount can't be split by weights that are all zero"),
+    }
+}
+
+/// Splits an amount of minor currency units into equal parts that add up to it exactly,
+/// like [`allocate`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidWeights`] if there are zero parts
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_allocate, RoundingError};
+///
+/// assert_eq!(try_allocate(100, 3), Ok(vec![34, 33, 33]));
+/// assert_eq!(try_allocate(100, 0), Err(RoundingError::InvalidWeights));
+/// ```
+pub fn try_allocate(amount: i64, parts: usize) -> Result<Vec<i64>, RoundingError> {
+    if parts == 0 {
+        return Err(RoundingError::InvalidWeights);
+    }
+
+    let magnitude = amount.unsigned_abs();
+    let (share, left) = (magnitude / parts as u64, magnitude % parts as u64);
+    let mut allocated = vec![signed(amount, share); parts];
+    for part in &mut allocated[..left as usize] {
+        *part = signed(amount, share + 1);
+    }
+    Ok(allocated)
+}
+
+/// Splits an amount of minor currency units in proportion to the weights, so the parts add up to
+/// it exactly, like [`allocate_weighted`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidWeights`] if there are no weights above zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_allocate_weighted, RoundingError};
+///
+/// assert_eq!(try_allocate_weighted(5, &[1, 3]), Ok(vec![1, 4]));
+/// assert_eq!(try_allocate_weighted(5, &[0, 0]), Err(RoundingError::InvalidWeights));
+/// assert_eq!(try_allocate_weighted(5, &[]), Err(RoundingError::InvalidWeights));
+/// ```
+pub fn try_allocate_weighted(amount: i64, weights: &[u64]) -> Result<Vec<i64>, RoundingError> {
+    let total: u128 = weights.iter().map(|&weight| weight as u128).sum();
+    if total == 0 {
+        return Err(RoundingError::InvalidWeights);
+    }
+
+    // The amount and a weight both fit in 64 bits, so their product fits in a u128
+    let magnitude = amount.unsigned_abs() as u128;
+    let mut shares = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+    for &weight in weights {
+        let exact = magnitude * weight as u128;
+        shares.push((exact / total) as u64);
+        remainders.push(exact % total);
+    }
+
+    // The remainders add up to the units left over times the total weight, so fewer units are
+    // left than there are parts with a remainder and a zero weight never gets one
+    let left = magnitude as u64 - shares.iter().sum::<u64>();
+    let mut order: Vec<usize> = (0..weights.len()).collect();
+    // The sort is stable, so equal remainders keep the order of their parts
+    order.sort_by(|&a, &b| remainders[b].cmp(&remainders[a]));
+    for &index in &order[..left as usize] {
+        shares[index] += 1;
+    }
+
+    Ok(shares
+        .into_iter()
+        .map(|share| signed(amount, share))
+        .collect())
+}
+
+/// Gives a share of the magnitude of the amount the sign of the amount
+#[inline]
+fn signed(amount: i64, share: u64) -> i64 {
+    // A share is at most the magnitude of the amount, so a negative one always fits
+    if amount < 0 {
+        0_i64.wrapping_sub_unsigned(share)
+    } else {
+        share as i64
+    }
+}
+
+mod tests;
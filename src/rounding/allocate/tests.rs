@@ -0,0 +1,96 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_thirds() {
+    assert_eq!(allocate(10_000, 3), [3_334, 3_333, 3_333]);
+    assert_eq!(allocate(10_001, 3), [3_334, 3_334, 3_333]);
+    assert_eq!(allocate(9_999, 3), [3_333, 3_333, 3_333]);
+    assert_eq!(allocate_weighted(10_000, &[1, 1, 1]), allocate(10_000, 3));
+    assert_eq!(allocate(0, 3), [0, 0, 0]);
+    assert_eq!(allocate(5, 1), [5]);
+}
+
+#[test]
+fn test_refunds() {
+    for amount in [1, 7, 100, 10_000, 12_345, i64::MAX] {
+        for parts in 1..8 {
+            let negated: Vec<i64> = allocate(amount, parts).iter().map(|part| -part).collect();
+            assert_eq!(allocate(-amount, parts), negated);
+        }
+        let weights = [3, 0, 5, 1];
+        let negated: Vec<i64> = allocate_weighted(amount, &weights)
+            .iter()
+            .map(|part| -part)
+            .collect();
+        assert_eq!(allocate_weighted(-amount, &weights), negated);
+    }
+    assert_eq!(allocate(i64::MIN, 2), [i64::MIN / 2, i64::MIN / 2]);
+    assert_eq!(allocate_weighted(i64::MIN, &[1]), [i64::MIN]);
+}
+
+#[test]
+fn test_weighted_with_zero() {
+    assert_eq!(allocate_weighted(100, &[0, 1, 0, 2]), [0, 33, 0, 67]);
+    assert_eq!(allocate_weighted(1, &[0, 1, 1]), [0, 1, 0]);
+    assert_eq!(allocate_weighted(3, &[0, 0, 5]), [0, 0, 3]);
+    assert_eq!(
+        allocate_weighted(1_000, &[u64::MAX, u64::MAX, 0]),
+        [500, 500, 0]
+    );
+    assert_eq!(
+        try_allocate_weighted(10, &[0]),
+        Err(RoundingError::InvalidWeights)
+    );
+    assert_eq!(try_allocate(10, 0), Err(RoundingError::InvalidWeights));
+    assert!(std::panic::catch_unwind(|| allocate(10, 0)).is_err());
+}
+
+#[test]
+fn test_exact_sum() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let amount = state as i64 >> (state % 60);
+        let parts = (state >> 58) as usize + 1;
+        let allocated = allocate(amount, parts);
+        assert_eq!(
+            allocated.iter().map(|&part| part as i128).sum::<i128>(),
+            amount as i128
+        );
+        let (smallest, largest) = (allocated.iter().min(), allocated.iter().max());
+        assert!(largest.unwrap() - smallest.unwrap() <= 1);
+
+        let mut weights = Vec::with_capacity(parts);
+        for _ in 0..parts {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            weights.push(if state.is_multiple_of(3) {
+                0
+            } else {
+                state >> (state % 64)
+            });
+        }
+        if let Ok(allocated) = try_allocate_weighted(amount, &weights) {
+            assert_eq!(
+                allocated.iter().map(|&part| part as i128).sum::<i128>(),
+                amount as i128
+            );
+            let total: u128 = weights.iter().map(|&weight| weight as u128).sum();
+            for (&part, &weight) in allocated.iter().zip(&weights) {
+                let exact = amount.unsigned_abs() as u128 * weight as u128;
+                let share = part.unsigned_abs() as u128;
+                assert!(share * total <= exact + total && exact < (share + 1) * total);
+                if weight == 0 {
+                    assert_eq!(part, 0);
+                }
+            }
+        } else {
+            assert!(weights.iter().all(|&weight| weight == 0));
+        }
+    }
+}
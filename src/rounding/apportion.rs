@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::sum::by_remainder;
+use super::RoundingError;
+
+/// The rule used by [`apportion`] to share a whole number of items out by weight
+///
+/// Both methods give every weight at least the whole part of its exact share, its quota, and only
+/// differ in who gets the items left over. Ties always go to the weight that comes first
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{apportion, ApportionMethod};
+///
+/// let votes = [5_000.0, 3_000.0, 1_200.0, 800.0];
+/// assert_eq!(apportion(10, &votes, ApportionMethod::Hamilton), [5, 3, 1, 1]);
+/// assert_eq!(apportion(10, &votes, ApportionMethod::DHondt), [6, 3, 1, 0]);
+/// assert_eq!(apportion(7, &votes, ApportionMethod::Hamilton), [3, 2, 1, 1]);
+/// assert_eq!(apportion(7, &votes, ApportionMethod::DHondt), [4, 2, 1, 0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ApportionMethod {
+    /// The largest remainder method, which gives the items left over after every weight has its
+    /// quota to the weights with the largest fractional parts of it
+    #[default]
+    Hamilton,
+    /// The highest averages method, also known as Jefferson's method, which gives out items one at
+    /// a time to the weight with the largest weight per item it would end up with.
+    /// It favours larger weights
+    DHondt,
+}
+
+/// Shares a whole number of items out in proportion to the weights, so the counts always add up
+/// to the total
+///
+/// A weight of zero always gets nothing. Equal claims to an item are settled in favour of the
+/// weight that comes first, so the result is always the same for the same input
+///
+/// # Panics
+///
+/// Panics if a weight is negative or not finite, or if there are no weights above zero,
+/// see [`try_apportion`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{apportion, ApportionMethod};
+///
+/// assert_eq!(apportion(100, &[1.0, 1.0, 1.0], ApportionMethod::Hamilton), [34, 33, 33]);
+/// assert_eq!(apportion(2, &[1.0, 0.0, 1.0, 1.0], ApportionMethod::Hamilton), [1, 0, 1, 0]);
+/// assert_eq!(apportion(5, &[0.6, 0.25, 0.15], ApportionMethod::DHondt), [4, 1, 0]);
+/// ```
+#[inline]
+pub fn apportion(total: u64, weights: &[f64], method: ApportionMethod) -> Vec<u64> {
+    match try_apportion(total, weights, method) {
+        Ok(counts) => counts,
+        Err(_) => panic!("weights must be finite and not negative, with at least one above zero"),
+    }
+}
+
+/// Shares a whole number of items out in proportion to the weights, like [`apportion`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidWeights`] if a weight is negative or not finite, or if there are no
+/// weights above zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_apportion, ApportionMethod, RoundingError};
+///
+/// let method = ApportionMethod::Hamilton;
+/// assert_eq!(try_apportion(3, &[2.0, 1.0], method), Ok(vec![2, 1]));
+/// assert_eq!(try_apportion(3, &[0.0, 0.0], method), Err(RoundingError::InvalidWeights));
+/// assert_eq!(try_apportion(3, &[1.0, -1.0], method), Err(RoundingError::InvalidWeights));
+/// assert_eq!(try_apportion(3, &[], method), Err(RoundingError::InvalidWeights));
+/// ```
+pub fn try_apportion(
+    total: u64,
+    weights: &[f64],
+    method: ApportionMethod,
+) -> Result<Vec<u64>, RoundingError> {
+    let sum: f64 = weights.iter().sum();
+    let valid = weights
+        .iter()
+        .all(|weight| weight.is_finite() && *weight >= 0.0);
+    if !valid || !sum.is_finite() || sum <= 0.0 {
+        return Err(RoundingError::InvalidWeights);
+    }
+
+    // Both methods give every weight at least the whole part of its quota
+    let quotas: Vec<f64> = weights
+        .iter()
+        .map(|weight| weight / sum * total as f64)
+        .collect();
+    let mut counts: Vec<u64> = quotas.iter().map(|quota| quota.floor() as u64).collect();
+
+    // The quotas add up to the total apart from floating point error, which could push it over
+    let mut given: u64 = counts.iter().sum();
+    while given > total {
+        let index = (0..counts.len())
+            .filter(|&index| counts[index] > 0)
+            .min_by(|&a, &b| {
+                (quotas[a] - counts[a] as f64).total_cmp(&(quotas[b] - counts[b] as f64))
+            })
+            .expect("a count above zero to take from");
+        counts[index] -= 1;
+        given -= 1;
+    }
+
+    let left = total - given;
+    match method {
+        ApportionMethod::Hamilton => {
+            let remainders: Vec<f64> = quotas
+                .iter()
+                .zip(&counts)
+                .map(|(quota, &count)| quota - count as f64)
+                .collect();
+            // A zero weight is never given an item, even when every remainder is zero
+            let order = by_remainder(&remainders)
+                .into_iter()
+                .filter(|&index| weights[index] > 0.0);
+            for index in order.cycle().take(left as usize) {
+                counts[index] += 1;
+            }
+        }
+        ApportionMethod::DHondt => {
+            for _ in 0..left {
+                let mut best = None;
+                for (index, &weight) in weights.iter().enumerate() {
+                    if weight == 0.0 {
+                        continue;
+                    }
+                    let average = weight / (counts[index] + 1) as f64;
+                    // Strictly greater, so the first weight wins a tie
+                    if best.is_none_or(|(_, best)| average > best) {
+                        best = Some((index, average));
+                    }
+                }
+                let (index, _) = best.expect("a weight above zero");
+                counts[index] += 1;
+            }
+        }
+    }
+
+    Ok(counts)
+}
+
+mod tests;
@@ -0,0 +1,110 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_election() {
+    // The example from the D'Hondt method article on Wikipedia
+    let votes = [100_000.0, 80_000.0, 30_000.0, 20_000.0];
+    assert_eq!(apportion(8, &votes, ApportionMethod::DHondt), [4, 3, 1, 0]);
+    assert_eq!(
+        apportion(8, &votes, ApportionMethod::Hamilton),
+        [3, 3, 1, 1]
+    );
+
+    // The Alabama paradox, Hamilton gives the third state fewer seats from a larger house
+    let populations = [6.0, 6.0, 2.0];
+    assert_eq!(
+        apportion(10, &populations, ApportionMethod::Hamilton),
+        [4, 4, 2]
+    );
+    assert_eq!(
+        apportion(11, &populations, ApportionMethod::Hamilton),
+        [5, 5, 1]
+    );
+    assert_eq!(
+        apportion(11, &populations, ApportionMethod::DHondt),
+        [5, 5, 1]
+    );
+}
+
+#[test]
+fn test_tiny_totals() {
+    let weights = [3.0, 1.0, 0.0, 2.0, 2.0];
+    for method in [ApportionMethod::Hamilton, ApportionMethod::DHondt] {
+        assert_eq!(apportion(0, &weights, method), [0, 0, 0, 0, 0]);
+        assert_eq!(apportion(1, &weights, method), [1, 0, 0, 0, 0]);
+        assert_eq!(apportion(2, &[1.0, 1.0, 1.0], method), [1, 1, 0]);
+        assert_eq!(apportion(3, &[0.0, 5.0], method), [0, 3]);
+        assert_eq!(apportion(3, &weights, method), [1, 0, 0, 1, 1]);
+    }
+    assert_eq!(
+        apportion(5, &weights, ApportionMethod::Hamilton),
+        [2, 1, 0, 1, 1]
+    );
+    assert_eq!(
+        apportion(5, &weights, ApportionMethod::DHondt),
+        [3, 0, 0, 1, 1]
+    );
+}
+
+#[test]
+fn test_invalid_weights() {
+    for weights in [
+        &[][..],
+        &[0.0, 0.0],
+        &[1.0, f64::NAN],
+        &[f64::INFINITY],
+        &[2.0, -1.0],
+    ] {
+        assert_eq!(
+            try_apportion(10, weights, ApportionMethod::Hamilton),
+            Err(RoundingError::InvalidWeights)
+        );
+    }
+    assert!(std::panic::catch_unwind(|| apportion(1, &[0.0], ApportionMethod::DHondt)).is_err());
+}
+
+#[test]
+fn test_exact_sum() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let total = state % 200;
+        let len = (state >> 56) as usize % 12 + 1;
+        let mut weights = Vec::with_capacity(len);
+        for _ in 0..len {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            // A quarter of the weights are zero
+            weights.push(if state.is_multiple_of(4) {
+                0.0
+            } else {
+                (state >> 40) as f64
+            });
+        }
+        if weights.iter().all(|&weight| weight == 0.0) {
+            weights[0] = 1.0;
+        }
+
+        let sum: f64 = weights.iter().sum();
+        for method in [ApportionMethod::Hamilton, ApportionMethod::DHondt] {
+            let counts = apportion(total, &weights, method);
+            assert_eq!(counts.iter().sum::<u64>(), total);
+            assert_eq!(counts, apportion(total, &weights, method));
+            for (&count, &weight) in counts.iter().zip(&weights) {
+                let quota = weight / sum * total as f64;
+                assert!(count as f64 >= quota.floor());
+                if weight == 0.0 {
+                    assert_eq!(count, 0);
+                }
+                if method == ApportionMethod::Hamilton {
+                    assert!(count as f64 <= quota.ceil());
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+use ndarray::{Array, ArrayBase, Data, DataMut, Dimension, RawData};
+
+use super::{Float, Roundable, RoundingMode};
+
+/// Element-wise rounding for [`ndarray`] arrays and views
+///
+/// Implemented for every [`ArrayBase`], with the decimal place methods available when it holds a
+/// [`Float`] and the significant figure methods when it holds any [`Roundable`] number. Each
+/// element gives the same result as the matching scalar function
+///
+/// The methods that return a new array keep the shape of the input, and the memory order of
+/// contiguous inputs. The `_inplace` methods need mutable elements, such as an owned array or an
+/// [`ArrayViewMut`](ndarray::ArrayViewMut) of part of a larger array
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::RoundArrayExt;
+/// use ndarray::array;
+///
+/// let data = array![[1.234_f64, 5.678], [-9.876, 0.005]];
+/// assert_eq!(data.round_dp(2), array![[1.23, 5.68], [-9.88, 0.01]]);
+/// assert_eq!(data.t().floor_dp(1), array![[1.2, -9.9], [5.6, 0.0]]);
+///
+/// let mut data = array![1234_u32, 5678];
+/// data.round_sf_inplace(2);
+/// assert_eq!(data, array![1200, 5700]);
+/// ```
+pub trait RoundArrayExt {
+    /// The type of the elements
+    type Elem;
+    /// The dimension of the array
+    type Dim: Dimension;
+    /// The storage of the elements, owned or borrowed
+    type Storage: RawData<Elem = Self::Elem>;
+
+    /// Rounds each element to the given number of decimal places like [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1.005, 2.675].round_dp(2), array![1.0, 2.68]);
+    /// ```
+    fn round_dp(&self, decimal_places: u32) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element up to the given number of decimal places like [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1.201_f32, -1.209].ceil_dp(2), array![1.21, -1.2]);
+    /// ```
+    fn ceil_dp(&self, decimal_places: u32) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element down to the given number of decimal places like [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![1.209, -1.201].floor_dp(2), array![1.2, -1.21]);
+    /// ```
+    fn floor_dp(&self, decimal_places: u32) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element to the given number of decimal places using the given rounding mode
+    /// like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundArrayExt, RoundingMode};
+    /// use ndarray::array;
+    ///
+    /// let rounded = array![0.125, 0.135].round_dp_mode(2, RoundingMode::HalfEven);
+    /// assert_eq!(rounded, array![0.12, 0.14]);
+    /// ```
+    fn round_dp_mode(
+        &self,
+        decimal_places: u32,
+        mode: RoundingMode,
+    ) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element to the given number of significant figures like
+    /// [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// assert_eq!(array![0.004567, 1234.5].round_sf(2), array![0.0046, 1200.0]);
+    /// assert_eq!(array![1250_i32, -1250].round_sf(2), array![1300, -1300]);
+    /// ```
+    fn round_sf(&self, sig_figs: u32) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element to the given number of significant figures using the given rounding
+    /// mode like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundArrayExt, RoundingMode};
+    /// use ndarray::array;
+    ///
+    /// let rounded = array![1250_u32, 1350].round_sf_mode(2, RoundingMode::HalfEven);
+    /// assert_eq!(rounded, array![1200, 1400]);
+    /// ```
+    fn round_sf_mode(&self, sig_figs: u32, mode: RoundingMode) -> Array<Self::Elem, Self::Dim>
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: Data;
+
+    /// Rounds each element to the given number of decimal places in place like
+    /// [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::{array, s};
+    ///
+    /// let mut data = array![[1.234, 5.678], [-9.876, 0.005]];
+    /// data.slice_mut(s![.., 1]).round_dp_inplace(1);
+    /// assert_eq!(data, array![[1.234, 5.7], [-9.876, 0.0]]);
+    /// ```
+    fn round_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: DataMut;
+
+    /// Rounds each element up to the given number of decimal places in place like
+    /// [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// let mut data = array![1.201_f32, -1.209];
+    /// data.ceil_dp_inplace(2);
+    /// assert_eq!(data, array![1.21, -1.2]);
+    /// ```
+    fn ceil_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: DataMut;
+
+    /// Rounds each element down to the given number of decimal places in place like
+    /// [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// let mut data = array![1.209, -1.201];
+    /// data.floor_dp_inplace(2);
+    /// assert_eq!(data, array![1.2, -1.21]);
+    /// ```
+    fn floor_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: DataMut;
+
+    /// Rounds each element to the given number of decimal places using the given rounding mode in
+    /// place like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// Contiguous elements are rounded like [`round_slice_dp_mode`](super::round_slice_dp_mode),
+    /// finding the power of ten once for the whole array
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundArrayExt, RoundingMode};
+    /// use ndarray::array;
+    ///
+    /// let mut data = array![0.125, 0.135];
+    /// data.round_dp_mode_inplace(2, RoundingMode::HalfEven);
+    /// assert_eq!(data, array![0.12, 0.14]);
+    /// ```
+    fn round_dp_mode_inplace(&mut self, decimal_places: u32, mode: RoundingMode)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: DataMut;
+
+    /// Rounds each element to the given number of significant figures in place like
+    /// [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundArrayExt;
+    /// use ndarray::array;
+    ///
+    /// let mut data = array![0.004567, 1234.5];
+    /// data.round_sf_inplace(2);
+    /// assert_eq!(data, array![0.0046, 1200.0]);
+    /// ```
+    fn round_sf_inplace(&mut self, sig_figs: u32)
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: DataMut;
+
+    /// Rounds each element to the given number of significant figures using the given rounding
+    /// mode in place like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundArrayExt, RoundingMode};
+    /// use ndarray::array;
+    ///
+    /// let mut data = array![1250_u32, 1350];
+    /// data.round_sf_mode_inplace(2, RoundingMode::HalfEven);
+    /// assert_eq!(data, array![1200, 1400]);
+    /// ```
+    fn round_sf_mode_inplace(&mut self, sig_figs: u32, mode: RoundingMode)
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: DataMut;
+}
+
+impl<S: RawData, D: Dimension> RoundArrayExt for ArrayBase<S, D> {
+    type Elem = S::Elem;
+    type Dim = D;
+    type Storage = S;
+
+    #[inline]
+    fn round_dp(&self, decimal_places: u32) -> Array<S::Elem, D>
+    where
+        S::Elem: Float + Copy,
+        S: Data,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_dp(&self, decimal_places: u32) -> Array<S::Elem, D>
+    where
+        S::Elem: Float + Copy,
+        S: Data,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_dp(&self, decimal_places: u32) -> Array<S::Elem, D>
+    where
+        S::Elem: Float + Copy,
+        S: Data,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_dp_mode(&self, decimal_places: u32, mode: RoundingMode) -> Array<S::Elem, D>
+    where
+        S::Elem: Float + Copy,
+        S: Data,
+    {
+        let mut rounded = self.to_owned();
+        rounded.round_dp_mode_inplace(decimal_places, mode);
+        rounded
+    }
+
+    #[inline]
+    fn round_sf(&self, sig_figs: u32) -> Array<S::Elem, D>
+    where
+        S::Elem: Roundable + Copy,
+        S: Data,
+    {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn round_sf_mode(&self, sig_figs: u32, mode: RoundingMode) -> Array<S::Elem, D>
+    where
+        S::Elem: Roundable + Copy,
+        S: Data,
+    {
+        self.map(|&number| number.round_sf_mode(sig_figs, mode))
+    }
+
+    #[inline]
+    fn round_dp_inplace(&mut self, decimal_places: u32)
+    where
+        S::Elem: Float + Copy,
+        S: DataMut,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::HalfAwayFromZero);
+    }
+
+    #[inline]
+    fn ceil_dp_inplace(&mut self, decimal_places: u32)
+    where
+        S::Elem: Float + Copy,
+        S: DataMut,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::Up);
+    }
+
+    #[inline]
+    fn floor_dp_inplace(&mut self, decimal_places: u32)
+    where
+        S::Elem: Float + Copy,
+        S: DataMut,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::Down);
+    }
+
+    #[inline]
+    fn round_dp_mode_inplace(&mut self, decimal_places: u32, mode: RoundingMode)
+    where
+        S::Elem: Float + Copy,
+        S: DataMut,
+    {
+        match self.as_slice_memory_order_mut() {
+            Some(values) => S::Elem::round_slice_dp_mode(values, decimal_places, mode),
+            None => self.map_inplace(|number| *number = number.round_dp_mode(decimal_places, mode)),
+        }
+    }
+
+    #[inline]
+    fn round_sf_inplace(&mut self, sig_figs: u32)
+    where
+        S::Elem: Roundable + Copy,
+        S: DataMut,
+    {
+        self.round_sf_mode_inplace(sig_figs, RoundingMode::HalfAwayFromZero);
+    }
+
+    #[inline]
+    fn round_sf_mode_inplace(&mut self, sig_figs: u32, mode: RoundingMode)
+    where
+        S::Elem: Roundable + Copy,
+        S: DataMut,
+    {
+        self.map_inplace(|number| *number = number.round_sf_mode(sig_figs, mode));
+    }
+}
+
+mod tests;
@@ -0,0 +1,146 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{ceil, floor, round, round_sf};
+#[cfg(test)]
+use ndarray::{s, Array1, Array2, ShapeBuilder};
+
+#[cfg(test)]
+fn random_floats(seed: u64, count: usize) -> Vec<f64> {
+    let mut state = seed;
+    let mut values: Vec<f64> = (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let exponent = (state % 12) as i32 - 6;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+        })
+        .collect();
+    values.extend([0.0, -0.0, 2.5, -2.675, 1.005, f64::NAN, f64::INFINITY]);
+    values
+}
+
+#[cfg(test)]
+fn assert_matches<D: Dimension>(
+    rounded: &Array<f64, D>,
+    original: &ArrayBase<impl Data<Elem = f64>, D>,
+    scalar: impl Fn(f64) -> f64,
+) {
+    assert_eq!(rounded.shape(), original.shape());
+    for (&rounded, &number) in rounded.iter().zip(original.iter()) {
+        assert_eq!(rounded.to_bits(), scalar(number).to_bits(), "{number}");
+    }
+}
+
+#[test]
+fn test_one_dimension() {
+    let values = Array1::from(random_floats(0x9e37_79b9_7f4a_7c15, 200));
+    for decimal_places in 0..6 {
+        assert_matches(&values.round_dp(decimal_places), &values, |number| {
+            round(number, decimal_places)
+        });
+        assert_matches(&values.ceil_dp(decimal_places), &values, |number| {
+            ceil(number, decimal_places)
+        });
+        assert_matches(&values.floor_dp(decimal_places), &values, |number| {
+            floor(number, decimal_places)
+        });
+        assert_matches(&values.round_sf(decimal_places + 1), &values, |number| {
+            round_sf(number, decimal_places + 1)
+        });
+
+        let mut in_place = values.clone();
+        in_place.round_dp_inplace(decimal_places);
+        assert_matches(&in_place, &values, |number| round(number, decimal_places));
+    }
+}
+
+#[test]
+fn test_two_dimensions() {
+    let values =
+        Array2::from_shape_vec((15, 14), random_floats(0x2545_f491_4f6c_dd1d, 203)).unwrap();
+    let rounded = values.round_dp(3);
+    assert!(rounded.is_standard_layout());
+    assert_matches(&rounded, &values, |number| round(number, 3));
+
+    let mut in_place = values.clone();
+    in_place.round_sf_inplace(2);
+    assert_matches(&in_place, &values, |number| round_sf(number, 2));
+
+    // Column major arrays keep their memory order
+    let mut column_major = Array2::zeros((15, 14).f());
+    column_major.assign(&values);
+    let rounded = column_major.floor_dp(2);
+    assert!(rounded.t().is_standard_layout());
+    assert_matches(&rounded, &values, |number| floor(number, 2));
+}
+
+#[test]
+fn test_transposed_view() {
+    let values = Array2::from_shape_vec((7, 30), random_floats(0xdead_beef, 203)).unwrap();
+    let transposed = values.t();
+    assert!(!transposed.is_standard_layout());
+    assert_matches(&transposed.round_dp(2), &transposed, |number| {
+        round(number, 2)
+    });
+    assert_matches(&transposed.ceil_dp(4), &transposed, |number| {
+        ceil(number, 4)
+    });
+
+    // Every other column is neither contiguous nor in memory order
+    let strided = values.slice(s![.., ..;2]);
+    assert!(strided.as_slice_memory_order().is_none());
+    assert_matches(&strided.round_sf(3), &strided, |number| round_sf(number, 3));
+}
+
+#[test]
+fn test_inplace_views() {
+    let values = Array2::from_shape_vec((10, 20), random_floats(0x1234_5678, 193)).unwrap();
+
+    let mut rounded = values.clone();
+    rounded.slice_mut(s![2..5, 3..9]).round_dp_inplace(1);
+    rounded
+        .slice_mut(s![6.., ..;3])
+        .reversed_axes()
+        .floor_dp_inplace(2);
+    for ((row, column), &number) in values.indexed_iter() {
+        let expected = if (2..5).contains(&row) && (3..9).contains(&column) {
+            round(number, 1)
+        } else if row >= 6 && column % 3 == 0 {
+            floor(number, 2)
+        } else {
+            number
+        };
+        assert_eq!(rounded[[row, column]].to_bits(), expected.to_bits());
+    }
+
+    let mut row = values.clone();
+    row.row_mut(4).ceil_dp_inplace(3);
+    row.column_mut(7)
+        .round_dp_mode_inplace(0, RoundingMode::HalfEven);
+    assert_eq!(row[[4, 0]].to_bits(), ceil(values[[4, 0]], 3).to_bits());
+    assert_eq!(row[[0, 7]], values[[0, 7]].round_ties_even());
+    assert_eq!(row[[5, 5]].to_bits(), values[[5, 5]].to_bits());
+}
+
+#[test]
+fn test_integers() {
+    let values = Array2::from_shape_fn((4, 5), |(row, column)| {
+        (row * 1000 + column * 37) as i64 - 1500
+    });
+    let rounded = values.round_sf_mode(2, RoundingMode::Down);
+    for (&rounded, &number) in rounded.iter().zip(values.iter()) {
+        assert_eq!(rounded, number.floor_sf(2));
+    }
+
+    let mut in_place = values.clone();
+    in_place
+        .view_mut()
+        .reversed_axes()
+        .round_sf_mode_inplace(1, RoundingMode::HalfEven);
+    for (&rounded, &number) in in_place.iter().zip(values.iter()) {
+        assert_eq!(rounded, number.round_sf_even(1));
+    }
+}
@@ -0,0 +1,494 @@
+//! [`Roundable`] for the big integers of num-bigint, [`BigInt`] and [`BigUint`]
+//!
+//! Every result is found exactly with big integer division and remainder. The integers have no
+//! upper bound, so the `checked_*` and `try_*` methods only fail for a [`BigUint`] grid point
+//! below zero, which only quantizing with an offset can reach
+
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
+
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{ToPrimitive, Zero};
+
+use super::{format, Digits, Notation, Roundable, RoundingError, RoundingMode};
+
+/// A big integer split into a sign and a magnitude
+trait BigInteger: Sized + Clone + Zero {
+    /// Whether the integer is negative and its magnitude
+    fn to_magnitude(self) -> (bool, BigUint);
+
+    /// The integer with the given sign and magnitude, `None` if it can't be negative
+    fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<Self>;
+}
+
+impl BigInteger for BigInt {
+    #[inline]
+    fn to_magnitude(self) -> (bool, BigUint) {
+        let (sign, magnitude) = self.into_parts();
+        (sign == Sign::Minus, magnitude)
+    }
+
+    #[inline]
+    fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<BigInt> {
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Some(BigInt::from_biguint(sign, magnitude))
+    }
+}
+
+impl BigInteger for BigUint {
+    #[inline]
+    fn to_magnitude(self) -> (bool, BigUint) {
+        (false, self)
+    }
+
+    #[inline]
+    fn from_magnitude(negative: bool, magnitude: BigUint) -> Option<BigUint> {
+        (!negative || magnitude.is_zero()).then_some(magnitude)
+    }
+}
+
+/// A big integer as a [`BigInt`]
+#[inline]
+fn signed<T: BigInteger>(number: T) -> BigInt {
+    let (negative, magnitude) = number.to_magnitude();
+    BigInt::from_magnitude(negative, magnitude).expect("a BigInt can be negative")
+}
+
+/// A [`BigInt`] as a big integer, `None` if it is negative and the type can't be
+#[inline]
+fn unsigned<T: BigInteger>(number: BigInt) -> Option<T> {
+    let (negative, magnitude) = number.to_magnitude();
+    T::from_magnitude(negative, magnitude)
+}
+
+/// The big integer with a magnitude rounded from one of the same sign
+#[inline]
+fn with_sign<T: BigInteger>(negative: bool, magnitude: BigUint) -> T {
+    T::from_magnitude(negative, magnitude).expect("rounding a magnitude keeps its sign")
+}
+
+/// `10^exponent` as a big integer
+#[inline]
+fn ten(exponent: u32) -> BigUint {
+    BigUint::from(10_u32).pow(exponent)
+}
+
+/// Counts the base 10 digits of a magnitude from the length of its decimal string, zero has a
+/// single digit
+#[inline]
+fn digit_count(magnitude: &BigUint) -> u32 {
+    u32::try_from(magnitude.to_string().len()).unwrap_or(u32::MAX)
+}
+
+/// Rounds a magnitude to a multiple of a non zero step in the direction the mode gives for a
+/// number with the given sign
+#[inline]
+fn round_to_step(
+    magnitude: &BigUint,
+    negative: bool,
+    step: &BigUint,
+    mode: RoundingMode,
+) -> BigUint {
+    let quotient = magnitude / step;
+    let remainder = magnitude % step;
+    let discarded = (&remainder << 1_u32).cmp(step);
+    let away = mode.rounds_away(negative, quotient.bit(0), remainder.is_zero(), discarded);
+    (quotient + away as u32) * step
+}
+
+/// Rounds a big integer to the given number of zeros
+#[inline]
+fn round_zeros<T: BigInteger>(number: T, zeros: u32, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    // Less than half a step is discarded, so the power of ten is only built if it is the result
+    if zeros > digit_count(&magnitude)
+        && !mode.rounds_away(negative, false, magnitude.is_zero(), Ordering::Less)
+    {
+        return T::zero();
+    }
+
+    with_sign(
+        negative,
+        round_to_step(&magnitude, negative, &ten(zeros), mode),
+    )
+}
+
+/// Rounds a big integer to the given number of significant figures
+#[inline]
+fn round_sf<T: BigInteger>(number: T, sig_figs: u32, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    let digits = digit_count(&magnitude);
+    if sig_figs >= digits {
+        return with_sign(negative, magnitude);
+    }
+
+    let zeros = ten(digits - sig_figs);
+    with_sign(negative, round_to_step(&magnitude, negative, &zeros, mode))
+}
+
+/// Rounds a big integer to a multiple of a positive step
+#[inline]
+fn round_multiple<T: BigInteger>(number: T, step: T, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    let (_, step) = step.to_magnitude();
+    with_sign(negative, round_to_step(&magnitude, negative, &step, mode))
+}
+
+/// Whether a big integer can be used as the step to round to a multiple of
+#[inline]
+fn is_valid_step<T: BigInteger>(step: &T) -> bool {
+    let (negative, magnitude) = step.clone().to_magnitude();
+    !negative && !magnitude.is_zero()
+}
+
+/// Rounds a big integer to a point on a grid of a positive step shifted by an offset, `None` if
+/// the point is negative and the type can't be
+#[inline]
+fn quantize<T: BigInteger>(number: T, step: T, offset: T, mode: RoundingMode) -> Option<T> {
+    let offset = signed(offset);
+    let (negative, magnitude) = (signed(number) - &offset).to_magnitude();
+    let (_, step) = step.to_magnitude();
+    let rounded = round_to_step(&magnitude, negative, &step, mode);
+    unsigned(with_sign::<BigInt>(negative, rounded) + offset)
+}
+
+/// Rounds a big integer to a point on a grid like [`quantize`], giving zero for a point below
+/// zero in a type that can't be negative
+#[inline]
+fn saturating_quantize<T: BigInteger>(number: T, step: T, offset: T, mode: RoundingMode) -> T {
+    quantize(number, step, offset, mode).unwrap_or_else(T::zero)
+}
+
+/// Rounds the magnitude of a big integer to a power of ten keeping its sign. Zero stays as it is
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+#[inline]
+fn power_of_ten<T: BigInteger>(number: T, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    if magnitude.is_zero() {
+        return with_sign(negative, magnitude);
+    }
+
+    let below = ten(digit_count(&magnitude) - 1);
+    let above = match mode {
+        _ if magnitude == below => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::HalfAwayFromZero => magnitude * 2_u32 >= &below * 11_u32,
+        _ => false,
+    };
+
+    with_sign(negative, if above { below * 10_u32 } else { below })
+}
+
+/// Counts the digits of a big integer from the first to the last non zero digit, zero has none
+#[inline]
+fn sig_figs(magnitude: &BigUint) -> u32 {
+    let digits = magnitude.to_string();
+    u32::try_from(digits.trim_end_matches('0').len()).unwrap_or(u32::MAX)
+}
+
+/// Rounds a big integer up or down at random with a probability of going up equal to its
+/// distance from the multiple of `10^zeros` below
+#[inline]
+fn stochastic<T: BigInteger, R: FnMut() -> f64>(number: T, zeros: u32, mut random: R) -> T {
+    let below = signed(round_zeros(number.clone(), zeros, RoundingMode::Down));
+    let above = signed(round_zeros(number.clone(), zeros, RoundingMode::Up));
+    if below == above {
+        return number;
+    }
+
+    let distance = (signed(number) - &below).to_f64().unwrap_or(0.0);
+    let fraction = distance / (&above - &below).to_f64().unwrap_or(f64::INFINITY);
+    let rounded = if random() < fraction { above } else { below };
+    unsigned(rounded).expect("a multiple next to the number has its sign")
+}
+
+/// The error `number - rounded`, or its magnitude for an unsigned number that was rounded up
+#[inline]
+fn rounding_error<T: BigInteger>(number: T, rounded: T) -> T {
+    let (negative, magnitude) = (signed(number) - signed(rounded)).to_magnitude();
+    T::from_magnitude(negative, magnitude.clone()).unwrap_or_else(|| with_sign(false, magnitude))
+}
+
+macro_rules! impl_roundable_big {
+    ($($t:ty),*) => {
+        $(
+            impl Roundable for $t {
+                #[inline]
+                fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> $t {
+                    round_zeros(self, zeros, mode)
+                }
+
+                #[inline]
+                fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> $t {
+                    round_sf(self, sig_figs, mode)
+                }
+
+                #[inline]
+                fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, random: R) -> $t {
+                    stochastic(self, zeros, random)
+                }
+
+                #[inline]
+                fn round_to_multiple(self, step: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    round_multiple(self, step, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_to_multiple(self, step: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    round_multiple(self, step, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn floor_to_multiple(self, step: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    round_multiple(self, step, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn try_round_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !is_valid_step(&step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        Ok(round_multiple(self, step, RoundingMode::HalfAwayFromZero))
+                    }
+                }
+
+                #[inline]
+                fn try_ceil_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !is_valid_step(&step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        Ok(round_multiple(self, step, RoundingMode::Up))
+                    }
+                }
+
+                #[inline]
+                fn try_floor_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !is_valid_step(&step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        Ok(round_multiple(self, step, RoundingMode::Down))
+                    }
+                }
+
+                #[inline]
+                fn quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    saturating_quantize(self, step, offset, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    saturating_quantize(self, step, offset, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn floor_quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    saturating_quantize(self, step, offset, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn checked_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    quantize(self, step, offset, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn checked_ceil_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    quantize(self, step, offset, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn checked_floor_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(is_valid_step(&step), "step must be positive");
+                    quantize(self, step, offset, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn round_pow10(self) -> $t {
+                    power_of_ten(self, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_pow10(self) -> $t {
+                    power_of_ten(self, RoundingMode::AwayFromZero)
+                }
+
+                #[inline]
+                fn floor_pow10(self) -> $t {
+                    power_of_ten(self, RoundingMode::TowardZero)
+                }
+
+                #[inline]
+                fn checked_round_pow10(self) -> Option<$t> {
+                    (!self.is_zero()).then(|| self.round_pow10())
+                }
+
+                #[inline]
+                fn checked_ceil_pow10(self) -> Option<$t> {
+                    (!self.is_zero()).then(|| self.ceil_pow10())
+                }
+
+                #[inline]
+                fn checked_floor_pow10(self) -> Option<$t> {
+                    (!self.is_zero()).then(|| self.floor_pow10())
+                }
+
+                #[inline]
+                fn order_of_magnitude(self) -> Option<i32> {
+                    (!self.is_zero()).then(|| self.get_digits() - 1)
+                }
+
+                #[inline]
+                fn count_sig_figs(self) -> u32 {
+                    sig_figs(&self.to_magnitude().1)
+                }
+
+                #[inline]
+                fn digit_count(self) -> u32 {
+                    digit_count(&self.to_magnitude().1)
+                }
+
+                #[inline]
+                fn digits(self) -> Digits {
+                    Digits::big(self.to_magnitude().1.to_radix_be(10))
+                }
+
+                #[inline]
+                fn format_sf(self, sig_figs: u32) -> String {
+                    self.format_sf_notation(sig_figs, Notation::Fixed)
+                }
+
+                #[inline]
+                fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+                    let rounded = self.round_sf(sig_figs.max(1));
+                    if sig_figs == 0 || rounded.is_zero() {
+                        return format::significant(0, 0, false, sig_figs, notation);
+                    }
+
+                    let (negative, magnitude) = rounded.to_magnitude();
+                    let digits = magnitude.to_string();
+                    let significand = digits.trim_end_matches('0');
+                    let zeros = (digits.len() - significand.len()) as i32;
+                    format::significant_digits(
+                        significand.to_string(),
+                        -zeros,
+                        negative,
+                        sig_figs,
+                        notation,
+                    )
+                }
+
+                #[inline]
+                fn format_grouped(
+                    self,
+                    decimal_places: u32,
+                    group_separator: char,
+                    decimal_separator: char,
+                ) -> String {
+                    self.format_locale(decimal_places, decimal_separator, Some(group_separator))
+                }
+
+                #[inline]
+                fn format_locale(
+                    self,
+                    decimal_places: u32,
+                    decimal_separator: char,
+                    group_separator: Option<char>,
+                ) -> String {
+                    let fixed = if decimal_places == 0 {
+                        self.to_string()
+                    } else {
+                        alloc::format!("{self}.{:0<width$}", "", width = decimal_places as usize)
+                    };
+                    format::group(&fixed, decimal_separator, group_separator)
+                }
+
+                #[inline]
+                fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<$t> {
+                    match u32::try_from(exponent) {
+                        Ok(zeros) => Some(round_zeros(self, zeros, mode)),
+                        Err(_) => Some(self),
+                    }
+                }
+
+                #[inline]
+                fn get_digits(&self) -> i32 {
+                    if self.is_zero() {
+                        0
+                    } else {
+                        let digits = digit_count(&self.clone().to_magnitude().1);
+                        i32::try_from(digits).unwrap_or(i32::MAX)
+                    }
+                }
+
+                #[inline]
+                fn to_decimal(&self) -> Option<(i128, i32)> {
+                    self.to_i128().map(|mantissa| (mantissa, 0))
+                }
+
+                #[inline]
+                fn from_decimal(mantissa: i128, exponent: i32) -> Option<$t> {
+                    let magnitude = BigUint::from(mantissa.unsigned_abs());
+                    let magnitude = match u32::try_from(exponent) {
+                        Ok(zeros) => magnitude * ten(zeros),
+                        Err(_) if magnitude.is_zero() => magnitude,
+                        // A non zero i128 has fewer digits than the point is moved, so has a fractional part
+                        Err(_) if exponent < -39 => return None,
+                        Err(_) => {
+                            let power = ten(exponent.unsigned_abs());
+                            if !(&magnitude % &power).is_zero() {
+                                return None;
+                            }
+                            magnitude / power
+                        }
+                    };
+                    <$t as BigInteger>::from_magnitude(mantissa < 0, magnitude)
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn nearer_above(self, below: $t, above: $t) -> bool {
+                    &above - &self < self - below
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn tie_at(self, decimal_places: i32) -> bool {
+                    let (_, magnitude) = self.to_magnitude();
+                    let zeros = decimal_places.unsigned_abs();
+                    if decimal_places >= 0 || zeros > digit_count(&magnitude) {
+                        return false;
+                    }
+
+                    let power = ten(zeros);
+                    (magnitude % &power) << 1_u32 == power
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn rounding_error(self, rounded: $t) -> $t {
+                    rounding_error(self, rounded)
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn round_slice_zeros_mode(values: &mut [$t], zeros: u32, mode: RoundingMode) {
+                    for value in values {
+                        *value = round_zeros(core::mem::take(value), zeros, mode);
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_roundable_big!(BigInt, BigUint);
+
+mod tests;
@@ -0,0 +1,180 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use core::str::FromStr;
+
+#[cfg(test)]
+fn int(text: &str) -> BigInt {
+    BigInt::from_str(text).unwrap()
+}
+
+#[cfg(test)]
+fn uint(text: &str) -> BigUint {
+    BigUint::from_str(text).unwrap()
+}
+
+#[test]
+fn test_zeros() {
+    assert_eq!(int("123456789").round_zeros(6), int("123000000"));
+    assert_eq!(int("-123500000").round_zeros(6), int("-124000000"));
+    assert_eq!(int("-123500000").round_zeros_even(6), int("-124000000"));
+    assert_eq!(int("-122500000").round_zeros_even(6), int("-122000000"));
+    assert_eq!(int("122500000").round_zeros_half_down(6), int("122000000"));
+    assert_eq!(uint("123456789").trunc_zeros(3), uint("123456000"));
+    assert_eq!(uint("123456001").away_zeros(3), uint("123457000"));
+    assert_eq!(uint("499").round_zeros(3), uint("0"));
+    assert_eq!(uint("500").round_zeros(3), uint("1000"));
+    assert_eq!(int("7").round_zeros(0), int("7"));
+    assert_eq!(int("-7").round_zeros(u32::MAX), int("0"));
+    assert_eq!(uint("7").floor_zeros(u32::MAX), uint("0"));
+}
+
+#[test]
+fn test_ceil_negative_and_floor_near_zero() {
+    assert_eq!(int("-1234567").ceil_zeros(3), int("-1234000"));
+    assert_eq!(int("-1234000").ceil_zeros(3), int("-1234000"));
+    assert_eq!(int("-999").ceil_zeros(3), int("0"));
+    assert_eq!(int("-999").ceil_zeros(3).sign(), Sign::NoSign);
+    assert_eq!(int("-1234567").ceil_sf(2), int("-1200000"));
+
+    assert_eq!(int("1").floor_zeros(3), int("0"));
+    assert_eq!(int("-1").floor_zeros(3), int("-1000"));
+    assert_eq!(int("0").floor_zeros(3), int("0"));
+    assert_eq!(int("-1").floor_sf(1), int("-1"));
+    assert_eq!(uint("1").floor_zeros(3), uint("0"));
+    assert_eq!(uint("999").floor_zeros(3), uint("0"));
+}
+
+#[test]
+fn test_significant_figures() {
+    let number = "1234567890".repeat(10);
+    assert_eq!(number.len(), 100);
+    let expected = alloc::format!("12346{}", "0".repeat(95));
+    assert_eq!(int(&number).round_sf(5), int(&expected));
+    assert_eq!(uint(&number).round_sf(5), uint(&expected));
+    assert_eq!(
+        int(&alloc::format!("-{number}")).round_sf(5),
+        int(&alloc::format!("-{expected}"))
+    );
+    assert_eq!(
+        uint(&number).floor_sf(5),
+        uint(&alloc::format!("12345{}", "0".repeat(95)))
+    );
+
+    assert_eq!(uint(&number).digit_count(), 100);
+    assert_eq!(uint(&expected).count_sig_figs(), 5);
+    assert_eq!(int(&number).order_of_magnitude(), Some(99));
+    assert_eq!(int("99950").round_sf(3), int("100000"));
+    assert_eq!(int("-123").round_sf(10), int("-123"));
+    assert_eq!(int("0").digit_count(), 1);
+    assert_eq!(int("0").count_sig_figs(), 0);
+    assert_eq!(int("0").order_of_magnitude(), None);
+
+    assert_eq!(uint("5").try_round_sf(0), Err(RoundingError::ZeroSigFigs));
+    assert_eq!(int("15").try_ceil_sf(1), Ok(int("20")));
+}
+
+#[test]
+fn test_matches_primitives() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let number = state as i64;
+        let zeros = (state % 20) as u32;
+        let sig_figs = (state % 19) as u32 + 1;
+
+        for mode in [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfUp,
+            RoundingMode::Up,
+            RoundingMode::Down,
+            RoundingMode::AwayFromZero,
+        ] {
+            // Rounded in an i128 so no result saturates
+            let wide = number as i128;
+            assert_eq!(
+                BigInt::from(number).round_zeros_mode(zeros, mode),
+                BigInt::from(wide.round_zeros_mode(zeros, mode))
+            );
+            assert_eq!(
+                BigInt::from(number).round_sf_mode(sig_figs, mode),
+                BigInt::from(wide.round_sf_mode(sig_figs, mode))
+            );
+            assert_eq!(
+                BigUint::from(state).round_sf_mode(sig_figs, mode),
+                BigUint::from((state as u128).round_sf_mode(sig_figs, mode))
+            );
+        }
+    }
+}
+
+#[test]
+fn test_multiples_and_powers() {
+    assert_eq!(int("-17").round_to_multiple(int("5")), int("-15"));
+    assert_eq!(int("-17").floor_to_multiple(int("5")), int("-20"));
+    assert_eq!(
+        int("1").try_round_to_multiple(int("-5")),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(uint("17").quantize(uint("5"), uint("1")), uint("16"));
+    assert_eq!(uint("1").checked_floor_quantize(uint("5"), uint("3")), None);
+    assert_eq!(uint("1").floor_quantize(uint("5"), uint("3")), uint("0"));
+    assert_eq!(int("1").floor_quantize(int("5"), int("3")), int("-2"));
+
+    assert_eq!(int("549").round_pow10(), int("100"));
+    assert_eq!(int("-550").round_pow10(), int("-1000"));
+    assert_eq!(uint("101").ceil_pow10(), uint("1000"));
+    assert_eq!(uint("0").checked_floor_pow10(), None);
+}
+
+#[test]
+fn test_digits_and_formatting() {
+    let number = alloc::format!("9{}", "0".repeat(40));
+    assert_eq!(uint(&number).digits().len(), 41);
+    assert_eq!(
+        int("-305").digits().collect::<alloc::vec::Vec<_>>(),
+        [3, 0, 5]
+    );
+    assert_eq!(uint("0").digits().collect::<alloc::vec::Vec<_>>(), [0]);
+
+    assert_eq!(int("-123456").format_sf(2), "-120000");
+    assert_eq!(
+        uint(&number).format_sf_notation(3, Notation::Scientific),
+        "9.00e40"
+    );
+    assert_eq!(int("0").format_sf(2), "0.0");
+    assert_eq!(int("1234567").format_grouped(0, ',', '.'), "1,234,567");
+}
+
+#[test]
+fn test_stochastic() {
+    for random in [0.0, 0.49, 0.51, 0.99] {
+        assert_eq!(
+            int("-1250").round_zeros_stochastic(2, || random),
+            BigInt::from((-1250_i32).round_zeros_stochastic(2, || random))
+        );
+    }
+    assert_eq!(
+        int("-1250").round_zeros_stochastic(2, || 0.49),
+        int("-1200")
+    );
+    assert_eq!(uint("1300").round_zeros_stochastic(2, || 0.0), uint("1300"));
+}
+
+#[test]
+fn test_decimal_bridge() {
+    assert_eq!(BigInt::from(-1_234).to_decimal(), Some((-1_234, 0)));
+    assert_eq!(BigInt::from(10_u8).pow(40).to_decimal(), None);
+    assert_eq!(
+        BigInt::from_decimal(-7, 40),
+        Some(-BigInt::from(7) * BigInt::from(10).pow(40))
+    );
+    assert_eq!(BigInt::from_decimal(-1_200, -2), Some(BigInt::from(-12)));
+    assert_eq!(BigInt::from_decimal(-1_234, -2), None);
+    assert_eq!(BigInt::from_decimal(1, -50), None);
+    assert_eq!(BigUint::from_decimal(12, 1), Some(BigUint::from(120_u8)));
+    assert_eq!(BigUint::from_decimal(-12, 1), None);
+}
@@ -0,0 +1,37 @@
+use super::RoundingMode;
+
+macro_rules! round_bits {
+    ($name:ident, $t:ty, $bits:ty, $significand_bits:expr) => {
+        /// Rounds a finite, non zero float to a number of significant bits, counting the implicit
+        /// leading bit, by rounding the bits of its magnitude as an integer. Rounding up past the
+        /// largest significand carries into the exponent, which is the next power of two
+        #[inline]
+        pub(crate) fn $name(number: $t, bits: u32, mode: RoundingMode) -> $t {
+            let sign_mask: $bits = 1 << (<$bits>::BITS - 1);
+            let magnitude = number.to_bits() & !sign_mask;
+
+            // Subnormal numbers have fewer significant bits than normal ones
+            let width = ($significand_bits).min(magnitude.ilog2() + 1);
+            let dropped = width.saturating_sub(bits);
+            if dropped == 0 {
+                return number;
+            }
+
+            let step: $bits = 1 << dropped;
+            let discarded = magnitude & (step - 1);
+            let truncated = magnitude - discarded;
+            let away = mode.rounds_away(
+                number.is_sign_negative(),
+                truncated & step != 0,
+                discarded == 0,
+                discarded.cmp(&(step / 2)),
+            );
+
+            let magnitude = if away { truncated + step } else { truncated };
+            <$t>::from_bits(magnitude | (number.to_bits() & sign_mask))
+        }
+    };
+}
+
+round_bits!(round_bits_f64, f64, u64, 53);
+round_bits!(round_bits_f32, f32, u32, 24);
@@ -0,0 +1,106 @@
+use super::{multiple, RoundingError};
+
+/// Finds the bucket a value falls in when the number line is split into buckets of `width`
+/// starting at `origin`, so bucket `i` holds the values from `origin + i * width` up to but not
+/// including `origin + (i + 1) * width`
+///
+/// Values below the origin have negative indices. The bounds are worked out on the decimal digits
+/// of the width and origin, and a value that is the closest float to a bound counts as on it, so
+/// it always lands in the bucket to its right. That keeps `0.3` in bucket 3 of width `0.1` even
+/// though `0.3 / 0.1` is just below 3 as a float, while values a float either side of the bound
+/// still land in the buckets either side of it
+///
+/// # Panics
+///
+/// Panics if `width` isn't positive and finite, if the value or origin isn't finite or if the
+/// index doesn't fit in an `i64`, see [`try_bucket_index`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::bucket_index;
+///
+/// assert_eq!(bucket_index(0.3, 0.0, 0.1), 3);
+/// assert_eq!(bucket_index(0.35, 0.0, 0.1), 3);
+/// assert_eq!(bucket_index(-0.05, 0.0, 0.1), -1);
+/// assert_eq!(bucket_index(17.0, 2.0, 5.0), 3);
+/// ```
+#[inline]
+pub fn bucket_index(value: f64, origin: f64, width: f64) -> i64 {
+    match try_bucket_index(value, origin, width) {
+        Ok(index) => index,
+        Err(RoundingError::InvalidStep) => panic!("width must be positive and finite"),
+        Err(RoundingError::NonFinite) => panic!("value and origin must be finite"),
+        Err(_) => panic!("bucket index doesn't fit in an i64"),
+    }
+}
+
+/// Finds the bucket a value falls in when the number line is split into buckets of `width`
+/// starting at `origin`, like [`bucket_index`]
+///
+/// # Errors
+///
+/// - [`RoundingError::InvalidStep`] if `width` isn't positive and finite
+/// - [`RoundingError::NonFinite`] if the value or origin isn't finite
+/// - [`RoundingError::Overflow`] if the index doesn't fit in an `i64`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_bucket_index, RoundingError};
+///
+/// assert_eq!(try_bucket_index(0.3, 0.0, 0.1), Ok(3));
+/// assert_eq!(try_bucket_index(0.3, 0.0, 0.0), Err(RoundingError::InvalidStep));
+/// assert_eq!(try_bucket_index(f64::NAN, 0.0, 0.1), Err(RoundingError::NonFinite));
+/// assert_eq!(try_bucket_index(1e300, 0.0, 1.0), Err(RoundingError::Overflow));
+/// ```
+#[inline]
+pub fn try_bucket_index(value: f64, origin: f64, width: f64) -> Result<i64, RoundingError> {
+    if !multiple::is_valid_step_f64(width) {
+        return Err(RoundingError::InvalidStep);
+    } else if !value.is_finite() || !origin.is_finite() {
+        return Err(RoundingError::NonFinite);
+    }
+
+    let index = multiple::steps_below_f64(value, width, origin);
+    // -2^63 is the smallest i64 but 2^63 is one past the largest
+    if (i64::MIN as f64..-(i64::MIN as f64)).contains(&index) {
+        Ok(index as i64)
+    } else {
+        Err(RoundingError::Overflow)
+    }
+}
+
+/// The lower and upper bounds of a bucket when the number line is split into buckets of `width`
+/// starting at `origin`, the inverse of [`bucket_index`]
+///
+/// Bucket `i` holds the values from the lower bound up to but not including the upper one, which
+/// is the lower bound of bucket `i + 1`. Each bound is the closest float to its decimal, so
+/// [`bucket_index`] of the lower bound gives the index back
+///
+/// # Panics
+///
+/// Panics if `width` isn't positive and finite or `origin` isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{bucket_bounds, bucket_index};
+///
+/// assert_eq!(bucket_bounds(3, 0.0, 0.1), (0.3, 0.4));
+/// assert_eq!(bucket_bounds(-1, 0.0, 0.1), (-0.1, 0.0));
+/// assert_eq!(bucket_bounds(3, 2.0, 5.0), (17.0, 22.0));
+/// assert_eq!(bucket_index(bucket_bounds(7, 0.0, 0.1).0, 0.0, 0.1), 7);
+/// ```
+#[inline]
+pub fn bucket_bounds(index: i64, origin: f64, width: f64) -> (f64, f64) {
+    assert!(
+        multiple::is_valid_step_f64(width),
+        "width must be positive and finite"
+    );
+    assert!(origin.is_finite(), "origin must be finite");
+
+    multiple::step_bounds_f64(index as f64, width, origin)
+}
+
+mod tests;
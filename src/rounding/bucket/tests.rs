@@ -0,0 +1,148 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_bucket_index() {
+    assert_eq!(bucket_index(0.0, 0.0, 1.0), 0);
+    assert_eq!(bucket_index(0.999, 0.0, 1.0), 0);
+    assert_eq!(bucket_index(1.0, 0.0, 1.0), 1);
+    assert_eq!(bucket_index(17.0, 2.0, 5.0), 3);
+    assert_eq!(bucket_index(16.999, 2.0, 5.0), 2);
+
+    // Negative values and origins
+    assert_eq!(bucket_index(-0.5, 0.0, 1.0), -1);
+    assert_eq!(bucket_index(-1.0, 0.0, 1.0), -1);
+    assert_eq!(bucket_index(-1.001, 0.0, 1.0), -2);
+    assert_eq!(bucket_index(-0.0, 0.0, 1.0), 0);
+    assert_eq!(bucket_index(0.0, -2.5, 1.0), 2);
+    assert_eq!(bucket_index(-2.5, -2.5, 1.0), 0);
+    assert_eq!(bucket_index(-3.0, -2.5, 1.0), -1);
+    assert_eq!(bucket_index(-7.2, -0.2, 0.5), -14);
+    assert_eq!(bucket_index(-7.21, -0.2, 0.5), -15);
+}
+
+#[test]
+fn test_boundaries() {
+    // Divided as floats these land just below the integer, as 0.3 / 0.1 is 2.9999999999999996,
+    // but each value is the closest float to the bound so it belongs to the bucket on the right
+    assert_eq!(bucket_index(0.3, 0.0, 0.1), 3);
+    assert_eq!(bucket_index(0.7, 0.1, 0.2), 3);
+    assert_eq!(bucket_index(-0.3, 0.0, 0.1), -3);
+    assert_eq!(bucket_index(1.1, 0.0, 0.1), 11);
+    assert_eq!(bucket_index(0.000_3, 0.0, 0.000_1), 3);
+
+    // The floats either side of a bound land in the buckets either side of it
+    assert_eq!(bucket_index(0.3_f64.next_down(), 0.0, 0.1), 2);
+    assert_eq!(bucket_index(0.3_f64.next_up(), 0.0, 0.1), 3);
+    assert_eq!(bucket_index((-0.3_f64).next_up(), 0.0, 0.1), -3);
+    assert_eq!(bucket_index((-0.3_f64).next_down(), 0.0, 0.1), -4);
+
+    // Every decimal on a grid of tenths is on a bound
+    for tenths in -1_000..=1_000 {
+        let value: f64 = format!("{}e-1", tenths).parse().unwrap();
+        assert_eq!(bucket_index(value, 0.0, 0.1), tenths, "{value}");
+        assert_eq!(
+            bucket_index(value.next_down(), 0.0, 0.1),
+            tenths - 1,
+            "{value}"
+        );
+    }
+}
+
+#[test]
+fn test_bucket_bounds() {
+    assert_eq!(bucket_bounds(0, 0.0, 1.0), (0.0, 1.0));
+    assert_eq!(bucket_bounds(3, 0.0, 0.1), (0.3, 0.4));
+    assert_eq!(bucket_bounds(-3, 0.0, 0.1), (-0.3, -0.2));
+    assert_eq!(bucket_bounds(3, 0.1, 0.2), (0.7, 0.9));
+    assert_eq!(bucket_bounds(-14, -0.2, 0.5), (-7.2, -6.7));
+    assert_eq!(bucket_bounds(-1, 0.0, 0.1).1.to_bits(), 0_f64.to_bits());
+}
+
+#[test]
+fn test_round_trip() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let width: f64 = format!("{}e{}", state % 9 + 1, (state >> 8) % 7)
+            .parse::<f64>()
+            .unwrap()
+            / 1e3;
+        let origin: f64 = format!("{}e-2", (state >> 16) % 2_001)
+            .parse::<f64>()
+            .unwrap()
+            - 10.0;
+        let index = ((state >> 32) % 20_001) as i64 - 10_000;
+
+        let (low, high) = bucket_bounds(index, origin, width);
+        assert!(low < high, "{index} {origin} {width}");
+        assert_eq!(
+            bucket_index(low, origin, width),
+            index,
+            "{index} {origin} {width}"
+        );
+        assert_eq!(
+            bucket_index(high, origin, width),
+            index + 1,
+            "{index} {origin} {width}"
+        );
+        assert_eq!(
+            bucket_index(high.next_down(), origin, width),
+            index,
+            "{index} {origin} {width}"
+        );
+        let middle = low + (high - low) / 2.0;
+        assert_eq!(
+            bucket_index(middle, origin, width),
+            index,
+            "{index} {origin} {width}"
+        );
+        assert_eq!(bucket_bounds(index + 1, origin, width).0, high);
+    }
+}
+
+#[test]
+fn test_errors() {
+    for width in [0.0, -0.1, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            try_bucket_index(1.0, 0.0, width),
+            Err(RoundingError::InvalidStep)
+        );
+    }
+    assert_eq!(
+        try_bucket_index(f64::INFINITY, 0.0, 0.1),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(
+        try_bucket_index(1.0, f64::NAN, 0.1),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(
+        try_bucket_index(1e300, 0.0, 1.0),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        try_bucket_index(f64::MAX, -f64::MAX, 1e300),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(try_bucket_index(i64::MIN as f64, 0.0, 1.0), Ok(i64::MIN));
+    assert_eq!(
+        try_bucket_index(-(i64::MIN as f64), 0.0, 1.0),
+        Err(RoundingError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "width must be positive and finite")]
+fn test_bucket_index_zero_width() {
+    bucket_index(1.0, 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "width must be positive and finite")]
+fn test_bucket_bounds_negative_width() {
+    bucket_bounds(1, 0.0, -1.0);
+}
@@ -0,0 +1,157 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+#[cfg(feature = "alloc")]
+use super::Roundable;
+
+/// The units a byte size is written in
+///
+/// ```rust
+/// use common_math::rounding::{format_bytes_units, ByteUnits};
+///
+/// assert_eq!(format_bytes_units(1_500_000, 2, ByteUnits::Binary), "1.4 MiB");
+/// assert_eq!(format_bytes_units(1_500_000, 2, ByteUnits::Decimal), "1.5 MB");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ByteUnits {
+    /// Powers of 1024, written `B`, `KiB`, `MiB`, `GiB`, `TiB`, `PiB` and `EiB`
+    #[default]
+    Binary,
+    /// Powers of 1000, written `B`, `kB`, `MB`, `GB`, `TB`, `PB` and `EB`
+    Decimal,
+}
+
+impl ByteUnits {
+    /// The size of each unit compared with the last and the symbols of the units
+    #[inline]
+    fn table(self) -> (u64, [&'static str; 7]) {
+        match self {
+            ByteUnits::Binary => (1024, ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"]),
+            ByteUnits::Decimal => (1000, ["B", "kB", "MB", "GB", "TB", "PB", "EB"]),
+        }
+    }
+}
+
+/// Formats a number of bytes in the largest binary unit it has at least one of, rounded to the
+/// given number of significant figures like [`round_sf`](super::round_sf)
+///
+/// When rounding reaches the next unit the size moves on to it, so `1_048_575` bytes to 4
+/// significant figures is `1.000 MiB` rather than `1024 KiB`. As a unit is 1024 of the one before, a size can round to
+/// between 1000 and 1023 of a unit without reaching the next, so `999` bytes to 2 significant
+/// figures stays as `1000 B`. Sizes in bytes are whole numbers and aren't padded with zeros
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_bytes;
+///
+/// assert_eq!(format_bytes(1536, 3), "1.50 KiB");
+/// assert_eq!(format_bytes(999, 2), "1000 B");
+/// assert_eq!(format_bytes(5, 3), "5 B");
+/// assert_eq!(format_bytes(u64::MAX, 3), "16.0 EiB");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn format_bytes(bytes: u64, sig_figs: u32) -> String {
+    format_bytes_units(bytes, sig_figs, ByteUnits::Binary)
+}
+
+/// Formats a number of bytes like [`format_bytes`] in the given units
+///
+/// Decimal units are 1000 of the one before, so `999` bytes to 2 significant figures rounds to
+/// `1.0 kB`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{format_bytes_units, ByteUnits};
+///
+/// assert_eq!(format_bytes_units(2_500_000_000, 2, ByteUnits::Decimal), "2.5 GB");
+/// assert_eq!(format_bytes_units(999, 2, ByteUnits::Decimal), "1.0 kB");
+/// assert_eq!(format_bytes_units(1536, 3, ByteUnits::Binary), "1.50 KiB");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn format_bytes_units(bytes: u64, sig_figs: u32, units: ByteUnits) -> String {
+    let (base, symbols) = units.table();
+    let last = symbols.len() - 1;
+    let mut unit = 0;
+    while unit < last && bytes >= base.pow(unit as u32 + 1) {
+        unit += 1;
+    }
+
+    let size = |unit: usize| bytes as f64 / base.pow(unit as u32) as f64;
+    let reaches_next = if unit == 0 {
+        bytes.round_sf(sig_figs) >= base
+    } else {
+        size(unit).round_sf(sig_figs) >= base as f64
+    };
+    if reaches_next && unit < last {
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes.round_sf(sig_figs), symbols[0])
+    } else {
+        format!("{} {}", size(unit).format_sf(sig_figs), symbols[unit])
+    }
+}
+
+/// Parses a size such as `2.5 GiB`, `512 kB` or `100` in bytes, rounding it to a whole number
+/// of bytes. The inverse of [`format_bytes`] and [`format_bytes_units`]
+///
+/// Both binary and decimal units are accepted, with `KB` read as `kB`. `None` is returned when
+/// the text isn't a number followed by a unit, or the size is negative or doesn't fit in a u64
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::parse_bytes;
+///
+/// assert_eq!(parse_bytes("2.5 GiB"), Some(2_684_354_560));
+/// assert_eq!(parse_bytes("1.5kB"), Some(1500));
+/// assert_eq!(parse_bytes("0.3 B"), Some(0));
+/// assert_eq!(parse_bytes("16 EiB"), None);
+/// assert_eq!(parse_bytes("12 bits"), None);
+/// ```
+#[inline]
+pub fn parse_bytes(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let split = text
+        .rfind(|c: char| !c.is_ascii_alphabetic())
+        .map_or(0, |last| last + 1);
+    let (number, symbol) = (text[..split].trim_end(), &text[split..]);
+
+    let symbol = if symbol == "KB" { "kB" } else { symbol };
+    let (base, unit) = if symbol.is_empty() {
+        (1, 0)
+    } else {
+        [ByteUnits::Binary, ByteUnits::Decimal]
+            .into_iter()
+            .find_map(|units| {
+                let (base, symbols) = units.table();
+                let unit = symbols.iter().position(|&unit| unit == symbol)?;
+                Some((base, unit))
+            })?
+    };
+
+    // Whole numbers of bytes are parsed exactly, as a float can't hold every u64
+    if unit == 0 {
+        if let Ok(bytes) = number.parse::<u64>() {
+            return Some(bytes);
+        }
+    }
+    if !number.bytes().any(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let bytes = (number.parse::<f64>().ok()? * base.pow(unit as u32) as f64).round();
+    // 2^64 is the first float past the end of a u64
+    (0.0..18_446_744_073_709_551_616.0)
+        .contains(&bytes)
+        .then_some(bytes as u64)
+}
+
+mod tests;
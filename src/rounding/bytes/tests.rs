@@ -0,0 +1,101 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_format_bytes() {
+    let cases = [
+        (0, 3, "0 B"),
+        (1, 3, "1 B"),
+        (999, 2, "1000 B"),
+        (999, 3, "999 B"),
+        (1536, 3, "1.50 KiB"),
+        (10_240, 2, "10 KiB"),
+        (123_456_789, 3, "118 MiB"),
+        (5 << 30, 4, "5.000 GiB"),
+        (1 << 40, 1, "1 TiB"),
+        (3 << 50, 2, "3.0 PiB"),
+        (u64::MAX, 3, "16.0 EiB"),
+        (u64::MAX, 1, "20 EiB"),
+    ];
+    for (bytes, sig_figs, expected) in cases {
+        assert_eq!(format_bytes(bytes, sig_figs), expected, "{bytes}");
+    }
+}
+
+#[test]
+fn test_format_bytes_unit_boundaries() {
+    assert_eq!(format_bytes(1023, 4), "1023 B");
+    assert_eq!(format_bytes(1023, 3), "1020 B");
+    assert_eq!(format_bytes(1024, 3), "1.00 KiB");
+    assert_eq!(format_bytes(1025, 3), "1.00 KiB");
+    assert_eq!(format_bytes(1025, 4), "1.001 KiB");
+
+    // Rounding up to 1024 of a unit moves on to the next
+    assert_eq!(format_bytes(1_048_575, 4), "1.000 MiB");
+    assert_eq!(format_bytes(1_048_575, 3), "1020 KiB");
+    assert_eq!(format_bytes(1_048_575, 7), "1023.999 KiB");
+    assert_eq!(format_bytes((1 << 30) - 1, 4), "1.000 GiB");
+    assert_eq!(format_bytes((1 << 20) - 600, 4), "1023 KiB");
+
+    assert_eq!(format_bytes_units(999, 3, ByteUnits::Decimal), "999 B");
+    assert_eq!(format_bytes_units(999, 2, ByteUnits::Decimal), "1.0 kB");
+    assert_eq!(format_bytes_units(1000, 3, ByteUnits::Decimal), "1.00 kB");
+    assert_eq!(
+        format_bytes_units(999_999, 3, ByteUnits::Decimal),
+        "1.00 MB"
+    );
+    assert_eq!(
+        format_bytes_units(u64::MAX, 3, ByteUnits::Decimal),
+        "18.4 EB"
+    );
+    assert_eq!(format_bytes_units(0, 2, ByteUnits::Decimal), "0 B");
+}
+
+#[test]
+fn test_parse_bytes() {
+    assert_eq!(parse_bytes("0"), Some(0));
+    assert_eq!(parse_bytes("512 B"), Some(512));
+    assert_eq!(parse_bytes("1.50 KiB"), Some(1536));
+    assert_eq!(parse_bytes("2.5 GiB"), Some(5 << 29));
+    assert_eq!(parse_bytes(" 1 MiB "), Some(1 << 20));
+    assert_eq!(parse_bytes("1.5kB"), Some(1500));
+    assert_eq!(parse_bytes("1.5 KB"), Some(1500));
+    assert_eq!(parse_bytes("1e3 MB"), Some(1_000_000_000));
+    assert_eq!(parse_bytes("1.5EiB"), Some(3 << 59));
+    assert_eq!(parse_bytes("0.5 B"), Some(1));
+    assert_eq!(parse_bytes("18446744073709551615"), Some(u64::MAX));
+    assert_eq!(parse_bytes("15.9 EiB"), Some(18_331_451_923_248_867_328));
+
+    assert_eq!(parse_bytes(""), None);
+    assert_eq!(parse_bytes("KiB"), None);
+    assert_eq!(parse_bytes("16 EiB"), None);
+    assert_eq!(parse_bytes("-1 KiB"), None);
+    assert_eq!(parse_bytes("1 kib"), None);
+    assert_eq!(parse_bytes("1 KiB B"), None);
+    assert_eq!(parse_bytes("inf B"), None);
+    assert_eq!(parse_bytes("NaN"), None);
+}
+
+#[test]
+fn test_format_parse_round_trip() {
+    let mut state = 0xb17e_5000_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let bytes = state >> (state % 64);
+        let sig_figs = (state >> 6) as u32 % 6 + 1;
+
+        for units in [ByteUnits::Binary, ByteUnits::Decimal] {
+            let formatted = format_bytes_units(bytes, sig_figs, units);
+            let parsed = parse_bytes(&formatted).unwrap_or(u64::MAX);
+
+            // The size is only kept to the significant figures
+            let error = parsed.abs_diff(bytes) as f64;
+            assert!(
+                error <= bytes as f64 * 0.5 * 10_f64.powi(1 - sig_figs as i32) + 1.0,
+                "{bytes} {formatted}"
+            );
+        }
+    }
+}
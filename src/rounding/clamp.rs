@@ -0,0 +1,368 @@
+use super::{Roundable, RoundingError};
+
+/// Rounds the number to the given number of decimal places, then clamps it to a range
+///
+/// The bounds are first moved inside the range onto the decimal place grid, the minimum up and
+/// the maximum down, so the result is always a value with that many decimal places that is inside
+/// the range. A NaN number stays NaN
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many decimal places between them, see [`try_round_dp_clamped`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_clamped;
+///
+/// assert_eq!(round_dp_clamped(99.96, 1, 0.0, 99.9), 99.9);
+/// assert_eq!(round_dp_clamped(0.04, 1, 0.05, 1.0), 0.1);
+/// assert_eq!(round_dp_clamped(1.234, 2, 0.0, 9.999), 1.23);
+/// assert!(round_dp_clamped(f64::NAN, 2, 0.0, 1.0).is_nan());
+/// ```
+#[inline]
+pub fn round_dp_clamped<T>(number: T, decimal_places: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_round_dp_clamped(number, decimal_places, min, max))
+}
+
+/// Rounds the number up to the given number of decimal places, then clamps it to a range
+///
+/// The bounds are snapped onto the decimal place grid like [`round_dp_clamped`]
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many decimal places between them, see [`try_ceil_dp_clamped`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_dp_clamped;
+///
+/// // A probability that picked up a floating point error
+/// assert_eq!(ceil_dp_clamped(1.0 + f64::EPSILON, 2, 0.0, 1.0), 1.0);
+/// assert_eq!(ceil_dp_clamped(0.123, 2, 0.0, 1.0), 0.13);
+/// ```
+#[inline]
+pub fn ceil_dp_clamped<T>(number: T, decimal_places: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_ceil_dp_clamped(number, decimal_places, min, max))
+}
+
+/// Rounds the number down to the given number of decimal places, then clamps it to a range
+///
+/// The bounds are snapped onto the decimal place grid like [`round_dp_clamped`]
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many decimal places between them, see [`try_floor_dp_clamped`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_dp_clamped;
+///
+/// assert_eq!(floor_dp_clamped(-0.001, 2, 0.0, 1.0), 0.0);
+/// assert_eq!(floor_dp_clamped(1_234, 0, 0, 999), 999);
+/// ```
+#[inline]
+pub fn floor_dp_clamped<T>(number: T, decimal_places: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_floor_dp_clamped(number, decimal_places, min, max))
+}
+
+/// Rounds the number to the given number of significant figures, then clamps it to a range
+///
+/// The bounds are first moved inside the range to values with that many significant figures, the
+/// minimum up and the maximum down, so the result always has at most that many significant
+/// figures and is inside the range. A NaN number stays NaN
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many significant figures between them, see [`try_round_sf_clamped`] to handle this
+/// instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_clamped;
+///
+/// assert_eq!(round_sf_clamped(99_960.0, 3, 0.0, 99_950.0), 99_900.0);
+/// assert_eq!(round_sf_clamped(1_234_u32, 2, 0, 5_000), 1_200);
+/// ```
+#[inline]
+pub fn round_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_round_sf_clamped(number, sig_figs, min, max))
+}
+
+/// Rounds the number up to the given number of significant figures, then clamps it to a range
+///
+/// The bounds are snapped to significant figures like [`round_sf_clamped`]
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many significant figures between them, see [`try_ceil_sf_clamped`] to handle this
+/// instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_sf_clamped;
+///
+/// assert_eq!(ceil_sf_clamped(0.9991, 2, 0.0, 1.0), 1.0);
+/// assert_eq!(ceil_sf_clamped(0.123, 2, 0.0, 1.0), 0.13);
+/// ```
+#[inline]
+pub fn ceil_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_ceil_sf_clamped(number, sig_figs, min, max))
+}
+
+/// Rounds the number down to the given number of significant figures, then clamps it to a range
+///
+/// The bounds are snapped to significant figures like [`round_sf_clamped`]
+///
+/// # Panics
+///
+/// Panics if the minimum is greater than the maximum, either of them is NaN or there is no value
+/// with that many significant figures between them, see [`try_floor_sf_clamped`] to handle this
+/// instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_sf_clamped;
+///
+/// assert_eq!(floor_sf_clamped(0.0123, 1, 0.015, 1.0), 0.02);
+/// ```
+#[inline]
+pub fn floor_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> T
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    expect_range(try_floor_sf_clamped(number, sig_figs, min, max))
+}
+
+/// Rounds the number to the given number of decimal places, then clamps it to a range,
+/// like [`round_dp_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many decimal places between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_dp_clamped, RoundingError};
+///
+/// assert_eq!(try_round_dp_clamped(99.96, 1, 0.0, 99.9), Ok(99.9));
+/// assert_eq!(try_round_dp_clamped(0.5, 1, 1.0, 0.0), Err(RoundingError::InvalidRange));
+/// assert_eq!(try_round_dp_clamped(0.5, 1, 0.11, 0.19), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_dp_clamped<T>(
+    number: T,
+    decimal_places: u32,
+    min: T,
+    max: T,
+) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_decimals(decimal_places), max.floor_decimals(decimal_places));
+    clamp(number.round_decimals(decimal_places), min, max)
+}
+
+/// Rounds the number up to the given number of decimal places, then clamps it to a range,
+/// like [`ceil_dp_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many decimal places between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_dp_clamped, RoundingError};
+///
+/// assert_eq!(try_ceil_dp_clamped(1.001, 2, 0.0, 1.0), Ok(1.0));
+/// assert_eq!(try_ceil_dp_clamped(0.5, 2, f64::NAN, 1.0), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_ceil_dp_clamped<T>(
+    number: T,
+    decimal_places: u32,
+    min: T,
+    max: T,
+) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_decimals(decimal_places), max.floor_decimals(decimal_places));
+    clamp(number.ceil_decimals(decimal_places), min, max)
+}
+
+/// Rounds the number down to the given number of decimal places, then clamps it to a range,
+/// like [`floor_dp_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many decimal places between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_dp_clamped, RoundingError};
+///
+/// assert_eq!(try_floor_dp_clamped(-0.5, 0, -0.4, 0.4), Ok(0.0));
+/// assert_eq!(try_floor_dp_clamped(5, 0, 9, 1), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_floor_dp_clamped<T>(
+    number: T,
+    decimal_places: u32,
+    min: T,
+    max: T,
+) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_decimals(decimal_places), max.floor_decimals(decimal_places));
+    clamp(number.floor_decimals(decimal_places), min, max)
+}
+
+/// Rounds the number to the given number of significant figures, then clamps it to a range,
+/// like [`round_sf_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many significant figures between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_sf_clamped, RoundingError};
+///
+/// assert_eq!(try_round_sf_clamped(0.0456, 1, 0.0, 0.1), Ok(0.05));
+/// assert_eq!(try_round_sf_clamped(125, 1, 110, 190), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_sf(sig_figs), max.floor_sf(sig_figs));
+    clamp(number.round_sf(sig_figs), min, max)
+}
+
+/// Rounds the number up to the given number of significant figures, then clamps it to a range,
+/// like [`ceil_sf_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many significant figures between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_sf_clamped, RoundingError};
+///
+/// assert_eq!(try_ceil_sf_clamped(0.991, 1, 0.0, 1.0), Ok(1.0));
+/// assert_eq!(try_ceil_sf_clamped(0.5, 1, 1.0, 0.0), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_ceil_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_sf(sig_figs), max.floor_sf(sig_figs));
+    clamp(number.ceil_sf(sig_figs), min, max)
+}
+
+/// Rounds the number down to the given number of significant figures, then clamps it to a range,
+/// like [`floor_sf_clamped`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the minimum is greater than the maximum, either of them is
+/// NaN or there is no value with that many significant figures between them
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_sf_clamped, RoundingError};
+///
+/// assert_eq!(try_floor_sf_clamped(-0.0123, 2, -0.01, 0.01), Ok(-0.01));
+/// assert_eq!(try_floor_sf_clamped(1.0, 2, 0.0, f64::NAN), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_floor_sf_clamped<T>(number: T, sig_figs: u32, min: T, max: T) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check_range(min, max)?;
+    let (min, max) = (min.ceil_sf(sig_figs), max.floor_sf(sig_figs));
+    clamp(number.floor_sf(sig_figs), min, max)
+}
+
+/// Checks the bounds can be ordered and the minimum isn't greater than the maximum
+#[inline]
+fn check_range<T: PartialOrd>(min: T, max: T) -> Result<(), RoundingError> {
+    // Written so NaN bounds fail the check too
+    if min <= max {
+        Ok(())
+    } else {
+        Err(RoundingError::InvalidRange)
+    }
+}
+
+/// Clamps a rounded number to bounds that have already been snapped to the grid,
+/// which may have crossed if there is no grid value between them
+#[inline]
+fn clamp<T: PartialOrd>(rounded: T, min: T, max: T) -> Result<T, RoundingError> {
+    check_range(&min, &max)?;
+    Ok(if rounded < min {
+        min
+    } else if rounded > max {
+        max
+    } else {
+        rounded
+    })
+}
+
+/// Unwraps the result of a `try_*` function, panicking for an invalid range
+#[inline]
+fn expect_range<T>(result: Result<T, RoundingError>) -> T {
+    match result {
+        Ok(clamped) => clamped,
+        Err(_) => panic!("the range to clamp to must have a rounded value in it"),
+    }
+}
+
+mod tests;
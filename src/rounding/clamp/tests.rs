@@ -0,0 +1,91 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::Float;
+
+#[test]
+fn test_probability_exceeds_one() {
+    // A probability that picked up a floating point error
+    let probability = 1.0 + f64::EPSILON;
+    assert_eq!(probability.ceil_dp(2), 1.01);
+    assert_eq!(ceil_dp_clamped(probability, 2, 0.0, 1.0), 1.0);
+    assert_eq!(ceil_sf_clamped(probability, 3, 0.0, 1.0), 1.0);
+    assert_eq!(ceil_dp_clamped(-1e-9, 3, 0.0, 1.0), 0.0);
+    assert_eq!(ceil_dp_clamped(0.4567_f32, 2, 0.0, 1.0), 0.46);
+}
+
+#[test]
+fn test_percentage_display() {
+    // The maximum isn't reachable by rounding, so it is the closest value below 100 that is
+    assert_eq!(round_dp_clamped(99.96, 1, 0.0, 99.95), 99.9);
+    assert_eq!(round_dp_clamped(99.94, 1, 0.0, 99.95), 99.9);
+    assert_eq!(round_dp_clamped(-0.04, 1, 0.0, 100.0), 0.0);
+    assert_eq!(round_sf_clamped(99.96, 3, 0.0, 99.9), 99.9);
+    assert_eq!(floor_dp_clamped(100_u32, 0, 0, 99), 99);
+
+    // Every result is on the grid and in the range
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let number = (state >> 11) as f64 / (1_u64 << 53) as f64 * 120.0 - 10.0;
+        let min = (state % 1_000) as f64 / 100.0;
+        let max = 100.0 - (state >> 54) as f64 / 100.0;
+        for clamped in [
+            round_dp_clamped(number, 1, min, max),
+            ceil_dp_clamped(number, 1, min, max),
+            floor_dp_clamped(number, 1, min, max),
+        ] {
+            assert!(min <= clamped && clamped <= max);
+            assert_eq!(clamped, clamped.round_dp(1));
+        }
+        for clamped in [
+            round_sf_clamped(number, 2, min, max),
+            ceil_sf_clamped(number, 2, min, max),
+            floor_sf_clamped(number, 2, min, max),
+        ] {
+            assert!(min <= clamped && clamped <= max);
+            assert_eq!(clamped, clamped.round_sf(2));
+        }
+    }
+}
+
+#[test]
+fn test_nan() {
+    assert!(round_dp_clamped(f64::NAN, 1, 0.0, 1.0).is_nan());
+    assert!(ceil_sf_clamped(f32::NAN, 1, 0.0, 1.0).is_nan());
+    assert_eq!(
+        try_floor_dp_clamped(f64::NAN, 1, 0.0, 1.0).map(f64::is_nan),
+        Ok(true)
+    );
+    assert_eq!(
+        try_round_dp_clamped(0.5, 1, f64::NAN, 1.0),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(
+        try_round_sf_clamped(0.5, 1, 0.0, f64::NAN),
+        Err(RoundingError::InvalidRange)
+    );
+}
+
+#[test]
+fn test_invalid_range() {
+    assert_eq!(
+        try_round_dp_clamped(0.5, 1, 1.0, 0.0),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(
+        try_ceil_dp_clamped(0.15, 1, 0.11, 0.19),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(
+        try_floor_sf_clamped(5_u8, 1, 11, 19),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(try_round_dp_clamped(0.5, 1, 0.3, 0.3), Ok(0.3));
+    assert_eq!(try_round_sf_clamped(7_i32, 1, 7, 7), Ok(7));
+    assert!(std::panic::catch_unwind(|| round_dp_clamped(0.5, 1, 1.0, 0.0)).is_err());
+}
@@ -0,0 +1,160 @@
+use super::{Float, Roundable};
+
+/// Whether two numbers are equal once rounded to the given number of decimal places
+///
+/// NaN is never equal to anything and infinities are only equal to an infinity of the same sign.
+/// Numbers either side of a rounding boundary are never equal however close they are, so
+/// `9.4449` and `9.4451` are equal at 3 decimal places as both round to `9.445`,
+/// but not at 2 as they round to `9.44` and `9.45`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::approx_eq_dp;
+///
+/// assert!(approx_eq_dp(0.1 + 0.2, 0.3, 10));
+/// assert!(approx_eq_dp(9.4449, 9.4451, 3));
+/// assert!(!approx_eq_dp(9.4449, 9.4451, 2));
+/// assert!(!approx_eq_dp(f64::NAN, f64::NAN, 2));
+/// ```
+#[inline]
+pub fn approx_eq_dp<T: Float + PartialEq>(a: T, b: T, decimal_places: u32) -> bool {
+    a.round_dp(decimal_places) == b.round_dp(decimal_places)
+}
+
+/// Whether two numbers are equal once rounded to the given number of significant figures
+///
+/// NaN is never equal to anything and infinities are only equal to an infinity of the same sign.
+/// Numbers either side of a rounding boundary are never equal however close they are.
+/// Zero significant figures round every finite number to zero, so they are all equal
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::approx_eq_sf;
+///
+/// assert!(approx_eq_sf(123_456.7_f64, 123_457.1, 6));
+/// assert!(!approx_eq_sf(123_456.7_f64, 123_457.6, 6));
+/// assert!(approx_eq_sf(1234_i32, 1240, 2));
+/// assert!(approx_eq_sf(f32::INFINITY, f32::INFINITY, 3));
+/// ```
+#[inline]
+pub fn approx_eq_sf<T: Roundable + PartialEq>(a: T, b: T, sig_figs: u32) -> bool {
+    a.round_sf(sig_figs) == b.round_sf(sig_figs)
+}
+
+/// Asserts that two floats are equal once rounded to the given number of decimal places
+/// using [`approx_eq_dp`](crate::rounding::approx_eq_dp)
+///
+/// On failure both values are printed along with their rounded forms.
+/// A custom message can be given after the decimal places, like [`assert_eq!`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::assert_approx_eq_dp;
+///
+/// assert_approx_eq_dp!(0.1 + 0.2, 0.3, 2);
+/// assert_approx_eq_dp!(1.004_f32, 1.0, 2, "{} should round to 1", 1.004);
+/// ```
+///
+/// ```should_panic
+/// use common_math::assert_approx_eq_dp;
+///
+/// assert_approx_eq_dp!(1.006, 1.0, 2);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq_dp {
+    ($left:expr, $right:expr, $decimal_places:expr $(,)?) => {
+        match (&$left, &$right, &$decimal_places) {
+            (left, right, decimal_places) => {
+                if !$crate::rounding::approx_eq_dp(*left, *right, *decimal_places) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed at {} decimal places\n  left: {:?} (rounded {:?})\n right: {:?} (rounded {:?})",
+                        decimal_places,
+                        left,
+                        $crate::rounding::round(*left, *decimal_places),
+                        right,
+                        $crate::rounding::round(*right, *decimal_places),
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $decimal_places:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$decimal_places) {
+            (left, right, decimal_places) => {
+                if !$crate::rounding::approx_eq_dp(*left, *right, *decimal_places) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed at {} decimal places: {}\n  left: {:?} (rounded {:?})\n right: {:?} (rounded {:?})",
+                        decimal_places,
+                        ::core::format_args!($($arg)+),
+                        left,
+                        $crate::rounding::round(*left, *decimal_places),
+                        right,
+                        $crate::rounding::round(*right, *decimal_places),
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two numbers are equal once rounded to the given number of significant figures
+/// using [`approx_eq_sf`](crate::rounding::approx_eq_sf)
+///
+/// On failure both values are printed along with their rounded forms.
+/// A custom message can be given after the significant figures, like [`assert_eq!`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::assert_approx_eq_sf;
+///
+/// assert_approx_eq_sf!(123_456.7, 123_457.1, 6);
+/// assert_approx_eq_sf!(1234_u32, 1240, 2, "counts differ");
+/// ```
+///
+/// ```should_panic
+/// use common_math::assert_approx_eq_sf;
+///
+/// assert_approx_eq_sf!(0.0123, 0.0124, 3);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq_sf {
+    ($left:expr, $right:expr, $sig_figs:expr $(,)?) => {
+        match (&$left, &$right, &$sig_figs) {
+            (left, right, sig_figs) => {
+                if !$crate::rounding::approx_eq_sf(*left, *right, *sig_figs) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed at {} significant figures\n  left: {:?} (rounded {:?})\n right: {:?} (rounded {:?})",
+                        sig_figs,
+                        left,
+                        $crate::rounding::round_sf(*left, *sig_figs),
+                        right,
+                        $crate::rounding::round_sf(*right, *sig_figs),
+                    );
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $sig_figs:expr, $($arg:tt)+) => {
+        match (&$left, &$right, &$sig_figs) {
+            (left, right, sig_figs) => {
+                if !$crate::rounding::approx_eq_sf(*left, *right, *sig_figs) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed at {} significant figures: {}\n  left: {:?} (rounded {:?})\n right: {:?} (rounded {:?})",
+                        sig_figs,
+                        ::core::format_args!($($arg)+),
+                        left,
+                        $crate::rounding::round_sf(*left, *sig_figs),
+                        right,
+                        $crate::rounding::round_sf(*right, *sig_figs),
+                    );
+                }
+            }
+        }
+    };
+}
+
+mod tests;
@@ -0,0 +1,67 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_approx_eq_dp() {
+    assert!(approx_eq_dp(0.1 + 0.2, 0.3, 15));
+    assert!(!approx_eq_dp(0.1 + 0.2, 0.3, 17));
+    assert!(approx_eq_dp(1.004_f32, 0.996, 2));
+    assert!(approx_eq_dp(-2.5_f64, -3.0, 0));
+    assert!(approx_eq_dp(0.004_f64, -0.004, 2));
+
+    // Numbers either side of a rounding boundary
+    assert!(approx_eq_dp(9.4449, 9.4451, 3));
+    assert!(!approx_eq_dp(9.4449, 9.4451, 2));
+    assert!(!approx_eq_dp(9.4449, 9.4451, 4));
+    assert!(!approx_eq_dp(1.2349999, 1.2350001, 2));
+}
+
+#[test]
+fn test_approx_eq_dp_non_finite() {
+    assert!(!approx_eq_dp(f64::NAN, f64::NAN, 2));
+    assert!(!approx_eq_dp(f32::NAN, 1.0, 0));
+    assert!(approx_eq_dp(f64::INFINITY, f64::INFINITY, 2));
+    assert!(approx_eq_dp(f32::NEG_INFINITY, f32::NEG_INFINITY, 2));
+    assert!(!approx_eq_dp(f64::INFINITY, f64::NEG_INFINITY, 2));
+    assert!(!approx_eq_dp(f64::INFINITY, f64::MAX, 0));
+}
+
+#[test]
+fn test_approx_eq_sf() {
+    assert!(approx_eq_sf(123_456.7_f64, 123_457.1, 6));
+    assert!(!approx_eq_sf(123_456.7_f64, 123_457.6, 6));
+    assert!(approx_eq_sf(0.012_34_f32, 0.012_3, 3));
+    assert!(approx_eq_sf(1234_i32, 1240, 2));
+    assert!(!approx_eq_sf(1249_u16, 1250, 2));
+    assert!(approx_eq_sf(-1249_i64, -1250, 3));
+    assert!(approx_eq_sf(17.3_f64, -4.0, 0));
+
+    assert!(!approx_eq_sf(f64::NAN, f64::NAN, 3));
+    assert!(approx_eq_sf(f64::INFINITY, f64::INFINITY, 3));
+    assert!(!approx_eq_sf(f32::NEG_INFINITY, f32::INFINITY, 3));
+}
+
+#[test]
+fn test_assert_macros() {
+    crate::assert_approx_eq_dp!(0.1 + 0.2, 0.3, 2);
+    crate::assert_approx_eq_dp!(1.0_f32, 1.0001, 3,);
+    crate::assert_approx_eq_dp!(9.4449, 9.4451, 3, "values {} apart", 0.0002);
+    crate::assert_approx_eq_sf!(123_456.7, 123_457.1, 6);
+    crate::assert_approx_eq_sf!(1234_u64, 1240, 2, "counts");
+}
+
+#[test]
+#[should_panic(expected = "assertion `left ≈ right` failed at 2 decimal places\n  \
+                           left: 9.4449 (rounded 9.44)\n right: 9.4451 (rounded 9.45)")]
+fn test_assert_approx_eq_dp_message() {
+    crate::assert_approx_eq_dp!(9.4449, 9.4451, 2);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left ≈ right` failed at 1 significant figures: counts\n  \
+                           left: 14 (rounded 10)\n right: 15 (rounded 20)"
+)]
+fn test_assert_approx_eq_sf_message() {
+    crate::assert_approx_eq_sf!(14_i32, 15, 1, "counts");
+}
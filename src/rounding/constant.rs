@@ -0,0 +1,240 @@
+//! Integer rounding as `const fn`s, which can't go through the [`Roundable`](super::Roundable)
+//! trait on stable Rust so there is a function for each type
+
+use super::{integer, RoundingMode};
+
+macro_rules! impl_const_rounding {
+    (@split signed $number:ident) => {
+        ($number < 0, $number.unsigned_abs() as u128)
+    };
+    (@split unsigned $number:ident) => {
+        (false, $number as u128)
+    };
+    // Rebuilds the integer from a rounded magnitude, saturating at the bound on the same side of
+    // zero as the number like the runtime functions
+    (@join signed $t:ty, $negative:ident, $rounded:expr) => {
+        match $rounded {
+            Some(magnitude) if $negative => {
+                if magnitude > <$t>::MIN.unsigned_abs() as u128 {
+                    <$t>::MIN
+                } else {
+                    (magnitude as $t).wrapping_neg()
+                }
+            }
+            Some(magnitude) if magnitude <= <$t>::MAX as u128 => magnitude as $t,
+            None if $negative => <$t>::MIN,
+            _ => <$t>::MAX,
+        }
+    };
+    (@join unsigned $t:ty, $negative:ident, $rounded:expr) => {
+        match $rounded {
+            Some(magnitude) if magnitude <= <$t>::MAX as u128 => magnitude as $t,
+            _ => <$t>::MAX,
+        }
+    };
+    ($(
+        $sign:ident $t:ty {
+            $round_zeros:ident, $ceil_zeros:ident, $floor_zeros:ident, $zeros_mode:ident,
+            $round_sf:ident, $ceil_sf:ident, $floor_sf:ident, $sf_mode:ident $(,)?
+        }
+    )*) => {
+        $(
+            #[doc = concat!("Rounds a `", stringify!($t), "` to the given number of zeros, usable in const contexts")]
+            ///
+            /// Gives the same result as [`round_zeros`](super::round_zeros), saturating when the
+            /// result doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($round_zeros), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($round_zeros), "(15, 1);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $round_zeros(number: $t, zeros: u32) -> $t {
+                $zeros_mode(number, zeros, RoundingMode::HalfAwayFromZero)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` up to the given number of zeros, usable in const contexts")]
+            ///
+            /// Gives the same result as [`ceil_zeros`](super::ceil_zeros), saturating when the
+            /// result doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($ceil_zeros), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($ceil_zeros), "(11, 1);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $ceil_zeros(number: $t, zeros: u32) -> $t {
+                $zeros_mode(number, zeros, RoundingMode::Up)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` down to the given number of zeros, usable in const contexts")]
+            ///
+            /// Gives the same result as [`floor_zeros`](super::floor_zeros)
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($floor_zeros), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($floor_zeros), "(19, 1);")]
+            /// assert_eq!(ROUNDED, 10);
+            /// ```
+            #[inline]
+            pub const fn $floor_zeros(number: $t, zeros: u32) -> $t {
+                $zeros_mode(number, zeros, RoundingMode::Down)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` to the given number of zeros using the given rounding mode, usable in const contexts")]
+            ///
+            /// Gives the same result as [`round_zeros_mode`](super::round_zeros_mode), saturating
+            /// when the result doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::{", stringify!($zeros_mode), ", RoundingMode};")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($zeros_mode), "(25, 1, RoundingMode::HalfEven);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $zeros_mode(number: $t, zeros: u32, mode: RoundingMode) -> $t {
+                let (negative, magnitude) = impl_const_rounding!(@split $sign number);
+                let rounded = integer::round_magnitude(magnitude, negative, zeros, mode);
+                impl_const_rounding!(@join $sign $t, negative, rounded)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` to the given number of significant figures, usable in const contexts")]
+            ///
+            /// Gives the same result as [`round_sf`](super::round_sf), saturating when the
+            /// result doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($round_sf), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($round_sf), "(15, 1);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $round_sf(number: $t, sig_figs: u32) -> $t {
+                $sf_mode(number, sig_figs, RoundingMode::HalfAwayFromZero)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` up to the given number of significant figures, usable in const contexts")]
+            ///
+            /// Gives the same result as [`ceil_sf`](super::ceil_sf), saturating when the result
+            /// doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($ceil_sf), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($ceil_sf), "(11, 1);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $ceil_sf(number: $t, sig_figs: u32) -> $t {
+                $sf_mode(number, sig_figs, RoundingMode::Up)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` down to the given number of significant figures, usable in const contexts")]
+            ///
+            /// Gives the same result as [`floor_sf`](super::floor_sf)
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::", stringify!($floor_sf), ";")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($floor_sf), "(19, 1);")]
+            /// assert_eq!(ROUNDED, 10);
+            /// ```
+            #[inline]
+            pub const fn $floor_sf(number: $t, sig_figs: u32) -> $t {
+                $sf_mode(number, sig_figs, RoundingMode::Down)
+            }
+
+            #[doc = concat!("Rounds a `", stringify!($t), "` to the given number of significant figures using the given rounding mode, usable in const contexts")]
+            ///
+            /// Gives the same result as [`round_sf_mode`](super::round_sf_mode), saturating when
+            /// the result doesn't fit in the type
+            ///
+            /// # Examples
+            ///
+            /// ```
+            #[doc = concat!("use common_math::rounding::{", stringify!($sf_mode), ", RoundingMode};")]
+            ///
+            #[doc = concat!("const ROUNDED: ", stringify!($t), " = ", stringify!($sf_mode), "(25, 1, RoundingMode::HalfEven);")]
+            /// assert_eq!(ROUNDED, 20);
+            /// ```
+            #[inline]
+            pub const fn $sf_mode(number: $t, sig_figs: u32, mode: RoundingMode) -> $t {
+                let (_, magnitude) = impl_const_rounding!(@split $sign number);
+                let digits = match magnitude.checked_ilog10() {
+                    Some(digits) => digits + 1,
+                    None => 1,
+                };
+                if sig_figs >= digits {
+                    return number;
+                }
+                $zeros_mode(number, digits - sig_figs, mode)
+            }
+        )*
+    };
+}
+
+impl_const_rounding! {
+    signed i8 {
+        round_zeros_const_i8, ceil_zeros_const_i8, floor_zeros_const_i8, round_zeros_mode_const_i8,
+        round_sf_const_i8, ceil_sf_const_i8, floor_sf_const_i8, round_sf_mode_const_i8,
+    }
+    signed i16 {
+        round_zeros_const_i16, ceil_zeros_const_i16, floor_zeros_const_i16, round_zeros_mode_const_i16,
+        round_sf_const_i16, ceil_sf_const_i16, floor_sf_const_i16, round_sf_mode_const_i16,
+    }
+    signed i32 {
+        round_zeros_const_i32, ceil_zeros_const_i32, floor_zeros_const_i32, round_zeros_mode_const_i32,
+        round_sf_const_i32, ceil_sf_const_i32, floor_sf_const_i32, round_sf_mode_const_i32,
+    }
+    signed i64 {
+        round_zeros_const_i64, ceil_zeros_const_i64, floor_zeros_const_i64, round_zeros_mode_const_i64,
+        round_sf_const_i64, ceil_sf_const_i64, floor_sf_const_i64, round_sf_mode_const_i64,
+    }
+    signed i128 {
+        round_zeros_const_i128, ceil_zeros_const_i128, floor_zeros_const_i128, round_zeros_mode_const_i128,
+        round_sf_const_i128, ceil_sf_const_i128, floor_sf_const_i128, round_sf_mode_const_i128,
+    }
+    unsigned u8 {
+        round_zeros_const_u8, ceil_zeros_const_u8, floor_zeros_const_u8, round_zeros_mode_const_u8,
+        round_sf_const_u8, ceil_sf_const_u8, floor_sf_const_u8, round_sf_mode_const_u8,
+    }
+    unsigned u16 {
+        round_zeros_const_u16, ceil_zeros_const_u16, floor_zeros_const_u16, round_zeros_mode_const_u16,
+        round_sf_const_u16, ceil_sf_const_u16, floor_sf_const_u16, round_sf_mode_const_u16,
+    }
+    unsigned u32 {
+        round_zeros_const_u32, ceil_zeros_const_u32, floor_zeros_const_u32, round_zeros_mode_const_u32,
+        round_sf_const_u32, ceil_sf_const_u32, floor_sf_const_u32, round_sf_mode_const_u32,
+    }
+    unsigned u64 {
+        round_zeros_const_u64, ceil_zeros_const_u64, floor_zeros_const_u64, round_zeros_mode_const_u64,
+        round_sf_const_u64, ceil_sf_const_u64, floor_sf_const_u64, round_sf_mode_const_u64,
+    }
+    unsigned u128 {
+        round_zeros_const_u128, ceil_zeros_const_u128, floor_zeros_const_u128, round_zeros_mode_const_u128,
+        round_sf_const_u128, ceil_sf_const_u128, floor_sf_const_u128, round_sf_mode_const_u128,
+    }
+}
+
+mod tests;
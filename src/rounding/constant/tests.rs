@@ -0,0 +1,129 @@
+#[cfg(test)]
+use super::*;
+#[cfg(test)]
+use crate::rounding::Roundable;
+
+#[cfg(test)]
+const MODES: [RoundingMode; 10] = [
+    RoundingMode::HalfUp,
+    RoundingMode::HalfDown,
+    RoundingMode::HalfEven,
+    RoundingMode::HalfOdd,
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::HalfTowardZero,
+    RoundingMode::Up,
+    RoundingMode::Down,
+    RoundingMode::TowardZero,
+    RoundingMode::AwayFromZero,
+];
+
+#[test]
+fn test_const_evaluation() {
+    const ZEROS: i32 = round_zeros_const_i32(-1_250, 2);
+    const CEIL_ZEROS: u16 = ceil_zeros_const_u16(1_201, 2);
+    const FLOOR_ZEROS: i64 = floor_zeros_const_i64(-1_201, 2);
+    const EVEN_ZEROS: u32 = round_zeros_mode_const_u32(1_250, 2, RoundingMode::HalfEven);
+    const SF: i8 = round_sf_const_i8(-125, 2);
+    const CEIL_SF: u64 = ceil_sf_const_u64(12_345, 1);
+    const FLOOR_SF: i16 = floor_sf_const_i16(-12_345, 3);
+    const ODD_SF: u8 = round_sf_mode_const_u8(125, 2, RoundingMode::HalfOdd);
+    const WIDE: u128 = ceil_sf_const_u128(u128::MAX, 3);
+
+    assert_eq!(ZEROS, -1_300);
+    assert_eq!(CEIL_ZEROS, 1_300);
+    assert_eq!(FLOOR_ZEROS, -1_300);
+    assert_eq!(EVEN_ZEROS, 1_200);
+    assert_eq!(SF, -127 - 1);
+    assert_eq!(CEIL_SF, 20_000);
+    assert_eq!(FLOOR_SF, -12_400);
+    assert_eq!(ODD_SF, 130);
+    assert_eq!(WIDE, u128::MAX);
+}
+
+#[test]
+fn test_saturation() {
+    assert_eq!(round_zeros_const_u8(250, 1), 250);
+    assert_eq!(round_zeros_const_u8(255, 1), u8::MAX);
+    assert_eq!(round_zeros_const_i8(-125, 1), i8::MIN);
+    assert_eq!(round_zeros_const_i8(i8::MIN, 0), i8::MIN);
+    assert_eq!(floor_zeros_const_i8(-1, 50), i8::MIN);
+    assert_eq!(ceil_zeros_const_u64(1, 50), u64::MAX);
+    assert_eq!(floor_zeros_const_u64(u64::MAX, 50), 0);
+    assert_eq!(round_sf_const_i128(i128::MIN, 1), i128::MIN);
+    assert_eq!(
+        round_sf_const_u128(340_282_366_920_938_463_463_374_607_431_768_211_455, 2),
+        340_000_000_000_000_000_000_000_000_000_000_000_000
+    );
+    assert_eq!(round_sf_const_u32(0, 1), 0);
+    assert_eq!(ceil_zeros_const_i32(0, 50), 0);
+}
+
+#[test]
+fn test_matches_roundable() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let places = (state % 22) as u32;
+
+        for mode in MODES {
+            assert_eq!(
+                round_zeros_mode_const_i8(state as i8, places, mode),
+                (state as i8).round_zeros_mode(places, mode)
+            );
+            assert_eq!(
+                round_sf_mode_const_i16(state as i16, places, mode),
+                (state as i16).round_sf_mode(places, mode)
+            );
+            assert_eq!(
+                round_zeros_mode_const_i32(state as i32, places, mode),
+                (state as i32).round_zeros_mode(places, mode)
+            );
+            assert_eq!(
+                round_sf_mode_const_i64(state as i64, places, mode),
+                (state as i64).round_sf_mode(places, mode)
+            );
+            assert_eq!(
+                round_sf_mode_const_u8(state as u8, places, mode),
+                (state as u8).round_sf_mode(places, mode)
+            );
+            assert_eq!(
+                round_zeros_mode_const_u16(state as u16, places, mode),
+                (state as u16).round_zeros_mode(places, mode)
+            );
+            assert_eq!(
+                round_sf_mode_const_u32(state as u32, places, mode),
+                (state as u32).round_sf_mode(places, mode)
+            );
+            assert_eq!(
+                round_zeros_mode_const_u64(state, places, mode),
+                state.round_zeros_mode(places, mode)
+            );
+        }
+        assert_eq!(
+            round_zeros_const_i64(state as i64, places),
+            (state as i64).round_zeros(places)
+        );
+        assert_eq!(
+            ceil_zeros_const_i64(state as i64, places),
+            (state as i64).ceil_zeros(places)
+        );
+        assert_eq!(
+            floor_zeros_const_i64(state as i64, places),
+            (state as i64).floor_zeros(places)
+        );
+        assert_eq!(
+            round_sf_const_i32(state as i32, places),
+            (state as i32).round_sf(places)
+        );
+        assert_eq!(
+            ceil_sf_const_i32(state as i32, places),
+            (state as i32).ceil_sf(places)
+        );
+        assert_eq!(
+            floor_sf_const_i32(state as i32, places),
+            (state as i32).floor_sf(places)
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::fixed::round_to_units;
+use super::{digits, multiple, RoundingMode};
+
+/// Rounds an amount of money to the minor units of its currency using the given rounding mode,
+/// such as 2 for cents or 0 for yen
+///
+/// Accounting usually rounds ties to even with [`RoundingMode::HalfEven`] while retail prices
+/// usually round them up with [`RoundingMode::HalfUp`]. The amount is rounded from its shortest
+/// decimal representation, so `1.005` is a tie even though the float is just below it.
+/// NaN and infinities are returned unchanged
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_currency, RoundingMode};
+///
+/// assert_eq!(round_currency(2.665, 2, RoundingMode::HalfUp), 2.67);
+/// assert_eq!(round_currency(2.665, 2, RoundingMode::HalfEven), 2.66);
+/// assert_eq!(round_currency(1.005, 2, RoundingMode::HalfUp), 1.01);
+/// assert_eq!(round_currency(1_234.5, 0, RoundingMode::HalfEven), 1_234.0);
+/// assert_eq!(round_currency(0.1235, 3, RoundingMode::Down), 0.123);
+/// ```
+#[inline]
+pub fn round_currency(amount: f64, minor_units: u32, mode: RoundingMode) -> f64 {
+    round_currency_step(amount, minor_units, 1, mode)
+}
+
+/// Rounds an amount of money to a multiple of `step` minor units using the given rounding mode,
+/// such as the cash rounding to 5 cents used in Switzerland and New Zealand
+///
+/// Works like [`round_currency`] with a step of more than one minor unit, and a step of 1 gives
+/// the same result as it. NaN and infinities are returned unchanged
+///
+/// # Panics
+///
+/// Panics if `step` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_currency_step, RoundingMode};
+///
+/// assert_eq!(round_currency_step(3.27, 2, 5, RoundingMode::HalfUp), 3.25);
+/// assert_eq!(round_currency_step(3.275, 2, 5, RoundingMode::HalfUp), 3.3);
+/// assert_eq!(round_currency_step(3.21, 2, 5, RoundingMode::Up), 3.25);
+/// assert_eq!(round_currency_step(1_240.0, 0, 50, RoundingMode::HalfEven), 1_250.0);
+/// ```
+#[inline]
+pub fn round_currency_step(amount: f64, minor_units: u32, step: u64, mode: RoundingMode) -> f64 {
+    assert!(step != 0, "step must not be zero");
+    if !amount.is_finite() {
+        return amount;
+    }
+
+    match round_to_units(amount, minor_units, step as u128, mode) {
+        Some(units) => digits::scale_down(units, minor_units),
+        // An amount too large to count in minor units can still be rounded as a float
+        None => multiple::multiple_f64(amount, digits::scale_down(step, minor_units), mode),
+    }
+}
+
+/// Converts an amount of money to a whole number of minor units, such as cents, rounding it with
+/// the given mode
+///
+/// The amount is rounded from its shortest decimal representation and the count is exact, with
+/// no float error. Returns `None` if the amount is NaN, infinite or the count doesn't fit in
+/// an `i64`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{to_minor_units, RoundingMode};
+///
+/// assert_eq!(to_minor_units(19.99, 2, RoundingMode::HalfEven), Some(1_999));
+/// assert_eq!(to_minor_units(-2.675, 2, RoundingMode::HalfEven), Some(-268));
+/// assert_eq!(to_minor_units(1.2345, 3, RoundingMode::TowardZero), Some(1_234));
+/// assert_eq!(to_minor_units(1e17, 2, RoundingMode::HalfEven), None);
+/// assert_eq!(to_minor_units(f64::NAN, 2, RoundingMode::HalfEven), None);
+/// ```
+#[inline]
+pub fn to_minor_units(amount: f64, minor_units: u32, mode: RoundingMode) -> Option<i64> {
+    to_minor_units_step(amount, minor_units, 1, mode)
+}
+
+/// Converts an amount of money to a whole number of minor units that is a multiple of `step`,
+/// rounding it with the given mode, such as cash rounding to 5 cents
+///
+/// Returns `None` if the amount is NaN, infinite or the count doesn't fit in an `i64`
+///
+/// # Panics
+///
+/// Panics if `step` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{to_minor_units_step, RoundingMode};
+///
+/// assert_eq!(to_minor_units_step(3.27, 2, 5, RoundingMode::HalfUp), Some(325));
+/// assert_eq!(to_minor_units_step(-3.275, 2, 5, RoundingMode::HalfUp), Some(-325));
+/// assert_eq!(to_minor_units_step(f64::INFINITY, 2, 5, RoundingMode::HalfUp), None);
+/// ```
+#[inline]
+pub fn to_minor_units_step(
+    amount: f64,
+    minor_units: u32,
+    step: u64,
+    mode: RoundingMode,
+) -> Option<i64> {
+    assert!(step != 0, "step must not be zero");
+    round_to_units(amount, minor_units, step as u128, mode).and_then(|units| units.try_into().ok())
+}
+
+mod tests;
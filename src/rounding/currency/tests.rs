@@ -0,0 +1,77 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_ties() {
+    assert_eq!(round_currency(2.675, 2, RoundingMode::HalfUp), 2.68);
+    assert_eq!(round_currency(2.675, 2, RoundingMode::HalfEven), 2.68);
+    assert_eq!(round_currency(-2.675, 2, RoundingMode::HalfUp), -2.67);
+    assert_eq!(round_currency(-2.675, 2, RoundingMode::HalfEven), -2.68);
+    assert_eq!(round_currency(2.685, 2, RoundingMode::HalfUp), 2.69);
+    assert_eq!(round_currency(2.685, 2, RoundingMode::HalfEven), 2.68);
+    assert_eq!(to_minor_units(2.675, 2, RoundingMode::HalfUp), Some(268));
+    assert_eq!(to_minor_units(2.685, 2, RoundingMode::HalfEven), Some(268));
+
+    // Bahraini dinar has 3 minor units
+    assert_eq!(round_currency(1.2345, 3, RoundingMode::HalfEven), 1.234);
+    assert_eq!(
+        to_minor_units(1.2355, 3, RoundingMode::HalfEven),
+        Some(1_236)
+    );
+}
+
+#[test]
+fn test_no_minor_units() {
+    // Yen has no minor units
+    assert_eq!(round_currency(1_234.5, 0, RoundingMode::HalfUp), 1_235.0);
+    assert_eq!(round_currency(1_234.5, 0, RoundingMode::HalfEven), 1_234.0);
+    assert_eq!(round_currency(-0.4, 0, RoundingMode::HalfEven), 0.0);
+    assert_eq!(to_minor_units(98_765.4, 0, RoundingMode::Up), Some(98_766));
+    assert_eq!(
+        to_minor_units(-98_765.4, 0, RoundingMode::Up),
+        Some(-98_765)
+    );
+}
+
+#[test]
+fn test_cash_rounding() {
+    let mode = RoundingMode::HalfUp;
+    let cases = [
+        (1.01, 1.0),
+        (1.02, 1.0),
+        (1.025, 1.05),
+        (1.03, 1.05),
+        (1.07, 1.05),
+        (1.075, 1.1),
+        (1.08, 1.1),
+    ];
+    for (amount, rounded) in cases {
+        assert_eq!(round_currency_step(amount, 2, 5, mode), rounded);
+        assert_eq!(
+            to_minor_units_step(amount, 2, 5, mode),
+            Some((rounded * 100.0).round() as i64)
+        );
+    }
+    assert!(round_currency_step(f64::NAN, 2, 5, mode).is_nan());
+    assert_eq!(round_currency_step(1e300, 2, 5, mode), 1e300);
+    assert_eq!(
+        round_currency_step(0.123, 2, 1, mode),
+        round_currency(0.123, 2, mode)
+    );
+}
+
+#[test]
+fn test_out_of_range() {
+    let mode = RoundingMode::HalfEven;
+    assert_eq!(to_minor_units(1e19, 0, mode), None);
+    assert_eq!(
+        to_minor_units(9e16, 2, mode),
+        Some(9_000_000_000_000_000_000)
+    );
+    assert_eq!(to_minor_units(9.3e16, 2, mode), None);
+    assert_eq!(to_minor_units(-1e30, 2, mode), None);
+    assert_eq!(to_minor_units(f64::NEG_INFINITY, 2, mode), None);
+    assert_eq!(to_minor_units(1.0, 40, mode), None);
+    assert_eq!(to_minor_units(1e-30, 2, mode), Some(0));
+    assert_eq!(to_minor_units(1e-30, 2, RoundingMode::Up), Some(1));
+}
@@ -0,0 +1,748 @@
+//! [`Float`] and [`Roundable`] for [`rust_decimal::Decimal`]
+//!
+//! A decimal is rounded exactly on its mantissa and scale, so `2.675` to 2 decimal places is
+//! `2.68` where an f64 gives `2.67`. Like the integers, results that don't fit saturate at
+//! [`Decimal::MAX`] and [`Decimal::MIN`] and the `checked_*` and `try_*` methods report them.
+//! Results that round to zero are positive, as a decimal zero has no meaningful sign.
+//!
+//! The bit methods round through an f64, as positions in binary have no exact decimal
+//! counterpart, and the stochastic methods compare their random number with an f64 fraction
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+#[cfg(feature = "alloc")]
+use super::{format, Notation};
+use super::{
+    fallback, integer, powers, round_position, Digits, Float, Roundable, RoundingError,
+    RoundingMode,
+};
+
+/// Rounds a decimal to a number of decimal places, or to zeros when they are negative,
+/// `None` if the result doesn't fit in a decimal
+#[inline]
+fn round_at(number: Decimal, decimal_places: i64, mode: RoundingMode) -> Option<Decimal> {
+    let scale = number.scale() as i64;
+    if decimal_places >= scale {
+        return Some(number);
+    }
+
+    let negative = number.is_sign_negative();
+    let dropped = u32::try_from(scale - decimal_places).unwrap_or(u32::MAX);
+    let rounded =
+        integer::round_magnitude(number.mantissa().unsigned_abs(), negative, dropped, mode)?;
+    if rounded == 0 {
+        return Some(Decimal::ZERO);
+    }
+
+    // A non zero multiple of the power fits in a u128, so the power does too
+    let steps = rounded / powers::ten_u128(dropped).expect("power below a u128 fits in one");
+    let (magnitude, scale) = match u32::try_from(decimal_places) {
+        Ok(decimal_places) => (steps, decimal_places),
+        Err(_) => {
+            let zeros = powers::ten_u128(decimal_places.unsigned_abs() as u32)?;
+            (steps.checked_mul(zeros)?, 0)
+        }
+    };
+
+    let mut rounded =
+        Decimal::try_from_i128_with_scale(i128::try_from(magnitude).ok()?, scale).ok()?;
+    rounded.set_sign_negative(negative);
+    Some(rounded)
+}
+
+/// Rounds a decimal to the given number of significant figures of its mantissa,
+/// `None` if the result doesn't fit in a decimal
+#[inline]
+fn round_sf_at(number: Decimal, sig_figs: u32, mode: RoundingMode) -> Option<Decimal> {
+    let digits = mantissa_digits(number);
+    if number.is_zero() || sig_figs >= digits {
+        return Some(number);
+    }
+
+    round_at(
+        number,
+        number.scale() as i64 - (digits - sig_figs) as i64,
+        mode,
+    )
+}
+
+/// Rounds a decimal to the given number of decimal places, which always fits
+#[inline]
+fn round_dp_at(number: Decimal, decimal_places: u32, mode: RoundingMode) -> Decimal {
+    round_at(number, decimal_places as i64, mode)
+        .expect("rounding to decimal places fits in a decimal")
+}
+
+/// Unwraps a rounded decimal, saturating at the bound on the same side of zero as the number
+#[inline]
+fn saturate(number: Decimal, rounded: Option<Decimal>) -> Decimal {
+    match rounded {
+        Some(rounded) => rounded,
+        None if number.is_sign_negative() => Decimal::MIN,
+        None => Decimal::MAX,
+    }
+}
+
+/// Counts the digits of the mantissa, zero has a single digit
+#[inline]
+fn mantissa_digits(number: Decimal) -> u32 {
+    let magnitude = number.mantissa().unsigned_abs();
+    magnitude.checked_ilog10().map_or(1, |digits| digits + 1)
+}
+
+/// The shortest digits of a decimal and the number of them after the decimal point, which is
+/// negative when the digits are followed by zeros before the decimal point
+#[inline]
+fn shortest_decimal(number: Decimal) -> (u128, i32) {
+    let mut significand = number.mantissa().unsigned_abs();
+    let mut fraction_digits = number.scale() as i32;
+    while significand != 0 && significand.is_multiple_of(10) {
+        significand /= 10;
+        fraction_digits -= 1;
+    }
+    (significand, fraction_digits)
+}
+
+/// Rounds a decimal to a multiple of a positive step, `None` if the result doesn't fit
+#[inline]
+fn round_multiple(number: Decimal, step: Decimal, mode: RoundingMode) -> Option<Decimal> {
+    let remainder = number.checked_rem(step)?;
+    let truncated = number - remainder;
+    if remainder.is_zero() {
+        return Some(truncated);
+    }
+
+    let discarded = remainder.abs();
+    // A step too large to double has at most one multiple on each side of zero
+    let odd = match step.checked_mul(Decimal::TWO) {
+        Some(double) => !truncated.checked_rem(double)?.is_zero(),
+        None => !truncated.is_zero(),
+    };
+    let negative = number.is_sign_negative();
+    let away = mode.rounds_away(negative, odd, false, discarded.cmp(&(step - discarded)));
+
+    match (away, negative) {
+        (false, _) => Some(truncated),
+        (true, false) => truncated.checked_add(step),
+        (true, true) => truncated.checked_sub(step),
+    }
+}
+
+/// Rounds a decimal to a point on a grid of a positive step shifted by an offset,
+/// `None` if the result doesn't fit
+#[inline]
+fn quantize_at(
+    number: Decimal,
+    step: Decimal,
+    offset: Decimal,
+    mode: RoundingMode,
+) -> Option<Decimal> {
+    let shifted = number.checked_sub(offset)?;
+    offset.checked_add(round_multiple(shifted, step, mode)?)
+}
+
+/// Unwraps a decimal rounded to a grid, saturating at the bound on the side of the offset
+/// the number is on
+#[inline]
+fn saturate_quantize(number: Decimal, offset: Decimal, rounded: Option<Decimal>) -> Decimal {
+    match rounded {
+        Some(rounded) => rounded,
+        None if number < offset => Decimal::MIN,
+        None => Decimal::MAX,
+    }
+}
+
+/// Rounds the magnitude of a non zero decimal to a power of ten keeping its sign,
+/// `None` if the result doesn't fit
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+#[inline]
+fn power_of_ten(number: Decimal, mode: RoundingMode) -> Option<Decimal> {
+    let order = number.get_digits() - 1;
+    let below = match u32::try_from(order) {
+        Ok(order) => Decimal::from_i128_with_scale(powers::ten_u128(order)? as i128, 0),
+        Err(_) => Decimal::from_i128_with_scale(1, order.unsigned_abs()),
+    };
+
+    let magnitude = number.abs();
+    let above = match mode {
+        _ if magnitude == below => false,
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::HalfAwayFromZero => below
+            .checked_mul(Decimal::new(55, 1))
+            .is_some_and(|half| magnitude >= half),
+        _ => false,
+    };
+
+    let mut rounded = if above {
+        below.checked_mul(Decimal::TEN)?
+    } else {
+        below
+    };
+    rounded.set_sign_negative(number.is_sign_negative());
+    Some(rounded)
+}
+
+/// Rounds a decimal to a multiple of `1 / denominator`, the integer part is kept exactly and the
+/// fraction is the closest decimal to the rounded count of fractions
+#[inline]
+fn fraction(number: Decimal, denominator: u32, mode: RoundingMode) -> Decimal {
+    let whole = number.trunc();
+    let part = number - whole;
+    let negative = number.is_sign_negative();
+
+    // The fraction has at most 28 digits, so its product with a u32 fits in a u128
+    let product = part.mantissa().unsigned_abs() * denominator as u128;
+    let scale = part.scale();
+    let count = integer::round_magnitude(product, negative, scale, mode)
+        .expect("rounded count of fractions fits in a u128")
+        / powers::ten_u128(scale).expect("power below a u128 fits in one");
+
+    let mut fraction = Decimal::from(count) / Decimal::from(denominator);
+    fraction.set_sign_negative(negative);
+    whole + fraction
+}
+
+/// Rounds a decimal to a number of significant bits through an f64
+#[inline]
+fn round_bits(number: Decimal, round: impl FnOnce(f64) -> f64) -> Decimal {
+    let Some(float) = number.to_f64() else {
+        return number;
+    };
+    Decimal::from_f64_retain(round(float)).unwrap_or(number)
+}
+
+/// Rounds a decimal up or down at random with a probability of going up equal to its distance
+/// from the step below
+#[inline]
+fn stochastic<R: FnMut() -> f64>(
+    number: Decimal,
+    below: Decimal,
+    above: Decimal,
+    mut random: R,
+) -> Decimal {
+    if below == above {
+        return number;
+    }
+
+    let fraction = ((number - below) / (above - below)).to_f64().unwrap_or(0.0);
+    if random() < fraction {
+        above
+    } else {
+        below
+    }
+}
+
+impl Float for Decimal {
+    #[inline]
+    fn trunc_dp(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    fn away_dp(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::AwayFromZero)
+    }
+
+    #[inline]
+    fn round_dp_mode(self, decimal_places: u32, mode: RoundingMode) -> Decimal {
+        round_dp_at(self, decimal_places, mode)
+    }
+
+    #[inline]
+    fn round_dp_half_odd(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::HalfOdd)
+    }
+
+    #[inline]
+    fn round_dp_half_away(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn round_dp_half_down(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::HalfDown)
+    }
+
+    #[inline]
+    fn round_dp_exact(self, decimal_places: u32) -> Decimal {
+        self.round_dp(decimal_places)
+    }
+
+    #[inline]
+    fn ceil_dp_exact(self, decimal_places: u32) -> Decimal {
+        self.ceil_dp(decimal_places)
+    }
+
+    #[inline]
+    fn floor_dp_exact(self, decimal_places: u32) -> Decimal {
+        self.floor_dp(decimal_places)
+    }
+
+    #[inline]
+    fn round_dp_even(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn round_to_match(self, reference: Decimal) -> Decimal {
+        if reference.is_zero() {
+            return self;
+        }
+
+        let (_, decimal_places) = shortest_decimal(reference);
+        round_position(self, decimal_places)
+    }
+
+    #[inline]
+    fn round_pair_sf(self, uncertainty: Decimal, sig_figs: u32) -> (Decimal, Decimal) {
+        let uncertainty = uncertainty.round_sf(sig_figs);
+        if uncertainty.is_zero() {
+            return (self, uncertainty);
+        }
+
+        // Counted from the significant figures so trailing zeros of the uncertainty are kept
+        let sig_figs = sig_figs.min(i32::MAX as u32) as i32;
+        let decimal_places = sig_figs.saturating_sub(uncertainty.get_digits());
+        (round_position(self, decimal_places), uncertainty)
+    }
+
+    #[inline]
+    fn round_dp_stochastic<R: FnMut() -> f64>(self, decimal_places: u32, random: R) -> Decimal {
+        stochastic(
+            self,
+            self.floor_dp(decimal_places),
+            self.ceil_dp(decimal_places),
+            random,
+        )
+    }
+
+    #[inline]
+    fn round_frac(self, denominator: u32) -> Decimal {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        fraction(self, denominator, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_frac(self, denominator: u32) -> Decimal {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        fraction(self, denominator, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_frac(self, denominator: u32) -> Decimal {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        fraction(self, denominator, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn try_round_frac(self, denominator: u32) -> Result<Decimal, RoundingError> {
+        if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.round_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_ceil_frac(self, denominator: u32) -> Result<Decimal, RoundingError> {
+        if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.ceil_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_floor_frac(self, denominator: u32) -> Result<Decimal, RoundingError> {
+        if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.floor_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn round_bits(self, bits: u32) -> Decimal {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        round_bits(self, |float| float.round_bits(bits))
+    }
+
+    #[inline]
+    fn trunc_bits(self, bits: u32) -> Decimal {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        round_bits(self, |float| float.trunc_bits(bits))
+    }
+
+    #[inline]
+    fn ceil_bits(self, bits: u32) -> Decimal {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        round_bits(self, |float| float.ceil_bits(bits))
+    }
+
+    #[inline]
+    fn count_decimal_places(self) -> u32 {
+        let (_, fraction_digits) = shortest_decimal(self);
+        fraction_digits.max(0) as u32
+    }
+
+    #[inline]
+    fn needs_rounding(self, decimal_places: u32) -> bool {
+        self.count_decimal_places() > decimal_places
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_dp(self, decimal_places: u32) -> String {
+        let rounded = self.round_dp(decimal_places);
+        let (significand, fraction_digits) = shortest_decimal(rounded);
+        format::fixed_decimal(
+            significand,
+            fraction_digits,
+            rounded.is_sign_negative(),
+            decimal_places,
+        )
+    }
+
+    #[inline]
+    fn to_scientific(self, sig_figs: u32) -> (Decimal, i32) {
+        let rounded = self.round_sf(sig_figs);
+        if rounded.is_zero() {
+            return (rounded, 0);
+        }
+
+        let (significand, fraction_digits) = shortest_decimal(rounded);
+        let leading = significand.ilog10();
+        let mut mantissa = Decimal::from_i128_with_scale(significand as i128, leading);
+        mantissa.set_sign_negative(rounded.is_sign_negative());
+        (mantissa, leading as i32 - fraction_digits)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_mode(values: &mut [Decimal], decimal_places: u32, mode: RoundingMode) {
+        for value in values {
+            *value = value.round_dp_mode(decimal_places, mode);
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_simd(values: &mut [Decimal], decimal_places: u32) {
+        Decimal::round_slice_dp_mode(values, decimal_places, RoundingMode::HalfAwayFromZero);
+    }
+}
+
+impl Roundable for Decimal {
+    #[inline]
+    fn round_decimals(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_decimals(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_decimals(self, decimal_places: u32) -> Decimal {
+        round_dp_at(self, decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> Decimal {
+        saturate(self, round_at(self, -(zeros as i64), mode))
+    }
+
+    #[inline]
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Decimal {
+        saturate(self, round_sf_at(self, sig_figs, mode))
+    }
+
+    #[inline]
+    fn checked_round_sf(self, sig_figs: u32) -> Option<Decimal> {
+        round_sf_at(self, sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn checked_ceil_sf(self, sig_figs: u32) -> Option<Decimal> {
+        round_sf_at(self, sig_figs, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn checked_floor_sf(self, sig_figs: u32) -> Option<Decimal> {
+        round_sf_at(self, sig_figs, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, random: R) -> Decimal {
+        stochastic(
+            self,
+            self.floor_zeros(zeros),
+            self.ceil_zeros(zeros),
+            random,
+        )
+    }
+
+    #[inline]
+    fn round_to_multiple(self, step: Decimal) -> Decimal {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        saturate(
+            self,
+            round_multiple(self, step, RoundingMode::HalfAwayFromZero),
+        )
+    }
+
+    #[inline]
+    fn ceil_to_multiple(self, step: Decimal) -> Decimal {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        saturate(self, round_multiple(self, step, RoundingMode::Up))
+    }
+
+    #[inline]
+    fn floor_to_multiple(self, step: Decimal) -> Decimal {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        saturate(self, round_multiple(self, step, RoundingMode::Down))
+    }
+
+    #[inline]
+    fn try_round_to_multiple(self, step: Decimal) -> Result<Decimal, RoundingError> {
+        if step <= Decimal::ZERO {
+            Err(RoundingError::InvalidStep)
+        } else {
+            round_multiple(self, step, RoundingMode::HalfAwayFromZero)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    #[inline]
+    fn try_ceil_to_multiple(self, step: Decimal) -> Result<Decimal, RoundingError> {
+        if step <= Decimal::ZERO {
+            Err(RoundingError::InvalidStep)
+        } else {
+            round_multiple(self, step, RoundingMode::Up).ok_or(RoundingError::Overflow)
+        }
+    }
+
+    #[inline]
+    fn try_floor_to_multiple(self, step: Decimal) -> Result<Decimal, RoundingError> {
+        if step <= Decimal::ZERO {
+            Err(RoundingError::InvalidStep)
+        } else {
+            round_multiple(self, step, RoundingMode::Down).ok_or(RoundingError::Overflow)
+        }
+    }
+
+    #[inline]
+    fn quantize(self, step: Decimal, offset: Decimal) -> Decimal {
+        let rounded = self.checked_quantize(step, offset);
+        saturate_quantize(self, offset, rounded)
+    }
+
+    #[inline]
+    fn ceil_quantize(self, step: Decimal, offset: Decimal) -> Decimal {
+        let rounded = self.checked_ceil_quantize(step, offset);
+        saturate_quantize(self, offset, rounded)
+    }
+
+    #[inline]
+    fn floor_quantize(self, step: Decimal, offset: Decimal) -> Decimal {
+        let rounded = self.checked_floor_quantize(step, offset);
+        saturate_quantize(self, offset, rounded)
+    }
+
+    #[inline]
+    fn checked_quantize(self, step: Decimal, offset: Decimal) -> Option<Decimal> {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        quantize_at(self, step, offset, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn checked_ceil_quantize(self, step: Decimal, offset: Decimal) -> Option<Decimal> {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        quantize_at(self, step, offset, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn checked_floor_quantize(self, step: Decimal, offset: Decimal) -> Option<Decimal> {
+        assert!(step > Decimal::ZERO, "step must be positive");
+        quantize_at(self, step, offset, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_pow10(self) -> Decimal {
+        if self.is_zero() {
+            return self;
+        }
+
+        saturate(self, power_of_ten(self, RoundingMode::HalfAwayFromZero))
+    }
+
+    #[inline]
+    fn ceil_pow10(self) -> Decimal {
+        if self.is_zero() {
+            return self;
+        }
+
+        saturate(self, power_of_ten(self, RoundingMode::AwayFromZero))
+    }
+
+    #[inline]
+    fn floor_pow10(self) -> Decimal {
+        if self.is_zero() {
+            return self;
+        }
+
+        saturate(self, power_of_ten(self, RoundingMode::TowardZero))
+    }
+
+    #[inline]
+    fn checked_round_pow10(self) -> Option<Decimal> {
+        (!self.is_zero()).then(|| power_of_ten(self, RoundingMode::HalfAwayFromZero))?
+    }
+
+    #[inline]
+    fn checked_ceil_pow10(self) -> Option<Decimal> {
+        (!self.is_zero()).then(|| power_of_ten(self, RoundingMode::AwayFromZero))?
+    }
+
+    #[inline]
+    fn checked_floor_pow10(self) -> Option<Decimal> {
+        (!self.is_zero()).then(|| power_of_ten(self, RoundingMode::TowardZero))?
+    }
+
+    #[inline]
+    fn order_of_magnitude(self) -> Option<i32> {
+        (!self.is_zero()).then(|| self.get_digits() - 1)
+    }
+
+    #[inline]
+    fn count_sig_figs(self) -> u32 {
+        let (significand, _) = shortest_decimal(self);
+        significand.checked_ilog10().map_or(0, |digits| digits + 1)
+    }
+
+    #[inline]
+    fn digits(self) -> Digits {
+        let power = powers::ten_u128(self.scale()).expect("power below a u128 fits in one");
+        Digits::integer(self.mantissa().unsigned_abs() / power)
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_sf(self, sig_figs: u32) -> String {
+        self.format_sf_notation(sig_figs, Notation::Fixed)
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+        let rounded = self.round_sf(sig_figs);
+        let (significand, fraction_digits) = shortest_decimal(rounded);
+        format::significant(
+            significand,
+            fraction_digits,
+            rounded.is_sign_negative(),
+            sig_figs,
+            notation,
+        )
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_grouped(
+        self,
+        decimal_places: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String {
+        self.format_locale(decimal_places, decimal_separator, Some(group_separator))
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_locale(
+        self,
+        decimal_places: u32,
+        decimal_separator: char,
+        group_separator: Option<char>,
+    ) -> String {
+        format::group(
+            &self.format_dp(decimal_places),
+            decimal_separator,
+            group_separator,
+        )
+    }
+
+    #[inline]
+    fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<Decimal> {
+        round_at(self, -(exponent as i64), mode)
+    }
+
+    #[inline]
+    fn get_digits(&self) -> i32 {
+        if self.is_zero() {
+            return 0;
+        }
+
+        mantissa_digits(*self) as i32 - self.scale() as i32
+    }
+
+    #[inline]
+    fn to_decimal(&self) -> Option<(i128, i32)> {
+        Some((self.mantissa(), -(self.scale() as i32)))
+    }
+
+    #[inline]
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<Decimal> {
+        // A decimal holds at most 28 digits after the point, so trailing zeros past them are dropped
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+        while exponent < -28 && mantissa % 10 == 0 && mantissa != 0 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+
+        match u32::try_from(exponent) {
+            Ok(_) => fallback::integer_value(mantissa, exponent)
+                .and_then(|value| Decimal::try_from_i128_with_scale(value, 0).ok()),
+            Err(_) if mantissa == 0 => Some(Decimal::ZERO),
+            Err(_) => Decimal::try_from_i128_with_scale(mantissa, exponent.unsigned_abs()).ok(),
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn nearer_above(self, below: Decimal, above: Decimal) -> bool {
+        above - self < self - below
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn tie_at(self, decimal_places: i32) -> bool {
+        let dropped = self.scale() as i64 - decimal_places as i64;
+        let Some(power) = u32::try_from(dropped).ok().and_then(powers::ten_u128) else {
+            return false;
+        };
+        dropped > 0 && self.mantissa().unsigned_abs() % power == power / 2
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn rounding_error(self, rounded: Decimal) -> Decimal {
+        self - rounded
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_zeros_mode(values: &mut [Decimal], zeros: u32, mode: RoundingMode) {
+        for value in values {
+            *value = value.round_zeros_mode(zeros, mode);
+        }
+    }
+}
+
+mod tests;
@@ -0,0 +1,213 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use core::str::FromStr;
+
+#[cfg(test)]
+fn decimal(text: &str) -> Decimal {
+    Decimal::from_str(text).unwrap()
+}
+
+#[test]
+fn test_decimal_places() {
+    assert_eq!(decimal("2.675").round_dp(2), decimal("2.68"));
+    assert_eq!(decimal("-2.675").round_dp(2), decimal("-2.68"));
+    assert_eq!(decimal("2.675").round_dp_even(2), decimal("2.68"));
+    assert_eq!(decimal("2.665").round_dp_even(2), decimal("2.66"));
+    assert_eq!(decimal("2.675").round_dp_half_down(2), decimal("2.67"));
+    assert_eq!(decimal("2.671").ceil_dp(2), decimal("2.68"));
+    assert_eq!(decimal("-2.671").ceil_dp(2), decimal("-2.67"));
+    assert_eq!(decimal("-2.671").floor_dp(2), decimal("-2.68"));
+    assert_eq!(decimal("-2.679").trunc_dp(2), decimal("-2.67"));
+    assert_eq!(decimal("2.671").away_dp(2), decimal("2.68"));
+    assert_eq!(decimal("1.05").round_dp_half_odd(1), decimal("1.1"));
+
+    // Nothing to round keeps the scale, and zero results lose their sign
+    assert_eq!(decimal("1.5").round_dp(3).scale(), 1);
+    assert!(!decimal("-0.004").round_dp(2).is_sign_negative());
+    assert!(!decimal("-0.4").round_zeros(1).is_sign_negative());
+}
+
+#[test]
+fn test_large_scales() {
+    let smallest = Decimal::new(1, 28);
+    assert_eq!(smallest.round_dp(28), smallest);
+    assert_eq!(smallest.round_dp(40), smallest);
+    assert_eq!(smallest.round_dp(27), Decimal::ZERO);
+    assert_eq!(smallest.ceil_dp(27), Decimal::new(1, 27));
+    assert_eq!(smallest.round_sf(50), smallest);
+    assert_eq!(smallest.get_digits(), -27);
+    assert_eq!(
+        decimal("0.1234567890123456789012345678").round_dp(u32::MAX),
+        decimal("0.1234567890123456789012345678")
+    );
+    assert_eq!(
+        decimal("0.1234567890123456789012345678").round_sf(3),
+        decimal("0.123")
+    );
+}
+
+#[test]
+fn test_significant_figures() {
+    assert_eq!(decimal("123.456").round_sf(4), decimal("123.5"));
+    assert_eq!(decimal("-123.456").round_sf(2), decimal("-120"));
+    assert_eq!(decimal("0.0012345").round_sf(3), decimal("0.00123"));
+    assert_eq!(decimal("0.0012355").round_sf_even(4), decimal("0.001236"));
+    assert_eq!(decimal("99.95").round_sf(3), decimal("100"));
+    assert_eq!(decimal("123.456").ceil_sf(1), decimal("200"));
+    assert_eq!(decimal("-123.456").floor_sf(1), decimal("-200"));
+
+    // Counted from the mantissa, so trailing zeros of the scale are significant figures
+    assert_eq!(decimal("1.500").round_sf(3), decimal("1.50"));
+    assert_eq!(decimal("1.500").round_sf(3).scale(), 2);
+    assert_eq!(decimal("1.500").count_sig_figs(), 2);
+    assert_eq!(decimal("1.500").count_decimal_places(), 1);
+
+    assert_eq!(
+        decimal("0.5").try_round_sf(0),
+        Err(RoundingError::ZeroSigFigs)
+    );
+}
+
+#[test]
+fn test_zeros_and_overflow() {
+    assert_eq!(decimal("12345.6").round_zeros(2), decimal("12300"));
+    assert_eq!(decimal("-12355").round_zeros_even(1), decimal("-12360"));
+    assert_eq!(decimal("12345").ceil_zeros(3), decimal("13000"));
+    assert_eq!(decimal("-12345").floor_zeros(3), decimal("-13000"));
+
+    assert_eq!(Decimal::MAX.round_zeros(1), Decimal::MAX);
+    assert_eq!(Decimal::MIN.round_sf(1), Decimal::MIN);
+    assert_eq!(Decimal::MAX.checked_round_zeros(1), None);
+    assert_eq!(Decimal::MAX.try_ceil_sf(2), Err(RoundingError::Overflow));
+    assert_eq!(
+        Decimal::MAX.checked_floor_zeros(1).unwrap().mantissa() % 10,
+        0
+    );
+    assert_eq!(decimal("5").checked_round_zeros(40), Some(Decimal::ZERO));
+    assert_eq!(decimal("5").checked_ceil_zeros(40), None);
+}
+
+#[test]
+fn test_multiples_and_fractions() {
+    let step = decimal("0.05");
+    assert_eq!(decimal("1.125").round_to_multiple(step), decimal("1.15"));
+    assert_eq!(decimal("-1.125").round_to_multiple(step), decimal("-1.15"));
+    assert_eq!(decimal("1.101").ceil_to_multiple(step), decimal("1.15"));
+    assert_eq!(decimal("-1.101").floor_to_multiple(step), decimal("-1.15"));
+    assert_eq!(
+        decimal("1").try_round_to_multiple(Decimal::ZERO),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(Decimal::MAX.round_to_multiple(decimal("10")), Decimal::MAX);
+    assert_eq!(
+        decimal("1.3").quantize(decimal("0.5"), decimal("0.25")),
+        decimal("1.25")
+    );
+    assert_eq!(
+        decimal("1.3").ceil_quantize(decimal("0.5"), decimal("0.25")),
+        decimal("1.75")
+    );
+
+    assert_eq!(decimal("2.3").round_frac(4), decimal("2.25"));
+    assert_eq!(decimal("-2.375").round_frac(4), decimal("-2.5"));
+    assert_eq!(decimal("2.1").ceil_frac(3).round_dp(4), decimal("2.3333"));
+    assert_eq!(
+        decimal("1").try_floor_frac(0),
+        Err(RoundingError::ZeroDenominator)
+    );
+}
+
+#[test]
+fn test_powers_and_digits() {
+    assert_eq!(decimal("540").round_pow10(), decimal("100"));
+    assert_eq!(decimal("550").round_pow10(), decimal("1000"));
+    assert_eq!(decimal("-0.03").ceil_pow10(), decimal("-0.1"));
+    assert_eq!(decimal("0.03").floor_pow10(), decimal("0.01"));
+    assert_eq!(Decimal::ZERO.checked_round_pow10(), None);
+    assert_eq!(Decimal::MAX.checked_ceil_pow10(), None);
+
+    assert_eq!(decimal("123.45").order_of_magnitude(), Some(2));
+    assert_eq!(decimal("0.05").order_of_magnitude(), Some(-2));
+    assert_eq!(decimal("0.05").digit_count(), 1);
+    assert_eq!(decimal("-123.45").digits().collect::<Vec<_>>(), [1, 2, 3]);
+    assert_eq!(decimal("123.45").to_scientific(3), (decimal("1.23"), 2));
+    assert_eq!(decimal("-0.0120").to_scientific(3), (decimal("-1.2"), -2));
+    assert_eq!(
+        decimal("0.5").round_to_match(decimal("1.00")),
+        decimal("1")
+    );
+    assert_eq!(
+        decimal("2.345").round_to_match(decimal("0.01")),
+        decimal("2.35")
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_formatting() {
+    assert_eq!(decimal("2.675").format_dp(2), "2.68");
+    assert_eq!(decimal("-0.5").format_dp(3), "-0.500");
+    assert_eq!(decimal("1.5").format_sf(4), "1.500");
+    assert_eq!(decimal("12345.678").format_grouped(1, ',', '.'), "12,345.7");
+}
+
+#[test]
+fn test_matches_f64() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        // A multiple of 1/1024 below a million is exact in both types
+        let number = ((state >> 34) as i64 - (1 << 29)) as f64 / 1024.0;
+        let exact = Decimal::from_f64_retain(number).unwrap();
+        let decimal_places = (state % 8) as u32;
+        let sig_figs = (state % 12) as u32 + 1;
+        let zeros = (state % 5) as u32;
+
+        assert_eq!(
+            exact.round_dp(decimal_places).to_f64().unwrap(),
+            number.round_dp(decimal_places)
+        );
+        assert_eq!(
+            exact.floor_dp(decimal_places).to_f64().unwrap(),
+            number.floor_dp(decimal_places)
+        );
+        assert_eq!(
+            exact.round_sf(sig_figs).to_f64().unwrap(),
+            number.round_sf(sig_figs)
+        );
+        assert_eq!(
+            exact.ceil_sf(sig_figs).to_f64().unwrap(),
+            number.ceil_sf(sig_figs)
+        );
+        assert_eq!(
+            exact.round_zeros(zeros).to_f64().unwrap(),
+            number.round_zeros(zeros)
+        );
+        assert_eq!(exact.count_decimal_places(), number.count_decimal_places());
+        assert_eq!(exact.get_digits(), number.get_digits());
+    }
+}
+
+#[test]
+fn test_decimal_bridge() {
+    let number = Decimal::from_str("-12.3400").unwrap();
+    assert_eq!(number.to_decimal(), Some((-123_400, -4)));
+    assert_eq!(Decimal::from_decimal(-123_400, -4), Some(number));
+    assert_eq!(Decimal::from_decimal(125, 2), Some(Decimal::from(12_500)));
+    assert_eq!(
+        Decimal::from_decimal(5, -28),
+        Some(Decimal::from_str("5e-28").unwrap())
+    );
+    assert_eq!(
+        Decimal::from_decimal(500, -30),
+        Some(Decimal::from_str("5e-28").unwrap())
+    );
+    assert_eq!(Decimal::from_decimal(5, -29), None);
+    assert_eq!(Decimal::from_decimal(1, 29), None);
+    assert_eq!(Decimal::from_decimal(0, -40), Some(Decimal::ZERO));
+}
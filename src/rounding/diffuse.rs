@@ -0,0 +1,167 @@
+use super::{round, round_to_multiple};
+
+/// The values a grid is quantized to by [`quantize_grid_diffuse`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Quantizer<'a> {
+    /// Round to the given number of decimal places
+    DecimalPlaces(u32),
+    /// Round to the nearest multiple of a positive, finite step like [`round_to_multiple`]
+    Step(f64),
+    /// Snap to the nearest value of a sorted, non empty palette, ties go to the smaller value
+    Palette(&'a [f64]),
+}
+
+impl Quantizer<'_> {
+    #[inline]
+    fn quantize(&self, number: f64) -> f64 {
+        match *self {
+            Quantizer::DecimalPlaces(decimal_places) => round(number, decimal_places),
+            Quantizer::Step(step) => round_to_multiple(number, step),
+            Quantizer::Palette(levels) => {
+                let index = levels.partition_point(|&level| level < number);
+                match (index.checked_sub(1).map(|i| levels[i]), levels.get(index)) {
+                    (Some(below), Some(&above)) if above - number < number - below => above,
+                    (Some(below), _) => below,
+                    (None, Some(&above)) => above,
+                    (None, None) => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+/// The order pixels are visited in by [`quantize_grid_diffuse`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanOrder {
+    /// Every row is scanned left to right
+    #[default]
+    RowMajor,
+    /// Rows alternate between left to right and right to left, which reduces directional artifacts
+    Serpentine,
+}
+
+/// Rounds a row major grid to the given number of decimal places using Floyd–Steinberg error diffusion
+///
+/// Each value is rounded and the rounding error is spread over the neighbouring values that
+/// haven't been visited yet, so large areas keep their average instead of all rounding the same way
+///
+/// Returns the total error left over after diffusion, which is `sum(input) - sum(output)`.
+/// This is the error that would have been pushed past the right or bottom edge of the grid
+///
+/// # Panics
+///
+/// Panics if `width` is zero or the length of `data` is not a multiple of `width`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_grid_diffuse;
+///
+/// let mut data = [0.25; 16];
+/// let residual = round_grid_diffuse(&mut data, 4, 0);
+///
+/// assert!(data.iter().all(|&value| value == 0.0 || value == 1.0));
+/// assert!((4.0 - data.iter().sum::<f64>() - residual).abs() < 1e-9);
+/// ```
+#[inline]
+pub fn round_grid_diffuse(data: &mut [f64], width: usize, decimal_places: u32) -> f64 {
+    quantize_grid_diffuse(
+        data,
+        width,
+        Quantizer::DecimalPlaces(decimal_places),
+        ScanOrder::RowMajor,
+    )
+}
+
+/// Quantizes a row major grid using Floyd–Steinberg error diffusion
+///
+/// Works like [`round_grid_diffuse`] but can quantize to an arbitrary step or palette and scan the
+/// grid in serpentine order. Values that aren't finite are left alone and don't diffuse any error
+///
+/// # Panics
+///
+/// Panics if `width` is zero, the length of `data` is not a multiple of `width`,
+/// a step is not positive and finite or a palette is empty
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{quantize_grid_diffuse, Quantizer, ScanOrder};
+///
+/// let mut data = [0.3, 0.3, 0.3, 0.3];
+/// let palette = [0.0, 1.0];
+/// quantize_grid_diffuse(&mut data, 2, Quantizer::Palette(&palette), ScanOrder::Serpentine);
+///
+/// assert!(data.iter().all(|value| palette.contains(value)));
+/// ```
+pub fn quantize_grid_diffuse(
+    data: &mut [f64],
+    width: usize,
+    quantizer: Quantizer,
+    scan: ScanOrder,
+) -> f64 {
+    assert!(width > 0, "width must be greater than zero");
+    assert!(
+        data.len().is_multiple_of(width),
+        "data length must be a multiple of width"
+    );
+    match quantizer {
+        Quantizer::Step(step) => assert!(
+            step > 0.0 && step.is_finite(),
+            "step must be positive and finite"
+        ),
+        Quantizer::Palette(levels) => assert!(!levels.is_empty(), "palette must not be empty"),
+        Quantizer::DecimalPlaces(_) => {}
+    }
+
+    let height = data.len() / width;
+    let mut residual = 0.0;
+
+    for row in 0..height {
+        let reverse = scan == ScanOrder::Serpentine && row % 2 == 1;
+
+        for step in 0..width {
+            let column = if reverse { width - 1 - step } else { step };
+            let index = row * width + column;
+            let old = data[index];
+            if !old.is_finite() {
+                continue;
+            }
+
+            let new = quantizer.quantize(old);
+            data[index] = new;
+            let error = old - new;
+
+            let forward = if reverse {
+                column.checked_sub(1)
+            } else {
+                Some(column + 1)
+            };
+            let backward = if reverse {
+                Some(column + 1)
+            } else {
+                column.checked_sub(1)
+            };
+            let targets = [
+                (row, forward, 7.0),
+                (row + 1, backward, 3.0),
+                (row + 1, Some(column), 5.0),
+                (row + 1, forward, 1.0),
+            ];
+
+            for (target_row, target_column, weight) in targets {
+                let share = error * weight / 16.0;
+                match target_column {
+                    Some(target_column) if target_row < height && target_column < width => {
+                        data[target_row * width + target_column] += share;
+                    }
+                    _ => residual += share,
+                }
+            }
+        }
+    }
+
+    residual
+}
+
+mod tests;
@@ -0,0 +1,113 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+fn mean(data: &[f64]) -> f64 {
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+#[test]
+fn test_round_grid_diffuse_constant_gray() {
+    for gray in [0.123, 0.37, 0.5, 0.81, 0.999] {
+        let mut data = vec![gray; 32 * 24];
+        let residual = round_grid_diffuse(&mut data, 32, 1);
+
+        assert!(data.iter().all(|&value| round(value, 1) == value));
+        assert!((mean(&data) - gray).abs() <= 0.1);
+        assert!((data.len() as f64 * (gray - mean(&data)) - residual).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_quantize_grid_diffuse_step_and_palette() {
+    let mut data = vec![0.3; 20 * 20];
+    quantize_grid_diffuse(&mut data, 20, Quantizer::Step(0.25), ScanOrder::Serpentine);
+    assert!(data.iter().all(|&value| value % 0.25 == 0.0));
+    assert!((mean(&data) - 0.3).abs() <= 0.25);
+
+    let palette = [0.0, 0.5, 1.0];
+    let mut data = vec![0.3; 20 * 20];
+    quantize_grid_diffuse(
+        &mut data,
+        20,
+        Quantizer::Palette(&palette),
+        ScanOrder::RowMajor,
+    );
+    assert!(data.iter().all(|value| palette.contains(value)));
+    assert!((mean(&data) - 0.3).abs() <= 0.5);
+}
+
+#[test]
+fn test_quantize_palette_ties() {
+    let palette = [-1.0, 0.0, 2.0];
+    let quantizer = Quantizer::Palette(&palette);
+
+    assert_eq!(quantizer.quantize(1.0), 0.0);
+    assert_eq!(quantizer.quantize(1.1), 2.0);
+    assert_eq!(quantizer.quantize(-5.0), -1.0);
+    assert_eq!(quantizer.quantize(5.0), 2.0);
+    assert_eq!(quantizer.quantize(-0.5), -1.0);
+}
+
+#[test]
+fn test_quantize_step_on_grid() {
+    let quantizer = Quantizer::Step(0.1);
+    for (number, expected) in [
+        (0.3, 0.3),
+        (0.7, 0.7),
+        (0.26, 0.3),
+        (-0.16, -0.2),
+        (1e20, 1e20),
+    ] {
+        assert_eq!(quantizer.quantize(number), expected);
+    }
+
+    let mut data = vec![0.3; 8 * 8];
+    let residual = quantize_grid_diffuse(&mut data, 8, quantizer, ScanOrder::RowMajor);
+    assert!(data.iter().all(|&value| value == 0.3));
+    assert_eq!(residual, 0.0);
+}
+
+#[test]
+fn test_round_grid_diffuse_single_column() {
+    let mut data = vec![0.4; 10];
+    let residual = round_grid_diffuse(&mut data, 1, 0);
+
+    assert!(data.iter().all(|&value| value == 0.0 || value == 1.0));
+    assert!((4.0 - data.iter().sum::<f64>() - residual).abs() < 1e-9);
+
+    let mut data = vec![0.4; 10];
+    quantize_grid_diffuse(&mut data, 1, Quantizer::Step(1.0), ScanOrder::Serpentine);
+    assert!(data.iter().all(|&value| value == 0.0 || value == 1.0));
+}
+
+#[test]
+fn test_round_grid_diffuse_edges() {
+    let mut data: Vec<f64> = vec![];
+    assert_eq!(round_grid_diffuse(&mut data, 3, 0), 0.0);
+
+    let mut data = vec![0.4; 5];
+    let residual = round_grid_diffuse(&mut data, 5, 0);
+    assert!((2.0 - data.iter().sum::<f64>() - residual).abs() < 1e-9);
+
+    let mut data = vec![0.2, f64::NAN, 0.2, 0.2];
+    round_grid_diffuse(&mut data, 2, 0);
+    assert!(data[1].is_nan());
+}
+
+#[test]
+#[should_panic]
+fn test_round_grid_diffuse_bad_width() {
+    round_grid_diffuse(&mut [0.0; 5], 2, 0);
+}
+
+#[test]
+#[should_panic]
+fn test_quantize_grid_diffuse_infinite_step() {
+    quantize_grid_diffuse(
+        &mut [0.0; 4],
+        2,
+        Quantizer::Step(f64::INFINITY),
+        ScanOrder::RowMajor,
+    );
+}
@@ -0,0 +1,266 @@
+use core::fmt::{self, Display, LowerExp, Write};
+use core::str::{self, FromStr};
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{powers, RoundingMode};
+
+/// A stack buffer big enough to hold the shortest scientific representation of any float, or an
+/// i128 with an exponent
+struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Counts the significant figures in the shortest decimal representation that round trips
+pub(crate) fn shortest_sig_figs<T: LowerExp>(number: T) -> u32 {
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{:e}", number).expect("float representation fits in the buffer");
+
+    buffer.bytes[..buffer.len]
+        .iter()
+        .take_while(|&&byte| byte != b'e')
+        .filter(|byte| byte.is_ascii_digit())
+        .count() as u32
+}
+
+/// Whether a finite, non zero f64 has at most `sig_figs` significant figures
+pub(crate) fn has_sig_figs_f64(number: f64, sig_figs: u32, digits: i32) -> bool {
+    let decimal_places = sig_figs as i32 - digits;
+
+    // Scaling by an exact power of ten is only reliable while the scaled value stays well
+    // within the precision of an f64, otherwise fall back to formatting the number
+    if sig_figs <= 15 && (-22..=22).contains(&decimal_places) {
+        let power = powers::ten_f64(decimal_places.abs());
+        if decimal_places >= 0 {
+            (number * power).round() / power == number
+        } else {
+            (number / power).round() * power == number
+        }
+    } else {
+        shortest_sig_figs(number) <= sig_figs
+    }
+}
+
+/// Whether a finite, non zero f32 has at most `sig_figs` significant figures
+pub(crate) fn has_sig_figs_f32(number: f32, sig_figs: u32, digits: i32) -> bool {
+    let decimal_places = sig_figs as i32 - digits;
+
+    if sig_figs <= 6 && (-10..=10).contains(&decimal_places) {
+        let power = powers::ten_f32(decimal_places.abs());
+        if decimal_places >= 0 {
+            (number * power).round() / power == number
+        } else {
+            (number / power).round() * power == number
+        }
+    } else {
+        shortest_sig_figs(number) <= sig_figs
+    }
+}
+
+/// Divides an integer by `10^decimal_places`, correctly rounded to the nearest float
+/// even when the power of ten isn't exact
+pub(crate) fn scale_down<I: Display, T: FromStr>(integer: I, decimal_places: u32) -> T {
+    scale(integer, -(decimal_places as i64))
+}
+
+/// Multiplies an integer by `10^exponent`, correctly rounded to the nearest float
+/// even when the power of ten isn't exact
+pub(crate) fn scale<I: Display, T: FromStr>(integer: I, exponent: i64) -> T {
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{}e{}", integer, exponent).expect("scaled integer fits in the buffer");
+
+    str::from_utf8(&buffer.bytes[..buffer.len])
+        .ok()
+        .and_then(|decimal| decimal.parse().ok())
+        .expect("formatted float parses")
+}
+
+/// Splits the shortest decimal representation that round trips into its digits as an integer
+/// and the number of those digits that come after the decimal point
+pub(crate) fn shortest_decimal<T: LowerExp>(number: T) -> (u64, i32) {
+    let mut buffer = Buffer {
+        bytes: [0; 64],
+        len: 0,
+    };
+    write!(buffer, "{:e}", number).expect("float representation fits in the buffer");
+
+    let formatted = &buffer.bytes[..buffer.len];
+    let split = formatted
+        .iter()
+        .position(|&byte| byte == b'e')
+        .expect("scientific representation has an exponent");
+
+    let mut significand = 0;
+    let mut digits = 0;
+    for &byte in formatted[..split]
+        .iter()
+        .filter(|byte| byte.is_ascii_digit())
+    {
+        significand = significand * 10 + (byte - b'0') as u64;
+        digits += 1;
+    }
+    let exponent: i32 = str::from_utf8(&formatted[split + 1..])
+        .ok()
+        .and_then(|exponent| exponent.parse().ok())
+        .expect("exponent is an integer");
+
+    (significand, digits - 1 - exponent)
+}
+
+/// Rounds the magnitude of a finite, non zero float to a power of ten given the power below it,
+/// ties between the powers either side go to the larger one
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+pub(crate) fn power_of_ten<T: LowerExp + FromStr + PartialEq + Copy>(
+    magnitude: T,
+    order: i32,
+    mode: RoundingMode,
+) -> T {
+    let below = scale(1, order as i64);
+    if magnitude == below {
+        return below;
+    }
+
+    let above = match mode {
+        RoundingMode::AwayFromZero => true,
+        RoundingMode::HalfAwayFromZero => {
+            // The halfway point is 5.5 times the power below, compared on the decimal digits
+            let (significand, _) = shortest_decimal(magnitude);
+            let digits = significand.ilog10();
+            significand as u128 * 10
+                >= 55 * powers::ten_u128(digits).expect("power below a u64 fits in a u128")
+        }
+        _ => false,
+    };
+    if above {
+        scale(1, order as i64 + 1)
+    } else {
+        below
+    }
+}
+
+/// An iterator over the base 10 digits of the integer part of a number, most significant first
+///
+/// Created by [`Roundable::digits`](super::Roundable::digits)
+#[derive(Debug, Clone)]
+pub struct Digits {
+    /// The leading digits, followed by `zeros` zeros
+    significand: u128,
+    /// The number of digits of the significand still to be yielded
+    remaining: u32,
+    zeros: u32,
+    /// The digits of an integer too large for the significand, yielded before everything else
+    #[cfg(feature = "bigint")]
+    big: alloc::vec::IntoIter<u8>,
+}
+
+impl Digits {
+    /// The digits of an integer magnitude, a single zero for zero
+    #[inline]
+    pub(crate) fn integer(magnitude: u128) -> Digits {
+        Digits {
+            significand: magnitude,
+            remaining: magnitude.checked_ilog10().map_or(1, |digits| digits + 1),
+            zeros: 0,
+            #[cfg(feature = "bigint")]
+            big: alloc::vec::Vec::new().into_iter(),
+        }
+    }
+
+    /// No digits at all, for a number that isn't finite
+    #[inline]
+    pub(crate) fn none() -> Digits {
+        let mut none = Digits::integer(0);
+        none.remaining = 0;
+        none
+    }
+
+    /// The digits of the integer part of a decimal `significand * 10^exponent`
+    #[inline]
+    pub(crate) fn decimal(significand: u128, exponent: i32) -> Digits {
+        match u32::try_from(exponent) {
+            Ok(zeros) => {
+                let mut digits = Digits::integer(significand);
+                digits.zeros = if significand == 0 { 0 } else { zeros };
+                digits
+            }
+            Err(_) => Digits::integer(
+                powers::ten_u128(exponent.unsigned_abs()).map_or(0, |power| significand / power),
+            ),
+        }
+    }
+
+    /// The digits of a big integer, most significant first
+    #[inline]
+    #[cfg(feature = "bigint")]
+    pub(crate) fn big(digits: alloc::vec::Vec<u8>) -> Digits {
+        let mut integer = Digits::integer(0);
+        integer.remaining = 0;
+        integer.big = digits.into_iter();
+        integer
+    }
+
+    /// The digits of the integer part of the shortest decimal representation of a float that
+    /// round trips, so the digits of `1e20` are a one and twenty zeros. Non finite floats have none
+    #[inline]
+    pub(crate) fn float<T: LowerExp>(number: T, is_finite: bool) -> Digits {
+        if !is_finite {
+            return Digits::none();
+        }
+
+        let (significand, fraction_digits) = shortest_decimal(number);
+        Digits::decimal(significand as u128, -fraction_digits)
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        #[cfg(feature = "bigint")]
+        if let Some(digit) = self.big.next() {
+            return Some(digit);
+        }
+
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            let power = powers::ten_u128(self.remaining).expect("power below a u128 fits in one");
+            Some((self.significand / power % 10) as u8)
+        } else if self.zeros > 0 {
+            self.zeros -= 1;
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.remaining + self.zeros) as usize;
+        #[cfg(feature = "bigint")]
+        let len = len + self.big.len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Digits {}
@@ -0,0 +1,200 @@
+use core::time::Duration;
+
+use super::RoundingMode;
+
+/// The number of nanoseconds in a second
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// The units a [`Duration`] can be rounded to with [`round_duration`]
+///
+/// Steps that aren't a whole unit, like 10 ms or a 60 Hz frame, can be rounded to with
+/// [`round_duration_to`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DurationUnit {
+    /// A thousandth of a millisecond
+    Microsecond,
+    /// A thousandth of a second
+    Millisecond,
+    /// A whole second
+    Second,
+    /// 60 seconds
+    Minute,
+    /// 60 minutes
+    Hour,
+    /// 24 hours
+    Day,
+}
+
+impl DurationUnit {
+    /// The length of one unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::DurationUnit;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(DurationUnit::Millisecond.as_duration(), Duration::from_millis(1));
+    /// assert_eq!(DurationUnit::Hour.as_duration(), Duration::from_secs(3600));
+    /// ```
+    #[inline]
+    pub const fn as_duration(self) -> Duration {
+        match self {
+            DurationUnit::Microsecond => Duration::from_micros(1),
+            DurationUnit::Millisecond => Duration::from_millis(1),
+            DurationUnit::Second => Duration::from_secs(1),
+            DurationUnit::Minute => Duration::from_secs(60),
+            DurationUnit::Hour => Duration::from_secs(3600),
+            DurationUnit::Day => Duration::from_secs(86_400),
+        }
+    }
+}
+
+/// Rounds the duration to the nearest whole unit, ties round up
+///
+/// # Panics
+///
+/// If the result doesn't fit in a [`Duration`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_duration, DurationUnit};
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_millis(1_500);
+/// assert_eq!(round_duration(duration, DurationUnit::Second), Duration::from_secs(2));
+///
+/// let duration = Duration::from_nanos(1_234_567);
+/// assert_eq!(round_duration(duration, DurationUnit::Millisecond), Duration::from_millis(1));
+/// ```
+#[inline]
+pub fn round_duration(duration: Duration, unit: DurationUnit) -> Duration {
+    round_duration_to(duration, unit.as_duration())
+}
+
+/// Rounds the duration up to a whole unit
+///
+/// # Panics
+///
+/// If the result doesn't fit in a [`Duration`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{ceil_duration, DurationUnit};
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_millis(1_001);
+/// assert_eq!(ceil_duration(duration, DurationUnit::Second), Duration::from_secs(2));
+/// ```
+#[inline]
+pub fn ceil_duration(duration: Duration, unit: DurationUnit) -> Duration {
+    ceil_duration_to(duration, unit.as_duration())
+}
+
+/// Rounds the duration down to a whole unit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{floor_duration, DurationUnit};
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_secs(119);
+/// assert_eq!(floor_duration(duration, DurationUnit::Minute), Duration::from_secs(60));
+/// ```
+#[inline]
+pub fn floor_duration(duration: Duration, unit: DurationUnit) -> Duration {
+    floor_duration_to(duration, unit.as_duration())
+}
+
+/// Rounds the duration to the nearest multiple of `step`, ties round up
+///
+/// # Panics
+///
+/// If `step` is zero or the result doesn't fit in a [`Duration`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_duration_to;
+/// use std::time::Duration;
+///
+/// let step = Duration::from_millis(10);
+/// assert_eq!(round_duration_to(Duration::from_millis(1_234), step), Duration::from_millis(1_230));
+///
+/// // Frames at 60 Hz
+/// let frame = Duration::from_nanos(16_666_667);
+/// let rounded = round_duration_to(Duration::from_millis(40), frame);
+/// assert_eq!(rounded, Duration::from_nanos(33_333_334));
+/// ```
+#[inline]
+pub fn round_duration_to(duration: Duration, step: Duration) -> Duration {
+    quantize_duration(duration, step, RoundingMode::HalfUp)
+}
+
+/// Rounds the duration up to a multiple of `step`
+///
+/// # Panics
+///
+/// If `step` is zero or the result doesn't fit in a [`Duration`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_duration_to;
+/// use std::time::Duration;
+///
+/// let step = Duration::from_millis(250);
+/// assert_eq!(ceil_duration_to(Duration::from_millis(1_001), step), Duration::from_millis(1_250));
+/// ```
+#[inline]
+pub fn ceil_duration_to(duration: Duration, step: Duration) -> Duration {
+    quantize_duration(duration, step, RoundingMode::Up)
+}
+
+/// Rounds the duration down to a multiple of `step`
+///
+/// # Panics
+///
+/// If `step` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_duration_to;
+/// use std::time::Duration;
+///
+/// let step = Duration::from_millis(250);
+/// assert_eq!(floor_duration_to(Duration::from_millis(1_249), step), Duration::from_secs(1));
+/// ```
+#[inline]
+pub fn floor_duration_to(duration: Duration, step: Duration) -> Duration {
+    quantize_duration(duration, step, RoundingMode::Down)
+}
+
+/// Rounds the duration to a multiple of a non zero step, working in whole nanoseconds
+#[inline]
+fn quantize_duration(duration: Duration, step: Duration, mode: RoundingMode) -> Duration {
+    assert!(!step.is_zero(), "step must be greater than zero");
+
+    let nanos = duration.as_nanos();
+    let step = step.as_nanos();
+    let count = nanos / step;
+    let discarded = nanos % step;
+
+    let away = mode.rounds_away(
+        false,
+        count % 2 == 1,
+        discarded == 0,
+        discarded.cmp(&(step - discarded)),
+    );
+    let rounded = (count + away as u128) * step;
+
+    let seconds = u64::try_from(rounded / NANOS_PER_SECOND)
+        .expect("rounded duration doesn't fit in a Duration");
+    Duration::new(seconds, (rounded % NANOS_PER_SECOND) as u32)
+}
+
+mod tests;
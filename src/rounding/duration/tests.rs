@@ -0,0 +1,134 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_round_to_seconds() {
+    let second = DurationUnit::Second;
+    assert_eq!(
+        round_duration(Duration::from_nanos(1_499_900_000), second),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        round_duration(Duration::from_nanos(1_499_999_999), second),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        round_duration(Duration::from_millis(1_500), second),
+        Duration::from_secs(2)
+    );
+    assert_eq!(
+        round_duration(Duration::from_millis(500), second),
+        Duration::from_secs(1)
+    );
+    assert_eq!(round_duration(Duration::ZERO, second), Duration::ZERO);
+}
+
+#[test]
+fn test_round_to_milliseconds() {
+    let millisecond = DurationUnit::Millisecond;
+    assert_eq!(
+        round_duration(Duration::from_nanos(999_999), millisecond),
+        Duration::from_millis(1)
+    );
+    assert_eq!(
+        floor_duration(Duration::from_nanos(999_999), millisecond),
+        Duration::ZERO
+    );
+    assert_eq!(
+        ceil_duration(Duration::from_nanos(1), millisecond),
+        Duration::from_millis(1)
+    );
+    assert_eq!(
+        round_duration(Duration::from_nanos(1_499_999), millisecond),
+        Duration::from_millis(1)
+    );
+}
+
+#[test]
+fn test_round_across_seconds() {
+    assert_eq!(
+        round_duration(Duration::new(5, 999_500_000), DurationUnit::Millisecond),
+        Duration::from_secs(6)
+    );
+    assert_eq!(
+        ceil_duration(Duration::new(5, 999_000_001), DurationUnit::Millisecond),
+        Duration::from_secs(6)
+    );
+    assert_eq!(
+        round_duration_to(Duration::new(59, 995_000_000), Duration::from_millis(10)),
+        Duration::from_secs(60)
+    );
+    assert_eq!(
+        ceil_duration(Duration::from_secs(3_601), DurationUnit::Hour),
+        Duration::from_secs(7_200)
+    );
+    assert_eq!(
+        floor_duration(Duration::new(86_399, 999_999_999), DurationUnit::Day),
+        Duration::ZERO
+    );
+}
+
+#[test]
+fn test_round_to_frames() {
+    let frame = Duration::from_nanos(16_666_667);
+    assert_eq!(
+        round_duration_to(Duration::from_millis(8), frame),
+        Duration::ZERO
+    );
+    assert_eq!(round_duration_to(Duration::from_millis(9), frame), frame);
+    assert_eq!(
+        round_duration_to(Duration::from_secs(1), frame),
+        Duration::from_nanos(60 * 16_666_667)
+    );
+    assert_eq!(
+        floor_duration_to(Duration::from_millis(50), frame),
+        Duration::from_nanos(2 * 16_666_667)
+    );
+    assert_eq!(
+        ceil_duration_to(Duration::from_millis(50), frame),
+        Duration::from_nanos(3 * 16_666_667)
+    );
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let duration = Duration::from_nanos(state >> 20);
+        let rounded = round_duration_to(duration, frame);
+        assert_eq!(rounded.as_nanos() % frame.as_nanos(), 0);
+        let distance = rounded.abs_diff(duration).as_nanos();
+        assert!(distance * 2 <= frame.as_nanos());
+        assert!(floor_duration_to(duration, frame) <= duration);
+        assert!(ceil_duration_to(duration, frame) >= duration);
+    }
+}
+
+#[test]
+fn test_ties_round_up() {
+    let step = Duration::from_nanos(10);
+    assert_eq!(
+        round_duration_to(Duration::from_nanos(15), step),
+        Duration::from_nanos(20)
+    );
+    assert_eq!(
+        round_duration_to(Duration::from_nanos(25), step),
+        Duration::from_nanos(30)
+    );
+    assert_eq!(
+        round_duration_to(Duration::from_nanos(24), step),
+        Duration::from_nanos(20)
+    );
+}
+
+#[test]
+#[should_panic(expected = "step must be greater than zero")]
+fn test_zero_step() {
+    round_duration_to(Duration::from_secs(1), Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "rounded duration doesn't fit in a Duration")]
+fn test_overflow() {
+    ceil_duration(Duration::MAX, DurationUnit::Second);
+}
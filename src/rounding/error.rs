@@ -0,0 +1,204 @@
+use core::error::Error;
+use core::fmt;
+
+/// The reasons a `try_*` rounding function can fail
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_sf, RoundingError};
+///
+/// assert_eq!(try_round_sf(123_u8, 2), Ok(120_u8));
+/// assert_eq!(try_round_sf(f64::NAN, 2), Err(RoundingError::NonFinite));
+/// assert_eq!(try_round_sf(123.456_f64, 0), Err(RoundingError::ZeroSigFigs));
+/// assert_eq!(try_round_sf(251_u8, 1), Err(RoundingError::Overflow));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingError {
+    /// The number was NaN or infinite
+    NonFinite,
+    /// Zero significant figures were requested, which has no meaningful result
+    ZeroSigFigs,
+    /// The rounded result doesn't fit in the type
+    Overflow,
+    /// The step to round to a multiple of wasn't positive and finite
+    InvalidStep,
+    /// The denominator of the fraction to round to was zero
+    ZeroDenominator,
+    /// There were no values to snap to
+    EmptyValues,
+    /// The values to snap to weren't sorted in ascending order
+    UnsortedValues,
+    /// The range to clamp to had no rounded values in it, or an interval's low end was above its
+    /// high end
+    InvalidRange,
+    /// The weights to share items out by were negative, not finite or all zero,
+    /// or there were no parts to share them between
+    InvalidWeights,
+    /// The uncertainty of a measurement was negative or not finite
+    InvalidUncertainty,
+}
+
+impl fmt::Display for RoundingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            RoundingError::NonFinite => "cannot round a number that isn't finite",
+            RoundingError::ZeroSigFigs => "cannot round to zero significant figures",
+            RoundingError::Overflow => "rounded result doesn't fit in the type",
+            RoundingError::InvalidStep => {
+                "cannot round to a multiple of a step that isn't positive"
+            }
+            RoundingError::ZeroDenominator => "cannot round to a fraction with a zero denominator",
+            RoundingError::EmptyValues => "cannot snap to an empty set of values",
+            RoundingError::UnsortedValues => "cannot snap to values that aren't sorted",
+            RoundingError::InvalidRange => "range is inverted or has no rounded values in it",
+            RoundingError::InvalidWeights => {
+                "cannot share out by weights that are negative, not finite or all zero"
+            }
+            RoundingError::InvalidUncertainty => "uncertainty is negative or not finite",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for RoundingError {}
+
+/// The reasons [`parse_sf`](super::parse_sf) can fail
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{parse_sf, ParseSfError};
+///
+/// assert_eq!(parse_sf(""), Err(ParseSfError::Empty));
+/// assert_eq!(
+///     parse_sf("1.2x"),
+///     Err(ParseSfError::InvalidCharacter { character: 'x', index: 3 })
+/// );
+/// assert_eq!(parse_sf("1.2e"), Err(ParseSfError::MissingDigits));
+/// assert_eq!(parse_sf("1e400"), Err(ParseSfError::OutOfRange));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseSfError {
+    /// The text was empty or only whitespace
+    Empty,
+    /// A character that isn't part of a decimal number, at the given byte index in the text
+    InvalidCharacter { character: char, index: usize },
+    /// The number or its exponent had no digits
+    MissingDigits,
+    /// The number is too large or too small to be held by an f64 without becoming infinite or zero
+    OutOfRange,
+}
+
+impl fmt::Display for ParseSfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseSfError::Empty => f.write_str("cannot parse a number from empty text"),
+            ParseSfError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character `{character}` at index {index}")
+            }
+            ParseSfError::MissingDigits => f.write_str("number or exponent has no digits"),
+            ParseSfError::OutOfRange => f.write_str("number is out of the range of an f64"),
+        }
+    }
+}
+
+impl Error for ParseSfError {}
+
+/// The reasons [`parse_locale`](super::parse_locale) can fail
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{parse_locale, ParseLocaleError};
+///
+/// assert_eq!(parse_locale(" ", ',', Some('.')), Err(ParseLocaleError::Empty));
+/// assert_eq!(
+///     parse_locale("1.5", ',', None),
+///     Err(ParseLocaleError::InvalidCharacter { character: '.', index: 1 })
+/// );
+/// assert_eq!(
+///     parse_locale("12.34.5,6", ',', Some('.')),
+///     Err(ParseLocaleError::MisplacedSeparator { index: 2 })
+/// );
+/// assert_eq!(parse_locale("-,", ',', None), Err(ParseLocaleError::MissingDigits));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseLocaleError {
+    /// The text was empty or only whitespace
+    Empty,
+    /// A character that isn't part of a number with the given separators, at the given byte index
+    /// in the text
+    InvalidCharacter { character: char, index: usize },
+    /// A group separator that doesn't have one to three digits before it and exactly three after it,
+    /// or is after the decimal separator, at the given byte index in the text
+    MisplacedSeparator { index: usize },
+    /// The number had no digits
+    MissingDigits,
+    /// The number is too large or too small to be held by an f64 without becoming infinite or zero
+    OutOfRange,
+}
+
+impl fmt::Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseLocaleError::Empty => f.write_str("cannot parse a number from empty text"),
+            ParseLocaleError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character `{character}` at index {index}")
+            }
+            ParseLocaleError::MisplacedSeparator { index } => {
+                write!(f, "misplaced group separator at index {index}")
+            }
+            ParseLocaleError::MissingDigits => f.write_str("number has no digits"),
+            ParseLocaleError::OutOfRange => f.write_str("number is out of the range of an f64"),
+        }
+    }
+}
+
+impl Error for ParseLocaleError {}
+
+/// The reasons parsing a [`Fixed`](super::Fixed) can fail
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{Fixed, ParseFixedError};
+///
+/// assert_eq!("".parse::<Fixed<2>>(), Err(ParseFixedError::Empty));
+/// assert_eq!(
+///     "1.2e3".parse::<Fixed<2>>(),
+///     Err(ParseFixedError::InvalidCharacter { character: 'e', index: 3 })
+/// );
+/// assert_eq!("-.".parse::<Fixed<2>>(), Err(ParseFixedError::MissingDigits));
+/// assert_eq!("1".repeat(40).parse::<Fixed<2>>(), Err(ParseFixedError::OutOfRange));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseFixedError {
+    /// The text was empty or only whitespace
+    Empty,
+    /// A character that isn't part of a decimal number, at the given byte index in the text
+    InvalidCharacter { character: char, index: usize },
+    /// The number had no digits
+    MissingDigits,
+    /// The number is too large to be held with the decimal places of the type
+    OutOfRange,
+}
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseFixedError::Empty => f.write_str("cannot parse a number from empty text"),
+            ParseFixedError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character `{character}` at index {index}")
+            }
+            ParseFixedError::MissingDigits => f.write_str("number has no digits"),
+            ParseFixedError::OutOfRange => {
+                f.write_str("number is out of the range of the fixed point type")
+            }
+        }
+    }
+}
+
+impl Error for ParseFixedError {}
+
+mod tests;
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        RoundingError::NonFinite.to_string(),
+        "cannot round a number that isn't finite"
+    );
+    assert_eq!(
+        RoundingError::ZeroSigFigs.to_string(),
+        "cannot round to zero significant figures"
+    );
+    assert_eq!(
+        RoundingError::Overflow.to_string(),
+        "rounded result doesn't fit in the type"
+    );
+    assert_eq!(
+        RoundingError::InvalidStep.to_string(),
+        "cannot round to a multiple of a step that isn't positive"
+    );
+    assert_eq!(
+        RoundingError::ZeroDenominator.to_string(),
+        "cannot round to a fraction with a zero denominator"
+    );
+    assert_eq!(
+        RoundingError::EmptyValues.to_string(),
+        "cannot snap to an empty set of values"
+    );
+    assert_eq!(
+        RoundingError::UnsortedValues.to_string(),
+        "cannot snap to values that aren't sorted"
+    );
+    assert_eq!(
+        RoundingError::InvalidRange.to_string(),
+        "range is inverted or has no rounded values in it"
+    );
+    assert_eq!(
+        RoundingError::InvalidWeights.to_string(),
+        "cannot share out by weights that are negative, not finite or all zero"
+    );
+    assert_eq!(
+        RoundingError::InvalidUncertainty.to_string(),
+        "uncertainty is negative or not finite"
+    );
+}
+
+#[test]
+fn test_error_source() {
+    let error: Box<dyn Error> = Box::new(RoundingError::Overflow);
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_parse_sf_error_display() {
+    assert_eq!(
+        ParseSfError::Empty.to_string(),
+        "cannot parse a number from empty text"
+    );
+    assert_eq!(
+        ParseSfError::InvalidCharacter {
+            character: 'x',
+            index: 3
+        }
+        .to_string(),
+        "invalid character `x` at index 3"
+    );
+    assert_eq!(
+        ParseSfError::MissingDigits.to_string(),
+        "number or exponent has no digits"
+    );
+    assert_eq!(
+        ParseSfError::OutOfRange.to_string(),
+        "number is out of the range of an f64"
+    );
+}
+
+#[test]
+fn test_parse_locale_error_display() {
+    assert_eq!(
+        ParseLocaleError::Empty.to_string(),
+        "cannot parse a number from empty text"
+    );
+    assert_eq!(
+        ParseLocaleError::InvalidCharacter {
+            character: '.',
+            index: 1
+        }
+        .to_string(),
+        "invalid character `.` at index 1"
+    );
+    assert_eq!(
+        ParseLocaleError::MisplacedSeparator { index: 5 }.to_string(),
+        "misplaced group separator at index 5"
+    );
+    assert_eq!(
+        ParseLocaleError::MissingDigits.to_string(),
+        "number has no digits"
+    );
+    assert_eq!(
+        ParseLocaleError::OutOfRange.to_string(),
+        "number is out of the range of an f64"
+    );
+}
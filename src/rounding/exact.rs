@@ -0,0 +1,120 @@
+use core::cmp::Ordering;
+
+use super::{digits, powers, RoundingMode};
+
+/// Rounds the digits of the shortest decimal representation of a float to the given decimal places,
+/// giving the kept digits as an integer or `None` if there are no digits to discard.
+/// Negative decimal places round to the left of the decimal point
+#[inline]
+fn round_shortest(
+    significand: u64,
+    fraction_digits: i32,
+    negative: bool,
+    decimal_places: i64,
+    mode: RoundingMode,
+) -> Option<u64> {
+    let zeros = fraction_digits as i64 - decimal_places;
+    if zeros <= 0 {
+        return None;
+    }
+
+    let power = u32::try_from(zeros).ok().and_then(powers::ten_u64);
+    let (kept, away) = match power {
+        Some(power) => {
+            let kept = significand / power;
+            let discarded = significand % power;
+            let away = mode.rounds_away(
+                negative,
+                kept % 2 == 1,
+                discarded == 0,
+                discarded.cmp(&(power - discarded)),
+            );
+            (kept, away)
+        }
+        // The significand has at most 17 digits so it is less than half of any larger power
+        None => (0, mode.rounds_away(negative, false, false, Ordering::Less)),
+    };
+
+    Some(if away { kept + 1 } else { kept })
+}
+
+/// Counts the digits before the decimal point of the shortest decimal representation
+#[inline]
+fn shortest_digits(significand: u64, fraction_digits: i32) -> i64 {
+    significand
+        .checked_ilog10()
+        .map_or(0, |digits| digits as i64 + 1)
+        - fraction_digits as i64
+}
+
+/// Rounds the shortest decimal representation of a finite f64 to the given decimal places
+#[inline]
+pub(crate) fn decimal_places_f64(number: f64, decimal_places: u32, mode: RoundingMode) -> f64 {
+    position_f64(number, decimal_places as i64, mode)
+}
+
+/// Rounds the shortest decimal representation of a finite f32 to the given decimal places
+#[inline]
+pub(crate) fn decimal_places_f32(number: f32, decimal_places: u32, mode: RoundingMode) -> f32 {
+    position_f32(number, decimal_places as i64, mode)
+}
+
+/// Rounds the shortest decimal representation of a finite f64 to the given number of zeros
+#[inline]
+pub(crate) fn zeros_f64(number: f64, zeros: u32, mode: RoundingMode) -> f64 {
+    position_f64(number, -(zeros as i64), mode)
+}
+
+/// Rounds the shortest decimal representation of a finite f32 to the given number of zeros
+#[inline]
+pub(crate) fn zeros_f32(number: f32, zeros: u32, mode: RoundingMode) -> f32 {
+    position_f32(number, -(zeros as i64), mode)
+}
+
+/// Rounds the shortest decimal representation of a finite f64 to the given significant figures
+#[inline]
+pub(crate) fn sig_figs_f64(number: f64, sig_figs: u32, mode: RoundingMode) -> f64 {
+    let (significand, fraction_digits) = digits::shortest_decimal(number);
+    let decimal_places = sig_figs as i64 - shortest_digits(significand, fraction_digits);
+    let negative = number.is_sign_negative();
+    match round_shortest(significand, fraction_digits, negative, decimal_places, mode) {
+        Some(kept) => digits::scale::<_, f64>(kept, -decimal_places).copysign(number),
+        None => number,
+    }
+}
+
+/// Rounds the shortest decimal representation of a finite f32 to the given significant figures
+#[inline]
+pub(crate) fn sig_figs_f32(number: f32, sig_figs: u32, mode: RoundingMode) -> f32 {
+    let (significand, fraction_digits) = digits::shortest_decimal(number);
+    let decimal_places = sig_figs as i64 - shortest_digits(significand, fraction_digits);
+    let negative = number.is_sign_negative();
+    match round_shortest(significand, fraction_digits, negative, decimal_places, mode) {
+        Some(kept) => digits::scale::<_, f32>(kept, -decimal_places).copysign(number),
+        None => number,
+    }
+}
+
+/// Rounds the shortest decimal representation of a finite f64 to a number of decimal places,
+/// where negative decimal places are zeros
+#[inline]
+fn position_f64(number: f64, decimal_places: i64, mode: RoundingMode) -> f64 {
+    let (significand, fraction_digits) = digits::shortest_decimal(number);
+    let negative = number.is_sign_negative();
+    match round_shortest(significand, fraction_digits, negative, decimal_places, mode) {
+        Some(kept) => digits::scale::<_, f64>(kept, -decimal_places).copysign(number),
+        None => number,
+    }
+}
+
+/// Rounds the shortest decimal representation of a finite f32 to a number of decimal places,
+/// where negative decimal places are zeros
+#[inline]
+fn position_f32(number: f32, decimal_places: i64, mode: RoundingMode) -> f32 {
+    let (significand, fraction_digits) = digits::shortest_decimal(number);
+    let negative = number.is_sign_negative();
+    match round_shortest(significand, fraction_digits, negative, decimal_places, mode) {
+        Some(kept) => digits::scale::<_, f32>(kept, -decimal_places).copysign(number),
+        None => number,
+    }
+}
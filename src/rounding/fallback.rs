@@ -0,0 +1,424 @@
+//! The default methods of [`Roundable`], built on the decimal a type gives through
+//! [`to_decimal`](Roundable::to_decimal) and [`from_decimal`](Roundable::from_decimal)
+//!
+//! Every type in the crate overrides them with exact arithmetic on the type itself. They are for
+//! types implemented outside it, and work on a mantissa in an `i128` and a power of ten
+
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::integer::{self, Integer};
+#[cfg(feature = "alloc")]
+use super::{dp_exponent, format, Notation};
+use super::{powers, Digits, Roundable, RoundingError, RoundingMode};
+
+/// Rounds a decimal `mantissa * 10^exponent` to a multiple of `10^to`, which must be above the
+/// exponent, giving the mantissa of the result at `to`
+#[inline]
+fn round_decimal(mantissa: i128, exponent: i32, to: i32, mode: RoundingMode) -> i128 {
+    let (negative, magnitude) = mantissa.to_magnitude();
+    let steps = match powers::ten_u128(to.abs_diff(exponent)) {
+        Some(power) => integer::round_steps(magnitude, negative, power, mode),
+        // Every i128 is less than half of a power of ten that doesn't fit in a u128
+        None => mode.rounds_away(negative, false, magnitude == 0, Ordering::Less) as u128,
+    };
+
+    // Dividing by at least ten leaves room for the step rounded away from zero
+    let steps = steps as i128;
+    if negative {
+        -steps
+    } else {
+        steps
+    }
+}
+
+/// Multiplies a mantissa by `10^shift`, `None` if the result doesn't fit in an i128
+#[inline]
+fn scale_up(mantissa: i128, shift: u32) -> Option<i128> {
+    if mantissa == 0 {
+        return Some(0);
+    }
+
+    let power = powers::ten_u128(shift).and_then(|power| i128::try_from(power).ok())?;
+    mantissa.checked_mul(power)
+}
+
+/// Drops the trailing zeros of a non zero mantissa into the exponent
+#[inline]
+fn trim((mut mantissa, mut exponent): (i128, i32)) -> (i128, i32) {
+    while mantissa != 0 && mantissa % 10 == 0 && exponent < i32::MAX {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    (mantissa, exponent)
+}
+
+/// The mantissas of numbers at the smallest exponent between them, `None` if a number has no
+/// decimal or its mantissa doesn't fit in an i128 at that exponent
+#[inline]
+fn aligned<T: Roundable, const N: usize>(numbers: [&T; N]) -> Option<([i128; N], i32)> {
+    let mut decimals = [(0, 0); N];
+    for (decimal, number) in decimals.iter_mut().zip(numbers) {
+        *decimal = trim(number.to_decimal()?);
+    }
+
+    let exponent = decimals
+        .iter()
+        .map(|&(_, exponent)| exponent)
+        .min()
+        .unwrap_or(0);
+    let mut mantissas = [0; N];
+    for (mantissa, (unaligned, from)) in mantissas.iter_mut().zip(decimals) {
+        *mantissa = scale_up(unaligned, from.abs_diff(exponent))?;
+    }
+    Some((mantissas, exponent))
+}
+
+/// The integer value of a decimal, `None` if it has a fractional part or doesn't fit in an i128
+#[inline]
+pub(crate) fn integer_value(mantissa: i128, exponent: i32) -> Option<i128> {
+    match u32::try_from(exponent) {
+        Ok(shift) => scale_up(mantissa, shift),
+        Err(_) => match powers::ten_u128(exponent.unsigned_abs()) {
+            Some(power) => {
+                let power = i128::try_from(power).ok()?;
+                (mantissa % power == 0).then(|| mantissa / power)
+            }
+            None => (mantissa == 0).then_some(0),
+        },
+    }
+}
+
+/// The number furthest from zero on the given side of it that the type holds with a mantissa at
+/// the given exponent, which is where results that don't fit saturate
+///
+/// A mantissa closer to zero than one that fits is taken to fit as well
+#[inline]
+pub(crate) fn bound<T: Roundable>(negative: bool, exponent: i32) -> T {
+    let signed = |magnitude: i128| if negative { -magnitude } else { magnitude };
+    let (mut low, mut high) = (0, i128::MAX);
+    while low < high {
+        let middle = low + (high - low - 1) / 2 + 1;
+        if T::from_decimal(signed(middle), exponent).is_some() {
+            low = middle;
+        } else {
+            high = middle - 1;
+        }
+    }
+
+    T::from_decimal(signed(low), exponent).expect("zero fits in every roundable type")
+}
+
+/// Unwraps a rounded number, saturating at the bound on the same side of zero as the decimal of
+/// the original number
+#[inline]
+fn saturate<T: Roundable>((mantissa, exponent): (i128, i32), rounded: Option<T>) -> T {
+    rounded.unwrap_or_else(|| bound(mantissa < 0, exponent))
+}
+
+/// Rounds a number to a multiple of `10^exponent`, `None` if the number has no decimal or the
+/// result doesn't fit in the type
+#[inline]
+pub(crate) fn round_to_exponent<T: Roundable>(
+    number: T,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<T> {
+    let (mantissa, from) = number.to_decimal()?;
+    if mantissa == 0 || exponent <= from {
+        return Some(number);
+    }
+
+    T::from_decimal(round_decimal(mantissa, from, exponent, mode), exponent)
+}
+
+/// Rounds a number to a multiple of `10^exponent`, saturating at the bounds of the type when the
+/// result doesn't fit. A number with no decimal is returned as it is
+#[inline]
+pub(crate) fn saturating_round_to_exponent<T: Roundable>(
+    number: T,
+    exponent: i32,
+    mode: RoundingMode,
+) -> T {
+    let Some(decimal) = number.to_decimal() else {
+        return number;
+    };
+    saturate(decimal, number.round_to_exponent(exponent, mode))
+}
+
+/// The number of digits before the decimal point, negative for the leading zeros after it
+#[inline]
+pub(crate) fn get_digits<T: Roundable>(number: &T) -> i32 {
+    match number.to_decimal() {
+        Some((mantissa, exponent)) if mantissa != 0 => {
+            (integer::digit_count(mantissa) as i32).saturating_add(exponent)
+        }
+        _ => 0,
+    }
+}
+
+/// Rounds a number to the given number of zeros, up or down at random with a probability of
+/// going up equal to its distance from the multiple below
+#[inline]
+pub(crate) fn round_zeros_stochastic<T: Roundable, R: FnMut() -> f64>(
+    number: T,
+    zeros: u32,
+    mut random: R,
+) -> T {
+    let Some((mantissa, exponent)) = number.to_decimal() else {
+        return number;
+    };
+    let Ok(dropped) = u32::try_from(zeros as i64 - exponent as i64) else {
+        return number;
+    };
+
+    let fraction = integer::fraction_to_zeros(mantissa, dropped);
+    if fraction == 0.0 {
+        return number;
+    }
+
+    let mode = if random() < fraction {
+        RoundingMode::Up
+    } else {
+        RoundingMode::Down
+    };
+    number.round_zeros_mode(zeros, mode)
+}
+
+/// Whether a number can be used as the step to round to a multiple of
+#[inline]
+fn is_valid_step<T: Roundable>(step: &T) -> bool {
+    step.to_decimal().is_some_and(|(mantissa, _)| mantissa > 0)
+}
+
+/// Rounds a number to a multiple of a step, reporting a number with no decimal, a step that
+/// isn't positive and a result that doesn't fit in the type
+#[inline]
+pub(crate) fn try_to_multiple<T: Roundable>(
+    number: &T,
+    step: &T,
+    mode: RoundingMode,
+) -> Result<T, RoundingError> {
+    number.to_decimal().ok_or(RoundingError::NonFinite)?;
+    if !is_valid_step(step) {
+        return Err(RoundingError::InvalidStep);
+    }
+
+    let ([mantissa, step], exponent) = aligned([number, step]).ok_or(RoundingError::Overflow)?;
+    integer::round_multiple(mantissa, step, mode)
+        .and_then(|rounded| T::from_decimal(rounded, exponent))
+        .ok_or(RoundingError::Overflow)
+}
+
+/// Rounds a number to a multiple of a step, saturating at the bounds of the type when the result
+/// doesn't fit. A number with no decimal is returned as it is
+///
+/// # Panics
+///
+/// If the step isn't positive
+#[inline]
+pub(crate) fn to_multiple<T: Roundable>(number: T, step: T, mode: RoundingMode) -> T {
+    assert!(is_valid_step(&step), "step must be positive");
+    let Some(decimal) = number.to_decimal() else {
+        return number;
+    };
+    saturate(decimal, try_to_multiple(&number, &step, mode).ok())
+}
+
+/// Rounds a number to a point on a grid of multiples of a step shifted by an offset as a
+/// mantissa and exponent, `None` if the number or offset has no decimal or the point doesn't fit
+/// in an i128
+///
+/// # Panics
+///
+/// If the step isn't positive
+#[inline]
+fn quantize_decimal<T: Roundable>(
+    number: &T,
+    step: &T,
+    offset: &T,
+    mode: RoundingMode,
+) -> Option<(i128, i32)> {
+    assert!(is_valid_step(step), "step must be positive");
+
+    let ([mantissa, step, offset], exponent) = aligned([number, step, offset])?;
+    integer::quantize(mantissa, step, offset, mode).map(|point| (point, exponent))
+}
+
+/// Rounds a number to a point on a grid, `None` if the number has no decimal or the point
+/// doesn't fit in the type
+///
+/// # Panics
+///
+/// If the step isn't positive
+#[inline]
+pub(crate) fn checked_quantize<T: Roundable>(
+    number: &T,
+    step: &T,
+    offset: &T,
+    mode: RoundingMode,
+) -> Option<T> {
+    let (point, exponent) = quantize_decimal(number, step, offset, mode)?;
+    T::from_decimal(point, exponent)
+}
+
+/// Rounds a number to a point on a grid, saturating at the bound on the same side of zero as
+/// the point when it doesn't fit in the type. A number with no decimal is returned as it is
+///
+/// # Panics
+///
+/// If the step isn't positive
+#[inline]
+pub(crate) fn quantize<T: Roundable>(number: T, step: T, offset: T, mode: RoundingMode) -> T {
+    let point = quantize_decimal(&number, &step, &offset, mode);
+    let Some((mantissa, exponent)) = number.to_decimal() else {
+        return number;
+    };
+
+    match point {
+        Some((point, at)) => {
+            T::from_decimal(point, at).unwrap_or_else(|| bound(point < 0, exponent))
+        }
+        None => bound(mantissa < 0, exponent),
+    }
+}
+
+/// Rounds the magnitude of a number to a power of ten keeping its sign, `None` if the number is
+/// zero, has no decimal or the power doesn't fit in the type
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+#[inline]
+pub(crate) fn checked_power_of_ten<T: Roundable>(number: &T, mode: RoundingMode) -> Option<T> {
+    let (mantissa, exponent) = number.to_decimal()?;
+    let (negative, magnitude) = mantissa.to_magnitude();
+    let power = integer::power_of_ten_exponent(magnitude, mode)?;
+    let exponent = exponent.checked_add(i32::try_from(power).ok()?)?;
+    T::from_decimal(if negative { -1 } else { 1 }, exponent)
+}
+
+/// Rounds the magnitude of a number to a power of ten like [`checked_power_of_ten`], saturating
+/// at the bounds of the type when it doesn't fit. Zero and numbers with no decimal stay as they are
+#[inline]
+pub(crate) fn power_of_ten<T: Roundable>(number: T, mode: RoundingMode) -> T {
+    match number.to_decimal() {
+        Some((mantissa, exponent)) if mantissa != 0 => {
+            checked_power_of_ten(&number, mode).unwrap_or_else(|| bound(mantissa < 0, exponent))
+        }
+        _ => number,
+    }
+}
+
+/// The exponent of the largest power of ten that is no more than the magnitude of a number,
+/// `None` if it is zero or has no decimal
+#[inline]
+pub(crate) fn order_of_magnitude<T: Roundable>(number: &T) -> Option<i32> {
+    let (mantissa, exponent) = number.to_decimal().filter(|&(mantissa, _)| mantissa != 0)?;
+    Some((integer::digit_count(mantissa) as i32 - 1).saturating_add(exponent))
+}
+
+/// Counts the significant figures of a number, none for zero and numbers with no decimal
+#[inline]
+pub(crate) fn count_sig_figs<T: Roundable>(number: &T) -> u32 {
+    number
+        .to_decimal()
+        .map_or(0, |(mantissa, _)| integer::sig_figs(mantissa))
+}
+
+/// The digits of the integer part of a number, none for a number with no decimal
+#[inline]
+pub(crate) fn digits<T: Roundable>(number: &T) -> Digits {
+    match number.to_decimal() {
+        Some((mantissa, exponent)) => Digits::decimal(mantissa.unsigned_abs(), exponent),
+        None => Digits::none(),
+    }
+}
+
+/// Renders a number rounded to the given significant figures like the integers do, so results
+/// past the bounds of the type aren't saturated. A number with no decimal renders as `NaN`
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn format_sf<T: Roundable>(number: &T, sig_figs: u32, notation: Notation) -> String {
+    let Some((mantissa, exponent)) = number.to_decimal() else {
+        return String::from("NaN");
+    };
+
+    let (negative, significand, zeros) = if sig_figs == 0 {
+        (false, 0, 0)
+    } else {
+        integer::round_sf_magnitude(mantissa, sig_figs, RoundingMode::HalfAwayFromZero)
+    };
+    let fraction_digits = (-(zeros as i32)).saturating_sub(exponent);
+    format::significant(significand, fraction_digits, negative, sig_figs, notation)
+}
+
+/// Renders a number rounded to the given decimal places with the given separators. A number with
+/// no decimal renders as `NaN`
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn format_locale<T: Roundable>(
+    number: &T,
+    decimal_places: u32,
+    decimal_separator: char,
+    group_separator: Option<char>,
+) -> String {
+    let Some((mantissa, exponent)) = number.to_decimal() else {
+        return String::from("NaN");
+    };
+
+    let to = dp_exponent(decimal_places);
+    let (significand, fraction_digits) = if exponent >= to {
+        (mantissa.unsigned_abs(), -exponent)
+    } else {
+        let rounded = round_decimal(mantissa, exponent, to, RoundingMode::HalfAwayFromZero);
+        (rounded.unsigned_abs(), -to)
+    };
+    let fixed = format::fixed_decimal(significand, fraction_digits, mantissa < 0, decimal_places);
+    format::group(&fixed, decimal_separator, group_separator)
+}
+
+/// Whether a number is nearer the number above it than the one below it
+#[inline]
+pub(crate) fn nearer_above<T: Roundable>(number: &T, below: &T, above: &T) -> bool {
+    aligned([number, below, above]).is_some_and(|([number, below, above], _)| {
+        integer::distance(number, above) < integer::distance(below, number)
+    })
+}
+
+/// Whether a number is exactly halfway between two multiples of `10^-decimal_places`
+#[inline]
+pub(crate) fn tie_at<T: Roundable>(number: &T, decimal_places: i32) -> bool {
+    number.to_decimal().is_some_and(|(mantissa, exponent)| {
+        let dropped = -(decimal_places as i64) - exponent as i64;
+        dropped > 0 && integer::is_tie(mantissa, u32::try_from(dropped).unwrap_or(u32::MAX))
+    })
+}
+
+/// The error `number - rounded`, saturating at the bounds of the type when it doesn't fit.
+/// A number with no decimal is returned as it is
+#[inline]
+pub(crate) fn rounding_error<T: Roundable>(number: T, rounded: T) -> T {
+    let Some(([number, rounded], exponent)) = aligned([&number, &rounded]) else {
+        return number;
+    };
+
+    number
+        .checked_sub(rounded)
+        .and_then(|error| T::from_decimal(error, exponent))
+        .unwrap_or_else(|| bound(number < rounded, exponent))
+}
+
+/// Rounds every number in a slice to the given number of zeros with the given mode, moving each
+/// one out with a zero left in its place as the type may not be `Clone`
+#[inline]
+pub(crate) fn round_slice_zeros_mode<T: Roundable>(
+    values: &mut [T],
+    zeros: u32,
+    mode: RoundingMode,
+) {
+    for value in values {
+        let zero = T::from_decimal(0, 0).expect("zero fits in every roundable type");
+        *value = core::mem::replace(value, zero).round_zeros_mode(zeros, mode);
+    }
+}
@@ -0,0 +1,773 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use super::{digits, fallback, integer, powers, sf_exponent};
+#[cfg(feature = "alloc")]
+use super::{format, Notation};
+use super::{Digits, ParseFixedError, Roundable, RoundingError, RoundingMode};
+use integer::Integer;
+
+/// A fixed point number with `DP` decimal places, stored exactly as a whole number of
+/// `10^-DP` units in an `i128`
+///
+/// Addition and subtraction are exact, while multiplication and division round the result to `DP`
+/// decimal places, half away from zero for the operators or with a given mode for
+/// [`mul_mode`](Fixed::mul_mode) and [`div_mode`](Fixed::div_mode). The operators panic if the
+/// result doesn't fit, the `checked_*` methods return `None` instead. `DP` can be at most 38, the
+/// most decimal places an `i128` can hold a whole unit with
+///
+/// It implements [`Roundable`], so rounding to zeros, significant figures or fewer decimal places
+/// is exact, and results that don't fit saturate at [`Fixed::MIN`] and [`Fixed::MAX`] like integers
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{Fixed, Roundable, RoundingMode};
+///
+/// let price = Fixed::<2>::from_f64_rounded(1.005);
+/// assert_eq!(price.to_string(), "1.01");
+///
+/// let tax = price.mul_mode("0.175".parse().unwrap(), RoundingMode::HalfEven);
+/// assert_eq!(tax, Fixed::from_units(18));
+/// assert_eq!((price + tax).to_f64(), 1.19);
+/// assert_eq!("1234.56".parse::<Fixed<2>>().unwrap().round_sf(2).to_string(), "1200.00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<const DP: u32>(i128);
+
+impl<const DP: u32> Fixed<DP> {
+    /// The number of units in one, checked when the type is used
+    const SCALE: u128 = {
+        assert!(
+            DP <= 38,
+            "a fixed point number can have at most 38 decimal places"
+        );
+        10_u128.pow(DP)
+    };
+
+    /// Zero
+    pub const ZERO: Fixed<DP> = Fixed(0);
+
+    /// The smallest number that can be held
+    pub const MIN: Fixed<DP> = Fixed(i128::MIN);
+
+    /// The largest number that can be held
+    pub const MAX: Fixed<DP> = Fixed(i128::MAX);
+
+    /// Makes a number from a whole number of `10^-DP` units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<2>::from_units(-150).to_string(), "-1.50");
+    /// ```
+    #[inline]
+    pub const fn from_units(units: i128) -> Fixed<DP> {
+        Fixed(units)
+    }
+
+    /// The number as a whole number of `10^-DP` units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<3>::from_f64_rounded(1.5).units(), 1_500);
+    /// ```
+    #[inline]
+    pub const fn units(self) -> i128 {
+        self.0
+    }
+
+    /// Rounds a float to `DP` decimal places with ties going away from zero,
+    /// saturating at the bounds if it doesn't fit
+    ///
+    /// The float is rounded from its shortest decimal representation, so `1.005` is taken as
+    /// exactly `1.005` rather than the binary value just below it and rounds up
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<2>::from_f64_rounded(1.005).units(), 101);
+    /// assert_eq!(Fixed::<2>::from_f64_rounded(-2.675).units(), -268);
+    /// assert_eq!(Fixed::<2>::from_f64_rounded(1e40), Fixed::MAX);
+    /// ```
+    #[inline]
+    pub fn from_f64_rounded(number: f64) -> Fixed<DP> {
+        Fixed::from_f64_rounded_mode(number, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds a float to `DP` decimal places using the given rounding mode,
+    /// saturating at the bounds if it doesn't fit
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// assert_eq!(Fixed::<1>::from_f64_rounded_mode(0.25, RoundingMode::HalfEven).units(), 2);
+    /// assert_eq!(Fixed::<1>::from_f64_rounded_mode(-0.21, RoundingMode::Down).units(), -3);
+    /// ```
+    #[inline]
+    pub fn from_f64_rounded_mode(number: f64, mode: RoundingMode) -> Fixed<DP> {
+        assert!(!number.is_nan(), "number must not be NaN");
+        Fixed::checked_from_f64(number, mode).unwrap_or(if number < 0.0 {
+            Fixed::MIN
+        } else {
+            Fixed::MAX
+        })
+    }
+
+    /// Rounds a float to `DP` decimal places using the given rounding mode,
+    /// or returns `None` if it isn't finite or doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// assert_eq!(
+    ///     Fixed::<2>::checked_from_f64(0.125, RoundingMode::HalfEven),
+    ///     Some(Fixed::from_units(12))
+    /// );
+    /// assert_eq!(Fixed::<2>::checked_from_f64(1e40, RoundingMode::HalfEven), None);
+    /// assert_eq!(Fixed::<2>::checked_from_f64(f64::NAN, RoundingMode::HalfEven), None);
+    /// ```
+    #[inline]
+    pub fn checked_from_f64(number: f64, mode: RoundingMode) -> Option<Fixed<DP>> {
+        round_to_units(number, DP, 1, mode).map(Fixed)
+    }
+
+    /// The closest float to the number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<2>::from_units(30).to_f64(), 0.3);
+    /// assert_eq!(Fixed::<3>::from_units(-1).to_f64(), -0.001);
+    /// ```
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        digits::scale_down(self.0, DP)
+    }
+
+    /// Adds two numbers, or returns `None` if the sum doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<1>::from_units(1).checked_add(Fixed::from_units(2)), Some(Fixed::from_units(3)));
+    /// assert_eq!(Fixed::<1>::MAX.checked_add(Fixed::from_units(1)), None);
+    /// ```
+    #[inline]
+    pub fn checked_add(self, rhs: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.0.checked_add(rhs.0).map(Fixed)
+    }
+
+    /// Subtracts a number, or returns `None` if the difference doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<1>::from_units(1).checked_sub(Fixed::from_units(2)), Some(Fixed::from_units(-1)));
+    /// assert_eq!(Fixed::<1>::MIN.checked_sub(Fixed::from_units(1)), None);
+    /// ```
+    #[inline]
+    pub fn checked_sub(self, rhs: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.0.checked_sub(rhs.0).map(Fixed)
+    }
+
+    /// Multiplies two numbers and rounds the product to `DP` decimal places using the given
+    /// rounding mode
+    ///
+    /// # Panics
+    ///
+    /// Panics if the product doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// let half = Fixed::<1>::from_units(5);
+    /// assert_eq!(half.mul_mode(half, RoundingMode::HalfEven), Fixed::from_units(2));
+    /// assert_eq!(half.mul_mode(half, RoundingMode::HalfUp), Fixed::from_units(3));
+    /// ```
+    #[inline]
+    pub fn mul_mode(self, rhs: Fixed<DP>, mode: RoundingMode) -> Fixed<DP> {
+        self.checked_mul_mode(rhs, mode)
+            .expect("product of fixed point numbers doesn't fit")
+    }
+
+    /// Multiplies two numbers and rounds the product to `DP` decimal places with ties going away
+    /// from zero, or returns `None` if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<1>::from_units(5).checked_mul(Fixed::from_units(5)), Some(Fixed::from_units(3)));
+    /// assert_eq!(Fixed::<1>::MAX.checked_mul(Fixed::from_units(20)), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul(self, rhs: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.checked_mul_mode(rhs, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Multiplies two numbers and rounds the product to `DP` decimal places using the given
+    /// rounding mode, or returns `None` if it doesn't fit
+    ///
+    /// The product of the units has to fit in a `u128` before it is rounded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// let third = Fixed::<2>::from_units(33);
+    /// assert_eq!(third.checked_mul_mode(third, RoundingMode::Up), Some(Fixed::from_units(11)));
+    /// assert_eq!(Fixed::<2>::MAX.checked_mul_mode(third, RoundingMode::Up), None);
+    /// ```
+    #[inline]
+    pub fn checked_mul_mode(self, rhs: Fixed<DP>, mode: RoundingMode) -> Option<Fixed<DP>> {
+        let (negative, magnitude) = self.0.to_magnitude();
+        let (rhs_negative, rhs_magnitude) = rhs.0.to_magnitude();
+        let negative = negative != rhs_negative;
+        let product = magnitude.checked_mul(rhs_magnitude)?;
+        let units = integer::round_steps(product, negative, Self::SCALE, mode);
+        i128::from_magnitude(negative && units != 0, units).map(Fixed)
+    }
+
+    /// Divides by a number and rounds the quotient to `DP` decimal places using the given
+    /// rounding mode
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero or the quotient doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// let one = Fixed::<2>::from_units(100);
+    /// assert_eq!(one.div_mode(Fixed::from_units(300), RoundingMode::Up), Fixed::from_units(34));
+    /// assert_eq!(one.div_mode(Fixed::from_units(-300), RoundingMode::Up), Fixed::from_units(-33));
+    /// ```
+    #[inline]
+    pub fn div_mode(self, rhs: Fixed<DP>, mode: RoundingMode) -> Fixed<DP> {
+        assert!(rhs.0 != 0, "attempt to divide a fixed point number by zero");
+        self.checked_div_mode(rhs, mode)
+            .expect("quotient of fixed point numbers doesn't fit")
+    }
+
+    /// Divides by a number and rounds the quotient to `DP` decimal places with ties going away
+    /// from zero, or returns `None` if the divisor is zero or the quotient doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<1>::from_units(10).checked_div(Fixed::from_units(40)), Some(Fixed::from_units(3)));
+    /// assert_eq!(Fixed::<1>::from_units(10).checked_div(Fixed::ZERO), None);
+    /// ```
+    #[inline]
+    pub fn checked_div(self, rhs: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.checked_div_mode(rhs, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Divides by a number and rounds the quotient to `DP` decimal places using the given
+    /// rounding mode, or returns `None` if the divisor is zero or the quotient doesn't fit
+    ///
+    /// The units of the dividend multiplied by `10^DP` have to fit in a `u128` before dividing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Fixed, RoundingMode};
+    ///
+    /// let one = Fixed::<2>::from_units(100);
+    /// assert_eq!(one.checked_div_mode(Fixed::from_units(800), RoundingMode::HalfEven), Some(Fixed::from_units(12)));
+    /// assert_eq!(one.checked_div_mode(Fixed::ZERO, RoundingMode::HalfEven), None);
+    /// ```
+    #[inline]
+    pub fn checked_div_mode(self, rhs: Fixed<DP>, mode: RoundingMode) -> Option<Fixed<DP>> {
+        if rhs.0 == 0 {
+            return None;
+        }
+
+        let (negative, magnitude) = self.0.to_magnitude();
+        let (rhs_negative, rhs_magnitude) = rhs.0.to_magnitude();
+        let negative = negative != rhs_negative;
+        let scaled = magnitude.checked_mul(Self::SCALE)?;
+        let units = integer::round_steps(scaled, negative, rhs_magnitude, mode);
+        i128::from_magnitude(negative && units != 0, units).map(Fixed)
+    }
+
+    /// Negates the number, or returns `None` for [`Fixed::MIN`] which has no positive counterpart
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Fixed;
+    ///
+    /// assert_eq!(Fixed::<2>::from_units(5).checked_neg(), Some(Fixed::from_units(-5)));
+    /// assert_eq!(Fixed::<2>::MIN.checked_neg(), None);
+    /// ```
+    #[inline]
+    pub fn checked_neg(self) -> Option<Fixed<DP>> {
+        self.0.checked_neg().map(Fixed)
+    }
+
+    /// Rounds to a multiple of `10^exponent`, saturating at the bounds if the result doesn't fit
+    #[inline]
+    fn saturating_round(self, exponent: i32, mode: RoundingMode) -> Fixed<DP> {
+        let rounded = self.round_to_exponent(exponent, mode).map(Fixed::units);
+        Fixed(integer::saturate(self.0, rounded))
+    }
+}
+
+impl<const DP: u32> Add for Fixed<DP> {
+    type Output = Fixed<DP>;
+
+    #[inline]
+    fn add(self, rhs: Fixed<DP>) -> Fixed<DP> {
+        self.checked_add(rhs)
+            .expect("sum of fixed point numbers doesn't fit")
+    }
+}
+
+impl<const DP: u32> Sub for Fixed<DP> {
+    type Output = Fixed<DP>;
+
+    #[inline]
+    fn sub(self, rhs: Fixed<DP>) -> Fixed<DP> {
+        self.checked_sub(rhs)
+            .expect("difference of fixed point numbers doesn't fit")
+    }
+}
+
+impl<const DP: u32> Mul for Fixed<DP> {
+    type Output = Fixed<DP>;
+
+    #[inline]
+    fn mul(self, rhs: Fixed<DP>) -> Fixed<DP> {
+        self.mul_mode(rhs, RoundingMode::HalfAwayFromZero)
+    }
+}
+
+impl<const DP: u32> Div for Fixed<DP> {
+    type Output = Fixed<DP>;
+
+    #[inline]
+    fn div(self, rhs: Fixed<DP>) -> Fixed<DP> {
+        self.div_mode(rhs, RoundingMode::HalfAwayFromZero)
+    }
+}
+
+impl<const DP: u32> Neg for Fixed<DP> {
+    type Output = Fixed<DP>;
+
+    #[inline]
+    fn neg(self) -> Fixed<DP> {
+        self.checked_neg()
+            .expect("negation of a fixed point number doesn't fit")
+    }
+}
+
+impl<const DP: u32> fmt::Display for Fixed<DP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (negative, magnitude) = self.0.to_magnitude();
+        let sign = if negative { "-" } else { "" };
+        let (integer, fraction) = (magnitude / Self::SCALE, magnitude % Self::SCALE);
+        if DP == 0 {
+            write!(f, "{sign}{integer}")
+        } else {
+            write!(f, "{sign}{integer}.{fraction:0width$}", width = DP as usize)
+        }
+    }
+}
+
+impl<const DP: u32> FromStr for Fixed<DP> {
+    type Err = ParseFixedError;
+
+    /// Parses a decimal number such as `-12.345`, rounding any digits past `DP` decimal places
+    /// with ties going away from zero
+    fn from_str(text: &str) -> Result<Fixed<DP>, ParseFixedError> {
+        let unsigned = text.strip_prefix(['-', '+']).unwrap_or(text);
+        let negative = text.starts_with('-');
+        if text.trim().is_empty() {
+            return Err(ParseFixedError::Empty);
+        }
+
+        let offset = text.len() - unsigned.len();
+        let mut magnitude: u128 = 0;
+        let mut any_digits = false;
+        let mut fraction_digits = None;
+        let mut discarded = (0, false);
+        for (index, character) in unsigned.char_indices() {
+            let digit = match character {
+                '.' if fraction_digits.is_none() => {
+                    fraction_digits = Some(0);
+                    continue;
+                }
+                '0'..='9' => character as u8 - b'0',
+                _ => {
+                    return Err(ParseFixedError::InvalidCharacter {
+                        character,
+                        index: offset + index,
+                    })
+                }
+            };
+            any_digits = true;
+
+            // Digits past the decimal places only decide which way to round
+            match &mut fraction_digits {
+                Some(count) if *count >= DP => {
+                    if *count == DP {
+                        discarded.0 = digit;
+                    } else {
+                        discarded.1 |= digit != 0;
+                    }
+                    *count += 1;
+                    continue;
+                }
+                Some(count) => *count += 1,
+                None => {}
+            }
+            magnitude = magnitude
+                .checked_mul(10)
+                .and_then(|magnitude| magnitude.checked_add(digit as u128))
+                .ok_or(ParseFixedError::OutOfRange)?;
+        }
+        if !any_digits {
+            return Err(ParseFixedError::MissingDigits);
+        }
+
+        let fraction_digits = fraction_digits.unwrap_or(0).min(DP);
+        let (first, rest) = discarded;
+        let half = match first.cmp(&5) {
+            Ordering::Equal if rest => Ordering::Greater,
+            ordering => ordering,
+        };
+        let away = RoundingMode::HalfAwayFromZero.rounds_away(
+            negative,
+            magnitude % 2 == 1,
+            first == 0 && !rest,
+            half,
+        );
+        let units = magnitude
+            .checked_add(away as u128)
+            .and_then(|magnitude| magnitude.checked_mul(powers::ten_u128(DP - fraction_digits)?))
+            .ok_or(ParseFixedError::OutOfRange)?;
+        i128::from_magnitude(negative && units != 0, units)
+            .map(Fixed)
+            .ok_or(ParseFixedError::OutOfRange)
+    }
+}
+
+impl<const DP: u32> Roundable for Fixed<DP> {
+    #[inline]
+    fn round_decimals(self, decimal_places: u32) -> Fixed<DP> {
+        self.saturating_round(
+            -(decimal_places.min(DP) as i32),
+            RoundingMode::HalfAwayFromZero,
+        )
+    }
+
+    #[inline]
+    fn ceil_decimals(self, decimal_places: u32) -> Fixed<DP> {
+        self.saturating_round(-(decimal_places.min(DP) as i32), RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_decimals(self, decimal_places: u32) -> Fixed<DP> {
+        self.saturating_round(-(decimal_places.min(DP) as i32), RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> Fixed<DP> {
+        self.saturating_round(i32::try_from(zeros).unwrap_or(i32::MAX), mode)
+    }
+
+    #[inline]
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Fixed<DP> {
+        let exponent = sf_exponent(&self, sig_figs);
+        self.saturating_round(exponent, mode)
+    }
+
+    #[inline]
+    fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, random: R) -> Fixed<DP> {
+        Fixed(
+            self.0
+                .round_zeros_stochastic(zeros.saturating_add(DP), random),
+        )
+    }
+
+    #[inline]
+    fn round_to_multiple(self, step: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.round_to_multiple(step.0))
+    }
+
+    #[inline]
+    fn ceil_to_multiple(self, step: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.ceil_to_multiple(step.0))
+    }
+
+    #[inline]
+    fn floor_to_multiple(self, step: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.floor_to_multiple(step.0))
+    }
+
+    #[inline]
+    fn try_round_to_multiple(self, step: Fixed<DP>) -> Result<Fixed<DP>, RoundingError> {
+        self.0.try_round_to_multiple(step.0).map(Fixed)
+    }
+
+    #[inline]
+    fn try_ceil_to_multiple(self, step: Fixed<DP>) -> Result<Fixed<DP>, RoundingError> {
+        self.0.try_ceil_to_multiple(step.0).map(Fixed)
+    }
+
+    #[inline]
+    fn try_floor_to_multiple(self, step: Fixed<DP>) -> Result<Fixed<DP>, RoundingError> {
+        self.0.try_floor_to_multiple(step.0).map(Fixed)
+    }
+
+    #[inline]
+    fn quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.quantize(step.0, offset.0))
+    }
+
+    #[inline]
+    fn ceil_quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.ceil_quantize(step.0, offset.0))
+    }
+
+    #[inline]
+    fn floor_quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.floor_quantize(step.0, offset.0))
+    }
+
+    #[inline]
+    fn checked_quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.0.checked_quantize(step.0, offset.0).map(Fixed)
+    }
+
+    #[inline]
+    fn checked_ceil_quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.0.checked_ceil_quantize(step.0, offset.0).map(Fixed)
+    }
+
+    #[inline]
+    fn checked_floor_quantize(self, step: Fixed<DP>, offset: Fixed<DP>) -> Option<Fixed<DP>> {
+        self.0.checked_floor_quantize(step.0, offset.0).map(Fixed)
+    }
+
+    // A power of ten number of units is a power of ten in the fixed point number as well
+
+    #[inline]
+    fn round_pow10(self) -> Fixed<DP> {
+        Fixed(self.0.round_pow10())
+    }
+
+    #[inline]
+    fn ceil_pow10(self) -> Fixed<DP> {
+        Fixed(self.0.ceil_pow10())
+    }
+
+    #[inline]
+    fn floor_pow10(self) -> Fixed<DP> {
+        Fixed(self.0.floor_pow10())
+    }
+
+    #[inline]
+    fn checked_round_pow10(self) -> Option<Fixed<DP>> {
+        self.0.checked_round_pow10().map(Fixed)
+    }
+
+    #[inline]
+    fn checked_ceil_pow10(self) -> Option<Fixed<DP>> {
+        self.0.checked_ceil_pow10().map(Fixed)
+    }
+
+    #[inline]
+    fn checked_floor_pow10(self) -> Option<Fixed<DP>> {
+        self.0.checked_floor_pow10().map(Fixed)
+    }
+
+    #[inline]
+    fn order_of_magnitude(self) -> Option<i32> {
+        self.0.order_of_magnitude().map(|order| order - DP as i32)
+    }
+
+    #[inline]
+    fn count_sig_figs(self) -> u32 {
+        self.0.count_sig_figs()
+    }
+
+    #[inline]
+    fn digits(self) -> Digits {
+        Digits::integer(self.0.unsigned_abs() / Self::SCALE)
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_sf(self, sig_figs: u32) -> String {
+        self.format_sf_notation(sig_figs, Notation::Fixed)
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+        let (negative, magnitude) = self.round_sf(sig_figs).0.to_magnitude();
+        format::significant(magnitude, DP as i32, negative, sig_figs, notation)
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_grouped(
+        self,
+        decimal_places: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String {
+        self.format_locale(decimal_places, decimal_separator, Some(group_separator))
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_locale(
+        self,
+        decimal_places: u32,
+        decimal_separator: char,
+        group_separator: Option<char>,
+    ) -> String {
+        let (negative, magnitude) = self.round_decimals(decimal_places).0.to_magnitude();
+        let dropped = DP.saturating_sub(decimal_places);
+        let power = powers::ten_u128(dropped).expect("power below a u128 fits in one");
+        let fixed = format::fixed_decimal(
+            magnitude / power,
+            (DP - dropped) as i32,
+            negative,
+            decimal_places,
+        );
+        format::group(&fixed, decimal_separator, group_separator)
+    }
+
+    #[inline]
+    fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<Fixed<DP>> {
+        match u32::try_from(exponent as i64 + DP as i64) {
+            Ok(zeros) => integer::round_zeros(self.0, zeros, mode).map(Fixed),
+            Err(_) => Some(self),
+        }
+    }
+
+    #[inline]
+    fn get_digits(&self) -> i32 {
+        if self.0 == 0 {
+            0
+        } else {
+            self.0.get_digits() - DP as i32
+        }
+    }
+
+    #[inline]
+    fn to_decimal(&self) -> Option<(i128, i32)> {
+        Some((self.0, -(DP as i32)))
+    }
+
+    #[inline]
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<Fixed<DP>> {
+        fallback::integer_value(mantissa, exponent.checked_add(DP as i32)?).map(Fixed)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn nearer_above(self, below: Fixed<DP>, above: Fixed<DP>) -> bool {
+        self.0.nearer_above(below.0, above.0)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn tie_at(self, decimal_places: i32) -> bool {
+        self.0.tie_at(decimal_places - DP as i32)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn rounding_error(self, rounded: Fixed<DP>) -> Fixed<DP> {
+        Fixed(self.0.rounding_error(rounded.0))
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_zeros_mode(values: &mut [Fixed<DP>], zeros: u32, mode: RoundingMode) {
+        for value in values {
+            *value = value.round_zeros_mode(zeros, mode);
+        }
+    }
+}
+
+/// Rounds a float to a multiple of `step` units of `10^-decimal_places`, giving the number of
+/// units, or `None` if it isn't finite or doesn't fit in an `i128`
+///
+/// The float is rounded from its shortest decimal representation, so `1.005` is taken as exactly
+/// `1.005` rather than the binary value just below it
+pub(crate) fn round_to_units(
+    number: f64,
+    decimal_places: u32,
+    step: u128,
+    mode: RoundingMode,
+) -> Option<i128> {
+    if !number.is_finite() {
+        return None;
+    }
+    if number == 0.0 {
+        return Some(0);
+    }
+
+    let negative = number < 0.0;
+    let (significand, fraction_digits) = digits::shortest_decimal(number.abs());
+    let steps = match u32::try_from(fraction_digits as i64 - decimal_places as i64) {
+        Ok(dropped) => match powers::ten_u128(dropped).and_then(|power| power.checked_mul(step)) {
+            Some(divisor) => integer::round_steps(significand as u128, negative, divisor, mode),
+            // The significand is less than half of a divisor that doesn't fit in a u128
+            None => mode.rounds_away(negative, false, false, Ordering::Less) as u128,
+        },
+        Err(_) => {
+            let zeros = u32::try_from(decimal_places as i64 - fraction_digits as i64).ok()?;
+            let units = (significand as u128).checked_mul(powers::ten_u128(zeros)?)?;
+            integer::round_steps(units, negative, step, mode)
+        }
+    };
+    i128::from_magnitude(negative, steps.checked_mul(step)?)
+}
+
+mod tests;
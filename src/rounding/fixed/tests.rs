@@ -0,0 +1,162 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use alloc::string::ToString;
+
+#[test]
+fn test_exact_addition() {
+    let sum = Fixed::<1>::from_f64_rounded(0.1) + Fixed::from_f64_rounded(0.2);
+    assert_eq!(sum, Fixed::from_f64_rounded(0.3));
+    assert_eq!(sum.to_f64(), 0.3);
+
+    let mut total = Fixed::<2>::ZERO;
+    for _ in 0..1_000 {
+        total = total + Fixed::from_f64_rounded(0.01);
+    }
+    assert_eq!(total, Fixed::from_units(1_000));
+    assert_eq!(total - Fixed::from_f64_rounded(10.0), Fixed::ZERO);
+}
+
+#[test]
+fn test_multiplication_modes() {
+    let cases = [
+        (0.5, 0.5, 0.2, 0.3),
+        (1.5, 0.5, 0.8, 0.8),
+        (0.5, 2.5, 1.2, 1.3),
+        (-0.5, 0.5, -0.2, -0.2),
+        (-1.5, 0.5, -0.8, -0.7),
+    ];
+    for (a, b, even, up) in cases {
+        let a = Fixed::<1>::from_f64_rounded(a);
+        let b = Fixed::from_f64_rounded(b);
+        assert_eq!(a.mul_mode(b, RoundingMode::HalfEven).to_f64(), even);
+        assert_eq!(a.mul_mode(b, RoundingMode::HalfUp).to_f64(), up);
+    }
+
+    // Multiplying then dividing by the same factor only loses what the rounding discarded
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let a = Fixed::<3>::from_units((state >> 20) as i128 - (1 << 43));
+        let b = Fixed::<3>::from_units((state & 0xf_ffff) as i128 + 1);
+        let product = a.mul_mode(b, RoundingMode::TowardZero);
+        let exact = a.units() * b.units();
+        assert!((exact - product.units() * 1_000).abs() < 1_000);
+        assert_eq!(
+            a.checked_mul_mode(b, RoundingMode::Down),
+            Some(Fixed::from_units(exact.div_euclid(1_000)))
+        );
+        assert_eq!((a * Fixed::from_units(1_000)) / Fixed::from_units(1_000), a);
+    }
+}
+
+#[test]
+fn test_overflow() {
+    let max = Fixed::<2>::MAX;
+    let one = Fixed::<2>::from_units(100);
+    assert_eq!(max.checked_add(Fixed::from_units(1)), None);
+    assert_eq!(Fixed::<2>::MIN.checked_sub(Fixed::from_units(1)), None);
+    // The intermediate product has to fit in a u128, which is most of the range
+    let large = Fixed::<2>::from_units(i128::MAX / 100);
+    assert_eq!(large.checked_mul(one), Some(large));
+    assert_eq!(large.checked_mul(Fixed::from_units(10_100)), None);
+    assert_eq!(max.checked_mul(one), None);
+    assert_eq!(large.checked_div(one), Some(large));
+    assert_eq!(
+        Fixed::<2>::from_units(i128::MAX / 50).checked_div(Fixed::from_units(1)),
+        None
+    );
+    assert_eq!(one.checked_div(Fixed::ZERO), None);
+    assert_eq!(Fixed::<2>::MIN.checked_neg(), None);
+
+    assert_eq!(
+        Fixed::<2>::checked_from_f64(1e37, RoundingMode::HalfEven),
+        None
+    );
+    assert_eq!(Fixed::<2>::from_f64_rounded(-1e37), Fixed::MIN);
+    assert_eq!(
+        Fixed::<0>::checked_from_f64(f64::INFINITY, RoundingMode::HalfEven),
+        None
+    );
+    assert_eq!(
+        Fixed::<38>::checked_from_f64(2.0, RoundingMode::HalfEven),
+        None
+    );
+    assert_eq!(
+        Fixed::<38>::from_f64_rounded(1.5).units(),
+        15 * 10_i128.pow(37)
+    );
+
+    // Rounding saturates like an integer instead of overflowing
+    assert_eq!(max.round_sf(1), max);
+    assert_eq!(max.checked_round_sf(1), None);
+    assert_eq!(
+        Fixed::<2>::from_units(-12_345).round_zeros(1),
+        Fixed::from_units(-12_000)
+    );
+
+    assert!(std::panic::catch_unwind(|| max + Fixed::from_units(1)).is_err());
+    assert!(std::panic::catch_unwind(|| one / Fixed::ZERO).is_err());
+}
+
+#[test]
+fn test_string_round_trip() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let units = (state as i64 as i128) * (state >> 32) as i128;
+        let fixed = Fixed::<5>::from_units(units);
+        assert_eq!(fixed.to_string().parse(), Ok(fixed));
+
+        let whole = Fixed::<0>::from_units(units);
+        assert_eq!(whole.to_string().parse(), Ok(whole));
+    }
+
+    for fixed in [Fixed::<4>::MIN, Fixed::MAX, Fixed::ZERO] {
+        assert_eq!(fixed.to_string().parse(), Ok(fixed));
+    }
+    assert_eq!(Fixed::<3>::from_units(-5).to_string(), "-0.005");
+    assert_eq!(Fixed::<0>::from_units(42).to_string(), "42");
+    assert_eq!("+.5".parse(), Ok(Fixed::<2>::from_units(50)));
+    assert_eq!("7.".parse(), Ok(Fixed::<2>::from_units(700)));
+    assert_eq!("-0.004".parse(), Ok(Fixed::<2>::ZERO));
+    assert_eq!("-0.005".parse(), Ok(Fixed::<2>::from_units(-1)));
+    assert_eq!("2.34500001".parse(), Ok(Fixed::<2>::from_units(235)));
+    assert_eq!(
+        "1.5.".parse::<Fixed<2>>(),
+        Err(ParseFixedError::InvalidCharacter {
+            character: '.',
+            index: 3
+        })
+    );
+}
+
+#[test]
+fn test_decimal_bridge() {
+    assert_eq!(
+        Fixed::<2>::from_units(-1_234).to_decimal(),
+        Some((-1_234, -2))
+    );
+    assert_eq!(
+        Fixed::<2>::from_decimal(-1_234, -2),
+        Some(Fixed::from_units(-1_234))
+    );
+    assert_eq!(
+        Fixed::<2>::from_decimal(5, 1),
+        Some(Fixed::from_units(5_000))
+    );
+    assert_eq!(
+        Fixed::<2>::from_decimal(1_234_000, -5),
+        Some(Fixed::from_units(1_234))
+    );
+    assert_eq!(Fixed::<2>::from_decimal(1_234_567, -5), None);
+    assert_eq!(Fixed::<2>::from_decimal(i128::MAX, 1), None);
+    assert_eq!(Fixed::<0>::from_decimal(7, i32::MAX), None);
+}
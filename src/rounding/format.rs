@@ -0,0 +1,261 @@
+use core::fmt::{Display, LowerExp};
+use core::str::FromStr;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use super::{digits, powers};
+
+/// How [`format_sf_notation`](super::format_sf_notation) lays out the digits of a number
+///
+/// ```rust
+/// use common_math::rounding::{format_sf_notation, Notation};
+///
+/// assert_eq!(format_sf_notation(123_456, 4, Notation::Fixed), "123500");
+/// assert_eq!(format_sf_notation(123_456, 4, Notation::Scientific), "1.235e5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Notation {
+    /// Plain digits with a decimal point, such as `0.00340` or `123500`. Zeros that pad an integer
+    /// part with more digits than the significant figures are ambiguous, as `123500` could have
+    /// 4, 5 or 6 significant figures
+    #[default]
+    Fixed,
+    /// A single digit before the decimal point and a power of ten, such as `3.40e-3` or `1.235e5`,
+    /// which always shows exactly the significant figures
+    Scientific,
+    /// One to three digits before the decimal point and a power of ten that is a multiple of
+    /// three, such as `3.40e-3` or `123.5e3`. Like fixed notation a mantissa with fewer significant
+    /// figures than its integer digits is padded with zeros, so `0.00047` to 2 significant figures
+    /// is `470e-6`
+    Engineering,
+}
+
+/// Renders the magnitude of a finite, rounded float with exactly the given number of digits after
+/// the decimal point
+///
+/// The digits come from the shortest decimal representation that round trips, padded with zeros,
+/// so the string parses back to the same float
+#[inline]
+pub(crate) fn fixed<T: Display + LowerExp + FromStr + PartialEq + Copy>(
+    magnitude: T,
+    negative: bool,
+    decimal_places: u32,
+) -> String {
+    let (mut significand, mut fraction_digits) = digits::shortest_decimal(magnitude);
+    if fraction_digits > decimal_places as i32 {
+        significand = nearest_decimal(magnitude, decimal_places);
+        fraction_digits = decimal_places as i32;
+    }
+
+    fixed_decimal(significand as u128, fraction_digits, negative, decimal_places)
+}
+
+/// Renders a decimal `significand * 10^-fraction_digits` with exactly the given number of digits
+/// after the decimal point, which must be at least the fraction digits
+#[inline]
+pub(crate) fn fixed_decimal(
+    significand: u128,
+    fraction_digits: i32,
+    negative: bool,
+    decimal_places: u32,
+) -> String {
+    let digits = significand.to_string();
+    let (integer, fraction) = if fraction_digits <= 0 {
+        let zeros = "0".repeat(fraction_digits.unsigned_abs() as usize);
+        (digits + &zeros, String::new())
+    } else if digits.len() > fraction_digits as usize {
+        let (integer, fraction) = digits.split_at(digits.len() - fraction_digits as usize);
+        (integer.to_string(), fraction.to_string())
+    } else {
+        let zeros = "0".repeat(fraction_digits as usize - digits.len());
+        ("0".to_string(), zeros + &digits)
+    };
+
+    let sign = if negative { "-" } else { "" };
+    if decimal_places == 0 {
+        format!("{sign}{integer}")
+    } else {
+        format!(
+            "{sign}{integer}.{fraction:0<width$}",
+            width = decimal_places as usize
+        )
+    }
+}
+
+/// Finds the digits of a decimal with the given number of decimal places whose closest float
+/// is the magnitude, for floats that were left as they are because they have more digits than
+/// the type can hold
+///
+/// The decimal closest to the exact binary value can be a step away from the one the float was
+/// rounded to, so the steps either side of it are checked as well
+#[inline]
+fn nearest_decimal<T: Display + FromStr + PartialEq>(magnitude: T, decimal_places: u32) -> u64 {
+    // The float has digits past the decimal places, so the digits up to them fit in a u64
+    let closest = format!("{:.*}", decimal_places as usize, magnitude)
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .fold(0, |digits, digit| digits * 10 + (digit - b'0') as u64);
+
+    [closest, closest.saturating_sub(1), closest + 1]
+        .into_iter()
+        .find(|&digits| digits::scale::<_, T>(digits, -(decimal_places as i64)) == magnitude)
+        .unwrap_or(closest)
+}
+
+/// Swaps the decimal point of a number rendered in fixed notation for the given separator and
+/// splits its integer digits into groups of three if there is a group separator. Text that isn't
+/// a number, like `NaN` or `inf`, is returned as it is
+#[inline]
+pub(crate) fn group(fixed: &str, decimal_separator: char, group_separator: Option<char>) -> String {
+    let (sign, unsigned) = match fixed.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", fixed),
+    };
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    if integer.is_empty() || !integer.bytes().all(|byte| byte.is_ascii_digit()) {
+        return fixed.to_string();
+    }
+
+    let mut grouped = String::from(sign);
+    for (index, digit) in integer.chars().enumerate() {
+        match group_separator {
+            Some(separator) if index > 0 && (integer.len() - index) % 3 == 0 => {
+                grouped.push(separator)
+            }
+            _ => {}
+        }
+        grouped.push(digit);
+    }
+    if let Some(fraction) = fraction {
+        grouped.push(decimal_separator);
+        grouped.push_str(fraction);
+    }
+    grouped
+}
+
+/// Renders a rounded decimal `significand * 10^-fraction_digits` with exactly the given number of
+/// significant figures, padding it with zeros where it has fewer
+///
+/// Zero has a single significant figure before the decimal point and the rest after it
+#[inline]
+pub(crate) fn significant(
+    mut significand: u128,
+    mut fraction_digits: i32,
+    negative: bool,
+    sig_figs: u32,
+    notation: Notation,
+) -> String {
+    let sign = if negative { "-" } else { "" };
+    if significand == 0 {
+        let fraction = "0".repeat(sig_figs.saturating_sub(1) as usize);
+        let point = if fraction.is_empty() { "" } else { "." };
+        let exponent = if notation == Notation::Fixed {
+            ""
+        } else {
+            "e0"
+        };
+        return format!("{sign}0{point}{fraction}{exponent}");
+    }
+
+    // Scaling to large powers of ten can leave the rounded float an ulp from the closest float to
+    // its significant figures, so any digits past them are rounded off the decimal
+    let length = significand.ilog10() + 1;
+    if length > sig_figs && sig_figs > 0 {
+        let power = powers::ten_u128(length - sig_figs).expect("power below a u128 fits in one");
+        let (quotient, remainder) = (significand / power, significand % power);
+        significand = if remainder >= power - remainder {
+            quotient + 1
+        } else {
+            quotient
+        };
+        fraction_digits -= (length - sig_figs) as i32;
+    }
+
+    while significand.is_multiple_of(10) {
+        significand /= 10;
+        fraction_digits -= 1;
+    }
+    significant_digits(
+        significand.to_string(),
+        fraction_digits,
+        negative,
+        sig_figs,
+        notation,
+    )
+}
+
+/// Renders the non zero digits of a rounded decimal `digits * 10^-fraction_digits`, which has no
+/// trailing zeros and at most the given number of significant figures, like [`significant`]
+#[inline]
+pub(crate) fn significant_digits(
+    mut digits: String,
+    fraction_digits: i32,
+    negative: bool,
+    sig_figs: u32,
+    notation: Notation,
+) -> String {
+    let sign = if negative { "-" } else { "" };
+    let exponent = digits.len() as i32 - fraction_digits - 1;
+    if digits.len() < sig_figs as usize {
+        digits += &"0".repeat(sig_figs as usize - digits.len());
+    }
+
+    // The exponent is taken from the rounded number, so rounding up to the next power of ten
+    // has already moved it along
+    let shown_exponent = match notation {
+        Notation::Fixed => 0,
+        Notation::Scientific => exponent,
+        Notation::Engineering => exponent.div_euclid(3) * 3,
+    };
+    let integer_digits = exponent - shown_exponent + 1;
+    let mantissa = if integer_digits <= 0 {
+        let zeros = "0".repeat(integer_digits.unsigned_abs() as usize);
+        format!("{sign}0.{zeros}{digits}")
+    } else if integer_digits as usize >= digits.len() {
+        let zeros = "0".repeat(integer_digits as usize - digits.len());
+        format!("{sign}{digits}{zeros}")
+    } else {
+        let (integer, fraction) = digits.split_at(integer_digits as usize);
+        format!("{sign}{integer}.{fraction}")
+    };
+
+    if notation == Notation::Fixed {
+        mantissa
+    } else {
+        format!("{mantissa}e{shown_exponent}")
+    }
+}
+
+/// Replaces the `e` exponent of a number formatted by [`significant`] with `× 10` and the
+/// exponent in superscript digits
+#[inline]
+pub(crate) fn superscript(formatted: &str) -> String {
+    let Some((mantissa, exponent)) = formatted.split_once('e') else {
+        return formatted.to_string();
+    };
+
+    let exponent: String = exponent
+        .chars()
+        .map(|c| match c {
+            '-' => '⁻',
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            _ => c,
+        })
+        .collect();
+    format!("{mantissa} × 10{exponent}")
+}
@@ -0,0 +1,273 @@
+//! Rounding for numbers that are only known to implement the [`num_traits`] traits
+//!
+//! Blanket implementations of [`Float`] and [`Roundable`](super::Roundable) would overlap with
+//! the ones for the primitives, so generic code calls these functions instead
+
+use num_traits::{FromPrimitive, NumCast, PrimInt};
+
+use super::{integer, Float, RoundingMode};
+
+/// Rounds the number to the given number of decimal places
+///
+/// Types with no more precision than an f32 are rounded as an f32 and the rest as an f64, so
+/// `f32` and `f64` give the same result as [`round`](super::round). Types with more precision
+/// than an f64 are rounded to the precision of an f64
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_generic;
+///
+/// fn mean<T: num_traits::Float + num_traits::FromPrimitive>(values: &[T]) -> T {
+///     let sum = values.iter().fold(T::zero(), |sum, &value| sum + value);
+///     round_dp_generic(sum / T::from_usize(values.len()).unwrap(), 2)
+/// }
+///
+/// assert_eq!(mean(&[1.0_f64, 2.0, 2.0]), 1.67);
+/// assert_eq!(mean(&[1.0_f32, 2.0, 2.0]), 1.67);
+/// ```
+#[inline]
+pub fn round_dp_generic<T: num_traits::Float + FromPrimitive>(number: T, decimal_places: u32) -> T {
+    round_dp_mode_generic(number, decimal_places, RoundingMode::HalfAwayFromZero)
+}
+
+/// Rounds the number up to the given number of decimal places
+///
+/// See [`round_dp_generic`] for how the number is rounded
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_dp_generic;
+///
+/// assert_eq!(ceil_dp_generic(1.201_f64, 2), 1.21);
+/// assert_eq!(ceil_dp_generic(-1.209_f32, 2), -1.2);
+/// ```
+#[inline]
+pub fn ceil_dp_generic<T: num_traits::Float + FromPrimitive>(number: T, decimal_places: u32) -> T {
+    round_dp_mode_generic(number, decimal_places, RoundingMode::Up)
+}
+
+/// Rounds the number down to the given number of decimal places
+///
+/// See [`round_dp_generic`] for how the number is rounded
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_dp_generic;
+///
+/// assert_eq!(floor_dp_generic(1.209_f64, 2), 1.2);
+/// assert_eq!(floor_dp_generic(-1.201_f32, 2), -1.21);
+/// ```
+#[inline]
+pub fn floor_dp_generic<T: num_traits::Float + FromPrimitive>(number: T, decimal_places: u32) -> T {
+    round_dp_mode_generic(number, decimal_places, RoundingMode::Down)
+}
+
+/// Rounds the number to the given number of decimal places using the given rounding mode
+///
+/// See [`round_dp_generic`] for how the number is rounded
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_dp_mode_generic, RoundingMode};
+///
+/// assert_eq!(round_dp_mode_generic(0.125_f64, 2, RoundingMode::HalfEven), 0.12);
+/// assert_eq!(round_dp_mode_generic(0.135_f32, 2, RoundingMode::HalfEven), 0.14);
+/// ```
+#[inline]
+pub fn round_dp_mode_generic<T: num_traits::Float + FromPrimitive>(
+    number: T,
+    decimal_places: u32,
+    mode: RoundingMode,
+) -> T {
+    let single = T::epsilon()
+        .to_f64()
+        .is_some_and(|epsilon| epsilon >= f32::EPSILON as f64);
+    let rounded = if single {
+        number
+            .to_f32()
+            .and_then(|number| T::from_f32(number.round_dp_mode(decimal_places, mode)))
+    } else {
+        number
+            .to_f64()
+            .and_then(|number| T::from_f64(number.round_dp_mode(decimal_places, mode)))
+    };
+    rounded.unwrap_or(number)
+}
+
+/// Rounds the integer to the given number of zeros,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// Gives the same result as [`round_zeros`](super::round_zeros) for the primitive integers
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_generic;
+///
+/// fn bucket<T: num_traits::PrimInt>(value: T) -> T {
+///     round_zeros_generic(value, 2)
+/// }
+///
+/// assert_eq!(bucket(1250_u32), 1300);
+/// assert_eq!(bucket(-1249_i64), -1200);
+/// assert_eq!(bucket(250_u8), u8::MAX);
+/// ```
+#[inline]
+pub fn round_zeros_generic<T: PrimInt>(number: T, zeros: u32) -> T {
+    round_zeros_mode_generic(number, zeros, RoundingMode::HalfAwayFromZero)
+}
+
+/// Rounds the integer up to the given number of zeros,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_zeros_generic;
+///
+/// assert_eq!(ceil_zeros_generic(1201_u32, 2), 1300);
+/// assert_eq!(ceil_zeros_generic(-1299_i16, 2), -1200);
+/// ```
+#[inline]
+pub fn ceil_zeros_generic<T: PrimInt>(number: T, zeros: u32) -> T {
+    round_zeros_mode_generic(number, zeros, RoundingMode::Up)
+}
+
+/// Rounds the integer down to the given number of zeros,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_zeros_generic;
+///
+/// assert_eq!(floor_zeros_generic(1299_u32, 2), 1200);
+/// assert_eq!(floor_zeros_generic(-1201_i16, 2), -1300);
+/// ```
+#[inline]
+pub fn floor_zeros_generic<T: PrimInt>(number: T, zeros: u32) -> T {
+    round_zeros_mode_generic(number, zeros, RoundingMode::Down)
+}
+
+/// Rounds the integer to the given number of zeros using the given rounding mode,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_zeros_mode_generic, RoundingMode};
+///
+/// assert_eq!(round_zeros_mode_generic(1250_u32, 2, RoundingMode::HalfEven), 1200);
+/// ```
+#[inline]
+pub fn round_zeros_mode_generic<T: PrimInt>(number: T, zeros: u32, mode: RoundingMode) -> T {
+    let (negative, magnitude) = to_magnitude(number);
+    let rounded = integer::round_magnitude(magnitude, negative, zeros, mode)
+        .and_then(|magnitude| from_magnitude(negative, magnitude));
+    match rounded {
+        Some(rounded) => rounded,
+        None if negative => T::min_value(),
+        None => T::max_value(),
+    }
+}
+
+/// Rounds the integer to the given number of significant figures,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// Gives the same result as [`round_sf`](super::round_sf) for the primitive integers
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_generic;
+///
+/// assert_eq!(round_sf_generic(123_456_u32, 2), 120_000);
+/// assert_eq!(round_sf_generic(-125_i8, 2), -128);
+/// ```
+#[inline]
+pub fn round_sf_generic<T: PrimInt>(number: T, sig_figs: u32) -> T {
+    round_sf_mode_generic(number, sig_figs, RoundingMode::HalfAwayFromZero)
+}
+
+/// Rounds the integer up to the given number of significant figures,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_sf_generic;
+///
+/// assert_eq!(ceil_sf_generic(1201_u32, 2), 1300);
+/// ```
+#[inline]
+pub fn ceil_sf_generic<T: PrimInt>(number: T, sig_figs: u32) -> T {
+    round_sf_mode_generic(number, sig_figs, RoundingMode::Up)
+}
+
+/// Rounds the integer down to the given number of significant figures,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_sf_generic;
+///
+/// assert_eq!(floor_sf_generic(1299_u32, 2), 1200);
+/// ```
+#[inline]
+pub fn floor_sf_generic<T: PrimInt>(number: T, sig_figs: u32) -> T {
+    round_sf_mode_generic(number, sig_figs, RoundingMode::Down)
+}
+
+/// Rounds the integer to the given number of significant figures using the given rounding mode,
+/// saturating at the bounds of the type when the result doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_sf_mode_generic, RoundingMode};
+///
+/// assert_eq!(round_sf_mode_generic(1250_u32, 2, RoundingMode::HalfEven), 1200);
+/// ```
+#[inline]
+pub fn round_sf_mode_generic<T: PrimInt>(number: T, sig_figs: u32, mode: RoundingMode) -> T {
+    let (_, magnitude) = to_magnitude(number);
+    let digits = magnitude.checked_ilog10().map_or(1, |digits| digits + 1);
+    if sig_figs >= digits {
+        return number;
+    }
+
+    round_zeros_mode_generic(number, digits - sig_figs, mode)
+}
+
+/// Splits a primitive integer into whether it is negative and its magnitude
+#[inline]
+fn to_magnitude<T: PrimInt>(number: T) -> (bool, u128) {
+    if number < T::zero() {
+        let number = number.to_i128().expect("primitive integer fits in an i128");
+        (true, number.unsigned_abs())
+    } else {
+        (
+            false,
+            number.to_u128().expect("primitive integer fits in a u128"),
+        )
+    }
+}
+
+/// Rebuilds a primitive integer from a sign and magnitude, `None` if it doesn't fit in the type
+#[inline]
+fn from_magnitude<T: PrimInt>(negative: bool, magnitude: u128) -> Option<T> {
+    if negative && magnitude != 0 {
+        0_i128
+            .checked_sub_unsigned(magnitude)
+            .and_then(<T as NumCast>::from)
+    } else {
+        <T as NumCast>::from(magnitude)
+    }
+}
+
+mod tests;
@@ -0,0 +1,120 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::Roundable;
+
+#[cfg(test)]
+use half::f16;
+
+#[test]
+fn test_floats_match_primitives() {
+    let mut state = 0x853c_49e6_748f_ea9b_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let number = f64::from_bits(state) % 1e6;
+        if !number.is_finite() {
+            continue;
+        }
+        let decimal_places = (state >> 60) as u32;
+
+        assert_eq!(
+            round_dp_generic(number, decimal_places).to_bits(),
+            number.round_dp(decimal_places).to_bits()
+        );
+        assert_eq!(
+            ceil_dp_generic(number as f32, decimal_places).to_bits(),
+            (number as f32).ceil_dp(decimal_places).to_bits()
+        );
+        assert_eq!(
+            floor_dp_generic(number, decimal_places).to_bits(),
+            number.floor_dp(decimal_places).to_bits()
+        );
+        for mode in [RoundingMode::HalfEven, RoundingMode::TowardZero] {
+            assert_eq!(
+                round_dp_mode_generic(number as f32, decimal_places, mode).to_bits(),
+                (number as f32)
+                    .round_dp_mode(decimal_places, mode)
+                    .to_bits()
+            );
+        }
+    }
+
+    // Rounded as an f32 rather than widened, so the f32 closest to 1.15 rounds up
+    assert_eq!(round_dp_generic(1.15_f32, 1), 1.2);
+    assert!(round_dp_generic(f64::NAN, 2).is_nan());
+    assert_eq!(round_dp_generic(f64::INFINITY, 2), f64::INFINITY);
+    assert_eq!(
+        round_dp_generic(-0.001_f64, 2).to_bits(),
+        (-0.0_f64).to_bits()
+    );
+}
+
+#[test]
+fn test_half_precision() {
+    let number = f16::from_f32(1.2345);
+    assert_eq!(round_dp_generic(number, 2), f16::from_f32(1.23));
+    assert_eq!(ceil_dp_generic(number, 1), f16::from_f32(1.3));
+    assert_eq!(floor_dp_generic(-number, 1), f16::from_f32(-1.3));
+    assert_eq!(round_dp_generic(f16::MAX, 0), f16::MAX);
+    assert!(round_dp_generic(f16::NAN, 1).is_nan());
+
+    fn total<T: num_traits::Float + FromPrimitive>(values: &[T]) -> T {
+        values
+            .iter()
+            .fold(T::zero(), |sum, &value| sum + round_dp_generic(value, 1))
+    }
+    assert_eq!(total(&[f16::from_f32(0.26), f16::from_f32(0.74)]), f16::ONE);
+    assert_eq!(total(&[0.26_f64, 0.74]), 1.0);
+}
+
+#[test]
+fn test_integers_match_primitives() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let places = (state >> 59) as u32;
+        let mode = [
+            RoundingMode::HalfAwayFromZero,
+            RoundingMode::HalfEven,
+            RoundingMode::Up,
+            RoundingMode::Down,
+        ][(state % 4) as usize];
+
+        let wide = state as i64;
+        assert_eq!(
+            round_zeros_mode_generic(wide, places, mode),
+            wide.round_zeros_mode(places, mode)
+        );
+        assert_eq!(
+            round_sf_mode_generic(wide, places, mode),
+            wide.round_sf_mode(places, mode)
+        );
+        let narrow = state as u8;
+        assert_eq!(
+            round_zeros_mode_generic(narrow, places, mode),
+            narrow.round_zeros_mode(places, mode)
+        );
+        let signed = state as i8;
+        assert_eq!(
+            round_sf_mode_generic(signed, places, mode),
+            signed.round_sf_mode(places, mode)
+        );
+        let huge = (state as u128) << 64 | state as u128;
+        assert_eq!(
+            round_sf_mode_generic(huge, places, mode),
+            huge.round_sf_mode(places, mode)
+        );
+    }
+
+    assert_eq!(round_zeros_generic(i128::MIN, 1), i128::MIN);
+    assert_eq!(ceil_zeros_generic(u128::MAX, 1), u128::MAX);
+    assert_eq!(floor_zeros_generic(-1_i32, 40), i32::MIN);
+    assert_eq!(ceil_sf_generic(-125_i8, 1), -100);
+    assert_eq!(floor_sf_generic(125_usize, 1), 100);
+    assert_eq!(round_sf_generic(0_u16, 3), 0);
+}
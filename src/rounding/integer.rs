@@ -0,0 +1,452 @@
+use core::cmp::Ordering;
+
+use super::{powers, Digits, RoundingMode};
+
+/// Primitive integers that can be rounded exactly by splitting them into a sign and a magnitude
+pub(crate) trait Integer: Copy + PartialEq {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Splits the integer into whether it is negative and its magnitude
+    fn to_magnitude(self) -> (bool, u128);
+
+    /// Rebuilds an integer from a sign and magnitude, `None` if it doesn't fit in the type
+    fn from_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+
+    /// Rebuilds an integer from a sign and magnitude, wrapping around the bounds of the type
+    fn from_magnitude_wrapping(negative: bool, magnitude: u128) -> Self;
+}
+
+macro_rules! impl_integer {
+    (signed: $($signed:ty),*; unsigned: $($unsigned:ty),*) => {
+        $(
+            impl Integer for $signed {
+                const MIN: Self = <$signed>::MIN;
+                const MAX: Self = <$signed>::MAX;
+
+                #[inline]
+                fn to_magnitude(self) -> (bool, u128) {
+                    (self < 0, self.unsigned_abs() as u128)
+                }
+
+                #[inline]
+                fn from_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    if !negative {
+                        <$signed>::try_from(magnitude).ok()
+                    } else if magnitude == <$signed>::MIN.unsigned_abs() as u128 {
+                        Some(<$signed>::MIN)
+                    } else {
+                        <$signed>::try_from(magnitude).ok().map(|magnitude| -magnitude)
+                    }
+                }
+
+                #[inline]
+                fn from_magnitude_wrapping(negative: bool, magnitude: u128) -> Self {
+                    let wrapped = magnitude as $signed;
+                    if negative {
+                        wrapped.wrapping_neg()
+                    } else {
+                        wrapped
+                    }
+                }
+            }
+        )*
+        $(
+            impl Integer for $unsigned {
+                const MIN: Self = <$unsigned>::MIN;
+                const MAX: Self = <$unsigned>::MAX;
+
+                #[inline]
+                fn to_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+
+                #[inline]
+                fn from_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    if negative && magnitude != 0 {
+                        None
+                    } else {
+                        <$unsigned>::try_from(magnitude).ok()
+                    }
+                }
+
+                #[inline]
+                fn from_magnitude_wrapping(negative: bool, magnitude: u128) -> Self {
+                    let wrapped = magnitude as $unsigned;
+                    if negative {
+                        wrapped.wrapping_neg()
+                    } else {
+                        wrapped
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(signed: i8, i16, i32, i64, i128, isize; unsigned: u8, u16, u32, u64, u128, usize);
+
+/// Rounds a magnitude to a multiple of `10^zeros`, `None` if the result doesn't fit in a u128
+#[inline]
+pub(crate) const fn round_magnitude(
+    magnitude: u128,
+    negative: bool,
+    zeros: u32,
+    mode: RoundingMode,
+) -> Option<u128> {
+    match powers::ten_u128(zeros) {
+        Some(power) => round_to_step(magnitude, negative, power, mode),
+        None => {
+            // Every u128 is less than half of a power of ten that doesn't fit in a u128
+            let away = mode.rounds_away(negative, false, magnitude == 0, Ordering::Less);
+            if away {
+                None
+            } else {
+                Some(0)
+            }
+        }
+    }
+}
+
+/// Rounds a magnitude to a multiple of a non zero step, `None` if the result doesn't fit in a u128
+#[inline]
+const fn round_to_step(
+    magnitude: u128,
+    negative: bool,
+    step: u128,
+    mode: RoundingMode,
+) -> Option<u128> {
+    round_steps(magnitude, negative, step, mode).checked_mul(step)
+}
+
+/// Rounds a magnitude to a whole number of non zero steps, giving the number of steps
+#[inline]
+pub(crate) const fn round_steps(magnitude: u128, negative: bool, step: u128, mode: RoundingMode) -> u128 {
+    let quotient = magnitude / step;
+    let remainder = magnitude % step;
+    let discarded = if remainder < step - remainder {
+        Ordering::Less
+    } else if remainder == step - remainder {
+        Ordering::Equal
+    } else {
+        Ordering::Greater
+    };
+    let away = mode.rounds_away(negative, quotient % 2 == 1, remainder == 0, discarded);
+
+    // Only a step of one can have a quotient of u128::MAX, which never rounds away
+    quotient + away as u128
+}
+
+/// Counts the digits of an integer, zero has a single digit
+#[inline]
+pub(crate) fn digit_count<T: Integer>(number: T) -> u32 {
+    let (_, magnitude) = number.to_magnitude();
+    magnitude.checked_ilog10().map_or(1, |digits| digits + 1)
+}
+
+/// The digits of an integer, most significant first
+#[inline]
+pub(crate) fn digits<T: Integer>(number: T) -> Digits {
+    let (_, magnitude) = number.to_magnitude();
+    Digits::integer(magnitude)
+}
+
+/// Counts the digits of an integer from the first to the last non zero digit, zero has none
+#[inline]
+pub(crate) fn sig_figs<T: Integer>(number: T) -> u32 {
+    let (_, mut magnitude) = number.to_magnitude();
+    if magnitude == 0 {
+        return 0;
+    }
+
+    let mut trailing_zeros = 0;
+    while magnitude % 10 == 0 {
+        magnitude /= 10;
+        trailing_zeros += 1;
+    }
+    digit_count(number) - trailing_zeros
+}
+
+/// Whether an integer is exactly halfway between two multiples of `10^zeros`
+#[inline]
+pub(crate) fn is_tie<T: Integer>(number: T, zeros: u32) -> bool {
+    let (_, magnitude) = number.to_magnitude();
+    powers::ten_u128(zeros).is_some_and(|power| magnitude % power == power / 2)
+}
+
+/// Rounds an integer to the given number of zeros, `None` if the result doesn't fit in the type
+#[inline]
+pub(crate) fn round_zeros<T: Integer>(number: T, zeros: u32, mode: RoundingMode) -> Option<T> {
+    let (negative, magnitude) = number.to_magnitude();
+    if magnitude == 0 {
+        return Some(number);
+    }
+
+    round_magnitude(magnitude, negative, zeros, mode)
+        .and_then(|magnitude| T::from_magnitude(negative, magnitude))
+}
+
+/// Rounds an integer to the given number of significant figures, `None` if the result doesn't fit in the type
+#[inline]
+pub(crate) fn round_sf<T: Integer>(number: T, sig_figs: u32, mode: RoundingMode) -> Option<T> {
+    let digits = digit_count(number);
+    if sig_figs >= digits {
+        return Some(number);
+    }
+
+    round_zeros(number, digits - sig_figs, mode)
+}
+
+/// Rounds an integer to the given number of zeros, wrapping around the bounds of the type when
+/// the result doesn't fit
+#[inline]
+pub(crate) fn wrapping_round_zeros<T: Integer>(number: T, zeros: u32, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    // The only result that doesn't fit in a u128 is a power of ten past it, which wraps the same
+    // way once it has wrapped to a u128
+    let rounded = round_magnitude(magnitude, negative, zeros, mode)
+        .unwrap_or_else(|| 10_u128.wrapping_pow(zeros));
+    T::from_magnitude_wrapping(negative, rounded)
+}
+
+/// Rounds an integer to the given number of significant figures, wrapping around the bounds of
+/// the type when the result doesn't fit
+#[inline]
+pub(crate) fn wrapping_round_sf<T: Integer>(number: T, sig_figs: u32, mode: RoundingMode) -> T {
+    let digits = digit_count(number);
+    if sig_figs >= digits {
+        return number;
+    }
+
+    wrapping_round_zeros(number, digits - sig_figs, mode)
+}
+
+/// How far an integer is from the multiple of `10^zeros` below it to the one above it, from zero
+/// when it is on the multiple below up to but not including one
+#[inline]
+pub(crate) fn fraction_to_zeros<T: Integer>(number: T, zeros: u32) -> f64 {
+    let (negative, magnitude) = number.to_magnitude();
+    match powers::ten_u128(zeros) {
+        Some(power) => {
+            let remainder = magnitude % power;
+            let distance = if negative && remainder != 0 {
+                power - remainder
+            } else {
+                remainder
+            };
+            distance as f64 / power as f64
+        }
+        None => {
+            let fraction = magnitude as f64 / powers::ten_f64(zeros as i32);
+            if negative && magnitude != 0 {
+                1.0 - fraction
+            } else {
+                fraction
+            }
+        }
+    }
+}
+
+/// Rounds an integer to the given number of significant figures as a sign, a significand and
+/// the number of zeros after it, which can be past the bounds of the type where the rounded
+/// integer would saturate
+///
+/// The zeros are only needed for the 128 bit integers, which can round past a u128
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn round_sf_magnitude<T: Integer>(
+    number: T,
+    sig_figs: u32,
+    mode: RoundingMode,
+) -> (bool, u128, u32) {
+    let (negative, magnitude) = number.to_magnitude();
+    let digits = digit_count(number);
+    if sig_figs >= digits {
+        return (negative, magnitude, 0);
+    }
+
+    let zeros = digits - sig_figs;
+    let power = powers::ten_u128(zeros).expect("power below a u128 fits in one");
+    let steps = round_steps(magnitude, negative, power, mode);
+    match steps.checked_mul(power) {
+        Some(rounded) => (negative, rounded, 0),
+        // One less zero always fits as the steps are at most a tenth of a u128 plus one
+        None => (negative, steps * (power / 10), 1),
+    }
+}
+
+/// Rounds the magnitude of an integer to a power of ten keeping its sign, ties between the powers
+/// either side go to the larger one. `None` if the integer is zero or the result doesn't fit in the type
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+#[inline]
+pub(crate) fn power_of_ten<T: Integer>(number: T, mode: RoundingMode) -> Option<T> {
+    let (negative, magnitude) = number.to_magnitude();
+    T::from_magnitude(negative, power_of_ten_magnitude(magnitude, mode)?)
+}
+
+/// Rounds the magnitude of an integer to a power of ten like [`power_of_ten`], wrapping around
+/// the bounds of the type when the result doesn't fit. Zero stays as it is
+#[inline]
+pub(crate) fn wrapping_power_of_ten<T: Integer>(number: T, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    match power_of_ten_magnitude(magnitude, mode) {
+        Some(power) => T::from_magnitude_wrapping(negative, power),
+        None => number,
+    }
+}
+
+/// Rounds a magnitude to a power of ten, `None` if it is zero or the power doesn't fit in a u128
+#[inline]
+fn power_of_ten_magnitude(magnitude: u128, mode: RoundingMode) -> Option<u128> {
+    powers::ten_u128(power_of_ten_exponent(magnitude, mode)?)
+}
+
+/// The exponent of the power of ten a magnitude rounds to, `None` if it is zero
+///
+/// Only `TowardZero`, `AwayFromZero` and `HalfAwayFromZero` are used, the others act like `TowardZero`
+#[inline]
+pub(crate) fn power_of_ten_exponent(magnitude: u128, mode: RoundingMode) -> Option<u32> {
+    let exponent = magnitude.checked_ilog10()?;
+    let below = powers::ten_u128(exponent)?;
+    let (leading, rest) = (magnitude / below, magnitude % below);
+    let above = match mode {
+        _ if magnitude == below => false,
+        RoundingMode::AwayFromZero => true,
+        // At least 5.5 times the power below, compared digit by digit so it can't overflow
+        RoundingMode::HalfAwayFromZero => leading > 5 || leading == 5 && rest >= below - rest,
+        _ => false,
+    };
+
+    Some(exponent + above as u32)
+}
+
+/// Whether an integer can be used as the step to round to a multiple of
+#[inline]
+pub(crate) fn is_valid_step<T: Integer>(step: T) -> bool {
+    let (negative, magnitude) = step.to_magnitude();
+    !negative && magnitude != 0
+}
+
+/// Rounds an integer to a multiple of a positive step, `None` if the result doesn't fit in the type
+#[inline]
+pub(crate) fn round_multiple<T: Integer>(number: T, step: T, mode: RoundingMode) -> Option<T> {
+    let (negative, magnitude) = number.to_magnitude();
+    let (_, step) = step.to_magnitude();
+    round_to_step(magnitude, negative, step, mode)
+        .and_then(|magnitude| T::from_magnitude(negative, magnitude))
+}
+
+/// Rounds an integer to a multiple of a positive step, wrapping around the bounds of the type when
+/// the result doesn't fit
+///
+/// Only used for integers narrower than a u128, whose rounded magnitudes always fit in one
+#[inline]
+pub(crate) fn wrapping_round_multiple<T: Integer>(number: T, step: T, mode: RoundingMode) -> T {
+    let (negative, magnitude) = number.to_magnitude();
+    let (_, step) = step.to_magnitude();
+    let rounded = round_to_step(magnitude, negative, step, mode)
+        .expect("rounded magnitude of a supported integer fits in a u128");
+    T::from_magnitude_wrapping(negative, rounded)
+}
+
+/// Rounds an integer to a point on a grid of a positive step shifted by an offset,
+/// `None` if the result doesn't fit in the type
+#[inline]
+pub(crate) fn quantize<T: Integer>(number: T, step: T, offset: T, mode: RoundingMode) -> Option<T> {
+    let (negative, rounded) = quantize_wide(number, step, offset, mode);
+    T::from_magnitude(negative, rounded?)
+}
+
+/// Rounds an integer to a point on a grid of a positive step shifted by an offset,
+/// saturating at the bound on the side of zero the result is on when it doesn't fit in the type
+///
+/// The result can be on the other side of zero from the number, such as an unsigned number
+/// below the offset rounding down to a point below zero
+#[inline]
+pub(crate) fn saturating_quantize<T: Integer>(
+    number: T,
+    step: T,
+    offset: T,
+    mode: RoundingMode,
+) -> T {
+    let (negative, rounded) = quantize_wide(number, step, offset, mode);
+    match rounded.and_then(|rounded| T::from_magnitude(negative, rounded)) {
+        Some(rounded) => rounded,
+        None if negative => T::MIN,
+        None => T::MAX,
+    }
+}
+
+/// Rounds an integer to a point on a grid of a positive step shifted by an offset, wrapping
+/// around the bounds of the type when the result doesn't fit
+///
+/// Only used for integers narrower than a u128, whose grid points always fit in one
+#[inline]
+pub(crate) fn wrapping_quantize<T: Integer>(
+    number: T,
+    step: T,
+    offset: T,
+    mode: RoundingMode,
+) -> T {
+    let (negative, rounded) = quantize_wide(number, step, offset, mode);
+    T::from_magnitude_wrapping(
+        negative,
+        rounded.expect("grid point next to a narrow integer fits in a u128"),
+    )
+}
+
+/// Rounds an integer to a point on a grid as a sign and magnitude, the magnitude is `None` when
+/// it doesn't fit in a u128 so is past the bounds of every integer on that side of zero
+#[inline]
+fn quantize_wide<T: Integer>(
+    number: T,
+    step: T,
+    offset: T,
+    mode: RoundingMode,
+) -> (bool, Option<u128>) {
+    let (offset_negative, offset) = offset.to_magnitude();
+    let (negative, shifted) = add_signed(number.to_magnitude(), (!offset_negative, offset));
+    let shifted = shifted.expect("distance between two integers of a type fits in a u128");
+    let (_, step) = step.to_magnitude();
+
+    match round_to_step(shifted, negative, step, mode) {
+        Some(rounded) => add_signed((offset_negative, offset), (negative, rounded)),
+        // A point more than a u128 from an integer is past the bounds of its type
+        None => (negative, None),
+    }
+}
+
+/// Adds two signed magnitudes, the magnitude is `None` when it doesn't fit in a u128
+#[inline]
+fn add_signed(
+    (a_negative, a): (bool, u128),
+    (b_negative, b): (bool, u128),
+) -> (bool, Option<u128>) {
+    if a_negative == b_negative {
+        (a_negative, a.checked_add(b))
+    } else if a >= b {
+        (a_negative, Some(a - b))
+    } else {
+        (b_negative, Some(b - a))
+    }
+}
+
+/// The distance between two integers, which always fits in a u128
+#[inline]
+pub(crate) fn distance<T: Integer>(from: T, to: T) -> u128 {
+    let (to_negative, to) = to.to_magnitude();
+    let (_, distance) = add_signed(from.to_magnitude(), (!to_negative, to));
+    distance.expect("distance between two integers of a type fits in a u128")
+}
+
+/// Unwraps a rounded integer, saturating at the bound on the same side of zero as the original number
+#[inline]
+pub(crate) fn saturate<T: Integer>(number: T, rounded: Option<T>) -> T {
+    let (negative, _) = number.to_magnitude();
+    match rounded {
+        Some(rounded) => rounded,
+        None if negative => T::MIN,
+        None => T::MAX,
+    }
+}
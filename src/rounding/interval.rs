@@ -0,0 +1,262 @@
+use super::{Float, RoundingError};
+
+/// Rounds an interval outward to the given number of decimal places, flooring the low end and
+/// ceiling the high end so the rounded interval always contains the original
+///
+/// Ends that already have that many decimal places are kept, so rounding twice gives the same
+/// interval as rounding once. A degenerate interval whose ends are equal is rounded to one that
+/// contains that value, which is degenerate again if the value is on the grid
+///
+/// # Panics
+///
+/// In debug builds, panics if the low end is greater than the high end or either is NaN,
+/// see [`try_round_interval_outward`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_interval_outward;
+///
+/// assert_eq!(round_interval_outward((1.234, 5.678), 1), (1.2, 5.7));
+/// assert_eq!(round_interval_outward((-2.25, -1.05), 1), (-2.3, -1.0));
+/// assert_eq!(round_interval_outward((0.12, 0.14), 1), (0.1, 0.2));
+/// assert_eq!(round_interval_outward((0.5, 0.5), 0), (0.0, 1.0));
+/// ```
+#[inline]
+pub fn round_interval_outward<T>((low, high): (T, T), decimal_places: u32) -> (T, T)
+where
+    T: Float + PartialOrd + Copy,
+{
+    debug_assert!(
+        low <= high,
+        "the low end of the interval must not be above the high end"
+    );
+    (low.floor_dp(decimal_places), high.ceil_dp(decimal_places))
+}
+
+/// Rounds an interval inward to the given number of decimal places, ceiling the low end and
+/// flooring the high end so the rounded interval is always inside the original
+///
+/// If there is no value with that many decimal places inside the interval, both ends become the
+/// low end rounded to the nearest one, so the low end of the result is never above the high end
+///
+/// # Panics
+///
+/// In debug builds, panics if the low end is greater than the high end or either is NaN,
+/// see [`try_round_interval_inward`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_interval_inward;
+///
+/// assert_eq!(round_interval_inward((1.234, 5.678), 1), (1.3, 5.6));
+/// assert_eq!(round_interval_inward((-2.25, 1.05), 1), (-2.2, 1.0));
+/// assert_eq!(round_interval_inward((0.12, 0.14), 1), (0.1, 0.1));
+/// ```
+#[inline]
+pub fn round_interval_inward<T>((low, high): (T, T), decimal_places: u32) -> (T, T)
+where
+    T: Float + PartialOrd + Copy,
+{
+    debug_assert!(
+        low <= high,
+        "the low end of the interval must not be above the high end"
+    );
+    let (ceiled, floored) = (low.ceil_dp(decimal_places), high.floor_dp(decimal_places));
+    if ceiled <= floored {
+        (ceiled, floored)
+    } else {
+        let rounded = low.round_dp(decimal_places);
+        (rounded, rounded)
+    }
+}
+
+/// Rounds an interval outward to the given number of significant figures, flooring the low end
+/// and ceiling the high end so the rounded interval always contains the original
+///
+/// Each end is rounded to its own significant figures, so the ends of an interval straddling zero
+/// can be rounded to different decimal places
+///
+/// # Panics
+///
+/// In debug builds, panics if the low end is greater than the high end or either is NaN,
+/// see [`try_round_interval_outward_sf`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_interval_outward_sf;
+///
+/// assert_eq!(round_interval_outward_sf((1_234.0, 5_678.0), 2), (1_200.0, 5_700.0));
+/// assert_eq!(round_interval_outward_sf((-0.0123, 45.6), 1), (-0.02, 50.0));
+/// ```
+#[inline]
+pub fn round_interval_outward_sf<T>((low, high): (T, T), sig_figs: u32) -> (T, T)
+where
+    T: Float + PartialOrd + Copy,
+{
+    debug_assert!(
+        low <= high,
+        "the low end of the interval must not be above the high end"
+    );
+    (low.floor_sf(sig_figs), high.ceil_sf(sig_figs))
+}
+
+/// Rounds an interval inward to the given number of significant figures, ceiling the low end and
+/// flooring the high end so the rounded interval is always inside the original
+///
+/// If there is no value with that many significant figures inside the interval, both ends become
+/// the low end rounded to the nearest one, so the low end of the result is never above the high end
+///
+/// # Panics
+///
+/// In debug builds, panics if the low end is greater than the high end or either is NaN,
+/// see [`try_round_interval_inward_sf`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_interval_inward_sf;
+///
+/// assert_eq!(round_interval_inward_sf((1_234.0, 5_678.0), 2), (1_300.0, 5_600.0));
+/// assert_eq!(round_interval_inward_sf((1_234.0, 1_256.0), 2), (1_200.0, 1_200.0));
+/// ```
+#[inline]
+pub fn round_interval_inward_sf<T>((low, high): (T, T), sig_figs: u32) -> (T, T)
+where
+    T: Float + PartialOrd + Copy,
+{
+    debug_assert!(
+        low <= high,
+        "the low end of the interval must not be above the high end"
+    );
+    let (ceiled, floored) = (low.ceil_sf(sig_figs), high.floor_sf(sig_figs));
+    if ceiled <= floored {
+        (ceiled, floored)
+    } else {
+        let rounded = low.round_sf(sig_figs);
+        (rounded, rounded)
+    }
+}
+
+/// Rounds an interval outward to the given number of decimal places,
+/// like [`round_interval_outward`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the low end is greater than the high end or either is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_interval_outward, RoundingError};
+///
+/// assert_eq!(try_round_interval_outward((0.15, 0.25), 1), Ok((0.1, 0.3)));
+/// assert_eq!(try_round_interval_outward((0.25, 0.15), 1), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_interval_outward<T>(
+    interval: (T, T),
+    decimal_places: u32,
+) -> Result<(T, T), RoundingError>
+where
+    T: Float + PartialOrd + Copy,
+{
+    check_interval(interval)?;
+    Ok(round_interval_outward(interval, decimal_places))
+}
+
+/// Rounds an interval inward to the given number of decimal places,
+/// like [`round_interval_inward`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the low end is greater than the high end or either is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_interval_inward, RoundingError};
+///
+/// assert_eq!(try_round_interval_inward((0.15, 0.25), 1), Ok((0.2, 0.2)));
+/// assert_eq!(try_round_interval_inward((f64::NAN, 1.0), 1), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_interval_inward<T>(
+    interval: (T, T),
+    decimal_places: u32,
+) -> Result<(T, T), RoundingError>
+where
+    T: Float + PartialOrd + Copy,
+{
+    check_interval(interval)?;
+    Ok(round_interval_inward(interval, decimal_places))
+}
+
+/// Rounds an interval outward to the given number of significant figures,
+/// like [`round_interval_outward_sf`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the low end is greater than the high end or either is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_interval_outward_sf, RoundingError};
+///
+/// assert_eq!(try_round_interval_outward_sf((0.123, 0.456), 1), Ok((0.1, 0.5)));
+/// assert_eq!(try_round_interval_outward_sf((2.0, 1.0), 1), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_interval_outward_sf<T>(
+    interval: (T, T),
+    sig_figs: u32,
+) -> Result<(T, T), RoundingError>
+where
+    T: Float + PartialOrd + Copy,
+{
+    check_interval(interval)?;
+    Ok(round_interval_outward_sf(interval, sig_figs))
+}
+
+/// Rounds an interval inward to the given number of significant figures,
+/// like [`round_interval_inward_sf`]
+///
+/// # Errors
+///
+/// [`RoundingError::InvalidRange`] if the low end is greater than the high end or either is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_interval_inward_sf, RoundingError};
+///
+/// assert_eq!(try_round_interval_inward_sf((0.123, 0.456), 1), Ok((0.2, 0.4)));
+/// assert_eq!(try_round_interval_inward_sf((1.0, f64::NAN), 1), Err(RoundingError::InvalidRange));
+/// ```
+#[inline]
+pub fn try_round_interval_inward_sf<T>(
+    interval: (T, T),
+    sig_figs: u32,
+) -> Result<(T, T), RoundingError>
+where
+    T: Float + PartialOrd + Copy,
+{
+    check_interval(interval)?;
+    Ok(round_interval_inward_sf(interval, sig_figs))
+}
+
+/// Checks the low end of the interval isn't above the high end
+#[inline]
+fn check_interval<T: PartialOrd>((low, high): (T, T)) -> Result<(), RoundingError> {
+    // Written so NaN ends fail the check too
+    if low <= high {
+        Ok(())
+    } else {
+        Err(RoundingError::InvalidRange)
+    }
+}
+
+mod tests;
@@ -0,0 +1,85 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_negative_intervals() {
+    assert_eq!(round_interval_outward((-5.678, -1.234), 1), (-5.7, -1.2));
+    assert_eq!(round_interval_inward((-5.678, -1.234), 1), (-5.6, -1.3));
+    assert_eq!(
+        round_interval_outward_sf((-5_678.0, -1_234.0), 2),
+        (-5_700.0, -1_200.0)
+    );
+    assert_eq!(
+        round_interval_inward_sf((-5_678.0_f32, -1_234.0), 2),
+        (-5_600.0, -1_300.0)
+    );
+}
+
+#[test]
+fn test_straddling_zero() {
+    assert_eq!(round_interval_outward((-0.05, 0.05), 1), (-0.1, 0.1));
+    assert_eq!(round_interval_inward((-0.05, 0.05), 1), (0.0, 0.0));
+    assert_eq!(
+        round_interval_outward_sf((-0.0123, 0.0456), 1),
+        (-0.02, 0.05)
+    );
+    assert_eq!(
+        round_interval_inward_sf((-0.0123, 0.0456), 1),
+        (-0.01, 0.04)
+    );
+}
+
+#[test]
+fn test_on_grid() {
+    // Ends already on the grid are kept, so the outward interval still contains them exactly
+    for (low, high) in [(0.1, 0.3), (-1.5, 2.5), (0.7, 0.7), (-0.0, 0.0)] {
+        assert_eq!(round_interval_outward((low, high), 1), (low, high));
+        assert_eq!(round_interval_inward((low, high), 1), (low, high));
+    }
+    assert_eq!(round_interval_outward((2.0, 2.0), 0), (2.0, 2.0));
+    assert_eq!(round_interval_outward((0.25, 0.25), 1), (0.2, 0.3));
+}
+
+#[test]
+fn test_narrow_intervals() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let low = (state >> 11) as f64 / (1_u64 << 53) as f64 * 20.0 - 10.0;
+        let high = low + (state % 1_000) as f64 * 1e-5;
+        for decimal_places in 0..4 {
+            let (outer_low, outer_high) = round_interval_outward((low, high), decimal_places);
+            assert!(outer_low <= low && high <= outer_high);
+            let (inner_low, inner_high) = round_interval_inward((low, high), decimal_places);
+            assert!(inner_low <= inner_high);
+            if inner_low < inner_high {
+                assert!(low <= inner_low && inner_high <= high);
+            }
+        }
+        for sig_figs in 1..4 {
+            let (outer_low, outer_high) = round_interval_outward_sf((low, high), sig_figs);
+            assert!(outer_low <= low && high <= outer_high);
+            let (inner_low, inner_high) = round_interval_inward_sf((low, high), sig_figs);
+            assert!(inner_low <= inner_high);
+        }
+    }
+}
+
+#[test]
+fn test_invalid_intervals() {
+    assert_eq!(
+        try_round_interval_outward((1.0, 0.0), 1),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(
+        try_round_interval_inward_sf((f32::NAN, 0.0), 1),
+        Err(RoundingError::InvalidRange)
+    );
+    assert_eq!(try_round_interval_outward_sf((0.0, 0.0), 1), Ok((0.0, 0.0)));
+    if cfg!(debug_assertions) {
+        assert!(std::panic::catch_unwind(|| round_interval_inward((1.0, 0.0), 1)).is_err());
+    }
+}
@@ -0,0 +1,366 @@
+use core::iter::FusedIterator;
+
+use super::{Float, Roundable, RoundingMode};
+
+/// Adapters that round every number an iterator yields as it is consumed
+///
+/// Implemented for every iterator, with the decimal place adapters available when it yields a
+/// [`Float`] and the significant figure and zero adapters when it yields any [`Roundable`] number.
+/// Each adapter gives the same results as the matching scalar function
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::RoundIteratorExt;
+///
+/// let data = [1.234_f64, 5.678, -9.876];
+/// assert_eq!(data.iter().copied().round_dp(2).collect::<Vec<_>>(), [1.23, 5.68, -9.88]);
+/// assert_eq!(data.iter().copied().floor_dp(1).collect::<Vec<_>>(), [1.2, 5.6, -9.9]);
+/// assert_eq!((1234..1237).round_sf(2).collect::<Vec<_>>(), [1200, 1200, 1200]);
+/// ```
+pub trait RoundIteratorExt: Iterator + Sized {
+    /// Rounds each number to the given number of decimal places like [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<f64> = [1.005, 2.675].into_iter().round_dp(2).collect();
+    /// assert_eq!(rounded, [1.0, 2.68]);
+    /// ```
+    #[inline]
+    fn round_dp(self, decimal_places: u32) -> RoundDp<Self>
+    where
+        Self::Item: Float,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds each number up to the given number of decimal places like [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<f32> = [1.201_f32, -1.209].into_iter().ceil_dp(2).collect();
+    /// assert_eq!(rounded, [1.21, -1.2]);
+    /// ```
+    #[inline]
+    fn ceil_dp(self, decimal_places: u32) -> RoundDp<Self>
+    where
+        Self::Item: Float,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Up)
+    }
+
+    /// Rounds each number down to the given number of decimal places like [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<f64> = [1.209, -1.201].into_iter().floor_dp(2).collect();
+    /// assert_eq!(rounded, [1.2, -1.21]);
+    /// ```
+    #[inline]
+    fn floor_dp(self, decimal_places: u32) -> RoundDp<Self>
+    where
+        Self::Item: Float,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Down)
+    }
+
+    /// Rounds each number to the given number of decimal places using the given rounding mode
+    /// like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundIteratorExt, RoundingMode};
+    ///
+    /// let rounded: Vec<f64> = [0.125, 0.135]
+    ///     .into_iter()
+    ///     .round_dp_mode(2, RoundingMode::HalfEven)
+    ///     .collect();
+    /// assert_eq!(rounded, [0.12, 0.14]);
+    /// ```
+    #[inline]
+    fn round_dp_mode(self, decimal_places: u32, mode: RoundingMode) -> RoundDp<Self>
+    where
+        Self::Item: Float,
+    {
+        RoundDp {
+            iter: self,
+            decimal_places,
+            mode,
+        }
+    }
+
+    /// Rounds each number to the given number of significant figures like [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<f64> = [1234.5, 0.012_345].into_iter().round_sf(3).collect();
+    /// assert_eq!(rounded, [1230.0, 0.0123]);
+    /// ```
+    #[inline]
+    fn round_sf(self, sig_figs: u32) -> RoundSf<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds each number up to the given number of significant figures like [`ceil_sf`](super::ceil_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<u32> = [1201_u32, 1300].into_iter().ceil_sf(2).collect();
+    /// assert_eq!(rounded, [1300, 1300]);
+    /// ```
+    #[inline]
+    fn ceil_sf(self, sig_figs: u32) -> RoundSf<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_sf_mode(sig_figs, RoundingMode::Up)
+    }
+
+    /// Rounds each number down to the given number of significant figures like [`floor_sf`](super::floor_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<i32> = [1299_i32, -1201].into_iter().floor_sf(2).collect();
+    /// assert_eq!(rounded, [1200, -1300]);
+    /// ```
+    #[inline]
+    fn floor_sf(self, sig_figs: u32) -> RoundSf<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_sf_mode(sig_figs, RoundingMode::Down)
+    }
+
+    /// Rounds each number to the given number of significant figures using the given rounding
+    /// mode like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundIteratorExt, RoundingMode};
+    ///
+    /// let rounded: Vec<u32> = [1250_u32, 1350]
+    ///     .into_iter()
+    ///     .round_sf_mode(2, RoundingMode::HalfEven)
+    ///     .collect();
+    /// assert_eq!(rounded, [1200, 1400]);
+    /// ```
+    #[inline]
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> RoundSf<Self>
+    where
+        Self::Item: Roundable,
+    {
+        RoundSf {
+            iter: self,
+            sig_figs,
+            mode,
+        }
+    }
+
+    /// Rounds each number to the given number of zeros like [`round_zeros`](super::round_zeros)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<i32> = [1234, -1250, 49].into_iter().round_zeros(2).collect();
+    /// assert_eq!(rounded, [1200, -1300, 0]);
+    /// ```
+    #[inline]
+    fn round_zeros(self, zeros: u32) -> RoundZeros<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_zeros_mode(zeros, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds each number up to the given number of zeros like [`ceil_zeros`](super::ceil_zeros)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<f64> = [1201.0, -1299.0].into_iter().ceil_zeros(2).collect();
+    /// assert_eq!(rounded, [1300.0, -1200.0]);
+    /// ```
+    #[inline]
+    fn ceil_zeros(self, zeros: u32) -> RoundZeros<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_zeros_mode(zeros, RoundingMode::Up)
+    }
+
+    /// Rounds each number down to the given number of zeros like [`floor_zeros`](super::floor_zeros)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundIteratorExt;
+    ///
+    /// let rounded: Vec<u8> = [199_u8, 99].into_iter().floor_zeros(2).collect();
+    /// assert_eq!(rounded, [100, 0]);
+    /// ```
+    #[inline]
+    fn floor_zeros(self, zeros: u32) -> RoundZeros<Self>
+    where
+        Self::Item: Roundable,
+    {
+        self.round_zeros_mode(zeros, RoundingMode::Down)
+    }
+
+    /// Rounds each number to the given number of zeros using the given rounding mode like
+    /// [`round_zeros_mode`](super::round_zeros_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundIteratorExt, RoundingMode};
+    ///
+    /// let rounded: Vec<i32> = [125, 135]
+    ///     .into_iter()
+    ///     .round_zeros_mode(1, RoundingMode::HalfEven)
+    ///     .collect();
+    /// assert_eq!(rounded, [120, 140]);
+    /// ```
+    #[inline]
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> RoundZeros<Self>
+    where
+        Self::Item: Roundable,
+    {
+        RoundZeros {
+            iter: self,
+            zeros,
+            mode,
+        }
+    }
+}
+
+impl<I: Iterator> RoundIteratorExt for I {}
+
+/// Implements the iterator traits for an adapter that rounds each item with a method of the item
+macro_rules! impl_round_iterator {
+    ($adapter:ident, $bound:ident, $round:ident, $places:ident) => {
+        impl<I> Iterator for $adapter<I>
+        where
+            I: Iterator,
+            I::Item: $bound,
+        {
+            type Item = I::Item;
+
+            #[inline]
+            fn next(&mut self) -> Option<I::Item> {
+                let number = self.iter.next()?;
+                Some(number.$round(self.$places, self.mode))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<I::Item> {
+                let number = self.iter.nth(n)?;
+                Some(number.$round(self.$places, self.mode))
+            }
+
+            #[inline]
+            fn count(self) -> usize {
+                self.iter.count()
+            }
+        }
+
+        impl<I> DoubleEndedIterator for $adapter<I>
+        where
+            I: DoubleEndedIterator,
+            I::Item: $bound,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<I::Item> {
+                let number = self.iter.next_back()?;
+                Some(number.$round(self.$places, self.mode))
+            }
+        }
+
+        impl<I> ExactSizeIterator for $adapter<I>
+        where
+            I: ExactSizeIterator,
+            I::Item: $bound,
+        {
+            #[inline]
+            fn len(&self) -> usize {
+                self.iter.len()
+            }
+        }
+
+        impl<I> FusedIterator for $adapter<I>
+        where
+            I: FusedIterator,
+            I::Item: $bound,
+        {
+        }
+    };
+}
+
+/// An iterator that rounds each number to a number of decimal places, made by
+/// [`round_dp`](RoundIteratorExt::round_dp) and the other decimal place adapters
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundDp<I> {
+    iter: I,
+    decimal_places: u32,
+    mode: RoundingMode,
+}
+
+impl_round_iterator!(RoundDp, Float, round_dp_mode, decimal_places);
+
+/// An iterator that rounds each number to a number of significant figures, made by
+/// [`round_sf`](RoundIteratorExt::round_sf) and the other significant figure adapters
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundSf<I> {
+    iter: I,
+    sig_figs: u32,
+    mode: RoundingMode,
+}
+
+impl_round_iterator!(RoundSf, Roundable, round_sf_mode, sig_figs);
+
+/// An iterator that rounds each number to a number of zeros, made by
+/// [`round_zeros`](RoundIteratorExt::round_zeros) and the other zero adapters
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundZeros<I> {
+    iter: I,
+    zeros: u32,
+    mode: RoundingMode,
+}
+
+impl_round_iterator!(RoundZeros, Roundable, round_zeros_mode, zeros);
+
+mod tests;
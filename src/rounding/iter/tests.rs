@@ -0,0 +1,184 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+use crate::rounding::{round_dp_mode, round_sf_mode, round_zeros_mode};
+
+#[test]
+fn test_adapters_agree_with_scalar() {
+    let mut state = 0x17e2_a7e5_u64;
+    let values: Vec<f64> = (0..2_000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let exponent = (state % 30) as i32 - 15;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+        })
+        .chain([f64::NAN, f64::INFINITY, -0.0])
+        .collect();
+
+    for places in [0, 1, 2, 3, 8] {
+        let check = |rounded: Vec<f64>, mode| {
+            for (&number, rounded) in values.iter().zip(rounded) {
+                let expected = round_dp_mode(number, places, mode);
+                assert!(
+                    rounded.to_bits() == expected.to_bits() || rounded.is_nan(),
+                    "{number}"
+                );
+            }
+        };
+        check(
+            values.iter().copied().round_dp(places).collect(),
+            RoundingMode::HalfAwayFromZero,
+        );
+        check(
+            values.iter().copied().ceil_dp(places).collect(),
+            RoundingMode::Up,
+        );
+        check(
+            values.iter().copied().floor_dp(places).collect(),
+            RoundingMode::Down,
+        );
+
+        let check = |rounded: Vec<f64>, mode| {
+            for (&number, rounded) in values.iter().zip(rounded) {
+                let expected = round_sf_mode(number, places, mode);
+                assert!(
+                    rounded.to_bits() == expected.to_bits() || rounded.is_nan(),
+                    "{number}"
+                );
+            }
+        };
+        check(
+            values.iter().copied().round_sf(places).collect(),
+            RoundingMode::HalfAwayFromZero,
+        );
+        check(
+            values.iter().copied().ceil_sf(places).collect(),
+            RoundingMode::Up,
+        );
+        check(
+            values.iter().copied().floor_sf(places).collect(),
+            RoundingMode::Down,
+        );
+
+        let check = |rounded: Vec<f64>, mode| {
+            for (&number, rounded) in values.iter().zip(rounded) {
+                let expected = round_zeros_mode(number, places, mode);
+                assert!(
+                    rounded.to_bits() == expected.to_bits() || rounded.is_nan(),
+                    "{number}"
+                );
+            }
+        };
+        check(
+            values.iter().copied().round_zeros(places).collect(),
+            RoundingMode::HalfAwayFromZero,
+        );
+        check(
+            values.iter().copied().ceil_zeros(places).collect(),
+            RoundingMode::Up,
+        );
+        check(
+            values.iter().copied().floor_zeros(places).collect(),
+            RoundingMode::Down,
+        );
+
+        let integers: Vec<i32> = values.iter().map(|&number| number as i32).collect();
+        let rounded: Vec<i32> = integers.iter().copied().round_sf(places).collect();
+        for (&number, rounded) in integers.iter().zip(rounded) {
+            assert_eq!(
+                rounded,
+                round_sf_mode(number, places, RoundingMode::HalfAwayFromZero)
+            );
+        }
+        let rounded: Vec<i32> = integers.iter().copied().floor_zeros(places).collect();
+        for (&number, rounded) in integers.iter().zip(rounded) {
+            assert_eq!(
+                rounded,
+                round_zeros_mode(number, places, RoundingMode::Down)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_adapters_are_lazy() {
+    let pulled = Cell::new(0);
+    let mut rounded = [1.25_f64, 2.35, 3.45]
+        .into_iter()
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .round_dp(1);
+    assert_eq!(pulled.get(), 0);
+
+    assert_eq!(rounded.next(), Some(1.3));
+    assert_eq!(pulled.get(), 1);
+    assert_eq!(rounded.next(), Some(2.4));
+    assert_eq!(pulled.get(), 2);
+
+    let pulled = Cell::new(0);
+    let mut rounded = (0..)
+        .inspect(|_| pulled.set(pulled.get() + 1))
+        .map(|number: u32| number * 7)
+        .round_sf(1);
+    assert_eq!(rounded.nth(2), Some(10));
+    assert_eq!(pulled.get(), 3);
+}
+
+#[test]
+fn test_size_hints() {
+    let values = [1.5_f64, 2.5, 3.5, 4.5];
+    let mut rounded = values.iter().copied().round_dp(0);
+    assert_eq!(rounded.len(), 4);
+    assert_eq!(rounded.size_hint(), (4, Some(4)));
+    rounded.next();
+    assert_eq!(rounded.len(), 3);
+
+    let filtered = values
+        .iter()
+        .copied()
+        .filter(|&number| number > 2.0)
+        .round_sf(1);
+    assert_eq!(filtered.size_hint(), (0, Some(4)));
+    assert_eq!(filtered.count(), 3);
+
+    assert_eq!((0_u64..).round_zeros(1).size_hint(), (usize::MAX, None));
+    assert_eq!((10_u8..20).round_zeros(1).len(), 10);
+}
+
+#[test]
+fn test_double_ended() {
+    let values = [1.25_f64, 2.35, 3.45];
+    let reversed: Vec<f64> = values.iter().copied().round_dp(1).rev().collect();
+    assert_eq!(reversed, [3.5, 2.4, 1.3]);
+
+    let mut rounded = (121..130).round_zeros(1);
+    assert_eq!(rounded.next(), Some(120));
+    assert_eq!(rounded.next_back(), Some(130));
+    assert_eq!(rounded.len(), 7);
+}
+
+#[test]
+fn test_composes_with_iterator_adapters() {
+    let data = [1.234_f64, 5.678, 9.1011];
+    let total: f64 = data.iter().copied().round_dp(1).sum();
+    assert_eq!(total, 1.2 + 5.7 + 9.1);
+
+    let labels: Vec<String> = data
+        .iter()
+        .copied()
+        .round_sf(2)
+        .map(|number| number.to_string())
+        .collect();
+    assert_eq!(labels, ["1.2", "5.7", "9.1"]);
+
+    let cloned = data.iter().copied().floor_dp(1);
+    assert_eq!(
+        cloned.clone().collect::<Vec<_>>(),
+        cloned.collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,153 @@
+use core::iter::FusedIterator;
+
+use super::{RoundDp, RoundIteratorExt};
+
+/// Makes an iterator over `n` evenly spaced values from `start` to `end`, including both
+///
+/// Each value is worked out from its index as `start + i * (end - start) / (n - 1)` rather than by
+/// adding up steps, so no error builds up along the way, and the last value is exactly `end`.
+/// Zero values give an empty iterator and one value gives just `start`. An `end` below `start`
+/// gives descending values, and endpoints that aren't finite give values that aren't either
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::linspace;
+///
+/// let tenths: Vec<f64> = linspace(0.0, 1.0, 11).collect();
+/// assert_eq!(tenths[3], 0.3);
+/// assert_eq!(tenths[10], 1.0);
+/// assert_eq!(linspace(5.0, -5.0, 5).collect::<Vec<_>>(), [5.0, 2.5, 0.0, -2.5, -5.0]);
+/// assert_eq!(linspace(2.0, 3.0, 1).collect::<Vec<_>>(), [2.0]);
+/// assert_eq!(linspace(2.0, 3.0, 0).count(), 0);
+/// ```
+#[inline]
+pub fn linspace(start: f64, end: f64, n: usize) -> Linspace {
+    // A span wider than the largest float is halved and the offsets added twice
+    let (span, halved) = match end - start {
+        span if span.is_finite() => (span, false),
+        _ => (end / 2.0 - start / 2.0, true),
+    };
+    Linspace {
+        start,
+        end,
+        span,
+        halved,
+        intervals: n.saturating_sub(1),
+        front: 0,
+        back: n,
+    }
+}
+
+/// Makes an iterator over `n` evenly spaced values from `start` to `end` like [`linspace`], with
+/// each value rounded to the given number of decimal places like [`round`](super::round)
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::linspace_rounded;
+///
+/// let thirds: Vec<f64> = linspace_rounded(0.0, 1.0, 4, 2).collect();
+/// assert_eq!(thirds, [0.0, 0.33, 0.67, 1.0]);
+/// ```
+#[inline]
+pub fn linspace_rounded(start: f64, end: f64, n: usize, decimal_places: u32) -> RoundDp<Linspace> {
+    linspace(start, end, n).round_dp(decimal_places)
+}
+
+/// An iterator over evenly spaced values, made by [`linspace`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Linspace {
+    start: f64,
+    end: f64,
+    span: f64,
+    halved: bool,
+    intervals: usize,
+    front: usize,
+    back: usize,
+}
+
+impl Linspace {
+    /// The value at an index, which is below the number of values
+    #[inline]
+    fn value(&self, index: usize) -> f64 {
+        if index == 0 {
+            return self.start;
+        } else if index == self.intervals {
+            return self.end;
+        }
+
+        let (index, intervals) = (index as f64, self.intervals as f64);
+        let mut offset = index * self.span / intervals;
+        if offset.is_infinite() {
+            // The product overflowed, although the offset itself is no wider than the span
+            offset = index * (self.span / intervals);
+        }
+        if self.halved {
+            self.start + offset + offset
+        } else {
+            self.start + offset
+        }
+    }
+}
+
+impl Iterator for Linspace {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<f64> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.value(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<f64> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.back - self.front
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<f64> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Linspace {
+    #[inline]
+    fn next_back(&mut self) -> Option<f64> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.value(self.back))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<f64> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for Linspace {}
+
+impl FusedIterator for Linspace {}
+
+mod tests;
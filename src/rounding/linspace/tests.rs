@@ -0,0 +1,115 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_endpoints() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let exponent = (state % 20) as i32 - 10;
+        let start = ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent);
+        let end = start + ((state >> 40) as f64 - 8e6) * 10_f64.powi(exponent - 6);
+        let n = (state % 1_000) as usize + 2;
+
+        let mut values = linspace(start, end, n);
+        assert_eq!(values.len(), n);
+        assert_eq!(values.next(), Some(start));
+        assert_eq!(values.next_back(), Some(end));
+        assert_eq!(values.len(), n - 2);
+    }
+
+    assert_eq!(linspace(0.1, 0.7, 7).next_back(), Some(0.7));
+    assert_eq!(
+        linspace(-1e300, 1e300, 3).collect::<Vec<_>>(),
+        [-1e300, 0.0, 1e300]
+    );
+    let widest: Vec<f64> = linspace(-f64::MAX, f64::MAX, 5).collect();
+    assert_eq!(widest.len(), 5);
+    assert_eq!(
+        (widest[0], widest[2], widest[4]),
+        (-f64::MAX, 0.0, f64::MAX)
+    );
+    assert_eq!(widest[1], -widest[3]);
+    assert!((widest[3] / f64::MAX - 0.5).abs() < 1e-15);
+}
+
+#[test]
+fn test_no_drift() {
+    // Adding up steps of 0.1 drifts, working each value out from its index doesn't
+    let tenths: Vec<f64> = linspace(0.0, 1.0, 11).collect();
+    assert_eq!(
+        tenths,
+        [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]
+    );
+    assert_ne!((0..3).map(|_| 0.1).sum::<f64>(), tenths[3]);
+
+    let values: Vec<f64> = linspace(0.0, 1e6, 1_000_001).collect();
+    assert!(values
+        .iter()
+        .enumerate()
+        .all(|(index, &value)| value == index as f64));
+}
+
+#[test]
+fn test_small_counts() {
+    assert_eq!(linspace(2.0, 3.0, 0).next(), None);
+    assert_eq!(linspace(2.0, 3.0, 0).next_back(), None);
+    assert_eq!(linspace(2.0, 3.0, 1).collect::<Vec<_>>(), [2.0]);
+    assert_eq!(linspace(2.0, 3.0, 1).rev().collect::<Vec<_>>(), [2.0]);
+    assert_eq!(linspace(2.0, 3.0, 2).collect::<Vec<_>>(), [2.0, 3.0]);
+    assert_eq!(linspace(2.0, 2.0, 3).collect::<Vec<_>>(), [2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn test_descending() {
+    assert_eq!(
+        linspace(1.0, 0.0, 5).collect::<Vec<_>>(),
+        [1.0, 0.75, 0.5, 0.25, 0.0]
+    );
+    assert_eq!(
+        linspace(-2.0, -3.0, 3).collect::<Vec<_>>(),
+        [-2.0, -2.5, -3.0]
+    );
+    let values: Vec<f64> = linspace(0.3, -0.3, 101).collect();
+    assert!(values.windows(2).all(|pair| pair[0] > pair[1]));
+}
+
+#[test]
+fn test_double_ended() {
+    let forward: Vec<f64> = linspace(-1.0, 2.0, 7).collect();
+    let mut backward: Vec<f64> = linspace(-1.0, 2.0, 7).rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let mut values = linspace(0.0, 10.0, 11);
+    assert_eq!(values.nth(2), Some(2.0));
+    assert_eq!(values.nth_back(2), Some(8.0));
+    assert_eq!(values.len(), 5);
+    assert_eq!(values.next_back(), Some(7.0));
+    assert_eq!(values.next(), Some(3.0));
+    assert_eq!(values.clone().count(), 3);
+    assert_eq!(values.nth(5), None);
+    assert_eq!(values.next(), None);
+    assert_eq!(values.next_back(), None);
+    assert_eq!(values.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_non_finite() {
+    let values: Vec<f64> = linspace(0.0, f64::INFINITY, 3).collect();
+    assert_eq!(values, [0.0, f64::INFINITY, f64::INFINITY]);
+    assert!(linspace(f64::NAN, 1.0, 3).nth(1).unwrap().is_nan());
+}
+
+#[test]
+fn test_linspace_rounded() {
+    let thirds: Vec<f64> = linspace_rounded(0.0, 1.0, 4, 2).collect();
+    assert_eq!(thirds, [0.0, 0.33, 0.67, 1.0]);
+    let sevenths: Vec<f64> = linspace_rounded(1.0, 0.0, 8, 1).collect();
+    assert_eq!(sevenths, [1.0, 0.9, 0.7, 0.6, 0.4, 0.3, 0.1, 0.0]);
+    assert_eq!(linspace_rounded(0.0, 1.0, 4, 2).next_back(), Some(1.0));
+    assert_eq!(linspace_rounded(0.0, 1.0, 4, 2).len(), 4);
+}
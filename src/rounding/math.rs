@@ -0,0 +1,126 @@
+//! The float functions that are only inherent methods with the standard library
+
+/// Float functions from `libm` with the same names as the inherent methods they stand in for,
+/// so the same calls work whether or not the `std` feature is enabled
+pub(crate) trait FloatMath {
+    fn round(self) -> Self;
+    fn ceil(self) -> Self;
+    fn floor(self) -> Self;
+    fn trunc(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn sqrt(self) -> Self;
+    #[cfg(feature = "alloc")]
+    fn cbrt(self) -> Self;
+    #[cfg(feature = "alloc")]
+    fn log2(self) -> Self;
+}
+
+impl FloatMath for f64 {
+    #[inline]
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> f64 {
+        libm::ceil(self)
+    }
+
+    #[inline]
+    fn floor(self) -> f64 {
+        libm::floor(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> f64 {
+        libm::trunc(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        libm::fma(self, a, b)
+    }
+
+    #[inline]
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    #[inline]
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn log2(self) -> f64 {
+        libm::log2(self)
+    }
+}
+
+impl FloatMath for f32 {
+    #[inline]
+    fn round(self) -> f32 {
+        libm::roundf(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> f32 {
+        libm::ceilf(self)
+    }
+
+    #[inline]
+    fn floor(self) -> f32 {
+        libm::floorf(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> f32 {
+        libm::truncf(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: f32, b: f32) -> f32 {
+        libm::fmaf(self, a, b)
+    }
+
+    #[inline]
+    fn hypot(self, other: f32) -> f32 {
+        libm::hypotf(self, other)
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> f32 {
+        libm::powf(self, n as f32)
+    }
+
+    #[inline]
+    fn sqrt(self) -> f32 {
+        libm::sqrtf(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn cbrt(self) -> f32 {
+        libm::cbrtf(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn log2(self) -> f32 {
+        libm::log2f(self)
+    }
+}
@@ -0,0 +1,366 @@
+use nalgebra::allocator::Allocator;
+use nalgebra::{DefaultAllocator, Dim, Matrix, OMatrix, RawStorage, RawStorageMut, Scalar};
+
+use super::{Float, Roundable, RoundingMode};
+
+/// Element-wise rounding for [`nalgebra`] matrices and vectors
+///
+/// Implemented for every [`Matrix`], statically or dynamically sized, with the decimal place
+/// methods available when it holds a [`Float`] and the significant figure methods when it holds
+/// any [`Roundable`] number. Each element gives the same result as the matching scalar function
+///
+/// The methods that return a new matrix give an owned matrix of the same shape, and the
+/// `_inplace` methods need mutable storage such as an owned matrix or a mutable slice of one
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::RoundMatrixExt;
+/// use nalgebra::{DMatrix, Vector3};
+///
+/// let translation = Vector3::new(1.234_f64, -5.678, 0.0049);
+/// assert_eq!(translation.round_dp(2), Vector3::new(1.23, -5.68, 0.0));
+///
+/// let mut transform = DMatrix::from_row_slice(2, 2, &[0.999_f32, 0.0452, -0.0452, 0.999]);
+/// transform.round_sf_inplace(2);
+/// assert_eq!(transform, DMatrix::from_row_slice(2, 2, &[1.0, 0.045, -0.045, 1.0]));
+/// ```
+pub trait RoundMatrixExt {
+    /// The type of the elements
+    type Elem: Scalar;
+    /// The number of rows
+    type Rows: Dim;
+    /// The number of columns
+    type Columns: Dim;
+    /// The storage of the elements, owned or borrowed
+    type Storage: RawStorage<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of decimal places like [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(1.005, 2.675).round_dp(2), Vector2::new(1.0, 2.68));
+    /// ```
+    fn round_dp(&self, decimal_places: u32) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Float + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element up to the given number of decimal places like [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(1.201_f32, -1.209).ceil_dp(2), Vector2::new(1.21, -1.2));
+    /// ```
+    fn ceil_dp(&self, decimal_places: u32) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Float + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element down to the given number of decimal places like [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(1.209, -1.201).floor_dp(2), Vector2::new(1.2, -1.21));
+    /// ```
+    fn floor_dp(&self, decimal_places: u32) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Float + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of decimal places using the given rounding mode
+    /// like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundMatrixExt, RoundingMode};
+    /// use nalgebra::Vector2;
+    ///
+    /// let rounded = Vector2::new(0.125, 0.135).round_dp_mode(2, RoundingMode::HalfEven);
+    /// assert_eq!(rounded, Vector2::new(0.12, 0.14));
+    /// ```
+    fn round_dp_mode(
+        &self,
+        decimal_places: u32,
+        mode: RoundingMode,
+    ) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Float + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of significant figures like
+    /// [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// assert_eq!(Vector2::new(0.004567, 1234.5).round_sf(2), Vector2::new(0.0046, 1200.0));
+    /// assert_eq!(Vector2::new(1250_i32, -1250).round_sf(2), Vector2::new(1300, -1300));
+    /// ```
+    fn round_sf(&self, sig_figs: u32) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Roundable + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of significant figures using the given rounding
+    /// mode like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundMatrixExt, RoundingMode};
+    /// use nalgebra::Vector2;
+    ///
+    /// let rounded = Vector2::new(1250_u32, 1350).round_sf_mode(2, RoundingMode::HalfEven);
+    /// assert_eq!(rounded, Vector2::new(1200, 1400));
+    /// ```
+    fn round_sf_mode(
+        &self,
+        sig_figs: u32,
+        mode: RoundingMode,
+    ) -> OMatrix<Self::Elem, Self::Rows, Self::Columns>
+    where
+        Self::Elem: Roundable + Copy,
+        DefaultAllocator: Allocator<Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of decimal places in place like
+    /// [`round`](super::round)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Matrix2;
+    ///
+    /// let mut transform = Matrix2::new(1.234, 5.678, -9.876, 0.005);
+    /// transform.column_mut(1).round_dp_inplace(1);
+    /// assert_eq!(transform, Matrix2::new(1.234, 5.7, -9.876, 0.0));
+    /// ```
+    fn round_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element up to the given number of decimal places in place like
+    /// [`ceil`](super::ceil)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// let mut vector = Vector2::new(1.201_f32, -1.209);
+    /// vector.ceil_dp_inplace(2);
+    /// assert_eq!(vector, Vector2::new(1.21, -1.2));
+    /// ```
+    fn ceil_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element down to the given number of decimal places in place like
+    /// [`floor`](super::floor)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// let mut vector = Vector2::new(1.209, -1.201);
+    /// vector.floor_dp_inplace(2);
+    /// assert_eq!(vector, Vector2::new(1.2, -1.21));
+    /// ```
+    fn floor_dp_inplace(&mut self, decimal_places: u32)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of decimal places using the given rounding mode in
+    /// place like [`round_dp_mode`](super::round_dp_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundMatrixExt, RoundingMode};
+    /// use nalgebra::Vector2;
+    ///
+    /// let mut vector = Vector2::new(0.125, 0.135);
+    /// vector.round_dp_mode_inplace(2, RoundingMode::HalfEven);
+    /// assert_eq!(vector, Vector2::new(0.12, 0.14));
+    /// ```
+    fn round_dp_mode_inplace(&mut self, decimal_places: u32, mode: RoundingMode)
+    where
+        Self::Elem: Float + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of significant figures in place like
+    /// [`round_sf`](super::round_sf)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundMatrixExt;
+    /// use nalgebra::Vector2;
+    ///
+    /// let mut vector = Vector2::new(0.004567, 1234.5);
+    /// vector.round_sf_inplace(2);
+    /// assert_eq!(vector, Vector2::new(0.0046, 1200.0));
+    /// ```
+    fn round_sf_inplace(&mut self, sig_figs: u32)
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+
+    /// Rounds each element to the given number of significant figures using the given rounding
+    /// mode in place like [`round_sf_mode`](super::round_sf_mode)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{RoundMatrixExt, RoundingMode};
+    /// use nalgebra::Vector2;
+    ///
+    /// let mut vector = Vector2::new(1250_u32, 1350);
+    /// vector.round_sf_mode_inplace(2, RoundingMode::HalfEven);
+    /// assert_eq!(vector, Vector2::new(1200, 1400));
+    /// ```
+    fn round_sf_mode_inplace(&mut self, sig_figs: u32, mode: RoundingMode)
+    where
+        Self::Elem: Roundable + Copy,
+        Self::Storage: RawStorageMut<Self::Elem, Self::Rows, Self::Columns>;
+}
+
+impl<T: Scalar, R: Dim, C: Dim, S: RawStorage<T, R, C>> RoundMatrixExt for Matrix<T, R, C, S> {
+    type Elem = T;
+    type Rows = R;
+    type Columns = C;
+    type Storage = S;
+
+    #[inline]
+    fn round_dp(&self, decimal_places: u32) -> OMatrix<T, R, C>
+    where
+        T: Float + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_dp(&self, decimal_places: u32) -> OMatrix<T, R, C>
+    where
+        T: Float + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_dp(&self, decimal_places: u32) -> OMatrix<T, R, C>
+    where
+        T: Float + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.round_dp_mode(decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_dp_mode(&self, decimal_places: u32, mode: RoundingMode) -> OMatrix<T, R, C>
+    where
+        T: Float + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.map(|number| number.round_dp_mode(decimal_places, mode))
+    }
+
+    #[inline]
+    fn round_sf(&self, sig_figs: u32) -> OMatrix<T, R, C>
+    where
+        T: Roundable + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn round_sf_mode(&self, sig_figs: u32, mode: RoundingMode) -> OMatrix<T, R, C>
+    where
+        T: Roundable + Copy,
+        DefaultAllocator: Allocator<R, C>,
+    {
+        self.map(|number| number.round_sf_mode(sig_figs, mode))
+    }
+
+    #[inline]
+    fn round_dp_inplace(&mut self, decimal_places: u32)
+    where
+        T: Float + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::HalfAwayFromZero);
+    }
+
+    #[inline]
+    fn ceil_dp_inplace(&mut self, decimal_places: u32)
+    where
+        T: Float + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::Up);
+    }
+
+    #[inline]
+    fn floor_dp_inplace(&mut self, decimal_places: u32)
+    where
+        T: Float + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.round_dp_mode_inplace(decimal_places, RoundingMode::Down);
+    }
+
+    #[inline]
+    fn round_dp_mode_inplace(&mut self, decimal_places: u32, mode: RoundingMode)
+    where
+        T: Float + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.apply(|number| *number = number.round_dp_mode(decimal_places, mode));
+    }
+
+    #[inline]
+    fn round_sf_inplace(&mut self, sig_figs: u32)
+    where
+        T: Roundable + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.round_sf_mode_inplace(sig_figs, RoundingMode::HalfAwayFromZero);
+    }
+
+    #[inline]
+    fn round_sf_mode_inplace(&mut self, sig_figs: u32, mode: RoundingMode)
+    where
+        T: Roundable + Copy,
+        S: RawStorageMut<T, R, C>,
+    {
+        self.apply(|number| *number = number.round_sf_mode(sig_figs, mode));
+    }
+}
+
+mod tests;
@@ -0,0 +1,112 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{ceil, floor, round, round_sf};
+#[cfg(test)]
+use nalgebra::{DMatrix, DVector, Matrix3x4, Vector3};
+
+#[cfg(test)]
+fn random_floats(seed: u64, count: usize) -> Vec<f64> {
+    let mut state = seed;
+    let mut values: Vec<f64> = (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let exponent = (state % 12) as i32 - 6;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+        })
+        .collect();
+    values.extend([0.0, -0.0, 2.5, -2.675, 1.005, f64::NAN, f64::INFINITY]);
+    values
+}
+
+#[test]
+fn test_static_sizes() {
+    let values = random_floats(0x9e37_79b9_7f4a_7c15, 200);
+    for chunk in values.chunks_exact(12) {
+        let matrix = Matrix3x4::from_column_slice(chunk);
+        for decimal_places in 0..5 {
+            let rounded = matrix.round_dp(decimal_places);
+            let ceiled = matrix.ceil_dp(decimal_places);
+            let floored = matrix.floor_dp(decimal_places);
+            let mut in_place = matrix;
+            in_place.round_dp_inplace(decimal_places);
+            for (index, &number) in matrix.iter().enumerate() {
+                let expected = round(number, decimal_places).to_bits();
+                assert_eq!(rounded[index].to_bits(), expected, "{number}");
+                assert_eq!(in_place[index].to_bits(), expected, "{number}");
+                let expected = ceil(number, decimal_places).to_bits();
+                assert_eq!(ceiled[index].to_bits(), expected, "{number}");
+                let expected = floor(number, decimal_places).to_bits();
+                assert_eq!(floored[index].to_bits(), expected, "{number}");
+            }
+        }
+
+        let vector = Vector3::new(chunk[0] as f32, chunk[1] as f32, chunk[2] as f32);
+        let rounded = vector.round_sf(3);
+        for (&rounded, &number) in rounded.iter().zip(vector.iter()) {
+            assert_eq!(rounded.to_bits(), round_sf(number, 3).to_bits(), "{number}");
+        }
+    }
+}
+
+#[test]
+fn test_dynamic_sizes() {
+    let values = random_floats(0x2545_f491_4f6c_dd1d, 196);
+    let matrix = DMatrix::from_row_slice(7, 29, &values);
+    let rounded = matrix.round_sf(2);
+    assert_eq!(rounded.shape(), (7, 29));
+    for (&rounded, &number) in rounded.iter().zip(matrix.iter()) {
+        assert_eq!(rounded.to_bits(), round_sf(number, 2).to_bits(), "{number}");
+    }
+
+    let mut in_place = matrix.clone();
+    in_place.round_sf_mode_inplace(3, RoundingMode::HalfEven);
+    for (&rounded, &number) in in_place.iter().zip(matrix.iter()) {
+        let expected = number.round_sf_even(3);
+        assert_eq!(rounded.to_bits(), expected.to_bits(), "{number}");
+    }
+
+    let vector = DVector::from_iterator(203, values.iter().map(|&number| number as f32));
+    let floored = vector.floor_dp(3);
+    for (&floored, &number) in floored.iter().zip(vector.iter()) {
+        assert_eq!(floored.to_bits(), floor(number, 3).to_bits(), "{number}");
+    }
+}
+
+#[test]
+fn test_inplace_slices() {
+    let values = random_floats(0xdead_beef, 193);
+    let matrix = DMatrix::from_column_slice(10, 20, &values);
+    let mut rounded = matrix.clone();
+    rounded.view_mut((2, 3), (3, 6)).round_dp_inplace(1);
+    rounded.row_mut(8).ceil_dp_inplace(2);
+    for row in 0..10 {
+        for column in 0..20 {
+            let number = matrix[(row, column)];
+            let expected = if (2..5).contains(&row) && (3..9).contains(&column) {
+                round(number, 1)
+            } else if row == 8 {
+                ceil(number, 2)
+            } else {
+                number
+            };
+            assert_eq!(rounded[(row, column)].to_bits(), expected.to_bits());
+        }
+    }
+}
+
+#[test]
+fn test_integers() {
+    let matrix = DMatrix::from_fn(4, 5, |row, column| (row * 1000 + column * 37) as i64 - 1500);
+    let rounded = matrix.round_sf_mode(2, RoundingMode::Down);
+    for (&rounded, &number) in rounded.iter().zip(matrix.iter()) {
+        assert_eq!(rounded, number.floor_sf(2));
+    }
+
+    let mut vector = Vector3::new(1250_u32, 1350, 99);
+    vector.round_sf_inplace(1);
+    assert_eq!(vector, Vector3::new(1000, 1000, 100));
+}
@@ -0,0 +1,364 @@
+use core::fmt;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{digits, powers, Float, Roundable, RoundingError};
+
+/// A measured value with its uncertainty, such as `12.35 ± 0.04`
+///
+/// The uncertainty is never negative. It is finite when the measurement is made, and only becomes
+/// infinite or NaN through arithmetic that overflows or divides by a value of zero
+///
+/// Arithmetic propagates the uncertainties of independent measurements to first order, adding
+/// absolute uncertainties in quadrature for `+` and `-` and relative uncertainties in quadrature
+/// for `*` and `/`. It doesn't round, so the value and uncertainty keep every digit until
+/// [`rounded`](Measurement::rounded) rounds them for reporting. A measurement combined with itself
+/// is treated as two independent measurements, so `x - x` has an uncertainty rather than none
+///
+/// It displays rounded, with the uncertainty to one significant figure or to the precision of the
+/// format if there is one and the value to the same decimal position. The `{:e}` format shows both
+/// scaled by the power of ten of the value, such as `(1.235 ± 0.004) × 10^1`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::Measurement;
+///
+/// let length = Measurement::new(12.3456, 0.0372);
+/// assert_eq!(length.to_string(), "12.35 ± 0.04");
+/// assert_eq!(format!("{length:.2}"), "12.346 ± 0.037");
+/// assert_eq!(format!("{length:e}"), "(1.235 ± 0.004) × 10^1");
+///
+/// let area = Measurement::new(10.0, 0.1) * Measurement::new(2.0, 0.05);
+/// assert_eq!(area.value(), 20.0);
+/// assert_eq!(area.to_string(), "20.0 ± 0.5");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Measurement {
+    value: f64,
+    uncertainty: f64,
+}
+
+impl Measurement {
+    /// Makes a measurement of the value with the given uncertainty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the uncertainty is negative or not finite, see [`try_new`](Measurement::try_new)
+    /// to handle this instead
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Measurement;
+    ///
+    /// let mass = Measurement::new(4.52, 0.02);
+    /// assert_eq!(mass.value(), 4.52);
+    /// assert_eq!(mass.uncertainty(), 0.02);
+    /// ```
+    #[inline]
+    pub fn new(value: f64, uncertainty: f64) -> Measurement {
+        match Measurement::try_new(value, uncertainty) {
+            Ok(measurement) => measurement,
+            Err(_) => panic!("uncertainty must be finite and not negative"),
+        }
+    }
+
+    /// Makes a measurement of the value with the given uncertainty, like
+    /// [`new`](Measurement::new)
+    ///
+    /// # Errors
+    ///
+    /// [`RoundingError::InvalidUncertainty`] if the uncertainty is negative or not finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::{Measurement, RoundingError};
+    ///
+    /// assert!(Measurement::try_new(4.52, 0.02).is_ok());
+    /// assert_eq!(Measurement::try_new(4.52, -0.02), Err(RoundingError::InvalidUncertainty));
+    /// assert_eq!(Measurement::try_new(4.52, f64::NAN), Err(RoundingError::InvalidUncertainty));
+    /// ```
+    #[inline]
+    pub fn try_new(value: f64, uncertainty: f64) -> Result<Measurement, RoundingError> {
+        if uncertainty.is_finite() && uncertainty >= 0.0 {
+            // Adding zero turns a negative zero into a positive one and leaves everything else alone
+            Ok(Measurement {
+                value,
+                uncertainty: uncertainty + 0.0,
+            })
+        } else {
+            Err(RoundingError::InvalidUncertainty)
+        }
+    }
+
+    /// The measured value
+    #[inline]
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The absolute uncertainty of the value
+    #[inline]
+    pub const fn uncertainty(&self) -> f64 {
+        self.uncertainty
+    }
+
+    /// The uncertainty as a fraction of the magnitude of the value
+    ///
+    /// A value of zero gives an infinite relative uncertainty, or NaN if the uncertainty is
+    /// zero as well
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Measurement;
+    ///
+    /// assert_eq!(Measurement::new(-20.0, 0.5).relative_uncertainty(), 0.025);
+    /// assert_eq!(Measurement::new(0.0, 0.5).relative_uncertainty(), f64::INFINITY);
+    /// ```
+    #[inline]
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.value.abs()
+    }
+
+    /// Raises the measurement to an integer power, which multiplies its relative uncertainty by
+    /// the magnitude of the power
+    ///
+    /// This isn't the same as multiplying the measurement by itself, as that treats the factors as
+    /// independent. A power of zero gives exactly one. A negative power of a value of zero gives
+    /// an infinite value and uncertainty, like dividing by it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Measurement;
+    ///
+    /// let side = Measurement::new(3.0, 0.03);
+    /// assert_eq!(side.powi(2), Measurement::new(9.0, 0.18));
+    /// assert_eq!(side.powi(2).relative_uncertainty(), 2.0 * side.relative_uncertainty());
+    /// assert_eq!(side.powi(0), Measurement::new(1.0, 0.0));
+    /// ```
+    #[inline]
+    pub fn powi(self, n: i32) -> Measurement {
+        if n == 0 {
+            return Measurement {
+                value: 1.0,
+                uncertainty: 0.0,
+            };
+        }
+
+        let value = self.value.powi(n);
+        if n < 0 && self.value == 0.0 {
+            return Measurement {
+                value,
+                uncertainty: f64::INFINITY,
+            };
+        }
+
+        // The derivative of the power is n * value^(n - 1)
+        let slope = n.unsigned_abs() as f64 * self.value.abs().powi(n - 1);
+        Measurement {
+            value,
+            uncertainty: slope * self.uncertainty,
+        }
+    }
+
+    /// Rounds the uncertainty to one significant figure and the value to the same decimal position
+    ///
+    /// Rounding the uncertainty up to the next power of ten moves the position along with it,
+    /// so `0.096` becomes `0.1` and the value is rounded to one decimal place. A zero uncertainty
+    /// leaves the value unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Measurement;
+    ///
+    /// assert_eq!(Measurement::new(12.3456, 0.0372).rounded(), Measurement::new(12.35, 0.04));
+    /// assert_eq!(Measurement::new(12.3456, 0.096).rounded(), Measurement::new(12.3, 0.1));
+    /// assert_eq!(Measurement::new(1234.5, 27.0).rounded(), Measurement::new(1230.0, 30.0));
+    /// ```
+    #[inline]
+    pub fn rounded(self) -> Measurement {
+        self.rounded_sf(1)
+    }
+
+    /// Rounds the uncertainty to the given number of significant figures and the value to the same
+    /// decimal position, like [`rounded`](Measurement::rounded)
+    ///
+    /// Two significant figures are common when the first digit of the uncertainty is a 1 or 2, as
+    /// rounding those to one figure changes them by up to a quarter
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Measurement;
+    ///
+    /// let length = Measurement::new(12.3456, 0.0137);
+    /// assert_eq!(length.rounded_sf(2), Measurement::new(12.346, 0.014));
+    /// assert_eq!(length.rounded_sf(1), Measurement::new(12.35, 0.01));
+    /// ```
+    #[inline]
+    pub fn rounded_sf(self, sig_figs: u32) -> Measurement {
+        let (value, uncertainty) = self.value.round_pair_sf(self.uncertainty, sig_figs);
+        Measurement { value, uncertainty }
+    }
+
+    /// Rounds the measurement for display, returning it with the decimal position of the last
+    /// significant figure of its uncertainty, or `None` if the uncertainty is zero or not finite
+    #[inline]
+    fn rounded_position(self, f: &fmt::Formatter<'_>) -> (Measurement, Option<i32>) {
+        // More significant figures than an f64 holds would show digits it doesn't have
+        let sig_figs = f.precision().unwrap_or(1).clamp(1, 17) as u32;
+        let mut rounded = self.rounded_sf(sig_figs);
+        // A value that rounds to zero from below isn't shown as negative
+        rounded.value += 0.0;
+        if rounded.uncertainty == 0.0 || !rounded.uncertainty.is_finite() {
+            (rounded, None)
+        } else {
+            // Counted like round_pair_sf so zeros the uncertainty gained from rounding are kept
+            let position = rounded.uncertainty.get_digits() - sig_figs as i32;
+            (rounded, Some(position))
+        }
+    }
+}
+
+impl fmt::Display for Measurement {
+    /// Writes the rounded value and uncertainty with the same number of decimal places, such as
+    /// `12.35 ± 0.04` or `1230 ± 30`. The precision of the format is the number of significant
+    /// figures of the uncertainty, one if it isn't given
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rounded, position) = self.rounded_position(f);
+        let Measurement { value, uncertainty } = rounded;
+        match position {
+            Some(position) => {
+                let decimal_places = position.min(0).unsigned_abs() as usize;
+                write!(
+                    f,
+                    "{value:.decimal_places$} ± {uncertainty:.decimal_places$}"
+                )
+            }
+            None => write!(f, "{value} ± {uncertainty}"),
+        }
+    }
+}
+
+impl fmt::LowerExp for Measurement {
+    /// Writes the rounded value and uncertainty as multiples of the power of ten of the value,
+    /// such as `(1.235 ± 0.004) × 10^1`. A value that rounds to zero takes the power of ten of the
+    /// uncertainty instead, and a value that isn't finite is written like [`Display`](fmt::Display)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (rounded, position) = self.rounded_position(f);
+        let Measurement { value, uncertainty } = rounded;
+        if !value.is_finite() {
+            return fmt::Display::fmt(self, f);
+        }
+
+        let exponent = match (value == 0.0, position) {
+            (false, _) => value.get_digits() - 1,
+            (true, Some(_)) => uncertainty.get_digits() - 1,
+            (true, None) => 0,
+        };
+        // Both are close to a decimal with these decimal places after scaling, so the error from
+        // dividing by the power of ten is rounded away when they are written
+        let power = powers::ten_f64(exponent);
+        match position {
+            Some(position) => {
+                let (value, uncertainty) = (value / power, uncertainty / power);
+                // The value is rounded to the position, so its power of ten is never below it
+                let decimal_places = (exponent - position).max(0) as usize;
+                write!(
+                    f,
+                    "({value:.decimal_places$} ± {uncertainty:.decimal_places$}) × 10^{exponent}"
+                )
+            }
+            None => {
+                let decimal_places = digits::shortest_sig_figs(rounded.value).saturating_sub(1);
+                let (value, decimal_places) = (value / power, decimal_places as usize);
+                write!(
+                    f,
+                    "({value:.decimal_places$} ± {uncertainty}) × 10^{exponent}"
+                )
+            }
+        }
+    }
+}
+
+impl Add for Measurement {
+    type Output = Measurement;
+
+    #[inline]
+    fn add(self, rhs: Measurement) -> Measurement {
+        Measurement {
+            value: self.value + rhs.value,
+            uncertainty: self.uncertainty.hypot(rhs.uncertainty),
+        }
+    }
+}
+
+impl Sub for Measurement {
+    type Output = Measurement;
+
+    #[inline]
+    fn sub(self, rhs: Measurement) -> Measurement {
+        Measurement {
+            value: self.value - rhs.value,
+            uncertainty: self.uncertainty.hypot(rhs.uncertainty),
+        }
+    }
+}
+
+impl Mul for Measurement {
+    type Output = Measurement;
+
+    #[inline]
+    fn mul(self, rhs: Measurement) -> Measurement {
+        // The relative uncertainties add in quadrature, written so a value of zero needs no
+        // division
+        Measurement {
+            value: self.value * rhs.value,
+            uncertainty: (rhs.value * self.uncertainty).hypot(self.value * rhs.uncertainty),
+        }
+    }
+}
+
+impl Div for Measurement {
+    type Output = Measurement;
+
+    /// Divides the values and adds their relative uncertainties in quadrature
+    ///
+    /// Dividing by a value of zero gives the quotient of the values as floats, an infinity or NaN,
+    /// with an infinite uncertainty
+    #[inline]
+    fn div(self, rhs: Measurement) -> Measurement {
+        let value = self.value / rhs.value;
+        if rhs.value == 0.0 {
+            return Measurement {
+                value,
+                uncertainty: f64::INFINITY,
+            };
+        }
+
+        Measurement {
+            value,
+            uncertainty: self.uncertainty.hypot(value * rhs.uncertainty) / rhs.value.abs(),
+        }
+    }
+}
+
+impl Neg for Measurement {
+    type Output = Measurement;
+
+    #[inline]
+    fn neg(self) -> Measurement {
+        Measurement {
+            value: -self.value,
+            uncertainty: self.uncertainty,
+        }
+    }
+}
+
+mod tests;
@@ -0,0 +1,244 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_new() {
+    let measurement = Measurement::new(-4.52, 0.02);
+    assert_eq!(measurement.value(), -4.52);
+    assert_eq!(measurement.uncertainty(), 0.02);
+
+    assert_eq!(
+        Measurement::new(1.0, -0.0).uncertainty().to_bits(),
+        0_f64.to_bits()
+    );
+    assert_eq!(Measurement::new(f64::NAN, 0.1).uncertainty(), 0.1);
+    assert_eq!(Measurement::default(), Measurement::new(0.0, 0.0));
+
+    for uncertainty in [-0.1, -f64::MIN_POSITIVE, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            Measurement::try_new(1.0, uncertainty),
+            Err(RoundingError::InvalidUncertainty)
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "uncertainty must be finite and not negative")]
+fn test_new_negative_uncertainty() {
+    Measurement::new(1.0, -0.1);
+}
+
+#[test]
+fn test_rounded() {
+    let rounded = |value, uncertainty| Measurement::new(value, uncertainty).rounded();
+    assert_eq!(rounded(12.3456, 0.0372), Measurement::new(12.35, 0.04));
+    assert_eq!(rounded(-12.3456, 0.0372), Measurement::new(-12.35, 0.04));
+    assert_eq!(rounded(12.3456, 0.096), Measurement::new(12.3, 0.1));
+    assert_eq!(rounded(1234.5, 27.0), Measurement::new(1230.0, 30.0));
+    assert_eq!(rounded(12.3456, 270.0), Measurement::new(0.0, 300.0));
+    assert_eq!(rounded(12.3456, 0.0), Measurement::new(12.3456, 0.0));
+
+    let length = Measurement::new(12.3456, 0.0996);
+    assert_eq!(length.rounded_sf(2), Measurement::new(12.35, 0.1));
+    assert_eq!(length.rounded_sf(3), Measurement::new(12.3456, 0.0996));
+    assert_eq!(length.rounded().rounded(), length.rounded());
+}
+
+#[test]
+fn test_display() {
+    let display = |value, uncertainty| Measurement::new(value, uncertainty).to_string();
+    assert_eq!(display(12.3456, 0.0372), "12.35 ± 0.04");
+    assert_eq!(display(-12.3456, 0.0372), "-12.35 ± 0.04");
+    assert_eq!(display(-0.012_34, 0.000_37), "-0.0123 ± 0.0004");
+
+    // Rounding the uncertainty up to the next power of ten moves the position along with it
+    assert_eq!(display(12.3456, 0.096), "12.3 ± 0.1");
+    assert_eq!(display(12.3456, 0.96), "12 ± 1");
+
+    // Uncertainties of at least one
+    assert_eq!(display(9.87, 1.4), "10 ± 1");
+    assert_eq!(display(1234.5, 27.0), "1230 ± 30");
+    assert_eq!(display(1234.5, 2.0), "1235 ± 2");
+
+    // Uncertainties larger than the value
+    assert_eq!(display(12.3456, 270.0), "0 ± 300");
+    assert_eq!(display(0.4, 2.0), "0 ± 2");
+    assert_eq!(display(-0.02, 0.3), "0.0 ± 0.3");
+
+    // The precision is the number of significant figures of the uncertainty
+    let length = Measurement::new(12.3456, 0.0996);
+    assert_eq!(format!("{length:.2}"), "12.35 ± 0.10");
+    assert_eq!(format!("{length:.3}"), "12.3456 ± 0.0996");
+    assert_eq!(format!("{length:.0}"), "12.3 ± 0.1");
+    assert_eq!(
+        format!("{:.2}", Measurement::new(1234.5, 27.0)),
+        "1235 ± 27"
+    );
+    assert_eq!(
+        format!("{:.2}", Measurement::new(12.3456, 270.0)),
+        "10 ± 270"
+    );
+
+    // Uncertainties and values that don't give a position
+    assert_eq!(display(12.3456, 0.0), "12.3456 ± 0");
+    assert_eq!(display(f64::NAN, 0.04), "NaN ± 0.04");
+    assert_eq!(display(f64::NEG_INFINITY, 0.04), "-inf ± 0.04");
+    let unbounded = Measurement::new(12.3456, 0.1) / Measurement::new(0.0, 0.1);
+    assert_eq!(unbounded.to_string(), "inf ± inf");
+}
+
+#[test]
+fn test_display_scientific() {
+    let scientific = |value, uncertainty| format!("{:e}", Measurement::new(value, uncertainty));
+    assert_eq!(scientific(12.3456, 0.0372), "(1.235 ± 0.004) × 10^1");
+    assert_eq!(scientific(-12.3456, 0.0372), "(-1.235 ± 0.004) × 10^1");
+    assert_eq!(scientific(-0.012_34, 0.000_37), "(-1.23 ± 0.04) × 10^-2");
+    assert_eq!(scientific(6.022e23, 3e20), "(6.022 ± 0.003) × 10^23");
+
+    // Rounding the uncertainty up to the next power of ten moves the position along with it
+    assert_eq!(scientific(12.3456, 0.096), "(1.23 ± 0.01) × 10^1");
+    assert_eq!(scientific(9.87, 1.4), "(1.0 ± 0.1) × 10^1");
+
+    // Uncertainties of at least one
+    assert_eq!(scientific(1234.5, 27.0), "(1.23 ± 0.03) × 10^3");
+    assert_eq!(scientific(1234.5, 2.0), "(1.235 ± 0.002) × 10^3");
+
+    // Uncertainties larger than the value, which take the power of ten when the value rounds to 0
+    assert_eq!(scientific(12.3456, 270.0), "(0 ± 3) × 10^2");
+    assert_eq!(scientific(-0.02, 0.3), "(0 ± 3) × 10^-1");
+    let length = Measurement::new(12.3456, 270.0);
+    assert_eq!(format!("{length:.2e}"), "(1 ± 27) × 10^1");
+
+    // The precision is the number of significant figures of the uncertainty
+    let length = Measurement::new(12.3456, 0.0996);
+    assert_eq!(format!("{length:.2e}"), "(1.235 ± 0.010) × 10^1");
+
+    // Uncertainties and values that don't give a position
+    assert_eq!(scientific(12.3456, 0.0), "(1.23456 ± 0) × 10^1");
+    assert_eq!(scientific(0.0, 0.0), "(0 ± 0) × 10^0");
+    assert_eq!(scientific(f64::NAN, 0.04), "NaN ± 0.04");
+    // An uncertainty that overflows when it is rounded
+    let unbounded = Measurement::new(12.3456, f64::MAX);
+    assert_eq!(format!("{unbounded:e}"), "(1.23456 ± inf) × 10^1");
+}
+
+#[cfg(test)]
+fn assert_close(measurement: Measurement, value: f64, uncertainty: f64) {
+    let close = |a: f64, b: f64| (a - b).abs() <= 1e-12 * b.abs().max(1.0);
+    assert!(
+        close(measurement.value(), value) && close(measurement.uncertainty(), uncertainty),
+        "{measurement:?} isn't {value} ± {uncertainty}"
+    );
+}
+
+#[test]
+fn test_add_sub() {
+    // Absolute uncertainties add in quadrature
+    let sum = Measurement::new(2.0, 0.3) + Measurement::new(1.5, 0.4);
+    assert_close(sum, 3.5, 0.5);
+    let difference = Measurement::new(5.0, 0.3) - Measurement::new(2.0, 0.4);
+    assert_close(difference, 3.0, 0.5);
+    assert_close(-difference, -3.0, 0.5);
+
+    // Independent measurements never cancel
+    let length = Measurement::new(12.0, 0.5);
+    assert_close(length - length, 0.0, 0.5 * 2_f64.sqrt());
+    assert_close(length + Measurement::new(3.0, 0.0), 15.0, 0.5);
+}
+
+#[test]
+fn test_mul_div() {
+    // Relative uncertainties of 1% and 2.5% add in quadrature to 2.69%
+    let product = Measurement::new(10.0, 0.1) * Measurement::new(2.0, 0.05);
+    assert_close(product, 20.0, 0.29_f64.sqrt());
+    assert_eq!(product.to_string(), "20.0 ± 0.5");
+    let quotient = Measurement::new(10.0, 0.1) / Measurement::new(2.0, 0.05);
+    assert_close(quotient, 5.0, 0.0725_f64.sqrt() / 2.0);
+    assert_eq!(quotient.to_string(), "5.0 ± 0.1");
+    assert_close(
+        Measurement::new(-10.0, 0.1) / Measurement::new(2.0, 0.05),
+        -5.0,
+        0.0725_f64.sqrt() / 2.0,
+    );
+
+    // A value of zero has no relative uncertainty to add but still contributes
+    let product = Measurement::new(0.0, 0.1) * Measurement::new(2.0, 0.05);
+    assert_close(product, 0.0, 0.2);
+    let quotient = Measurement::new(0.0, 0.1) / Measurement::new(2.0, 0.05);
+    assert_close(quotient, 0.0, 0.05);
+
+    // Results aren't rounded
+    let third = Measurement::new(1.0, 0.01) / Measurement::new(3.0, 0.0);
+    assert_eq!(third.value(), 1.0 / 3.0);
+    assert_close(third, 1.0 / 3.0, 0.01 / 3.0);
+}
+
+#[test]
+fn test_div_by_zero() {
+    let quotient = Measurement::new(1.0, 0.1) / Measurement::new(0.0, 0.1);
+    assert_eq!(quotient.value(), f64::INFINITY);
+    assert_eq!(quotient.uncertainty(), f64::INFINITY);
+    assert_eq!(quotient.to_string(), "inf ± inf");
+
+    let quotient = Measurement::new(-1.0, 0.1) / Measurement::new(0.0, 0.0);
+    assert_eq!(quotient.value(), f64::NEG_INFINITY);
+    assert_eq!(quotient.uncertainty(), f64::INFINITY);
+
+    let quotient = Measurement::new(0.0, 0.1) / Measurement::new(0.0, 0.1);
+    assert!(quotient.value().is_nan());
+    assert_eq!(quotient.uncertainty(), f64::INFINITY);
+
+    let reciprocal = Measurement::new(0.0, 0.0).powi(-1);
+    assert_eq!(reciprocal.value(), f64::INFINITY);
+    assert_eq!(reciprocal.uncertainty(), f64::INFINITY);
+}
+
+#[test]
+fn test_powi() {
+    // The relative uncertainty is multiplied by the magnitude of the power
+    let side = Measurement::new(2.0, 0.1);
+    assert_close(side.powi(3), 8.0, 1.2);
+    assert_close(side.powi(-1), 0.5, 0.025);
+    assert_close(side.powi(-2), 0.25, 0.025);
+    assert_close(side.powi(1), 2.0, 0.1);
+    assert_close((-side).powi(3), -8.0, 1.2);
+    assert_close((-side).powi(2), 4.0, 0.4);
+    assert_eq!(side.powi(0), Measurement::new(1.0, 0.0));
+    assert_eq!(
+        Measurement::new(0.0, 0.1).powi(0),
+        Measurement::new(1.0, 0.0)
+    );
+    assert_close(Measurement::new(0.0, 0.1).powi(2), 0.0, 0.0);
+
+    // Squaring differs from multiplying by itself, which treats the factors as independent
+    assert_close(side * side, 4.0, 0.2 * 2_f64.sqrt());
+}
+
+#[test]
+fn test_chained() {
+    // The density of a cube from its mass of 50.0 ± 0.5 g and side of 2.00 ± 0.02 cm, with
+    // relative uncertainties of 1% and 3% for the volume adding in quadrature to 3.16%
+    let mass = Measurement::new(50.0, 0.5);
+    let side = Measurement::new(2.0, 0.02);
+    let density = mass / side.powi(3);
+    assert_close(density, 6.25, 6.25 * 0.001_f64.sqrt());
+    assert_eq!(density.to_string(), "6.3 ± 0.2");
+    assert_eq!(format!("{density:.2}"), "6.25 ± 0.20");
+
+    // The mean of three readings with the same uncertainty has it divided by the square root of 3
+    let readings = [9.79, 9.82, 9.81].map(|reading| Measurement::new(reading, 0.03));
+    let mean = (readings[0] + readings[1] + readings[2]) / Measurement::new(3.0, 0.0);
+    assert_close(mean, 9.806_666_666_666_667, 0.03 / 3_f64.sqrt());
+    assert_eq!(mean.to_string(), "9.81 ± 0.02");
+}
+
+#[test]
+fn test_relative_uncertainty() {
+    assert_eq!(Measurement::new(-20.0, 0.5).relative_uncertainty(), 0.025);
+    assert_eq!(Measurement::new(20.0, 0.0).relative_uncertainty(), 0.0);
+    assert_eq!(
+        Measurement::new(0.0, 0.5).relative_uncertainty(),
+        f64::INFINITY
+    );
+    assert!(Measurement::new(0.0, 0.0).relative_uncertainty().is_nan());
+}
@@ -1,3 +1,164 @@
+mod all;
+#[cfg(feature = "alloc")]
+mod allocate;
+#[cfg(feature = "alloc")]
+mod apportion;
+#[cfg(feature = "ndarray")]
+mod array;
+#[cfg(feature = "bigint")]
+mod bigint;
+mod bits;
+mod bucket;
+mod bytes;
+mod clamp;
+mod cmp;
+mod constant;
+mod currency;
+#[cfg(feature = "decimal")]
+mod decimal;
+mod diffuse;
+mod digits;
+mod duration;
+mod error;
+mod exact;
+mod fallback;
+mod fixed;
+#[cfg(feature = "alloc")]
+mod format;
+#[cfg(feature = "num-traits")]
+mod generic;
+mod integer;
+mod interval;
+mod iter;
+mod linspace;
+#[cfg(not(feature = "std"))]
+pub(crate) mod math;
+#[cfg(feature = "nalgebra")]
+mod matrix;
+mod measurement;
+mod mode;
+mod multiple;
+mod newtype;
+mod nice;
+mod nonzero;
+mod outcome;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "alloc")]
+mod parse;
+mod pow2;
+mod powers;
+mod reference;
+mod residual;
+mod rounded;
+mod scale;
+#[cfg(feature = "serde")]
+pub mod serde_dp;
+#[cfg(feature = "serde")]
+pub mod serde_sf;
+mod si;
+mod slice;
+mod snap;
+#[cfg(feature = "alloc")]
+mod sum;
+mod tick;
+mod time;
+mod wrapping;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+pub use all::RoundAll;
+#[cfg(feature = "alloc")]
+pub use allocate::{allocate, allocate_weighted, try_allocate, try_allocate_weighted};
+#[cfg(feature = "alloc")]
+pub use apportion::{apportion, try_apportion, ApportionMethod};
+#[cfg(feature = "ndarray")]
+pub use array::RoundArrayExt;
+#[cfg(feature = "alloc")]
+pub use bytes::{format_bytes, format_bytes_units};
+pub use bucket::{bucket_bounds, bucket_index, try_bucket_index};
+pub use bytes::{parse_bytes, ByteUnits};
+pub use clamp::{
+    ceil_dp_clamped, ceil_sf_clamped, floor_dp_clamped, floor_sf_clamped, round_dp_clamped,
+    round_sf_clamped, try_ceil_dp_clamped, try_ceil_sf_clamped, try_floor_dp_clamped,
+    try_floor_sf_clamped, try_round_dp_clamped, try_round_sf_clamped,
+};
+pub use cmp::{approx_eq_dp, approx_eq_sf};
+pub use constant::*;
+pub use currency::{round_currency, round_currency_step, to_minor_units, to_minor_units_step};
+pub use diffuse::{quantize_grid_diffuse, round_grid_diffuse, Quantizer, ScanOrder};
+pub use digits::Digits;
+pub use duration::{
+    ceil_duration, ceil_duration_to, floor_duration, floor_duration_to, round_duration,
+    round_duration_to, DurationUnit,
+};
+pub use error::{ParseFixedError, ParseLocaleError, ParseSfError, RoundingError};
+pub use fixed::Fixed;
+#[cfg(feature = "alloc")]
+pub use format::Notation;
+#[cfg(feature = "num-traits")]
+pub use generic::{
+    ceil_dp_generic, ceil_sf_generic, ceil_zeros_generic, floor_dp_generic, floor_sf_generic,
+    floor_zeros_generic, round_dp_generic, round_dp_mode_generic, round_sf_generic,
+    round_sf_mode_generic, round_zeros_generic, round_zeros_mode_generic,
+};
+pub use interval::{
+    round_interval_inward, round_interval_inward_sf, round_interval_outward,
+    round_interval_outward_sf, try_round_interval_inward, try_round_interval_inward_sf,
+    try_round_interval_outward, try_round_interval_outward_sf,
+};
+pub use iter::{RoundDp, RoundIteratorExt, RoundSf, RoundZeros};
+pub use linspace::{linspace, linspace_rounded, Linspace};
+#[cfg(feature = "nalgebra")]
+pub use matrix::RoundMatrixExt;
+pub use measurement::Measurement;
+pub use mode::{Direction, RoundingMode};
+#[doc(hidden)]
+pub use newtype::__private;
+#[cfg(feature = "alloc")]
+pub use nice::ticks;
+pub use nice::{ceil_nice, floor_nice, nice_bounds, round_nice};
+pub use nonzero::{
+    ceil_sf_nz, ceil_zeros_nz, floor_sf_nz, floor_zeros_nz, round_sf_mode_nz, round_sf_nz,
+    round_zeros_mode_nz, round_zeros_nz, RoundableNonZero,
+};
+pub use outcome::{
+    round_dp_outcome, round_dp_with_error, round_sf_outcome, round_sf_with_error,
+    round_zeros_outcome, round_zeros_with_error, try_round_sf_with_error,
+    try_round_zeros_with_error, Adjustment, RoundOutcome,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::{
+    par_round_slice_dp, par_round_slice_dp_mode, par_round_slice_dp_simd, par_round_slice_sf,
+    par_round_slice_sf_mode, par_round_slice_zeros, par_round_slice_zeros_mode,
+};
+#[cfg(feature = "alloc")]
+pub use parse::{parse_locale, parse_sf};
+pub use pow2::{
+    ceil_pow2, checked_ceil_pow2, checked_floor_pow2, checked_round_pow2, floor_pow2, round_pow2,
+    PowerOfTwo,
+};
+pub use reference::RoundRefExt;
+pub use residual::{IntegerResidualRounder, ResidualRounder};
+pub use rounded::Rounded;
+pub use si::Prefix;
+#[cfg(feature = "alloc")]
+pub use si::{format_si, format_si_prefix, format_si_with, parse_si};
+pub use slice::{
+    ceil_slice_dp, ceil_slice_sf, ceil_slice_zeros, floor_slice_dp, floor_slice_sf,
+    floor_slice_zeros, round_slice_dp, round_slice_dp_mode, round_slice_dp_simd, round_slice_sf,
+    round_slice_sf_mode, round_slice_zeros, round_slice_zeros_mode,
+};
+pub use snap::{snap_down, snap_to_values, snap_up};
+#[cfg(feature = "alloc")]
+pub use sum::round_preserving_sum;
+pub use tick::{ceil_to_tick, floor_to_tick, is_on_tick, round_to_tick};
+pub use time::{
+    ceil_time_of_day, ceil_time_of_day_f64, floor_time_of_day, floor_time_of_day_f64,
+    round_time_of_day, round_time_of_day_f64, TimeOfDayRounding, SECONDS_PER_DAY,
+};
+
 /// Rounds the number to the given number of decimal places
 ///
 /// # Examples
@@ -71,7 +232,7 @@ pub fn ceil_zeros<T: Roundable>(number: T, zeros: u32) -> T {
 ///
 /// assert_eq!(floor(123.456_f64, 2), 123.45_f64);
 /// assert_eq!(floor(123.456_f64, 0), 123_f64);
-/// assert_eq!(floor(123.454_f32, 2), 123.454_f32);
+/// assert_eq!(floor(123.454_f32, 2), 123.45_f32);
 /// ```
 #[inline]
 pub fn floor<T: Float>(number: T, decimal_places: u32) -> T {
@@ -142,638 +303,5850 @@ pub fn floor_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
     number.floor_sf(sig_figs)
 }
 
-pub trait Float {
-    /// Rounds the number to the given number of decimal places
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123.456_f64.round_dp(2), 123.46_f64);
-    /// assert_eq!(123.456_f64.round_dp(0), 123_f64);
-    /// assert_eq!(123.456_f32.round_dp(2), 123.46_f32);
-    /// ```
-    fn round_dp(self, decimal_places: u32) -> Self;
+/// Rounds the number towards zero to the given number of decimal places
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::trunc;
+///
+/// assert_eq!(trunc(123.456_f64, 2), 123.45_f64);
+/// assert_eq!(trunc(-123.456_f64, 2), -123.45_f64);
+/// assert_eq!(trunc(-123.456_f32, 0), -123_f32);
+/// ```
+#[inline]
+pub fn trunc<T: Float>(number: T, decimal_places: u32) -> T {
+    number.trunc_dp(decimal_places)
+}
+
+/// Rounds the number towards zero to the given number of zeros
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::trunc_zeros;
+///
+/// assert_eq!(trunc_zeros(156_i32, 1), 150_i32);
+/// assert_eq!(trunc_zeros(-156_i32, 1), -150_i32);
+/// assert_eq!(trunc_zeros(-156.5_f64, 2), -100_f64);
+/// ```
+#[inline]
+pub fn trunc_zeros<T: Roundable>(number: T, zeros: u32) -> T {
+    number.trunc_zeros(zeros)
+}
+
+/// Rounds the number towards zero to the given number of significant figures
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::trunc_sf;
+///
+/// assert_eq!(trunc_sf(123.456_f64, 4), 123.4_f64);
+/// assert_eq!(trunc_sf(-123.456_f64, 4), -123.4_f64);
+/// assert_eq!(trunc_sf(-1567_i32, 2), -1500_i32);
+/// ```
+#[inline]
+pub fn trunc_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.trunc_sf(sig_figs)
+}
+
+/// Rounds the number away from zero to the given number of decimal places
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::away;
+///
+/// assert_eq!(away(123.451_f64, 2), 123.46_f64);
+/// assert_eq!(away(-123.451_f64, 2), -123.46_f64);
+/// assert_eq!(away(-123.456_f32, 0), -124_f32);
+/// ```
+#[inline]
+pub fn away<T: Float>(number: T, decimal_places: u32) -> T {
+    number.away_dp(decimal_places)
+}
+
+/// Rounds the number away from zero to the given number of zeros
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::away_zeros;
+///
+/// assert_eq!(away_zeros(151_i32, 1), 160_i32);
+/// assert_eq!(away_zeros(-151_i32, 1), -160_i32);
+/// assert_eq!(away_zeros(-151.5_f64, 2), -200_f64);
+/// ```
+#[inline]
+pub fn away_zeros<T: Roundable>(number: T, zeros: u32) -> T {
+    number.away_zeros(zeros)
+}
+
+/// Rounds the number away from zero to the given number of significant figures
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::away_sf;
+///
+/// assert_eq!(away_sf(123.412_f64, 4), 123.5_f64);
+/// assert_eq!(away_sf(-123.412_f64, 4), -123.5_f64);
+/// assert_eq!(away_sf(-1512_i32, 2), -1600_i32);
+/// ```
+#[inline]
+pub fn away_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.away_sf(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places in the given direction
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_dir, Direction};
+///
+/// assert_eq!(round_dir(123.456_f64, 2, Direction::Nearest), 123.46_f64);
+/// assert_eq!(round_dir(-123.456_f64, 2, Direction::TowardZero), -123.45_f64);
+/// assert_eq!(round_dir(-123.451_f32, 2, Direction::AwayFromZero), -123.46_f32);
+/// ```
+#[inline]
+pub fn round_dir<T: Float>(number: T, decimal_places: u32, direction: Direction) -> T {
+    match direction {
+        Direction::Nearest => number.round_dp(decimal_places),
+        Direction::Up => number.ceil_dp(decimal_places),
+        Direction::Down => number.floor_dp(decimal_places),
+        Direction::TowardZero => number.trunc_dp(decimal_places),
+        Direction::AwayFromZero => number.away_dp(decimal_places),
+    }
+}
+
+/// Rounds the number to the given number of zeros in the given direction
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_zeros_dir, Direction};
+///
+/// assert_eq!(round_zeros_dir(156_i32, 1, Direction::Down), 150_i32);
+/// assert_eq!(round_zeros_dir(-156_i32, 1, Direction::TowardZero), -150_i32);
+/// assert_eq!(round_zeros_dir(-151.5_f64, 2, Direction::AwayFromZero), -200_f64);
+/// ```
+#[inline]
+pub fn round_zeros_dir<T: Roundable>(number: T, zeros: u32, direction: Direction) -> T {
+    match direction {
+        Direction::Nearest => number.round_zeros(zeros),
+        Direction::Up => number.ceil_zeros(zeros),
+        Direction::Down => number.floor_zeros(zeros),
+        Direction::TowardZero => number.trunc_zeros(zeros),
+        Direction::AwayFromZero => number.away_zeros(zeros),
+    }
+}
+
+/// Rounds the number to the given number of significant figures in the given direction
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_sf_dir, Direction};
+///
+/// assert_eq!(round_sf_dir(123456_f64, 4, Direction::Up), 123500_f64);
+/// assert_eq!(round_sf_dir(-1567_i32, 2, Direction::TowardZero), -1500_i32);
+/// assert_eq!(round_sf_dir(-123.412_f32, 4, Direction::AwayFromZero), -123.5_f32);
+/// ```
+#[inline]
+pub fn round_sf_dir<T: Roundable>(number: T, sig_figs: u32, direction: Direction) -> T {
+    match direction {
+        Direction::Nearest => number.round_sf(sig_figs),
+        Direction::Up => number.ceil_sf(sig_figs),
+        Direction::Down => number.floor_sf(sig_figs),
+        Direction::TowardZero => number.trunc_sf(sig_figs),
+        Direction::AwayFromZero => number.away_sf(sig_figs),
+    }
+}
+
+/// Rounds the number to the nearest multiple of `1 / denominator`, ties go away from zero
+///
+/// The multiple is found from the exact product of the number and the denominator and divided
+/// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+/// come out as the closest float
+///
+/// A number that is the closest float to a multiple is already on it, like `0.7` for `1 / 10`,
+/// so a power of ten denominator gives the same result as rounding to decimal places
+///
+/// # Panics
+///
+/// If `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_frac;
+///
+/// assert_eq!(round_frac(1.3_f64, 4), 1.25_f64);
+/// assert_eq!(round_frac(0.3_f64, 3), 1.0_f64 / 3.0);
+/// assert_eq!(round_frac(-0.04_f64, 16), -0.0625_f64);
+/// ```
+#[inline]
+pub fn round_frac<T: Float>(number: T, denominator: u32) -> T {
+    number.round_frac(denominator)
+}
+
+/// Rounds the number up to the next multiple of `1 / denominator`
+///
+/// The multiple is found from the exact product of the number and the denominator and divided
+/// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+/// come out as the closest float
+///
+/// A number that is the closest float to a multiple is already on it, like `0.7` for `1 / 10`,
+/// so a power of ten denominator gives the same result as rounding to decimal places
+///
+/// # Panics
+///
+/// If `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_frac;
+///
+/// assert_eq!(ceil_frac(1.3_f64, 4), 1.5_f64);
+/// assert_eq!(ceil_frac(0.3_f64, 3), 1.0_f64 / 3.0);
+/// assert_eq!(ceil_frac(-0.03_f64, 16), -0_f64);
+/// ```
+#[inline]
+pub fn ceil_frac<T: Float>(number: T, denominator: u32) -> T {
+    number.ceil_frac(denominator)
+}
+
+/// Rounds the number down to the previous multiple of `1 / denominator`
+///
+/// The multiple is found from the exact product of the number and the denominator and divided
+/// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+/// come out as the closest float
+///
+/// A number that is the closest float to a multiple is already on it, like `0.7` for `1 / 10`,
+/// so a power of ten denominator gives the same result as rounding to decimal places
+///
+/// # Panics
+///
+/// If `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_frac;
+///
+/// assert_eq!(floor_frac(1.3_f64, 4), 1.25_f64);
+/// assert_eq!(floor_frac(0.3_f64, 3), 0_f64);
+/// assert_eq!(floor_frac(-0.03_f64, 16), -0.0625_f64);
+/// ```
+#[inline]
+pub fn floor_frac<T: Float>(number: T, denominator: u32) -> T {
+    number.floor_frac(denominator)
+}
+
+/// Rounds the number to the nearest multiple of `1 / denominator`, ties go away from zero,
+/// returning an error if the number isn't finite or `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_frac, RoundingError};
+///
+/// assert_eq!(try_round_frac(1.3_f64, 4), Ok(1.25_f64));
+/// assert_eq!(try_round_frac(1.3_f64, 0), Err(RoundingError::ZeroDenominator));
+/// assert_eq!(try_round_frac(f64::NAN, 4), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_round_frac<T: Float>(number: T, denominator: u32) -> Result<T, RoundingError> {
+    number.try_round_frac(denominator)
+}
+
+/// Rounds the number up to the next multiple of `1 / denominator`,
+/// returning an error if the number isn't finite or `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_frac, RoundingError};
+///
+/// assert_eq!(try_ceil_frac(1.3_f64, 4), Ok(1.5_f64));
+/// assert_eq!(try_ceil_frac(1.3_f32, 0), Err(RoundingError::ZeroDenominator));
+/// assert_eq!(try_ceil_frac(f64::INFINITY, 4), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_ceil_frac<T: Float>(number: T, denominator: u32) -> Result<T, RoundingError> {
+    number.try_ceil_frac(denominator)
+}
+
+/// Rounds the number down to the previous multiple of `1 / denominator`,
+/// returning an error if the number isn't finite or `denominator` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_frac, RoundingError};
+///
+/// assert_eq!(try_floor_frac(1.3_f64, 4), Ok(1.25_f64));
+/// assert_eq!(try_floor_frac(1.3_f64, 0), Err(RoundingError::ZeroDenominator));
+/// assert_eq!(try_floor_frac(-0.7_f32, 2), Ok(-1_f32));
+/// ```
+#[inline]
+pub fn try_floor_frac<T: Float>(number: T, denominator: u32) -> Result<T, RoundingError> {
+    number.try_floor_frac(denominator)
+}
+
+/// Rounds the number to the given number of significant bits, ties go to the even bit
+///
+/// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+/// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+/// floats have fewer bits to begin with, infinities and NaN are returned as is
+///
+/// # Panics
+///
+/// If `bits` is zero or more than the significant bits of the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_bits;
+///
+/// assert_eq!(round_bits(1.3_f64, 2), 1.5_f64);
+/// assert_eq!(round_bits(0.625_f64, 2), 0.5_f64);
+/// assert_eq!(round_bits(0.875_f32, 2), 1_f32);
+/// ```
+#[inline]
+pub fn round_bits<T: Float>(number: T, bits: u32) -> T {
+    number.round_bits(bits)
+}
+
+/// Rounds the number towards zero to the given number of significant bits
+///
+/// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+/// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+/// floats have fewer bits to begin with, infinities and NaN are returned as is
+///
+/// # Panics
+///
+/// If `bits` is zero or more than the significant bits of the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::trunc_bits;
+///
+/// assert_eq!(trunc_bits(1.9_f64, 1), 1_f64);
+/// assert_eq!(trunc_bits(-0.875_f64, 2), -0.75_f64);
+/// assert_eq!(trunc_bits(7_f32, 2), 6_f32);
+/// ```
+#[inline]
+pub fn trunc_bits<T: Float>(number: T, bits: u32) -> T {
+    number.trunc_bits(bits)
+}
+
+/// Rounds the number up to the given number of significant bits
+///
+/// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+/// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+/// floats have fewer bits to begin with, infinities and NaN are returned as is
+///
+/// # Panics
+///
+/// If `bits` is zero or more than the significant bits of the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_bits;
+///
+/// assert_eq!(ceil_bits(1.1_f64, 2), 1.5_f64);
+/// assert_eq!(ceil_bits(-0.875_f64, 2), -0.75_f64);
+/// assert_eq!(ceil_bits(5_f32, 2), 6_f32);
+/// ```
+#[inline]
+pub fn ceil_bits<T: Float>(number: T, bits: u32) -> T {
+    number.ceil_bits(bits)
+}
+
+/// Counts the digits after the decimal point in the shortest decimal representation of the number
+/// that round trips, so `0.1` has 1 decimal place even though the float isn't exactly `0.1`
+///
+/// Whole numbers have none, and neither do non finite numbers
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::count_decimal_places;
+///
+/// assert_eq!(count_decimal_places(0.25_f64), 2);
+/// assert_eq!(count_decimal_places(0.1_f64), 1);
+/// assert_eq!(count_decimal_places(1e-7_f64), 7);
+/// assert_eq!(count_decimal_places(1500_f32), 0);
+/// ```
+#[inline]
+pub fn count_decimal_places<T: Float>(number: T) -> u32 {
+    number.count_decimal_places()
+}
+
+/// Whether rounding the number to the given number of decimal places would change it, which is
+/// whenever it has more decimal places than that
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::needs_rounding;
+///
+/// assert!(needs_rounding(0.25_f64, 1));
+/// assert!(!needs_rounding(0.25_f64, 2));
+/// assert!(needs_rounding(1e-7_f32, 6));
+/// assert!(!needs_rounding(f64::NAN, 0));
+/// ```
+#[inline]
+pub fn needs_rounding<T: Float>(number: T, decimal_places: u32) -> bool {
+    number.needs_rounding(decimal_places)
+}
+
+/// Counts the digits of the integer part of the number, which is 1 for zero and for floats
+/// with a magnitude below one. Non finite numbers have none
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::digit_count;
+///
+/// assert_eq!(digit_count(9_u8), 1);
+/// assert_eq!(digit_count(10_i32), 2);
+/// assert_eq!(digit_count(1e15_f64), 16);
+/// assert_eq!(digit_count(f32::NAN), 0);
+/// ```
+#[inline]
+pub fn digit_count<T: Roundable>(number: T) -> u32 {
+    number.digit_count()
+}
+
+/// The base 10 digits of the integer part of the number, most significant first
+///
+/// Floats use their shortest decimal representation that round trips, so the digits of `1e20`
+/// are a one followed by twenty zeros. Non finite numbers have no digits
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::digits;
+///
+/// assert!(digits(0_u8).eq([0]));
+/// assert!(digits(-907_i16).eq([9, 0, 7]));
+/// assert!(digits(12.9_f32).eq([1, 2]));
+/// assert_eq!(digits(1e20_f64).count(), 21);
+/// ```
+#[inline]
+pub fn digits<T: Roundable>(number: T) -> Digits {
+    number.digits()
+}
+
+/// Rounds the number to the given number of decimal places like [`round`] and renders it with
+/// exactly that many digits after the decimal point, keeping trailing zeros
+///
+/// Magnitudes below one have a `0` before the decimal point, and results that round to zero keep
+/// the sign of the number like [`round`] does, so `-0.001` renders as `-0.00`.
+/// NaN renders as `NaN` and infinities as `inf` and `-inf`
+///
+/// The string parses back to the rounded number, except for numbers with more significant digits
+/// than the type can hold at those decimal places, which aren't rounded and can only be rendered
+/// to within an ulp
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_dp;
+///
+/// assert_eq!(format_dp(1.5_f64, 2), "1.50");
+/// assert_eq!(format_dp(-0.125_f64, 2), "-0.13");
+/// assert_eq!(format_dp(2.5_f32, 0), "3");
+/// assert_eq!(format_dp(f64::NAN, 2), "NaN");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_dp<T: Float>(number: T, decimal_places: u32) -> String {
+    number.format_dp(decimal_places)
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`] and renders
+/// it with exactly that many significant digits in fixed notation, padding with trailing zeros
+/// after the decimal point so `1.203` to 3 significant figures is `1.20`
+///
+/// An integer part with more digits than the significant figures is padded with zeros, which is
+/// ambiguous as `123500` could have 4, 5 or 6 significant figures, use [`format_sf_notation`] with
+/// [`Notation::Scientific`] to avoid it. Integers that round past the bounds of their type aren't
+/// saturated, so `250_u8` to 1 significant figure is `300`. Results that round to zero keep the
+/// sign of the number and zero significant figures render as `0`.
+/// NaN renders as `NaN` and infinities as `inf` and `-inf`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_sf;
+///
+/// assert_eq!(format_sf(1.203_f64, 3), "1.20");
+/// assert_eq!(format_sf(0.0034_f64, 3), "0.00340");
+/// assert_eq!(format_sf(-123_456_i32, 4), "-123500");
+/// assert_eq!(format_sf(f64::NAN, 2), "NaN");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_sf<T: Roundable>(number: T, sig_figs: u32) -> String {
+    number.format_sf(sig_figs)
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`] and renders
+/// it with exactly that many significant digits in the given notation
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{format_sf_notation, Notation};
+///
+/// assert_eq!(format_sf_notation(1.203_f64, 3, Notation::Scientific), "1.20e0");
+/// assert_eq!(format_sf_notation(0.0034_f32, 3, Notation::Scientific), "3.40e-3");
+/// assert_eq!(format_sf_notation(-123_456_i32, 4, Notation::Scientific), "-1.235e5");
+/// assert_eq!(format_sf_notation(999_u16, 2, Notation::Fixed), "1000");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_sf_notation<T: Roundable>(number: T, sig_figs: u32, notation: Notation) -> String {
+    number.format_sf_notation(sig_figs, notation)
+}
+
+/// Rounds the number to the given number of decimal places like [`round`] and renders it like
+/// [`format_dp`], with the integer digits split into groups of three by a separator
+///
+/// Integers have no fractional part to round and are padded with zeros to the decimal places.
+/// Every magnitude is written out in full, so large numbers never switch to scientific notation
+/// and `1e20` has 21 digits. NaN renders as `NaN` and infinities as `inf` and `-inf`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_grouped;
+///
+/// assert_eq!(format_grouped(1_234_567.891_f64, 2, ',', '.'), "1,234,567.89");
+/// assert_eq!(format_grouped(1_234_567.891_f64, 2, '.', ','), "1.234.567,89");
+/// assert_eq!(format_grouped(-999.5_f64, 0, ',', '.'), "-1,000");
+/// assert_eq!(format_grouped(1_234_567_u32, 1, ' ', '.'), "1 234 567.0");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_grouped<T: Roundable>(
+    number: T,
+    decimal_places: u32,
+    group_separator: char,
+    decimal_separator: char,
+) -> String {
+    number.format_grouped(decimal_places, group_separator, decimal_separator)
+}
+
+/// Rounds the number to the given number of decimal places like [`round`] and renders it
+/// with the given decimal separator, and the integer digits split into groups of three by the
+/// group separator if there is one. The inverse of [`parse_locale`]
+///
+/// [`format_grouped`] renders the digits the same way, with the separator arguments swapped and
+/// a group separator required
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_locale;
+///
+/// assert_eq!(format_locale(1_234.567_f64, 2, ',', Some('.')), "1.234,57");
+/// assert_eq!(format_locale(1_234.567_f64, 2, ',', None), "1234,57");
+/// assert_eq!(format_locale(-0.5_f32, 1, '.', Some(',')), "-0.5");
+/// assert_eq!(format_locale(12_345_i32, 0, ',', Some(' ')), "12 345");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_locale<T: Roundable>(
+    number: T,
+    decimal_places: u32,
+    decimal_separator: char,
+    group_separator: Option<char>,
+) -> String {
+    number.format_locale(decimal_places, decimal_separator, group_separator)
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`] and renders
+/// it in engineering notation, with one to three digits before the decimal point and a power of
+/// ten that is a multiple of three
+///
+/// Rounding up to the next power of ten moves the exponent along, so `999.7` to 3 significant
+/// figures is `1.00e3`. See [`Notation::Engineering`] for how the digits are laid out
+///
+/// # Panics
+///
+/// If `sig_figs` is zero, as there is no digit to put before the decimal point
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_engineering;
+///
+/// assert_eq!(format_engineering(0.00047, 2), "470e-6");
+/// assert_eq!(format_engineering(12_345_678.0, 3), "12.3e6");
+/// assert_eq!(format_engineering(-999.7, 3), "-1.00e3");
+/// assert_eq!(format_engineering(0.0, 2), "0.0e0");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_engineering(number: f64, sig_figs: u32) -> String {
+    assert!(sig_figs > 0, "sig_figs must be greater than zero");
+    number.format_sf_notation(sig_figs, Notation::Engineering)
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`] and renders
+/// it in engineering notation like [`format_engineering`], writing the power of ten as `× 10`
+/// with a superscript exponent
+///
+/// # Panics
+///
+/// If `sig_figs` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_engineering_superscript;
+///
+/// assert_eq!(format_engineering_superscript(0.00047, 2), "470 × 10⁻⁶");
+/// assert_eq!(format_engineering_superscript(12_345_678.0, 3), "12.3 × 10⁶");
+/// assert_eq!(format_engineering_superscript(f64::INFINITY, 3), "inf");
+/// ```
+#[inline]
+#[cfg(feature = "alloc")]
+pub fn format_engineering_superscript(number: f64, sig_figs: u32) -> String {
+    format::superscript(&format_engineering(number, sig_figs))
+}
+
+/// Splits the number rounded to the given number of significant figures like [`round_sf`] into a
+/// mantissa with a magnitude in `[1, 10)` and a power of ten, so `mantissa * 10^exponent` is the
+/// rounded number to within an ulp
+///
+/// The exponent is taken after rounding, so `9.97` to 2 significant figures is `(1.0, 1)`.
+/// Negative numbers have a negative mantissa, zero is `(0.0, 0)` keeping its sign and non finite
+/// numbers are returned as they are with an exponent of `0`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::to_scientific;
+///
+/// assert_eq!(to_scientific(123_456_f64, 3), (1.23, 5));
+/// assert_eq!(to_scientific(0.00345_f64, 2), (3.5, -3));
+/// assert_eq!(to_scientific(-9.97_f32, 2), (-1.0, 1));
+/// assert_eq!(to_scientific(0.0_f64, 3), (0.0, 0));
+/// ```
+#[inline]
+pub fn to_scientific<T: Float>(number: T, sig_figs: u32) -> (T, i32) {
+    number.to_scientific(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places using the given rounding mode
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_dp_mode, RoundingMode};
+///
+/// assert_eq!(round_dp_mode(2.5_f64, 0, RoundingMode::HalfEven), 2_f64);
+/// assert_eq!(round_dp_mode(-0.125_f64, 2, RoundingMode::HalfUp), -0.12_f64);
+/// assert_eq!(round_dp_mode(123.451_f32, 1, RoundingMode::TowardZero), 123.4_f32);
+/// ```
+#[inline]
+pub fn round_dp_mode<T: Float>(number: T, decimal_places: u32, mode: RoundingMode) -> T {
+    number.round_dp_mode(decimal_places, mode)
+}
+
+/// Rounds the number to the given number of zeros using the given rounding mode
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_zeros_mode, RoundingMode};
+///
+/// assert_eq!(round_zeros_mode(125_i32, 1, RoundingMode::HalfEven), 120_i32);
+/// assert_eq!(round_zeros_mode(-125_i32, 1, RoundingMode::HalfDown), -130_i32);
+/// assert_eq!(round_zeros_mode(121.5_f64, 1, RoundingMode::AwayFromZero), 130_f64);
+/// ```
+#[inline]
+pub fn round_zeros_mode<T: Roundable>(number: T, zeros: u32, mode: RoundingMode) -> T {
+    number.round_zeros_mode(zeros, mode)
+}
+
+/// Rounds the number to the given number of significant figures using the given rounding mode
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_sf_mode, RoundingMode};
+///
+/// assert_eq!(round_sf_mode(1250_u32, 2, RoundingMode::HalfOdd), 1300_u32);
+/// assert_eq!(round_sf_mode(0.125_f64, 2, RoundingMode::HalfTowardZero), 0.12_f64);
+/// assert_eq!(round_sf_mode(-123.4_f32, 2, RoundingMode::Down), -130_f32);
+/// ```
+#[inline]
+pub fn round_sf_mode<T: Roundable>(number: T, sig_figs: u32, mode: RoundingMode) -> T {
+    number.round_sf_mode(sig_figs, mode)
+}
+
+/// Rounds the number to the given number of zeros,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_round_zeros;
+///
+/// assert_eq!(checked_round_zeros(123_u8, 1), Some(120_u8));
+/// assert_eq!(checked_round_zeros(250_u8, 2), None);
+/// assert_eq!(checked_round_zeros(f64::NAN, 1), None);
+/// ```
+#[inline]
+pub fn checked_round_zeros<T: Roundable>(number: T, zeros: u32) -> Option<T> {
+    number.checked_round_zeros(zeros)
+}
+
+/// Rounds the number up to the given number of zeros,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_ceil_zeros;
+///
+/// assert_eq!(checked_ceil_zeros(123_i16, 1), Some(130_i16));
+/// assert_eq!(checked_ceil_zeros(250_u8, 2), None);
+/// assert_eq!(checked_ceil_zeros(f64::INFINITY, 1), None);
+/// ```
+#[inline]
+pub fn checked_ceil_zeros<T: Roundable>(number: T, zeros: u32) -> Option<T> {
+    number.checked_ceil_zeros(zeros)
+}
+
+/// Rounds the number down to the given number of zeros,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_floor_zeros;
+///
+/// assert_eq!(checked_floor_zeros(-113_i8, 1), Some(-120_i8));
+/// assert_eq!(checked_floor_zeros(-125_i8, 1), None);
+/// assert_eq!(checked_floor_zeros(123.456_f64, 1), Some(120_f64));
+/// ```
+#[inline]
+pub fn checked_floor_zeros<T: Roundable>(number: T, zeros: u32) -> Option<T> {
+    number.checked_floor_zeros(zeros)
+}
+
+/// Rounds the number to the given number of significant figures,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_round_sf;
+///
+/// assert_eq!(checked_round_sf(123_u8, 2), Some(120_u8));
+/// assert_eq!(checked_round_sf(251_u8, 1), None);
+/// assert_eq!(checked_round_sf(123.456_f64, 2), Some(120_f64));
+/// ```
+#[inline]
+pub fn checked_round_sf<T: Roundable>(number: T, sig_figs: u32) -> Option<T> {
+    number.checked_round_sf(sig_figs)
+}
+
+/// Rounds the number up to the given number of significant figures,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_ceil_sf;
+///
+/// assert_eq!(checked_ceil_sf(123_u8, 2), Some(130_u8));
+/// assert_eq!(checked_ceil_sf(201_u8, 1), None);
+/// assert_eq!(checked_ceil_sf(f64::NAN, 2), None);
+/// ```
+#[inline]
+pub fn checked_ceil_sf<T: Roundable>(number: T, sig_figs: u32) -> Option<T> {
+    number.checked_ceil_sf(sig_figs)
+}
+
+/// Rounds the number down to the given number of significant figures,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_floor_sf;
+///
+/// assert_eq!(checked_floor_sf(-113_i8, 2), Some(-120_i8));
+/// assert_eq!(checked_floor_sf(-121_i8, 1), None);
+/// assert_eq!(checked_floor_sf(123.456_f32, 4), Some(123.4_f32));
+/// ```
+#[inline]
+pub fn checked_floor_sf<T: Roundable>(number: T, sig_figs: u32) -> Option<T> {
+    number.checked_floor_sf(sig_figs)
+}
+
+/// Rounds the number to the given number of zeros,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_round_zeros;
+///
+/// assert_eq!(saturating_round_zeros(250_u8, 2), u8::MAX);
+/// assert_eq!(saturating_round_zeros(-128_i8, 1), i8::MIN);
+/// assert_eq!(saturating_round_zeros(123_i16, 1), 120_i16);
+/// ```
+#[inline]
+pub fn saturating_round_zeros<T: Roundable>(number: T, zeros: u32) -> T {
+    number.saturating_round_zeros(zeros)
+}
+
+/// Rounds the number up to the given number of zeros,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_ceil_zeros;
+///
+/// assert_eq!(saturating_ceil_zeros(250_u8, 2), u8::MAX);
+/// assert_eq!(saturating_ceil_zeros(-128_i8, 1), -120_i8);
+/// assert_eq!(saturating_ceil_zeros(123_i16, 1), 130_i16);
+/// ```
+#[inline]
+pub fn saturating_ceil_zeros<T: Roundable>(number: T, zeros: u32) -> T {
+    number.saturating_ceil_zeros(zeros)
+}
+
+/// Rounds the number down to the given number of zeros,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_floor_zeros;
+///
+/// assert_eq!(saturating_floor_zeros(-120_i8, 2), i8::MIN);
+/// assert_eq!(saturating_floor_zeros(255_u8, 2), 200_u8);
+/// assert_eq!(saturating_floor_zeros(123_i16, 1), 120_i16);
+/// ```
+#[inline]
+pub fn saturating_floor_zeros<T: Roundable>(number: T, zeros: u32) -> T {
+    number.saturating_floor_zeros(zeros)
+}
+
+/// Rounds the number to the given number of significant figures,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_round_sf;
+///
+/// assert_eq!(saturating_round_sf(251_u8, 1), u8::MAX);
+/// assert_eq!(saturating_round_sf(-125_i8, 1), -100_i8);
+/// assert_eq!(saturating_round_sf(123_u8, 2), 120_u8);
+/// ```
+#[inline]
+pub fn saturating_round_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.saturating_round_sf(sig_figs)
+}
+
+/// Rounds the number up to the given number of significant figures,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_ceil_sf;
+///
+/// assert_eq!(saturating_ceil_sf(201_u8, 1), u8::MAX);
+/// assert_eq!(saturating_ceil_sf(-128_i8, 1), -100_i8);
+/// assert_eq!(saturating_ceil_sf(123_u8, 2), 130_u8);
+/// ```
+#[inline]
+pub fn saturating_ceil_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.saturating_ceil_sf(sig_figs)
+}
+
+/// Rounds the number down to the given number of significant figures,
+/// clamping the result to the bounds of the type if it doesn't fit
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::saturating_floor_sf;
+///
+/// assert_eq!(saturating_floor_sf(-121_i8, 1), i8::MIN);
+/// assert_eq!(saturating_floor_sf(255_u8, 1), 200_u8);
+/// assert_eq!(saturating_floor_sf(-113_i8, 2), -120_i8);
+/// ```
+#[inline]
+pub fn saturating_floor_sf<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.saturating_floor_sf(sig_figs)
+}
+
+/// Rounds the number to the given number of zeros,
+/// returning an error if the number isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_zeros, RoundingError};
+///
+/// assert_eq!(try_round_zeros(123_u8, 1), Ok(120_u8));
+/// assert_eq!(try_round_zeros(250_u8, 2), Err(RoundingError::Overflow));
+/// assert_eq!(try_round_zeros(f64::NAN, 1), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_round_zeros<T: Roundable>(number: T, zeros: u32) -> Result<T, RoundingError> {
+    number.try_round_zeros(zeros)
+}
+
+/// Rounds the number up to the given number of zeros,
+/// returning an error if the number isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_zeros, RoundingError};
+///
+/// assert_eq!(try_ceil_zeros(123_i16, 1), Ok(130_i16));
+/// assert_eq!(try_ceil_zeros(250_u8, 2), Err(RoundingError::Overflow));
+/// assert_eq!(try_ceil_zeros(f64::INFINITY, 1), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_ceil_zeros<T: Roundable>(number: T, zeros: u32) -> Result<T, RoundingError> {
+    number.try_ceil_zeros(zeros)
+}
+
+/// Rounds the number down to the given number of zeros,
+/// returning an error if the number isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_zeros, RoundingError};
+///
+/// assert_eq!(try_floor_zeros(-113_i8, 1), Ok(-120_i8));
+/// assert_eq!(try_floor_zeros(-125_i8, 1), Err(RoundingError::Overflow));
+/// assert_eq!(try_floor_zeros(123.456_f64, 1), Ok(120_f64));
+/// ```
+#[inline]
+pub fn try_floor_zeros<T: Roundable>(number: T, zeros: u32) -> Result<T, RoundingError> {
+    number.try_floor_zeros(zeros)
+}
+
+/// Rounds the number to the given number of significant figures,
+/// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_sf, RoundingError};
+///
+/// assert_eq!(try_round_sf(123_u8, 2), Ok(120_u8));
+/// assert_eq!(try_round_sf(251_u8, 1), Err(RoundingError::Overflow));
+/// assert_eq!(try_round_sf(123.456_f64, 0), Err(RoundingError::ZeroSigFigs));
+/// ```
+#[inline]
+pub fn try_round_sf<T: Roundable>(number: T, sig_figs: u32) -> Result<T, RoundingError> {
+    number.try_round_sf(sig_figs)
+}
+
+/// Rounds the number up to the given number of significant figures,
+/// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_sf, RoundingError};
+///
+/// assert_eq!(try_ceil_sf(123_u8, 2), Ok(130_u8));
+/// assert_eq!(try_ceil_sf(201_u8, 1), Err(RoundingError::Overflow));
+/// assert_eq!(try_ceil_sf(f64::NAN, 2), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_ceil_sf<T: Roundable>(number: T, sig_figs: u32) -> Result<T, RoundingError> {
+    number.try_ceil_sf(sig_figs)
+}
+
+/// Rounds the number down to the given number of significant figures,
+/// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_sf, RoundingError};
+///
+/// assert_eq!(try_floor_sf(-113_i8, 2), Ok(-120_i8));
+/// assert_eq!(try_floor_sf(123_i32, 0), Err(RoundingError::ZeroSigFigs));
+/// assert_eq!(try_floor_sf(123.456_f32, 4), Ok(123.4_f32));
+/// ```
+#[inline]
+pub fn try_floor_sf<T: Roundable>(number: T, sig_figs: u32) -> Result<T, RoundingError> {
+    number.try_floor_sf(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places,
+/// using the digits of its shortest decimal representation instead of its exact binary value
+///
+/// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+/// so this rounds the way the number was written rather than the way it is stored
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_exact;
+///
+/// assert_eq!(round_exact(2.675_f64, 2), 2.68_f64);
+/// assert_eq!(round_exact(0.145_f64, 2), 0.15_f64);
+/// assert_eq!(round_exact(1.005_f32, 2), 1.01_f32);
+/// ```
+#[inline]
+pub fn round_exact<T: Float>(number: T, decimal_places: u32) -> T {
+    number.round_dp_exact(decimal_places)
+}
+
+/// Rounds the number up to the given number of decimal places,
+/// using the digits of its shortest decimal representation instead of its exact binary value
+///
+/// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+/// so this rounds the way the number was written rather than the way it is stored
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_exact;
+///
+/// assert_eq!(ceil_exact(2.671_f64, 2), 2.68_f64);
+/// assert_eq!(ceil_exact(1.15_f64, 2), 1.15_f64);
+/// assert_eq!(ceil_exact(-0.145_f32, 2), -0.14_f32);
+/// ```
+#[inline]
+pub fn ceil_exact<T: Float>(number: T, decimal_places: u32) -> T {
+    number.ceil_dp_exact(decimal_places)
+}
+
+/// Rounds the number down to the given number of decimal places,
+/// using the digits of its shortest decimal representation instead of its exact binary value
+///
+/// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+/// so this rounds the way the number was written rather than the way it is stored
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_exact;
+///
+/// assert_eq!(floor_exact(2.679_f64, 2), 2.67_f64);
+/// assert_eq!(floor_exact(4.35_f64, 2), 4.35_f64);
+/// assert_eq!(floor_exact(-0.145_f32, 2), -0.15_f32);
+/// ```
+#[inline]
+pub fn floor_exact<T: Float>(number: T, decimal_places: u32) -> T {
+    number.floor_dp_exact(decimal_places)
+}
+
+/// Rounds the number to the given number of decimal places with ties going to the even neighbour,
+/// also known as banker's rounding
+///
+/// Ties are found in the shortest decimal representation of the number rather than its binary value
+/// scaled by a power of ten, as scaling can move a number onto or off a midpoint.
+/// `2.135` is stored slightly below `2.135` and `2.135 * 100.0` is `213.49999999999997`,
+/// but it is written as a midpoint so it rounds to `2.14`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_even;
+///
+/// assert_eq!(round_dp_even(2.125_f64, 2), 2.12_f64);
+/// assert_eq!(round_dp_even(2.135_f64, 2), 2.14_f64);
+/// assert_eq!(round_dp_even(-0.5_f32, 0), -0_f32);
+/// ```
+#[inline]
+pub fn round_dp_even<T: Float>(number: T, decimal_places: u32) -> T {
+    number.round_dp_even(decimal_places)
+}
+
+/// Rounds the number to the given number of zeros with ties going to the even neighbour,
+/// also known as banker's rounding
+///
+/// Float ties are found in the shortest decimal representation of the number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_even;
+///
+/// assert_eq!(round_zeros_even(125_i32, 1), 120_i32);
+/// assert_eq!(round_zeros_even(-135_i32, 1), -140_i32);
+/// assert_eq!(round_zeros_even(2500_f64, 3), 2000_f64);
+/// ```
+#[inline]
+pub fn round_zeros_even<T: Roundable>(number: T, zeros: u32) -> T {
+    number.round_zeros_even(zeros)
+}
+
+/// Rounds the number to the given number of significant figures with ties going to the even neighbour,
+/// also known as banker's rounding
+///
+/// Float ties are found in the shortest decimal representation of the number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_even;
+///
+/// assert_eq!(round_sf_even(1250_u32, 2), 1200_u32);
+/// assert_eq!(round_sf_even(0.135_f64, 2), 0.14_f64);
+/// assert_eq!(round_sf_even(-2.5_f32, 1), -2_f32);
+/// ```
+#[inline]
+pub fn round_sf_even<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.round_sf_even(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places with ties going towards negative infinity
+/// like [`RoundingMode::HalfDown`], so `2.5` rounds to `2` and `-2.5` rounds to `-3`
+///
+/// Ties are found in the number scaled by a power of ten, which is exact for midpoints such as `2.5`
+/// or `0.25` to one decimal place. Other midpoints are best effort as the stored value can be
+/// slightly off the midpoint, in which case it rounds by the stored value
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_half_down;
+///
+/// assert_eq!(round_dp_half_down(2.25_f64, 1), 2.2_f64);
+/// assert_eq!(round_dp_half_down(-2.25_f64, 1), -2.3_f64);
+/// assert_eq!(round_dp_half_down(2.26_f32, 1), 2.3_f32);
+/// ```
+#[inline]
+pub fn round_dp_half_down<T: Float>(number: T, decimal_places: u32) -> T {
+    number.round_dp_half_down(decimal_places)
+}
+
+/// Rounds the number to the given number of zeros with ties going towards negative infinity like
+/// [`RoundingMode::HalfDown`], so `25` rounds to `20` and `-25` rounds to `-30`
+///
+/// Float ties are found in the number scaled by a power of ten, the same as for decimal places
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_half_down;
+///
+/// assert_eq!(round_zeros_half_down(25_i32, 1), 20_i32);
+/// assert_eq!(round_zeros_half_down(-150_i32, 2), -200_i32);
+/// assert_eq!(round_zeros_half_down(-151_f64, 2), -200_f64);
+/// ```
+#[inline]
+pub fn round_zeros_half_down<T: Roundable>(number: T, zeros: u32) -> T {
+    number.round_zeros_half_down(zeros)
+}
+
+/// Rounds the number to the given number of significant figures with ties going towards negative
+/// infinity like [`RoundingMode::HalfDown`], so `2.5` rounds to `2` and `-2.5` rounds to `-3`
+///
+/// Float ties are found in the number scaled by a power of ten, the same as for decimal places
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_half_down;
+///
+/// assert_eq!(round_sf_half_down(1250_u32, 2), 1200_u32);
+/// assert_eq!(round_sf_half_down(-0.125_f64, 2), -0.13_f64);
+/// assert_eq!(round_sf_half_down(-2.5_f32, 1), -3_f32);
+/// ```
+#[inline]
+pub fn round_sf_half_down<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.round_sf_half_down(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places with ties going away from zero,
+/// so `2.5` rounds to `3` and `-2.5` rounds to `-3`
+///
+/// Ties always move away from zero and the result for a negative number is the negated result
+/// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_half_away;
+///
+/// assert_eq!(round_dp_half_away(0.125_f64, 2), 0.13_f64);
+/// assert_eq!(round_dp_half_away(-0.125_f64, 2), -0.13_f64);
+/// assert_eq!(round_dp_half_away(-2.5_f32, 0), -3_f32);
+/// ```
+#[inline]
+pub fn round_dp_half_away<T: Float>(number: T, decimal_places: u32) -> T {
+    number.round_dp_half_away(decimal_places)
+}
+
+/// Rounds the number to the given number of zeros with ties going away from zero,
+/// so `25` rounds to `30` and `-25` rounds to `-30`
+///
+/// Ties always move away from zero and the result for a negative number is the negated result
+/// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_half_away;
+///
+/// assert_eq!(round_zeros_half_away(25_i32, 1), 30_i32);
+/// assert_eq!(round_zeros_half_away(-25_i32, 1), -30_i32);
+/// assert_eq!(round_zeros_half_away(-150_f64, 2), -200_f64);
+/// ```
+#[inline]
+pub fn round_zeros_half_away<T: Roundable>(number: T, zeros: u32) -> T {
+    number.round_zeros_half_away(zeros)
+}
+
+/// Rounds the number to the given number of significant figures with ties going away from zero,
+/// so `2.5` rounds to `3` and `-2.5` rounds to `-3`
+///
+/// Ties always move away from zero and the result for a negative number is the negated result
+/// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_half_away;
+///
+/// assert_eq!(round_sf_half_away(1250_u32, 2), 1300_u32);
+/// assert_eq!(round_sf_half_away(-0.125_f64, 2), -0.13_f64);
+/// assert_eq!(round_sf_half_away(-2.5_f32, 1), -3_f32);
+/// ```
+#[inline]
+pub fn round_sf_half_away<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.round_sf_half_away(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places with ties going to the odd neighbour
+///
+/// Float ties are found in the shortest decimal representation of the number, the same as for [`round_dp_even`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_half_odd;
+///
+/// assert_eq!(round_dp_half_odd(2.125_f64, 2), 2.13_f64);
+/// assert_eq!(round_dp_half_odd(2.135_f64, 2), 2.13_f64);
+/// assert_eq!(round_dp_half_odd(-2.5_f32, 0), -3_f32);
+/// ```
+#[inline]
+pub fn round_dp_half_odd<T: Float>(number: T, decimal_places: u32) -> T {
+    number.round_dp_half_odd(decimal_places)
+}
+
+/// Rounds the number to the given number of zeros with ties going to the odd neighbour
+///
+/// Float ties are found in the shortest decimal representation of the number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_half_odd;
+///
+/// assert_eq!(round_zeros_half_odd(125_i32, 1), 130_i32);
+/// assert_eq!(round_zeros_half_odd(-135_i32, 1), -130_i32);
+/// assert_eq!(round_zeros_half_odd(2500_f64, 3), 3000_f64);
+/// ```
+#[inline]
+pub fn round_zeros_half_odd<T: Roundable>(number: T, zeros: u32) -> T {
+    number.round_zeros_half_odd(zeros)
+}
+
+/// Rounds the number to the given number of significant figures with ties going to the odd neighbour
+///
+/// Float ties are found in the shortest decimal representation of the number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_half_odd;
+///
+/// assert_eq!(round_sf_half_odd(1250_u32, 2), 1300_u32);
+/// assert_eq!(round_sf_half_odd(0.135_f64, 2), 0.13_f64);
+/// assert_eq!(round_sf_half_odd(-3.5_f32, 1), -3_f32);
+/// ```
+#[inline]
+pub fn round_sf_half_odd<T: Roundable>(number: T, sig_figs: u32) -> T {
+    number.round_sf_half_odd(sig_figs)
+}
+
+/// Rounds the number to the given number of decimal places, up or down at random
+/// with a probability of going up equal to its distance from the step below
+///
+/// `random` must give values uniformly distributed in `[0, 1)`, a seeded generator makes the
+/// results reproducible. On average the rounded values keep the value of the number,
+/// so `2.37` to one decimal place gives `2.4` 70% of the time and `2.3` otherwise
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_stochastic;
+///
+/// assert_eq!(round_dp_stochastic(2.37_f64, 1, || 0.5), 2.4_f64);
+/// assert_eq!(round_dp_stochastic(2.37_f64, 1, || 0.9), 2.3_f64);
+/// assert_eq!(round_dp_stochastic(2.3_f32, 1, || 0.0), 2.3_f32);
+/// ```
+#[inline]
+pub fn round_dp_stochastic<T: Float, R: FnMut() -> f64>(
+    number: T,
+    decimal_places: u32,
+    random: R,
+) -> T {
+    number.round_dp_stochastic(decimal_places, random)
+}
+
+/// Rounds the number to the given number of zeros, up or down at random
+/// with a probability of going up equal to its distance from the step below
+///
+/// `random` must give values uniformly distributed in `[0, 1)`, a seeded generator makes the
+/// results reproducible. Integer results that don't fit in the type saturate at its bounds
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_stochastic;
+///
+/// assert_eq!(round_zeros_stochastic(237_i32, 1, || 0.5), 240_i32);
+/// assert_eq!(round_zeros_stochastic(-237_i32, 1, || 0.5), -240_i32);
+/// assert_eq!(round_zeros_stochastic(237_u32, 1, || 0.9), 230_u32);
+/// ```
+#[inline]
+pub fn round_zeros_stochastic<T: Roundable, R: FnMut() -> f64>(
+    number: T,
+    zeros: u32,
+    random: R,
+) -> T {
+    number.round_zeros_stochastic(zeros, random)
+}
+
+/// Counts the significant figures of the number, from its first to its last non zero digit
+///
+/// Floats are counted in their shortest decimal representation that round trips, so `1.20` has 2
+/// significant figures as it is the same float as `1.2`. Trailing zeros of integers are ambiguous
+/// and never counted, so `1500` has 2. Zero and non finite numbers have none
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::count_sig_figs;
+///
+/// assert_eq!(count_sig_figs(1500_u32), 2);
+/// assert_eq!(count_sig_figs(1.20_f64), 2);
+/// assert_eq!(count_sig_figs(0.00340_f64), 2);
+/// assert_eq!(count_sig_figs(-120_034_i64), 6);
+/// assert_eq!(count_sig_figs(0_i32), 0);
+/// ```
+#[inline]
+pub fn count_sig_figs<T: Roundable>(number: T) -> u32 {
+    number.count_sig_figs()
+}
+
+/// Rounds the value to the decimal position of the last significant digit of the reference
+///
+/// The reference is taken to already be rounded to its significant figures,
+/// so a zero, infinite or NaN reference returns the value unchanged
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_to_match;
+///
+/// assert_eq!(round_to_match(12.3456_f64, 0.023), 12.346_f64);
+/// assert_eq!(round_to_match(1234.5_f64, 20.0), 1230_f64);
+/// assert_eq!(round_to_match(12.3456_f32, 0.0), 12.3456_f32);
+/// ```
+#[inline]
+pub fn round_to_match<T: Float>(value: T, reference: T) -> T {
+    value.round_to_match(reference)
+}
+
+/// Rounds the uncertainty to the given number of significant figures
+/// and the value to the same decimal position, returning both
+///
+/// Zeros the uncertainty gained from rounding still count as significant,
+/// so an uncertainty of `0.0996` to two significant figures keeps the value to two decimal places
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_pair_sf;
+///
+/// assert_eq!(round_pair_sf(12.3456_f64, 0.0234, 2), (12.346_f64, 0.023_f64));
+/// assert_eq!(round_pair_sf(12.3456_f64, 0.0996, 2), (12.35_f64, 0.1_f64));
+/// assert_eq!(round_pair_sf(1234.5_f32, 27.0, 1), (1230_f32, 30_f32));
+/// ```
+#[inline]
+pub fn round_pair_sf<T: Float>(value: T, uncertainty: T, sig_figs: u32) -> (T, T) {
+    value.round_pair_sf(uncertainty, sig_figs)
+}
+
+/// Rounds the number to the nearest multiple of the step, ties go away from zero
+///
+/// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+/// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_to_multiple;
+///
+/// assert_eq!(round_to_multiple(0.3_f64, 0.1), 0.3_f64);
+/// assert_eq!(round_to_multiple(1.3_f64, 0.25), 1.25_f64);
+/// assert_eq!(round_to_multiple(-7_i32, 5), -5_i32);
+/// assert_eq!(round_to_multiple(3_u8, 10), 0_u8);
+/// ```
+#[inline]
+pub fn round_to_multiple<T: Roundable>(number: T, step: T) -> T {
+    number.round_to_multiple(step)
+}
+
+/// Rounds the number up to the next multiple of the step
+///
+/// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+/// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_to_multiple;
+///
+/// assert_eq!(ceil_to_multiple(1.3_f64, 0.25), 1.5_f64);
+/// assert_eq!(ceil_to_multiple(-7_i32, 5), -5_i32);
+/// assert_eq!(ceil_to_multiple(3_u8, 10), 10_u8);
+/// ```
+#[inline]
+pub fn ceil_to_multiple<T: Roundable>(number: T, step: T) -> T {
+    number.ceil_to_multiple(step)
+}
+
+/// Rounds the number down to the previous multiple of the step
+///
+/// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+/// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_to_multiple;
+///
+/// assert_eq!(floor_to_multiple(1.3_f64, 0.25), 1.25_f64);
+/// assert_eq!(floor_to_multiple(-7_i32, 5), -10_i32);
+/// assert_eq!(floor_to_multiple(3_u8, 10), 0_u8);
+/// ```
+#[inline]
+pub fn floor_to_multiple<T: Roundable>(number: T, step: T) -> T {
+    number.floor_to_multiple(step)
+}
+
+/// Rounds the number to the nearest multiple of the step, ties go away from zero,
+/// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_to_multiple, RoundingError};
+///
+/// assert_eq!(try_round_to_multiple(1.3_f64, 0.25), Ok(1.25_f64));
+/// assert_eq!(try_round_to_multiple(7_i32, 0), Err(RoundingError::InvalidStep));
+/// assert_eq!(try_round_to_multiple(f64::NAN, 0.1), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_round_to_multiple<T: Roundable>(number: T, step: T) -> Result<T, RoundingError> {
+    number.try_round_to_multiple(step)
+}
+
+/// Rounds the number up to the next multiple of the step,
+/// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_ceil_to_multiple, RoundingError};
+///
+/// assert_eq!(try_ceil_to_multiple(7_i32, 5), Ok(10_i32));
+/// assert_eq!(try_ceil_to_multiple(251_u8, 10), Err(RoundingError::Overflow));
+/// assert_eq!(try_ceil_to_multiple(1.3_f64, -0.25), Err(RoundingError::InvalidStep));
+/// ```
+#[inline]
+pub fn try_ceil_to_multiple<T: Roundable>(number: T, step: T) -> Result<T, RoundingError> {
+    number.try_ceil_to_multiple(step)
+}
+
+/// Rounds the number down to the previous multiple of the step,
+/// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_floor_to_multiple, RoundingError};
+///
+/// assert_eq!(try_floor_to_multiple(-7_i32, 5), Ok(-10_i32));
+/// assert_eq!(try_floor_to_multiple(-127_i8, 5), Err(RoundingError::Overflow));
+/// assert_eq!(try_floor_to_multiple(1.3_f32, 0.25), Ok(1.25_f32));
+/// ```
+#[inline]
+pub fn try_floor_to_multiple<T: Roundable>(number: T, step: T) -> Result<T, RoundingError> {
+    number.try_floor_to_multiple(step)
+}
+
+/// Rounds the number to the nearest point of the grid, ties go away from zero relative to the offset of multiples of `step` shifted by `offset`
+///
+/// The grid is made of every multiple of the step plus the offset, such as prices
+/// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+/// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+/// below the offset can round down to zero
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::quantize;
+///
+/// assert_eq!(quantize(103_u32, 10, 7), 107_u32);
+/// assert_eq!(quantize(12.34_f64, 1.0, 0.99), 11.99_f64);
+/// assert_eq!(quantize(-12_i32, 5, 1), -14_i32);
+/// ```
+#[inline]
+pub fn quantize<T: Roundable>(number: T, step: T, offset: T) -> T {
+    number.quantize(step, offset)
+}
+
+/// Rounds the number up to the next point of the grid of multiples of `step` shifted by `offset`
+///
+/// The grid is made of every multiple of the step plus the offset, such as prices
+/// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+/// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+/// below the offset can round down to zero
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_quantize;
+///
+/// assert_eq!(ceil_quantize(103_u32, 10, 7), 107_u32);
+/// assert_eq!(ceil_quantize(12.34_f64, 1.0, 0.99), 12.99_f64);
+/// assert_eq!(ceil_quantize(-12_i32, 5, 1), -9_i32);
+/// ```
+#[inline]
+pub fn ceil_quantize<T: Roundable>(number: T, step: T, offset: T) -> T {
+    number.ceil_quantize(step, offset)
+}
+
+/// Rounds the number down to the previous point of the grid of multiples of `step` shifted by `offset`
+///
+/// The grid is made of every multiple of the step plus the offset, such as prices
+/// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+/// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+/// below the offset can round down to zero
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_quantize;
+///
+/// assert_eq!(floor_quantize(103_u32, 10, 7), 97_u32);
+/// assert_eq!(floor_quantize(12.34_f64, 1.0, 0.99), 11.99_f64);
+/// assert_eq!(floor_quantize(3_u8, 10, 7), 0_u8);
+/// ```
+#[inline]
+pub fn floor_quantize<T: Roundable>(number: T, step: T, offset: T) -> T {
+    number.floor_quantize(step, offset)
+}
+
+/// Rounds the number to the nearest point of the grid, ties go away from zero relative to the offset of multiples of `step` shifted by `offset`,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_quantize;
+///
+/// assert_eq!(checked_quantize(3_u8, 10, 7), Some(7_u8));
+/// assert_eq!(checked_quantize(252_u8, 10, 7), None);
+/// assert_eq!(checked_quantize(f64::NAN, 1.0, 0.99), None);
+/// ```
+#[inline]
+pub fn checked_quantize<T: Roundable>(number: T, step: T, offset: T) -> Option<T> {
+    number.checked_quantize(step, offset)
+}
+
+/// Rounds the number up to the next point of the grid of multiples of `step` shifted by `offset`,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_ceil_quantize;
+///
+/// assert_eq!(checked_ceil_quantize(3_u8, 10, 7), Some(7_u8));
+/// assert_eq!(checked_ceil_quantize(250_u8, 10, 7), None);
+/// assert_eq!(checked_ceil_quantize(12.34_f64, 1.0, 0.99), Some(12.99_f64));
+/// ```
+#[inline]
+pub fn checked_ceil_quantize<T: Roundable>(number: T, step: T, offset: T) -> Option<T> {
+    number.checked_ceil_quantize(step, offset)
+}
+
+/// Rounds the number down to the previous point of the grid of multiples of `step` shifted by `offset`,
+/// returning `None` if the result doesn't fit in the type or the number isn't finite
+///
+/// # Panics
+///
+/// If `step` isn't positive, or isn't finite for floats
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_floor_quantize;
+///
+/// assert_eq!(checked_floor_quantize(3_u8, 10, 7), None);
+/// assert_eq!(checked_floor_quantize(13_u8, 10, 7), Some(7_u8));
+/// assert_eq!(checked_floor_quantize(-12_i32, 5, 1), Some(-14_i32));
+/// ```
+#[inline]
+pub fn checked_floor_quantize<T: Roundable>(number: T, step: T, offset: T) -> Option<T> {
+    number.checked_floor_quantize(step, offset)
+}
+
+/// Rounds the number to the nearest power of ten, ties go to the larger power
+///
+/// Numbers are compared by distance, so the halfway point between `100` and `1000` is `550`
+///
+/// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+/// and integer results that don't fit in the type saturate at its bounds
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_pow10;
+///
+/// assert_eq!(round_pow10(3456_u32), 1000_u32);
+/// assert_eq!(round_pow10(5500_u32), 10000_u32);
+/// assert_eq!(round_pow10(0.034_f64), 0.01_f64);
+/// assert_eq!(round_pow10(-70_i32), -100_i32);
+/// ```
+#[inline]
+pub fn round_pow10<T: Roundable>(number: T) -> T {
+    number.round_pow10()
+}
+
+/// Rounds the number up to a power of ten, a power of ten stays the same
+///
+/// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+/// and integer results that don't fit in the type saturate at its bounds
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_pow10;
+///
+/// assert_eq!(ceil_pow10(3456_u32), 10000_u32);
+/// assert_eq!(ceil_pow10(1000_u32), 1000_u32);
+/// assert_eq!(ceil_pow10(0.034_f64), 0.1_f64);
+/// assert_eq!(ceil_pow10(-3456_i32), -10000_i32);
+/// ```
+#[inline]
+pub fn ceil_pow10<T: Roundable>(number: T) -> T {
+    number.ceil_pow10()
+}
+
+/// Rounds the number down to a power of ten, a power of ten stays the same
+///
+/// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_pow10;
+///
+/// assert_eq!(floor_pow10(3456_u32), 1000_u32);
+/// assert_eq!(floor_pow10(1000_u32), 1000_u32);
+/// assert_eq!(floor_pow10(0.034_f64), 0.01_f64);
+/// assert_eq!(floor_pow10(-3456_i32), -1000_i32);
+/// ```
+#[inline]
+pub fn floor_pow10<T: Roundable>(number: T) -> T {
+    number.floor_pow10()
+}
+
+/// Rounds the number to the nearest power of ten, ties go to the larger power,
+/// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_round_pow10;
+///
+/// assert_eq!(checked_round_pow10(60_u8), Some(100_u8));
+/// assert_eq!(checked_round_pow10(55_000_u16), None);
+/// assert_eq!(checked_round_pow10(0_i32), None);
+/// ```
+#[inline]
+pub fn checked_round_pow10<T: Roundable>(number: T) -> Option<T> {
+    number.checked_round_pow10()
+}
+
+/// Rounds the number up to a power of ten,
+/// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_ceil_pow10;
+///
+/// assert_eq!(checked_ceil_pow10(0.034_f64), Some(0.1_f64));
+/// assert_eq!(checked_ceil_pow10(101_u8), None);
+/// assert_eq!(checked_ceil_pow10(f64::MAX), None);
+/// ```
+#[inline]
+pub fn checked_ceil_pow10<T: Roundable>(number: T) -> Option<T> {
+    number.checked_ceil_pow10()
+}
+
+/// Rounds the number down to a power of ten,
+/// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_floor_pow10;
+///
+/// assert_eq!(checked_floor_pow10(-3456_i32), Some(-1000_i32));
+/// assert_eq!(checked_floor_pow10(0.0_f64), None);
+/// assert_eq!(checked_floor_pow10(f64::NAN), None);
+/// ```
+#[inline]
+pub fn checked_floor_pow10<T: Roundable>(number: T) -> Option<T> {
+    number.checked_floor_pow10()
+}
+
+/// The exponent of the largest power of ten that is no more than the magnitude of the number,
+/// `None` if the number is zero or isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::order_of_magnitude;
+///
+/// assert_eq!(order_of_magnitude(3456_u32), Some(3));
+/// assert_eq!(order_of_magnitude(0.034_f64), Some(-2));
+/// assert_eq!(order_of_magnitude(-0.1_f32), Some(-1));
+/// assert_eq!(order_of_magnitude(0_i32), None);
+/// ```
+#[inline]
+pub fn order_of_magnitude<T: Roundable>(number: T) -> Option<i32> {
+    number.order_of_magnitude()
+}
+
+/// Rounding to a number of decimal places
+///
+/// It extends [`Roundable`], which rounds integers to decimal places as well with
+/// [`round_decimals`](Roundable::round_decimals), [`ceil_decimals`](Roundable::ceil_decimals)
+/// and [`floor_decimals`](Roundable::floor_decimals), the defaults of
+/// [`round_dp`](Float::round_dp), [`ceil_dp`](Float::ceil_dp) and [`floor_dp`](Float::floor_dp)
+///
+/// NaN is propagated and infinities are returned unchanged.
+/// Rounding to more decimal places than the type can hold returns the number unchanged.
+/// Results that round to zero keep the sign of the number, so `-0.25` rounds to `-0.0`.
+/// A number that is already the closest float to a value with the given decimal places,
+/// such as `1.15`, is returned unchanged so rounding twice gives the same result as once
+///
+/// References don't implement the trait as every method returns `Self`, but method calls
+/// dereference them, so a slice can be rounded with `values.iter().map(|value| value.round_dp(2))`.
+/// Generic code over references can be bounded on [`RoundRefExt`], which rounds `&T` to a `T`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::Float;
+///
+/// fn total<T: Float + Copy + std::iter::Sum>(values: &[T]) -> T {
+///     values.iter().map(|value| value.round_dp(1)).sum()
+/// }
+///
+/// assert_eq!(total(&[1.04_f64, 2.06]), 3.1);
+/// ```
+pub trait Float: Roundable {
+    /// Rounds the number to the given number of decimal places
+    ///
+    /// Defaults to [`Roundable::round_decimals`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.round_dp(2), 123.46_f64);
+    /// assert_eq!(123.456_f64.round_dp(0), 123_f64);
+    /// assert_eq!(123.456_f32.round_dp(2), 123.46_f32);
+    /// ```
+    #[inline]
+    fn round_dp(self, decimal_places: u32) -> Self {
+        self.round_decimals(decimal_places)
+    }
+
+    /// Rounds the number up to the given number of decimal places
+    ///
+    /// Defaults to [`Roundable::ceil_decimals`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.454_f64.ceil_dp(2), 123.46_f64);
+    /// assert_eq!(123.456_f64.ceil_dp(0), 124_f64);
+    /// assert_eq!(123.454_f32.ceil_dp(2), 123.46_f32);
+    /// ```
+    #[inline]
+    fn ceil_dp(self, decimal_places: u32) -> Self {
+        self.ceil_decimals(decimal_places)
+    }
+
+    /// Rounds the number down to the given number of decimal places
+    ///
+    /// Defaults to [`Roundable::floor_decimals`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.floor_dp(2), 123.45_f64);
+    /// assert_eq!(123.456_f64.floor_dp(0), 123_f64);
+    /// assert_eq!(123.454_f32.floor_dp(2), 123.45_f32);
+    /// ```
+    #[inline]
+    fn floor_dp(self, decimal_places: u32) -> Self {
+        self.floor_decimals(decimal_places)
+    }
+
+    /// Rounds the number towards zero to the given number of decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.trunc_dp(2), 123.45_f64);
+    /// assert_eq!((-123.456_f64).trunc_dp(2), -123.45_f64);
+    /// assert_eq!((-123.456_f32).trunc_dp(0), -123_f32);
+    /// ```
+    fn trunc_dp(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number away from zero to the given number of decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.451_f64.away_dp(2), 123.46_f64);
+    /// assert_eq!((-123.451_f64).away_dp(2), -123.46_f64);
+    /// assert_eq!((-123.456_f32).away_dp(0), -124_f32);
+    /// ```
+    fn away_dp(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the given number of decimal places using the given rounding mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.5_f64.round_dp_mode(0, RoundingMode::HalfEven), 2_f64);
+    /// assert_eq!((-0.125_f64).round_dp_mode(2, RoundingMode::HalfUp), -0.12_f64);
+    /// assert_eq!(123.451_f32.round_dp_mode(1, RoundingMode::TowardZero), 123.4_f32);
+    /// ```
+    fn round_dp_mode(self, decimal_places: u32, mode: RoundingMode) -> Self;
+
+    /// Rounds the number to the given number of decimal places with ties going to the odd neighbour
+    ///
+    /// Float ties are found in the shortest decimal representation of the number, the same as for [`round_dp_even`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.125_f64.round_dp_half_odd(2), 2.13_f64);
+    /// assert_eq!(2.135_f64.round_dp_half_odd(2), 2.13_f64);
+    /// assert_eq!((-2.5_f32).round_dp_half_odd(0), -3_f32);
+    /// ```
+    fn round_dp_half_odd(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the given number of decimal places with ties going away from zero,
+    /// so `2.5` rounds to `3` and `-2.5` rounds to `-3`
+    ///
+    /// Ties always move away from zero and the result for a negative number is the negated result
+    /// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0.125_f64.round_dp_half_away(2), 0.13_f64);
+    /// assert_eq!((-0.125_f64).round_dp_half_away(2), -0.13_f64);
+    /// assert_eq!((-2.5_f32).round_dp_half_away(0), -3_f32);
+    /// ```
+    fn round_dp_half_away(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the given number of decimal places with ties going towards negative
+    /// infinity like [`RoundingMode::HalfDown`], so `2.5` rounds to `2` and `-2.5` rounds to `-3`
+    ///
+    /// Ties are found in the number scaled by a power of ten, which is exact for midpoints such as `2.5`
+    /// or `0.25` to one decimal place. Other midpoints are best effort as the stored value can be
+    /// slightly off the midpoint, in which case it rounds by the stored value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.25_f64.round_dp_half_down(1), 2.2_f64);
+    /// assert_eq!((-2.25_f64).round_dp_half_down(1), -2.3_f64);
+    /// assert_eq!(2.26_f32.round_dp_half_down(1), 2.3_f32);
+    /// ```
+    fn round_dp_half_down(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the given number of decimal places,
+    /// using the digits of its shortest decimal representation instead of its exact binary value
+    ///
+    /// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+    /// so this rounds the way the number was written rather than the way it is stored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.675_f64.round_dp_exact(2), 2.68_f64);
+    /// assert_eq!(0.145_f64.round_dp_exact(2), 0.15_f64);
+    /// assert_eq!(1.005_f32.round_dp_exact(2), 1.01_f32);
+    /// ```
+    fn round_dp_exact(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number up to the given number of decimal places,
+    /// using the digits of its shortest decimal representation instead of its exact binary value
+    ///
+    /// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+    /// so this rounds the way the number was written rather than the way it is stored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.671_f64.ceil_dp_exact(2), 2.68_f64);
+    /// assert_eq!(1.15_f64.ceil_dp_exact(2), 1.15_f64);
+    /// assert_eq!((-0.145_f32).ceil_dp_exact(2), -0.14_f32);
+    /// ```
+    fn ceil_dp_exact(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number down to the given number of decimal places,
+    /// using the digits of its shortest decimal representation instead of its exact binary value
+    ///
+    /// Decimal input such as `2.675` is stored as a float slightly below `2.675`,
+    /// so this rounds the way the number was written rather than the way it is stored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.679_f64.floor_dp_exact(2), 2.67_f64);
+    /// assert_eq!(4.35_f64.floor_dp_exact(2), 4.35_f64);
+    /// assert_eq!((-0.145_f32).floor_dp_exact(2), -0.15_f32);
+    /// ```
+    fn floor_dp_exact(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the given number of decimal places with ties going to the even neighbour,
+    /// also known as banker's rounding
+    ///
+    /// Ties are found in the shortest decimal representation of the number rather than its binary value
+    /// scaled by a power of ten, as scaling can move a number onto or off a midpoint.
+    /// `2.135` is stored slightly below `2.135` and `2.135 * 100.0` is `213.49999999999997`,
+    /// but it is written as a midpoint so it rounds to `2.14`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.125_f64.round_dp_even(2), 2.12_f64);
+    /// assert_eq!(2.135_f64.round_dp_even(2), 2.14_f64);
+    /// assert_eq!((-0.5_f32).round_dp_even(0), -0_f32);
+    /// ```
+    fn round_dp_even(self, decimal_places: u32) -> Self;
+
+    /// Rounds the number to the decimal position of the last significant digit of the reference
+    ///
+    /// The reference is taken to already be rounded to its significant figures,
+    /// so a zero, infinite or NaN reference returns the number unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(12.3456_f64.round_to_match(0.023), 12.346_f64);
+    /// assert_eq!(1234.5_f64.round_to_match(20.0), 1230_f64);
+    /// assert_eq!(12.3456_f32.round_to_match(0.0), 12.3456_f32);
+    /// ```
+    fn round_to_match(self, reference: Self) -> Self;
+
+    /// Rounds the uncertainty to the given number of significant figures
+    /// and the number to the same decimal position, returning both
+    ///
+    /// Zeros the uncertainty gained from rounding still count as significant,
+    /// so an uncertainty of `0.0996` to two significant figures keeps the number to two decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(12.3456_f64.round_pair_sf(0.0234, 2), (12.346_f64, 0.023_f64));
+    /// assert_eq!(12.3456_f64.round_pair_sf(0.0996, 2), (12.35_f64, 0.1_f64));
+    /// assert_eq!(1234.5_f32.round_pair_sf(27.0, 1), (1230_f32, 30_f32));
+    /// ```
+    fn round_pair_sf(self, uncertainty: Self, sig_figs: u32) -> (Self, Self)
+    where
+        Self: Sized;
+
+    /// Rounds the number to the given number of decimal places, up or down at random
+    /// with a probability of going up equal to its distance from the step below
+    ///
+    /// `random` must give values uniformly distributed in `[0, 1)`, a seeded generator makes the
+    /// results reproducible. On average the rounded values keep the value of the number,
+    /// so `2.37` to one decimal place gives `2.4` 70% of the time and `2.3` otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(2.37_f64.round_dp_stochastic(1, || 0.5), 2.4_f64);
+    /// assert_eq!(2.37_f64.round_dp_stochastic(1, || 0.9), 2.3_f64);
+    /// assert_eq!(2.3_f32.round_dp_stochastic(1, || 0.0), 2.3_f32);
+    /// ```
+    fn round_dp_stochastic<R: FnMut() -> f64>(self, decimal_places: u32, random: R) -> Self;
+
+    /// Rounds the number to the nearest multiple of `1 / denominator`, ties go away from zero
+    ///
+    /// The multiple is found from the exact product of the number and the denominator and divided
+    /// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+    /// come out as the closest float
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.round_frac(4), 1.25_f64);
+    /// assert_eq!(0.3_f64.round_frac(3), 1.0_f64 / 3.0);
+    /// assert_eq!((-0.04_f64).round_frac(16), -0.0625_f64);
+    /// ```
+    fn round_frac(self, denominator: u32) -> Self;
+
+    /// Rounds the number up to the next multiple of `1 / denominator`
+    ///
+    /// The multiple is found from the exact product of the number and the denominator and divided
+    /// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+    /// come out as the closest float
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.ceil_frac(4), 1.5_f64);
+    /// assert_eq!(0.3_f64.ceil_frac(3), 1.0_f64 / 3.0);
+    /// assert_eq!((-0.03_f64).ceil_frac(16), -0_f64);
+    /// ```
+    fn ceil_frac(self, denominator: u32) -> Self;
+
+    /// Rounds the number down to the previous multiple of `1 / denominator`
+    ///
+    /// The multiple is found from the exact product of the number and the denominator and divided
+    /// back, so fractions like `1 / 16` that floats can hold come out exactly and the others, like `1 / 3`,
+    /// come out as the closest float
+    ///
+    /// # Panics
+    ///
+    /// If `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.floor_frac(4), 1.25_f64);
+    /// assert_eq!(0.3_f64.floor_frac(3), 0_f64);
+    /// assert_eq!((-0.03_f64).floor_frac(16), -0.0625_f64);
+    /// ```
+    fn floor_frac(self, denominator: u32) -> Self;
+
+    /// Rounds the number to the nearest multiple of `1 / denominator`, ties go away from zero,
+    /// returning an error if the number isn't finite or `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.try_round_frac(4), Ok(1.25_f64));
+    /// assert_eq!(1.3_f64.try_round_frac(0), Err(RoundingError::ZeroDenominator));
+    /// assert_eq!(f64::NAN.try_round_frac(4), Err(RoundingError::NonFinite));
+    /// ```
+    fn try_round_frac(self, denominator: u32) -> Result<Self, RoundingError>
+    where
+        Self: Sized;
+
+    /// Rounds the number up to the next multiple of `1 / denominator`,
+    /// returning an error if the number isn't finite or `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.try_ceil_frac(4), Ok(1.5_f64));
+    /// assert_eq!(1.3_f32.try_ceil_frac(0), Err(RoundingError::ZeroDenominator));
+    /// assert_eq!(f64::INFINITY.try_ceil_frac(4), Err(RoundingError::NonFinite));
+    /// ```
+    fn try_ceil_frac(self, denominator: u32) -> Result<Self, RoundingError>
+    where
+        Self: Sized;
+
+    /// Rounds the number down to the previous multiple of `1 / denominator`,
+    /// returning an error if the number isn't finite or `denominator` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.try_floor_frac(4), Ok(1.25_f64));
+    /// assert_eq!(1.3_f64.try_floor_frac(0), Err(RoundingError::ZeroDenominator));
+    /// assert_eq!((-0.7_f32).try_floor_frac(2), Ok(-1_f32));
+    /// ```
+    fn try_floor_frac(self, denominator: u32) -> Result<Self, RoundingError>
+    where
+        Self: Sized;
+
+    /// Rounds the number to the given number of significant bits, ties go to the even bit
+    ///
+    /// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+    /// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+    /// floats have fewer bits to begin with, infinities and NaN are returned as is
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is zero or more than the significant bits of the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.round_bits(2), 1.5_f64);
+    /// assert_eq!(0.625_f64.round_bits(2), 0.5_f64);
+    /// assert_eq!(0.875_f32.round_bits(2), 1_f32);
+    /// ```
+    fn round_bits(self, bits: u32) -> Self;
+
+    /// Rounds the number towards zero to the given number of significant bits
+    ///
+    /// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+    /// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+    /// floats have fewer bits to begin with, infinities and NaN are returned as is
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is zero or more than the significant bits of the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.9_f64.trunc_bits(1), 1_f64);
+    /// assert_eq!((-0.875_f64).trunc_bits(2), -0.75_f64);
+    /// assert_eq!(7_f32.trunc_bits(2), 6_f32);
+    /// ```
+    fn trunc_bits(self, bits: u32) -> Self;
+
+    /// Rounds the number up to the given number of significant bits
+    ///
+    /// The binary counterpart of significant figures, `bits` counts the leading one bit so it ranges
+    /// from 1 up to 53 for f64 and 24 for f32, where every float is returned unchanged. Subnormal
+    /// floats have fewer bits to begin with, infinities and NaN are returned as is
+    ///
+    /// # Panics
+    ///
+    /// If `bits` is zero or more than the significant bits of the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.1_f64.ceil_bits(2), 1.5_f64);
+    /// assert_eq!((-0.875_f64).ceil_bits(2), -0.75_f64);
+    /// assert_eq!(5_f32.ceil_bits(2), 6_f32);
+    /// ```
+    fn ceil_bits(self, bits: u32) -> Self;
+
+    /// Counts the digits after the decimal point in the shortest decimal representation of the number
+    /// that round trips, so `0.1` has 1 decimal place even though the float isn't exactly `0.1`
+    ///
+    /// Whole numbers have none, and neither do non finite numbers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0.25_f64.count_decimal_places(), 2);
+    /// assert_eq!(0.1_f64.count_decimal_places(), 1);
+    /// assert_eq!(1e-7_f64.count_decimal_places(), 7);
+    /// assert_eq!(1500_f32.count_decimal_places(), 0);
+    /// ```
+    fn count_decimal_places(self) -> u32;
+
+    /// Whether rounding the number to the given number of decimal places would change it, which is
+    /// whenever it has more decimal places than that
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert!(0.25_f64.needs_rounding(1));
+    /// assert!(!0.25_f64.needs_rounding(2));
+    /// assert!(1e-7_f32.needs_rounding(6));
+    /// assert!(!f64::NAN.needs_rounding(0));
+    /// ```
+    fn needs_rounding(self, decimal_places: u32) -> bool;
+
+    /// Rounds the number to the given number of decimal places like [`round`] and renders it
+    /// with exactly that many digits after the decimal point, keeping trailing zeros
+    ///
+    /// Magnitudes below one have a `0` before the decimal point, and results that round to zero
+    /// keep the sign of the number like [`round`] does, so `-0.001` renders as `-0.00`.
+    /// NaN renders as `NaN` and infinities as `inf` and `-inf`
+    ///
+    /// The string parses back to the rounded number, except for numbers with more significant digits
+    /// than the type can hold at those decimal places, which aren't rounded and can only be rendered
+    /// to within an ulp
+    ///
+    /// The string parses back to the rounded number, except for numbers with more significant digits
+    /// than the type can hold at those decimal places, which aren't rounded and can only be rendered
+    /// to within an ulp
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.5_f64.format_dp(2), "1.50");
+    /// assert_eq!((-0.125_f64).format_dp(2), "-0.13");
+    /// assert_eq!(2.5_f32.format_dp(0), "3");
+    /// assert_eq!(f64::NAN.format_dp(2), "NaN");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn format_dp(self, decimal_places: u32) -> String;
+
+    /// Splits the number rounded to the given number of significant figures like [`round_sf`](Roundable::round_sf) into a
+    /// mantissa with a magnitude in `[1, 10)` and a power of ten, so `mantissa * 10^exponent` is the
+    /// rounded number to within an ulp
+    ///
+    /// The exponent is taken after rounding, so `9.97` to 2 significant figures is `(1.0, 1)`.
+    /// Negative numbers have a negative mantissa, zero is `(0.0, 0)` keeping its sign and non finite
+    /// numbers are returned as they are with an exponent of `0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_456_f64.to_scientific(3), (1.23, 5));
+    /// assert_eq!(0.00345_f64.to_scientific(2), (3.5, -3));
+    /// assert_eq!((-9.97_f32).to_scientific(2), (-1.0, 1));
+    /// assert_eq!(0.0_f64.to_scientific(3), (0.0, 0));
+    /// ```
+    fn to_scientific(self, sig_figs: u32) -> (Self, i32)
+    where
+        Self: Sized;
+
+    #[doc(hidden)]
+    fn round_slice_dp_mode(values: &mut [Self], decimal_places: u32, mode: RoundingMode)
+    where
+        Self: Sized;
+
+    #[doc(hidden)]
+    fn round_slice_dp_simd(values: &mut [Self], decimal_places: u32)
+    where
+        Self: Sized;
+}
+
+/// Rounds to decimal places when the position is after the decimal point and to zeros otherwise
+#[inline]
+fn round_position<T: Float + Roundable>(number: T, decimal_places: i32) -> T {
+    match u32::try_from(decimal_places) {
+        Ok(decimal_places) => number.round_dp(decimal_places),
+        Err(_) => number.round_zeros(decimal_places.unsigned_abs()),
+    }
+}
+
+/// Subtracts two floats, giving the rounded difference and the error of that rounding
+#[inline]
+fn two_difference<T: Copy + core::ops::Add<Output = T> + core::ops::Sub<Output = T>>(
+    a: T,
+    b: T,
+) -> (T, T) {
+    let difference = a - b;
+    let b_virtual = a - difference;
+    let a_virtual = difference + b_virtual;
+    (difference, (a - a_virtual) + (b_virtual - b))
+}
+
+impl Float for f32 {
+    #[inline]
+    fn trunc_dp(self, decimal_places: u32) -> f32 {
+        self.round_dp_mode(decimal_places, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    fn away_dp(self, decimal_places: u32) -> f32 {
+        self.abs().ceil_dp(decimal_places).copysign(self)
+    }
+
+    #[inline]
+    fn round_dp_half_down(self, decimal_places: u32) -> f32 {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfDown)
+    }
+
+    #[inline]
+    fn round_dp_half_away(self, decimal_places: u32) -> f32 {
+        self.abs()
+            .round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
+    }
+
+    #[inline]
+    fn round_dp_half_odd(self, decimal_places: u32) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f32(self, decimal_places, RoundingMode::HalfOdd)
+    }
+
+    #[inline]
+    fn round_dp_mode(self, decimal_places: u32, mode: RoundingMode) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        scale::decimal_places_f32(self, decimal_places, mode)
+    }
+
+    #[inline]
+    fn round_dp_exact(self, decimal_places: u32) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f32(self, decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_dp_exact(self, decimal_places: u32) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f32(self, decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_dp_exact(self, decimal_places: u32) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f32(self, decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_dp_even(self, decimal_places: u32) -> f32 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f32(self, decimal_places, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn round_to_match(self, reference: f32) -> f32 {
+        if reference == 0.0 || !reference.is_finite() {
+            return self;
+        }
+
+        let (_, decimal_places) = digits::shortest_decimal(reference);
+        round_position(self, decimal_places)
+    }
+
+    #[inline]
+    fn round_pair_sf(self, uncertainty: f32, sig_figs: u32) -> (f32, f32) {
+        let uncertainty = uncertainty.round_sf(sig_figs);
+        if uncertainty == 0.0 || !uncertainty.is_finite() {
+            return (self, uncertainty);
+        }
+
+        // Counted from the significant figures rather than the shortest representation
+        // so trailing zeros of the uncertainty are kept
+        let sig_figs = sig_figs.min(i32::MAX as u32) as i32;
+        let decimal_places = sig_figs.saturating_sub(uncertainty.get_digits());
+        (round_position(self, decimal_places), uncertainty)
+    }
+
+    #[inline]
+    fn round_dp_stochastic<R: FnMut() -> f64>(self, decimal_places: u32, mut random: R) -> f32 {
+        let below = self.floor_dp(decimal_places);
+        let above = self.ceil_dp(decimal_places);
+        if below == above {
+            return self;
+        }
+
+        let fraction = (self - below) as f64 / (above - below) as f64;
+        if random() < fraction {
+            above
+        } else {
+            below
+        }
+    }
+
+    #[inline]
+    fn round_frac(self, denominator: u32) -> f32 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f32(self, denominator, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_frac(self, denominator: u32) -> f32 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f32(self, denominator, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_frac(self, denominator: u32) -> f32 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f32(self, denominator, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn try_round_frac(self, denominator: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.round_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_ceil_frac(self, denominator: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.ceil_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_floor_frac(self, denominator: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.floor_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn round_bits(self, bits: u32) -> f32 {
+        assert!((1..=24).contains(&bits), "bits must be between 1 and 24");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f32(self, bits, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn trunc_bits(self, bits: u32) -> f32 {
+        assert!((1..=24).contains(&bits), "bits must be between 1 and 24");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f32(self, bits, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    fn ceil_bits(self, bits: u32) -> f32 {
+        assert!((1..=24).contains(&bits), "bits must be between 1 and 24");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f32(self, bits, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn count_decimal_places(self) -> u32 {
+        if !self.is_finite() {
+            return 0;
+        }
+
+        let (_, fraction_digits) = digits::shortest_decimal(self);
+        fraction_digits.max(0) as u32
+    }
+
+    #[inline]
+    fn needs_rounding(self, decimal_places: u32) -> bool {
+        self.count_decimal_places() > decimal_places
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_dp(self, decimal_places: u32) -> String {
+        let rounded = self.round_dp(decimal_places);
+        if !rounded.is_finite() {
+            return rounded.to_string();
+        }
+
+        format::fixed(rounded.abs(), rounded.is_sign_negative(), decimal_places)
+    }
+
+    #[inline]
+    fn to_scientific(self, sig_figs: u32) -> (f32, i32) {
+        let rounded = self.round_sf(sig_figs);
+        if rounded == 0.0 || !rounded.is_finite() {
+            return (rounded, 0);
+        }
+
+        // The mantissa is parsed from the digits of the rounded number so it is the closest float
+        let (significand, fraction_digits) = digits::shortest_decimal(rounded);
+        let leading = significand.ilog10() as i32;
+        let mantissa: f32 = digits::scale(significand, -(leading as i64));
+        (mantissa.copysign(rounded), leading - fraction_digits)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_mode(values: &mut [f32], decimal_places: u32, mode: RoundingMode) {
+        let scale = scale::DecimalPlacesF32::new(decimal_places);
+        for value in values.iter_mut().filter(|value| value.is_finite()) {
+            *value = scale.round(*value, mode);
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_simd(values: &mut [f32], decimal_places: u32) {
+        let scale = scale::DecimalPlacesF32::new(decimal_places);
+        #[cfg(feature = "simd")]
+        let values = scale.round_lanes(values);
+        for value in values.iter_mut().filter(|value| value.is_finite()) {
+            *value = scale.round(*value, RoundingMode::HalfAwayFromZero);
+        }
+    }
+}
+
+impl Float for f64 {
+    #[inline]
+    fn trunc_dp(self, decimal_places: u32) -> f64 {
+        self.round_dp_mode(decimal_places, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    fn away_dp(self, decimal_places: u32) -> f64 {
+        self.abs().ceil_dp(decimal_places).copysign(self)
+    }
+
+    #[inline]
+    fn round_dp_half_down(self, decimal_places: u32) -> f64 {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfDown)
+    }
+
+    #[inline]
+    fn round_dp_half_away(self, decimal_places: u32) -> f64 {
+        self.abs()
+            .round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
+    }
+
+    #[inline]
+    fn round_dp_half_odd(self, decimal_places: u32) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f64(self, decimal_places, RoundingMode::HalfOdd)
+    }
+
+    #[inline]
+    fn round_dp_mode(self, decimal_places: u32, mode: RoundingMode) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        scale::decimal_places_f64(self, decimal_places, mode)
+    }
+
+    #[inline]
+    fn round_dp_exact(self, decimal_places: u32) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f64(self, decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_dp_exact(self, decimal_places: u32) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f64(self, decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_dp_exact(self, decimal_places: u32) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f64(self, decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn round_dp_even(self, decimal_places: u32) -> f64 {
+        if !self.is_finite() {
+            return self;
+        }
+
+        exact::decimal_places_f64(self, decimal_places, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn round_to_match(self, reference: f64) -> f64 {
+        if reference == 0.0 || !reference.is_finite() {
+            return self;
+        }
+
+        let (_, decimal_places) = digits::shortest_decimal(reference);
+        round_position(self, decimal_places)
+    }
+
+    #[inline]
+    fn round_pair_sf(self, uncertainty: f64, sig_figs: u32) -> (f64, f64) {
+        let uncertainty = uncertainty.round_sf(sig_figs);
+        if uncertainty == 0.0 || !uncertainty.is_finite() {
+            return (self, uncertainty);
+        }
+
+        // Counted from the significant figures rather than the shortest representation
+        // so trailing zeros of the uncertainty are kept
+        let sig_figs = sig_figs.min(i32::MAX as u32) as i32;
+        let decimal_places = sig_figs.saturating_sub(uncertainty.get_digits());
+        (round_position(self, decimal_places), uncertainty)
+    }
+
+    #[inline]
+    fn round_dp_stochastic<R: FnMut() -> f64>(self, decimal_places: u32, mut random: R) -> f64 {
+        let below = self.floor_dp(decimal_places);
+        let above = self.ceil_dp(decimal_places);
+        if below == above {
+            return self;
+        }
+
+        let fraction = (self - below) / (above - below);
+        if random() < fraction {
+            above
+        } else {
+            below
+        }
+    }
+
+    #[inline]
+    fn round_frac(self, denominator: u32) -> f64 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f64(self, denominator, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_frac(self, denominator: u32) -> f64 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f64(self, denominator, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_frac(self, denominator: u32) -> f64 {
+        assert!(denominator > 0, "denominator must be greater than zero");
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::fraction_f64(self, denominator, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn try_round_frac(self, denominator: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.round_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_ceil_frac(self, denominator: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.ceil_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn try_floor_frac(self, denominator: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if denominator == 0 {
+            Err(RoundingError::ZeroDenominator)
+        } else {
+            Ok(self.floor_frac(denominator))
+        }
+    }
+
+    #[inline]
+    fn round_bits(self, bits: u32) -> f64 {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f64(self, bits, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn trunc_bits(self, bits: u32) -> f64 {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f64(self, bits, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    fn ceil_bits(self, bits: u32) -> f64 {
+        assert!((1..=53).contains(&bits), "bits must be between 1 and 53");
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        bits::round_bits_f64(self, bits, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn count_decimal_places(self) -> u32 {
+        if !self.is_finite() {
+            return 0;
+        }
+
+        let (_, fraction_digits) = digits::shortest_decimal(self);
+        fraction_digits.max(0) as u32
+    }
+
+    #[inline]
+    fn needs_rounding(self, decimal_places: u32) -> bool {
+        self.count_decimal_places() > decimal_places
+    }
+
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_dp(self, decimal_places: u32) -> String {
+        let rounded = self.round_dp(decimal_places);
+        if !rounded.is_finite() {
+            return rounded.to_string();
+        }
+
+        format::fixed(rounded.abs(), rounded.is_sign_negative(), decimal_places)
+    }
+
+    #[inline]
+    fn to_scientific(self, sig_figs: u32) -> (f64, i32) {
+        let rounded = self.round_sf(sig_figs);
+        if rounded == 0.0 || !rounded.is_finite() {
+            return (rounded, 0);
+        }
+
+        // The mantissa is parsed from the digits of the rounded number so it is the closest float
+        let (significand, fraction_digits) = digits::shortest_decimal(rounded);
+        let leading = significand.ilog10() as i32;
+        let mantissa: f64 = digits::scale(significand, -(leading as i64));
+        (mantissa.copysign(rounded), leading - fraction_digits)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_mode(values: &mut [f64], decimal_places: u32, mode: RoundingMode) {
+        let scale = scale::DecimalPlacesF64::new(decimal_places);
+        for value in values.iter_mut().filter(|value| value.is_finite()) {
+            *value = scale.round(*value, mode);
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_dp_simd(values: &mut [f64], decimal_places: u32) {
+        let scale = scale::DecimalPlacesF64::new(decimal_places);
+        #[cfg(feature = "simd")]
+        let values = scale.round_lanes(values);
+        for value in values.iter_mut().filter(|value| value.is_finite()) {
+            *value = scale.round(*value, RoundingMode::HalfAwayFromZero);
+        }
+    }
+}
+
+/// Rounding to a number of zeros or significant figures
+///
+/// Integers are rounded exactly without going through a float,
+/// results that don't fit in the type saturate at its bounds.
+/// [`Wrapping`](core::num::Wrapping) integers are the exception and wrap around the bounds instead
+///
+/// For floats NaN is propagated and infinities are returned unchanged.
+/// Rounding to more zeros than the type can hold gives zero or overflows to an infinity.
+/// Float results that round to zero keep the sign of the number.
+/// Subnormal floats round to significant figures like any other float,
+/// giving the nearest representable value to the rounded result
+///
+/// Like [`Float`] it isn't implemented for references, which [`RoundRefExt`] rounds instead
+///
+/// # Implementing
+///
+/// Only [`to_decimal`](Roundable::to_decimal) and [`from_decimal`](Roundable::from_decimal) have to
+/// be implemented. Every other method has a default that rounds the decimal exactly with a mantissa
+/// in an `i128`, and the rounding methods all go through [`round_to_exponent`](Roundable::round_to_exponent)
+/// and [`get_digits`](Roundable::get_digits), so overriding those two changes them all.
+/// Results that don't fit in the type saturate at its bounds and numbers with no decimal, like a
+/// NaN, are returned as they are. A type with values past an `i128` mantissa, or faster arithmetic
+/// of its own, should override the methods it uses
+pub trait Roundable: Sized {
+    /// Rounds the number to the given number of decimal places, like [`Float::round_dp`] but for
+    /// every type that can be rounded
+    ///
+    /// Integers have no fractional part to round, so they are returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.round_decimals(2), 123.46_f64);
+    /// assert_eq!(123.456_f64.round_decimals(0), 123_f64);
+    /// assert_eq!(123.456_f32.round_decimals(2), 123.46_f32);
+    /// assert_eq!(123_i32.round_decimals(2), 123_i32);
+    /// ```
+    #[inline]
+    fn round_decimals(self, decimal_places: u32) -> Self {
+        fallback::saturating_round_to_exponent(
+            self,
+            dp_exponent(decimal_places),
+            RoundingMode::HalfAwayFromZero,
+        )
+    }
+
+    /// Rounds the number up to the given number of decimal places, like [`Float::ceil_dp`] but for
+    /// every type that can be rounded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.454_f64.ceil_decimals(2), 123.46_f64);
+    /// assert_eq!(123.456_f64.ceil_decimals(0), 124_f64);
+    /// assert_eq!(123.454_f32.ceil_decimals(2), 123.46_f32);
+    /// assert_eq!(123_u64.ceil_decimals(1), 123_u64);
+    /// ```
+    #[inline]
+    fn ceil_decimals(self, decimal_places: u32) -> Self {
+        fallback::saturating_round_to_exponent(self, dp_exponent(decimal_places), RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the given number of decimal places, like [`Float::floor_dp`] but
+    /// for every type that can be rounded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.floor_decimals(2), 123.45_f64);
+    /// assert_eq!(123.456_f64.floor_decimals(0), 123_f64);
+    /// assert_eq!(123.454_f32.floor_decimals(2), 123.45_f32);
+    /// assert_eq!((-123_i32).floor_decimals(0), -123_i32);
+    /// ```
+    #[inline]
+    fn floor_decimals(self, decimal_places: u32) -> Self {
+        fallback::saturating_round_to_exponent(self, dp_exponent(decimal_places), RoundingMode::Down)
+    }
+
+    /// Rounds the number to the given number of zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.round_zeros(1), 120_f64);
+    /// assert_eq!(123.456_f64.round_zeros(0), 123_f64);
+    /// assert_eq!(123_i32.round_zeros(2), 100_i32);
+    /// ```
+    #[inline]
+    fn round_zeros(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the given number of zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(ceil_zeros(123.456_f64, 1), 130_f64);
+    /// assert_eq!(ceil_zeros(123.456_f64, 0), 124_f64);
+    /// assert_eq!(ceil_zeros(123_i32, 2), 200_i32);
+    /// ```
+    #[inline]
+    fn ceil_zeros(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the given number of zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.floor_zeros(1), 120_f64);
+    /// assert_eq!(123.654_f64.floor_zeros(0), 123_f64);
+    /// assert_eq!(156_i32.floor_zeros(2), 100_i32);
+    /// ```
+    #[inline]
+    fn floor_zeros(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::Down)
+    }
+
+    /// Rounds the number towards zero to the given number of zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(156_i32.trunc_zeros(1), 150_i32);
+    /// assert_eq!((-156_i32).trunc_zeros(1), -150_i32);
+    /// assert_eq!((-156.5_f64).trunc_zeros(2), -100_f64);
+    /// ```
+    #[inline]
+    fn trunc_zeros(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::TowardZero)
+    }
+
+    /// Rounds the number away from zero to the given number of zeros
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(151_i32.away_zeros(1), 160_i32);
+    /// assert_eq!((-151_i32).away_zeros(1), -160_i32);
+    /// assert_eq!((-151.5_f64).away_zeros(2), -200_f64);
+    /// ```
+    #[inline]
+    fn away_zeros(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::AwayFromZero)
+    }
+
+    /// Rounds the number to the given number of zeros using the given rounding mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(125_i32.round_zeros_mode(1, RoundingMode::HalfEven), 120_i32);
+    /// assert_eq!((-125_i32).round_zeros_mode(1, RoundingMode::HalfDown), -130_i32);
+    /// assert_eq!(121.5_f64.round_zeros_mode(1, RoundingMode::AwayFromZero), 130_f64);
+    /// ```
+    #[inline]
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> Self {
+        fallback::saturating_round_to_exponent(self, zeros_exponent(zeros), mode)
+    }
+
+    /// Rounds the number to the given number of zeros with ties going to the odd neighbour
+    ///
+    /// Float ties are found in the shortest decimal representation of the number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(125_i32.round_zeros_half_odd(1), 130_i32);
+    /// assert_eq!((-135_i32).round_zeros_half_odd(1), -130_i32);
+    /// assert_eq!(2500_f64.round_zeros_half_odd(3), 3000_f64);
+    /// ```
+    #[inline]
+    fn round_zeros_half_odd(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::HalfOdd)
+    }
+
+    /// Rounds the number to the given number of zeros with ties going away from zero,
+    /// so `25` rounds to `30` and `-25` rounds to `-30`
+    ///
+    /// Ties always move away from zero and the result for a negative number is the negated result
+    /// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(25_i32.round_zeros_half_away(1), 30_i32);
+    /// assert_eq!((-25_i32).round_zeros_half_away(1), -30_i32);
+    /// assert_eq!((-150_f64).round_zeros_half_away(2), -200_f64);
+    /// ```
+    #[inline]
+    fn round_zeros_half_away(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number to the given number of zeros with ties going towards negative infinity
+    /// like [`RoundingMode::HalfDown`], so `25` rounds to `20` and `-25` rounds to `-30`
+    ///
+    /// Float ties are found in the number scaled by a power of ten, the same as for decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(25_i32.round_zeros_half_down(1), 20_i32);
+    /// assert_eq!((-150_i32).round_zeros_half_down(2), -200_i32);
+    /// assert_eq!((-151_f64).round_zeros_half_down(2), -200_f64);
+    /// ```
+    #[inline]
+    fn round_zeros_half_down(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::HalfDown)
+    }
+
+    /// Rounds the number to the given number of zeros with ties going to the even neighbour,
+    /// also known as banker's rounding
+    ///
+    /// Float ties are found in the shortest decimal representation of the number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(125_i32.round_zeros_even(1), 120_i32);
+    /// assert_eq!((-135_i32).round_zeros_even(1), -140_i32);
+    /// assert_eq!(2500_f64.round_zeros_even(3), 2000_f64);
+    /// ```
+    #[inline]
+    fn round_zeros_even(self, zeros: u32) -> Self {
+        self.round_zeros_mode(zeros, RoundingMode::HalfEven)
+    }
+
+    /// Rounds the number to the given number of significant figures
+    ///
+    /// If the number doesn't have more than `sig_figs` significant figures it is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123456_f64.round_sf(4), 123500_f64);
+    /// assert_eq!(123.456_f64.round_sf(2), 120_f64);
+    /// assert_eq!(123.456_f32.round_sf(4), 123.5_f32);
+    /// ```
+    #[inline]
+    fn round_sf(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the given number of significant figures
+    ///
+    /// If the number doesn't have more than `sig_figs` significant figures it is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123321_f64.ceil_sf(4), 123400_f64);
+    /// assert_eq!(123.456_f64.ceil_sf(2), 130_f64);
+    /// assert_eq!(123.321_f32.ceil_sf(4), 123.4_f32);
+    /// ```
+    #[inline]
+    fn ceil_sf(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the given number of significant figures
+    ///
+    /// If the number doesn't have more than `sig_figs` significant figures it is returned unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123456_f64.floor_sf(4), 123400_f64);
+    /// assert_eq!(656.323_f64.floor_sf(2), 650_f64);
+    /// assert_eq!(123.456_f32.floor_sf(4), 123.4_f32);
+    /// ```
+    #[inline]
+    fn floor_sf(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::Down)
+    }
+
+    /// Rounds the number towards zero to the given number of significant figures
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.456_f64.trunc_sf(4), 123.4_f64);
+    /// assert_eq!((-123.456_f64).trunc_sf(4), -123.4_f64);
+    /// assert_eq!((-1567_i32).trunc_sf(2), -1500_i32);
+    /// ```
+    #[inline]
+    fn trunc_sf(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::TowardZero)
+    }
+
+    /// Rounds the number away from zero to the given number of significant figures
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.412_f64.away_sf(4), 123.5_f64);
+    /// assert_eq!((-123.412_f64).away_sf(4), -123.5_f64);
+    /// assert_eq!((-1512_i32).away_sf(2), -1600_i32);
+    /// ```
+    #[inline]
+    fn away_sf(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::AwayFromZero)
+    }
+
+    /// Rounds the number to the given number of significant figures using the given rounding mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1250_u32.round_sf_mode(2, RoundingMode::HalfOdd), 1300_u32);
+    /// assert_eq!(0.125_f64.round_sf_mode(2, RoundingMode::HalfTowardZero), 0.12_f64);
+    /// assert_eq!((-123.4_f32).round_sf_mode(2, RoundingMode::Down), -130_f32);
+    /// ```
+    #[inline]
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Self {
+        let exponent = sf_exponent(&self, sig_figs);
+        fallback::saturating_round_to_exponent(self, exponent, mode)
+    }
+
+    /// Rounds the number to the given number of significant figures with ties going to the odd neighbour
+    ///
+    /// Float ties are found in the shortest decimal representation of the number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1250_u32.round_sf_half_odd(2), 1300_u32);
+    /// assert_eq!(0.135_f64.round_sf_half_odd(2), 0.13_f64);
+    /// assert_eq!((-3.5_f32).round_sf_half_odd(1), -3_f32);
+    /// ```
+    #[inline]
+    fn round_sf_half_odd(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfOdd)
+    }
+
+    /// Rounds the number to the given number of significant figures with ties going away from zero,
+    /// so `2.5` rounds to `3` and `-2.5` rounds to `-3`
+    ///
+    /// Ties always move away from zero and the result for a negative number is the negated result
+    /// for its absolute value, as the number is rounded by its magnitude before the sign is put back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1250_u32.round_sf_half_away(2), 1300_u32);
+    /// assert_eq!((-0.125_f64).round_sf_half_away(2), -0.13_f64);
+    /// assert_eq!((-2.5_f32).round_sf_half_away(1), -3_f32);
+    /// ```
+    #[inline]
+    fn round_sf_half_away(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number to the given number of significant figures with ties going towards
+    /// negative infinity like [`RoundingMode::HalfDown`], so `2.5` rounds to `2` and `-2.5` rounds
+    /// to `-3`
+    ///
+    /// Float ties are found in the number scaled by a power of ten, the same as for decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1250_u32.round_sf_half_down(2), 1200_u32);
+    /// assert_eq!((-0.125_f64).round_sf_half_down(2), -0.13_f64);
+    /// assert_eq!((-2.5_f32).round_sf_half_down(1), -3_f32);
+    /// ```
+    #[inline]
+    fn round_sf_half_down(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfDown)
+    }
+
+    /// Rounds the number to the given number of significant figures with ties going to the even neighbour,
+    /// also known as banker's rounding
+    ///
+    /// Float ties are found in the shortest decimal representation of the number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1250_u32.round_sf_even(2), 1200_u32);
+    /// assert_eq!(0.135_f64.round_sf_even(2), 0.14_f64);
+    /// assert_eq!((-2.5_f32).round_sf_even(1), -2_f32);
+    /// ```
+    #[inline]
+    fn round_sf_even(self, sig_figs: u32) -> Self {
+        self.round_sf_mode(sig_figs, RoundingMode::HalfEven)
+    }
+
+    /// Rounds the number to the given number of zeros,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.checked_round_zeros(1), Some(120_u8));
+    /// assert_eq!(250_u8.checked_round_zeros(2), None);
+    /// assert_eq!(f64::NAN.checked_round_zeros(1), None);
+    /// ```
+    #[inline]
+    fn checked_round_zeros(self, zeros: u32) -> Option<Self> {
+        self.round_to_exponent(zeros_exponent(zeros), RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the given number of zeros,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_i16.checked_ceil_zeros(1), Some(130_i16));
+    /// assert_eq!(250_u8.checked_ceil_zeros(2), None);
+    /// assert_eq!(f64::INFINITY.checked_ceil_zeros(1), None);
+    /// ```
+    #[inline]
+    fn checked_ceil_zeros(self, zeros: u32) -> Option<Self> {
+        self.round_to_exponent(zeros_exponent(zeros), RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the given number of zeros,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-113_i8).checked_floor_zeros(1), Some(-120_i8));
+    /// assert_eq!((-125_i8).checked_floor_zeros(1), None);
+    /// assert_eq!(123.456_f64.checked_floor_zeros(1), Some(120_f64));
+    /// ```
+    #[inline]
+    fn checked_floor_zeros(self, zeros: u32) -> Option<Self> {
+        self.round_to_exponent(zeros_exponent(zeros), RoundingMode::Down)
+    }
+
+    /// Rounds the number to the given number of significant figures,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.checked_round_sf(2), Some(120_u8));
+    /// assert_eq!(251_u8.checked_round_sf(1), None);
+    /// assert_eq!(123.456_f64.checked_round_sf(2), Some(120_f64));
+    /// ```
+    #[inline]
+    fn checked_round_sf(self, sig_figs: u32) -> Option<Self> {
+        let exponent = sf_exponent(&self, sig_figs);
+        self.round_to_exponent(exponent, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the given number of significant figures,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.checked_ceil_sf(2), Some(130_u8));
+    /// assert_eq!(201_u8.checked_ceil_sf(1), None);
+    /// assert_eq!(f64::NAN.checked_ceil_sf(2), None);
+    /// ```
+    #[inline]
+    fn checked_ceil_sf(self, sig_figs: u32) -> Option<Self> {
+        let exponent = sf_exponent(&self, sig_figs);
+        self.round_to_exponent(exponent, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the given number of significant figures,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-113_i8).checked_floor_sf(2), Some(-120_i8));
+    /// assert_eq!((-121_i8).checked_floor_sf(1), None);
+    /// assert_eq!(123.456_f32.checked_floor_sf(4), Some(123.4_f32));
+    /// ```
+    #[inline]
+    fn checked_floor_sf(self, sig_figs: u32) -> Option<Self> {
+        let exponent = sf_exponent(&self, sig_figs);
+        self.round_to_exponent(exponent, RoundingMode::Down)
+    }
+
+    /// Rounds the number to the given number of zeros,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(250_u8.saturating_round_zeros(2), u8::MAX);
+    /// assert_eq!((-128_i8).saturating_round_zeros(1), i8::MIN);
+    /// assert_eq!(123_i16.saturating_round_zeros(1), 120_i16);
+    /// ```
+    #[inline]
+    fn saturating_round_zeros(self, zeros: u32) -> Self {
+        self.round_zeros(zeros)
+    }
+
+    /// Rounds the number up to the given number of zeros,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(250_u8.saturating_ceil_zeros(2), u8::MAX);
+    /// assert_eq!((-128_i8).saturating_ceil_zeros(1), -120_i8);
+    /// assert_eq!(123_i16.saturating_ceil_zeros(1), 130_i16);
+    /// ```
+    #[inline]
+    fn saturating_ceil_zeros(self, zeros: u32) -> Self {
+        self.ceil_zeros(zeros)
+    }
+
+    /// Rounds the number down to the given number of zeros,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-120_i8).saturating_floor_zeros(2), i8::MIN);
+    /// assert_eq!(255_u8.saturating_floor_zeros(2), 200_u8);
+    /// assert_eq!(123_i16.saturating_floor_zeros(1), 120_i16);
+    /// ```
+    #[inline]
+    fn saturating_floor_zeros(self, zeros: u32) -> Self {
+        self.floor_zeros(zeros)
+    }
+
+    /// Rounds the number to the given number of significant figures,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(251_u8.saturating_round_sf(1), u8::MAX);
+    /// assert_eq!((-125_i8).saturating_round_sf(1), -100_i8);
+    /// assert_eq!(123_u8.saturating_round_sf(2), 120_u8);
+    /// ```
+    #[inline]
+    fn saturating_round_sf(self, sig_figs: u32) -> Self {
+        self.round_sf(sig_figs)
+    }
+
+    /// Rounds the number up to the given number of significant figures,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(201_u8.saturating_ceil_sf(1), u8::MAX);
+    /// assert_eq!((-128_i8).saturating_ceil_sf(1), -100_i8);
+    /// assert_eq!(123_u8.saturating_ceil_sf(2), 130_u8);
+    /// ```
+    #[inline]
+    fn saturating_ceil_sf(self, sig_figs: u32) -> Self {
+        self.ceil_sf(sig_figs)
+    }
+
+    /// Rounds the number down to the given number of significant figures,
+    /// clamping the result to the bounds of the type if it doesn't fit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-121_i8).saturating_floor_sf(1), i8::MIN);
+    /// assert_eq!(255_u8.saturating_floor_sf(1), 200_u8);
+    /// assert_eq!((-113_i8).saturating_floor_sf(2), -120_i8);
+    /// ```
+    #[inline]
+    fn saturating_floor_sf(self, sig_figs: u32) -> Self {
+        self.floor_sf(sig_figs)
+    }
+
+    /// Rounds the number to the given number of zeros,
+    /// returning an error if the number isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.try_round_zeros(1), Ok(120_u8));
+    /// assert_eq!(250_u8.try_round_zeros(2), Err(RoundingError::Overflow));
+    /// assert_eq!(f64::NAN.try_round_zeros(1), Err(RoundingError::NonFinite));
+    /// ```
+    #[inline]
+    fn try_round_zeros(self, zeros: u32) -> Result<Self, RoundingError> {
+        self.checked_round_zeros(zeros)
+            .ok_or(RoundingError::Overflow)
+    }
+
+    /// Rounds the number up to the given number of zeros,
+    /// returning an error if the number isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_i16.try_ceil_zeros(1), Ok(130_i16));
+    /// assert_eq!(250_u8.try_ceil_zeros(2), Err(RoundingError::Overflow));
+    /// assert_eq!(f64::INFINITY.try_ceil_zeros(1), Err(RoundingError::NonFinite));
+    /// ```
+    #[inline]
+    fn try_ceil_zeros(self, zeros: u32) -> Result<Self, RoundingError> {
+        self.checked_ceil_zeros(zeros)
+            .ok_or(RoundingError::Overflow)
+    }
+
+    /// Rounds the number down to the given number of zeros,
+    /// returning an error if the number isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-113_i8).try_floor_zeros(1), Ok(-120_i8));
+    /// assert_eq!((-125_i8).try_floor_zeros(1), Err(RoundingError::Overflow));
+    /// assert_eq!(123.456_f64.try_floor_zeros(1), Ok(120_f64));
+    /// ```
+    #[inline]
+    fn try_floor_zeros(self, zeros: u32) -> Result<Self, RoundingError> {
+        self.checked_floor_zeros(zeros)
+            .ok_or(RoundingError::Overflow)
+    }
+
+    /// Rounds the number to the given number of significant figures,
+    /// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.try_round_sf(2), Ok(120_u8));
+    /// assert_eq!(251_u8.try_round_sf(1), Err(RoundingError::Overflow));
+    /// assert_eq!(123.456_f64.try_round_sf(0), Err(RoundingError::ZeroSigFigs));
+    /// ```
+    #[inline]
+    fn try_round_sf(self, sig_figs: u32) -> Result<Self, RoundingError> {
+        if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_round_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    /// Rounds the number up to the given number of significant figures,
+    /// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123_u8.try_ceil_sf(2), Ok(130_u8));
+    /// assert_eq!(201_u8.try_ceil_sf(1), Err(RoundingError::Overflow));
+    /// assert_eq!(f64::NAN.try_ceil_sf(2), Err(RoundingError::NonFinite));
+    /// ```
+    #[inline]
+    fn try_ceil_sf(self, sig_figs: u32) -> Result<Self, RoundingError> {
+        if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_ceil_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    /// Rounds the number down to the given number of significant figures,
+    /// returning an error if the number isn't finite, `sig_figs` is zero or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-113_i8).try_floor_sf(2), Ok(-120_i8));
+    /// assert_eq!(123_i32.try_floor_sf(0), Err(RoundingError::ZeroSigFigs));
+    /// assert_eq!(123.456_f32.try_floor_sf(4), Ok(123.4_f32));
+    /// ```
+    #[inline]
+    fn try_floor_sf(self, sig_figs: u32) -> Result<Self, RoundingError> {
+        if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_floor_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    /// Rounds the number to the given number of zeros, up or down at random
+    /// with a probability of going up equal to its distance from the step below
+    ///
+    /// `random` must give values uniformly distributed in `[0, 1)`, a seeded generator makes the
+    /// results reproducible. Integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(237_i32.round_zeros_stochastic(1, || 0.5), 240_i32);
+    /// assert_eq!((-237_i32).round_zeros_stochastic(1, || 0.5), -240_i32);
+    /// assert_eq!(237_u32.round_zeros_stochastic(1, || 0.9), 230_u32);
+    /// ```
+    #[inline]
+    fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, random: R) -> Self {
+        fallback::round_zeros_stochastic(self, zeros, random)
+    }
+
+    /// Rounds the number to the nearest multiple of the step, ties go away from zero
+    ///
+    /// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+    /// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0.3_f64.round_to_multiple(0.1), 0.3_f64);
+    /// assert_eq!(1.3_f64.round_to_multiple(0.25), 1.25_f64);
+    /// assert_eq!((-7_i32).round_to_multiple(5), -5_i32);
+    /// assert_eq!(3_u8.round_to_multiple(10), 0_u8);
+    /// ```
+    #[inline]
+    fn round_to_multiple(self, step: Self) -> Self {
+        fallback::to_multiple(self, step, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the next multiple of the step
+    ///
+    /// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+    /// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.ceil_to_multiple(0.25), 1.5_f64);
+    /// assert_eq!((-7_i32).ceil_to_multiple(5), -5_i32);
+    /// assert_eq!(3_u8.ceil_to_multiple(10), 10_u8);
+    /// ```
+    #[inline]
+    fn ceil_to_multiple(self, step: Self) -> Self {
+        fallback::to_multiple(self, step, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the previous multiple of the step
+    ///
+    /// Multiples are found from the decimal digits of float steps, so `0.3` rounded to a multiple of
+    /// `0.1` stays `0.3`. Integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.floor_to_multiple(0.25), 1.25_f64);
+    /// assert_eq!((-7_i32).floor_to_multiple(5), -10_i32);
+    /// assert_eq!(3_u8.floor_to_multiple(10), 0_u8);
+    /// ```
+    #[inline]
+    fn floor_to_multiple(self, step: Self) -> Self {
+        fallback::to_multiple(self, step, RoundingMode::Down)
+    }
+
+    /// Rounds the number to the nearest multiple of the step, ties go away from zero,
+    /// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.3_f64.try_round_to_multiple(0.25), Ok(1.25_f64));
+    /// assert_eq!(7_i32.try_round_to_multiple(0), Err(RoundingError::InvalidStep));
+    /// assert_eq!(f64::NAN.try_round_to_multiple(0.1), Err(RoundingError::NonFinite));
+    /// ```
+    #[inline]
+    fn try_round_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+        fallback::try_to_multiple(&self, &step, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the next multiple of the step,
+    /// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(7_i32.try_ceil_to_multiple(5), Ok(10_i32));
+    /// assert_eq!(251_u8.try_ceil_to_multiple(10), Err(RoundingError::Overflow));
+    /// assert_eq!(1.3_f64.try_ceil_to_multiple(-0.25), Err(RoundingError::InvalidStep));
+    /// ```
+    #[inline]
+    fn try_ceil_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+        fallback::try_to_multiple(&self, &step, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the previous multiple of the step,
+    /// returning an error if the number isn't finite, the step isn't positive or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-7_i32).try_floor_to_multiple(5), Ok(-10_i32));
+    /// assert_eq!((-127_i8).try_floor_to_multiple(5), Err(RoundingError::Overflow));
+    /// assert_eq!(1.3_f32.try_floor_to_multiple(0.25), Ok(1.25_f32));
+    /// ```
+    #[inline]
+    fn try_floor_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+        fallback::try_to_multiple(&self, &step, RoundingMode::Down)
+    }
+
+    /// Rounds the number to the nearest point of the grid, ties go away from zero relative to the offset of multiples of `step` shifted by `offset`
+    ///
+    /// The grid is made of every multiple of the step plus the offset, such as prices
+    /// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+    /// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+    /// below the offset can round down to zero
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(103_u32.quantize(10, 7), 107_u32);
+    /// assert_eq!(12.34_f64.quantize(1.0, 0.99), 11.99_f64);
+    /// assert_eq!((-12_i32).quantize(5, 1), -14_i32);
+    /// ```
+    #[inline]
+    fn quantize(self, step: Self, offset: Self) -> Self {
+        fallback::quantize(self, step, offset, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the next point of the grid of multiples of `step` shifted by `offset`
+    ///
+    /// The grid is made of every multiple of the step plus the offset, such as prices
+    /// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+    /// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+    /// below the offset can round down to zero
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(103_u32.ceil_quantize(10, 7), 107_u32);
+    /// assert_eq!(12.34_f64.ceil_quantize(1.0, 0.99), 12.99_f64);
+    /// assert_eq!((-12_i32).ceil_quantize(5, 1), -9_i32);
+    /// ```
+    #[inline]
+    fn ceil_quantize(self, step: Self, offset: Self) -> Self {
+        fallback::quantize(self, step, offset, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the previous point of the grid of multiples of `step` shifted by `offset`
+    ///
+    /// The grid is made of every multiple of the step plus the offset, such as prices
+    /// ending in `.99` with a step of `1` and an offset of `0.99`. Integer results that don't fit in
+    /// the type saturate at the bound on the same side of zero as the result, so an unsigned number
+    /// below the offset can round down to zero
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(103_u32.floor_quantize(10, 7), 97_u32);
+    /// assert_eq!(12.34_f64.floor_quantize(1.0, 0.99), 11.99_f64);
+    /// assert_eq!(3_u8.floor_quantize(10, 7), 0_u8);
+    /// ```
+    #[inline]
+    fn floor_quantize(self, step: Self, offset: Self) -> Self {
+        fallback::quantize(self, step, offset, RoundingMode::Down)
+    }
+
+    /// Rounds the number to the nearest point of the grid, ties go away from zero relative to the offset of multiples of `step` shifted by `offset`,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3_u8.checked_quantize(10, 7), Some(7_u8));
+    /// assert_eq!(252_u8.checked_quantize(10, 7), None);
+    /// assert_eq!(f64::NAN.checked_quantize(1.0, 0.99), None);
+    /// ```
+    #[inline]
+    fn checked_quantize(self, step: Self, offset: Self) -> Option<Self> {
+        fallback::checked_quantize(&self, &step, &offset, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to the next point of the grid of multiples of `step` shifted by `offset`,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3_u8.checked_ceil_quantize(10, 7), Some(7_u8));
+    /// assert_eq!(250_u8.checked_ceil_quantize(10, 7), None);
+    /// assert_eq!(12.34_f64.checked_ceil_quantize(1.0, 0.99), Some(12.99_f64));
+    /// ```
+    #[inline]
+    fn checked_ceil_quantize(self, step: Self, offset: Self) -> Option<Self> {
+        fallback::checked_quantize(&self, &step, &offset, RoundingMode::Up)
+    }
+
+    /// Rounds the number down to the previous point of the grid of multiples of `step` shifted by `offset`,
+    /// returning `None` if the result doesn't fit in the type or the number isn't finite
+    ///
+    /// # Panics
+    ///
+    /// If `step` isn't positive, or isn't finite for floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3_u8.checked_floor_quantize(10, 7), None);
+    /// assert_eq!(13_u8.checked_floor_quantize(10, 7), Some(7_u8));
+    /// assert_eq!((-12_i32).checked_floor_quantize(5, 1), Some(-14_i32));
+    /// ```
+    #[inline]
+    fn checked_floor_quantize(self, step: Self, offset: Self) -> Option<Self> {
+        fallback::checked_quantize(&self, &step, &offset, RoundingMode::Down)
+    }
+
+    /// Rounds the number to the nearest power of ten, ties go to the larger power
+    ///
+    /// Numbers are compared by distance, so the halfway point between `100` and `1000` is `550`
+    ///
+    /// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+    /// and integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3456_u32.round_pow10(), 1000_u32);
+    /// assert_eq!(5500_u32.round_pow10(), 10000_u32);
+    /// assert_eq!(0.034_f64.round_pow10(), 0.01_f64);
+    /// assert_eq!((-70_i32).round_pow10(), -100_i32);
+    /// ```
+    #[inline]
+    fn round_pow10(self) -> Self {
+        fallback::power_of_ten(self, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to a power of ten, a power of ten stays the same
+    ///
+    /// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+    /// and integer results that don't fit in the type saturate at its bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3456_u32.ceil_pow10(), 10000_u32);
+    /// assert_eq!(1000_u32.ceil_pow10(), 1000_u32);
+    /// assert_eq!(0.034_f64.ceil_pow10(), 0.1_f64);
+    /// assert_eq!((-3456_i32).ceil_pow10(), -10000_i32);
+    /// ```
+    #[inline]
+    fn ceil_pow10(self) -> Self {
+        fallback::power_of_ten(self, RoundingMode::AwayFromZero)
+    }
+
+    /// Rounds the number down to a power of ten, a power of ten stays the same
+    ///
+    /// Negative numbers are rounded by their magnitude and keep their sign, zero is returned as is
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3456_u32.floor_pow10(), 1000_u32);
+    /// assert_eq!(1000_u32.floor_pow10(), 1000_u32);
+    /// assert_eq!(0.034_f64.floor_pow10(), 0.01_f64);
+    /// assert_eq!((-3456_i32).floor_pow10(), -1000_i32);
+    /// ```
+    #[inline]
+    fn floor_pow10(self) -> Self {
+        fallback::power_of_ten(self, RoundingMode::TowardZero)
+    }
+
+    /// Rounds the number to the nearest power of ten, ties go to the larger power,
+    /// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(60_u8.checked_round_pow10(), Some(100_u8));
+    /// assert_eq!(55_000_u16.checked_round_pow10(), None);
+    /// assert_eq!(0_i32.checked_round_pow10(), None);
+    /// ```
+    #[inline]
+    fn checked_round_pow10(self) -> Option<Self> {
+        fallback::checked_power_of_ten(&self, RoundingMode::HalfAwayFromZero)
+    }
+
+    /// Rounds the number up to a power of ten,
+    /// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0.034_f64.checked_ceil_pow10(), Some(0.1_f64));
+    /// assert_eq!(101_u8.checked_ceil_pow10(), None);
+    /// assert_eq!(f64::MAX.checked_ceil_pow10(), None);
+    /// ```
+    #[inline]
+    fn checked_ceil_pow10(self) -> Option<Self> {
+        fallback::checked_power_of_ten(&self, RoundingMode::AwayFromZero)
+    }
+
+    /// Rounds the number down to a power of ten,
+    /// returning `None` if the number is zero, isn't finite or the result doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-3456_i32).checked_floor_pow10(), Some(-1000_i32));
+    /// assert_eq!(0.0_f64.checked_floor_pow10(), None);
+    /// assert_eq!(f64::NAN.checked_floor_pow10(), None);
+    /// ```
+    #[inline]
+    fn checked_floor_pow10(self) -> Option<Self> {
+        fallback::checked_power_of_ten(&self, RoundingMode::TowardZero)
+    }
+
+    /// The exponent of the largest power of ten that is no more than the magnitude of the number,
+    /// `None` if the number is zero or isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3456_u32.order_of_magnitude(), Some(3));
+    /// assert_eq!(0.034_f64.order_of_magnitude(), Some(-2));
+    /// assert_eq!((-0.1_f32).order_of_magnitude(), Some(-1));
+    /// assert_eq!(0_i32.order_of_magnitude(), None);
+    /// ```
+    #[inline]
+    fn order_of_magnitude(self) -> Option<i32> {
+        fallback::order_of_magnitude(&self)
+    }
+
+    /// Counts the significant figures of the number, from its first to its last non zero digit
+    ///
+    /// Floats are counted in their shortest decimal representation that round trips, so `1.20` has 2
+    /// significant figures as it is the same float as `1.2`. Trailing zeros of integers are ambiguous
+    /// and never counted, so `1500` has 2. Zero and non finite numbers have none
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1500_u32.count_sig_figs(), 2);
+    /// assert_eq!(1.20_f64.count_sig_figs(), 2);
+    /// assert_eq!(0.00340_f64.count_sig_figs(), 2);
+    /// assert_eq!((-120_034_i64).count_sig_figs(), 6);
+    /// assert_eq!(0_i32.count_sig_figs(), 0);
+    /// ```
+    #[inline]
+    fn count_sig_figs(self) -> u32 {
+        fallback::count_sig_figs(&self)
+    }
+
+    /// Counts the digits of the integer part of the number, which is 1 for zero and for floats
+    /// with a magnitude below one. Non finite numbers have none
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0_u32.digit_count(), 1);
+    /// assert_eq!(u64::MAX.digit_count(), 20);
+    /// assert_eq!((-123.456_f64).digit_count(), 3);
+    /// assert_eq!(0.5_f32.digit_count(), 1);
+    /// ```
+    #[inline]
+    fn digit_count(self) -> u32 {
+        self.get_digits().max(1) as u32
+    }
+
+    /// The base 10 digits of the integer part of the number, most significant first
+    ///
+    /// Floats use their shortest decimal representation that round trips, so the digits of `1e20`
+    /// are a one followed by twenty zeros. Non finite numbers have no digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert!(1203_u32.digits().eq([1, 2, 0, 3]));
+    /// assert!((-45_i8).digits().eq([4, 5]));
+    /// assert!(123.456_f64.digits().eq([1, 2, 3]));
+    /// assert!(0.5_f64.digits().eq([0]));
+    /// ```
+    #[inline]
+    fn digits(self) -> Digits {
+        fallback::digits(&self)
+    }
+
+    /// Rounds the number to the given number of significant figures like [`round_sf`](Roundable::round_sf) and renders
+    /// it with exactly that many significant digits in fixed notation, padding with trailing zeros
+    /// after the decimal point so `1.203` to 3 significant figures is `1.20`
+    ///
+    /// An integer part with more digits than the significant figures is padded with zeros, which is
+    /// ambiguous as `123500` could have 4, 5 or 6 significant figures, use [`format_sf_notation`](Roundable::format_sf_notation) with
+    /// [`Notation::Scientific`] to avoid it. Integers that round past the bounds of their type aren't
+    /// saturated, so `250_u8` to 1 significant figure is `300`. Results that round to zero keep the
+    /// sign of the number and zero significant figures render as `0`.
+    /// NaN renders as `NaN` and infinities as `inf` and `-inf`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.203_f64.format_sf(3), "1.20");
+    /// assert_eq!(0.0034_f64.format_sf(3), "0.00340");
+    /// assert_eq!((-123_456_i32).format_sf(4), "-123500");
+    /// assert_eq!(f64::NAN.format_sf(2), "NaN");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn format_sf(self, sig_figs: u32) -> String {
+        self.format_sf_notation(sig_figs, Notation::Fixed)
+    }
+
+    /// Rounds the number to the given number of significant figures like [`round_sf`](Roundable::round_sf) and renders
+    /// it with exactly that many significant digits in the given notation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1.203_f64.format_sf_notation(3, Notation::Scientific), "1.20e0");
+    /// assert_eq!(0.0034_f32.format_sf_notation(3, Notation::Scientific), "3.40e-3");
+    /// assert_eq!((-123_456_i32).format_sf_notation(4, Notation::Scientific), "-1.235e5");
+    /// assert_eq!(999_u16.format_sf_notation(2, Notation::Fixed), "1000");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+        fallback::format_sf(&self, sig_figs, notation)
+    }
+
+    /// Rounds the number to the given number of decimal places like
+    /// [`round_decimals`](Roundable::round_decimals) and renders it like
+    /// [`format_dp`](Float::format_dp), with the integer digits split into groups of three by a
+    /// separator
+    ///
+    /// Integers have no fractional part to round and are padded with zeros to the decimal places.
+    /// Every magnitude is written out in full, so large numbers never switch to scientific notation
+    /// and `1e20` has 21 digits. NaN renders as `NaN` and infinities as `inf` and `-inf`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1_234_567.891_f64.format_grouped(2, ',', '.'), "1,234,567.89");
+    /// assert_eq!(1_234_567.891_f64.format_grouped(2, '.', ','), "1.234.567,89");
+    /// assert_eq!((-999.5_f64).format_grouped(0, ',', '.'), "-1,000");
+    /// assert_eq!(1_234_567_u32.format_grouped(1, ' ', '.'), "1 234 567.0");
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_grouped(
+        self,
+        decimal_places: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String {
+        self.format_locale(decimal_places, decimal_separator, Some(group_separator))
+    }
+
+    /// Rounds the number to the given number of decimal places like
+    /// [`round_decimals`](Roundable::round_decimals) and renders it with the given decimal
+    /// separator, and the integer digits split into groups of three by the group separator if
+    /// there is one. The inverse of [`parse_locale`]
+    ///
+    /// [`format_grouped`](Roundable::format_grouped) renders the digits the same way, with the separator arguments
+    /// swapped and a group separator required
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1_234.567_f64.format_locale(2, ',', Some('.')), "1.234,57");
+    /// assert_eq!(1_234.567_f64.format_locale(2, ',', None), "1234,57");
+    /// assert_eq!((-0.5_f32).format_locale(1, '.', Some(',')), "-0.5");
+    /// assert_eq!(12_345_i32.format_locale(0, ',', Some(' ')), "12 345");
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn format_locale(
+        self,
+        decimal_places: u32,
+        decimal_separator: char,
+        group_separator: Option<char>,
+    ) -> String {
+        fallback::format_locale(&self, decimal_places, decimal_separator, group_separator)
+    }
+
+    /// Rounds the number to a multiple of `10^exponent` using the given rounding mode, or returns
+    /// `None` if the result doesn't fit in the type
+    ///
+    /// A negative exponent rounds to decimal places, which leaves integers unchanged. This and
+    /// [`get_digits`](Roundable::get_digits) are the core the zeros and significant figure methods
+    /// are built on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(1_250_i32.round_to_exponent(2, RoundingMode::HalfEven), Some(1_200));
+    /// assert_eq!(125_i32.round_to_exponent(-1, RoundingMode::Up), Some(125));
+    /// assert_eq!(1.25_f64.round_to_exponent(-1, RoundingMode::Up), Some(1.3));
+    /// assert_eq!(120_i8.round_to_exponent(2, RoundingMode::Up), None);
+    /// ```
+    #[inline]
+    fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<Self> {
+        fallback::round_to_exponent(self, exponent, mode)
+    }
+
+    /// The number of digits before the decimal point, which is negative for the leading zeros
+    /// after it. This is `floor(log10(|x|)) + 1`, so `0.05` has -1 digits, and zero or a non finite
+    /// number has none
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(123.4_f64.get_digits(), 3);
+    /// assert_eq!(0.05_f64.get_digits(), -1);
+    /// assert_eq!((-999_i32).get_digits(), 3);
+    /// assert_eq!(0_u8.get_digits(), 0);
+    /// ```
+    #[inline]
+    fn get_digits(&self) -> i32 {
+        fallback::get_digits(self)
+    }
+
+    /// The number as a decimal `mantissa * 10^exponent`, or `None` if it isn't finite or its
+    /// mantissa doesn't fit in an i128
+    ///
+    /// Any mantissa and exponent with the right value will do. The default methods saturate at the
+    /// largest mantissa [`from_decimal`](Roundable::from_decimal) accepts at the exponent given here,
+    /// so it should be the exponent of the smallest step of the type. Floats give their shortest
+    /// decimal representation that round trips
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!((-1_250_i32).to_decimal(), Some((-1_250, 0)));
+    /// assert_eq!(1.25_f64.to_decimal(), Some((125, -2)));
+    /// assert_eq!(f64::NAN.to_decimal(), None);
+    /// ```
+    fn to_decimal(&self) -> Option<(i128, i32)>;
+
+    /// The number with the value of a decimal `mantissa * 10^exponent`, or `None` if the type
+    /// can't hold it. Floats give the closest float and `None` if it isn't finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(i32::from_decimal(125, 1), Some(1_250));
+    /// assert_eq!(i32::from_decimal(125, -1), None);
+    /// assert_eq!(u8::from_decimal(3, 2), None);
+    /// assert_eq!(f64::from_decimal(125, -2), Some(1.25));
+    /// ```
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<Self>;
+
+    #[doc(hidden)]
+    #[inline]
+    fn nearer_above(self, below: Self, above: Self) -> bool {
+        fallback::nearer_above(&self, &below, &above)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn tie_at(self, decimal_places: i32) -> bool {
+        fallback::tie_at(&self, decimal_places)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn rounding_error(self, rounded: Self) -> Self {
+        fallback::rounding_error(self, rounded)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn round_slice_zeros_mode(values: &mut [Self], zeros: u32, mode: RoundingMode) {
+        fallback::round_slice_zeros_mode(values, zeros, mode)
+    }
+}
+
+/// The exponent of the power of ten that rounding to the given number of decimal places rounds to
+/// a multiple of, clamped to the exponents the core method takes
+#[inline]
+fn dp_exponent(decimal_places: u32) -> i32 {
+    -i32::try_from(decimal_places).unwrap_or(i32::MAX)
+}
+
+/// The exponent of the power of ten that rounding to the given number of zeros rounds to a multiple
+/// of, clamped to the exponents the core method takes
+#[inline]
+fn zeros_exponent(zeros: u32) -> i32 {
+    i32::try_from(zeros).unwrap_or(i32::MAX)
+}
+
+/// The exponent of the power of ten that rounding the number to the given significant figures
+/// rounds to a multiple of
+#[inline]
+fn sf_exponent<T: Roundable>(number: &T, sig_figs: u32) -> i32 {
+    (number.get_digits() as i64 - sig_figs as i64).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+impl Roundable for f32 {
+    #[inline]
+    fn round_decimals(self, decimal_places: u32) -> f32 {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_decimals(self, decimal_places: u32) -> f32 {
+        self.round_dp_mode(decimal_places, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_decimals(self, decimal_places: u32) -> f32 {
+        self.round_dp_mode(decimal_places, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn away_zeros(self, zeros: u32) -> f32 {
+        self.abs().ceil_zeros(zeros).copysign(self)
+    }
+
+    #[inline]
+    fn round_zeros_half_away(self, zeros: u32) -> f32 {
+        self.abs()
+            .round_zeros_mode(zeros, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
+    }
+
+    #[inline]
+    fn round_zeros_half_odd(self, zeros: u32) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::zeros_f32(self, zeros, RoundingMode::HalfOdd)
+    }
+
+    #[inline]
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        scale::zeros_f32(self, zeros, mode)
+    }
+
+    #[inline]
+    fn round_zeros_even(self, zeros: u32) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::zeros_f32(self, zeros, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn away_sf(self, sig_figs: u32) -> f32 {
+        self.abs().ceil_sf(sig_figs).copysign(self)
+    }
+
+    #[inline]
+    fn round_sf_half_away(self, sig_figs: u32) -> f32 {
+        self.abs()
+            .round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
+    }
+
+    #[inline]
+    fn round_sf_half_odd(self, sig_figs: u32) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::sig_figs_f32(self, sig_figs, RoundingMode::HalfOdd)
+    }
+
+    #[inline]
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Self {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        // No f32 needs more than 9 significant figures to be represented exactly
+        if sig_figs >= 9 {
+            return self;
+        }
+
+        // Checked explicitly as the error in scaling could push these to the next step
+        let digits = self.get_digits();
+        if digits::has_sig_figs_f32(self, sig_figs, digits) {
+            return self;
+        }
+
+        let decimal_places = sig_figs as i32 - digits;
+        if decimal_places >= 0 {
+            self.round_dp_mode(decimal_places as u32, mode)
+        } else {
+            self.round_zeros_mode(decimal_places.unsigned_abs(), mode)
+        }
+    }
+
+    #[inline]
+    fn round_sf_even(self, sig_figs: u32) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::sig_figs_f32(self, sig_figs, RoundingMode::HalfEven)
+    }
+
+    #[inline]
+    fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, mut random: R) -> f32 {
+        let below = self.floor_zeros(zeros);
+        let above = self.ceil_zeros(zeros);
+        if below == above {
+            return self;
+        }
+
+        let fraction = (self - below) as f64 / (above - below) as f64;
+        if random() < fraction {
+            above
+        } else {
+            below
+        }
+    }
+
+    #[inline]
+    fn round_to_multiple(self, step: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f32(self, step, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_to_multiple(self, step: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f32(self, step, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_to_multiple(self, step: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f32(self, step, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn try_round_to_multiple(self, step: f32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f32(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f32(self, step, RoundingMode::HalfAwayFromZero);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
+    }
+
+    #[inline]
+    fn try_ceil_to_multiple(self, step: f32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f32(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f32(self, step, RoundingMode::Up);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
+    }
+
+    #[inline]
+    fn try_floor_to_multiple(self, step: f32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f32(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f32(self, step, RoundingMode::Down);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
+    }
+
+    #[inline]
+    fn quantize(self, step: f32, offset: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f32(self, step, offset, RoundingMode::HalfAwayFromZero)
+    }
+
+    #[inline]
+    fn ceil_quantize(self, step: f32, offset: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f32(self, step, offset, RoundingMode::Up)
+    }
+
+    #[inline]
+    fn floor_quantize(self, step: f32, offset: f32) -> f32 {
+        assert!(
+            multiple::is_valid_step_f32(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f32(self, step, offset, RoundingMode::Down)
+    }
+
+    #[inline]
+    fn checked_quantize(self, step: f32, offset: f32) -> Option<f32> {
+        let rounded = self.quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn checked_ceil_quantize(self, step: f32, offset: f32) -> Option<f32> {
+        let rounded = self.ceil_quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn checked_floor_quantize(self, step: f32, offset: f32) -> Option<f32> {
+        let rounded = self.floor_quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn round_pow10(self) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(
+            self.abs(),
+            self.get_digits() - 1,
+            RoundingMode::HalfAwayFromZero,
+        )
+        .copysign(self)
+    }
+
+    #[inline]
+    fn ceil_pow10(self) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(
+            self.abs(),
+            self.get_digits() - 1,
+            RoundingMode::AwayFromZero,
+        )
+        .copysign(self)
+    }
+
+    #[inline]
+    fn floor_pow10(self) -> f32 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(self.abs(), self.get_digits() - 1, RoundingMode::TowardZero)
+            .copysign(self)
+    }
+
+    #[inline]
+    fn checked_round_pow10(self) -> Option<f32> {
+        let rounded = self.round_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn checked_ceil_pow10(self) -> Option<f32> {
+        let rounded = self.ceil_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn checked_floor_pow10(self) -> Option<f32> {
+        let rounded = self.floor_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn order_of_magnitude(self) -> Option<i32> {
+        (self != 0.0 && self.is_finite()).then(|| self.get_digits() - 1)
+    }
+
+    #[inline]
+    fn count_sig_figs(self) -> u32 {
+        if self == 0.0 || !self.is_finite() {
+            return 0;
+        }
+
+        digits::shortest_sig_figs(self)
+    }
 
-    /// Rounds the number up to the given number of decimal places
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123.454_f64.ceil_dp(2), 123.46_f64);
-    /// assert_eq!(123.456_f64.ceil_dp(0), 124_f64);
-    /// assert_eq!(123.454_f32.ceil_dp(2), 123.46_f32);
-    /// ```
-    fn ceil_dp(self, decimal_places: u32) -> Self;
+    #[inline]
+    fn digit_count(self) -> u32 {
+        if !self.is_finite() {
+            return 0;
+        }
 
-    /// Rounds the number down to the given number of decimal places
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123.456_f64.floor_dp(2), 123.45_f64);
-    /// assert_eq!(123.456_f64.floor_dp(0), 123_f64);
-    /// assert_eq!(123.454_f32.floor_dp(2), 123.454_f32);
-    /// ```
-    fn floor_dp(self, decimal_places: u32) -> Self;
-}
+        self.get_digits().max(1) as u32
+    }
 
-impl Float for f32 {
     #[inline]
-    fn round_dp(self, decimal_places: u32) -> f32 {
-        let power = 10_f32.powi(decimal_places as i32);
-        (self * power).round() / power
+    fn digits(self) -> Digits {
+        Digits::float(self, self.is_finite())
     }
 
     #[inline]
-    fn ceil_dp(self, decimal_places: u32) -> f32 {
-        let power = 10_f32.powi(decimal_places as i32);
-        (self * power).ceil() / power
+    #[cfg(feature = "alloc")]
+    fn format_sf(self, sig_figs: u32) -> String {
+        self.format_sf_notation(sig_figs, Notation::Fixed)
     }
 
     #[inline]
-    fn floor_dp(self, decimal_places: u32) -> f32 {
-        let power = 10_f32.powi(decimal_places as i32);
-        (self * power).floor() / power
+    #[cfg(feature = "alloc")]
+    fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+        let rounded = self.round_sf(sig_figs);
+        if !rounded.is_finite() {
+            return rounded.to_string();
+        }
+
+        let (significand, fraction_digits) = digits::shortest_decimal(rounded);
+        format::significant(
+            significand as u128,
+            fraction_digits,
+            rounded.is_sign_negative(),
+            sig_figs,
+            notation,
+        )
     }
-}
 
-impl Float for f64 {
     #[inline]
-    fn round_dp(self, decimal_places: u32) -> f64 {
-        let power = 10_f64.powi(decimal_places as i32);
-        (self * power).round() / power
+    #[cfg(feature = "alloc")]
+    fn format_grouped(
+        self,
+        decimal_places: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String {
+        self.format_locale(decimal_places, decimal_separator, Some(group_separator))
     }
 
     #[inline]
-    fn ceil_dp(self, decimal_places: u32) -> f64 {
-        let power = 10_f64.powi(decimal_places as i32);
-        (self * power).ceil() / power
+    #[cfg(feature = "alloc")]
+    fn format_locale(
+        self,
+        decimal_places: u32,
+        decimal_separator: char,
+        group_separator: Option<char>,
+    ) -> String {
+        format::group(
+            &self.format_dp(decimal_places),
+            decimal_separator,
+            group_separator,
+        )
     }
 
     #[inline]
-    fn floor_dp(self, decimal_places: u32) -> f64 {
-        let power = 10_f64.powi(decimal_places as i32);
-        (self * power).floor() / power
+    fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<f32> {
+        let rounded = match u32::try_from(exponent) {
+            Ok(zeros) => self.round_zeros_mode(zeros, mode),
+            Err(_) => self.round_dp_mode(exponent.unsigned_abs(), mode),
+        };
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
-}
 
-pub trait Roundable {
-    /// Rounds the number to the given number of zeros
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123.456_f64.round_zeros(1), 120_f64);
-    /// assert_eq!(123.456_f64.round_zeros(0), 123_f64);
-    /// assert_eq!(123_i32.round_zeros(2), 100_i32);
-    /// ```
-    fn round_zeros(self, zeros: u32) -> Self;
+    #[inline]
+    fn get_digits(&self) -> i32 {
+        let magnitude = self.abs();
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return 0;
+        }
 
-    /// Rounds the number up to the given number of zeros
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(ceil_zeros(123.456_f64, 1), 130_f64);
-    /// assert_eq!(ceil_zeros(123.456_f64, 0), 124_f64);
-    /// assert_eq!(ceil_zeros(123_i32, 2), 200_i32);
-    /// ```
-    fn ceil_zeros(self, zeros: u32) -> Self;
+        // The powers of ten below the normal range underflow, so count the digits of a scaled up copy
+        if magnitude < f32::MIN_POSITIVE {
+            return (magnitude * 1e30).get_digits() - 30;
+        }
 
-    /// Rounds the number down to the given number of zeros
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123.456_f64.floor_zeros(1), 120_f64);
-    /// assert_eq!(123.654_f64.floor_zeros(0), 123_f64);
-    /// assert_eq!(156_i32.floor_zeros(2), 100_i32);
-    /// ```
-    fn floor_zeros(self, zeros: u32) -> Self;
+        // The estimate from the exponent can be one short so check it against the next power,
+        // which is only approximate for large exponents so it is parsed exactly when it is close
+        let mut digits = powers::min_digits_f32(magnitude);
+        let mut power = powers::ten_f32(digits);
+        if (magnitude / power - 1.0).abs() < 1e-4 {
+            power = digits::scale(1, digits as i64);
+        }
+        if power <= magnitude {
+            digits += 1;
+        }
+        digits
+    }
 
-    /// Rounds the number to the given number of significant figures
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123456_f64.round_sf(4), 123500_f64);
-    /// assert_eq!(123.456_f64.round_sf(2), 120_f64);
-    /// assert_eq!(123.456_f32.round_sf(4), 123.5_f32);
-    /// ```
-    fn round_sf(self, sig_figs: u32) -> Self;
+    #[inline]
+    fn to_decimal(&self) -> Option<(i128, i32)> {
+        if !self.is_finite() {
+            return None;
+        }
 
-    /// Rounds the number up to the given number of significant figures
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123321_f64.ceil_sf(4), 123400_f64);
-    /// assert_eq!(123.456_f64.ceil_sf(2), 130_f64);
-    /// assert_eq!(123.321_f32.ceil_sf(4), 123.4_f32);
-    /// ```
-    fn ceil_sf(self, sig_figs: u32) -> Self;
+        let (significand, fraction_digits) = digits::shortest_decimal(self.abs());
+        let mantissa = significand as i128;
+        let mantissa = if self.is_sign_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+        Some((mantissa, -fraction_digits))
+    }
+
+    #[inline]
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<f32> {
+        let number: f32 = digits::scale(mantissa, exponent as i64);
+        number.is_finite().then_some(number)
+    }
 
-    /// Rounds the number down to the given number of significant figures
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use common_math::rounding::*;
-    ///
-    /// assert_eq!(123456_f64.floor_sf(4), 123400_f64);
-    /// assert_eq!(656.323_f64.floor_sf(2), 650_f64);
-    /// assert_eq!(123.456_f32.floor_sf(4), 123.4_f32);
-    /// ```
-    fn floor_sf(self, sig_figs: u32) -> Self;
-    
     #[doc(hidden)]
-    fn get_digits(&self) -> u32;
-}
+    #[inline]
+    fn tie_at(self, decimal_places: i32) -> bool {
+        outcome::is_tie_f32(self, decimal_places)
+    }
 
-impl Roundable for f32 {
+    #[doc(hidden)]
     #[inline]
-    fn round_zeros(self, zeros: u32) -> f32 {
-        let power = 10_f32.powi(zeros as i32);
-        (self / power).round() * power
+    fn rounding_error(self, rounded: f32) -> f32 {
+        self - rounded
     }
 
+    #[doc(hidden)]
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> f32 {
-        let power = 10_f32.powi(zeros as i32);
-        (self / power).ceil() * power
+    fn round_slice_zeros_mode(values: &mut [f32], zeros: u32, mode: RoundingMode) {
+        let scale = scale::ZerosF32::new(zeros);
+        for value in values
+            .iter_mut()
+            .filter(|value| **value != 0.0 && value.is_finite())
+        {
+            *value = scale.round(*value, mode);
+        }
     }
 
+    #[doc(hidden)]
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> f32 {
-        let power = 10_f32.powi(zeros as i32);
-        (self / power).floor() * power
+    fn nearer_above(self, below: f32, above: f32) -> bool {
+        // Each distance is kept as a float and its rounding error so they compare exactly
+        let (distance_below, error_below) = two_difference(self, below);
+        let (distance_above, error_above) = two_difference(above, self);
+        (distance_above, error_above) < (distance_below, error_below)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f32.powi(digits - sig_figs as i32);
-        (self / power).round() * power
+    fn checked_round_zeros(self, zeros: u32) -> Option<f32> {
+        let rounded = self.round_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f32.powi(digits - sig_figs as i32);
-        (self / power).ceil() * power
+    fn checked_ceil_zeros(self, zeros: u32) -> Option<f32> {
+        let rounded = self.ceil_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f32.powi(digits - sig_figs as i32);
-        (self / power).floor() * power
+    fn checked_floor_zeros(self, zeros: u32) -> Option<f32> {
+        let rounded = self.floor_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        self.abs().log10().ceil() as u32
+    fn checked_round_sf(self, sig_figs: u32) -> Option<f32> {
+        let rounded = self.round_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
-}
 
-impl Roundable for f64 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> f64 {
-        let power = 10_f64.powi(zeros as i32);
-        (self / power).round() * power
+    fn checked_ceil_sf(self, sig_figs: u32) -> Option<f32> {
+        let rounded = self.ceil_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> f64 {
-        let power = 10_f64.powi(zeros as i32);
-        (self / power).ceil() * power
+    fn checked_floor_sf(self, sig_figs: u32) -> Option<f32> {
+        let rounded = self.floor_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> f64 {
-        let power = 10_f64.powi(zeros as i32);
-        (self / power).floor() * power
+    fn try_round_zeros(self, zeros: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_round_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        (self / power).round() * power
+    fn try_ceil_zeros(self, zeros: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_ceil_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        (self / power).ceil() * power
+    fn try_floor_zeros(self, zeros: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_floor_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        (self / power).floor() * power
+    fn try_round_sf(self, sig_figs: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_round_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        self.abs().log10().ceil() as u32
+    fn try_ceil_sf(self, sig_figs: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_ceil_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    #[inline]
+    fn try_floor_sf(self, sig_figs: u32) -> Result<f32, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_floor_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 }
 
-impl Roundable for i8 {
+impl Roundable for f64 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> i8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as i8
+    fn round_decimals(self, decimal_places: u32) -> f64 {
+        self.round_dp_mode(decimal_places, RoundingMode::HalfAwayFromZero)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> i8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as i8
+    fn ceil_decimals(self, decimal_places: u32) -> f64 {
+        self.round_dp_mode(decimal_places, RoundingMode::Up)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> i8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as i8
+    fn floor_decimals(self, decimal_places: u32) -> f64 {
+        self.round_dp_mode(decimal_places, RoundingMode::Down)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as i8
+    fn away_zeros(self, zeros: u32) -> f64 {
+        self.abs().ceil_zeros(zeros).copysign(self)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as i8
+    fn round_zeros_half_away(self, zeros: u32) -> f64 {
+        self.abs()
+            .round_zeros_mode(zeros, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as i8
+    fn round_zeros_half_odd(self, zeros: u32) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::zeros_f64(self, zeros, RoundingMode::HalfOdd)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        scale::zeros_f64(self, zeros, mode)
     }
-}
 
-impl Roundable for i16 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> i16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as i16
+    fn round_zeros_even(self, zeros: u32) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::zeros_f64(self, zeros, RoundingMode::HalfEven)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> i16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as i16
+    fn away_sf(self, sig_figs: u32) -> f64 {
+        self.abs().ceil_sf(sig_figs).copysign(self)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> i16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as i16
+    fn round_sf_half_away(self, sig_figs: u32) -> f64 {
+        self.abs()
+            .round_sf_mode(sig_figs, RoundingMode::HalfAwayFromZero)
+            .copysign(self)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as i16
+    fn round_sf_half_odd(self, sig_figs: u32) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::sig_figs_f64(self, sig_figs, RoundingMode::HalfOdd)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as i16
+    fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Self {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        // No f64 needs more than 17 significant figures to be represented exactly
+        if sig_figs >= 17 {
+            return self;
+        }
+
+        // Checked explicitly as the error in scaling could push these to the next step
+        let digits = self.get_digits();
+        if digits::has_sig_figs_f64(self, sig_figs, digits) {
+            return self;
+        }
+
+        let decimal_places = sig_figs as i32 - digits;
+        if decimal_places >= 0 {
+            self.round_dp_mode(decimal_places as u32, mode)
+        } else {
+            self.round_zeros_mode(decimal_places.unsigned_abs(), mode)
+        }
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as i16
+    fn round_sf_even(self, sig_figs: u32) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        exact::sig_figs_f64(self, sig_figs, RoundingMode::HalfEven)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, mut random: R) -> f64 {
+        let below = self.floor_zeros(zeros);
+        let above = self.ceil_zeros(zeros);
+        if below == above {
+            return self;
+        }
+
+        let fraction = (self - below) / (above - below);
+        if random() < fraction {
+            above
+        } else {
+            below
+        }
     }
-}
 
-impl Roundable for i32 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> i32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as i32
+    fn round_to_multiple(self, step: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f64(self, step, RoundingMode::HalfAwayFromZero)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> i32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as i32
+    fn ceil_to_multiple(self, step: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f64(self, step, RoundingMode::Up)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> i32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as i32
+    fn floor_to_multiple(self, step: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::multiple_f64(self, step, RoundingMode::Down)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as i32
+    fn try_round_to_multiple(self, step: f64) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f64(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f64(self, step, RoundingMode::HalfAwayFromZero);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as i32
+    fn try_ceil_to_multiple(self, step: f64) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f64(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f64(self, step, RoundingMode::Up);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as i32
+    fn try_floor_to_multiple(self, step: f64) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if !multiple::is_valid_step_f64(step) {
+            Err(RoundingError::InvalidStep)
+        } else {
+            let rounded = multiple::multiple_f64(self, step, RoundingMode::Down);
+            if rounded.is_finite() {
+                Ok(rounded)
+            } else {
+                Err(RoundingError::Overflow)
+            }
+        }
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn quantize(self, step: f64, offset: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f64(self, step, offset, RoundingMode::HalfAwayFromZero)
     }
-}
 
-impl Roundable for i64 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> i64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as i64
+    fn ceil_quantize(self, step: f64, offset: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f64(self, step, offset, RoundingMode::Up)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> i64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as i64
+    fn floor_quantize(self, step: f64, offset: f64) -> f64 {
+        assert!(
+            multiple::is_valid_step_f64(step),
+            "step must be positive and finite"
+        );
+        if !self.is_finite() {
+            return self;
+        }
+
+        multiple::quantize_f64(self, step, offset, RoundingMode::Down)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> i64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as i64
+    fn checked_quantize(self, step: f64, offset: f64) -> Option<f64> {
+        let rounded = self.quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as i64
+    fn checked_ceil_quantize(self, step: f64, offset: f64) -> Option<f64> {
+        let rounded = self.ceil_quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as i64
+    fn checked_floor_quantize(self, step: f64, offset: f64) -> Option<f64> {
+        let rounded = self.floor_quantize(step, offset);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as i64
+    fn round_pow10(self) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(
+            self.abs(),
+            self.get_digits() - 1,
+            RoundingMode::HalfAwayFromZero,
+        )
+        .copysign(self)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn ceil_pow10(self) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(
+            self.abs(),
+            self.get_digits() - 1,
+            RoundingMode::AwayFromZero,
+        )
+        .copysign(self)
     }
-}
 
-impl Roundable for u8 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> u8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as u8
+    fn floor_pow10(self) -> f64 {
+        if self == 0.0 || !self.is_finite() {
+            return self;
+        }
+
+        digits::power_of_ten(self.abs(), self.get_digits() - 1, RoundingMode::TowardZero)
+            .copysign(self)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> u8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as u8
+    fn checked_round_pow10(self) -> Option<f64> {
+        let rounded = self.round_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> u8 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as u8
+    fn checked_ceil_pow10(self) -> Option<f64> {
+        let rounded = self.ceil_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as u8
+    fn checked_floor_pow10(self) -> Option<f64> {
+        let rounded = self.floor_pow10();
+        (self != 0.0 && self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as u8
+    fn order_of_magnitude(self) -> Option<i32> {
+        (self != 0.0 && self.is_finite()).then(|| self.get_digits() - 1)
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as u8
+    fn count_sig_figs(self) -> u32 {
+        if self == 0.0 || !self.is_finite() {
+            return 0;
+        }
+
+        digits::shortest_sig_figs(self)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn digit_count(self) -> u32 {
+        if !self.is_finite() {
+            return 0;
+        }
+
+        self.get_digits().max(1) as u32
     }
-}
 
-impl Roundable for u16 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> u16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as u16
+    fn digits(self) -> Digits {
+        Digits::float(self, self.is_finite())
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> u16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as u16
+    #[cfg(feature = "alloc")]
+    fn format_sf(self, sig_figs: u32) -> String {
+        self.format_sf_notation(sig_figs, Notation::Fixed)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> u16 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as u16
+    #[cfg(feature = "alloc")]
+    fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+        let rounded = self.round_sf(sig_figs);
+        if !rounded.is_finite() {
+            return rounded.to_string();
+        }
+
+        let (significand, fraction_digits) = digits::shortest_decimal(rounded);
+        format::significant(
+            significand as u128,
+            fraction_digits,
+            rounded.is_sign_negative(),
+            sig_figs,
+            notation,
+        )
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as u16
+    #[cfg(feature = "alloc")]
+    fn format_grouped(
+        self,
+        decimal_places: u32,
+        group_separator: char,
+        decimal_separator: char,
+    ) -> String {
+        self.format_locale(decimal_places, decimal_separator, Some(group_separator))
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as u16
+    #[cfg(feature = "alloc")]
+    fn format_locale(
+        self,
+        decimal_places: u32,
+        decimal_separator: char,
+        group_separator: Option<char>,
+    ) -> String {
+        format::group(
+            &self.format_dp(decimal_places),
+            decimal_separator,
+            group_separator,
+        )
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as u16
+    fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<f64> {
+        let rounded = match u32::try_from(exponent) {
+            Ok(zeros) => self.round_zeros_mode(zeros, mode),
+            Err(_) => self.round_dp_mode(exponent.unsigned_abs(), mode),
+        };
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
+    }
+
+    #[inline]
+    fn get_digits(&self) -> i32 {
+        let magnitude = self.abs();
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return 0;
+        }
+
+        // The powers of ten below the normal range underflow, so count the digits of a scaled up copy
+        if magnitude < f64::MIN_POSITIVE {
+            return (magnitude * 1e300).get_digits() - 300;
+        }
+
+        // The estimate from the exponent can be one short so check it against the next power,
+        // which is only approximate for large exponents so it is parsed exactly when it is close
+        let mut digits = powers::min_digits_f64(magnitude);
+        let mut power = powers::ten_f64(digits);
+        if (magnitude / power - 1.0).abs() < 1e-4 {
+            power = digits::scale(1, digits as i64);
+        }
+        if power <= magnitude {
+            digits += 1;
+        }
+        digits
+    }
+
+    #[inline]
+    fn to_decimal(&self) -> Option<(i128, i32)> {
+        if !self.is_finite() {
+            return None;
+        }
+
+        let (significand, fraction_digits) = digits::shortest_decimal(self.abs());
+        let mantissa = significand as i128;
+        let mantissa = if self.is_sign_negative() {
+            -mantissa
+        } else {
+            mantissa
+        };
+        Some((mantissa, -fraction_digits))
+    }
+
+    #[inline]
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<f64> {
+        let number: f64 = digits::scale(mantissa, exponent as i64);
+        number.is_finite().then_some(number)
     }
 
     #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn tie_at(self, decimal_places: i32) -> bool {
+        outcome::is_tie_f64(self, decimal_places)
     }
-}
 
-impl Roundable for u32 {
+    #[doc(hidden)]
     #[inline]
-    fn round_zeros(self, zeros: u32) -> u32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as u32
+    fn rounding_error(self, rounded: f64) -> f64 {
+        self - rounded
     }
 
+    #[doc(hidden)]
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> u32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as u32
+    fn round_slice_zeros_mode(values: &mut [f64], zeros: u32, mode: RoundingMode) {
+        let scale = scale::ZerosF64::new(zeros);
+        for value in values
+            .iter_mut()
+            .filter(|value| **value != 0.0 && value.is_finite())
+        {
+            *value = scale.round(*value, mode);
+        }
     }
 
+    #[doc(hidden)]
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> u32 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as u32
+    fn nearer_above(self, below: f64, above: f64) -> bool {
+        // Each distance is kept as a float and its rounding error so they compare exactly
+        let (distance_below, error_below) = two_difference(self, below);
+        let (distance_above, error_above) = two_difference(above, self);
+        (distance_above, error_above) < (distance_below, error_below)
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as u32
+    fn checked_round_zeros(self, zeros: u32) -> Option<f64> {
+        let rounded = self.round_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as u32
+    fn checked_ceil_zeros(self, zeros: u32) -> Option<f64> {
+        let rounded = self.ceil_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as u32
+    fn checked_floor_zeros(self, zeros: u32) -> Option<f64> {
+        let rounded = self.floor_zeros(zeros);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn checked_round_sf(self, sig_figs: u32) -> Option<f64> {
+        let rounded = self.round_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
-}
 
-impl Roundable for u64 {
     #[inline]
-    fn round_zeros(self, zeros: u32) -> u64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).round() * power) as u64
+    fn checked_ceil_sf(self, sig_figs: u32) -> Option<f64> {
+        let rounded = self.ceil_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn ceil_zeros(self, zeros: u32) -> u64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).ceil() * power) as u64
+    fn checked_floor_sf(self, sig_figs: u32) -> Option<f64> {
+        let rounded = self.floor_sf(sig_figs);
+        (self.is_finite() && rounded.is_finite()).then_some(rounded)
     }
 
     #[inline]
-    fn floor_zeros(self, zeros: u32) -> u64 {
-        let power = 10_f64.powi(zeros as i32);
-        ((self as f64 / power).floor() * power) as u64
+    fn try_round_zeros(self, zeros: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_round_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn round_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).round() * power) as u64
+    fn try_ceil_zeros(self, zeros: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_ceil_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn ceil_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).ceil() * power) as u64
+    fn try_floor_zeros(self, zeros: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else {
+            self.checked_floor_zeros(zeros)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
     #[inline]
-    fn floor_sf(self, sig_figs: u32) -> Self {
-        let digits: i32 = self.get_digits() as i32;
-        let power = 10_f64.powi(digits - sig_figs as i32);
-        ((self as f64 / power).floor() * power) as u64
+    fn try_round_sf(self, sig_figs: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_round_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
+    }
+
+    #[inline]
+    fn try_ceil_sf(self, sig_figs: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_ceil_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 
-    #[doc(hidden)]
     #[inline]
-    fn get_digits(&self) -> u32 {
-        (*self as f64).abs().log10().ceil() as u32
+    fn try_floor_sf(self, sig_figs: u32) -> Result<f64, RoundingError> {
+        if !self.is_finite() {
+            Err(RoundingError::NonFinite)
+        } else if sig_figs == 0 {
+            Err(RoundingError::ZeroSigFigs)
+        } else {
+            self.checked_floor_sf(sig_figs)
+                .ok_or(RoundingError::Overflow)
+        }
     }
 }
 
+macro_rules! impl_roundable_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Roundable for $t {
+                #[inline]
+                fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> $t {
+                    integer::saturate(self, integer::round_zeros(self, zeros, mode))
+                }
+
+                #[inline]
+                fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> $t {
+                    integer::saturate(self, integer::round_sf(self, sig_figs, mode))
+                }
+
+                #[inline]
+                fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, mut random: R) -> $t {
+                    let below = self.floor_zeros(zeros);
+                    let above = self.ceil_zeros(zeros);
+                    if below == above {
+                        return self;
+                    }
+
+                    let fraction = integer::distance(below, self) as f64
+                        / integer::distance(below, above) as f64;
+                    if random() < fraction {
+                        above
+                    } else {
+                        below
+                    }
+                }
+
+                #[inline]
+                fn round_to_multiple(self, step: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturate(self, integer::round_multiple(self, step, RoundingMode::HalfAwayFromZero))
+                }
+
+                #[inline]
+                fn ceil_to_multiple(self, step: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturate(self, integer::round_multiple(self, step, RoundingMode::Up))
+                }
+
+                #[inline]
+                fn floor_to_multiple(self, step: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturate(self, integer::round_multiple(self, step, RoundingMode::Down))
+                }
+
+                #[inline]
+                fn try_round_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !integer::is_valid_step(step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        integer::round_multiple(self, step, RoundingMode::HalfAwayFromZero)
+                            .ok_or(RoundingError::Overflow)
+                    }
+                }
+
+                #[inline]
+                fn try_ceil_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !integer::is_valid_step(step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        integer::round_multiple(self, step, RoundingMode::Up)
+                            .ok_or(RoundingError::Overflow)
+                    }
+                }
+
+                #[inline]
+                fn try_floor_to_multiple(self, step: $t) -> Result<$t, RoundingError> {
+                    if !integer::is_valid_step(step) {
+                        Err(RoundingError::InvalidStep)
+                    } else {
+                        integer::round_multiple(self, step, RoundingMode::Down)
+                            .ok_or(RoundingError::Overflow)
+                    }
+                }
+
+                #[inline]
+                fn quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturating_quantize(self, step, offset, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturating_quantize(self, step, offset, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn floor_quantize(self, step: $t, offset: $t) -> $t {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::saturating_quantize(self, step, offset, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn checked_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::quantize(self, step, offset, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn checked_ceil_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::quantize(self, step, offset, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn checked_floor_quantize(self, step: $t, offset: $t) -> Option<$t> {
+                    assert!(integer::is_valid_step(step), "step must be positive");
+                    integer::quantize(self, step, offset, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn round_pow10(self) -> $t {
+                    if self == 0 {
+                        return self;
+                    }
+
+                    integer::saturate(self, integer::power_of_ten(self, RoundingMode::HalfAwayFromZero))
+                }
+
+                #[inline]
+                fn ceil_pow10(self) -> $t {
+                    if self == 0 {
+                        return self;
+                    }
+
+                    integer::saturate(self, integer::power_of_ten(self, RoundingMode::AwayFromZero))
+                }
+
+                #[inline]
+                fn floor_pow10(self) -> $t {
+                    if self == 0 {
+                        return self;
+                    }
+
+                    integer::saturate(self, integer::power_of_ten(self, RoundingMode::TowardZero))
+                }
+
+                #[inline]
+                fn checked_round_pow10(self) -> Option<$t> {
+                    integer::power_of_ten(self, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn checked_ceil_pow10(self) -> Option<$t> {
+                    integer::power_of_ten(self, RoundingMode::AwayFromZero)
+                }
+
+                #[inline]
+                fn checked_floor_pow10(self) -> Option<$t> {
+                    integer::power_of_ten(self, RoundingMode::TowardZero)
+                }
+
+                #[inline]
+                fn order_of_magnitude(self) -> Option<i32> {
+                    (self != 0).then(|| self.get_digits() - 1)
+                }
+
+                #[inline]
+                fn count_sig_figs(self) -> u32 {
+                    integer::sig_figs(self)
+                }
+
+                #[inline]
+                fn digit_count(self) -> u32 {
+                    integer::digit_count(self)
+                }
+
+                #[inline]
+                fn digits(self) -> Digits {
+                    integer::digits(self)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_sf(self, sig_figs: u32) -> String {
+                    self.format_sf_notation(sig_figs, Notation::Fixed)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+                    let (negative, significand, zeros) = if sig_figs == 0 {
+                        (false, 0, 0)
+                    } else {
+                        integer::round_sf_magnitude(self, sig_figs, RoundingMode::HalfAwayFromZero)
+                    };
+                    format::significant(significand, -(zeros as i32), negative, sig_figs, notation)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_grouped(
+                    self,
+                    decimal_places: u32,
+                    group_separator: char,
+                    decimal_separator: char,
+                ) -> String {
+                    self.format_locale(decimal_places, decimal_separator, Some(group_separator))
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_locale(
+                    self,
+                    decimal_places: u32,
+                    decimal_separator: char,
+                    group_separator: Option<char>,
+                ) -> String {
+                    let fixed = if decimal_places == 0 {
+                        self.to_string()
+                    } else {
+                        alloc::format!("{self}.{:0<width$}", "", width = decimal_places as usize)
+                    };
+                    format::group(&fixed, decimal_separator, group_separator)
+                }
+
+                #[inline]
+                fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<$t> {
+                    match u32::try_from(exponent) {
+                        Ok(zeros) => integer::round_zeros(self, zeros, mode),
+                        Err(_) => Some(self),
+                    }
+                }
+
+                #[inline]
+                fn get_digits(&self) -> i32 {
+                    if *self == 0 {
+                        0
+                    } else {
+                        integer::digit_count(*self) as i32
+                    }
+                }
+
+                #[inline]
+                fn to_decimal(&self) -> Option<(i128, i32)> {
+                    i128::try_from(*self).ok().map(|mantissa| (mantissa, 0))
+                }
+
+                #[inline]
+                fn from_decimal(mantissa: i128, exponent: i32) -> Option<$t> {
+                    fallback::integer_value(mantissa, exponent).and_then(|value| <$t>::try_from(value).ok())
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn tie_at(self, decimal_places: i32) -> bool {
+                    decimal_places < 0 && integer::is_tie(self, decimal_places.unsigned_abs())
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn rounding_error(self, rounded: $t) -> $t {
+                    self.wrapping_sub(rounded)
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn round_slice_zeros_mode(values: &mut [$t], zeros: u32, mode: RoundingMode) {
+                    for value in values {
+                        *value = value.round_zeros_mode(zeros, mode);
+                    }
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn nearer_above(self, below: $t, above: $t) -> bool {
+                    integer::distance(self, above) < integer::distance(below, self)
+                }
+            }
+        )*
+    };
+}
+
+impl_roundable_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 mod tests;
@@ -0,0 +1,146 @@
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+
+/// The rule used to choose between the two neighbouring values when rounding
+///
+/// The `Half*` modes round to the nearest value and only differ in how exact ties are broken,
+/// the remaining modes always round in a fixed direction
+///
+/// ```rust
+/// use common_math::rounding::RoundingMode;
+///
+/// assert_eq!(RoundingMode::default(), RoundingMode::HalfAwayFromZero);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RoundingMode {
+    /// Ties round towards positive infinity
+    HalfUp,
+    /// Ties round towards negative infinity
+    HalfDown,
+    /// Ties round to the neighbour whose last digit is even, also known as banker's rounding
+    HalfEven,
+    /// Ties round to the neighbour whose last digit is odd
+    HalfOdd,
+    /// Ties round away from zero, the rule used by [`f64::round`]
+    #[default]
+    HalfAwayFromZero,
+    /// Ties round towards zero
+    HalfTowardZero,
+    /// Always rounds towards positive infinity, the rule used by [`f64::ceil`]
+    Up,
+    /// Always rounds towards negative infinity, the rule used by [`f64::floor`]
+    Down,
+    /// Always rounds towards zero, the rule used by [`f64::trunc`]
+    TowardZero,
+    /// Always rounds away from zero
+    AwayFromZero,
+}
+
+/// The direction a number is moved in when rounding, for choosing between
+/// [`round`](super::round), [`ceil`](super::ceil), [`floor`](super::floor),
+/// [`trunc`](super::trunc) and [`away`](super::away) at runtime
+///
+/// ```rust
+/// use common_math::rounding::{round_dir, Direction};
+///
+/// assert_eq!(round_dir(-1.25_f64, 1, Direction::TowardZero), -1.2);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Rounds to the nearest value with ties away from zero, like [`round`](super::round)
+    #[default]
+    Nearest,
+    /// Rounds towards positive infinity, like [`ceil`](super::ceil)
+    Up,
+    /// Rounds towards negative infinity, like [`floor`](super::floor)
+    Down,
+    /// Rounds towards zero, like [`trunc`](super::trunc)
+    TowardZero,
+    /// Rounds away from zero, like [`away`](super::away)
+    AwayFromZero,
+}
+
+impl RoundingMode {
+    /// Decides whether a value that has been truncated towards zero should move one step away from zero
+    ///
+    /// `odd` is whether the truncated value ends in an odd digit, `exact` is whether nothing was
+    /// discarded and `discarded` compares the discarded part with half a step
+    #[inline]
+    pub(crate) const fn rounds_away(
+        self,
+        negative: bool,
+        odd: bool,
+        exact: bool,
+        discarded: Ordering,
+    ) -> bool {
+        if exact {
+            return false;
+        }
+
+        // Matched rather than compared with `==` so it can be used in const functions
+        let above = matches!(discarded, Ordering::Greater);
+        let tie = matches!(discarded, Ordering::Equal);
+        match self {
+            RoundingMode::HalfUp => above || (tie && !negative),
+            RoundingMode::HalfDown => above || (tie && negative),
+            RoundingMode::HalfEven => above || (tie && odd),
+            RoundingMode::HalfOdd => above || (tie && !odd),
+            RoundingMode::HalfAwayFromZero => above || tie,
+            RoundingMode::HalfTowardZero => above,
+            RoundingMode::Up => !negative,
+            RoundingMode::Down => negative,
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+        }
+    }
+
+    /// Rounds a float to an integer
+    #[inline]
+    pub(crate) fn round_f64(self, number: f64) -> f64 {
+        match self {
+            RoundingMode::HalfAwayFromZero => number.round(),
+            RoundingMode::Up => number.ceil(),
+            RoundingMode::Down => number.floor(),
+            RoundingMode::TowardZero => number.trunc(),
+            _ => {
+                let magnitude = number.abs();
+                let truncated = magnitude.trunc();
+                let discarded = magnitude - truncated;
+                let away = self.rounds_away(
+                    number.is_sign_negative(),
+                    truncated % 2.0 == 1.0,
+                    discarded == 0.0,
+                    discarded.total_cmp(&0.5),
+                );
+                let rounded = if away { truncated + 1.0 } else { truncated };
+                rounded.copysign(number)
+            }
+        }
+    }
+
+    /// Rounds a float to an integer
+    #[inline]
+    pub(crate) fn round_f32(self, number: f32) -> f32 {
+        match self {
+            RoundingMode::HalfAwayFromZero => number.round(),
+            RoundingMode::Up => number.ceil(),
+            RoundingMode::Down => number.floor(),
+            RoundingMode::TowardZero => number.trunc(),
+            _ => {
+                let magnitude = number.abs();
+                let truncated = magnitude.trunc();
+                let discarded = magnitude - truncated;
+                let away = self.rounds_away(
+                    number.is_sign_negative(),
+                    truncated % 2.0 == 1.0,
+                    discarded == 0.0,
+                    discarded.total_cmp(&0.5),
+                );
+                let rounded = if away { truncated + 1.0 } else { truncated };
+                rounded.copysign(number)
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{digits, powers, scale, RoundingMode};
+
+/// Whether a float can be used as the step to round to a multiple of
+#[inline]
+pub(crate) fn is_valid_step_f64(step: f64) -> bool {
+    step > 0.0 && step.is_finite()
+}
+
+/// Whether a float can be used as the step to round to a multiple of
+#[inline]
+pub(crate) fn is_valid_step_f32(step: f32) -> bool {
+    step > 0.0 && step.is_finite()
+}
+
+/// Every f64 at least this large is an integer
+const MIN_INTEGRAL_F64: f64 = 4_503_599_627_370_496.0;
+
+/// Every f32 at least this large is an integer
+const MIN_INTEGRAL_F32: f32 = 8_388_608.0;
+
+/// Counts of steps below this are multiplied exactly from the digits of the grid
+const MAX_EXACT_STEPS: f64 = 9_007_199_254_740_992.0;
+
+/// The points `offset + k * step` for every integer `k`, stored as the shortest decimal digits of
+/// the step and offset so a point like `3 * 0.1` is the float closest to the decimal `0.3`
+/// instead of collecting the error in `0.1`
+struct Grid {
+    step: f64,
+    offset: f64,
+    /// The step and offset as integers scaled by `10^fraction_digits`, `None` if they don't fit
+    digits: Option<(i128, i128)>,
+    fraction_digits: i32,
+}
+
+impl Grid {
+    #[inline]
+    fn new<T: core::fmt::LowerExp>(step: T, offset: T, step_f64: f64, offset_f64: f64) -> Grid {
+        let (step_digits, step_fraction) = digits::shortest_decimal(step);
+        let (offset_digits, offset_fraction) = digits::shortest_decimal(offset);
+        let fraction_digits = if offset_f64 == 0.0 {
+            step_fraction
+        } else {
+            step_fraction.max(offset_fraction)
+        };
+
+        let widen = |digits: u64, fraction: i32| {
+            // Every power of ten that fits in a u128 also fits in an i128
+            powers::ten_u128((fraction_digits - fraction) as u32)
+                .and_then(|power| (power as i128).checked_mul(digits as i128))
+        };
+        let offset_digits = if offset_f64 == 0.0 {
+            Some(0)
+        } else {
+            widen(offset_digits, offset_fraction)
+        };
+        let digits = widen(step_digits, step_fraction)
+            .zip(offset_digits)
+            .map(|(step, offset)| (step, if offset_f64 < 0.0 { -offset } else { offset }));
+
+        Grid {
+            step: step_f64,
+            offset: offset_f64,
+            digits,
+            fraction_digits,
+        }
+    }
+
+    /// The point `steps` steps from the offset as a decimal integer and a power of ten,
+    /// `None` if it doesn't fit in an i64
+    #[inline]
+    fn decimal_point(&self, steps: f64) -> Option<(i64, i64)> {
+        let (step, offset) = self.digits?;
+        if steps.abs() >= MAX_EXACT_STEPS {
+            return None;
+        }
+
+        let point = (steps as i128).checked_mul(step)?.checked_add(offset)?;
+        Some((i64::try_from(point).ok()?, -(self.fraction_digits as i64)))
+    }
+
+    #[inline]
+    fn point_f64(&self, steps: f64) -> f64 {
+        self.decimal_point(steps)
+            .map_or(steps * self.step + self.offset, |(digits, exponent)| {
+                digits::scale(digits, exponent)
+            })
+    }
+
+    #[inline]
+    fn point_f32(&self, steps: f64) -> f32 {
+        self.decimal_point(steps).map_or(
+            (steps * self.step + self.offset) as f32,
+            |(digits, exponent)| digits::scale(digits, exponent),
+        )
+    }
+}
+
+/// Rounds the number of steps from the offset to a number and finds the point it lands on,
+/// moving a directed result back onto the right side of the number when the division
+/// rounded the count of steps onto the integer past it
+#[inline]
+fn round_steps<T: PartialOrd + Copy>(
+    number: T,
+    steps: f64,
+    mode: RoundingMode,
+    point: impl Fn(f64) -> T,
+) -> T {
+    // A number that is already the closest float to a point is returned as is
+    if point(steps.round()) == number {
+        return number;
+    }
+
+    let rounded = mode.round_f64(steps);
+    let result = point(rounded);
+    let below = match mode {
+        RoundingMode::Up => false,
+        RoundingMode::Down => true,
+        RoundingMode::TowardZero => steps > 0.0,
+        RoundingMode::AwayFromZero => steps < 0.0,
+        _ => return result,
+    };
+
+    if below && result > number {
+        point(rounded - 1.0)
+    } else if !below && result < number {
+        point(rounded + 1.0)
+    } else {
+        result
+    }
+}
+
+/// Rounds a finite f64 to a multiple of a positive, finite step
+#[inline]
+pub(crate) fn multiple_f64(number: f64, step: f64, mode: RoundingMode) -> f64 {
+    quantize_f64(number, step, 0.0, mode).copysign(number)
+}
+
+/// Rounds a finite f32 to a multiple of a positive, finite step
+#[inline]
+pub(crate) fn multiple_f32(number: f32, step: f32, mode: RoundingMode) -> f32 {
+    quantize_f32(number, step, 0.0, mode).copysign(number)
+}
+
+/// Rounds a finite f64 to a point on a grid of a positive, finite step shifted by an offset
+#[inline]
+pub(crate) fn quantize_f64(number: f64, step: f64, offset: f64, mode: RoundingMode) -> f64 {
+    let steps = (number - offset) / step;
+    if !steps.is_finite() {
+        return steps;
+    }
+
+    let grid = Grid::new(step, offset, step, offset);
+    round_steps(number, steps, mode, |steps| grid.point_f64(steps))
+}
+
+/// Rounds a finite f32 to a point on a grid of a positive, finite step shifted by an offset
+///
+/// The count of steps is found in an f64, where it has enough precision to round the right way
+#[inline]
+pub(crate) fn quantize_f32(number: f32, step: f32, offset: f32, mode: RoundingMode) -> f32 {
+    let steps = (number as f64 - offset as f64) / step as f64;
+    let grid = Grid::new(step, offset, step as f64, offset as f64);
+    round_steps(number, steps, mode, |steps| grid.point_f32(steps))
+}
+
+/// Counts the whole steps from the offset to a finite f64 on a grid of a positive, finite step,
+/// rounding down, with a number that is the closest float to a point counted as on it
+#[inline]
+pub(crate) fn steps_below_f64(number: f64, step: f64, offset: f64) -> f64 {
+    let steps = (number - offset) / step;
+    if !steps.is_finite() {
+        return steps;
+    }
+
+    let grid = Grid::new(step, offset, step, offset);
+    let nearest = steps.round();
+    if grid.point_f64(nearest) == number {
+        return nearest;
+    }
+
+    // The division can round the count onto the integer either side of the right one
+    let below = steps.floor();
+    if grid.point_f64(below) > number {
+        below - 1.0
+    } else if grid.point_f64(below + 1.0) <= number {
+        below + 1.0
+    } else {
+        below
+    }
+}
+
+/// The points a whole number of steps from the offset on a grid of a positive, finite step and
+/// one step further
+#[inline]
+pub(crate) fn step_bounds_f64(steps: f64, step: f64, offset: f64) -> (f64, f64) {
+    let grid = Grid::new(step, offset, step, offset);
+    (grid.point_f64(steps), grid.point_f64(steps + 1.0))
+}
+
+/// Rounds a finite f64 to a multiple of `1 / denominator` for a non zero denominator
+///
+/// A power of ten denominator rounds to decimal places, so `1 / 100` gives the same result as
+/// rounding to 2 decimal places
+#[inline]
+pub(crate) fn fraction_f64(number: f64, denominator: u32, mode: RoundingMode) -> f64 {
+    // Large floats are integers, which are already a multiple of every fraction
+    if number.abs() >= MIN_INTEGRAL_F64 {
+        return number;
+    }
+
+    match decimal_places(denominator) {
+        Some(decimal_places) => scale::decimal_places_f64(number, decimal_places, mode),
+        None => fraction(number, denominator, mode, |closest| closest),
+    }
+}
+
+/// Rounds a finite f32 to a multiple of `1 / denominator` for a non zero denominator like
+/// [`fraction_f64`], with the multiples and halfway points compared as f32s
+#[inline]
+pub(crate) fn fraction_f32(number: f32, denominator: u32, mode: RoundingMode) -> f32 {
+    if number.abs() >= MIN_INTEGRAL_F32 {
+        return number;
+    }
+
+    match decimal_places(denominator) {
+        Some(decimal_places) => scale::decimal_places_f32(number, decimal_places, mode),
+        None => fraction(number as f64, denominator, mode, |closest| {
+            closest as f32 as f64
+        }) as f32,
+    }
+}
+
+/// The number of decimal places a power of ten denominator rounds to
+#[inline]
+fn decimal_places(denominator: u32) -> Option<u32> {
+    let exponent = denominator.checked_ilog10()?;
+    (powers::ten_u128(exponent) == Some(denominator as u128)).then_some(exponent)
+}
+
+/// Rounds a number to a multiple of `1 / denominator`, where `narrow` gives the closest number
+/// of the type being rounded to an f64
+///
+/// The product with the denominator is split into a float and the rounding error of the
+/// multiplication, so the count of fractions is rounded from the exact product. Dividing it back
+/// gives the closest float to the fraction while the count fits in 53 bits.
+/// Like rounding to decimal places, a number that is the closest float to a multiple is already
+/// on it and one that is the closest float to a point halfway between two multiples is a tie,
+/// so `0.7` is a multiple of `1 / 10` although its exact value is just below `7 / 10`
+#[inline]
+fn fraction<N: Fn(f64) -> f64>(
+    number: f64,
+    denominator: u32,
+    mode: RoundingMode,
+    narrow: N,
+) -> f64 {
+    let denominator = denominator as f64;
+    let product = number * denominator;
+    let error = number.mul_add(denominator, -product);
+
+    // The exact product as a whole part and a fraction in [0, 1), compared with a half
+    let (whole, discarded, exact) = if product == product.floor() {
+        let fraction = error - error.floor();
+        let whole = product as i128 + error.floor() as i128;
+        (whole, fraction.total_cmp(&0.5), fraction == 0.0)
+    } else {
+        let fraction = product - product.floor();
+        let discarded = fraction.total_cmp(&0.5).then(error.total_cmp(&0.0));
+        (product.floor() as i128, discarded, false)
+    };
+
+    let closest = |count: i128, denominator: f64| narrow(count as f64 / denominator);
+    if closest(whole, denominator) == number || closest(whole + 1, denominator) == number {
+        return number;
+    }
+    let discarded = if closest(2 * whole + 1, 2.0 * denominator) == number {
+        Ordering::Equal
+    } else {
+        discarded
+    };
+
+    // Switched to a magnitude and the part discarded by truncating it towards zero
+    let negative = whole < 0;
+    let (truncated, discarded) = if negative && !exact {
+        (whole.unsigned_abs() - 1, discarded.reverse())
+    } else {
+        (whole.unsigned_abs(), discarded)
+    };
+
+    let away = mode.rounds_away(negative, truncated % 2 == 1, exact, discarded);
+    let count = (truncated + away as u128) as f64;
+    (count / denominator).copysign(number)
+}
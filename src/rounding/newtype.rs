@@ -0,0 +1,687 @@
+//! Macros that implement the rounding traits for newtypes by delegating to the wrapped number
+
+/// Paths used by the exported macros, so they expand without imports at the call site
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::string::String;
+}
+
+/// Implements [`Roundable`](crate::rounding::Roundable) for a newtype by rounding the number it wraps
+///
+/// Takes a tuple struct and the type it wraps as `Name => Inner`, or a struct with a single named
+/// field as `Name { field: Inner }`. Every method rounds the wrapped number and wraps the result
+/// again, and the generated code uses full paths so nothing needs to be imported where it is called
+///
+/// # Examples
+///
+/// ```
+/// use common_math::impl_roundable;
+/// use common_math::rounding::Roundable;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(f64);
+/// impl_roundable!(Meters => f64);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Price {
+///     amount: i64,
+/// }
+/// impl_roundable!(Price { amount: i64 });
+///
+/// assert_eq!(Meters(1234.5).round_sf(2), Meters(1200.0));
+/// assert_eq!(Price { amount: 1_250 }.round_zeros(2), Price { amount: 1_300 });
+/// ```
+#[macro_export]
+macro_rules! impl_roundable {
+    ($name:ident => $inner:ty $(,)?) => {
+        $crate::impl_roundable!(@impl $name, 0, $inner);
+    };
+    ($name:ident { $field:ident : $inner:ty $(,)? }) => {
+        $crate::impl_roundable!(@impl $name, $field, $inner);
+    };
+    (@impl $name:ident, $field:tt, $inner:ty) => {
+        impl $crate::rounding::Roundable for $name {
+            #[inline]
+            fn round_decimals(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_decimals(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn ceil_decimals(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_decimals(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn floor_decimals(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_decimals(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn ceil_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn floor_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn trunc_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::trunc_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn away_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::away_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn round_zeros_mode(self, zeros: u32, mode: $crate::rounding::RoundingMode) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_mode(self.$field, zeros, mode) }
+            }
+
+            #[inline]
+            fn round_zeros_half_odd(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_half_odd(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn round_zeros_half_away(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_half_away(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn round_zeros_half_down(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_half_down(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn round_zeros_even(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_even(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn round_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn ceil_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn floor_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn trunc_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::trunc_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn away_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::away_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn round_sf_mode(self, sig_figs: u32, mode: $crate::rounding::RoundingMode) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf_mode(self.$field, sig_figs, mode) }
+            }
+
+            #[inline]
+            fn round_sf_half_odd(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf_half_odd(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn round_sf_half_away(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf_half_away(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn round_sf_half_down(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf_half_down(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn round_sf_even(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_sf_even(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn checked_round_zeros(self, zeros: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_round_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_ceil_zeros(self, zeros: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_ceil_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_floor_zeros(self, zeros: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_floor_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_round_sf(self, sig_figs: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_round_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_ceil_sf(self, sig_figs: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_ceil_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_floor_sf(self, sig_figs: u32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_floor_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn saturating_round_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_round_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn saturating_ceil_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_ceil_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn saturating_floor_zeros(self, zeros: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_floor_zeros(self.$field, zeros) }
+            }
+
+            #[inline]
+            fn saturating_round_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_round_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn saturating_ceil_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_ceil_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn saturating_floor_sf(self, sig_figs: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::saturating_floor_sf(self.$field, sig_figs) }
+            }
+
+            #[inline]
+            fn try_round_zeros(self, zeros: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_round_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_ceil_zeros(self, zeros: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_ceil_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_floor_zeros(self, zeros: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_floor_zeros(self.$field, zeros).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_round_sf(self, sig_figs: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_round_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_ceil_sf(self, sig_figs: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_ceil_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_floor_sf(self, sig_figs: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_floor_sf(self.$field, sig_figs).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn round_zeros_stochastic<R: ::core::ops::FnMut() -> f64>(self, zeros: u32, random: R) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_zeros_stochastic(self.$field, zeros, random) }
+            }
+
+            #[inline]
+            fn round_to_multiple(self, step: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_to_multiple(self.$field, step.$field) }
+            }
+
+            #[inline]
+            fn ceil_to_multiple(self, step: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_to_multiple(self.$field, step.$field) }
+            }
+
+            #[inline]
+            fn floor_to_multiple(self, step: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_to_multiple(self.$field, step.$field) }
+            }
+
+            #[inline]
+            fn try_round_to_multiple(self, step: Self) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_round_to_multiple(self.$field, step.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_ceil_to_multiple(self, step: Self) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_ceil_to_multiple(self.$field, step.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_floor_to_multiple(self, step: Self) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Roundable>::try_floor_to_multiple(self.$field, step.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn quantize(self, step: Self, offset: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::quantize(self.$field, step.$field, offset.$field) }
+            }
+
+            #[inline]
+            fn ceil_quantize(self, step: Self, offset: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_quantize(self.$field, step.$field, offset.$field) }
+            }
+
+            #[inline]
+            fn floor_quantize(self, step: Self, offset: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_quantize(self.$field, step.$field, offset.$field) }
+            }
+
+            #[inline]
+            fn checked_quantize(self, step: Self, offset: Self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_quantize(self.$field, step.$field, offset.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_ceil_quantize(self, step: Self, offset: Self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_ceil_quantize(self.$field, step.$field, offset.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_floor_quantize(self, step: Self, offset: Self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_floor_quantize(self.$field, step.$field, offset.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn round_pow10(self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::round_pow10(self.$field) }
+            }
+
+            #[inline]
+            fn ceil_pow10(self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::ceil_pow10(self.$field) }
+            }
+
+            #[inline]
+            fn floor_pow10(self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::floor_pow10(self.$field) }
+            }
+
+            #[inline]
+            fn checked_round_pow10(self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_round_pow10(self.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_ceil_pow10(self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_ceil_pow10(self.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn checked_floor_pow10(self) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::checked_floor_pow10(self.$field).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn order_of_magnitude(self) -> ::core::option::Option<i32> {
+                <$inner as $crate::rounding::Roundable>::order_of_magnitude(self.$field)
+            }
+
+            #[inline]
+            fn count_sig_figs(self) -> u32 {
+                <$inner as $crate::rounding::Roundable>::count_sig_figs(self.$field)
+            }
+
+            #[inline]
+            fn digit_count(self) -> u32 {
+                <$inner as $crate::rounding::Roundable>::digit_count(self.$field)
+            }
+
+            #[inline]
+            fn digits(self) -> $crate::rounding::Digits {
+                <$inner as $crate::rounding::Roundable>::digits(self.$field)
+            }
+
+            #[inline]
+            fn round_to_exponent(self, exponent: i32, mode: $crate::rounding::RoundingMode) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::round_to_exponent(self.$field, exponent, mode).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn get_digits(&self) -> i32 {
+                <$inner as $crate::rounding::Roundable>::get_digits(&self.$field)
+            }
+
+            #[inline]
+            fn to_decimal(&self) -> ::core::option::Option<(i128, i32)> {
+                <$inner as $crate::rounding::Roundable>::to_decimal(&self.$field)
+            }
+
+            #[inline]
+            fn from_decimal(mantissa: i128, exponent: i32) -> ::core::option::Option<Self> {
+                <$inner as $crate::rounding::Roundable>::from_decimal(mantissa, exponent).map(|value| $name { $field: value })
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn nearer_above(self, below: Self, above: Self) -> bool {
+                <$inner as $crate::rounding::Roundable>::nearer_above(self.$field, below.$field, above.$field)
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn tie_at(self, decimal_places: i32) -> bool {
+                <$inner as $crate::rounding::Roundable>::tie_at(self.$field, decimal_places)
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn rounding_error(self, rounded: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Roundable>::rounding_error(self.$field, rounded.$field) }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn round_slice_zeros_mode(values: &mut [Self], zeros: u32, mode: $crate::rounding::RoundingMode) {
+                for value in values {
+                    let inner = ::core::clone::Clone::clone(&value.$field);
+                    value.$field = <$inner as $crate::rounding::Roundable>::round_zeros_mode(inner, zeros, mode);
+                }
+            }
+
+            $crate::__impl_roundable_alloc!($name, $field, $inner);
+        }
+    };
+}
+
+/// Implements [`Float`](crate::rounding::Float) for a newtype by rounding the float it wraps
+///
+/// Takes the newtype like [`impl_roundable!`], as `Name => Inner` for a tuple struct or
+/// `Name { field: Inner }` for a struct with a single named field. `Float` extends `Roundable`, so
+/// the newtype needs [`impl_roundable!`] as well
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{Float, Roundable};
+/// use common_math::{impl_float, impl_roundable};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(f64);
+/// impl_roundable!(Meters => f64);
+/// impl_float!(Meters => f64);
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Celsius {
+///     degrees: f32,
+/// }
+/// impl_roundable!(Celsius { degrees: f32 });
+/// impl_float!(Celsius { degrees: f32 });
+///
+/// assert_eq!(Meters(2.345).round_dp(2), Meters(2.35));
+/// assert_eq!(Celsius { degrees: 21.37 }.floor_frac(2), Celsius { degrees: 21.0 });
+/// ```
+#[macro_export]
+macro_rules! impl_float {
+    ($name:ident => $inner:ty $(,)?) => {
+        $crate::impl_float!(@impl $name, 0, $inner);
+    };
+    ($name:ident { $field:ident : $inner:ty $(,)? }) => {
+        $crate::impl_float!(@impl $name, $field, $inner);
+    };
+    (@impl $name:ident, $field:tt, $inner:ty) => {
+        impl $crate::rounding::Float for $name {
+            #[inline]
+            fn trunc_dp(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::trunc_dp(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn away_dp(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::away_dp(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_dp_mode(self, decimal_places: u32, mode: $crate::rounding::RoundingMode) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_mode(self.$field, decimal_places, mode) }
+            }
+
+            #[inline]
+            fn round_dp_half_odd(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_half_odd(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_dp_half_away(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_half_away(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_dp_half_down(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_half_down(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_dp_exact(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_exact(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn ceil_dp_exact(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::ceil_dp_exact(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn floor_dp_exact(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::floor_dp_exact(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_dp_even(self, decimal_places: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_even(self.$field, decimal_places) }
+            }
+
+            #[inline]
+            fn round_to_match(self, reference: Self) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_to_match(self.$field, reference.$field) }
+            }
+
+            #[inline]
+            fn round_pair_sf(self, uncertainty: Self, sig_figs: u32) -> (Self, Self) {
+                let (value, uncertainty) = <$inner as $crate::rounding::Float>::round_pair_sf(self.$field, uncertainty.$field, sig_figs);
+                ($name { $field: value }, $name { $field: uncertainty })
+            }
+
+            #[inline]
+            fn round_dp_stochastic<R: ::core::ops::FnMut() -> f64>(self, decimal_places: u32, random: R) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_dp_stochastic(self.$field, decimal_places, random) }
+            }
+
+            #[inline]
+            fn round_frac(self, denominator: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_frac(self.$field, denominator) }
+            }
+
+            #[inline]
+            fn ceil_frac(self, denominator: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::ceil_frac(self.$field, denominator) }
+            }
+
+            #[inline]
+            fn floor_frac(self, denominator: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::floor_frac(self.$field, denominator) }
+            }
+
+            #[inline]
+            fn try_round_frac(self, denominator: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Float>::try_round_frac(self.$field, denominator).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_ceil_frac(self, denominator: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Float>::try_ceil_frac(self.$field, denominator).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn try_floor_frac(self, denominator: u32) -> ::core::result::Result<Self, $crate::rounding::RoundingError> {
+                <$inner as $crate::rounding::Float>::try_floor_frac(self.$field, denominator).map(|value| $name { $field: value })
+            }
+
+            #[inline]
+            fn round_bits(self, bits: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::round_bits(self.$field, bits) }
+            }
+
+            #[inline]
+            fn trunc_bits(self, bits: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::trunc_bits(self.$field, bits) }
+            }
+
+            #[inline]
+            fn ceil_bits(self, bits: u32) -> Self {
+                $name { $field: <$inner as $crate::rounding::Float>::ceil_bits(self.$field, bits) }
+            }
+
+            #[inline]
+            fn count_decimal_places(self) -> u32 {
+                <$inner as $crate::rounding::Float>::count_decimal_places(self.$field)
+            }
+
+            #[inline]
+            fn needs_rounding(self, decimal_places: u32) -> bool {
+                <$inner as $crate::rounding::Float>::needs_rounding(self.$field, decimal_places)
+            }
+
+            #[inline]
+            fn to_scientific(self, sig_figs: u32) -> (Self, i32) {
+                let (mantissa, exponent) = <$inner as $crate::rounding::Float>::to_scientific(self.$field, sig_figs);
+                ($name { $field: mantissa }, exponent)
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn round_slice_dp_mode(values: &mut [Self], decimal_places: u32, mode: $crate::rounding::RoundingMode) {
+                for value in values {
+                    let inner = ::core::clone::Clone::clone(&value.$field);
+                    value.$field = <$inner as $crate::rounding::Float>::round_dp_mode(inner, decimal_places, mode);
+                }
+            }
+
+            #[doc(hidden)]
+            #[inline]
+            fn round_slice_dp_simd(values: &mut [Self], decimal_places: u32) {
+                for value in values {
+                    let inner = ::core::clone::Clone::clone(&value.$field);
+                    value.$field = <$inner as $crate::rounding::Float>::round_dp(inner, decimal_places);
+                }
+            }
+
+            $crate::__impl_float_alloc!($name, $field, $inner);
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_roundable_alloc {
+    ($name:ident, $field:tt, $inner:ty) => {
+        #[inline]
+        fn format_sf(self, sig_figs: u32) -> $crate::rounding::__private::String {
+            <$inner as $crate::rounding::Roundable>::format_sf(self.$field, sig_figs)
+        }
+
+        #[inline]
+        fn format_sf_notation(
+            self,
+            sig_figs: u32,
+            notation: $crate::rounding::Notation,
+        ) -> $crate::rounding::__private::String {
+            <$inner as $crate::rounding::Roundable>::format_sf_notation(
+                self.$field,
+                sig_figs,
+                notation,
+            )
+        }
+
+        #[inline]
+        fn format_grouped(
+            self,
+            decimal_places: u32,
+            group_separator: char,
+            decimal_separator: char,
+        ) -> $crate::rounding::__private::String {
+            <$inner as $crate::rounding::Roundable>::format_grouped(
+                self.$field,
+                decimal_places,
+                group_separator,
+                decimal_separator,
+            )
+        }
+
+        #[inline]
+        fn format_locale(
+            self,
+            decimal_places: u32,
+            decimal_separator: char,
+            group_separator: ::core::option::Option<char>,
+        ) -> $crate::rounding::__private::String {
+            <$inner as $crate::rounding::Roundable>::format_locale(
+                self.$field,
+                decimal_places,
+                decimal_separator,
+                group_separator,
+            )
+        }
+    };
+}
+
+/// Expands to nothing, as the formatting methods only exist with the `alloc` feature
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_roundable_alloc {
+    ($($tokens:tt)*) => {};
+}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_float_alloc {
+    ($name:ident, $field:tt, $inner:ty) => {
+        #[inline]
+        fn format_dp(self, decimal_places: u32) -> $crate::rounding::__private::String {
+            <$inner as $crate::rounding::Float>::format_dp(self.$field, decimal_places)
+        }
+    };
+}
+
+/// Expands to nothing, as the formatting methods only exist with the `alloc` feature
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_float_alloc {
+    ($($tokens:tt)*) => {};
+}
+
+mod tests;
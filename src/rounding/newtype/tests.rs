@@ -0,0 +1,98 @@
+#[cfg(test)]
+use crate::rounding::{Float, Roundable, RoundingError, RoundingMode};
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Meters(f64);
+
+#[cfg(test)]
+crate::impl_roundable!(Meters => f64);
+
+#[cfg(test)]
+crate::impl_float!(Meters => f64);
+
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cents {
+    amount: i64,
+}
+
+#[cfg(test)]
+crate::impl_roundable!(Cents { amount: i64 });
+
+#[test]
+fn test_tuple_newtype() {
+    assert_eq!(Meters(2.675).round_dp(2), Meters(2.68));
+    assert_eq!(Meters(-2.671).floor_dp(2), Meters(-2.68));
+    assert_eq!(Meters(1234.5).round_sf(2), Meters(1200.0));
+    assert_eq!(
+        Meters(1234.5).round_zeros_mode(2, RoundingMode::Up),
+        Meters(1300.0)
+    );
+    assert_eq!(Meters(1.3).round_to_multiple(Meters(0.5)), Meters(1.5));
+    assert_eq!(
+        Meters(1.3).try_round_frac(0),
+        Err(RoundingError::ZeroDenominator)
+    );
+    assert_eq!(Meters(f64::NAN).checked_round_sf(2), None);
+    assert_eq!(
+        Meters(12.3456).round_pair_sf(Meters(0.0996), 2),
+        (Meters(12.35), Meters(0.1))
+    );
+    assert_eq!(Meters(0.00345).to_scientific(2), (Meters(3.5), -3));
+    assert_eq!(Meters(123.45).order_of_magnitude(), Some(2));
+    assert_eq!(Meters(0.125).count_decimal_places(), 3);
+
+    let mut values = [Meters(1.25), Meters(-2.35)];
+    Meters::round_slice_dp_mode(&mut values, 1, RoundingMode::HalfEven);
+    assert_eq!(values, [Meters(1.2), Meters(-2.4)]);
+}
+
+#[test]
+fn test_named_newtype() {
+    assert_eq!(
+        Cents { amount: 1_250 }.round_zeros(2),
+        Cents { amount: 1_300 }
+    );
+    assert_eq!(
+        Cents { amount: -1_250 }.ceil_zeros(2),
+        Cents { amount: -1_200 }
+    );
+    assert_eq!(
+        Cents { amount: 98_765 }.round_sf(2),
+        Cents { amount: 99_000 }
+    );
+    assert_eq!(Cents { amount: i64::MAX }.checked_round_zeros(1), None);
+    assert_eq!(
+        Cents { amount: i64::MAX }.round_zeros(1),
+        Cents { amount: i64::MAX }
+    );
+    assert_eq!(
+        Cents { amount: 17 }.quantize(Cents { amount: 5 }, Cents { amount: 1 }),
+        Cents { amount: 16 }
+    );
+    assert_eq!(
+        Cents { amount: 0 }.try_round_sf(0),
+        Err(RoundingError::ZeroSigFigs)
+    );
+    assert_eq!(Cents { amount: 1500 }.count_sig_figs(), 2);
+    assert_eq!(
+        Cents { amount: -305 }.digits().collect::<Vec<_>>(),
+        [3, 0, 5]
+    );
+
+    let mut values = [Cents { amount: 149 }, Cents { amount: -150 }];
+    Cents::round_slice_zeros_mode(&mut values, 2, RoundingMode::HalfAwayFromZero);
+    assert_eq!(values, [Cents { amount: 100 }, Cents { amount: -200 }]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_formatting() {
+    assert_eq!(Meters(2.5).format_dp(2), "2.50");
+    assert_eq!(
+        Cents { amount: 1_234_567 }.format_grouped(0, ',', '.'),
+        "1,234,567"
+    );
+    assert_eq!(Cents { amount: 123_456 }.format_sf(2), "120000");
+}
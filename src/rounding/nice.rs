@@ -0,0 +1,217 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{digits, multiple, powers, Float, Roundable, RoundingMode};
+
+/// Rounds a positive number to the nearest value in the 1, 2, 5 series, such as `0.2`, `5` or `100`
+///
+/// Numbers are compared by distance, so the halfway points between the values of a decade are
+/// `1.5`, `3.5` and `7.5` times its power of ten, and ties go to the larger value
+///
+/// Zero, negative and non finite numbers have no value in the series and give NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_nice;
+///
+/// assert_eq!(round_nice(3.4), 2.0);
+/// assert_eq!(round_nice(3.5), 5.0);
+/// assert_eq!(round_nice(0.08), 0.1);
+/// assert!(round_nice(-3.0).is_nan());
+/// ```
+#[inline]
+pub fn round_nice(number: f64) -> f64 {
+    nice(number, nearest)
+}
+
+/// Rounds a positive number up to a value in the 1, 2, 5 series, a value in the series stays the same
+///
+/// Zero, negative and non finite numbers have no value in the series and give NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_nice;
+///
+/// assert_eq!(ceil_nice(2.1), 5.0);
+/// assert_eq!(ceil_nice(20.0), 20.0);
+/// assert_eq!(ceil_nice(0.011), 0.02);
+/// ```
+#[inline]
+pub fn ceil_nice(number: f64) -> f64 {
+    nice(number, |leading, tenths| match tenths {
+        _ if tenths <= 10 * leading => 1,
+        _ if tenths <= 20 * leading => 2,
+        _ if tenths <= 50 * leading => 5,
+        _ => 10,
+    })
+}
+
+/// Rounds a positive number down to a value in the 1, 2, 5 series, a value in the series stays the same
+///
+/// Zero, negative and non finite numbers have no value in the series and give NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_nice;
+///
+/// assert_eq!(floor_nice(4.9), 2.0);
+/// assert_eq!(floor_nice(50.0), 50.0);
+/// assert_eq!(floor_nice(0.019), 0.01);
+/// ```
+#[inline]
+pub fn floor_nice(number: f64) -> f64 {
+    nice(number, |leading, tenths| match tenths {
+        _ if tenths < 20 * leading => 1,
+        _ if tenths < 50 * leading => 2,
+        _ => 5,
+    })
+}
+
+/// Picks axis bounds that enclose a range of data and a step in the 1, 2, 5 series between them,
+/// returning `(axis_min, axis_max, step)`
+///
+/// The step is the value of the series nearest to the width of the range divided by
+/// `target_ticks`, so there are roughly that many steps between the bounds, and the bounds are the
+/// multiples of the step just outside the range. Bounds given the wrong way round are swapped.
+/// A range with no width is given the width of its value, or of one if it is zero, and bounds that
+/// would both land on it are moved a step out either side. Bounds past the largest float are
+/// infinite
+///
+/// # Panics
+///
+/// Panics if either bound isn't finite or `target_ticks` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::nice_bounds;
+///
+/// assert_eq!(nice_bounds(0.0, 97.0, 5), (0.0, 100.0, 20.0));
+/// assert_eq!(nice_bounds(-3.7, 12.2, 5), (-4.0, 14.0, 2.0));
+/// assert_eq!(nice_bounds(0.13, 0.87, 4), (0.0, 1.0, 0.2));
+/// assert_eq!(nice_bounds(5.0, 5.0, 5), (4.0, 6.0, 1.0));
+/// ```
+#[inline]
+pub fn nice_bounds(min: f64, max: f64, target_ticks: u32) -> (f64, f64, f64) {
+    let (low, high, digit, order) = nice_steps(min, max, target_ticks);
+    (
+        digits::scale(low * digit as i128, order as i64),
+        digits::scale(high * digit as i128, order as i64),
+        digits::scale(digit, order as i64),
+    )
+}
+
+/// The tick values of the axis [`nice_bounds`] picks for a range of data, from the lower bound to
+/// the upper one
+///
+/// Every tick is the float closest to its multiple of the step, rather than the sum of the steps
+/// before it, so the ticks don't drift and print without stray digits
+///
+/// # Panics
+///
+/// Panics if either bound isn't finite or `target_ticks` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ticks;
+///
+/// assert_eq!(ticks(0.0, 97.0, 5), [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+/// assert_eq!(ticks(-0.25, 0.25, 5), [-0.3, -0.2, -0.1, 0.0, 0.1, 0.2, 0.3]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn ticks(min: f64, max: f64, target_ticks: u32) -> Vec<f64> {
+    let (low, high, digit, order) = nice_steps(min, max, target_ticks);
+    (low..=high)
+        .map(|index| digits::scale(index * digit as i128, order as i64))
+        .collect()
+}
+
+/// The step of a nice axis for a range of data as a digit of the series and a power of ten,
+/// and the multiples of it at the bounds of the axis
+#[inline]
+fn nice_steps(min: f64, max: f64, target_ticks: u32) -> (i128, i128, u64, i32) {
+    assert!(min.is_finite() && max.is_finite(), "bounds must be finite");
+    assert!(target_ticks > 0, "target_ticks must be greater than zero");
+    let (min, max) = if min <= max { (min, max) } else { (max, min) };
+
+    let width = match max - min {
+        width if width > 0.0 => width,
+        _ if min != 0.0 => min.abs(),
+        _ => 1.0,
+    };
+    let mut raw = width / target_ticks as f64;
+    if raw.is_infinite() {
+        // The range is wider than the largest float
+        raw = max / target_ticks as f64 - min / target_ticks as f64;
+    }
+    // The step can't be smaller than the smallest float, and a step past the largest one
+    // overflows to infinity along with the bounds
+    let raw = raw.clamp(f64::from_bits(1), f64::MAX);
+    let (digit, order) = nice_digits(raw, nearest).expect("a positive, finite step");
+
+    // The bounds are found on the decimal digits of the step so a bound already on a multiple of
+    // it stays put, and the multiples are rounded to whole numbers of steps
+    let step = digits::scale(digit, order as i64);
+    let steps = |bound: f64, mode| {
+        let multiple = multiple::multiple_f64(bound, step, mode);
+        // A multiple past the largest float is counted from the quotient instead
+        let count = if multiple.is_finite() {
+            (multiple / step).round()
+        } else {
+            (bound / step).round_dp_mode(0, mode)
+        };
+        count as i128
+    };
+    let (low, high) = if step.is_finite() {
+        (steps(min, RoundingMode::Down), steps(max, RoundingMode::Up))
+    } else {
+        // Zero and the infinities are the only multiples of a step that overflowed
+        (-((min < 0.0) as i128), (max > 0.0) as i128)
+    };
+    if low == high {
+        (low - 1, high + 1, digit, order)
+    } else {
+        (low, high, digit, order)
+    }
+}
+
+/// Picks the nearest value of the series, ties going to the larger one
+#[inline]
+fn nearest(leading: u128, tenths: u128) -> u64 {
+    match tenths {
+        _ if tenths < 15 * leading => 1,
+        _ if tenths < 35 * leading => 2,
+        _ if tenths < 75 * leading => 5,
+        _ => 10,
+    }
+}
+
+/// Picks a value of the series for the decade of a number from the digits of its shortest decimal
+/// representation. `pick` is given the power of ten of the first digit and the digits times ten,
+/// so comparing them is the same as comparing the number with halfway points like `1.5`
+#[inline]
+fn nice(number: f64, pick: impl Fn(u128, u128) -> u64) -> f64 {
+    match nice_digits(number, pick) {
+        Some((digit, order)) => digits::scale(digit, order as i64),
+        None => f64::NAN,
+    }
+}
+
+/// The value of the series [`nice`] picks as a digit, which is 10 if it moved up to the next
+/// decade, and the power of ten of its decade, or `None` if the number has no value in the series
+#[inline]
+fn nice_digits(number: f64, pick: impl Fn(u128, u128) -> u64) -> Option<(u64, i32)> {
+    let order = number.order_of_magnitude().filter(|_| number > 0.0)?;
+
+    let (significand, _) = digits::shortest_decimal(number);
+    let leading = powers::ten_u128(significand.ilog10()).expect("power below a u64 fits in a u128");
+    Some((pick(leading, significand as u128 * 10), order))
+}
+
+mod tests;
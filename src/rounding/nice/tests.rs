@@ -0,0 +1,179 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_series() {
+    for exponent in -10..=10 {
+        for leading in [1, 2, 5] {
+            let value = format!("{leading}e{exponent}").parse::<f64>().unwrap();
+            assert_eq!(round_nice(value), value);
+            assert_eq!(ceil_nice(value), value);
+            assert_eq!(floor_nice(value), value);
+        }
+    }
+}
+
+#[test]
+fn test_ties() {
+    // Ties go to the larger value
+    assert_eq!(round_nice(1.5), 2.0);
+    assert_eq!(round_nice(1.49), 1.0);
+    assert_eq!(round_nice(3.5), 5.0);
+    assert_eq!(round_nice(3.49), 2.0);
+    assert_eq!(round_nice(7.5), 10.0);
+    assert_eq!(round_nice(7.49), 5.0);
+    assert_eq!(round_nice(0.035), 0.05);
+    assert_eq!(round_nice(350.0), 500.0);
+}
+
+#[test]
+fn test_below_one() {
+    assert_eq!(round_nice(0.02), 0.02);
+    assert_eq!(round_nice(0.05), 0.05);
+    assert_eq!(ceil_nice(0.021), 0.05);
+    assert_eq!(floor_nice(0.049), 0.02);
+    assert_eq!(ceil_nice(0.06), 0.1);
+    assert_eq!(floor_nice(0.3), 0.2);
+    assert_eq!(round_nice(0.0009), 0.001);
+}
+
+#[test]
+fn test_invalid() {
+    assert!(round_nice(0.0).is_nan());
+    assert!(ceil_nice(-1.0).is_nan());
+    assert!(floor_nice(-0.0).is_nan());
+    assert!(round_nice(f64::NAN).is_nan());
+    assert!(ceil_nice(f64::INFINITY).is_nan());
+}
+
+#[test]
+fn test_consistent() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..50_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        // Spread over twenty decades
+        let exponent = (state % 20) as i32 - 10;
+        let number =
+            (1.0 + (state >> 11) as f64 / (1_u64 << 53) as f64 * 9.0) * 10_f64.powi(exponent);
+
+        let below = floor_nice(number);
+        let above = ceil_nice(number);
+        let rounded = round_nice(number);
+        assert!(below <= number && number <= above, "{number}");
+        assert!(rounded == below || rounded == above, "{number}");
+        if number - below < above - number {
+            assert_eq!(rounded, below, "{number}");
+        } else if number - below > above - number {
+            assert_eq!(rounded, above, "{number}");
+        }
+        if below == above {
+            assert_eq!(below, number);
+        }
+        assert_eq!(floor_nice(below), below);
+        assert_eq!(ceil_nice(above), above);
+    }
+}
+
+#[test]
+fn test_nice_bounds() {
+    assert_eq!(nice_bounds(0.0, 97.0, 5), (0.0, 100.0, 20.0));
+    assert_eq!(nice_bounds(0.0, 100.0, 5), (0.0, 100.0, 20.0));
+    assert_eq!(nice_bounds(0.0, 97.0, 10), (0.0, 100.0, 10.0));
+    assert_eq!(nice_bounds(3.0, 97.0, 2), (0.0, 100.0, 50.0));
+    assert_eq!(nice_bounds(0.13, 0.87, 4), (0.0, 1.0, 0.2));
+    assert_eq!(
+        nice_bounds(1_234.0, 5_678.0, 5),
+        (1_000.0, 6_000.0, 1_000.0)
+    );
+
+    // Negative and crossing ranges
+    assert_eq!(nice_bounds(-97.0, -3.0, 5), (-100.0, 0.0, 20.0));
+    assert_eq!(nice_bounds(-3.7, 12.2, 5), (-4.0, 14.0, 2.0));
+    assert_eq!(nice_bounds(-0.25, 0.25, 5), (-0.3, 0.3, 0.1));
+    assert_eq!(nice_bounds(-97.0, -3.0, 5).1.to_bits(), 0_f64.to_bits());
+
+    // Bounds given the wrong way round are swapped
+    assert_eq!(nice_bounds(97.0, 0.0, 5), (0.0, 100.0, 20.0));
+
+    // Ranges with no width
+    assert_eq!(nice_bounds(5.0, 5.0, 5), (4.0, 6.0, 1.0));
+    assert_eq!(nice_bounds(5.3, 5.3, 5), (5.0, 6.0, 1.0));
+    assert_eq!(nice_bounds(-0.07, -0.07, 1), (-0.1, -0.05, 0.05));
+    assert_eq!(nice_bounds(0.0, 0.0, 5), (-0.2, 0.2, 0.2));
+
+    // Very small and very large spans
+    assert_eq!(
+        nice_bounds(1.0, 1.000_000_001, 5),
+        (1.0, 1.000_000_001, 2e-10)
+    );
+    assert_eq!(nice_bounds(3e-9, 4e-9, 5), (3e-9, 4e-9, 2e-10));
+    assert_eq!(nice_bounds(0.0, 1e300, 4), (0.0, 1e300, 2e299));
+    assert_eq!(
+        nice_bounds(-f64::MAX, f64::MAX, 4),
+        (f64::NEG_INFINITY, f64::INFINITY, 1e308)
+    );
+    let (low, high, _) = nice_bounds(-f64::MAX, f64::MAX, 1);
+    assert!(low == f64::NEG_INFINITY && high == f64::INFINITY);
+}
+
+#[test]
+#[should_panic(expected = "bounds must be finite")]
+fn test_nice_bounds_nan() {
+    nice_bounds(f64::NAN, 1.0, 5);
+}
+
+#[test]
+#[should_panic(expected = "target_ticks must be greater than zero")]
+fn test_nice_bounds_zero_ticks() {
+    nice_bounds(0.0, 1.0, 0);
+}
+
+#[test]
+fn test_ticks() {
+    assert_eq!(ticks(0.0, 97.0, 5), [0.0, 20.0, 40.0, 60.0, 80.0, 100.0]);
+    assert_eq!(ticks(-3.0, 1.0, 2), [-4.0, -2.0, 0.0, 2.0]);
+    assert_eq!(ticks(5.0, 5.0, 5), [4.0, 5.0, 6.0]);
+    assert_eq!(ticks(0.0, 0.0, 5), [-0.2, 0.0, 0.2]);
+
+    // Every tick is the float closest to its decimal, which adding up steps of 0.1 doesn't give
+    let tenths = ticks(0.0, 1.0, 10);
+    assert_eq!(
+        tenths,
+        [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0]
+    );
+    assert_ne!((0..3).map(|_| 0.1).sum::<f64>(), tenths[3]);
+
+    let tiny = ticks(1.0, 1.000_000_001, 5);
+    assert_eq!(tiny.len(), 6);
+    assert_eq!(tiny[1], 1.000_000_000_2);
+    assert_eq!(tiny[5], 1.000_000_001);
+}
+
+#[test]
+fn test_ticks_enclose() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let exponent = (state % 20) as i32 - 10;
+        let scale = 10_f64.powi(exponent);
+        let a = ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * scale;
+        let b = a + (state >> 40) as f64 / (1_u64 << 24) as f64 * scale;
+        let target = (state % 10 + 1) as u32;
+
+        let (low, high, step) = nice_bounds(a, b, target);
+        let values = ticks(a, b, target);
+        assert!(low <= a && b <= high, "{a} {b} {target}");
+        assert_eq!(values.first(), Some(&low), "{a} {b} {target}");
+        assert_eq!(values.last(), Some(&high), "{a} {b} {target}");
+        assert_eq!(round_nice(step), step);
+        // Nearest value of the series to the width over the target, and one step outside either end
+        assert!(values.len() as u32 <= 3 * target + 3, "{a} {b} {target}");
+        assert!(low + step > a - step * 1e-9 && high - step < b + step * 1e-9);
+    }
+}
@@ -0,0 +1,322 @@
+use core::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
+
+use super::{integer, RoundingMode};
+
+/// Rounding for the non zero integers, such as [`NonZeroU32`]
+///
+/// Rounding a non zero integer can give zero, such as `4` rounded down to 1 zero, so every
+/// method returns `None` when the result is zero or doesn't fit in the type
+pub trait RoundableNonZero: Sized {
+    /// Rounds the number to the given number of zeros,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroU8;
+    ///
+    /// let number = NonZeroU8::new(123).unwrap();
+    /// assert_eq!(number.round_zeros_nz(1), NonZeroU8::new(120));
+    /// assert_eq!(number.round_zeros_nz(3), None);
+    /// ```
+    fn round_zeros_nz(self, zeros: u32) -> Option<Self>;
+
+    /// Rounds the number up to the given number of zeros,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::{NonZeroI32, NonZeroU8};
+    ///
+    /// assert_eq!(NonZeroI32::new(-123).unwrap().ceil_zeros_nz(1), NonZeroI32::new(-120));
+    /// assert_eq!(NonZeroU8::new(250).unwrap().ceil_zeros_nz(2), None);
+    /// ```
+    fn ceil_zeros_nz(self, zeros: u32) -> Option<Self>;
+
+    /// Rounds the number down to the given number of zeros,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroU32;
+    ///
+    /// assert_eq!(NonZeroU32::new(156).unwrap().floor_zeros_nz(1), NonZeroU32::new(150));
+    /// assert_eq!(NonZeroU32::new(4).unwrap().floor_zeros_nz(1), None);
+    /// ```
+    fn floor_zeros_nz(self, zeros: u32) -> Option<Self>;
+
+    /// Rounds the number to the given number of zeros using the given rounding mode,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroU32;
+    ///
+    /// let number = NonZeroU32::new(125).unwrap();
+    /// assert_eq!(number.round_zeros_mode_nz(1, RoundingMode::HalfEven), NonZeroU32::new(120));
+    /// assert_eq!(number.round_zeros_mode_nz(3, RoundingMode::TowardZero), None);
+    /// ```
+    fn round_zeros_mode_nz(self, zeros: u32, mode: RoundingMode) -> Option<Self>;
+
+    /// Rounds the number to the given number of significant figures,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroU64;
+    ///
+    /// let number = NonZeroU64::new(1_234_567).unwrap();
+    /// assert_eq!(number.round_sf_nz(2), NonZeroU64::new(1_200_000));
+    /// assert_eq!(NonZeroU64::new(u64::MAX).unwrap().round_sf_nz(1), None);
+    /// ```
+    fn round_sf_nz(self, sig_figs: u32) -> Option<Self>;
+
+    /// Rounds the number up to the given number of significant figures,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroI16;
+    ///
+    /// assert_eq!(NonZeroI16::new(1201).unwrap().ceil_sf_nz(2), NonZeroI16::new(1300));
+    /// assert_eq!(NonZeroI16::new(32_001).unwrap().ceil_sf_nz(1), None);
+    /// ```
+    fn ceil_sf_nz(self, sig_figs: u32) -> Option<Self>;
+
+    /// Rounds the number down to the given number of significant figures,
+    /// returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroI16;
+    ///
+    /// assert_eq!(NonZeroI16::new(-1201).unwrap().floor_sf_nz(2), NonZeroI16::new(-1300));
+    /// assert_eq!(NonZeroI16::new(1299).unwrap().floor_sf_nz(0), None);
+    /// ```
+    fn floor_sf_nz(self, sig_figs: u32) -> Option<Self>;
+
+    /// Rounds the number to the given number of significant figures using the given rounding
+    /// mode, returning `None` if the result is zero or doesn't fit in the type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    /// use std::num::NonZeroU32;
+    ///
+    /// let number = NonZeroU32::new(1250).unwrap();
+    /// assert_eq!(number.round_sf_mode_nz(2, RoundingMode::HalfEven), NonZeroU32::new(1200));
+    /// ```
+    fn round_sf_mode_nz(self, sig_figs: u32, mode: RoundingMode) -> Option<Self>;
+}
+
+/// Rounds the number to the given number of zeros,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_nz;
+/// use std::num::NonZeroU32;
+///
+/// assert_eq!(round_zeros_nz(NonZeroU32::new(150).unwrap(), 2), NonZeroU32::new(200));
+/// assert_eq!(round_zeros_nz(NonZeroU32::new(49).unwrap(), 2), None);
+/// ```
+#[inline]
+pub fn round_zeros_nz<T: RoundableNonZero>(number: T, zeros: u32) -> Option<T> {
+    number.round_zeros_nz(zeros)
+}
+
+/// Rounds the number up to the given number of zeros,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_zeros_nz;
+/// use std::num::NonZeroU8;
+///
+/// assert_eq!(ceil_zeros_nz(NonZeroU8::new(4).unwrap(), 1), NonZeroU8::new(10));
+/// assert_eq!(ceil_zeros_nz(NonZeroU8::new(251).unwrap(), 1), None);
+/// ```
+#[inline]
+pub fn ceil_zeros_nz<T: RoundableNonZero>(number: T, zeros: u32) -> Option<T> {
+    number.ceil_zeros_nz(zeros)
+}
+
+/// Rounds the number down to the given number of zeros,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_zeros_nz;
+/// use std::num::NonZeroU8;
+///
+/// assert_eq!(floor_zeros_nz(NonZeroU8::new(14).unwrap(), 1), NonZeroU8::new(10));
+/// assert_eq!(floor_zeros_nz(NonZeroU8::new(4).unwrap(), 1), None);
+/// ```
+#[inline]
+pub fn floor_zeros_nz<T: RoundableNonZero>(number: T, zeros: u32) -> Option<T> {
+    number.floor_zeros_nz(zeros)
+}
+
+/// Rounds the number to the given number of zeros using the given rounding mode,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_zeros_mode_nz, RoundingMode};
+/// use std::num::NonZeroI64;
+///
+/// let number = NonZeroI64::new(-125).unwrap();
+/// assert_eq!(round_zeros_mode_nz(number, 1, RoundingMode::HalfUp), NonZeroI64::new(-120));
+/// ```
+#[inline]
+pub fn round_zeros_mode_nz<T: RoundableNonZero>(
+    number: T,
+    zeros: u32,
+    mode: RoundingMode,
+) -> Option<T> {
+    number.round_zeros_mode_nz(zeros, mode)
+}
+
+/// Rounds the number to the given number of significant figures,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_nz;
+/// use std::num::NonZeroU16;
+///
+/// assert_eq!(round_sf_nz(NonZeroU16::new(1250).unwrap(), 2), NonZeroU16::new(1300));
+/// assert_eq!(round_sf_nz(NonZeroU16::new(65_000).unwrap(), 1), None);
+/// ```
+#[inline]
+pub fn round_sf_nz<T: RoundableNonZero>(number: T, sig_figs: u32) -> Option<T> {
+    number.round_sf_nz(sig_figs)
+}
+
+/// Rounds the number up to the given number of significant figures,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_sf_nz;
+/// use std::num::NonZeroU16;
+///
+/// assert_eq!(ceil_sf_nz(NonZeroU16::new(1201).unwrap(), 2), NonZeroU16::new(1300));
+/// ```
+#[inline]
+pub fn ceil_sf_nz<T: RoundableNonZero>(number: T, sig_figs: u32) -> Option<T> {
+    number.ceil_sf_nz(sig_figs)
+}
+
+/// Rounds the number down to the given number of significant figures,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_sf_nz;
+/// use std::num::NonZeroU16;
+///
+/// assert_eq!(floor_sf_nz(NonZeroU16::new(1299).unwrap(), 2), NonZeroU16::new(1200));
+/// ```
+#[inline]
+pub fn floor_sf_nz<T: RoundableNonZero>(number: T, sig_figs: u32) -> Option<T> {
+    number.floor_sf_nz(sig_figs)
+}
+
+/// Rounds the number to the given number of significant figures using the given rounding mode,
+/// returning `None` if the result is zero or doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_sf_mode_nz, RoundingMode};
+/// use std::num::NonZeroU16;
+///
+/// let number = NonZeroU16::new(1350).unwrap();
+/// assert_eq!(round_sf_mode_nz(number, 2, RoundingMode::HalfEven), NonZeroU16::new(1400));
+/// ```
+#[inline]
+pub fn round_sf_mode_nz<T: RoundableNonZero>(
+    number: T,
+    sig_figs: u32,
+    mode: RoundingMode,
+) -> Option<T> {
+    number.round_sf_mode_nz(sig_figs, mode)
+}
+
+macro_rules! impl_roundable_non_zero {
+    ($($t:ty),*) => {
+        $(
+            impl RoundableNonZero for $t {
+                #[inline]
+                fn round_zeros_nz(self, zeros: u32) -> Option<$t> {
+                    self.round_zeros_mode_nz(zeros, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_zeros_nz(self, zeros: u32) -> Option<$t> {
+                    self.round_zeros_mode_nz(zeros, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn floor_zeros_nz(self, zeros: u32) -> Option<$t> {
+                    self.round_zeros_mode_nz(zeros, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn round_zeros_mode_nz(self, zeros: u32, mode: RoundingMode) -> Option<$t> {
+                    integer::round_zeros(self.get(), zeros, mode).and_then(<$t>::new)
+                }
+
+                #[inline]
+                fn round_sf_nz(self, sig_figs: u32) -> Option<$t> {
+                    self.round_sf_mode_nz(sig_figs, RoundingMode::HalfAwayFromZero)
+                }
+
+                #[inline]
+                fn ceil_sf_nz(self, sig_figs: u32) -> Option<$t> {
+                    self.round_sf_mode_nz(sig_figs, RoundingMode::Up)
+                }
+
+                #[inline]
+                fn floor_sf_nz(self, sig_figs: u32) -> Option<$t> {
+                    self.round_sf_mode_nz(sig_figs, RoundingMode::Down)
+                }
+
+                #[inline]
+                fn round_sf_mode_nz(self, sig_figs: u32, mode: RoundingMode) -> Option<$t> {
+                    integer::round_sf(self.get(), sig_figs, mode).and_then(<$t>::new)
+                }
+            }
+        )*
+    };
+}
+
+impl_roundable_non_zero!(
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64
+);
+
+mod tests;
@@ -0,0 +1,90 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::Roundable;
+
+#[test]
+fn test_rounds_to_zero() {
+    let four = NonZeroU8::new(4).unwrap();
+    assert_eq!(floor_zeros_nz(four, 1), None);
+    assert_eq!(round_zeros_nz(four, 1), None);
+    assert_eq!(four.round_zeros_mode_nz(1, RoundingMode::TowardZero), None);
+    assert_eq!(four.round_sf_nz(0), None);
+    assert_eq!(ceil_zeros_nz(four, 1), NonZeroU8::new(10));
+
+    let negative = NonZeroI32::new(-4).unwrap();
+    assert_eq!(ceil_zeros_nz(negative, 1), None);
+    assert_eq!(floor_zeros_nz(negative, 1), NonZeroI32::new(-10));
+    assert_eq!(
+        round_zeros_nz(NonZeroI64::new(-5).unwrap(), 1),
+        NonZeroI64::new(-10)
+    );
+    assert_eq!(round_zeros_nz(NonZeroI64::new(-4).unwrap(), 1), None);
+    assert_eq!(floor_zeros_nz(NonZeroU64::new(u64::MAX).unwrap(), 20), None);
+}
+
+#[test]
+fn test_overflow() {
+    assert_eq!(ceil_zeros_nz(NonZeroU8::new(250).unwrap(), 2), None);
+    assert_eq!(round_zeros_nz(NonZeroU8::new(255).unwrap(), 1), None);
+    assert_eq!(round_zeros_nz(NonZeroI8::new(-125).unwrap(), 1), None);
+    assert_eq!(ceil_sf_nz(NonZeroU16::new(65_001).unwrap(), 1), None);
+    assert_eq!(floor_sf_nz(NonZeroI16::new(i16::MIN).unwrap(), 1), None);
+    assert_eq!(ceil_zeros_nz(NonZeroU32::new(1).unwrap(), 10), None);
+    assert_eq!(ceil_zeros_nz(NonZeroU32::new(1).unwrap(), 50), None);
+}
+
+#[test]
+fn test_matches_integers() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let places = (state % 12) as u32;
+        let wide = state as i64;
+
+        let expected = wide
+            .checked_round_zeros(places)
+            .filter(|&rounded| rounded != 0);
+        let number = NonZeroI64::new(wide).unwrap();
+        assert_eq!(number.round_zeros_nz(places).map(NonZeroI64::get), expected);
+
+        let narrow = (state >> 40) as u32 | 1;
+        let number = NonZeroU32::new(narrow).unwrap();
+        let expected = narrow
+            .checked_ceil_sf(places)
+            .filter(|&rounded| rounded != 0);
+        assert_eq!(number.ceil_sf_nz(places).map(NonZeroU32::get), expected);
+        let expected = narrow
+            .checked_floor_zeros(places)
+            .filter(|&rounded| rounded != 0);
+        assert_eq!(number.floor_zeros_nz(places).map(NonZeroU32::get), expected);
+        for mode in [
+            RoundingMode::HalfEven,
+            RoundingMode::HalfOdd,
+            RoundingMode::Down,
+        ] {
+            let rounded = number
+                .round_sf_mode_nz(places, mode)
+                .map_or(0, NonZeroU32::get);
+            if rounded != 0 {
+                assert_eq!(rounded, narrow.round_sf_mode(places, mode));
+            }
+        }
+    }
+
+    assert_eq!(
+        floor_sf_nz(NonZeroU16::new(1299).unwrap(), 2),
+        NonZeroU16::new(1200)
+    );
+    assert_eq!(
+        round_sf_mode_nz(NonZeroI8::new(-125).unwrap(), 2, RoundingMode::HalfEven),
+        NonZeroI8::new(-120)
+    );
+    assert_eq!(
+        round_zeros_mode_nz(NonZeroU64::new(150).unwrap(), 2, RoundingMode::HalfDown),
+        NonZeroU64::new(100)
+    );
+}
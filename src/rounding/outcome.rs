@@ -0,0 +1,305 @@
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{powers, Float, Roundable, RoundingError};
+
+/// Which way rounding moved a number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Adjustment {
+    /// The rounded value is greater than the number
+    Up,
+    /// The rounded value is less than the number
+    Down,
+    /// The number was already rounded, or isn't a number that can be rounded
+    Unchanged,
+}
+
+/// The result of a rounding operation along with how it was reached, for auditing which way
+/// midpoints went
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_dp_outcome, Adjustment, RoundOutcome};
+///
+/// assert_eq!(
+///     round_dp_outcome(2.5_f64, 0),
+///     RoundOutcome { value: 3.0, was_tie: true, direction: Adjustment::Up }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RoundOutcome<T> {
+    /// The rounded number
+    pub value: T,
+    /// Whether the number was exactly halfway between the two values it could round to
+    pub was_tie: bool,
+    /// Which way the number moved to reach the rounded value
+    pub direction: Adjustment,
+}
+
+impl<T: PartialOrd> RoundOutcome<T> {
+    #[inline]
+    fn new(number: T, value: T, was_tie: bool) -> RoundOutcome<T> {
+        let direction = match value.partial_cmp(&number) {
+            Some(Ordering::Greater) => Adjustment::Up,
+            Some(Ordering::Less) => Adjustment::Down,
+            _ => Adjustment::Unchanged,
+        };
+
+        RoundOutcome {
+            value,
+            was_tie,
+            direction,
+        }
+    }
+}
+
+/// Rounds the number to the given number of decimal places like [`round`](super::round),
+/// reporting whether it was a tie and which way it went
+///
+/// A float is a tie when scaling it by the power of ten lands exactly on a half, which is how it
+/// is rounded. The float closest to `2.675` is slightly below it, but the error in scaling it by
+/// `100` gives exactly `267.5` so it is a tie, while `1.005` scales to just below `100.5` and isn't
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_dp_outcome, Adjustment};
+///
+/// let outcome = round_dp_outcome(-2.5_f64, 0);
+/// assert_eq!(outcome.value, -3.0);
+/// assert!(outcome.was_tie);
+/// assert_eq!(outcome.direction, Adjustment::Down);
+///
+/// let outcome = round_dp_outcome(1.005_f64, 2);
+/// assert_eq!(outcome.value, 1.0);
+/// assert!(!outcome.was_tie);
+/// assert_eq!(outcome.direction, Adjustment::Down);
+/// ```
+#[inline]
+pub fn round_dp_outcome<T: Float + Roundable + PartialOrd + Copy>(
+    number: T,
+    decimal_places: u32,
+) -> RoundOutcome<T> {
+    let was_tie = number.tie_at(i32::try_from(decimal_places).unwrap_or(i32::MAX));
+    RoundOutcome::new(number, number.round_dp(decimal_places), was_tie)
+}
+
+/// Rounds the number to the given number of zeros like [`round_zeros`](super::round_zeros),
+/// reporting whether it was a tie and which way it went
+///
+/// Ties are exact for integers. A float is a tie when scaling it by the power of ten lands
+/// exactly on a half, which is how it is rounded, so it can differ from the exact binary value
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_zeros_outcome, Adjustment};
+///
+/// let outcome = round_zeros_outcome(125_i32, 1);
+/// assert_eq!(outcome.value, 130);
+/// assert!(outcome.was_tie);
+/// assert_eq!(outcome.direction, Adjustment::Up);
+///
+/// let outcome = round_zeros_outcome(1234.5_f64, 2);
+/// assert_eq!(outcome.value, 1200.0);
+/// assert!(!outcome.was_tie);
+/// assert_eq!(outcome.direction, Adjustment::Down);
+/// ```
+#[inline]
+pub fn round_zeros_outcome<T: Roundable + PartialOrd + Copy>(
+    number: T,
+    zeros: u32,
+) -> RoundOutcome<T> {
+    let was_tie = number.tie_at(i32::try_from(zeros).map_or(i32::MIN, |zeros| -zeros));
+    RoundOutcome::new(number, number.round_zeros(zeros), was_tie)
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`](super::round_sf),
+/// reporting whether it was a tie and which way it went
+///
+/// Ties are exact for integers. A float is a tie when scaling it by the power of ten lands
+/// exactly on a half, which is how it is rounded, so it can differ from the exact binary value
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_sf_outcome, Adjustment};
+///
+/// let outcome = round_sf_outcome(-1250_i32, 2);
+/// assert_eq!(outcome.value, -1300);
+/// assert!(outcome.was_tie);
+/// assert_eq!(outcome.direction, Adjustment::Down);
+///
+/// let outcome = round_sf_outcome(0.375_f64, 2);
+/// assert_eq!(outcome.value, 0.38);
+/// assert!(outcome.was_tie);
+/// ```
+#[inline]
+pub fn round_sf_outcome<T: Roundable + PartialOrd + Copy>(
+    number: T,
+    sig_figs: u32,
+) -> RoundOutcome<T> {
+    let decimal_places = i32::try_from(sig_figs)
+        .unwrap_or(i32::MAX)
+        .saturating_sub(number.get_digits());
+    let was_tie = sig_figs != 0 && number.tie_at(decimal_places);
+    RoundOutcome::new(number, number.round_sf(sig_figs), was_tie)
+}
+
+/// Rounds the number to the given number of decimal places like [`round`](super::round),
+/// returning the rounded number and the error `number - rounded` it introduced
+///
+/// The error is found from the number itself rather than the scaled value it was rounded from,
+/// so adding it back to the rounded number gives the original to within an ulp
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_dp_with_error;
+///
+/// assert_eq!(round_dp_with_error(1.25_f64, 1), (1.3, -0.050000000000000044));
+/// assert_eq!(round_dp_with_error(-2.5_f32, 0), (-3.0, 0.5));
+/// ```
+#[inline]
+pub fn round_dp_with_error<T: Float + Roundable + Copy>(number: T, decimal_places: u32) -> (T, T) {
+    let rounded = number.round_dp(decimal_places);
+    (rounded, number.rounding_error(rounded))
+}
+
+/// Rounds the number to the given number of zeros like [`round_zeros`](super::round_zeros),
+/// returning the rounded number and the error `number - rounded` it introduced
+///
+/// The error of an integer is exact. A result that saturates at the bounds of the type includes
+/// the saturation in the error, so `127_i8` rounded to 1 zero stays `127` with no error rather
+/// than the error of `-3` from rounding to `130`, see [`try_round_zeros_with_error`] to catch
+/// this instead. The error of an unsigned integer that rounded up wraps around so
+/// `rounded.wrapping_add(error)` is always the original number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_zeros_with_error;
+///
+/// assert_eq!(round_zeros_with_error(1234_i32, 2), (1200, 34));
+/// assert_eq!(round_zeros_with_error(-1250_i32, 2), (-1300, 50));
+/// assert_eq!(round_zeros_with_error(1750.5_f64, 3), (2000.0, -249.5));
+/// assert_eq!(round_zeros_with_error(i8::MAX, 1), (127, 0));
+/// ```
+#[inline]
+pub fn round_zeros_with_error<T: Roundable + Copy>(number: T, zeros: u32) -> (T, T) {
+    let rounded = number.round_zeros(zeros);
+    (rounded, number.rounding_error(rounded))
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf`](super::round_sf),
+/// returning the rounded number and the error `number - rounded` it introduced
+///
+/// The error of an integer is exact. A result that saturates at the bounds of the type includes
+/// the saturation in the error, see [`try_round_sf_with_error`] to catch this instead. The error
+/// of an unsigned integer that rounded up wraps around so `rounded.wrapping_add(error)` is always
+/// the original number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_sf_with_error;
+///
+/// assert_eq!(round_sf_with_error(123_456_i64, 2), (120_000, 3_456));
+/// assert_eq!(round_sf_with_error(0.375_f64, 1), (0.4, -0.025000000000000022));
+/// assert_eq!(round_sf_with_error(126_u8, 2), (130, 252));
+/// ```
+#[inline]
+pub fn round_sf_with_error<T: Roundable + Copy>(number: T, sig_figs: u32) -> (T, T) {
+    let rounded = number.round_sf(sig_figs);
+    (rounded, number.rounding_error(rounded))
+}
+
+/// Rounds the number to the given number of zeros like [`round_zeros_with_error`], returning an
+/// error instead of saturating if the number isn't finite or the result doesn't fit in the type
+///
+/// # Errors
+///
+/// - [`RoundingError::NonFinite`] if the number is NaN or infinite
+/// - [`RoundingError::Overflow`] if the rounded number doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_zeros_with_error, RoundingError};
+///
+/// assert_eq!(try_round_zeros_with_error(1234_i32, 2), Ok((1200, 34)));
+/// assert_eq!(try_round_zeros_with_error(i8::MAX, 1), Err(RoundingError::Overflow));
+/// assert_eq!(try_round_zeros_with_error(f64::NAN, 1), Err(RoundingError::NonFinite));
+/// ```
+#[inline]
+pub fn try_round_zeros_with_error<T: Roundable + Copy>(
+    number: T,
+    zeros: u32,
+) -> Result<(T, T), RoundingError> {
+    let rounded = number.try_round_zeros(zeros)?;
+    Ok((rounded, number.rounding_error(rounded)))
+}
+
+/// Rounds the number to the given number of significant figures like [`round_sf_with_error`],
+/// returning an error instead of saturating if the number isn't finite, `sig_figs` is zero or the
+/// result doesn't fit in the type
+///
+/// # Errors
+///
+/// - [`RoundingError::NonFinite`] if the number is NaN or infinite
+/// - [`RoundingError::ZeroSigFigs`] if `sig_figs` is zero
+/// - [`RoundingError::Overflow`] if the rounded number doesn't fit in the type
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{try_round_sf_with_error, RoundingError};
+///
+/// assert_eq!(try_round_sf_with_error(-1250_i32, 2), Ok((-1300, 50)));
+/// assert_eq!(try_round_sf_with_error(251_u8, 1), Err(RoundingError::Overflow));
+/// assert_eq!(try_round_sf_with_error(0.5_f64, 0), Err(RoundingError::ZeroSigFigs));
+/// ```
+#[inline]
+pub fn try_round_sf_with_error<T: Roundable + Copy>(
+    number: T,
+    sig_figs: u32,
+) -> Result<(T, T), RoundingError> {
+    let rounded = number.try_round_sf(sig_figs)?;
+    Ok((rounded, number.rounding_error(rounded)))
+}
+
+/// Whether a float scaled by `10^decimal_places`, the same way it is scaled to be rounded,
+/// lands exactly halfway between two integers
+#[inline]
+pub(crate) fn is_tie_f64(number: f64, decimal_places: i32) -> bool {
+    let scaled = if decimal_places >= 0 {
+        // 10^309 and above overflow so the largest powers are applied in two steps
+        number
+            * powers::ten_f64(decimal_places.min(300))
+            * powers::ten_f64(decimal_places.max(300) - 300)
+    } else {
+        number / powers::ten_f64(decimal_places.saturating_neg())
+    };
+    (scaled - scaled.trunc()).abs() == 0.5
+}
+
+/// Whether a float scaled by `10^decimal_places`, the same way it is scaled to be rounded,
+/// lands exactly halfway between two integers
+#[inline]
+pub(crate) fn is_tie_f32(number: f32, decimal_places: i32) -> bool {
+    if decimal_places > 10 {
+        // Larger powers overflow an f32 so the number is scaled in an f64
+        return is_tie_f64(number as f64, decimal_places);
+    }
+
+    let scaled = if decimal_places >= 0 {
+        number * powers::ten_f32(decimal_places)
+    } else {
+        number / powers::ten_f32(decimal_places.saturating_neg())
+    };
+    (scaled - scaled.trunc()).abs() == 0.5
+}
+
+mod tests;
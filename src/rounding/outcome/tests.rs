@@ -0,0 +1,302 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{round, round_sf, round_zeros};
+
+#[test]
+fn test_round_dp_outcome() {
+    let cases = [
+        // (number, decimal places, value, was tie, direction)
+        (2.5, 0, 3.0, true, Adjustment::Up),
+        (-2.5, 0, -3.0, true, Adjustment::Down),
+        (0.125, 2, 0.13, true, Adjustment::Up),
+        (-0.375, 2, -0.38, true, Adjustment::Down),
+        (2.4, 0, 2.0, false, Adjustment::Down),
+        (2.6, 0, 3.0, false, Adjustment::Up),
+        (-2.6, 0, -3.0, false, Adjustment::Down),
+        (1.25, 2, 1.25, false, Adjustment::Unchanged),
+        (0.0, 3, 0.0, false, Adjustment::Unchanged),
+    ];
+    for (number, decimal_places, value, was_tie, direction) in cases {
+        let expected = RoundOutcome {
+            value,
+            was_tie,
+            direction,
+        };
+        assert_eq!(
+            round_dp_outcome(number, decimal_places),
+            expected,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_outcome(number as f32, decimal_places),
+            RoundOutcome {
+                value: value as f32,
+                was_tie,
+                direction,
+            },
+            "{number}"
+        );
+    }
+
+    // Ties are found in the scaled number, so they follow the error in scaling
+    assert!(round_dp_outcome(2.675_f64, 2).was_tie);
+    assert_eq!(round_dp_outcome(2.675_f64, 2).value, 2.68);
+    assert_eq!(
+        round_dp_outcome(1.005_f64, 2),
+        RoundOutcome {
+            value: 1.0,
+            was_tie: false,
+            direction: Adjustment::Down,
+        }
+    );
+
+    let outcome = round_dp_outcome(f64::NAN, 2);
+    assert!(outcome.value.is_nan());
+    assert!(!outcome.was_tie);
+    assert_eq!(outcome.direction, Adjustment::Unchanged);
+    assert_eq!(
+        round_dp_outcome(f64::INFINITY, 2).direction,
+        Adjustment::Unchanged
+    );
+}
+
+#[test]
+fn test_round_zeros_outcome() {
+    assert_eq!(
+        round_zeros_outcome(125_i32, 1),
+        RoundOutcome {
+            value: 130,
+            was_tie: true,
+            direction: Adjustment::Up,
+        }
+    );
+    assert_eq!(
+        round_zeros_outcome(-125_i64, 1),
+        RoundOutcome {
+            value: -130,
+            was_tie: true,
+            direction: Adjustment::Down,
+        }
+    );
+    assert_eq!(
+        round_zeros_outcome(124_u8, 1),
+        RoundOutcome {
+            value: 120,
+            was_tie: false,
+            direction: Adjustment::Down,
+        }
+    );
+    assert_eq!(
+        round_zeros_outcome(1500_u16, 2),
+        RoundOutcome {
+            value: 1500,
+            was_tie: false,
+            direction: Adjustment::Unchanged,
+        }
+    );
+    assert!(round_zeros_outcome(5_000_000_000_000_000_000_u64, 19).was_tie);
+    assert!(!round_zeros_outcome(0_i32, 1).was_tie);
+    assert!(!round_zeros_outcome(5_i32, 0).was_tie);
+    assert!(!round_zeros_outcome(u64::MAX, 40).was_tie);
+
+    assert_eq!(
+        round_zeros_outcome(125_f64, 1),
+        RoundOutcome {
+            value: 130.0,
+            was_tie: true,
+            direction: Adjustment::Up,
+        }
+    );
+    assert_eq!(
+        round_zeros_outcome(-2_500_f32, 3),
+        RoundOutcome {
+            value: -3_000.0,
+            was_tie: true,
+            direction: Adjustment::Down,
+        }
+    );
+    assert!(!round_zeros_outcome(125.5_f64, 1).was_tie);
+    assert!(!round_zeros_outcome(15_f64, 2).was_tie);
+}
+
+#[test]
+fn test_round_sf_outcome() {
+    assert_eq!(
+        round_sf_outcome(1250_u32, 2),
+        RoundOutcome {
+            value: 1300,
+            was_tie: true,
+            direction: Adjustment::Up,
+        }
+    );
+    assert_eq!(
+        round_sf_outcome(-0.0125_f64, 2),
+        RoundOutcome {
+            value: -0.013,
+            was_tie: true,
+            direction: Adjustment::Down,
+        }
+    );
+    assert_eq!(
+        round_sf_outcome(2.5_f64, 1),
+        RoundOutcome {
+            value: 3.0,
+            was_tie: true,
+            direction: Adjustment::Up,
+        }
+    );
+    assert_eq!(
+        round_sf_outcome(123.456_f64, 4),
+        RoundOutcome {
+            value: 123.5,
+            was_tie: false,
+            direction: Adjustment::Up,
+        }
+    );
+    assert!(round_sf_outcome(0.0625_f32, 2).was_tie);
+    assert!(!round_sf_outcome(0.0126_f64, 2).was_tie);
+    assert!(!round_sf_outcome(1250_i32, 3).was_tie);
+    assert!(!round_sf_outcome(1250_i32, 0).was_tie);
+}
+
+#[test]
+fn test_ties_match_exact_midpoints() {
+    // Halves of every integer are ties at 0 decimal places and quarters aren't
+    let mut state = 0x0ddb_a115_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let integer = (state >> 20) as i64 - (1 << 43);
+        let half = integer as f64 + 0.5;
+        let quarter = integer as f64 + 0.25;
+
+        let outcome = round_dp_outcome(half, 0);
+        assert!(outcome.was_tie, "{half}");
+        assert_eq!(outcome.value, round(half, 0));
+        assert!(!round_dp_outcome(quarter, 0).was_tie, "{quarter}");
+
+        // Integers are ties exactly when the discarded digits are a five followed by zeros
+        let zeros = (state % 6) as u32 + 1;
+        let power = 10_i64.pow(zeros);
+        let midpoint = integer - integer % power + integer.signum() * power / 2;
+        assert!(round_zeros_outcome(midpoint, zeros).was_tie, "{midpoint}");
+        let outcome = round_zeros_outcome(integer, zeros);
+        assert_eq!(outcome.was_tie, integer == midpoint, "{integer}");
+        assert_eq!(outcome.value, round_zeros(integer, zeros));
+        assert_eq!(round_sf_outcome(integer, 3).value, round_sf(integer, 3));
+    }
+}
+
+#[test]
+fn test_with_error() {
+    assert_eq!(round_dp_with_error(2.5_f64, 0), (3.0, -0.5));
+    assert_eq!(round_dp_with_error(0.0_f64, 2), (0.0, 0.0));
+    assert_eq!(
+        round_zeros_with_error(125_u32, 1),
+        (130, 125_u32.wrapping_sub(130))
+    );
+    assert_eq!(round_zeros_with_error(-125_i8, 1), (-128, 3));
+    assert_eq!(round_zeros_with_error(i64::MIN, 1), (i64::MIN, 0));
+    assert_eq!(round_sf_with_error(-0.0_f32, 1), (-0.0, 0.0));
+    assert_eq!(
+        round_sf_with_error(1500_u16, 1),
+        (2000, 1500_u16.wrapping_sub(2000))
+    );
+
+    let (rounded, error) = round_dp_with_error(f64::NAN, 1);
+    assert!(rounded.is_nan() && error.is_nan());
+}
+
+#[test]
+fn test_with_error_saturates() {
+    // 127 would round to 130, so it saturates back to itself with no error
+    assert_eq!(round_zeros_with_error(i8::MAX, 1), (127, 0));
+    assert_eq!(
+        try_round_zeros_with_error(i8::MAX, 1),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        try_round_zeros_with_error(-125_i8, 1),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        try_round_sf_with_error(u16::MAX, 1),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        try_round_zeros_with_error(f32::INFINITY, 1),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(
+        try_round_sf_with_error(12_i32, 0),
+        Err(RoundingError::ZeroSigFigs)
+    );
+
+    // Results that fit are the same as without the check
+    assert_eq!(try_round_zeros_with_error(124_i8, 1), Ok((120, 4)));
+    assert_eq!(try_round_zeros_with_error(-124_i8, 1), Ok((-120, -4)));
+    assert_eq!(
+        try_round_sf_with_error(126_u8, 2),
+        Ok(round_sf_with_error(126_u8, 2))
+    );
+    assert_eq!(
+        try_round_zeros_with_error(1750.5_f64, 3),
+        Ok((2000.0, -249.5))
+    );
+}
+
+#[test]
+fn test_with_error_reconstructs() {
+    let mut state = 0xe770_0000_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let magnitude = 10_f64.powi((state % 16) as i32 - 8);
+        let number = ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * magnitude;
+        let places = (state >> 3) as u32 % 10;
+        let ulp = f64::EPSILON * number.abs();
+
+        // Rounding to the nearest value never moves the number by more than half a step
+        let (rounded, error) = round_dp_with_error(number, places);
+        assert!((rounded + error - number).abs() <= ulp, "{number} {places}");
+        let half_step = 0.5 * 10_f64.powi(-(places as i32));
+        assert!(error.abs() <= half_step + ulp, "{number} {places}");
+
+        let (rounded, error) = round_zeros_with_error(number, places);
+        assert!((rounded + error - number).abs() <= ulp, "{number} {places}");
+        let half_step = 0.5 * 10_f64.powi(places as i32);
+        assert!(error.abs() <= half_step + ulp, "{number} {places}");
+
+        let sig_figs = places + 1;
+        let (rounded, error) = round_sf_with_error(number, sig_figs);
+        assert!(
+            (rounded + error - number).abs() <= ulp,
+            "{number} {sig_figs}"
+        );
+        if number != 0.0 {
+            let order = number.abs().log10().floor() as i32;
+            let half_step = 0.5 * 10_f64.powi(order + 1 - sig_figs as i32);
+            assert!(
+                error.abs() <= half_step * (1.0 + 1e-9),
+                "{number} {sig_figs}"
+            );
+        }
+
+        // Integers are exact
+        let integer = (state >> 33) as i64 - (1 << 30);
+        let zeros = places;
+        let (rounded, error) = round_zeros_with_error(integer, zeros);
+        assert_eq!(rounded + error, integer);
+        assert!(error.abs() * 2 <= 10_i64.pow(zeros), "{integer} {zeros}");
+
+        let (rounded, error) = round_sf_with_error(integer, sig_figs);
+        assert_eq!(rounded + error, integer);
+        let unsigned = integer.unsigned_abs() as u32;
+        let (rounded, error) = round_sf_with_error(unsigned, sig_figs);
+        assert_eq!(rounded.wrapping_add(error), unsigned);
+    }
+}
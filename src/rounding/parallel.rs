@@ -0,0 +1,185 @@
+use rayon::iter::ParallelIterator;
+use rayon::slice::ParallelSliceMut;
+
+use super::{slice, Float, Roundable, RoundingMode};
+
+/// Slices are split into chunks of at least this many numbers, as handing a chunk to another
+/// thread costs more than rounding a smaller one
+const MIN_CHUNK_LENGTH: usize = 1 << 14;
+
+/// Splits the slice into chunks with `par_chunks_mut` and rounds them across rayon's thread pool
+///
+/// Every number is rounded on its own so the result doesn't depend on how the slice is split or
+/// how many threads there are
+#[inline]
+fn for_each_chunk<T: Send>(values: &mut [T], round: impl Fn(&mut [T]) + Send + Sync) {
+    if values.len() <= MIN_CHUNK_LENGTH {
+        round(values);
+        return;
+    }
+
+    values.par_chunks_mut(MIN_CHUNK_LENGTH).for_each(round);
+}
+
+/// Rounds every number in the slice to the given number of decimal places in place, splitting
+/// it across the available threads
+///
+/// The result is the same as [`round_slice_dp`](super::round_slice_dp) for any number of threads.
+/// Slices too short to be worth splitting are rounded on the calling thread
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::par_round_slice_dp;
+///
+/// let mut values = vec![1.234_f64; 100_000];
+/// par_round_slice_dp(&mut values, 2);
+/// assert!(values.iter().all(|&value| value == 1.23));
+/// ```
+#[inline]
+pub fn par_round_slice_dp<T: Float + Send>(values: &mut [T], decimal_places: u32) {
+    par_round_slice_dp_mode(values, decimal_places, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice to the given number of decimal places using the given
+/// rounding mode in place, splitting it across the available threads
+///
+/// The result is the same as [`round_slice_dp_mode`](super::round_slice_dp_mode) for any number
+/// of threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{par_round_slice_dp_mode, RoundingMode};
+///
+/// let mut values = vec![1.231_f32; 100_000];
+/// par_round_slice_dp_mode(&mut values, 2, RoundingMode::Up);
+/// assert!(values.iter().all(|&value| value == 1.24));
+/// ```
+#[inline]
+pub fn par_round_slice_dp_mode<T: Float + Send>(
+    values: &mut [T],
+    decimal_places: u32,
+    mode: RoundingMode,
+) {
+    for_each_chunk(values, |chunk| {
+        slice::round_slice_dp_mode(chunk, decimal_places, mode)
+    });
+}
+
+/// Rounds every number in the slice to the given number of decimal places in place, splitting
+/// it across rayon's thread pool and rounding several numbers at a time on each with the
+/// `simd` feature
+///
+/// The result is the same as [`round_slice_dp_simd`](super::round_slice_dp_simd) for any number
+/// of threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::par_round_slice_dp_simd;
+///
+/// let mut values = vec![-2.675_f64; 100_000];
+/// par_round_slice_dp_simd(&mut values, 2);
+/// assert!(values.iter().all(|&value| value == -2.68));
+/// ```
+#[inline]
+pub fn par_round_slice_dp_simd<T: Float + Send>(values: &mut [T], decimal_places: u32) {
+    for_each_chunk(values, |chunk| {
+        slice::round_slice_dp_simd(chunk, decimal_places)
+    });
+}
+
+/// Rounds every number in the slice to the given number of zeros in place, splitting it across
+/// the available threads
+///
+/// The result is the same as [`round_slice_zeros`](super::round_slice_zeros) for any number of
+/// threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::par_round_slice_zeros;
+///
+/// let mut values: Vec<u32> = (0..100_000).collect();
+/// par_round_slice_zeros(&mut values, 3);
+/// assert_eq!(values[1499], 1000);
+/// assert_eq!(values[1500], 2000);
+/// ```
+#[inline]
+pub fn par_round_slice_zeros<T: Roundable + Send>(values: &mut [T], zeros: u32) {
+    par_round_slice_zeros_mode(values, zeros, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice to the given number of zeros using the given rounding mode
+/// in place, splitting it across the available threads
+///
+/// The result is the same as [`round_slice_zeros_mode`](super::round_slice_zeros_mode) for any
+/// number of threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{par_round_slice_zeros_mode, RoundingMode};
+///
+/// let mut values: Vec<i64> = (0..100_000).collect();
+/// par_round_slice_zeros_mode(&mut values, 3, RoundingMode::Down);
+/// assert_eq!(values[1999], 1000);
+/// ```
+#[inline]
+pub fn par_round_slice_zeros_mode<T: Roundable + Send>(
+    values: &mut [T],
+    zeros: u32,
+    mode: RoundingMode,
+) {
+    for_each_chunk(values, |chunk| {
+        slice::round_slice_zeros_mode(chunk, zeros, mode)
+    });
+}
+
+/// Rounds every number in the slice to the given number of significant figures in place,
+/// splitting it across the available threads
+///
+/// The result is the same as [`round_slice_sf`](super::round_slice_sf) for any number of threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::par_round_slice_sf;
+///
+/// let mut values: Vec<f64> = (0..100_000).map(|value| value as f64 + 0.5).collect();
+/// par_round_slice_sf(&mut values, 2);
+/// assert_eq!(values[12_345], 12_000.0);
+/// ```
+#[inline]
+pub fn par_round_slice_sf<T: Roundable + Copy + Send>(values: &mut [T], sig_figs: u32) {
+    par_round_slice_sf_mode(values, sig_figs, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice to the given number of significant figures using the given
+/// rounding mode in place, splitting it across the available threads
+///
+/// The result is the same as [`round_slice_sf_mode`](super::round_slice_sf_mode) for any number
+/// of threads
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{par_round_slice_sf_mode, RoundingMode};
+///
+/// let mut values: Vec<u32> = (0..100_000).collect();
+/// par_round_slice_sf_mode(&mut values, 1, RoundingMode::Up);
+/// assert_eq!(values[12_345], 20_000);
+/// ```
+#[inline]
+pub fn par_round_slice_sf_mode<T: Roundable + Copy + Send>(
+    values: &mut [T],
+    sig_figs: u32,
+    mode: RoundingMode,
+) {
+    for_each_chunk(values, |chunk| {
+        slice::round_slice_sf_mode(chunk, sig_figs, mode)
+    });
+}
+
+mod tests;
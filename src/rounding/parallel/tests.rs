@@ -0,0 +1,117 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{
+    round_slice_dp, round_slice_dp_mode, round_slice_dp_simd, round_slice_sf, round_slice_zeros,
+};
+
+#[cfg(test)]
+fn random_floats(count: usize) -> Vec<f64> {
+    let mut state = 0x9a2a_11e1_u64;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let exponent = (state % 30) as i32 - 15;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+fn same_bits(a: &[f64], b: &[f64]) -> bool {
+    a.iter()
+        .map(|value| value.to_bits())
+        .eq(b.iter().map(|value| value.to_bits()))
+}
+
+#[test]
+fn test_parallel_matches_serial() {
+    let values = random_floats(3_000_000);
+
+    let (mut serial, mut parallel) = (values.clone(), values.clone());
+    round_slice_dp(&mut serial, 3);
+    par_round_slice_dp(&mut parallel, 3);
+    assert!(same_bits(&serial, &parallel));
+
+    let (mut serial, mut parallel) = (values.clone(), values.clone());
+    round_slice_dp_mode(&mut serial, 3, RoundingMode::Down);
+    par_round_slice_dp_mode(&mut parallel, 3, RoundingMode::Down);
+    assert!(same_bits(&serial, &parallel));
+
+    let (mut serial, mut parallel) = (values.clone(), values.clone());
+    round_slice_dp_simd(&mut serial, 3);
+    par_round_slice_dp_simd(&mut parallel, 3);
+    assert!(same_bits(&serial, &parallel));
+
+    let (mut serial, mut parallel) = (values.clone(), values.clone());
+    round_slice_zeros(&mut serial, 2);
+    par_round_slice_zeros(&mut parallel, 2);
+    assert!(same_bits(&serial, &parallel));
+
+    let (mut serial, mut parallel) = (values.clone(), values.clone());
+    round_slice_sf(&mut serial, 3);
+    par_round_slice_sf(&mut parallel, 3);
+    assert!(same_bits(&serial, &parallel));
+
+    let integers: Vec<i32> = values.iter().map(|&value| value as i32).collect();
+    let (mut serial, mut parallel) = (integers.clone(), integers);
+    round_slice_sf(&mut serial, 2);
+    par_round_slice_sf(&mut parallel, 2);
+    assert_eq!(serial, parallel);
+}
+
+#[test]
+fn test_any_thread_count() {
+    let values = random_floats(MIN_CHUNK_LENGTH * 7 + 5);
+    let mut serial = values.clone();
+    round_slice_dp(&mut serial, 4);
+    for threads in [1, 2, 3, 8] {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        let mut parallel = values.clone();
+        pool.install(|| par_round_slice_dp(&mut parallel, 4));
+        assert!(same_bits(&serial, &parallel), "{threads}");
+    }
+}
+
+#[test]
+fn test_chunks_cover_the_slice() {
+    for length in [
+        0,
+        1,
+        MIN_CHUNK_LENGTH - 1,
+        MIN_CHUNK_LENGTH + 1,
+        5 * MIN_CHUNK_LENGTH + 3,
+    ] {
+        let mut values = vec![0_u32; length];
+        for_each_chunk(&mut values, |chunk| {
+            chunk.iter_mut().for_each(|value| *value += 1)
+        });
+        assert!(values.iter().all(|&value| value == 1), "{length}");
+    }
+}
+
+#[test]
+fn test_short_slices() {
+    let mut empty: [f64; 0] = [];
+    par_round_slice_dp(&mut empty, 2);
+    par_round_slice_sf(&mut empty, 2);
+    par_round_slice_zeros(&mut empty, 2);
+
+    let mut single = [1.005_f64];
+    par_round_slice_dp(&mut single, 2);
+    assert_eq!(single, [1.0]);
+
+    let mut single = [f64::NAN];
+    par_round_slice_sf(&mut single, 2);
+    assert!(single[0].is_nan());
+
+    let mut single = [-1250_i32];
+    par_round_slice_zeros(&mut single, 2);
+    assert_eq!(single, [-1300]);
+}
@@ -0,0 +1,180 @@
+use alloc::string::String;
+
+use super::{ParseLocaleError, ParseSfError};
+
+/// Parses a decimal number such as `1.20`, `-0.00340` or `1.2e3` and counts the significant
+/// figures implied by how it was written, the inverse of [`format_sf`](super::format_sf)
+///
+/// Leading zeros are never significant. Trailing zeros are significant when the number has a
+/// decimal point, so `1.20` and `1200.` have 3 and 4 significant figures, and aren't when it
+/// doesn't, so `1200` has 2. Only the digits before the exponent are counted, so `1.20e3` has 3.
+/// Zero written as `0` has 1 significant figure and each zero after the decimal point adds one,
+/// so `0.00` has 3 like `format_sf(0.0, 3)`
+///
+/// The text can have a leading `+` or `-` and whitespace around it, but not `inf`, `NaN` or
+/// digit separators. Numbers that don't fit in an f64 are an error rather than becoming infinite
+/// or zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{parse_sf, ParseSfError};
+///
+/// assert_eq!(parse_sf("1.20"), Ok((1.2, 3)));
+/// assert_eq!(parse_sf("0.00340"), Ok((0.0034, 3)));
+/// assert_eq!(parse_sf("-1.2e3"), Ok((-1200.0, 2)));
+/// assert_eq!(parse_sf("1200"), Ok((1200.0, 2)));
+/// assert_eq!(parse_sf("1200."), Ok((1200.0, 4)));
+/// assert_eq!(parse_sf("1,200"), Err(ParseSfError::InvalidCharacter { character: ',', index: 1 }));
+/// ```
+#[inline]
+pub fn parse_sf(text: &str) -> Result<(f64, u32), ParseSfError> {
+    let start = text.len() - text.trim_start().len();
+    let number = text.trim();
+    if number.is_empty() {
+        return Err(ParseSfError::Empty);
+    }
+
+    let mut digits = String::new();
+    let mut point = None;
+    let mut exponent: Option<String> = None;
+    for (index, character) in number.char_indices() {
+        match (character, &mut exponent) {
+            ('+' | '-', None) if index == 0 => {}
+            ('+' | '-', Some(exponent)) if exponent.is_empty() => exponent.push(character),
+            ('0'..='9', None) => digits.push(character),
+            ('0'..='9', Some(exponent)) => exponent.push(character),
+            ('.', None) if point.is_none() => point = Some(digits.len()),
+            ('e' | 'E', None) => exponent = Some(String::new()),
+            _ => {
+                return Err(ParseSfError::InvalidCharacter {
+                    character,
+                    index: start + index,
+                })
+            }
+        }
+    }
+    let exponent_missing =
+        exponent.is_some_and(|exponent| !exponent.ends_with(|c: char| c.is_ascii_digit()));
+    if digits.is_empty() || exponent_missing {
+        return Err(ParseSfError::MissingDigits);
+    }
+
+    let significant = digits.trim_start_matches('0');
+    let sig_figs = if significant.is_empty() {
+        1 + point.map_or(0, |point| digits.len() - point)
+    } else if point.is_some() {
+        significant.len()
+    } else {
+        significant.trim_end_matches('0').len()
+    };
+
+    let value: f64 = number.parse().map_err(|_| ParseSfError::OutOfRange)?;
+    if value.is_infinite() || (value == 0.0 && !significant.is_empty()) {
+        return Err(ParseSfError::OutOfRange);
+    }
+    Ok((value, sig_figs as u32))
+}
+
+/// Parses a number written with the given decimal separator and optional group separator, such
+/// as `1.234,56` with a decimal comma. The inverse of [`format_locale`](super::format_locale)
+///
+/// Group separators are optional, so `1234,56` is also accepted, but where they are used every
+/// group after the first must have exactly three digits and the first one to three, so
+/// `12.34.5,6` is rejected. Without a group separator any other separator is an invalid
+/// character, so a lone `,` in `1,5` is always the decimal separator when it is the one given.
+/// The text can have a leading `+` or `-` and whitespace around it, but not an exponent, `inf`
+/// or `NaN`. Numbers that don't fit in an f64 are an error rather than becoming infinite or zero
+///
+/// # Panics
+///
+/// If the separators are the same, or either is a digit, `+` or `-`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::parse_locale;
+///
+/// assert_eq!(parse_locale("1.234,56", ',', Some('.')), Ok(1234.56));
+/// assert_eq!(parse_locale("1234,56", ',', Some('.')), Ok(1234.56));
+/// assert_eq!(parse_locale("-1,234,567.5", '.', Some(',')), Ok(-1_234_567.5));
+/// assert_eq!(parse_locale("1,5", ',', None), Ok(1.5));
+/// assert!(parse_locale("12,34,5.6", '.', Some(',')).is_err());
+/// ```
+#[inline]
+pub fn parse_locale(
+    text: &str,
+    decimal_separator: char,
+    group_separator: Option<char>,
+) -> Result<f64, ParseLocaleError> {
+    let is_valid = |separator: char| !matches!(separator, '0'..='9' | '+' | '-');
+    assert!(
+        is_valid(decimal_separator) && group_separator.is_none_or(is_valid),
+        "separators can't be digits or signs"
+    );
+    assert!(
+        group_separator != Some(decimal_separator),
+        "separators must be different"
+    );
+
+    let start = text.len() - text.trim_start().len();
+    let number = text.trim();
+    if number.is_empty() {
+        return Err(ParseLocaleError::Empty);
+    }
+
+    let mut normalized = String::with_capacity(number.len());
+    let mut fraction = false;
+    // The digits since the start or the last group separator, and where that separator is
+    let mut group_digits = 0;
+    let mut last_separator = None;
+    let complete_group = |group_digits: usize, last_separator: Option<usize>| match last_separator {
+        Some(index) if group_digits != 3 => Err(ParseLocaleError::MisplacedSeparator { index }),
+        _ => Ok(()),
+    };
+
+    for (index, character) in number.char_indices() {
+        let index = start + index;
+        match character {
+            '+' | '-' if index == start => normalized.push(character),
+            '0'..='9' => {
+                normalized.push(character);
+                group_digits += 1;
+            }
+            _ if character == decimal_separator && !fraction => {
+                complete_group(group_digits, last_separator)?;
+                normalized.push('.');
+                fraction = true;
+            }
+            _ if Some(character) == group_separator => {
+                let first = last_separator.is_none();
+                if fraction || (first && !(1..=3).contains(&group_digits)) {
+                    return Err(ParseLocaleError::MisplacedSeparator { index });
+                }
+                if !first {
+                    complete_group(group_digits, last_separator)?;
+                }
+                group_digits = 0;
+                last_separator = Some(index);
+            }
+            _ => return Err(ParseLocaleError::InvalidCharacter { character, index }),
+        }
+    }
+    if !fraction {
+        complete_group(group_digits, last_separator)?;
+    }
+
+    if !normalized.contains(|c: char| c.is_ascii_digit()) {
+        return Err(ParseLocaleError::MissingDigits);
+    }
+    let value: f64 = normalized
+        .parse()
+        .map_err(|_| ParseLocaleError::OutOfRange)?;
+    let is_zero = !normalized.contains(|c: char| matches!(c, '1'..='9'));
+    if value.is_infinite() || (value == 0.0 && !is_zero) {
+        return Err(ParseLocaleError::OutOfRange);
+    }
+    Ok(value)
+}
+
+mod tests;
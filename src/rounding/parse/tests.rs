@@ -0,0 +1,223 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{format_locale, format_sf, format_sf_notation, round, Notation};
+
+#[test]
+fn test_parse_sf() {
+    let cases = [
+        ("1.20", 1.2, 3),
+        ("0.00340", 0.0034, 3),
+        ("1.2e3", 1200.0, 2),
+        ("1.20E-3", 0.0012, 3),
+        ("1.2e+3", 1200.0, 2),
+        ("1200", 1200.0, 2),
+        ("1200.", 1200.0, 4),
+        ("1200.0", 1200.0, 5),
+        ("1.200e3", 1200.0, 4),
+        ("100e2", 10_000.0, 1),
+        ("1020", 1020.0, 3),
+        ("0.05", 0.05, 1),
+        (".5", 0.5, 1),
+        ("007", 7.0, 1),
+        ("-2.50", -2.5, 3),
+        ("+42", 42.0, 2),
+        ("  6.0  ", 6.0, 2),
+        ("0", 0.0, 1),
+        ("0.", 0.0, 1),
+        ("0.00", 0.0, 3),
+        ("-0.0e5", -0.0, 2),
+        ("123456789012345678901234567890", 1.2345678901234568e29, 29),
+    ];
+    for (text, value, sig_figs) in cases {
+        assert_eq!(parse_sf(text), Ok((value, sig_figs)), "{text}");
+    }
+    assert!(parse_sf("-0").unwrap().0.is_sign_negative());
+}
+
+#[test]
+fn test_parse_sf_errors() {
+    let invalid = |character, index| ParseSfError::InvalidCharacter { character, index };
+    let cases = [
+        ("", ParseSfError::Empty),
+        ("   ", ParseSfError::Empty),
+        ("-", ParseSfError::MissingDigits),
+        (".", ParseSfError::MissingDigits),
+        ("e3", ParseSfError::MissingDigits),
+        ("1e", ParseSfError::MissingDigits),
+        ("1e-", ParseSfError::MissingDigits),
+        ("abc", invalid('a', 0)),
+        (" 1.2.3", invalid('.', 4)),
+        ("1,200", invalid(',', 1)),
+        ("1 200", invalid(' ', 1)),
+        ("--1", invalid('-', 1)),
+        ("1e3.5", invalid('.', 3)),
+        ("1e3e4", invalid('e', 3)),
+        ("1e+-3", invalid('-', 3)),
+        ("inf", invalid('i', 0)),
+        ("NaN", invalid('N', 0)),
+        ("1.2µ", invalid('µ', 3)),
+        ("1e309", ParseSfError::OutOfRange),
+        ("-1e400", ParseSfError::OutOfRange),
+        ("1e-400", ParseSfError::OutOfRange),
+    ];
+    for (text, error) in cases {
+        assert_eq!(parse_sf(text), Err(error), "{text}");
+    }
+    assert_eq!(parse_sf("0e-400"), Ok((0.0, 1)));
+}
+
+#[test]
+fn test_parse_sf_round_trip() {
+    let mut state = 0x5eed_f165_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let sig_figs = (state % 15) as u32 + 1;
+        let exponent = (state >> 8) % 40;
+        let number =
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent as i32 - 20);
+
+        // Scientific notation always shows exactly the significant figures
+        let scientific = format_sf_notation(number, sig_figs, Notation::Scientific);
+        let (value, parsed) = parse_sf(&scientific).unwrap();
+        assert_eq!(parsed, sig_figs, "{scientific}");
+        assert_eq!(value, scientific.parse::<f64>().unwrap());
+
+        // Fixed notation pads large integer parts with zeros that can't be told apart from
+        // significant ones, which only lowers the count
+        let fixed = format_sf(number, sig_figs);
+        let (value, parsed) = parse_sf(&fixed).unwrap();
+        assert!(parsed <= sig_figs, "{fixed}");
+        if fixed.contains('.') {
+            assert_eq!(parsed, sig_figs, "{fixed}");
+        }
+        assert_eq!(value, fixed.parse::<f64>().unwrap());
+    }
+}
+
+#[test]
+fn test_parse_locale() {
+    let cases = [
+        ("1.234,56", ',', Some('.'), 1234.56),
+        ("1234,56", ',', Some('.'), 1234.56),
+        ("1,234.56", '.', Some(','), 1234.56),
+        ("1 234 567,8", ',', Some(' '), 1_234_567.8),
+        ("1'234'567.8", '.', Some('\''), 1_234_567.8),
+        ("12.345.678", ',', Some('.'), 12_345_678.0),
+        ("123", ',', Some('.'), 123.0),
+        ("-1.000", ',', Some('.'), -1000.0),
+        ("+0,5", ',', Some('.'), 0.5),
+        (",5", ',', Some('.'), 0.5),
+        ("5,", ',', Some('.'), 5.0),
+        ("  7,25 ", ',', None, 7.25),
+        ("1,5", ',', None, 1.5),
+        ("1.5", '.', None, 1.5),
+        ("0,000", ',', None, 0.0),
+    ];
+    for (text, decimal_separator, group_separator, expected) in cases {
+        assert_eq!(
+            parse_locale(text, decimal_separator, group_separator),
+            Ok(expected),
+            "{text}"
+        );
+    }
+    assert!(parse_locale("-0,0", ',', None).unwrap().is_sign_negative());
+}
+
+#[test]
+fn test_parse_locale_errors() {
+    let invalid = |character, index| ParseLocaleError::InvalidCharacter { character, index };
+    let misplaced = |index| ParseLocaleError::MisplacedSeparator { index };
+    let cases = [
+        ("", ',', Some('.'), ParseLocaleError::Empty),
+        (" \t", ',', None, ParseLocaleError::Empty),
+        ("-", ',', None, ParseLocaleError::MissingDigits),
+        ("+,", ',', Some('.'), ParseLocaleError::MissingDigits),
+        ("12,34,5.6", '.', Some(','), misplaced(2)),
+        ("1,23.4", '.', Some(','), misplaced(1)),
+        ("1,2345", '.', Some(','), misplaced(1)),
+        ("1234,567", '.', Some(','), misplaced(4)),
+        (",123", '.', Some(','), misplaced(0)),
+        ("1,,234", '.', Some(','), misplaced(1)),
+        ("1,234,", '.', Some(','), misplaced(5)),
+        ("1.5,000", '.', Some(','), misplaced(3)),
+        (" 1.234,5", '.', Some(','), misplaced(6)),
+        ("1,5", '.', None, invalid(',', 1)),
+        ("1,234.5", ',', None, invalid('.', 5)),
+        ("1,2,3", ',', None, invalid(',', 3)),
+        ("1e3", '.', None, invalid('e', 1)),
+        ("--1", '.', None, invalid('-', 1)),
+        ("1-", '.', None, invalid('-', 1)),
+        ("inf", '.', None, invalid('i', 0)),
+        ("1,5 €", ',', Some('.'), invalid(' ', 3)),
+        (
+            "1000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+             000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+             000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+             000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            '.',
+            None,
+            ParseLocaleError::OutOfRange,
+        ),
+    ];
+    for (text, decimal_separator, group_separator, error) in cases {
+        assert_eq!(
+            parse_locale(text, decimal_separator, group_separator),
+            Err(error),
+            "{text}"
+        );
+    }
+
+    let tiny = format!("0.{}1", "0".repeat(400));
+    assert_eq!(
+        parse_locale(&tiny, '.', None),
+        Err(ParseLocaleError::OutOfRange)
+    );
+}
+
+#[test]
+#[should_panic(expected = "separators must be different")]
+fn test_parse_locale_same_separators() {
+    let _ = parse_locale("1,5", ',', Some(','));
+}
+
+#[test]
+#[should_panic(expected = "separators can't be digits or signs")]
+fn test_parse_locale_digit_separator() {
+    let _ = parse_locale("105", '0', None);
+}
+
+#[test]
+fn test_format_parse_locale_round_trip() {
+    let conventions = [
+        (',', Some('.')),
+        ('.', Some(',')),
+        (',', Some(' ')),
+        ('.', Some('\'')),
+        (',', None),
+        ('.', None),
+    ];
+    for (decimal_separator, group_separator) in conventions {
+        for exponent in -6..=15 {
+            for mantissa in [1.0, -1.5, 2.345_678, -9.999_999, 5.0] {
+                let number = mantissa * 10_f64.powi(exponent);
+                for decimal_places in 0..=6 {
+                    let formatted =
+                        format_locale(number, decimal_places, decimal_separator, group_separator);
+                    let parsed = parse_locale(&formatted, decimal_separator, group_separator)
+                        .unwrap_or_else(|error| panic!("{formatted}: {error}"));
+                    assert_eq!(parsed, round(number, decimal_places), "{formatted}");
+                }
+            }
+        }
+
+        let formatted = format_locale(-1_234_567_i64, 2, decimal_separator, group_separator);
+        assert_eq!(
+            parse_locale(&formatted, decimal_separator, group_separator),
+            Ok(-1_234_567.0)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+/// Numbers that can be rounded to a power of two
+///
+/// Implemented for the unsigned integers and floats. Rounding to the nearest power goes by
+/// the distance between the number and the powers either side of it, so `3` is exactly halfway
+/// between `2` and `4` and ties go to the larger power
+pub trait PowerOfTwo: Sized {
+    /// Rounds the number to the nearest power of two, ties go to the larger power
+    ///
+    /// Zero rounds to one for integers
+    ///
+    /// # Panics
+    ///
+    /// If the result doesn't fit in an integer type, or a float isn't positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(5_u32.round_pow2(), 4_u32);
+    /// assert_eq!(6_u32.round_pow2(), 8_u32);
+    /// assert_eq!(0.3_f64.round_pow2(), 0.25_f64);
+    /// ```
+    fn round_pow2(self) -> Self;
+
+    /// Rounds the number up to a power of two, a power of two stays the same
+    ///
+    /// Zero rounds up to one for integers
+    ///
+    /// # Panics
+    ///
+    /// If the result doesn't fit in an integer type, or a float isn't positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(5_u32.ceil_pow2(), 8_u32);
+    /// assert_eq!(8_u32.ceil_pow2(), 8_u32);
+    /// assert_eq!(0.3_f64.ceil_pow2(), 0.5_f64);
+    /// ```
+    fn ceil_pow2(self) -> Self;
+
+    /// Rounds the number down to a power of two, a power of two stays the same
+    ///
+    /// # Panics
+    ///
+    /// If an integer is zero, or a float isn't positive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(7_u32.floor_pow2(), 4_u32);
+    /// assert_eq!(8_u32.floor_pow2(), 8_u32);
+    /// assert_eq!(0.3_f64.floor_pow2(), 0.25_f64);
+    /// ```
+    fn floor_pow2(self) -> Self;
+
+    /// Rounds the number to the nearest power of two, ties go to the larger power,
+    /// returning `None` if the result doesn't fit in the type or a float isn't positive and finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(3_u8.checked_round_pow2(), Some(4_u8));
+    /// assert_eq!(200_u8.checked_round_pow2(), None);
+    /// assert_eq!((-3.0_f64).checked_round_pow2(), None);
+    /// ```
+    fn checked_round_pow2(self) -> Option<Self>;
+
+    /// Rounds the number up to a power of two,
+    /// returning `None` if the result doesn't fit in the type or a float isn't positive and finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(0_u32.checked_ceil_pow2(), Some(1_u32));
+    /// assert_eq!(u32::MAX.checked_ceil_pow2(), None);
+    /// assert_eq!(f64::MAX.checked_ceil_pow2(), None);
+    /// ```
+    fn checked_ceil_pow2(self) -> Option<Self>;
+
+    /// Rounds the number down to a power of two,
+    /// returning `None` if the number is zero or a float isn't positive and finite
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::*;
+    ///
+    /// assert_eq!(u32::MAX.checked_floor_pow2(), Some(1_u32 << 31));
+    /// assert_eq!(0_u32.checked_floor_pow2(), None);
+    /// assert_eq!(0.0_f64.checked_floor_pow2(), None);
+    /// ```
+    fn checked_floor_pow2(self) -> Option<Self>;
+}
+
+/// Rounds the number to the nearest power of two, ties go to the larger power
+///
+/// Zero rounds to one for integers
+///
+/// # Panics
+///
+/// If the result doesn't fit in an integer type, or a float isn't positive
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_pow2;
+///
+/// assert_eq!(round_pow2(3_u8), 4_u8);
+/// assert_eq!(round_pow2(1000_u64), 1024_u64);
+/// assert_eq!(round_pow2(0.7_f32), 0.5_f32);
+/// ```
+#[inline]
+pub fn round_pow2<T: PowerOfTwo>(number: T) -> T {
+    number.round_pow2()
+}
+
+/// Rounds the number up to a power of two, a power of two stays the same
+///
+/// Zero rounds up to one for integers
+///
+/// # Panics
+///
+/// If the result doesn't fit in an integer type, or a float isn't positive
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_pow2;
+///
+/// assert_eq!(ceil_pow2(3_u8), 4_u8);
+/// assert_eq!(ceil_pow2(1024_u64), 1024_u64);
+/// assert_eq!(ceil_pow2(0.3_f32), 0.5_f32);
+/// ```
+#[inline]
+pub fn ceil_pow2<T: PowerOfTwo>(number: T) -> T {
+    number.ceil_pow2()
+}
+
+/// Rounds the number down to a power of two, a power of two stays the same
+///
+/// # Panics
+///
+/// If an integer is zero, or a float isn't positive
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_pow2;
+///
+/// assert_eq!(floor_pow2(3_u8), 2_u8);
+/// assert_eq!(floor_pow2(1025_u64), 1024_u64);
+/// assert_eq!(floor_pow2(3.0_f32), 2_f32);
+/// ```
+#[inline]
+pub fn floor_pow2<T: PowerOfTwo>(number: T) -> T {
+    number.floor_pow2()
+}
+
+/// Rounds the number to the nearest power of two, ties go to the larger power,
+/// returning `None` if the result doesn't fit in the type or a float isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_round_pow2;
+///
+/// assert_eq!(checked_round_pow2(191_u8), Some(128_u8));
+/// assert_eq!(checked_round_pow2(192_u8), None);
+/// assert_eq!(checked_round_pow2(f64::NAN), None);
+/// ```
+#[inline]
+pub fn checked_round_pow2<T: PowerOfTwo>(number: T) -> Option<T> {
+    number.checked_round_pow2()
+}
+
+/// Rounds the number up to a power of two,
+/// returning `None` if the result doesn't fit in the type or a float isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_ceil_pow2;
+///
+/// assert_eq!(checked_ceil_pow2(128_u8), Some(128_u8));
+/// assert_eq!(checked_ceil_pow2(129_u8), None);
+/// assert_eq!(checked_ceil_pow2(0.0_f64), None);
+/// ```
+#[inline]
+pub fn checked_ceil_pow2<T: PowerOfTwo>(number: T) -> Option<T> {
+    number.checked_ceil_pow2()
+}
+
+/// Rounds the number down to a power of two,
+/// returning `None` if the number is zero or a float isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::checked_floor_pow2;
+///
+/// assert_eq!(checked_floor_pow2(255_u8), Some(128_u8));
+/// assert_eq!(checked_floor_pow2(0_u8), None);
+/// assert_eq!(checked_floor_pow2(f64::INFINITY), None);
+/// ```
+#[inline]
+pub fn checked_floor_pow2<T: PowerOfTwo>(number: T) -> Option<T> {
+    number.checked_floor_pow2()
+}
+
+macro_rules! impl_power_of_two_integer {
+    ($($t:ty),*) => {
+        $(
+            impl PowerOfTwo for $t {
+                #[inline]
+                fn round_pow2(self) -> $t {
+                    self.checked_round_pow2()
+                        .expect("nearest power of two doesn't fit in the type")
+                }
+
+                #[inline]
+                fn ceil_pow2(self) -> $t {
+                    self.checked_ceil_pow2()
+                        .expect("next power of two doesn't fit in the type")
+                }
+
+                #[inline]
+                fn floor_pow2(self) -> $t {
+                    self.checked_floor_pow2()
+                        .expect("zero has no power of two below it")
+                }
+
+                #[inline]
+                fn checked_round_pow2(self) -> Option<$t> {
+                    let Some(below) = self.checked_floor_pow2() else {
+                        return Some(1);
+                    };
+                    if self == below || self - below < below - (self - below) {
+                        Some(below)
+                    } else {
+                        below.checked_mul(2)
+                    }
+                }
+
+                #[inline]
+                fn checked_ceil_pow2(self) -> Option<$t> {
+                    self.checked_next_power_of_two()
+                }
+
+                #[inline]
+                fn checked_floor_pow2(self) -> Option<$t> {
+                    self.checked_ilog2().map(|exponent| 1 << exponent)
+                }
+            }
+        )*
+    };
+}
+
+impl_power_of_two_integer!(u8, u16, u32, u64);
+
+macro_rules! impl_power_of_two_float {
+    ($($t:ty, $bits:ty, $mantissa_bits:expr);*) => {
+        $(
+            impl PowerOfTwo for $t {
+                #[inline]
+                fn round_pow2(self) -> $t {
+                    let below = self.floor_pow2();
+                    // Both distances are exact as the number is within a factor of two of the power,
+                    // and the distance above is found without doubling so it can't overflow
+                    let distance = self - below;
+                    if distance < below - distance {
+                        below
+                    } else {
+                        below * 2.0
+                    }
+                }
+
+                #[inline]
+                fn ceil_pow2(self) -> $t {
+                    let below = self.floor_pow2();
+                    if self == below {
+                        below
+                    } else {
+                        below * 2.0
+                    }
+                }
+
+                #[inline]
+                fn floor_pow2(self) -> $t {
+                    assert!(self > 0.0, "number must be positive");
+                    if !self.is_finite() {
+                        return self;
+                    }
+
+                    let bits = self.to_bits();
+                    let mantissa_mask: $bits = (1 << $mantissa_bits) - 1;
+                    if bits & !mantissa_mask == 0 {
+                        // Subnormal numbers are a power of two at the position of their highest bit
+                        <$t>::from_bits(1 << bits.ilog2())
+                    } else {
+                        <$t>::from_bits(bits & !mantissa_mask)
+                    }
+                }
+
+                #[inline]
+                fn checked_round_pow2(self) -> Option<$t> {
+                    (self > 0.0 && self.is_finite())
+                        .then(|| self.round_pow2())
+                        .filter(|rounded| rounded.is_finite())
+                }
+
+                #[inline]
+                fn checked_ceil_pow2(self) -> Option<$t> {
+                    (self > 0.0 && self.is_finite())
+                        .then(|| self.ceil_pow2())
+                        .filter(|rounded| rounded.is_finite())
+                }
+
+                #[inline]
+                fn checked_floor_pow2(self) -> Option<$t> {
+                    (self > 0.0 && self.is_finite()).then(|| self.floor_pow2())
+                }
+            }
+        )*
+    };
+}
+
+impl_power_of_two_float!(f32, u32, 23; f64, u64, 52);
+
+mod tests;
@@ -0,0 +1,138 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+macro_rules! check_unsigned {
+    ($($t:ty),*) => {
+        $(
+            assert_eq!(round_pow2(0 as $t), 1);
+            assert_eq!(ceil_pow2(0 as $t), 1);
+            assert_eq!(checked_floor_pow2(0 as $t), None);
+            assert_eq!(round_pow2(1 as $t), 1);
+            assert_eq!(floor_pow2(1 as $t), 1);
+            assert_eq!(ceil_pow2(1 as $t), 1);
+
+            // 3 is halfway between 2 and 4
+            assert_eq!(round_pow2(3 as $t), 4);
+            assert_eq!(round_pow2(5 as $t), 4);
+            assert_eq!(round_pow2(6 as $t), 8);
+            assert_eq!(ceil_pow2(5 as $t), 8);
+            assert_eq!(floor_pow2(7 as $t), 4);
+
+            for exponent in 2..<$t>::BITS {
+                let power = (1 as $t) << exponent;
+                assert_eq!(round_pow2(power), power);
+                assert_eq!(ceil_pow2(power), power);
+                assert_eq!(floor_pow2(power), power);
+                assert_eq!(ceil_pow2(power - 1), power);
+                assert_eq!(floor_pow2(power - 1), power / 2);
+                assert_eq!(checked_ceil_pow2(power + 1), power.checked_mul(2));
+                assert_eq!(floor_pow2(power + 1), power);
+            }
+
+            let top = (1 as $t) << (<$t>::BITS - 1);
+            assert_eq!(checked_ceil_pow2(<$t>::MAX), None);
+            assert_eq!(checked_round_pow2(<$t>::MAX), None);
+            assert_eq!(checked_round_pow2(top + top / 2 - 1), Some(top));
+            assert_eq!(checked_round_pow2(top + top / 2), None);
+            assert_eq!(floor_pow2(<$t>::MAX), top);
+        )*
+    };
+}
+
+#[test]
+fn test_unsigned() {
+    check_unsigned!(u8, u16, u32, u64);
+}
+
+#[test]
+fn test_unsigned_matches_std() {
+    for number in 0..=u16::MAX {
+        assert_eq!(
+            checked_ceil_pow2(number),
+            number.checked_next_power_of_two()
+        );
+        let Some(below) = checked_floor_pow2(number) else {
+            continue;
+        };
+        assert!(below <= number && (number as u32) < below as u32 * 2);
+        let rounded = round_pow2(number as u32);
+        let distance = |power: u32| power.abs_diff(number as u32);
+        assert!(distance(rounded) <= distance(below as u32));
+        assert!(distance(rounded) <= distance(below as u32 * 2));
+    }
+}
+
+#[test]
+fn test_f64() {
+    assert_eq!(round_pow2(3.0_f64), 4.0);
+    assert_eq!(round_pow2(2.99_f64), 2.0);
+    assert_eq!(ceil_pow2(2.01_f64), 4.0);
+    assert_eq!(floor_pow2(3.99_f64), 2.0);
+    assert_eq!(round_pow2(0.375_f64), 0.5);
+    assert_eq!(round_pow2(0.374_f64), 0.25);
+    assert_eq!(ceil_pow2(0.1_f64), 0.125);
+    assert_eq!(floor_pow2(0.1_f64), 0.0625);
+    assert_eq!(round_pow2(f64::MIN_POSITIVE * 3.0), f64::MIN_POSITIVE * 4.0);
+
+    for exponent in -1074..=1023 {
+        let power = if exponent < -1022 {
+            f64::from_bits(1 << (exponent + 1074))
+        } else {
+            f64::from_bits(((exponent + 1023) as u64) << 52)
+        };
+        assert_eq!(round_pow2(power), power);
+        assert_eq!(ceil_pow2(power), power);
+        assert_eq!(floor_pow2(power), power);
+        if exponent > -1073 {
+            let above = f64::from_bits(power.to_bits() + 1);
+            assert_eq!(floor_pow2(above), power);
+            assert_eq!(round_pow2(above), power);
+            assert_eq!(
+                checked_ceil_pow2(above),
+                Some(power * 2.0).filter(|p| p.is_finite())
+            );
+        }
+    }
+
+    // Subnormals
+    assert_eq!(floor_pow2(f64::from_bits(7)), f64::from_bits(4));
+    assert_eq!(ceil_pow2(f64::from_bits(5)), f64::from_bits(8));
+    assert_eq!(round_pow2(f64::from_bits(6)), f64::from_bits(8));
+
+    assert_eq!(checked_ceil_pow2(f64::MAX), None);
+    assert_eq!(checked_round_pow2(f64::MAX), None);
+    assert_eq!(floor_pow2(f64::MAX), 2.0_f64.powi(1023));
+    assert_eq!(ceil_pow2(f64::MAX), f64::INFINITY);
+    assert_eq!(checked_floor_pow2(0.0_f64), None);
+    assert_eq!(checked_floor_pow2(-1.0_f64), None);
+    assert_eq!(checked_floor_pow2(f64::NAN), None);
+    assert_eq!(floor_pow2(f64::INFINITY), f64::INFINITY);
+}
+
+#[test]
+fn test_f32() {
+    assert_eq!(round_pow2(3.0_f32), 4.0);
+    assert_eq!(round_pow2(1000.0_f32), 1024.0);
+    assert_eq!(floor_pow2(f32::from_bits(3)), f32::from_bits(2));
+    assert_eq!(checked_ceil_pow2(f32::MAX), None);
+    assert_eq!(floor_pow2(f32::MAX), 2.0_f32.powi(127));
+}
+
+#[test]
+#[should_panic(expected = "zero has no power of two below it")]
+fn test_floor_zero() {
+    floor_pow2(0_u32);
+}
+
+#[test]
+#[should_panic(expected = "next power of two doesn't fit in the type")]
+fn test_ceil_overflow() {
+    ceil_pow2(u32::MAX);
+}
+
+#[test]
+#[should_panic(expected = "number must be positive")]
+fn test_float_negative() {
+    round_pow2(-3.0_f64);
+}
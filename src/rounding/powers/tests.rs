@@ -0,0 +1,43 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_tables_match_powi() {
+    for (exponent, &power) in POW10_F64.iter().enumerate() {
+        assert_eq!(power.to_bits(), 10_f64.powi(exponent as i32).to_bits());
+        assert_eq!(power, format!("1e{exponent}").parse::<f64>().unwrap());
+    }
+    for (exponent, &power) in POW10_F32.iter().enumerate() {
+        assert_eq!(power.to_bits(), 10_f32.powi(exponent as i32).to_bits());
+        assert_eq!(power, format!("1e{exponent}").parse::<f32>().unwrap());
+    }
+    for (exponent, &power) in POW10_U128.iter().enumerate() {
+        assert_eq!(Some(power), 10_u128.checked_pow(exponent as u32));
+    }
+}
+
+#[test]
+fn test_ten() {
+    for exponent in -400..400 {
+        let expected = format!("1e{exponent}").parse::<f64>().unwrap();
+        if (-22..=22).contains(&exponent) {
+            assert_eq!(ten_f64(exponent), expected, "{exponent}");
+        } else if (-300..=308).contains(&exponent) {
+            let error = (ten_f64(exponent) - expected).abs();
+            assert!(error <= expected * f64::EPSILON * 4.0, "{exponent}");
+        }
+
+        let expected = format!("1e{exponent}").parse::<f32>().unwrap();
+        if (-10..=10).contains(&exponent) {
+            assert_eq!(ten_f32(exponent), expected, "{exponent}");
+        }
+    }
+    assert_eq!(ten_f64(i32::MAX), f64::INFINITY);
+    assert_eq!(ten_f64(i32::MIN), 0.0);
+    assert_eq!(ten_f32(39), f32::INFINITY);
+
+    for exponent in 0..50 {
+        assert_eq!(ten_u128(exponent), 10_u128.checked_pow(exponent));
+        assert_eq!(ten_u64(exponent), 10_u64.checked_pow(exponent));
+    }
+}
@@ -0,0 +1,202 @@
+use super::{Float, Roundable};
+
+/// Rounding for references to numbers, returning the rounded number the reference points to
+///
+/// [`Float`] and [`Roundable`] can't be implemented for `&T` as every method returns `Self`, so
+/// this trait is implemented for `&T` instead with each method rounding a copy of the number like
+/// the method of the same name. Generic code bounded on it takes references as they come out of
+/// [`iter`](slice::iter) without copying them first
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::RoundRefExt;
+///
+/// fn rounded_total<'a, I>(values: I) -> f64
+/// where
+///     I: IntoIterator<Item = &'a f64>,
+/// {
+///     values.into_iter().map(|value| value.round_dp(1)).sum()
+/// }
+///
+/// assert_eq!(rounded_total(&[1.04, 2.06]), 3.1);
+/// assert_eq!(rounded_total(vec![&0.25, &-0.25]), 0.0);
+/// ```
+pub trait RoundRefExt {
+    /// The number the reference points to, which the methods return
+    type Number;
+
+    /// Rounds the number to the given number of decimal places like [`Float::round_dp`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// assert_eq!((&2.675_f64).round_dp(2), 2.68);
+    /// ```
+    fn round_dp(self, decimal_places: u32) -> Self::Number
+    where
+        Self::Number: Float;
+
+    /// Rounds the number up to the given number of decimal places like [`Float::ceil_dp`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// assert_eq!((&1.201_f32).ceil_dp(2), 1.21);
+    /// ```
+    fn ceil_dp(self, decimal_places: u32) -> Self::Number
+    where
+        Self::Number: Float;
+
+    /// Rounds the number down to the given number of decimal places like [`Float::floor_dp`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// assert_eq!((&-1.201_f64).floor_dp(2), -1.21);
+    /// ```
+    fn floor_dp(self, decimal_places: u32) -> Self::Number
+    where
+        Self::Number: Float;
+
+    /// Rounds the number to the given number of decimal places like
+    /// [`Roundable::round_decimals`], for integers as well as floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// assert_eq!((&1.25_f64).round_decimals(1), 1.3);
+    /// assert_eq!((&125_i32).round_decimals(1), 125);
+    /// ```
+    fn round_decimals(self, decimal_places: u32) -> Self::Number;
+
+    /// Rounds the number up to the given number of decimal places like
+    /// [`Roundable::ceil_decimals`]
+    fn ceil_decimals(self, decimal_places: u32) -> Self::Number;
+
+    /// Rounds the number down to the given number of decimal places like
+    /// [`Roundable::floor_decimals`]
+    fn floor_decimals(self, decimal_places: u32) -> Self::Number;
+
+    /// Rounds the number to the given number of zeros like [`Roundable::round_zeros`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// let rounded: Vec<u32> = [1234_u32, 5678].iter().map(|count| count.round_zeros(2)).collect();
+    /// assert_eq!(rounded, [1200, 5700]);
+    /// ```
+    fn round_zeros(self, zeros: u32) -> Self::Number;
+
+    /// Rounds the number up to the given number of zeros like [`Roundable::ceil_zeros`]
+    fn ceil_zeros(self, zeros: u32) -> Self::Number;
+
+    /// Rounds the number down to the given number of zeros like [`Roundable::floor_zeros`]
+    fn floor_zeros(self, zeros: u32) -> Self::Number;
+
+    /// Rounds the number to the given number of significant figures like
+    /// [`Roundable::round_sf`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::RoundRefExt;
+    ///
+    /// assert_eq!((&-5.678_f64).round_sf(1), -6.0);
+    /// assert_eq!((&1250_i64).round_sf(2), 1300);
+    /// ```
+    fn round_sf(self, sig_figs: u32) -> Self::Number;
+
+    /// Rounds the number up to the given number of significant figures like
+    /// [`Roundable::ceil_sf`]
+    fn ceil_sf(self, sig_figs: u32) -> Self::Number;
+
+    /// Rounds the number down to the given number of significant figures like
+    /// [`Roundable::floor_sf`]
+    fn floor_sf(self, sig_figs: u32) -> Self::Number;
+}
+
+impl<T: Roundable + Copy> RoundRefExt for &T {
+    type Number = T;
+
+    #[inline]
+    fn round_dp(self, decimal_places: u32) -> T
+    where
+        T: Float,
+    {
+        Float::round_dp(*self, decimal_places)
+    }
+
+    #[inline]
+    fn ceil_dp(self, decimal_places: u32) -> T
+    where
+        T: Float,
+    {
+        Float::ceil_dp(*self, decimal_places)
+    }
+
+    #[inline]
+    fn floor_dp(self, decimal_places: u32) -> T
+    where
+        T: Float,
+    {
+        Float::floor_dp(*self, decimal_places)
+    }
+
+    #[inline]
+    fn round_decimals(self, decimal_places: u32) -> T {
+        Roundable::round_decimals(*self, decimal_places)
+    }
+
+    #[inline]
+    fn ceil_decimals(self, decimal_places: u32) -> T {
+        Roundable::ceil_decimals(*self, decimal_places)
+    }
+
+    #[inline]
+    fn floor_decimals(self, decimal_places: u32) -> T {
+        Roundable::floor_decimals(*self, decimal_places)
+    }
+
+    #[inline]
+    fn round_zeros(self, zeros: u32) -> T {
+        Roundable::round_zeros(*self, zeros)
+    }
+
+    #[inline]
+    fn ceil_zeros(self, zeros: u32) -> T {
+        Roundable::ceil_zeros(*self, zeros)
+    }
+
+    #[inline]
+    fn floor_zeros(self, zeros: u32) -> T {
+        Roundable::floor_zeros(*self, zeros)
+    }
+
+    #[inline]
+    fn round_sf(self, sig_figs: u32) -> T {
+        Roundable::round_sf(*self, sig_figs)
+    }
+
+    #[inline]
+    fn ceil_sf(self, sig_figs: u32) -> T {
+        Roundable::ceil_sf(*self, sig_figs)
+    }
+
+    #[inline]
+    fn floor_sf(self, sig_figs: u32) -> T {
+        Roundable::floor_sf(*self, sig_figs)
+    }
+}
+
+mod tests;
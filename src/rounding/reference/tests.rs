@@ -0,0 +1,101 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::Fixed;
+
+#[test]
+fn test_agrees_with_owned() {
+    for value in [1.234_f64, -5.678, 9.995, 0.0, -0.0, 1e-300, f64::MAX] {
+        let reference = &value;
+        assert_eq!(
+            reference.round_dp(2).to_bits(),
+            Float::round_dp(value, 2).to_bits()
+        );
+        assert_eq!(
+            reference.ceil_dp(2).to_bits(),
+            Float::ceil_dp(value, 2).to_bits()
+        );
+        assert_eq!(
+            reference.floor_dp(2).to_bits(),
+            Float::floor_dp(value, 2).to_bits()
+        );
+        assert_eq!(reference.round_decimals(1), value.round_decimals(1));
+        assert_eq!(reference.ceil_decimals(1), value.ceil_decimals(1));
+        assert_eq!(reference.floor_decimals(1), value.floor_decimals(1));
+        assert_eq!(reference.round_zeros(1), value.round_zeros(1));
+        assert_eq!(reference.ceil_zeros(1), value.ceil_zeros(1));
+        assert_eq!(reference.floor_zeros(1), value.floor_zeros(1));
+        assert_eq!(reference.round_sf(2), value.round_sf(2));
+        assert_eq!(reference.ceil_sf(2), value.ceil_sf(2));
+        assert_eq!(reference.floor_sf(2), value.floor_sf(2));
+    }
+    assert!((&f64::NAN).round_dp(2).is_nan());
+
+    for value in [1250_i32, -1250, i32::MAX, i32::MIN] {
+        let reference = &value;
+        assert_eq!(reference.round_zeros(2), value.round_zeros(2));
+        assert_eq!(reference.ceil_zeros(2), value.ceil_zeros(2));
+        assert_eq!(reference.floor_zeros(2), value.floor_zeros(2));
+        assert_eq!(reference.round_sf(2), value.round_sf(2));
+        assert_eq!(reference.ceil_sf(2), value.ceil_sf(2));
+        assert_eq!(reference.floor_sf(2), value.floor_sf(2));
+    }
+}
+
+#[test]
+fn test_generic() {
+    fn round_all<'a, I, T>(values: I, decimal_places: u32) -> Vec<T>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: Roundable + Copy + 'a,
+    {
+        values
+            .into_iter()
+            .map(|value| value.round_decimals(decimal_places))
+            .collect()
+    }
+    fn largest<R: RoundRefExt>(values: impl IntoIterator<Item = R>, sig_figs: u32) -> R::Number
+    where
+        R::Number: PartialOrd,
+    {
+        values
+            .into_iter()
+            .map(|value| value.round_sf(sig_figs))
+            .reduce(|a, b| if b > a { b } else { a })
+            .unwrap()
+    }
+
+    let values = vec![0.125_f32, 0.135, -0.125];
+    assert_eq!(round_all(&values, 2), [0.13, 0.14, -0.13]);
+    assert_eq!(round_all(&[1234_u32, 5678], 0), [1234, 5678]);
+    assert_eq!(largest(&values, 1), 0.1);
+    assert_eq!(largest(&[1234_i64, 1251], 2), 1300);
+
+    let prices = [
+        Fixed::<2>::from_f64_rounded(1.25),
+        Fixed::<2>::from_f64_rounded(-1.25),
+    ];
+    assert_eq!(
+        round_all(&prices, 1),
+        prices.map(|price| price.round_decimals(1))
+    );
+}
+
+#[test]
+fn test_float_references() {
+    fn total<T: Float + Copy + core::iter::Sum>(values: &[T], decimal_places: u32) -> T {
+        values
+            .iter()
+            .map(|value| value.round_dp(decimal_places))
+            .sum()
+    }
+    fn is_whole(value: &(impl Float + PartialEq + Copy)) -> bool {
+        value.floor_dp(0) == *value && value.ceil_dp(0) == *value
+    }
+
+    assert_eq!(total(&[1.04_f64, 2.06], 1), 3.1);
+    assert_eq!(total(&[0.25_f32, 0.7499], 2), 1.0);
+    assert!([2.0_f64, -3.0].iter().all(is_whole));
+    assert!(![2.5_f32, -3.0].iter().all(is_whole));
+}
@@ -0,0 +1,186 @@
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::Float;
+
+/// Rounds a stream of numbers to a number of decimal places, carrying each rounding error forward
+/// into the next number so the running total of the outputs never drifts from the running total of
+/// the inputs by more than half a step of `10^-decimal_places`
+///
+/// Numbers that aren't finite are returned as they are and don't change the carried error
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ResidualRounder;
+///
+/// let mut rounder = ResidualRounder::new(1);
+/// let rounded: Vec<f64> = [0.04; 5].iter().map(|&x| rounder.round_next(x)).collect();
+/// assert_eq!(rounded, [0.0, 0.1, 0.0, 0.1, 0.0]);
+/// assert!(rounder.residual().abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResidualRounder {
+    decimal_places: u32,
+    residual: f64,
+}
+
+impl ResidualRounder {
+    /// Makes a rounder to the given number of decimal places with no error carried
+    #[inline]
+    pub const fn new(decimal_places: u32) -> ResidualRounder {
+        ResidualRounder {
+            decimal_places,
+            residual: 0.0,
+        }
+    }
+
+    /// Adds the carried error to the number, rounds it and carries the new error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::ResidualRounder;
+    ///
+    /// let mut rounder = ResidualRounder::new(0);
+    /// assert_eq!(rounder.round_next(0.6), 1.0);
+    /// assert_eq!(rounder.round_next(0.6), 0.0);
+    /// assert_eq!(rounder.round_next(0.6), 1.0);
+    /// ```
+    #[inline]
+    pub fn round_next(&mut self, number: f64) -> f64 {
+        if !number.is_finite() {
+            return number;
+        }
+
+        let carried = number + self.residual;
+        let rounded = carried.round_dp(self.decimal_places);
+        self.residual = carried - rounded;
+        rounded
+    }
+
+    /// The error carried into the next number, the sum of the inputs so far minus the sum of the
+    /// outputs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::ResidualRounder;
+    ///
+    /// let mut rounder = ResidualRounder::new(0);
+    /// rounder.round_next(2.25);
+    /// assert_eq!(rounder.residual(), 0.25);
+    /// ```
+    #[inline]
+    pub const fn residual(&self) -> f64 {
+        self.residual
+    }
+
+    /// Drops the carried error, so the next number is rounded on its own
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::ResidualRounder;
+    ///
+    /// let mut rounder = ResidualRounder::new(0);
+    /// rounder.round_next(0.4);
+    /// rounder.reset();
+    /// assert_eq!(rounder.round_next(0.4), 0.0);
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        self.residual = 0.0;
+    }
+}
+
+/// Rounds a stream of numbers to whole numbers, carrying each rounding error forward into the next
+/// number so the running total of the outputs never drifts from the running total of the inputs
+/// by more than a half
+///
+/// Results outside the range of an `i64` saturate, and NaN gives 0. Numbers that aren't finite
+/// don't change the carried error
+///
+/// # Examples
+///
+/// Splitting 100 pixels between three columns
+///
+/// ```
+/// use common_math::rounding::IntegerResidualRounder;
+///
+/// let mut rounder = IntegerResidualRounder::new();
+/// let widths: Vec<i64> = [100.0 / 3.0; 3].iter().map(|&x| rounder.round_next(x)).collect();
+/// assert_eq!(widths, [33, 34, 33]);
+/// assert_eq!(widths.iter().sum::<i64>(), 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct IntegerResidualRounder {
+    residual: f64,
+}
+
+impl IntegerResidualRounder {
+    /// Makes a rounder with no error carried
+    #[inline]
+    pub const fn new() -> IntegerResidualRounder {
+        IntegerResidualRounder { residual: 0.0 }
+    }
+
+    /// Adds the carried error to the number, rounds it to a whole number and carries the new error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::IntegerResidualRounder;
+    ///
+    /// let mut rounder = IntegerResidualRounder::new();
+    /// assert_eq!(rounder.round_next(-1.5), -2);
+    /// assert_eq!(rounder.round_next(-1.5), -1);
+    /// assert_eq!(rounder.round_next(f64::INFINITY), i64::MAX);
+    /// ```
+    #[inline]
+    pub fn round_next(&mut self, number: f64) -> i64 {
+        if !number.is_finite() {
+            return number as i64;
+        }
+
+        let carried = number + self.residual;
+        let rounded = carried.round();
+        self.residual = carried - rounded;
+        rounded as i64
+    }
+
+    /// The error carried into the next number, the sum of the inputs so far minus the sum of the
+    /// outputs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::IntegerResidualRounder;
+    ///
+    /// let mut rounder = IntegerResidualRounder::new();
+    /// rounder.round_next(0.75);
+    /// assert_eq!(rounder.residual(), -0.25);
+    /// ```
+    #[inline]
+    pub const fn residual(&self) -> f64 {
+        self.residual
+    }
+
+    /// Drops the carried error, so the next number is rounded on its own
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::IntegerResidualRounder;
+    ///
+    /// let mut rounder = IntegerResidualRounder::new();
+    /// rounder.round_next(0.4);
+    /// rounder.reset();
+    /// assert_eq!(rounder.round_next(0.4), 0);
+    /// ```
+    #[inline]
+    pub fn reset(&mut self) {
+        self.residual = 0.0;
+    }
+}
+
+mod tests;
@@ -0,0 +1,62 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_cumulative_drift() {
+    let mut rounder = ResidualRounder::new(0);
+    let mut integer_rounder = IntegerResidualRounder::new();
+    let (mut inputs, mut outputs, mut integer_outputs) = (0.0, 0.0, 0);
+    for step in 0..100_000 {
+        let number = (step % 10) as f64 * 0.1;
+        inputs += number;
+        outputs += rounder.round_next(number);
+        integer_outputs += integer_rounder.round_next(number);
+        assert!((inputs - outputs).abs() <= 0.5 + 1e-6);
+        assert!((inputs - integer_outputs as f64).abs() <= 0.5 + 1e-6);
+        assert!(rounder.residual().abs() <= 0.5);
+    }
+
+    let mut rounder = ResidualRounder::new(2);
+    let mut total = 0.0;
+    for _ in 0..10_000 {
+        let rounded = rounder.round_next(0.1 / 3.0);
+        assert_eq!(rounded, rounded.round_dp(2));
+        total += rounded;
+    }
+    assert!((total - 10_000.0 * 0.1 / 3.0).abs() <= 0.005 + 1e-6);
+}
+
+#[test]
+fn test_column_widths() {
+    let mut rounder = IntegerResidualRounder::new();
+    let widths: Vec<i64> = [100.0 / 3.0; 3]
+        .iter()
+        .map(|&x| rounder.round_next(x))
+        .collect();
+    assert_eq!(widths, [33, 34, 33]);
+
+    // Seven columns sharing 1000 pixels by weight
+    rounder.reset();
+    let weights = [1.0, 2.0, 1.0, 3.0, 1.0, 1.0, 2.0];
+    let total: f64 = weights.iter().sum();
+    let widths: Vec<i64> = weights
+        .iter()
+        .map(|weight| rounder.round_next(weight / total * 1_000.0))
+        .collect();
+    assert_eq!(widths.iter().sum::<i64>(), 1_000);
+    assert_eq!(widths, [91, 182, 91, 272, 91, 91, 182]);
+}
+
+#[test]
+fn test_non_finite() {
+    let mut rounder = ResidualRounder::new(1);
+    rounder.round_next(0.04);
+    assert!(rounder.round_next(f64::NAN).is_nan());
+    assert_eq!(rounder.round_next(f64::NEG_INFINITY), f64::NEG_INFINITY);
+    assert_eq!(rounder.round_next(0.04), 0.1);
+
+    let mut rounder = IntegerResidualRounder::new();
+    assert_eq!(rounder.round_next(0.4), 0);
+    assert_eq!(rounder.round_next(f64::NAN), 0);
+    assert_eq!(rounder.residual(), 0.4);
+}
@@ -0,0 +1,193 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::Float;
+
+/// A float that is always rounded to `DP` decimal places
+///
+/// The number is rounded with [`round_dp`](Float::round_dp) when it is created and again after
+/// every arithmetic operation, so a `Rounded<2>` holds a 2 decimal place value by construction.
+/// Zero is stored without a sign so equal values hash the same. NaN and infinities are kept as they
+/// are, and like the float they wrap NaN isn't equal to itself
+///
+/// It displays with exactly `DP` digits after the decimal point, and with the `serde` feature it
+/// serializes as that string and deserializes from a string or a number
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::Rounded;
+///
+/// let price = Rounded::<2>::new(19.999);
+/// assert_eq!(price.into_inner(), 20.0);
+/// assert_eq!(price.to_string(), "20.00");
+///
+/// let total = price * Rounded::new(3.0) - Rounded::new(0.019);
+/// assert_eq!(total.into_inner(), 59.98);
+/// assert_eq!(Rounded::<1>::new(0.1) + Rounded::new(0.2), Rounded::new(0.3));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rounded<const DP: u32>(f64);
+
+impl<const DP: u32> Rounded<DP> {
+    /// Rounds the number to `DP` decimal places
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Rounded;
+    ///
+    /// assert_eq!(Rounded::<2>::new(1.2345).into_inner(), 1.23);
+    /// assert_eq!(Rounded::<0>::new(-2.5).into_inner(), -3.0);
+    /// assert_eq!(Rounded::<1>::new(-0.01).into_inner().to_bits(), 0.0_f64.to_bits());
+    /// ```
+    #[inline]
+    pub fn new(number: f64) -> Rounded<DP> {
+        // Adding zero turns a negative zero into a positive one and leaves everything else alone
+        Rounded(number.round_dp(DP) + 0.0)
+    }
+
+    /// The rounded number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::rounding::Rounded;
+    ///
+    /// assert_eq!(Rounded::<3>::new(2.0 / 3.0).into_inner(), 0.667);
+    /// ```
+    #[inline]
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl<const DP: u32> From<f64> for Rounded<DP> {
+    #[inline]
+    fn from(number: f64) -> Rounded<DP> {
+        Rounded::new(number)
+    }
+}
+
+impl<const DP: u32> PartialEq for Rounded<DP> {
+    #[inline]
+    fn eq(&self, other: &Rounded<DP>) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<const DP: u32> PartialOrd for Rounded<DP> {
+    #[inline]
+    fn partial_cmp(&self, other: &Rounded<DP>) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<const DP: u32> Hash for Rounded<DP> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
+
+impl<const DP: u32> fmt::Display for Rounded<DP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The number is the closest float to a value with `DP` decimal places, so while the float
+        // can hold that many formatting its exact binary value to them gives back the value's digits
+        write!(f, "{:.*}", DP as usize, self.0)
+    }
+}
+
+impl<const DP: u32> Add for Rounded<DP> {
+    type Output = Rounded<DP>;
+
+    #[inline]
+    fn add(self, rhs: Rounded<DP>) -> Rounded<DP> {
+        Rounded::new(self.0 + rhs.0)
+    }
+}
+
+impl<const DP: u32> Sub for Rounded<DP> {
+    type Output = Rounded<DP>;
+
+    #[inline]
+    fn sub(self, rhs: Rounded<DP>) -> Rounded<DP> {
+        Rounded::new(self.0 - rhs.0)
+    }
+}
+
+impl<const DP: u32> Mul for Rounded<DP> {
+    type Output = Rounded<DP>;
+
+    #[inline]
+    fn mul(self, rhs: Rounded<DP>) -> Rounded<DP> {
+        Rounded::new(self.0 * rhs.0)
+    }
+}
+
+impl<const DP: u32> Div for Rounded<DP> {
+    type Output = Rounded<DP>;
+
+    #[inline]
+    fn div(self, rhs: Rounded<DP>) -> Rounded<DP> {
+        Rounded::new(self.0 / rhs.0)
+    }
+}
+
+impl<const DP: u32> Neg for Rounded<DP> {
+    type Output = Rounded<DP>;
+
+    #[inline]
+    fn neg(self) -> Rounded<DP> {
+        Rounded::new(-self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<const DP: u32> serde::Serialize for Rounded<DP> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const DP: u32> serde::Deserialize<'de> for Rounded<DP> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Rounded<DP>, D::Error> {
+        deserializer.deserialize_any(RoundedVisitor)
+    }
+}
+
+/// Reads a [`Rounded`] from the string it serializes as or from a number
+#[cfg(feature = "serde")]
+struct RoundedVisitor<const DP: u32>;
+
+#[cfg(feature = "serde")]
+impl<const DP: u32> serde::de::Visitor<'_> for RoundedVisitor<DP> {
+    type Value = Rounded<DP>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a number or a string holding a number")
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, number: f64) -> Result<Rounded<DP>, E> {
+        Ok(Rounded::new(number))
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, number: i64) -> Result<Rounded<DP>, E> {
+        Ok(Rounded::new(number as f64))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, number: u64) -> Result<Rounded<DP>, E> {
+        Ok(Rounded::new(number as f64))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, text: &str) -> Result<Rounded<DP>, E> {
+        text.parse()
+            .map(Rounded::new)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Str(text), &self))
+    }
+}
+
+mod tests;
@@ -0,0 +1,88 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use core::hash::BuildHasher;
+#[cfg(test)]
+use std::collections::hash_map::RandomState;
+
+#[test]
+fn test_arithmetic_chain() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut total = Rounded::<2>::new(100.0);
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let operand = Rounded::new((state >> 40) as f64 / 1e5 + 0.5);
+        total = match state % 4 {
+            0 => total + operand,
+            1 => total - operand,
+            2 => total * operand,
+            _ => total / operand,
+        };
+        if total.into_inner().abs() > 1e9 {
+            total = -Rounded::new(total.into_inner() / 1e9);
+        }
+
+        // Every intermediate value is the closest float to a 2 decimal place value
+        assert_eq!(total.into_inner(), total.into_inner().round_dp(2));
+        assert_eq!(
+            total.to_string().parse::<f64>().unwrap(),
+            total.into_inner()
+        );
+    }
+}
+
+#[test]
+fn test_equality() {
+    assert_eq!(
+        Rounded::<1>::new(0.1) + Rounded::new(0.2),
+        Rounded::new(0.3)
+    );
+    assert_eq!(Rounded::<1>::from(0.1 + 0.2), Rounded::from(0.3));
+    assert_ne!(Rounded::<2>::new(1.004), Rounded::new(1.006));
+    assert!(Rounded::<1>::new(1.04) < Rounded::new(1.06));
+    assert_ne!(Rounded::<1>::new(f64::NAN), Rounded::new(f64::NAN));
+
+    // Zero has no sign, so a negative result that rounds to zero hashes like zero
+    let hasher = RandomState::new();
+    let negative = -Rounded::<1>::new(0.01);
+    assert_eq!(negative, Rounded::new(0.0));
+    assert_eq!(
+        hasher.hash_one(negative),
+        hasher.hash_one(Rounded::<1>::new(0.0))
+    );
+    assert_eq!(
+        hasher.hash_one(Rounded::<1>::new(0.1) + Rounded::new(0.2)),
+        hasher.hash_one(Rounded::<1>::new(0.3))
+    );
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Rounded::<2>::new(1.5).to_string(), "1.50");
+    assert_eq!(Rounded::<3>::new(-2.0).to_string(), "-2.000");
+    assert_eq!(Rounded::<2>::new(-0.001).to_string(), "0.00");
+    assert_eq!(Rounded::<0>::new(2.5).to_string(), "3");
+    assert_eq!(Rounded::<4>::new(1e-5).to_string(), "0.0000");
+    assert_eq!(Rounded::<1>::new(f64::INFINITY).to_string(), "inf");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let price = Rounded::<2>::new(1.5);
+    assert_eq!(serde_json::to_string(&price).unwrap(), r#""1.50""#);
+    assert_eq!(
+        serde_json::from_str::<Rounded<2>>(r#""1.50""#).unwrap(),
+        price
+    );
+    assert_eq!(serde_json::from_str::<Rounded<2>>("1.499").unwrap(), price);
+    assert_eq!(
+        serde_json::from_str::<Rounded<2>>("2").unwrap(),
+        Rounded::new(2.0)
+    );
+    assert!(serde_json::from_str::<Rounded<2>>(r#""one""#).is_err());
+}
@@ -0,0 +1,419 @@
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{digits, powers, RoundingMode};
+#[cfg(feature = "simd")]
+use wide::{f32x8, f64x4, CmpGe, CmpLt};
+
+/// Rounding an f64 to more significant figures than this always gives back the same f64
+const MAX_SIG_FIGS_F64: i64 = 17;
+
+/// Rounding an f32 to more significant figures than this always gives back the same f32
+const MAX_SIG_FIGS_F32: i64 = 8;
+
+/// Every f64 at least this large is an integer
+const MIN_INTEGRAL_F64: f64 = 4_503_599_627_370_496.0;
+
+/// Every f32 at least this large is an integer
+const MIN_INTEGRAL_F32: f32 = 8_388_608.0;
+
+/// The f64s rounded together in an [`f64x4`] by [`DecimalPlacesF64::round_lanes`]
+#[cfg(feature = "simd")]
+const LANES_F64: usize = 4;
+
+/// The f32s rounded together in an [`f32x8`] by [`DecimalPlacesF32::round_lanes`]
+#[cfg(feature = "simd")]
+const LANES_F32: usize = 8;
+
+/// Scales a rounded integer back and checks the result is on the right side of the number for
+/// the directed modes. Near the limit of precision the scaled number can round onto the integer
+/// past it, so rounding up could otherwise give a value below the number
+#[inline]
+fn directed_f64(
+    number: f64,
+    rounded: f64,
+    mode: RoundingMode,
+    scale_back: impl Fn(f64) -> f64,
+) -> f64 {
+    let result = scale_back(rounded);
+    let (wrong_side, step) = match mode {
+        RoundingMode::Up => (result < number, 1.0),
+        RoundingMode::Down => (result > number, -1.0),
+        RoundingMode::TowardZero => (result.abs() > number.abs(), -number.signum()),
+        RoundingMode::AwayFromZero => (result.abs() < number.abs(), number.signum()),
+        _ => (false, 0.0),
+    };
+
+    if wrong_side {
+        // A step back to zero keeps the sign of the number
+        scale_back(rounded + step).copysign(number)
+    } else {
+        result
+    }
+}
+
+/// Scales a rounded integer back and checks the result is on the right side of the number for
+/// the directed modes
+#[inline]
+fn directed_f32(
+    number: f32,
+    rounded: f64,
+    mode: RoundingMode,
+    scale_back: impl Fn(f64) -> f32,
+) -> f32 {
+    let result = scale_back(rounded);
+    let (wrong_side, step) = match mode {
+        RoundingMode::Up => (result < number, 1.0),
+        RoundingMode::Down => (result > number, -1.0),
+        RoundingMode::TowardZero => (result.abs() > number.abs(), -number.signum()),
+        RoundingMode::AwayFromZero => (result.abs() < number.abs(), number.signum()),
+        _ => (false, 0.0),
+    };
+
+    if wrong_side {
+        // A step back to zero keeps the sign of the number
+        scale_back(rounded + step as f64).copysign(number)
+    } else {
+        result
+    }
+}
+
+/// Rounds a finite f64 scaled up by `10^decimal_places` and scales the result back down
+#[inline]
+pub(crate) fn decimal_places_f64(number: f64, decimal_places: u32, mode: RoundingMode) -> f64 {
+    DecimalPlacesF64::new(decimal_places).round(number, mode)
+}
+
+/// The powers of ten for rounding f64s to a number of decimal places, found once so they can be
+/// shared by every number rounded to it
+pub(crate) struct DecimalPlacesF64 {
+    decimal_places: u32,
+    /// `10^decimal_places` split into two factors, as 10^309 and above overflow
+    high: f64,
+    low: f64,
+}
+
+impl DecimalPlacesF64 {
+    #[inline]
+    pub(crate) fn new(decimal_places: u32) -> DecimalPlacesF64 {
+        // 10^309 and above overflow so the largest powers are applied in two steps
+        let (high, low) = if decimal_places > 300 {
+            (1e300, powers::ten_f64(decimal_places as i32 - 300))
+        } else {
+            (powers::ten_f64(decimal_places as i32), 1.0)
+        };
+
+        DecimalPlacesF64 {
+            decimal_places,
+            high,
+            low,
+        }
+    }
+
+    /// Rounds a finite f64 scaled up by `10^decimal_places` and scales the result back down
+    #[inline]
+    pub(crate) fn round(&self, number: f64, mode: RoundingMode) -> f64 {
+        let decimal_places = self.decimal_places;
+        if powers::min_digits_f64(number) as i64 + decimal_places as i64 > MAX_SIG_FIGS_F64 {
+            return number;
+        }
+
+        // Powers of ten up to 10^22 are exact so dividing by one is correctly rounded
+        if decimal_places <= 22 {
+            let power = self.high;
+            let scaled = number * power;
+
+            // A number that is already the closest float to a step is returned as is. The error
+            // in scaling could otherwise push it to the next step. Scaled past twice the first
+            // integral float, the floats either side of the number are further apart than the
+            // steps, so it is always the closest float to one
+            let nearest = scaled.round();
+            if scaled.abs() >= 2.0 * MIN_INTEGRAL_F64 || nearest / power == number {
+                return number;
+            }
+
+            // Close to the limit of the precision the error in scaling can reach half a step,
+            // so the neighbouring steps are checked as well
+            if scaled.abs() >= MIN_INTEGRAL_F64 / 2.0
+                && ((nearest - 1.0) / power == number || (nearest + 1.0) / power == number)
+            {
+                return number;
+            }
+            return directed_f64(number, mode.round_f64(scaled), mode, |rounded| {
+                rounded / power
+            });
+        }
+
+        // The rounded value has at most as many digits as the type can hold,
+        // so it is scaled back down through its decimal representation
+        let scaled = number * self.high * self.low;
+        if scaled.abs() >= 2.0 * MIN_INTEGRAL_F64
+            || digits::scale_down::<_, f64>(scaled.round(), decimal_places) == number
+        {
+            return number;
+        }
+        directed_f64(number, mode.round_f64(scaled), mode, |rounded| {
+            digits::scale_down(rounded, decimal_places)
+        })
+    }
+
+    /// Rounds the numbers with ties away from zero in [`f64x4`] vectors, returning the numbers at
+    /// the end too few to fill one
+    ///
+    /// Below half of the first integral float every scaled number has a fraction to round and
+    /// [`round`](Self::round) only scales, rounds and scales back, so a vector of those does the
+    /// same operations in lockstep. A vector with any other number is rounded one at a time
+    #[cfg(feature = "simd")]
+    #[inline]
+    pub(crate) fn round_lanes<'a>(&self, numbers: &'a mut [f64]) -> &'a mut [f64] {
+        if self.decimal_places > 22 {
+            return numbers;
+        }
+
+        let power = f64x4::splat(self.high);
+        let mut chunks = numbers.chunks_exact_mut(LANES_F64);
+        for chunk in &mut chunks {
+            let lanes: [f64; LANES_F64] = (&*chunk).try_into().unwrap();
+            let scaled = f64x4::new(lanes) * power;
+            let magnitude = scaled.abs();
+
+            if magnitude
+                .cmp_lt(f64x4::splat(MIN_INTEGRAL_F64 / 2.0))
+                .all()
+            {
+                // The fraction of a magnitude below the first integral float is exact, so ties
+                // go away from zero without the error of adding a half
+                let whole = magnitude.floor();
+                let rounded = (magnitude - whole)
+                    .cmp_ge(f64x4::HALF)
+                    .blend(whole + f64x4::ONE, whole);
+                chunk.copy_from_slice(&(rounded.copysign(scaled) / power).to_array());
+            } else {
+                for number in chunk.iter_mut().filter(|number| number.is_finite()) {
+                    *number = self.round(*number, RoundingMode::HalfAwayFromZero);
+                }
+            }
+        }
+        chunks.into_remainder()
+    }
+}
+
+/// Rounds a finite f32 scaled up by `10^decimal_places` and scales the result back down
+#[inline]
+pub(crate) fn decimal_places_f32(number: f32, decimal_places: u32, mode: RoundingMode) -> f32 {
+    DecimalPlacesF32::new(decimal_places).round(number, mode)
+}
+
+/// The power of ten for rounding f32s to a number of decimal places, found once so it can be
+/// shared by every number rounded to it
+pub(crate) struct DecimalPlacesF32 {
+    decimal_places: u32,
+    /// `10^decimal_places` while it is exact in an f32
+    power: f32,
+    /// `10^decimal_places` in an f64 for the larger powers, which overflow an f32
+    wide_power: f64,
+}
+
+impl DecimalPlacesF32 {
+    #[inline]
+    pub(crate) fn new(decimal_places: u32) -> DecimalPlacesF32 {
+        // Only the power used for the decimal places is found
+        let (power, wide_power) = if decimal_places <= 10 {
+            (powers::ten_f32(decimal_places as i32), 1.0)
+        } else {
+            (1.0, powers::ten_f64(decimal_places as i32))
+        };
+
+        DecimalPlacesF32 {
+            decimal_places,
+            power,
+            wide_power,
+        }
+    }
+
+    /// Rounds a finite f32 scaled up by `10^decimal_places` and scales the result back down
+    #[inline]
+    pub(crate) fn round(&self, number: f32, mode: RoundingMode) -> f32 {
+        let decimal_places = self.decimal_places;
+        if powers::min_digits_f32(number) as i64 + decimal_places as i64 > MAX_SIG_FIGS_F32 {
+            return number;
+        }
+
+        // Powers of ten up to 10^10 are exact so dividing by one is correctly rounded
+        if decimal_places <= 10 {
+            let power = self.power;
+            let scaled = number * power;
+            let nearest = scaled.round();
+            if scaled.abs() >= 2.0 * MIN_INTEGRAL_F32 || nearest / power == number {
+                return number;
+            }
+
+            // Close to the limit of the precision the error in scaling can reach half a step,
+            // so the neighbouring steps are checked as well
+            if scaled.abs() >= MIN_INTEGRAL_F32 / 2.0
+                && ((nearest - 1.0) / power == number || (nearest + 1.0) / power == number)
+            {
+                return number;
+            }
+            let rounded = mode.round_f32(scaled) as f64;
+            return directed_f32(number, rounded, mode, |rounded| rounded as f32 / power);
+        }
+
+        // Larger powers overflow an f32 so the number is scaled in an f64, which is exact for any f32
+        let scaled = number as f64 * self.wide_power;
+
+        // The rounded value has at most as many digits as the type can hold,
+        // so it is scaled back down through its decimal representation
+        if digits::scale_down::<_, f32>(scaled.round(), decimal_places) == number {
+            return number;
+        }
+        directed_f32(number, mode.round_f64(scaled), mode, |rounded| {
+            digits::scale_down(rounded, decimal_places)
+        })
+    }
+
+    /// Rounds the numbers with ties away from zero in [`f32x8`] vectors, returning the numbers at
+    /// the end too few to fill one
+    ///
+    /// Works the same way as [`DecimalPlacesF64::round_lanes`] for the powers that are exact in
+    /// an f32
+    #[cfg(feature = "simd")]
+    #[inline]
+    pub(crate) fn round_lanes<'a>(&self, numbers: &'a mut [f32]) -> &'a mut [f32] {
+        if self.decimal_places > 10 {
+            return numbers;
+        }
+
+        let power = f32x8::splat(self.power);
+        let mut chunks = numbers.chunks_exact_mut(LANES_F32);
+        for chunk in &mut chunks {
+            let lanes: [f32; LANES_F32] = (&*chunk).try_into().unwrap();
+            let scaled = f32x8::new(lanes) * power;
+            let magnitude = scaled.abs();
+
+            if magnitude
+                .cmp_lt(f32x8::splat(MIN_INTEGRAL_F32 / 2.0))
+                .all()
+            {
+                let whole = magnitude.floor();
+                let rounded = (magnitude - whole)
+                    .cmp_ge(f32x8::HALF)
+                    .blend(whole + f32x8::ONE, whole);
+                chunk.copy_from_slice(&(rounded.copysign(scaled) / power).to_array());
+            } else {
+                for number in chunk.iter_mut().filter(|number| number.is_finite()) {
+                    *number = self.round(*number, RoundingMode::HalfAwayFromZero);
+                }
+            }
+        }
+        chunks.into_remainder()
+    }
+}
+
+/// Rounds a finite f64 scaled down by `10^zeros` and scales the result back up
+///
+/// Zeros too large for the power to be represented give zero or an infinity
+#[inline]
+pub(crate) fn zeros_f64(number: f64, zeros: u32, mode: RoundingMode) -> f64 {
+    ZerosF64::new(zeros).round(number, mode)
+}
+
+/// The power of ten for rounding f64s to a number of zeros, found once so it can be shared by
+/// every number rounded to it
+pub(crate) struct ZerosF64 {
+    power: f64,
+}
+
+impl ZerosF64 {
+    #[inline]
+    pub(crate) fn new(zeros: u32) -> ZerosF64 {
+        ZerosF64 {
+            power: powers::ten_f64(zeros.min(i32::MAX as u32) as i32),
+        }
+    }
+
+    /// Rounds a finite f64 scaled down by `10^zeros` and scales the result back up
+    #[inline]
+    pub(crate) fn round(&self, number: f64, mode: RoundingMode) -> f64 {
+        let power = self.power;
+
+        // A number that is already the closest float to a multiple of the step, or is too large
+        // to be anything else, is returned as is
+        let mut quotient = number / power;
+        let nearest = quotient.round();
+        if quotient.abs() >= MIN_INTEGRAL_F64 || nearest * power == number {
+            return number;
+        }
+        if quotient.abs() >= MIN_INTEGRAL_F64 / 2.0
+            && ((nearest - 1.0) * power == number || (nearest + 1.0) * power == number)
+        {
+            return number;
+        }
+
+        // When the quotient underflows the number is far below a single step,
+        // so only its sign decides which way it rounds
+        if quotient == 0.0 {
+            quotient = 0.25_f64.copysign(number);
+        }
+
+        // Zero keeps the sign it was rounded with and scaling it by an infinite power would give NaN
+        let rounded = mode.round_f64(quotient);
+        if rounded == 0.0 {
+            rounded
+        } else {
+            directed_f64(number, rounded, mode, |rounded| rounded * power)
+        }
+    }
+}
+
+/// Rounds a finite f32 scaled down by `10^zeros` and scales the result back up
+///
+/// Zeros too large for the power to be represented give zero or an infinity
+#[inline]
+pub(crate) fn zeros_f32(number: f32, zeros: u32, mode: RoundingMode) -> f32 {
+    ZerosF32::new(zeros).round(number, mode)
+}
+
+/// The power of ten for rounding f32s to a number of zeros, found once so it can be shared by
+/// every number rounded to it
+pub(crate) struct ZerosF32 {
+    power: f32,
+}
+
+impl ZerosF32 {
+    #[inline]
+    pub(crate) fn new(zeros: u32) -> ZerosF32 {
+        ZerosF32 {
+            power: powers::ten_f32(zeros.min(i32::MAX as u32) as i32),
+        }
+    }
+
+    /// Rounds a finite f32 scaled down by `10^zeros` and scales the result back up
+    #[inline]
+    pub(crate) fn round(&self, number: f32, mode: RoundingMode) -> f32 {
+        let power = self.power;
+
+        let mut quotient = number / power;
+        let nearest = quotient.round();
+        if quotient.abs() >= MIN_INTEGRAL_F32 || nearest * power == number {
+            return number;
+        }
+        if quotient.abs() >= MIN_INTEGRAL_F32 / 2.0
+            && ((nearest - 1.0) * power == number || (nearest + 1.0) * power == number)
+        {
+            return number;
+        }
+
+        if quotient == 0.0 {
+            quotient = 0.25_f32.copysign(number);
+        }
+
+        let rounded = mode.round_f32(quotient);
+        if rounded == 0.0 {
+            rounded
+        } else {
+            directed_f32(number, rounded as f64, mode, |rounded| {
+                rounded as f32 * power
+            })
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Rounds numbers to a number of decimal places as they are serialized and deserialized
+//!
+//! The modules [`zero`] to [`nine`] round to that many decimal places and can be named in a
+//! `#[serde(with = "...")]` attribute, so a field keeps its type while the output is limited to
+//! the precision it is meaningful to. Any other number of decimal places can be given to the
+//! generic functions with `serialize_with` and `deserialize_with`
+//!
+//! A number that can't be rounded, such as NaN or an infinity, is serialized as none, which is
+//! `null` in JSON, and deserialized as it is. The `strict` module inside each of them returns an
+//! error for it instead
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "common_math::rounding::serde_dp::two")]
+//!     volts: f64,
+//!     #[serde(serialize_with = "common_math::rounding::serde_dp::serialize::<4, _, _>")]
+//!     amps: f32,
+//! }
+//!
+//! let reading = Reading { volts: 3.14159, amps: 0.012345 };
+//! assert_eq!(serde_json::to_string(&reading).unwrap(), r#"{"volts":3.14,"amps":0.0123}"#);
+//!
+//! let reading: Reading = serde_json::from_str(r#"{"volts":1.006,"amps":0.5}"#).unwrap();
+//! assert_eq!(reading.volts, 1.01);
+//! ```
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{dp_exponent, Roundable, RoundingMode};
+
+/// Serializes the number rounded to `DP` decimal places, or none if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Price(#[serde(serialize_with = "common_math::rounding::serde_dp::serialize::<3, _, _>")] f64);
+///
+/// assert_eq!(serde_json::to_string(&Price(2.0005)).unwrap(), "2.001");
+/// assert_eq!(serde_json::to_string(&Price(f64::NAN)).unwrap(), "null");
+/// ```
+#[inline]
+pub fn serialize<const DP: u32, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Roundable + Clone + Serialize,
+    S: Serializer,
+{
+    match round::<DP, T>(value.clone()) {
+        Some(rounded) => rounded.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a number and rounds it to `DP` decimal places, leaving it as it is if it can't be
+/// rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Price(#[serde(deserialize_with = "common_math::rounding::serde_dp::deserialize::<1, _, _>")] f64);
+///
+/// assert_eq!(serde_json::from_str::<Price>("2.25").unwrap().0, 2.3);
+/// ```
+#[inline]
+pub fn deserialize<'de, const DP: u32, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Roundable + Clone + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+    Ok(round::<DP, T>(value.clone()).unwrap_or(value))
+}
+
+/// Serializes the number rounded to `DP` decimal places,
+/// returning an error if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Price(#[serde(serialize_with = "common_math::rounding::serde_dp::serialize_strict::<2, _, _>")] f64);
+///
+/// assert_eq!(serde_json::to_string(&Price(2.005)).unwrap(), "2.01");
+/// assert!(serde_json::to_string(&Price(f64::INFINITY)).is_err());
+/// ```
+#[inline]
+pub fn serialize_strict<const DP: u32, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Roundable + Clone + Serialize,
+    S: Serializer,
+{
+    match round::<DP, T>(value.clone()) {
+        Some(rounded) => rounded.serialize(serializer),
+        None => Err(ser::Error::custom(
+            "the number can't be rounded to decimal places",
+        )),
+    }
+}
+
+/// Deserializes a number and rounds it to `DP` decimal places,
+/// returning an error if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Price(#[serde(deserialize_with = "common_math::rounding::serde_dp::deserialize_strict::<0, _, _>")] f64);
+///
+/// assert_eq!(serde_json::from_str::<Price>("2.5").unwrap().0, 3.0);
+/// ```
+#[inline]
+pub fn deserialize_strict<'de, const DP: u32, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Roundable + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    round::<DP, T>(T::deserialize(deserializer)?)
+        .ok_or_else(|| de::Error::custom("the number can't be rounded to decimal places"))
+}
+
+/// Rounds to the decimal places, or `None` for NaN, an infinity or a result that doesn't fit
+#[inline]
+fn round<const DP: u32, T: Roundable>(value: T) -> Option<T> {
+    value.round_to_exponent(dp_exponent(DP), RoundingMode::HalfAwayFromZero)
+}
+
+/// Generates a module for each precision that forwards to the generic functions of the module it
+/// is called in, with a `strict` module inside it that forwards to the strict ones
+macro_rules! impl_serde_modules {
+    ($unit:literal, $($name:ident => $precision:literal),*) => {
+        $(
+            #[doc = concat!("Rounds to ", stringify!($precision), " ", $unit, " in `#[serde(with = \"...\")]`")]
+            pub mod $name {
+                use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+                use crate::rounding::Roundable;
+
+                #[doc = concat!("Serializes the number rounded to ", stringify!($precision), " ", $unit, ", or none if it can't be rounded")]
+                #[inline]
+                pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    T: Roundable + Clone + Serialize,
+                    S: Serializer,
+                {
+                    super::serialize::<$precision, T, S>(value, serializer)
+                }
+
+                #[doc = concat!("Deserializes a number and rounds it to ", stringify!($precision), " ", $unit)]
+                #[inline]
+                pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    T: Roundable + Clone + Deserialize<'de>,
+                    D: Deserializer<'de>,
+                {
+                    super::deserialize::<$precision, T, D>(deserializer)
+                }
+
+                #[doc = concat!("Rounds to ", stringify!($precision), " ", $unit, ", returning an error for a number that can't be rounded")]
+                pub mod strict {
+                    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+                    use crate::rounding::Roundable;
+
+                    #[doc = concat!("Serializes the number rounded to ", stringify!($precision), " ", $unit, ", returning an error if it can't be rounded")]
+                    #[inline]
+                    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        T: Roundable + Clone + Serialize,
+                        S: Serializer,
+                    {
+                        super::super::serialize_strict::<$precision, T, S>(value, serializer)
+                    }
+
+                    #[doc = concat!("Deserializes a number and rounds it to ", stringify!($precision), " ", $unit, ", returning an error if it can't be rounded")]
+                    #[inline]
+                    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                    where
+                        T: Roundable + Deserialize<'de>,
+                        D: Deserializer<'de>,
+                    {
+                        super::super::deserialize_strict::<$precision, T, D>(deserializer)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_serde_modules;
+
+impl_serde_modules!(
+    "decimal places",
+    zero => 0,
+    one => 1,
+    two => 2,
+    three => 3,
+    four => 4,
+    five => 5,
+    six => 6,
+    seven => 7,
+    eight => 8,
+    nine => 9
+);
+
+mod tests;
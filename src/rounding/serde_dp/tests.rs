@@ -0,0 +1,96 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[derive(Debug, Serialize, Deserialize)]
+struct Position {
+    #[serde(with = "crate::rounding::serde_dp::two")]
+    latitude: f64,
+    #[serde(with = "crate::rounding::serde_dp::two")]
+    longitude: f64,
+    #[serde(with = "crate::rounding::serde_dp::zero::strict")]
+    altitude: f32,
+}
+
+#[test]
+fn test_serialize() {
+    let position = Position {
+        latitude: 51.507_35,
+        longitude: -0.127_758,
+        altitude: 11.5,
+    };
+    assert_eq!(
+        serde_json::to_string(&position).unwrap(),
+        r#"{"latitude":51.51,"longitude":-0.13,"altitude":12.0}"#
+    );
+
+    let position = Position {
+        latitude: 1.006,
+        longitude: -2.674,
+        altitude: -0.4,
+    };
+    assert_eq!(
+        serde_json::to_string(&position).unwrap(),
+        r#"{"latitude":1.01,"longitude":-2.67,"altitude":-0.0}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    let position: Position =
+        serde_json::from_str(r#"{"latitude":51.507351,"longitude":-0.1277583,"altitude":11.49}"#)
+            .unwrap();
+    assert_eq!(position.latitude, 51.51);
+    assert_eq!(position.longitude, -0.13);
+    assert_eq!(position.altitude, 11.0);
+}
+
+#[test]
+fn test_non_finite() {
+    let position = Position {
+        latitude: f64::NAN,
+        longitude: f64::NEG_INFINITY,
+        altitude: 0.0,
+    };
+    assert_eq!(
+        serde_json::to_string(&position).unwrap(),
+        r#"{"latitude":null,"longitude":null,"altitude":0.0}"#
+    );
+
+    let position = Position {
+        latitude: 0.0,
+        longitude: 0.0,
+        altitude: f32::NAN,
+    };
+    assert!(serde_json::to_string(&position).is_err());
+}
+
+#[test]
+fn test_generic() {
+    #[derive(Serialize, Deserialize)]
+    struct Reading {
+        #[serde(
+            serialize_with = "serialize::<12, _, _>",
+            deserialize_with = "deserialize::<12, _, _>"
+        )]
+        value: f64,
+        #[serde(with = "crate::rounding::serde_dp::nine")]
+        count: i64,
+    }
+
+    let reading = Reading {
+        value: 0.1 + 0.2,
+        count: -7,
+    };
+    assert_eq!(
+        serde_json::to_string(&reading).unwrap(),
+        r#"{"value":0.3,"count":-7}"#
+    );
+
+    let reading: Reading = serde_json::from_str(r#"{"value":0.30000000000049,"count":5}"#).unwrap();
+    assert_eq!(reading.value, 0.3);
+    assert_eq!(reading.count, 5);
+}
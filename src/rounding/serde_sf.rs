@@ -0,0 +1,153 @@
+//! Rounds numbers to a number of significant figures as they are serialized and deserialized
+//!
+//! Works like [`serde_dp`](super::serde_dp), with the modules [`one`] to [`nine`] rounding to that
+//! many significant figures and the generic functions taking any other number of them. A number
+//! that can't be rounded, such as NaN or an infinity, is serialized as none, which is `null` in
+//! JSON, unless the `strict` module inside each of them is used
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Measurement {
+//!     #[serde(with = "common_math::rounding::serde_sf::three")]
+//!     mass: f64,
+//!     #[serde(with = "common_math::rounding::serde_sf::two::strict")]
+//!     count: u32,
+//! }
+//!
+//! let measurement = Measurement { mass: 0.0123456, count: 1_234 };
+//! assert_eq!(serde_json::to_string(&measurement).unwrap(), r#"{"mass":0.0123,"count":1200}"#);
+//!
+//! let measurement: Measurement = serde_json::from_str(r#"{"mass":98765.4,"count":99}"#).unwrap();
+//! assert_eq!(measurement.mass, 98_800.0);
+//! ```
+
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::serde_dp::impl_serde_modules;
+use super::{sf_exponent, Roundable, RoundingMode};
+
+/// Serializes the number rounded to `SF` significant figures, or none if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Ratio(#[serde(serialize_with = "common_math::rounding::serde_sf::serialize::<4, _, _>")] f64);
+///
+/// assert_eq!(serde_json::to_string(&Ratio(1.0 / 3.0)).unwrap(), "0.3333");
+/// assert_eq!(serde_json::to_string(&Ratio(f64::NAN)).unwrap(), "null");
+/// ```
+#[inline]
+pub fn serialize<const SF: u32, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Roundable + Clone + Serialize,
+    S: Serializer,
+{
+    match round::<SF, T>(value.clone()) {
+        Some(rounded) => rounded.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes a number and rounds it to `SF` significant figures, leaving it as it is if it
+/// can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Ratio(#[serde(deserialize_with = "common_math::rounding::serde_sf::deserialize::<2, _, _>")] f64);
+///
+/// assert_eq!(serde_json::from_str::<Ratio>("0.01234").unwrap().0, 0.012);
+/// ```
+#[inline]
+pub fn deserialize<'de, const SF: u32, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Roundable + Clone + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let value = T::deserialize(deserializer)?;
+    Ok(round::<SF, T>(value.clone()).unwrap_or(value))
+}
+
+/// Serializes the number rounded to `SF` significant figures,
+/// returning an error if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Ratio(#[serde(serialize_with = "common_math::rounding::serde_sf::serialize_strict::<2, _, _>")] i8);
+///
+/// assert_eq!(serde_json::to_string(&Ratio(-123)).unwrap(), "-120");
+/// assert!(serde_json::to_string(&Ratio(127)).is_err());
+/// ```
+#[inline]
+pub fn serialize_strict<const SF: u32, T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Roundable + Clone + Serialize,
+    S: Serializer,
+{
+    match round::<SF, T>(value.clone()) {
+        Some(rounded) => rounded.serialize(serializer),
+        None => Err(ser::Error::custom(
+            "the number can't be rounded to significant figures",
+        )),
+    }
+}
+
+/// Deserializes a number and rounds it to `SF` significant figures,
+/// returning an error if it can't be rounded
+///
+/// # Examples
+///
+/// ```
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Ratio(#[serde(deserialize_with = "common_math::rounding::serde_sf::deserialize_strict::<1, _, _>")] u8);
+///
+/// assert_eq!(serde_json::from_str::<Ratio>("249").unwrap().0, 200);
+/// assert!(serde_json::from_str::<Ratio>("251").is_err());
+/// ```
+#[inline]
+pub fn deserialize_strict<'de, const SF: u32, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Roundable + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    round::<SF, T>(T::deserialize(deserializer)?)
+        .ok_or_else(|| de::Error::custom("the number can't be rounded to significant figures"))
+}
+
+/// Rounds to the significant figures, or `None` for NaN, an infinity or a result that doesn't fit
+#[inline]
+fn round<const SF: u32, T: Roundable>(value: T) -> Option<T> {
+    let exponent = sf_exponent(&value, SF);
+    value.round_to_exponent(exponent, RoundingMode::HalfAwayFromZero)
+}
+
+impl_serde_modules!(
+    "significant figures",
+    one => 1,
+    two => 2,
+    three => 3,
+    four => 4,
+    five => 5,
+    six => 6,
+    seven => 7,
+    eight => 8,
+    nine => 9
+);
+
+mod tests;
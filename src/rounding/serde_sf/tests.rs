@@ -0,0 +1,76 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+#[derive(Debug, Serialize, Deserialize)]
+struct Sample {
+    #[serde(with = "crate::rounding::serde_sf::three")]
+    concentration: f64,
+    #[serde(with = "crate::rounding::serde_sf::one::strict")]
+    population: u16,
+}
+
+#[test]
+fn test_serialize() {
+    let sample = Sample {
+        concentration: 0.000_123_456,
+        population: 64_321,
+    };
+    assert_eq!(
+        serde_json::to_string(&sample).unwrap(),
+        r#"{"concentration":0.000123,"population":60000}"#
+    );
+
+    let sample = Sample {
+        concentration: -98_765.0,
+        population: 0,
+    };
+    assert_eq!(
+        serde_json::to_string(&sample).unwrap(),
+        r#"{"concentration":-98800.0,"population":0}"#
+    );
+}
+
+#[test]
+fn test_deserialize() {
+    let sample: Sample =
+        serde_json::from_str(r#"{"concentration":1.23456e-7,"population":149}"#).unwrap();
+    assert_eq!(sample.concentration, 1.23e-7);
+    assert_eq!(sample.population, 100);
+
+    // 65,000 rounds up past the largest u16
+    assert!(serde_json::from_str::<Sample>(r#"{"concentration":1.0,"population":65000}"#).is_err());
+}
+
+#[test]
+fn test_non_finite() {
+    let sample = Sample {
+        concentration: f64::INFINITY,
+        population: 1,
+    };
+    assert_eq!(
+        serde_json::to_string(&sample).unwrap(),
+        r#"{"concentration":null,"population":1}"#
+    );
+
+    let sample = Sample {
+        concentration: 1.0,
+        population: 65_535,
+    };
+    assert!(serde_json::to_string(&sample).is_err());
+}
+
+#[test]
+fn test_generic() {
+    #[derive(Serialize)]
+    struct Ratio(#[serde(serialize_with = "serialize::<15, _, _>")] f64);
+
+    assert_eq!(serde_json::to_string(&Ratio(0.1 + 0.2)).unwrap(), "0.3");
+    assert_eq!(
+        serde_json::to_string(&Ratio(2.0 / 3.0)).unwrap(),
+        "0.666666666666667"
+    );
+}
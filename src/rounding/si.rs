@@ -0,0 +1,269 @@
+#[cfg(feature = "alloc")]
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+#[cfg(feature = "alloc")]
+use super::{digits, format, Notation, Roundable};
+
+/// The SI prefixes from yocto to yotta, each a power of ten that is a multiple of three
+///
+/// ```rust
+/// use common_math::rounding::Prefix;
+///
+/// assert_eq!(Prefix::Mega.exponent(), 6);
+/// assert_eq!(Prefix::Micro.symbol(), "µ");
+/// assert_eq!(Prefix::from_exponent(-9), Some(Prefix::Nano));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub enum Prefix {
+    /// `y`, 10^-24
+    Yocto,
+    /// `z`, 10^-21
+    Zepto,
+    /// `a`, 10^-18
+    Atto,
+    /// `f`, 10^-15
+    Femto,
+    /// `p`, 10^-12
+    Pico,
+    /// `n`, 10^-9
+    Nano,
+    /// `µ`, 10^-6
+    Micro,
+    /// `m`, 10^-3
+    Milli,
+    /// No prefix, 10^0
+    #[default]
+    Unit,
+    /// `k`, 10^3
+    Kilo,
+    /// `M`, 10^6
+    Mega,
+    /// `G`, 10^9
+    Giga,
+    /// `T`, 10^12
+    Tera,
+    /// `P`, 10^15
+    Peta,
+    /// `E`, 10^18
+    Exa,
+    /// `Z`, 10^21
+    Zetta,
+    /// `Y`, 10^24
+    Yotta,
+}
+
+impl Prefix {
+    /// Every prefix from the smallest to the largest
+    pub const ALL: [Prefix; 17] = [
+        Prefix::Yocto,
+        Prefix::Zepto,
+        Prefix::Atto,
+        Prefix::Femto,
+        Prefix::Pico,
+        Prefix::Nano,
+        Prefix::Micro,
+        Prefix::Milli,
+        Prefix::Unit,
+        Prefix::Kilo,
+        Prefix::Mega,
+        Prefix::Giga,
+        Prefix::Tera,
+        Prefix::Peta,
+        Prefix::Exa,
+        Prefix::Zetta,
+        Prefix::Yotta,
+    ];
+
+    /// The power of ten the prefix stands for
+    #[inline]
+    pub fn exponent(self) -> i32 {
+        (self as i32 - Prefix::Unit as i32) * 3
+    }
+
+    /// The symbol of the prefix, which is empty for [`Prefix::Unit`]
+    #[inline]
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Prefix::Yocto => "y",
+            Prefix::Zepto => "z",
+            Prefix::Atto => "a",
+            Prefix::Femto => "f",
+            Prefix::Pico => "p",
+            Prefix::Nano => "n",
+            Prefix::Micro => "µ",
+            Prefix::Milli => "m",
+            Prefix::Unit => "",
+            Prefix::Kilo => "k",
+            Prefix::Mega => "M",
+            Prefix::Giga => "G",
+            Prefix::Tera => "T",
+            Prefix::Peta => "P",
+            Prefix::Exa => "E",
+            Prefix::Zetta => "Z",
+            Prefix::Yotta => "Y",
+        }
+    }
+
+    /// The prefix for a power of ten, `None` if there isn't one
+    #[inline]
+    pub fn from_exponent(exponent: i32) -> Option<Prefix> {
+        Prefix::ALL
+            .into_iter()
+            .find(|prefix| prefix.exponent() == exponent)
+    }
+
+    /// The prefix with a symbol, also accepting `u` and the Greek `μ` for micro
+    #[inline]
+    pub fn from_symbol(symbol: &str) -> Option<Prefix> {
+        match symbol {
+            "u" | "μ" => Some(Prefix::Micro),
+            _ => Prefix::ALL
+                .into_iter()
+                .find(|prefix| prefix.symbol() == symbol),
+        }
+    }
+
+    /// The prefix that puts the magnitude of a finite, non zero number in `[1, 1000)`,
+    /// the smallest or largest prefix for numbers beyond them
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn for_number(number: f64) -> Prefix {
+        let exponent = number
+            .order_of_magnitude()
+            .map_or(0, |order| order.div_euclid(3) * 3)
+            .clamp(Prefix::Yocto.exponent(), Prefix::Yotta.exponent());
+        Prefix::from_exponent(exponent).expect("clamped exponent is a multiple of three")
+    }
+}
+
+/// Rounds the number to the given number of significant figures like
+/// [`round_sf`](super::round_sf) and renders it with the SI prefix that puts the mantissa in
+/// `[1, 1000)`, separated by a space
+///
+/// The prefix is picked after rounding, so `999_999` to 3 significant figures is `1.00 M`.
+/// Numbers below the smallest prefix or above the largest use it anyway, so the mantissa is
+/// outside the range. Zero has no prefix, and NaN and infinities are rendered like `Display`.
+/// The separator is kept for numbers without a prefix so a unit can be appended
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_si;
+///
+/// assert_eq!(format_si(1_234_567.0, 3), "1.23 M");
+/// assert_eq!(format_si(0.000_056, 2), "56 µ");
+/// assert_eq!(format_si(-1200.0, 2), "-1.2 k");
+/// assert_eq!(format_si(999.96, 4), "1.000 k");
+/// assert_eq!(format_si(12.0, 2) + "W", "12 W");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn format_si(number: f64, sig_figs: u32) -> String {
+    format_si_with(number, sig_figs, " ")
+}
+
+/// Formats the number with an SI prefix like [`format_si`] using the given separator between the
+/// mantissa and the prefix
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::format_si_with;
+///
+/// assert_eq!(format_si_with(1_234_567.0, 3, ""), "1.23M");
+/// assert_eq!(format_si_with(4.7e-9, 2, "\u{2009}"), "4.7\u{2009}n");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn format_si_with(number: f64, sig_figs: u32, separator: &str) -> String {
+    let rounded = number.round_sf(sig_figs);
+    let prefix = if rounded == 0.0 || !rounded.is_finite() {
+        Prefix::Unit
+    } else {
+        Prefix::for_number(rounded)
+    };
+    format_si_prefix(number, sig_figs, prefix, separator)
+}
+
+/// Rounds the number to the given number of significant figures like
+/// [`round_sf`](super::round_sf) and renders it with the given SI prefix, whatever the
+/// magnitude of the mantissa
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{format_si_prefix, Prefix};
+///
+/// assert_eq!(format_si_prefix(1_234_567.0, 3, Prefix::Kilo, " "), "1230 k");
+/// assert_eq!(format_si_prefix(0.5, 2, Prefix::Milli, ""), "500m");
+/// assert_eq!(format_si_prefix(0.000_12, 2, Prefix::Unit, " "), "0.00012 ");
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn format_si_prefix(number: f64, sig_figs: u32, prefix: Prefix, separator: &str) -> String {
+    let rounded = number.round_sf(sig_figs);
+    if !rounded.is_finite() {
+        return rounded.to_string();
+    }
+
+    // Dividing by the power of the prefix only moves the decimal point of the digits
+    let (significand, fraction_digits) = digits::shortest_decimal(rounded);
+    let mantissa = format::significant(
+        significand as u128,
+        fraction_digits + prefix.exponent(),
+        rounded.is_sign_negative(),
+        sig_figs,
+        Notation::Fixed,
+    );
+    format!("{mantissa}{separator}{}", prefix.symbol())
+}
+
+/// Parses a number with an optional SI prefix after it such as `1.23M`, `56 µ` or `-4.7e-3 k`,
+/// the inverse of [`format_si`]. Micro can also be written as `u`
+///
+/// The number is scaled by the prefix in decimal so the result is the closest float to the value
+/// written, and `None` is returned when it isn't a number followed by a prefix. A trailing `E`
+/// is read as exa, so `1E` is `1e18` but `1E3` is `1000`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::parse_si;
+///
+/// assert_eq!(parse_si("1.23M"), Some(1_230_000.0));
+/// assert_eq!(parse_si("56 µ"), Some(0.000_056));
+/// assert_eq!(parse_si("-4.7e-3 k"), Some(-4.7));
+/// assert_eq!(parse_si("12"), Some(12.0));
+/// assert_eq!(parse_si("12 X"), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn parse_si(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if let Ok(number) = text.parse() {
+        return Some(number);
+    }
+
+    let symbol_start = text.char_indices().last()?.0;
+    let prefix = Prefix::from_symbol(&text[symbol_start..])?;
+    let mantissa = text[..symbol_start].trim_end();
+    let (mantissa, exponent) = match mantissa.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().ok()?),
+        None => (mantissa, 0),
+    };
+
+    // A mantissa like `inf` or `1e` would otherwise be accepted
+    if !mantissa.bytes().any(|byte| byte.is_ascii_digit())
+        || mantissa.contains(|c: char| c.is_ascii_alphabetic())
+    {
+        return None;
+    }
+    format!("{mantissa}e{}", exponent.checked_add(prefix.exponent())?)
+        .parse()
+        .ok()
+}
+
+mod tests;
@@ -0,0 +1,131 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{format_sf_notation, round_sf};
+
+#[test]
+fn test_prefix() {
+    for (index, prefix) in Prefix::ALL.into_iter().enumerate() {
+        assert_eq!(prefix.exponent(), index as i32 * 3 - 24);
+        assert_eq!(Prefix::from_exponent(prefix.exponent()), Some(prefix));
+        assert_eq!(Prefix::from_symbol(prefix.symbol()), Some(prefix));
+    }
+    assert_eq!(Prefix::from_exponent(1), None);
+    assert_eq!(Prefix::from_exponent(27), None);
+    assert_eq!(Prefix::from_symbol("u"), Some(Prefix::Micro));
+    assert_eq!(Prefix::from_symbol("μ"), Some(Prefix::Micro));
+    assert_eq!(Prefix::from_symbol("K"), None);
+    assert_eq!(Prefix::default(), Prefix::Unit);
+}
+
+#[test]
+fn test_format_si() {
+    let cases = [
+        (1_234_567.0, 3, "1.23 M"),
+        (1234.0, 2, "1.2 k"),
+        (999.0, 3, "999 "),
+        (12.0, 3, "12.0 "),
+        (0.5, 1, "500 m"),
+        (0.000_056, 2, "56 µ"),
+        (-3.4e6, 2, "-3.4 M"),
+        (-0.0047, 3, "-4.70 m"),
+        (6.02e23, 3, "602 Z"),
+        (1.5e-22, 2, "150 y"),
+        (0.0, 3, "0.00 "),
+        (f64::NAN, 3, "NaN"),
+        (f64::NEG_INFINITY, 3, "-inf"),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(format_si(number, sig_figs), expected, "{number}");
+    }
+}
+
+#[test]
+fn test_format_si_prefix_bump() {
+    // Rounding the mantissa up to 1000 moves on to the next prefix
+    assert_eq!(format_si(999.96, 4), "1.000 k");
+    assert_eq!(format_si(999_999.0, 3), "1.00 M");
+    assert_eq!(format_si(-999_500.0, 3), "-1.00 M");
+    assert_eq!(format_si(0.000_999_7, 3), "1.00 m");
+    assert_eq!(format_si(999.4, 3), "999 ");
+}
+
+#[test]
+fn test_format_si_beyond_prefixes() {
+    assert_eq!(format_si(1.5e-25, 2), "0.15 y");
+    assert_eq!(format_si(4e-30, 1), "0.000004 y");
+    assert_eq!(format_si(1.234e27, 3), "1230 Y");
+    assert_eq!(format_si(9.999e26, 3), "1000 Y");
+}
+
+#[test]
+fn test_format_si_with_separator_and_prefix() {
+    assert_eq!(format_si_with(1_234_567.0, 3, ""), "1.23M");
+    assert_eq!(format_si_with(12.0, 2, ""), "12");
+    assert_eq!(format_si_with(-56e-6, 2, "_"), "-56_µ");
+
+    assert_eq!(
+        format_si_prefix(1_234_567.0, 3, Prefix::Kilo, " "),
+        "1230 k"
+    );
+    assert_eq!(
+        format_si_prefix(1_234_567.0, 3, Prefix::Giga, " "),
+        "0.00123 G"
+    );
+    assert_eq!(format_si_prefix(0.5, 2, Prefix::Milli, ""), "500m");
+    assert_eq!(format_si_prefix(0.0, 2, Prefix::Mega, " "), "0.0 M");
+}
+
+#[test]
+fn test_parse_si() {
+    assert_eq!(parse_si("1.23M"), Some(1.23e6));
+    assert_eq!(parse_si(" 1.23 M "), Some(1.23e6));
+    assert_eq!(parse_si("56 µ"), Some(56e-6));
+    assert_eq!(parse_si("56u"), Some(56e-6));
+    assert_eq!(parse_si("-4.7e-3 k"), Some(-4.7));
+    assert_eq!(parse_si("0.1 m"), Some(1e-4));
+    assert_eq!(parse_si("2E"), Some(2e18));
+    assert_eq!(parse_si("2E3"), Some(2000.0));
+    assert_eq!(parse_si("42"), Some(42.0));
+    assert_eq!(parse_si("-inf"), Some(f64::NEG_INFINITY));
+
+    assert_eq!(parse_si(""), None);
+    assert_eq!(parse_si("k"), None);
+    assert_eq!(parse_si("12 X"), None);
+    assert_eq!(parse_si("1.2.3 k"), None);
+    assert_eq!(parse_si("inf k"), None);
+    assert_eq!(parse_si("1e k"), None);
+    assert_eq!(parse_si("1 kk"), None);
+}
+
+#[test]
+fn test_format_parse_round_trip() {
+    // Every magnitude from below the smallest prefix to above the largest
+    for exponent in -27..=27 {
+        for leading in [1.0, 1.5, 2.345_678, 4.999, 9.9996] {
+            for sign in [1.0, -1.0] {
+                let number: f64 = format!("{}e{exponent}", sign * leading).parse().unwrap();
+                for sig_figs in 1..=6 {
+                    for separator in ["", " "] {
+                        let formatted = format_si_with(number, sig_figs, separator);
+                        let parsed = parse_si(&formatted).unwrap();
+                        let scientific = format_sf_notation(number, sig_figs, Notation::Scientific);
+                        assert_eq!(
+                            parsed,
+                            scientific.parse::<f64>().unwrap(),
+                            "{number} {formatted}"
+                        );
+
+                        // The rounded float can be an ulp from the closest float to its digits
+                        let rounded = round_sf(number, sig_figs);
+                        assert!(
+                            (parsed - rounded).abs() <= f64::EPSILON * rounded.abs(),
+                            "{number} {formatted}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use super::{Float, Roundable, RoundingMode};
+
+/// Rounds every number in the slice to the given number of decimal places in place
+///
+/// Each number gives the same result as [`round`](super::round), with the power of ten found
+/// once for the whole slice instead of for every number. NaN and infinities are left as they are
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_slice_dp;
+///
+/// let mut values = [1.234_f64, -5.678, f64::NAN];
+/// round_slice_dp(&mut values, 2);
+/// assert_eq!(values[..2], [1.23, -5.68]);
+/// assert!(values[2].is_nan());
+/// ```
+#[inline]
+pub fn round_slice_dp<T: Float>(values: &mut [T], decimal_places: u32) {
+    T::round_slice_dp_mode(values, decimal_places, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice up to the given number of decimal places in place
+///
+/// Each number gives the same result as [`ceil`](super::ceil), with the power of ten found
+/// once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_slice_dp;
+///
+/// let mut values = [1.231_f32, -5.678];
+/// ceil_slice_dp(&mut values, 2);
+/// assert_eq!(values, [1.24, -5.67]);
+/// ```
+#[inline]
+pub fn ceil_slice_dp<T: Float>(values: &mut [T], decimal_places: u32) {
+    T::round_slice_dp_mode(values, decimal_places, RoundingMode::Up);
+}
+
+/// Rounds every number in the slice down to the given number of decimal places in place
+///
+/// Each number gives the same result as [`floor`](super::floor), with the power of ten found
+/// once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_slice_dp;
+///
+/// let mut values = [1.239_f64, -5.671];
+/// floor_slice_dp(&mut values, 2);
+/// assert_eq!(values, [1.23, -5.68]);
+/// ```
+#[inline]
+pub fn floor_slice_dp<T: Float>(values: &mut [T], decimal_places: u32) {
+    T::round_slice_dp_mode(values, decimal_places, RoundingMode::Down);
+}
+
+/// Rounds every number in the slice to the given number of decimal places using the given
+/// rounding mode in place
+///
+/// Each number gives the same result as [`round_dp_mode`](super::round_dp_mode), with the power
+/// of ten found once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_slice_dp_mode, RoundingMode};
+///
+/// let mut values = [0.125_f64, 0.135, -0.125];
+/// round_slice_dp_mode(&mut values, 2, RoundingMode::HalfEven);
+/// assert_eq!(values, [0.12, 0.14, -0.12]);
+/// ```
+#[inline]
+pub fn round_slice_dp_mode<T: Float>(values: &mut [T], decimal_places: u32, mode: RoundingMode) {
+    T::round_slice_dp_mode(values, decimal_places, mode);
+}
+
+/// Rounds every number in the slice to the given number of decimal places in place, several
+/// numbers at a time with the `simd` feature
+///
+/// Each number gives the same result as [`round`](super::round) down to the bits. With the
+/// `simd` feature the numbers are scaled, rounded and scaled back 4 f64s or 8 f32s at a time in
+/// the SIMD vectors of the `wide` crate, which use the vector instructions of the target where it
+/// has them. Numbers too large for that to be exact, and the numbers at the end of the slice too
+/// few to fill a vector, are rounded one at a time. Without the feature this is the same as
+/// [`round_slice_dp`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_slice_dp_simd;
+///
+/// let mut values = [1.234_f64, -5.678, 2.675, 1.005, f64::INFINITY];
+/// round_slice_dp_simd(&mut values, 2);
+/// assert_eq!(values, [1.23, -5.68, 2.68, 1.0, f64::INFINITY]);
+/// ```
+#[inline]
+pub fn round_slice_dp_simd<T: Float>(values: &mut [T], decimal_places: u32) {
+    T::round_slice_dp_simd(values, decimal_places);
+}
+
+/// Rounds every number in the slice to the given number of zeros in place
+///
+/// Each number gives the same result as [`round_zeros`](super::round_zeros), with the power of
+/// ten for floats found once for the whole slice instead of for every number. NaN and infinities
+/// are left as they are and integers saturate
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_slice_zeros;
+///
+/// let mut values = [1234_i32, -1250, 49];
+/// round_slice_zeros(&mut values, 2);
+/// assert_eq!(values, [1200, -1300, 0]);
+/// ```
+#[inline]
+pub fn round_slice_zeros<T: Roundable>(values: &mut [T], zeros: u32) {
+    T::round_slice_zeros_mode(values, zeros, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice up to the given number of zeros in place
+///
+/// Each number gives the same result as [`ceil_zeros`](super::ceil_zeros), with the power of
+/// ten for floats found once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_slice_zeros;
+///
+/// let mut values = [1201.5_f64, -1299.0];
+/// ceil_slice_zeros(&mut values, 2);
+/// assert_eq!(values, [1300.0, -1200.0]);
+/// ```
+#[inline]
+pub fn ceil_slice_zeros<T: Roundable>(values: &mut [T], zeros: u32) {
+    T::round_slice_zeros_mode(values, zeros, RoundingMode::Up);
+}
+
+/// Rounds every number in the slice down to the given number of zeros in place
+///
+/// Each number gives the same result as [`floor_zeros`](super::floor_zeros), with the power of
+/// ten for floats found once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_slice_zeros;
+///
+/// let mut values = [1299_u32, 99];
+/// floor_slice_zeros(&mut values, 2);
+/// assert_eq!(values, [1200, 0]);
+/// ```
+#[inline]
+pub fn floor_slice_zeros<T: Roundable>(values: &mut [T], zeros: u32) {
+    T::round_slice_zeros_mode(values, zeros, RoundingMode::Down);
+}
+
+/// Rounds every number in the slice to the given number of zeros using the given rounding mode
+/// in place
+///
+/// Each number gives the same result as [`round_zeros_mode`](super::round_zeros_mode), with the
+/// power of ten for floats found once for the whole slice
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_slice_zeros_mode, RoundingMode};
+///
+/// let mut values = [125_i32, 135, -125];
+/// round_slice_zeros_mode(&mut values, 1, RoundingMode::HalfEven);
+/// assert_eq!(values, [120, 140, -120]);
+/// ```
+#[inline]
+pub fn round_slice_zeros_mode<T: Roundable>(values: &mut [T], zeros: u32, mode: RoundingMode) {
+    T::round_slice_zeros_mode(values, zeros, mode);
+}
+
+/// Rounds every number in the slice to the given number of significant figures in place
+///
+/// Each number gives the same result as [`round_sf`](super::round_sf). The power of ten depends
+/// on the magnitude of each number so it can't be shared across the slice, and this is the same
+/// as rounding each number in a loop
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_slice_sf;
+///
+/// let mut values = [1234.5_f64, 0.012_345, -98.76];
+/// round_slice_sf(&mut values, 2);
+/// assert_eq!(values, [1200.0, 0.012, -99.0]);
+/// ```
+#[inline]
+pub fn round_slice_sf<T: Roundable + Copy>(values: &mut [T], sig_figs: u32) {
+    round_slice_sf_mode(values, sig_figs, RoundingMode::HalfAwayFromZero);
+}
+
+/// Rounds every number in the slice up to the given number of significant figures in place
+///
+/// Each number gives the same result as [`ceil_sf`](super::ceil_sf)
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_slice_sf;
+///
+/// let mut values = [1201_u32, 99_901];
+/// ceil_slice_sf(&mut values, 2);
+/// assert_eq!(values, [1300, 100_000]);
+/// ```
+#[inline]
+pub fn ceil_slice_sf<T: Roundable + Copy>(values: &mut [T], sig_figs: u32) {
+    round_slice_sf_mode(values, sig_figs, RoundingMode::Up);
+}
+
+/// Rounds every number in the slice down to the given number of significant figures in place
+///
+/// Each number gives the same result as [`floor_sf`](super::floor_sf)
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_slice_sf;
+///
+/// let mut values = [1.299_f32, -0.012_31];
+/// floor_slice_sf(&mut values, 2);
+/// assert_eq!(values, [1.2, -0.013]);
+/// ```
+#[inline]
+pub fn floor_slice_sf<T: Roundable + Copy>(values: &mut [T], sig_figs: u32) {
+    round_slice_sf_mode(values, sig_figs, RoundingMode::Down);
+}
+
+/// Rounds every number in the slice to the given number of significant figures using the given
+/// rounding mode in place
+///
+/// Each number gives the same result as [`round_sf_mode`](super::round_sf_mode)
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_slice_sf_mode, RoundingMode};
+///
+/// let mut values = [1250_i32, -1350];
+/// round_slice_sf_mode(&mut values, 2, RoundingMode::HalfEven);
+/// assert_eq!(values, [1200, -1400]);
+/// ```
+#[inline]
+pub fn round_slice_sf_mode<T: Roundable + Copy>(
+    values: &mut [T],
+    sig_figs: u32,
+    mode: RoundingMode,
+) {
+    for value in values {
+        *value = value.round_sf_mode(sig_figs, mode);
+    }
+}
+
+mod tests;
@@ -0,0 +1,241 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{round_dp_mode, round_sf_mode, round_zeros_mode};
+
+#[cfg(test)]
+const MODES: [RoundingMode; 4] = [
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::Up,
+    RoundingMode::Down,
+    RoundingMode::HalfEven,
+];
+
+#[cfg(test)]
+fn random_floats(seed: u64, count: usize) -> Vec<f64> {
+    let mut state = seed;
+    let mut values: Vec<f64> = (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let exponent = (state % 40) as i32 - 20;
+            ((state >> 11) as f64 / (1_u64 << 53) as f64 - 0.5) * 10_f64.powi(exponent)
+        })
+        .collect();
+    values.extend([
+        0.0,
+        -0.0,
+        2.5,
+        -2.675,
+        1.005,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        5e-324,
+    ]);
+    values
+}
+
+#[cfg(test)]
+fn same(a: f64, b: f64) -> bool {
+    a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan())
+}
+
+#[test]
+fn test_round_slice_dp_matches_scalar() {
+    let values = random_floats(0x51_1ce5, 2_000);
+    for decimal_places in [0, 1, 2, 5, 10, 11, 16, 22, 23, 100, 301, 400] {
+        for mode in MODES {
+            let mut rounded = values.clone();
+            round_slice_dp_mode(&mut rounded, decimal_places, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                let expected = round_dp_mode(number, decimal_places, mode);
+                assert!(
+                    same(rounded, expected),
+                    "{number} {decimal_places} {mode:?}"
+                );
+            }
+
+            let values: Vec<f32> = values.iter().map(|&number| number as f32).collect();
+            let mut rounded = values.clone();
+            round_slice_dp_mode(&mut rounded, decimal_places, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                let expected = round_dp_mode(number, decimal_places, mode);
+                assert!(
+                    same(rounded as f64, expected as f64),
+                    "{number} {decimal_places} {mode:?}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_slice_zeros_matches_scalar() {
+    let values = random_floats(0x2e_1005, 2_000);
+    for zeros in [0, 1, 2, 5, 15, 30, 40, 400] {
+        for mode in MODES {
+            let mut rounded = values.clone();
+            round_slice_zeros_mode(&mut rounded, zeros, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                let expected = round_zeros_mode(number, zeros, mode);
+                assert!(same(rounded, expected), "{number} {zeros} {mode:?}");
+            }
+
+            let values: Vec<f32> = values.iter().map(|&number| number as f32).collect();
+            let mut rounded = values.clone();
+            round_slice_zeros_mode(&mut rounded, zeros, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                let expected = round_zeros_mode(number, zeros, mode);
+                assert!(
+                    same(rounded as f64, expected as f64),
+                    "{number} {zeros} {mode:?}"
+                );
+            }
+
+            let values: Vec<i64> = values.iter().map(|&number| number as i64).collect();
+            let mut rounded = values.clone();
+            round_slice_zeros_mode(&mut rounded, zeros, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                assert_eq!(rounded, round_zeros_mode(number, zeros, mode), "{number}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_slice_sf_matches_scalar() {
+    let values = random_floats(0x5f_5f5f, 2_000);
+    for sig_figs in [0, 1, 2, 3, 8, 9, 16, 17] {
+        for mode in MODES {
+            let mut rounded = values.clone();
+            round_slice_sf_mode(&mut rounded, sig_figs, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                let expected = round_sf_mode(number, sig_figs, mode);
+                assert!(same(rounded, expected), "{number} {sig_figs} {mode:?}");
+            }
+
+            let values: Vec<u16> = values.iter().map(|&number| number as u16).collect();
+            let mut rounded = values.clone();
+            round_slice_sf_mode(&mut rounded, sig_figs, mode);
+            for (&number, &rounded) in values.iter().zip(&rounded) {
+                assert_eq!(rounded, round_sf_mode(number, sig_figs, mode), "{number}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_round_slice_shorthands() {
+    let values = [1.234_5, -1.234_5, 98_765.4, -0.000_987_6, f64::NAN];
+    let check = |round_slice: fn(&mut [f64], u32), round: fn(f64, u32) -> f64, places| {
+        let mut rounded = values;
+        round_slice(&mut rounded, places);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            assert!(same(rounded, round(number, places)), "{number}");
+        }
+    };
+    check(round_slice_dp, crate::rounding::round, 2);
+    check(ceil_slice_dp, crate::rounding::ceil, 2);
+    check(floor_slice_dp, crate::rounding::floor, 2);
+    check(round_slice_zeros, crate::rounding::round_zeros, 2);
+    check(ceil_slice_zeros, crate::rounding::ceil_zeros, 2);
+    check(floor_slice_zeros, crate::rounding::floor_zeros, 2);
+    check(round_slice_sf, crate::rounding::round_sf, 3);
+    check(ceil_slice_sf, crate::rounding::ceil_sf, 3);
+    check(floor_slice_sf, crate::rounding::floor_sf, 3);
+}
+
+#[test]
+fn test_round_slice_empty() {
+    let mut empty: [f64; 0] = [];
+    round_slice_dp(&mut empty, 2);
+    round_slice_zeros(&mut empty, 2);
+    round_slice_sf(&mut empty, 2);
+
+    let mut empty: [u8; 0] = [];
+    round_slice_zeros(&mut empty, 2);
+    round_slice_sf(&mut empty, 2);
+}
+
+#[test]
+fn test_round_slice_dp_simd_matches_scalar() {
+    let mut values = random_floats(0x51_3d00, 100_003);
+    // Runs of numbers large enough to be rounded one at a time inside a chunk
+    values[1000..1010].fill(1e15);
+    values[2000..2003].fill(f64::NAN);
+
+    for decimal_places in [0, 1, 2, 3, 6, 10, 11, 15, 22, 23, 300] {
+        let mut rounded = values.clone();
+        round_slice_dp_simd(&mut rounded, decimal_places);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            let expected = crate::rounding::round(number, decimal_places);
+            assert!(same(rounded, expected), "{number} {decimal_places}");
+        }
+
+        let values: Vec<f32> = values.iter().map(|&number| number as f32).collect();
+        let mut rounded = values.clone();
+        round_slice_dp_simd(&mut rounded, decimal_places);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            let expected = crate::rounding::round(number, decimal_places);
+            assert!(
+                same(rounded as f64, expected as f64),
+                "{number} {decimal_places}"
+            );
+        }
+    }
+
+    for length in 0..20 {
+        let values = &values[..length];
+        let mut rounded = values.to_vec();
+        round_slice_dp_simd(&mut rounded, 2);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            assert!(same(rounded, crate::rounding::round(number, 2)), "{number}");
+        }
+    }
+}
+
+#[test]
+fn test_round_slice_dp_simd_ties() {
+    let values = [
+        0.5,
+        -0.5,
+        2.5,
+        -2.5,
+        0.499_999_999_999_999_94,
+        -0.499_999_999_999_999_94,
+        0.0,
+        -0.0,
+        1e-310,
+        -1e-310,
+        0.125,
+        -0.125,
+        2_251_799_813_685_247.5,
+        -2_251_799_813_685_247.5,
+        4_503_599_627_370_495.0,
+        f64::INFINITY,
+    ];
+    for decimal_places in [0, 1, 2] {
+        let mut rounded = values;
+        round_slice_dp_simd(&mut rounded, decimal_places);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            let expected = crate::rounding::round(number, decimal_places);
+            assert!(same(rounded, expected), "{number} {decimal_places}");
+        }
+
+        let values = values.map(|number| number as f32);
+        let mut rounded = values;
+        round_slice_dp_simd(&mut rounded, decimal_places);
+        for (&number, &rounded) in values.iter().zip(&rounded) {
+            let expected = crate::rounding::round(number, decimal_places);
+            assert!(
+                same(rounded as f64, expected as f64),
+                "{number} {decimal_places}"
+            );
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use super::{Roundable, RoundingError};
+
+/// Snaps the number to the closest of a set of allowed values, such as standard resistor values or
+/// zoom levels, ties go to the smaller value
+///
+/// `allowed` must be sorted in ascending order, the closest value is then found with a binary search
+///
+/// # Errors
+///
+/// [`RoundingError::EmptyValues`] if there are no allowed values, [`RoundingError::UnsortedValues`]
+/// if they aren't sorted and [`RoundingError::NonFinite`] if the number is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{snap_to_values, RoundingError};
+///
+/// let e6 = [1.0, 1.5, 2.2, 3.3, 4.7, 6.8];
+/// assert_eq!(snap_to_values(2.0, &e6), Ok(2.2));
+/// assert_eq!(snap_to_values(40_u32, &[10, 20, 50, 100]), Ok(50));
+/// assert_eq!(snap_to_values(35_u32, &[10, 20, 50, 100]), Ok(20));
+/// assert_eq!(snap_to_values(1.0, &[]), Err(RoundingError::EmptyValues));
+/// ```
+pub fn snap_to_values<T>(number: T, allowed: &[T]) -> Result<T, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check(number, allowed)?;
+
+    let index = allowed.partition_point(|&value| value < number);
+    let snapped = match (index.checked_sub(1).map(|i| allowed[i]), allowed.get(index)) {
+        (_, Some(&above)) if above == number => above,
+        (Some(below), Some(&above)) if number.nearer_above(below, above) => above,
+        (Some(below), _) => below,
+        (None, Some(&above)) => above,
+        (None, None) => unreachable!(),
+    };
+    Ok(snapped)
+}
+
+/// Snaps the number up to the smallest allowed value that is at least as large as it,
+/// `None` if every allowed value is smaller
+///
+/// `allowed` must be sorted in ascending order, the value is then found with a binary search
+///
+/// # Errors
+///
+/// [`RoundingError::EmptyValues`] if there are no allowed values, [`RoundingError::UnsortedValues`]
+/// if they aren't sorted and [`RoundingError::NonFinite`] if the number is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::snap_up;
+///
+/// let zoom = [0.25, 0.5, 1.0, 2.0, 4.0];
+/// assert_eq!(snap_up(0.6, &zoom), Ok(Some(1.0)));
+/// assert_eq!(snap_up(2.0, &zoom), Ok(Some(2.0)));
+/// assert_eq!(snap_up(5.0, &zoom), Ok(None));
+/// ```
+pub fn snap_up<T>(number: T, allowed: &[T]) -> Result<Option<T>, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check(number, allowed)?;
+
+    let index = allowed.partition_point(|&value| value < number);
+    Ok(allowed.get(index).copied())
+}
+
+/// Snaps the number down to the largest allowed value that is no larger than it,
+/// `None` if every allowed value is larger
+///
+/// `allowed` must be sorted in ascending order, the value is then found with a binary search
+///
+/// # Errors
+///
+/// [`RoundingError::EmptyValues`] if there are no allowed values, [`RoundingError::UnsortedValues`]
+/// if they aren't sorted and [`RoundingError::NonFinite`] if the number is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{snap_down, RoundingError};
+///
+/// let sizes = [8_u8, 10, 12, 14, 18, 24];
+/// assert_eq!(snap_down(13, &sizes), Ok(Some(12)));
+/// assert_eq!(snap_down(4, &sizes), Ok(None));
+/// assert_eq!(snap_down(13, &[12, 8]), Err(RoundingError::UnsortedValues));
+/// ```
+pub fn snap_down<T>(number: T, allowed: &[T]) -> Result<Option<T>, RoundingError>
+where
+    T: Roundable + PartialOrd + Copy,
+{
+    check(number, allowed)?;
+
+    let index = allowed.partition_point(|&value| value <= number);
+    Ok(index.checked_sub(1).map(|index| allowed[index]))
+}
+
+/// Checks the number can be compared and the allowed values can be searched
+#[inline]
+fn check<T: PartialOrd>(number: T, allowed: &[T]) -> Result<(), RoundingError> {
+    if number.partial_cmp(&number).is_none() {
+        Err(RoundingError::NonFinite)
+    } else if allowed.is_empty() {
+        Err(RoundingError::EmptyValues)
+    } else if !allowed.windows(2).all(|pair| pair[0] <= pair[1]) {
+        // NaN can't be ordered so it also counts as unsorted
+        Err(RoundingError::UnsortedValues)
+    } else {
+        Ok(())
+    }
+}
+
+mod tests;
@@ -0,0 +1,101 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+const E12: [f64; 12] = [1.0, 1.2, 1.5, 1.8, 2.2, 2.7, 3.3, 3.9, 4.7, 5.6, 6.8, 8.2];
+
+#[test]
+fn test_e12_resistors() {
+    assert_eq!(snap_to_values(1.15, &E12), Ok(1.2));
+    assert_eq!(snap_to_values(1.95, &E12), Ok(1.8));
+    assert_eq!(snap_to_values(4.4, &E12), Ok(4.7));
+    assert_eq!(snap_to_values(7.4, &E12), Ok(6.8));
+    assert_eq!(snap_up(4.0, &E12), Ok(Some(4.7)));
+    assert_eq!(snap_down(4.0, &E12), Ok(Some(3.9)));
+
+    // Ties go to the smaller value
+    assert_eq!(snap_to_values(1.25, &[1.0, 1.5]), Ok(1.0));
+    assert_eq!(snap_to_values(15_i32, &[10, 20]), Ok(10));
+    assert_eq!(snap_to_values(-15_i32, &[-20, -10]), Ok(-20));
+}
+
+#[test]
+fn test_exact_values() {
+    for value in E12 {
+        assert_eq!(snap_to_values(value, &E12), Ok(value));
+        assert_eq!(snap_up(value, &E12), Ok(Some(value)));
+        assert_eq!(snap_down(value, &E12), Ok(Some(value)));
+    }
+    assert_eq!(snap_to_values(5_u8, &[1, 5, 5, 9]), Ok(5));
+}
+
+#[test]
+fn test_outside_range() {
+    assert_eq!(snap_to_values(0.1, &E12), Ok(1.0));
+    assert_eq!(snap_to_values(100.0, &E12), Ok(8.2));
+    assert_eq!(snap_up(8.3, &E12), Ok(None));
+    assert_eq!(snap_down(0.9, &E12), Ok(None));
+    assert_eq!(snap_up(0.9, &E12), Ok(Some(1.0)));
+    assert_eq!(snap_down(8.3, &E12), Ok(Some(8.2)));
+    assert_eq!(snap_to_values(f64::INFINITY, &E12), Ok(8.2));
+    assert_eq!(
+        snap_to_values(u64::MAX, &[0, u64::MAX - 1]),
+        Ok(u64::MAX - 1)
+    );
+    assert_eq!(
+        snap_to_values(i64::MIN, &[i64::MIN + 1, i64::MAX]),
+        Ok(i64::MIN + 1)
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        snap_to_values(1.0_f64, &[]),
+        Err(RoundingError::EmptyValues)
+    );
+    assert_eq!(snap_up(1_i32, &[]), Err(RoundingError::EmptyValues));
+    assert_eq!(
+        snap_to_values(2.0, &[1.0, 3.0, 2.0]),
+        Err(RoundingError::UnsortedValues)
+    );
+    assert_eq!(
+        snap_down(2.0, &[1.0, f64::NAN, 3.0]),
+        Err(RoundingError::UnsortedValues)
+    );
+    assert_eq!(
+        snap_to_values(f64::NAN, &E12),
+        Err(RoundingError::NonFinite)
+    );
+}
+
+#[test]
+fn test_closest_float() {
+    // The distances are compared exactly, so the closer value wins even when both round the same
+    let below = 0.1_f64;
+    let above = 0.3_f64;
+    let middle = 0.2_f64;
+    let expected = if (middle - below) <= (above - middle) {
+        below
+    } else {
+        above
+    };
+    assert_eq!(snap_to_values(middle, &[below, above]), Ok(expected));
+    assert_eq!(snap_to_values(1e16, &[0.0, 2e16 + 2.0]), Ok(0.0));
+    assert_eq!(
+        snap_to_values(1e16 + 2.0, &[0.0, 2e16 + 2.0]),
+        Ok(2e16 + 2.0)
+    );
+
+    let mut state = 0x51ab_51ab_u64;
+    for _ in 0..10_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        let number = (state % 1000) as f64 / 100.0;
+        let snapped = snap_to_values(number, &E12).unwrap();
+        for value in E12 {
+            assert!((snapped - number).abs() <= (value - number).abs() + 1e-12);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::{digits, powers, Float};
+
+/// Rounds every number to the given number of decimal places while keeping their sum equal to the
+/// rounded sum of the numbers, using the largest remainder method
+///
+/// Each number is rounded on its own first. If those don't add up to the rounded total, the
+/// numbers that lost the most to rounding are moved one step of `10^-decimal_places` up, or the
+/// ones that gained the most are moved one step down, until they do. No number ends up more than
+/// one step from its own rounding, and ties go to the number that comes first so the result is
+/// always the same for the same input
+///
+/// # Panics
+///
+/// Panics if any of the numbers is NaN or infinite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_preserving_sum;
+///
+/// let shares = [100.0 / 3.0, 100.0 / 3.0, 100.0 / 3.0];
+/// assert_eq!(round_preserving_sum(&shares, 1), [33.4, 33.3, 33.3]);
+///
+/// let parts = [0.4, 0.4, 0.4, 0.4, 0.4];
+/// assert_eq!(round_preserving_sum(&parts, 0), [1.0, 1.0, 0.0, 0.0, 0.0]);
+/// ```
+pub fn round_preserving_sum(values: &[f64], decimal_places: u32) -> Vec<f64> {
+    assert!(
+        values.iter().all(|value| value.is_finite()),
+        "numbers must be finite"
+    );
+
+    let power = powers::ten_f64(decimal_places.min(i32::MAX as u32) as i32);
+    let to_steps = |rounded: f64| (rounded * power).round() as i128;
+    let mut steps: Vec<i128> = values
+        .iter()
+        .map(|value| to_steps(value.round_dp(decimal_places)))
+        .collect();
+
+    let total = values.iter().sum::<f64>().round_dp(decimal_places);
+    let missing = to_steps(total) - steps.iter().sum::<i128>();
+    if missing != 0 {
+        // How far each number is from the next step in the direction the sum has to move
+        let direction = missing.signum();
+        let remainders: Vec<f64> = values
+            .iter()
+            .zip(&steps)
+            .map(|(value, &step)| {
+                (value - digits::scale_down::<_, f64>(step, decimal_places)) * direction as f64
+            })
+            .collect();
+
+        let count = usize::try_from(missing.unsigned_abs()).unwrap_or(usize::MAX);
+        for index in by_remainder(&remainders).into_iter().take(count) {
+            steps[index] += direction;
+        }
+    }
+
+    steps
+        .into_iter()
+        .map(|step| digits::scale_down(step, decimal_places))
+        .collect()
+}
+
+/// The indices of the remainders from the largest to the smallest, equal remainders staying in
+/// index order
+pub(super) fn by_remainder(remainders: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..remainders.len()).collect();
+    // The sort is stable, so ties keep the order of their indices
+    order.sort_by(|&a, &b| remainders[b].total_cmp(&remainders[a]));
+    order
+}
+
+mod tests;
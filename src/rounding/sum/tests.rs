@@ -0,0 +1,73 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_percentages() {
+    let shares = [33.333, 33.333, 33.334];
+    let rounded = round_preserving_sum(&shares, 2);
+    assert_eq!(rounded, [33.33, 33.33, 33.34]);
+
+    let counts = [17.0, 23.0, 11.0, 9.0, 41.0, 6.0];
+    let total: f64 = counts.iter().sum();
+    let percentages: Vec<f64> = counts.iter().map(|count| count / total * 100.0).collect();
+    for decimal_places in 0..4 {
+        let rounded = round_preserving_sum(&percentages, decimal_places);
+        let steps: i128 = rounded
+            .iter()
+            .map(|value| (value * 10_f64.powi(decimal_places as i32)).round() as i128)
+            .sum();
+        assert_eq!(steps, 100 * 10_i128.pow(decimal_places));
+    }
+    assert_eq!(
+        round_preserving_sum(&[12.5, 12.5, 12.5, 12.5, 50.0], 0),
+        [12.0, 12.0, 13.0, 13.0, 50.0]
+    );
+}
+
+#[test]
+fn test_negatives() {
+    let values = [-0.4, -0.4, -0.4, -0.4, -0.4];
+    assert_eq!(
+        round_preserving_sum(&values, 0),
+        [-1.0, -1.0, 0.0, 0.0, 0.0]
+    );
+    assert_eq!(
+        round_preserving_sum(&[-1.26, -1.26, 2.52], 1),
+        [-1.2, -1.3, 2.5]
+    );
+    assert_eq!(round_preserving_sum(&[0.0, 0.0, 0.0], 2), [0.0, 0.0, 0.0]);
+    assert!(round_preserving_sum(&[], 2).is_empty());
+}
+
+#[test]
+fn test_invariants() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for _ in 0..1_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+
+        let len = (state % 20) as usize;
+        let decimal_places = (state >> 60) as u32 % 4;
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            values.push((state >> 11) as f64 / (1_u64 << 53) as f64 * 200.0 - 100.0);
+        }
+
+        let power = 10_f64.powi(decimal_places as i32);
+        let rounded = round_preserving_sum(&values, decimal_places);
+        assert_eq!(rounded, round_preserving_sum(&values, decimal_places));
+        let mut steps = 0;
+        for (value, rounded) in values.iter().zip(&rounded) {
+            assert_eq!(*rounded, rounded.round_dp(decimal_places));
+            let naive = value.round_dp(decimal_places);
+            assert!(((rounded - naive) * power).round().abs() <= 1.0);
+            steps += (rounded * power).round() as i128;
+        }
+        let total = values.iter().sum::<f64>().round_dp(decimal_places);
+        assert_eq!(steps, (total * power).round() as i128);
+    }
+}
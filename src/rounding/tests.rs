@@ -35,7 +35,9 @@ fn test_ceil_zeros() {
     assert_eq!(ceil_zeros(123453789_u64, 4), 123460000);
     assert_eq!(ceil_zeros(12345_u32, 0), 12345_u32);
     assert_eq!(ceil_zeros(-12645_i32, 3), -12000_i32);
-    // assert_eq!(ceil_zeros(-12345_i128, 3), -12000_i128);
+    assert_eq!(ceil_zeros(-12345_i128, 3), -12000_i128);
+    assert_eq!(ceil_zeros(u128::MAX - 10, 1), u128::MAX - 5);
+    assert_eq!(ceil_zeros(u128::MAX - 4, 1), u128::MAX);
 }
 
 #[test]
@@ -53,7 +55,83 @@ fn test_floor_zeros() {
     assert_eq!(floor_zeros(123.654_f64, 0), 123_f64);
     assert_eq!(floor_zeros(156_i32, 2), 100_i32);
     assert_eq!(floor_zeros(-12345_i64, 3), -13000_i64);
-    // assert_eq!(round_zeros(123456789_i128, 4), 123450000);
+    assert_eq!(floor_zeros(123456789_i128, 4), 123450000);
+    assert_eq!(floor_zeros(i128::MIN, 1), i128::MIN);
+}
+
+#[test]
+fn test_128_bit_integers() {
+    // Past 2^64, where an f64 can't hold every integer
+    let number = 100_000_000_000_000_000_005_u128;
+    assert_eq!(round_zeros(number, 1), 100_000_000_000_000_000_010);
+    assert_eq!(floor_zeros(number, 1), 100_000_000_000_000_000_000);
+    assert_eq!(round_zeros(number + 1, 0), number + 1);
+    assert_eq!(round_zeros_even(number, 1), 100_000_000_000_000_000_000);
+    assert_eq!(
+        round_sf(-(number as i128) - 6, 20),
+        -100_000_000_000_000_000_010
+    );
+    assert_eq!(
+        round_sf(u128::MAX, 3),
+        340_000_000_000_000_000_000_000_000_000_000_000_000
+    );
+    assert_eq!(
+        floor_zeros(u128::MAX, 1),
+        340_282_366_920_938_463_463_374_607_431_768_211_450
+    );
+    assert_eq!(
+        ceil_zeros(i128::MIN + 1, 1),
+        -170_141_183_460_469_231_731_687_303_715_884_105_720
+    );
+
+    // Near the limits of the types
+    assert_eq!(ceil_sf(u128::MAX, 1), u128::MAX);
+    assert_eq!(u128::MAX.checked_round_sf(4), None);
+    assert_eq!(saturating_round_sf(i128::MIN, 1), i128::MIN);
+    assert_eq!(checked_round_sf(i128::MIN, 2), Some(-170 * 10_i128.pow(36)));
+    assert_eq!(u128::MAX.try_ceil_zeros(1), Err(RoundingError::Overflow));
+    assert_eq!(u128::MAX.checked_round_zeros(39), Some(0));
+    assert_eq!(u128::MAX.checked_ceil_zeros(39), None);
+
+    // Multiples and grids with distances past an i128
+    assert_eq!(round_to_multiple(u128::MAX, 2), u128::MAX);
+    assert_eq!(
+        try_round_to_multiple(u128::MAX, 2),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        floor_quantize(u128::MAX, 10, 3),
+        340_282_366_920_938_463_463_374_607_431_768_211_453
+    );
+    assert_eq!(ceil_quantize(u128::MAX - 1, 10, 3), u128::MAX);
+    assert_eq!(ceil_quantize(-i128::MAX, 3, i128::MAX), i128::MIN + 3);
+    assert_eq!(quantize(-i128::MAX, 4, i128::MAX), i128::MIN);
+    assert_eq!((-i128::MAX).checked_quantize(4, i128::MAX), None);
+    assert_eq!(floor_quantize(i128::MIN, 3, i128::MAX), i128::MIN);
+    assert_eq!(u128::MAX.checked_ceil_quantize(1 << 127, 1), None);
+    assert_eq!(
+        round_zeros_stochastic(u128::MAX - 5, 1, || 0.0),
+        u128::MAX - 5
+    );
+
+    assert_eq!(digit_count(u128::MAX), 39);
+    assert_eq!(count_sig_figs(10_u128.pow(38)), 1);
+    assert!(u128::MAX.digits().eq([3, 4, 0, 2, 8, 2].into_iter().chain([
+        3, 6, 6, 9, 2, 0, 9, 3, 8, 4, 6, 3, 4, 6, 3, 3, 7, 4, 6, 0, 7, 4, 3, 1, 7, 6, 8, 2, 1, 1,
+        4, 5, 5
+    ])));
+    assert_eq!(
+        format_sf(u128::MAX, 4),
+        "340300000000000000000000000000000000000"
+    );
+    assert_eq!(
+        format_sf(i128::MIN, 1),
+        "-200000000000000000000000000000000000000"
+    );
+    assert_eq!(
+        format_sf(u128::MAX, 2),
+        "340000000000000000000000000000000000000"
+    );
 }
 
 #[test]
@@ -66,3 +144,3243 @@ fn test_round_sf() {
     assert_eq!(round_sf(-123456_i64, 2), -120000_i64);
     assert_eq!(round_sf(123.456_f64, 4), 123.5_f64);
 }
+
+#[cfg(test)]
+struct XorShift(u64);
+
+#[cfg(test)]
+impl XorShift {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+/// Rounds using the exact decimal expansion of the number, `direction` is 0 for nearest,
+/// 1 for up and -1 for down
+#[cfg(test)]
+fn reference_sf(number: f64, sig_figs: u32, direction: i32) -> f64 {
+    let formatted = format!("{:.60e}", number.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap();
+    let digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|d| d - b'0')
+        .collect();
+    let (kept, discarded) = digits.split_at(sig_figs as usize);
+
+    let away = match direction * if number < 0.0 { -1 } else { 1 } {
+        0 => discarded[0] >= 5,
+        1 => discarded.iter().any(|&d| d != 0),
+        _ => false,
+    };
+
+    let mut kept: Vec<u8> = kept.to_vec();
+    let mut exponent: i32 = exponent.parse().unwrap();
+    if away {
+        let mut i = kept.len();
+        loop {
+            if i == 0 {
+                kept.insert(0, 1);
+                kept.pop();
+                exponent += 1;
+                break;
+            }
+            i -= 1;
+            if kept[i] == 9 {
+                kept[i] = 0;
+            } else {
+                kept[i] += 1;
+                break;
+            }
+        }
+    }
+
+    let kept: String = kept.iter().map(|d| (d + b'0') as char).collect();
+    let result: f64 = format!("{kept}e{}", exponent - sig_figs as i32 + 1)
+        .parse()
+        .unwrap();
+    if number < 0.0 {
+        -result
+    } else {
+        result
+    }
+}
+
+#[test]
+fn test_get_digits() {
+    assert_eq!(1_f64.get_digits(), 1);
+    assert_eq!(10_f64.get_digits(), 2);
+    assert_eq!(100_f64.get_digits(), 3);
+    assert_eq!(1e15_f64.get_digits(), 16);
+    assert_eq!(0.999999_f64.get_digits(), 0);
+    assert_eq!(0.5_f64.get_digits(), 0);
+    assert_eq!(0.1_f64.get_digits(), 0);
+    assert_eq!(0.099_f64.get_digits(), -1);
+    assert_eq!(0.004567_f64.get_digits(), -2);
+    assert_eq!(0.001_f32.get_digits(), -2);
+    assert_eq!(1_f32.get_digits(), 1);
+    assert_eq!(1000_f32.get_digits(), 4);
+    assert_eq!(1_i32.get_digits(), 1);
+    assert_eq!(10_u8.get_digits(), 2);
+    assert_eq!(100_i16.get_digits(), 3);
+    assert_eq!(1_000_000_000_000_000_i64.get_digits(), 16);
+    assert_eq!(999_999_999_999_999_i64.get_digits(), 15);
+    assert_eq!(u64::MAX.get_digits(), 20);
+    assert_eq!(i64::MIN.get_digits(), 19);
+    assert_eq!((-100_i8).get_digits(), 3);
+
+    for power in 0..=15 {
+        let exact = 10_f64.powi(power);
+        let below = f64::from_bits(exact.to_bits() - 1);
+        let above = f64::from_bits(exact.to_bits() + 1);
+        assert_eq!(exact.get_digits(), power + 1);
+        assert_eq!(below.get_digits(), power);
+        assert_eq!(above.get_digits(), power + 1);
+        assert_eq!((-exact).get_digits(), power + 1);
+    }
+}
+
+#[test]
+fn test_round_sf_powers_of_ten() {
+    assert_eq!(round_sf(1_f64, 1), 1_f64);
+    assert_eq!(round_sf(10_f64, 1), 10_f64);
+    assert_eq!(round_sf(1000_f64, 2), 1000_f64);
+    assert_eq!(round_sf(1.5_f64, 1), 2_f64);
+    assert_eq!(round_sf(15_f64, 1), 20_f64);
+    assert_eq!(ceil_sf(1001_f64, 1), 2000_f64);
+    assert_eq!(floor_sf(1999_i32, 1), 1000_i32);
+    assert_eq!(round_sf(100_i32, 1), 100_i32);
+    assert_eq!(round_sf(1e15_f64, 1), 1e15_f64);
+}
+
+#[test]
+fn test_round_sf_matches_reference() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 30) as i32 - 14;
+        let sig_figs = (rng.next_u64() % 8) as u32 + 1;
+        let number = (1.0 + 9.0 * rng.next_f64()) * 10_f64.powi(exponent);
+        let number = if rng.next_bool() { number } else { -number };
+
+        assert_eq!(
+            round_sf(number, sig_figs),
+            reference_sf(number, sig_figs, 0),
+            "{number} {sig_figs}"
+        );
+        assert_eq!(
+            ceil_sf(number, sig_figs),
+            reference_sf(number, sig_figs, 1),
+            "{number} {sig_figs}"
+        );
+        assert_eq!(
+            floor_sf(number, sig_figs),
+            reference_sf(number, sig_figs, -1),
+            "{number} {sig_figs}"
+        );
+    }
+
+    for _ in 0..100_000 {
+        let digits = (rng.next_u64() % 15) as u32 + 1;
+        let sig_figs = (rng.next_u64() % 8) as u32 + 1;
+        let number = (rng.next_u64() % 10_u64.pow(digits)) as i64;
+        let number = if rng.next_bool() { number } else { -number };
+
+        assert_eq!(
+            round_sf(number, sig_figs),
+            reference_sf(number as f64, sig_figs, 0) as i64,
+            "{number} {sig_figs}"
+        );
+    }
+}
+
+#[test]
+fn test_zero() {
+    assert_eq!(round_sf(0_i32, 5), 0);
+    assert_eq!(round_sf(0.0_f64, 1), 0.0);
+    assert_eq!(round_sf(0.0_f32, 3), 0.0);
+    assert_eq!(ceil_sf(0_u8, 2), 0);
+    assert_eq!(floor_sf(0_i64, 2), 0);
+    assert_eq!(round_zeros(0_u16, 3), 0);
+    assert_eq!(ceil_zeros(0.0_f64, 3), 0.0);
+    assert_eq!(floor_zeros(0_i8, 1), 0);
+
+    for sig_figs in 0..5 {
+        assert_eq!(round_sf(-0.0_f64, sig_figs).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(ceil_sf(-0.0_f64, sig_figs).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(floor_sf(-0.0_f64, sig_figs).to_bits(), (-0.0_f64).to_bits());
+        assert_eq!(round_sf(-0.0_f32, sig_figs).to_bits(), (-0.0_f32).to_bits());
+        assert_eq!(
+            round_zeros(-0.0_f64, sig_figs).to_bits(),
+            (-0.0_f64).to_bits()
+        );
+        assert_eq!(round_sf(0.0_f64, sig_figs).to_bits(), 0);
+    }
+}
+
+#[test]
+fn test_sf_below_one() {
+    assert_eq!(round_sf(0.004567_f64, 1), 0.005);
+    assert_eq!(round_sf(0.004567_f64, 2), 0.0046);
+    assert_eq!(round_sf(0.004567_f64, 3), 0.00457);
+    assert_eq!(ceil_sf(0.004567_f64, 1), 0.005);
+    assert_eq!(ceil_sf(0.004567_f64, 2), 0.0046);
+    assert_eq!(ceil_sf(0.004567_f64, 3), 0.00457);
+    assert_eq!(floor_sf(0.004567_f64, 1), 0.004);
+    assert_eq!(floor_sf(0.004567_f64, 2), 0.0045);
+    assert_eq!(floor_sf(0.004567_f64, 3), 0.00456);
+    assert_eq!(round_sf(0.0999_f64, 2), 0.1);
+    assert_eq!(round_sf(0.0999_f64, 1), 0.1);
+    assert_eq!(round_sf(-0.0321_f64, 2), -0.032);
+    assert_eq!(ceil_sf(-0.0321_f64, 2), -0.032);
+    assert_eq!(floor_sf(-0.0321_f64, 2), -0.033);
+    assert_eq!(round_sf(0.5_f64, 1), 0.5);
+    assert_eq!(round_sf(0.004567_f32, 2), 0.0046);
+    assert_eq!(round_sf(-0.0321_f32, 1), -0.03);
+}
+
+#[test]
+fn test_sf_at_least_digits() {
+    assert_eq!(round_sf(999_u16, 3), 999);
+    assert_eq!(round_sf(999_u16, 2), 1000);
+    assert_eq!(round_sf(1000_u16, 3), 1000);
+    assert_eq!(round_sf(1000_u16, 4), 1000);
+    assert_eq!(round_sf(5_i32, 3), 5);
+    assert_eq!(ceil_sf(-7_i8, 1), -7);
+    assert_eq!(floor_sf(123_u8, 3), 123);
+    assert_eq!(
+        round_sf(18_446_744_073_709_551_557_u64, 20),
+        18_446_744_073_709_551_557
+    );
+    assert_eq!(ceil_sf(i64::MIN + 1, 19), i64::MIN + 1);
+    assert_eq!(floor_sf(u32::MAX, u32::MAX), u32::MAX);
+
+    assert_eq!(round_sf(12.5_f64, 10).to_bits(), 12.5_f64.to_bits());
+    assert_eq!(ceil_sf(12.5_f64, 3).to_bits(), 12.5_f64.to_bits());
+    assert_eq!(floor_sf(0.1_f64, 5).to_bits(), 0.1_f64.to_bits());
+    assert_eq!(round_sf(0.1_f32, 20).to_bits(), 0.1_f32.to_bits());
+    assert_eq!(round_sf(f64::MAX, 17).to_bits(), f64::MAX.to_bits());
+    assert_eq!(
+        floor_sf(1.2345e-8_f32, 5).to_bits(),
+        1.2345e-8_f32.to_bits()
+    );
+    assert_eq!(
+        ceil_sf(3.4655e-14_f64, 12).to_bits(),
+        3.4655e-14_f64.to_bits()
+    );
+    assert_eq!(
+        ceil_sf(0.12345678_f32, 8).to_bits(),
+        0.12345678_f32.to_bits()
+    );
+
+    let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 30) as i32 - 14;
+        let number = (1.0 + 9.0 * rng.next_f64()) * 10_f64.powi(exponent);
+        let sig_figs = (rng.next_u64() % 8) as u32 + 1;
+
+        assert_eq!(round_sf(number, 17).to_bits(), number.to_bits());
+        assert_eq!(ceil_sf(number, 17 + sig_figs).to_bits(), number.to_bits());
+
+        let rounded = round_sf(number, sig_figs);
+        for extra in 0..4 {
+            assert_eq!(
+                round_sf(rounded, sig_figs + extra).to_bits(),
+                rounded.to_bits()
+            );
+            assert_eq!(
+                ceil_sf(rounded, sig_figs + extra).to_bits(),
+                rounded.to_bits()
+            );
+            assert_eq!(
+                floor_sf(rounded, sig_figs + extra).to_bits(),
+                rounded.to_bits()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_integer_exact() {
+    // These are all values where going through an f64 gives the wrong answer
+    assert_eq!(
+        round_zeros(9_007_199_254_740_993_u64, 0),
+        9_007_199_254_740_993
+    );
+    assert_eq!(
+        floor_zeros(9_007_199_254_740_999_u64, 0),
+        9_007_199_254_740_999
+    );
+    assert_eq!(floor_zeros(i64::MIN + 1, 0), i64::MIN + 1);
+    assert_eq!(
+        round_zeros(9_007_199_254_740_993_i64, 1),
+        9_007_199_254_740_990
+    );
+    assert_eq!(
+        ceil_zeros(-9_007_199_254_740_995_i64, 1),
+        -9_007_199_254_740_990
+    );
+    assert_eq!(
+        round_sf(9_007_199_254_740_993_u64, 15),
+        9_007_199_254_740_990
+    );
+    assert_eq!(round_sf(u64::MAX, 18), 18_446_744_073_709_551_600);
+
+    assert_eq!(round_zeros(u64::MAX, 0), u64::MAX);
+    assert_eq!(floor_zeros(u64::MAX, 1), 18_446_744_073_709_551_610);
+    assert_eq!(round_zeros(u64::MAX, 20), 0);
+    assert_eq!(
+        round_zeros(14_999_999_999_999_999_999_u64, 19),
+        10_000_000_000_000_000_000
+    );
+    assert_eq!(round_sf(u64::MAX, 2), 18_000_000_000_000_000_000);
+    assert_eq!(floor_sf(u64::MAX, 1), 10_000_000_000_000_000_000);
+    assert_eq!(round_sf(i64::MIN + 1, 3), -9_220_000_000_000_000_000);
+    assert_eq!(ceil_sf(i64::MIN + 1, 1), -9_000_000_000_000_000_000);
+    assert_eq!(round_zeros(i64::MIN, 18), -9_000_000_000_000_000_000);
+}
+
+#[test]
+fn test_integer_ties() {
+    assert_eq!(round_zeros(15_i32, 1), 20);
+    assert_eq!(round_zeros(-15_i32, 1), -20);
+    assert_eq!(round_zeros(14_u8, 1), 10);
+    assert_eq!(round_zeros(250_u16, 3), 0);
+    assert_eq!(round_zeros(500_u16, 3), 1000);
+    assert_eq!(round_sf(-25_i8, 1), -30);
+    assert_eq!(ceil_zeros(-5_i16, 1), 0);
+    assert_eq!(floor_zeros(5_i16, 1), 0);
+    assert_eq!(floor_zeros(-5_i16, 1), -10);
+    assert_eq!(round_zeros(123_u32, 40), 0);
+    assert_eq!(floor_zeros(123_u32, u32::MAX), 0);
+}
+
+#[test]
+fn test_integer_saturates() {
+    assert_eq!(ceil_zeros(250_u8, 2), u8::MAX);
+    assert_eq!(round_zeros(127_i8, 1), i8::MAX);
+    assert_eq!(floor_zeros(-125_i8, 1), i8::MIN);
+    assert_eq!(round_sf(u64::MAX, 1), u64::MAX);
+    assert_eq!(ceil_zeros(1_u64, 40), u64::MAX);
+    assert_eq!(floor_zeros(-1_i64, 40), i64::MIN);
+}
+
+#[test]
+fn test_checked_u8() {
+    assert_eq!(checked_ceil_zeros(250_u8, 2), None);
+    assert_eq!(checked_round_zeros(250_u8, 2), None);
+    assert_eq!(checked_round_zeros(249_u8, 2), Some(200));
+    assert_eq!(checked_floor_zeros(255_u8, 2), Some(200));
+    assert_eq!(checked_round_zeros(255_u8, 0), Some(255));
+    assert_eq!(checked_round_zeros(255_u8, 1), None);
+    assert_eq!(checked_round_zeros(254_u8, 1), Some(250));
+    assert_eq!(checked_ceil_zeros(201_u8, 1), Some(210));
+    assert_eq!(checked_round_zeros(255_u8, 3), Some(0));
+    assert_eq!(checked_ceil_zeros(1_u8, 3), None);
+    assert_eq!(checked_round_sf(255_u8, 1), None);
+    assert_eq!(checked_round_sf(249_u8, 1), Some(200));
+    assert_eq!(checked_ceil_sf(201_u8, 1), None);
+    assert_eq!(checked_floor_sf(255_u8, 1), Some(200));
+    assert_eq!(checked_round_sf(0_u8, 1), Some(0));
+}
+
+#[test]
+fn test_checked_i8() {
+    assert_eq!(checked_round_zeros(127_i8, 1), None);
+    assert_eq!(checked_round_zeros(124_i8, 1), Some(120));
+    assert_eq!(checked_round_zeros(-128_i8, 1), None);
+    assert_eq!(checked_round_zeros(-124_i8, 1), Some(-120));
+    assert_eq!(checked_ceil_zeros(-128_i8, 1), Some(-120));
+    assert_eq!(checked_floor_zeros(127_i8, 1), Some(120));
+    assert_eq!(checked_floor_zeros(-121_i8, 1), None);
+    assert_eq!(checked_round_zeros(-128_i8, 0), Some(-128));
+    assert_eq!(checked_round_sf(-128_i8, 3), Some(-128));
+    assert_eq!(checked_round_sf(-128_i8, 2), None);
+    assert_eq!(checked_ceil_sf(-128_i8, 1), Some(-100));
+    assert_eq!(checked_floor_sf(-101_i8, 1), None);
+}
+
+#[test]
+fn test_checked_i16() {
+    assert_eq!(checked_round_zeros(32_767_i16, 1), None);
+    assert_eq!(checked_round_zeros(32_764_i16, 1), Some(32_760));
+    assert_eq!(checked_ceil_zeros(32_761_i16, 1), None);
+    assert_eq!(checked_floor_zeros(-32_761_i16, 1), None);
+    assert_eq!(checked_floor_zeros(-32_760_i16, 1), Some(-32_760));
+    assert_eq!(checked_round_zeros(-32_768_i16, 4), Some(-30_000));
+    assert_eq!(checked_round_zeros(-32_768_i16, 5), Some(0));
+    assert_eq!(checked_floor_zeros(-1_i16, 5), None);
+    assert_eq!(checked_round_sf(32_767_i16, 2), None);
+    assert_eq!(checked_round_sf(32_467_i16, 2), Some(32_000));
+    assert_eq!(checked_ceil_sf(-32_768_i16, 1), Some(-30_000));
+}
+
+#[test]
+fn test_references() {
+    let values = [1.234_f64, -5.678, 9.999];
+    let rounded: Vec<f64> = values.iter().map(|value| value.round_dp(2)).collect();
+    assert_eq!(rounded, [1.23, -5.68, 10.0]);
+    let rounded: Vec<f64> = values.iter().map(|value| value.round_sf(1)).collect();
+    assert_eq!(rounded, [1.0, -6.0, 10.0]);
+
+    let counts = [1234_u32, 5678];
+    let rounded: Vec<u32> = counts.iter().map(|count| count.round_zeros(2)).collect();
+    assert_eq!(rounded, [1200, 5700]);
+
+    fn round_each<T: Float + Copy>(values: &[T], decimal_places: u32) -> Vec<T> {
+        values
+            .iter()
+            .map(|value| value.round_dp(decimal_places))
+            .collect()
+    }
+    fn is_whole(value: impl Float + PartialEq + Copy) -> bool {
+        value.round_dp(0) == value
+    }
+    assert_eq!(round_each(&[0.125_f32, 0.135], 2), [0.13, 0.14]);
+    assert!(values.iter().all(|value| !is_whole(*value)));
+    assert!([2.0_f64, -3.0].iter().copied().all(is_whole));
+}
+
+#[test]
+fn test_pointer_sized_integers() {
+    assert_eq!(round_zeros(1234_usize, 2), 1200);
+    assert_eq!(ceil_zeros(1201_usize, 2), 1300);
+    assert_eq!(floor_zeros(-1201_isize, 2), -1300);
+    assert_eq!(round_sf(-1250_isize, 2), -1300);
+    assert_eq!(round_sf_even(1250_usize, 2), 1200);
+    assert_eq!(round_to_multiple(1023_usize, 64), 1024);
+    assert_eq!(quantize(-7_isize, 4, 1), -7);
+    assert_eq!(digit_count(4096_usize), 4);
+
+    // The bounds differ between pointer widths, so the expectations come from the fixed width
+    // integer of the same size
+    #[cfg(target_pointer_width = "32")]
+    type Signed = i32;
+    #[cfg(target_pointer_width = "32")]
+    type Unsigned = u32;
+    #[cfg(target_pointer_width = "64")]
+    type Signed = i64;
+    #[cfg(target_pointer_width = "64")]
+    type Unsigned = u64;
+    #[cfg(any(target_pointer_width = "32", target_pointer_width = "64"))]
+    {
+        for zeros in 0..22 {
+            assert_eq!(
+                round_zeros(usize::MAX, zeros) as Unsigned,
+                round_zeros(Unsigned::MAX, zeros)
+            );
+            assert_eq!(
+                checked_ceil_zeros(usize::MAX - 9, zeros).map(|n| n as Unsigned),
+                checked_ceil_zeros(Unsigned::MAX - 9, zeros)
+            );
+            assert_eq!(
+                floor_zeros(isize::MIN + 1, zeros) as Signed,
+                floor_zeros(Signed::MIN + 1, zeros)
+            );
+            assert_eq!(
+                checked_round_sf(isize::MAX, zeros).map(|n| n as Signed),
+                checked_round_sf(Signed::MAX, zeros)
+            );
+        }
+    }
+    assert_eq!(saturating_ceil_sf(usize::MAX, 1), usize::MAX);
+    assert_eq!(saturating_floor_sf(isize::MIN, 1), isize::MIN);
+    assert_eq!(checked_ceil_zeros(usize::MAX, 1), None);
+}
+
+#[test]
+fn test_checked_float() {
+    assert_eq!(checked_round_zeros(123.456_f64, 1), Some(120.0));
+    assert_eq!(checked_round_sf(123.456_f32, 4), Some(123.5));
+    assert_eq!(checked_round_zeros(f64::NAN, 1), None);
+    assert_eq!(checked_ceil_zeros(f64::INFINITY, 1), None);
+    assert_eq!(checked_floor_sf(f64::NEG_INFINITY, 1), None);
+    assert_eq!(checked_ceil_sf(f32::NAN, 3), None);
+    assert_eq!(checked_round_sf(f64::MAX, 1), None);
+}
+
+#[test]
+fn test_saturating_signed() {
+    assert_eq!(saturating_round_zeros(127_i8, 1), i8::MAX);
+    assert_eq!(saturating_ceil_zeros(121_i8, 1), i8::MAX);
+    assert_eq!(saturating_ceil_zeros(1_i8, 3), i8::MAX);
+    assert_eq!(saturating_round_zeros(-128_i8, 1), i8::MIN);
+    assert_eq!(saturating_floor_zeros(-120_i8, 2), i8::MIN);
+    assert_eq!(saturating_floor_zeros(-1_i8, 3), i8::MIN);
+    assert_eq!(saturating_round_sf(-128_i8, 2), i8::MIN);
+    assert_eq!(saturating_floor_sf(-121_i8, 1), i8::MIN);
+    assert_eq!(saturating_ceil_sf(101_i8, 1), i8::MAX);
+    assert_eq!(saturating_ceil_zeros(-128_i8, 1), -120);
+    assert_eq!(saturating_floor_zeros(127_i8, 1), 120);
+
+    assert_eq!(saturating_round_zeros(i64::MAX, 1), i64::MAX);
+    assert_eq!(saturating_round_zeros(i64::MIN, 1), i64::MIN);
+    assert_eq!(saturating_ceil_sf(i64::MAX, 1), i64::MAX);
+    assert_eq!(saturating_floor_sf(i64::MIN, 1), i64::MIN);
+    assert_eq!(saturating_round_sf(i64::MIN, 1), -9_000_000_000_000_000_000);
+    assert_eq!(saturating_round_zeros(i32::MIN, 40), 0);
+    assert_eq!(saturating_floor_zeros(i32::MIN, 40), i32::MIN);
+}
+
+#[test]
+fn test_saturating_unsigned() {
+    assert_eq!(saturating_ceil_zeros(250_u8, 2), u8::MAX);
+    assert_eq!(saturating_round_zeros(250_u8, 2), u8::MAX);
+    assert_eq!(saturating_round_zeros(249_u8, 2), 200);
+    assert_eq!(saturating_ceil_sf(201_u8, 1), u8::MAX);
+    assert_eq!(saturating_round_sf(255_u8, 2), u8::MAX);
+    assert_eq!(saturating_floor_sf(255_u8, 1), 200);
+    assert_eq!(saturating_floor_zeros(0_u8, 2), 0);
+    assert_eq!(saturating_ceil_zeros(u64::MAX, 1), u64::MAX);
+    assert_eq!(saturating_round_sf(u64::MAX, 1), u64::MAX);
+    assert_eq!(saturating_floor_sf(u64::MAX, 1), 10_000_000_000_000_000_000);
+    assert_eq!(saturating_ceil_zeros(1_u32, u32::MAX), u32::MAX);
+}
+
+#[test]
+fn test_saturating_float() {
+    assert_eq!(saturating_round_zeros(123.456_f64, 1), 120.0);
+    assert_eq!(saturating_ceil_sf(123.456_f32, 2), 130.0);
+    assert_eq!(saturating_floor_sf(-123.456_f64, 2), -130.0);
+}
+
+#[test]
+fn test_try_errors() {
+    assert_eq!(try_round_zeros(f64::NAN, 1), Err(RoundingError::NonFinite));
+    assert_eq!(
+        try_ceil_zeros(f32::INFINITY, 1),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(
+        try_floor_sf(f64::NEG_INFINITY, 2),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(try_round_sf(f64::NAN, 0), Err(RoundingError::NonFinite));
+
+    assert_eq!(
+        try_round_sf(123.456_f64, 0),
+        Err(RoundingError::ZeroSigFigs)
+    );
+    assert_eq!(try_ceil_sf(0_f32, 0), Err(RoundingError::ZeroSigFigs));
+    assert_eq!(try_floor_sf(0_u8, 0), Err(RoundingError::ZeroSigFigs));
+    assert_eq!(try_round_sf(123_i64, 0), Err(RoundingError::ZeroSigFigs));
+
+    assert_eq!(try_round_zeros(127_i8, 1), Err(RoundingError::Overflow));
+    assert_eq!(try_floor_zeros(-121_i8, 1), Err(RoundingError::Overflow));
+    assert_eq!(try_ceil_sf(201_u8, 1), Err(RoundingError::Overflow));
+    assert_eq!(try_round_sf(f64::MAX, 1), Err(RoundingError::Overflow));
+}
+
+#[test]
+fn test_try_matches_infallible() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..1000 {
+        let number = rng.next_u64() as i32 >> 8;
+        let float = rng.next_f64() * 1e6 - 5e5;
+        let zeros = (rng.next_u64() % 8) as u32;
+        let sig_figs = (rng.next_u64() % 8) as u32 + 1;
+
+        assert_eq!(
+            try_round_zeros(number, zeros),
+            Ok(round_zeros(number, zeros))
+        );
+        assert_eq!(try_ceil_sf(number, sig_figs), Ok(ceil_sf(number, sig_figs)));
+        assert_eq!(try_floor_zeros(float, zeros), Ok(floor_zeros(float, zeros)));
+        assert_eq!(try_round_sf(float, sig_figs), Ok(round_sf(float, sig_figs)));
+    }
+}
+
+#[test]
+fn test_non_finite() {
+    assert!(round(f64::NAN, 2).is_nan());
+    assert!(ceil(f32::NAN, 2).is_nan());
+    assert_eq!(ceil(f64::INFINITY, 3), f64::INFINITY);
+    assert_eq!(floor(f64::NEG_INFINITY, 1), f64::NEG_INFINITY);
+
+    macro_rules! check {
+        ($t:ty) => {
+            for number in [<$t>::NAN, <$t>::INFINITY, <$t>::NEG_INFINITY] {
+                for n in [0, 1, 2, 5, 20] {
+                    let results = [
+                        number.round_dp(n),
+                        number.ceil_dp(n),
+                        number.floor_dp(n),
+                        number.round_zeros(n),
+                        number.ceil_zeros(n),
+                        number.floor_zeros(n),
+                        number.round_sf(n),
+                        number.ceil_sf(n),
+                        number.floor_sf(n),
+                    ];
+                    for result in results {
+                        if number.is_nan() {
+                            assert!(result.is_nan());
+                        } else {
+                            assert_eq!(result, number);
+                        }
+                    }
+                }
+            }
+        };
+    }
+    check!(f32);
+    check!(f64);
+}
+
+#[test]
+fn test_huge_decimal_places() {
+    for decimal_places in [100, 308, 309, 400, u32::MAX] {
+        for number in [123.456_f64, -0.1, 1e300, -f64::MAX, 2.5e-30] {
+            assert_eq!(round(number, decimal_places), number);
+            assert_eq!(ceil(number, decimal_places), number);
+            assert_eq!(floor(number, decimal_places), number);
+        }
+        for number in [123.456_f32, -0.1, 1e30, -f32::MAX, 2.5e-30] {
+            assert_eq!(round(number, decimal_places), number);
+            assert_eq!(ceil(number, decimal_places), number);
+            assert_eq!(floor(number, decimal_places), number);
+        }
+    }
+
+    assert_eq!(round(1.2e-309_f64, 309), 1e-309);
+    assert_eq!(ceil(1.2e-309_f64, 309), 2e-309);
+    assert_eq!(floor(-1.2e-309_f64, 309), -2e-309);
+    assert_eq!(round(1.2e-309_f64, 300), 0.0);
+    assert_eq!(ceil(5e-324_f64, 323), 1e-323);
+    assert_eq!(round(1.2e-40_f32, 40), 1e-40);
+    assert_eq!(ceil(1e-45_f32, 44), 1e-44);
+}
+
+#[test]
+fn test_huge_zeros() {
+    for zeros in [100, 308, 309, 400, u32::MAX] {
+        assert_eq!(round_zeros(123.456_f64, zeros), 0.0);
+        assert_eq!(floor_zeros(123.456_f64, zeros), 0.0);
+        assert_eq!(ceil_zeros(-123.456_f64, zeros), 0.0);
+        assert_eq!(round_zeros(-5e-324_f64, zeros), 0.0);
+        assert_eq!(round_zeros(123.456_f32, zeros), 0.0);
+        assert_eq!(ceil_zeros(-f32::MAX, zeros), 0.0);
+    }
+
+    assert!((ceil_zeros(123.456_f64, 100) / 1e100 - 1.0).abs() < 1e-15);
+    assert!((floor_zeros(-123.456_f64, 308) / -1e308 - 1.0).abs() < 1e-15);
+    assert!((ceil_zeros(5e-324_f64, 308) / 1e308 - 1.0).abs() < 1e-15);
+    assert_eq!(ceil_zeros(123.456_f64, 309), f64::INFINITY);
+    assert_eq!(floor_zeros(-123.456_f64, u32::MAX), f64::NEG_INFINITY);
+    assert!((ceil_zeros(1e-45_f32, 38) / 1e38 - 1.0).abs() < 1e-6);
+    assert_eq!(ceil_zeros(123.456_f32, 39), f32::INFINITY);
+    assert_eq!(round_zeros(f64::MAX, 308), f64::INFINITY);
+}
+
+#[test]
+fn test_negative_zero() {
+    macro_rules! check {
+        ($t:ty) => {
+            let negative_zero = (-0.0 as $t).to_bits();
+            for number in [-0.0 as $t, -0.0025, -0.0001, -1e-30] {
+                assert_eq!(number.round_dp(0).to_bits(), negative_zero);
+                assert_eq!(number.ceil_dp(2).to_bits(), negative_zero);
+                assert_eq!(number.round_zeros(1).to_bits(), negative_zero);
+                assert_eq!(number.ceil_zeros(3).to_bits(), negative_zero);
+                assert_eq!(number.round_zeros(u32::MAX).to_bits(), negative_zero);
+            }
+            assert_eq!((-0.0 as $t).floor_dp(2).to_bits(), negative_zero);
+            assert_eq!((-0.0 as $t).floor_zeros(2).to_bits(), negative_zero);
+            for sig_figs in [0, 1, 3, 20] {
+                assert_eq!((-0.0 as $t).round_sf(sig_figs).to_bits(), negative_zero);
+                assert_eq!((-0.0 as $t).ceil_sf(sig_figs).to_bits(), negative_zero);
+                assert_eq!((-0.0 as $t).floor_sf(sig_figs).to_bits(), negative_zero);
+            }
+            assert_eq!((-0.4 as $t).round_sf(0).to_bits(), negative_zero);
+            assert_eq!((0.0 as $t).round_dp(2).to_bits(), (0.0 as $t).to_bits());
+            assert_eq!((0.25 as $t).round_dp(0).to_bits(), (0.0 as $t).to_bits());
+        };
+    }
+    check!(f32);
+    check!(f64);
+
+    assert_eq!(round(-0.25_f64, 0).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(ceil(-0.0001_f64, 2).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(round_sf(-0.0_f64, 3).to_bits(), (-0.0_f64).to_bits());
+}
+
+#[test]
+fn test_subnormal_sf() {
+    assert_eq!(round_sf(f64::MIN_POSITIVE, 3), 2.23e-308);
+    assert_eq!(ceil_sf(f64::MIN_POSITIVE, 1), 3e-308);
+    assert_eq!(floor_sf(-f64::MIN_POSITIVE, 2), -2.3e-308);
+    assert_eq!(round_sf(1.2345678e-315_f64, 3), 1.23e-315);
+    assert_eq!(ceil_sf(1.2345678e-315_f64, 5), 1.2346e-315);
+    assert_eq!(floor_sf(-1.2345678e-315_f64, 1), -2e-315);
+    assert_eq!(round_sf(5e-324_f64, 1), 5e-324);
+    assert_eq!(ceil_sf(5e-324_f64, 1), 5e-324);
+    assert_eq!(round_sf(-7.4e-323_f64, 1), -7e-323);
+
+    assert_eq!(round_sf(f32::MIN_POSITIVE, 3), 1.18e-38);
+    assert_eq!(floor_sf(f32::MIN_POSITIVE, 1), 1e-38);
+    assert_eq!(round_sf(1.2345e-40_f32, 2), 1.2e-40);
+    assert_eq!(ceil_sf(-1.2345e-40_f32, 3), -1.23e-40);
+    assert_eq!(round_sf(1e-45_f32, 1), 1e-45);
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100_000 {
+        let number = f64::from_bits(rng.next_u64() % (1 << 52) + 1);
+        let number = if rng.next_bool() { number } else { -number };
+        let sig_figs = (rng.next_u64() % 20) as u32;
+        for result in [
+            round_sf(number, sig_figs),
+            ceil_sf(number, sig_figs),
+            floor_sf(number, sig_figs),
+        ] {
+            assert!(result.is_finite(), "{number:e} {sig_figs}");
+        }
+
+        // Subnormals have fewer digits of precision, so only compare rounding well within them
+        let sig_figs = sig_figs % 6 + 1;
+        if digits::shortest_sig_figs(number) <= sig_figs + 1 {
+            continue;
+        }
+        assert_eq!(
+            round_sf(number, sig_figs),
+            reference_sf(number, sig_figs, 0)
+        );
+        assert_eq!(ceil_sf(number, sig_figs), reference_sf(number, sig_figs, 1));
+        assert_eq!(
+            floor_sf(number, sig_figs),
+            reference_sf(number, sig_figs, -1)
+        );
+    }
+
+    for _ in 0..100_000 {
+        let number = f32::from_bits((rng.next_u64() % (1 << 23)) as u32 + 1);
+        let sig_figs = (rng.next_u64() % 10) as u32;
+        for result in [
+            round_sf(number, sig_figs),
+            ceil_sf(number, sig_figs),
+            floor_sf(number, sig_figs),
+        ] {
+            assert!(result.is_finite(), "{number:e} {sig_figs}");
+        }
+
+        let sig_figs = sig_figs % 3 + 1;
+        if digits::shortest_sig_figs(number) <= sig_figs + 1 {
+            continue;
+        }
+        assert_eq!(
+            round_sf(number, sig_figs),
+            reference_sf(number as f64, sig_figs, 0) as f32
+        );
+        assert_eq!(
+            ceil_sf(number, sig_figs),
+            reference_sf(number as f64, sig_figs, 1) as f32
+        );
+        assert_eq!(
+            floor_sf(number, sig_figs),
+            reference_sf(number as f64, sig_figs, -1) as f32
+        );
+    }
+}
+
+#[test]
+fn test_idempotent() {
+    macro_rules! check {
+        ($t:ty, $rng:expr) => {
+            let exponent = ($rng.next_u64() % 20) as i32 - 10;
+            let number = ($rng.next_f64() * 10.0 - 5.0) as $t * (10.0 as $t).powi(exponent);
+            let n = ($rng.next_u64() % 11) as u32;
+
+            let operations: [(fn($t, u32) -> $t, u32); 9] = [
+                (round, n),
+                (ceil, n),
+                (floor, n),
+                (round_zeros, n),
+                (ceil_zeros, n),
+                (floor_zeros, n),
+                // Zero significant figures has no meaningful result so isn't expected to be stable
+                (round_sf, n.max(1)),
+                (ceil_sf, n.max(1)),
+                (floor_sf, n.max(1)),
+            ];
+            for (index, (operation, n)) in operations.into_iter().enumerate() {
+                let once = operation(number, n);
+                let twice = operation(once, n);
+                assert_eq!(twice.to_bits(), once.to_bits(), "{index} {number} {n}");
+            }
+        };
+    }
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..1_000_000 {
+        check!(f64, rng);
+        check!(f32, rng);
+    }
+}
+
+#[test]
+fn test_on_grid_unchanged() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..1_000_000 {
+        let decimal_places = (rng.next_u64() % 11) as u32;
+        let steps = (rng.next_u64() % 10_000_000) as i64 - 5_000_000;
+
+        let number = steps as f64 / 10_f64.powi(decimal_places as i32);
+        assert_eq!(ceil(number, decimal_places), number, "{number}");
+        assert_eq!(floor(number, decimal_places), number, "{number}");
+        assert_eq!(round(number, decimal_places), number, "{number}");
+
+        let number = (steps % 100_000) as f32 / 10_f32.powi(decimal_places as i32);
+        assert_eq!(ceil(number, decimal_places), number, "{number}");
+        assert_eq!(floor(number, decimal_places), number, "{number}");
+    }
+
+    assert_eq!(ceil(1.15_f64, 2), 1.15);
+    assert_eq!(floor(1.15_f64, 2), 1.15);
+    assert_eq!(ceil(0.1_f64 + 0.2, 1), 0.4);
+    assert_eq!(ceil(4.35_f64, 2), 4.35);
+    assert_eq!(floor(4.35_f64 * 100.0 / 100.0, 2), 4.35);
+}
+
+#[test]
+fn test_large_scaled_on_grid() {
+    // Scaled past 2^52 the number has no fraction left, but isn't on the grid itself
+    assert_eq!(round(-522_871.134_076_647_87_f64, 10), -522_871.134_076_647_9);
+    assert_eq!(
+        round(-522_871.134_076_647_87_f64, 10),
+        round_exact(-522_871.134_076_647_87_f64, 10)
+    );
+    assert_eq!(ceil(472_606_156_112_090.25_f64, 1), 472_606_156_112_090.3);
+    assert_eq!(floor(472_606_156_112_090.25_f64, 1), 472_606_156_112_090.2);
+    let rounded = round(472_606_156_112_090.25_f64, 1);
+    assert_ne!(rounded, 472_606_156_112_090.25);
+    assert_eq!(format!("{rounded:.1}").parse::<f64>(), Ok(rounded));
+    assert_eq!(ceil(1_000_000.06_f32, 1), 1_000_000.1);
+    assert_eq!(floor(1_000_000.06_f32, 1), 1_000_000.0);
+    assert_eq!(floor(-1_000_000.06_f32, 1), -1_000_000.1);
+
+    let rounded = round(-4.675_627_670_313_210_5e-5_f64, 20);
+    assert_eq!(format!("{rounded:.20}").parse::<f64>(), Ok(rounded));
+    let rounded = round(-103.064_514_f32, 5);
+    assert_ne!(rounded, -103.064_514);
+    assert_eq!(format!("{rounded:.5}").parse::<f32>(), Ok(rounded));
+
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..1_000_000 {
+        let decimal_places = (rng.next_u64() % 16) as u32;
+        // Scaled by the power the number lands between 2^50 and 2^54
+        let number = (rng.next_f64() * 15.0 + 1.0) * 2_f64.powi(50)
+            / 10_f64.powi(decimal_places as i32);
+        let number = if rng.next_bool() { -number } else { number };
+        for (index, rounded) in [
+            round(number, decimal_places),
+            ceil(number, decimal_places),
+            floor(number, decimal_places),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let on_grid = format!("{rounded:.*}", decimal_places as usize);
+            assert_eq!(
+                on_grid.parse::<f64>(),
+                Ok(rounded),
+                "{index} {number} {decimal_places}"
+            );
+        }
+        assert!(ceil(number, decimal_places) >= number);
+        assert!(floor(number, decimal_places) <= number);
+    }
+}
+
+/// Rounds the digits of the shortest representation of a number given by its `Display` output,
+/// `direction` is 0 for nearest with ties away from zero, 1 for up and -1 for down
+#[cfg(test)]
+fn reference_exact(formatted: &str, decimal_places: usize, direction: i32) -> String {
+    let (sign, magnitude) = match formatted.strip_prefix('-') {
+        Some(magnitude) => ("-", magnitude),
+        None => ("", formatted),
+    };
+    let (integer, fraction) = magnitude.split_once('.').unwrap_or((magnitude, ""));
+    if fraction.len() <= decimal_places {
+        return formatted.to_string();
+    }
+
+    let (kept, discarded) = fraction.split_at(decimal_places);
+    let away = match direction * if sign == "-" { -1 } else { 1 } {
+        0 => discarded.as_bytes()[0] >= b'5',
+        1 => discarded.bytes().any(|d| d != b'0'),
+        _ => false,
+    };
+
+    let mut digits: Vec<u8> = format!("{integer}{kept}").into_bytes();
+    if away {
+        let mut i = digits.len();
+        loop {
+            if i == 0 {
+                digits.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    let digits = String::from_utf8(digits).unwrap();
+    format!("{sign}{digits}e-{decimal_places}")
+}
+
+#[test]
+fn test_exact_trap_values() {
+    let table: [(f64, u32, f64, f64, f64); 12] = [
+        // (number, decimal places, round, ceil, floor)
+        (2.675, 2, 2.68, 2.68, 2.67),
+        (0.145, 2, 0.15, 0.15, 0.14),
+        (1.005, 2, 1.01, 1.01, 1.0),
+        (1.115, 2, 1.12, 1.12, 1.11),
+        (8.345, 2, 8.35, 8.35, 8.34),
+        (0.285, 2, 0.29, 0.29, 0.28),
+        (1.45, 1, 1.5, 1.5, 1.4),
+        (2.5, 0, 3.0, 3.0, 2.0),
+        (1.15, 2, 1.15, 1.15, 1.15),
+        (4.35, 1, 4.4, 4.4, 4.3),
+        (0.1 + 0.2, 1, 0.3, 0.4, 0.3),
+        (-2.675, 2, -2.68, -2.67, -2.68),
+    ];
+
+    for (number, decimal_places, rounded, ceiled, floored) in table {
+        assert_eq!(round_exact(number, decimal_places), rounded, "{number}");
+        assert_eq!(ceil_exact(number, decimal_places), ceiled, "{number}");
+        assert_eq!(floor_exact(number, decimal_places), floored, "{number}");
+    }
+
+    assert_eq!(round(1.005_f64, 2), 1.0);
+    assert_eq!(round_exact(2.675_f32, 2), 2.68);
+    assert_eq!(round_exact(1.005_f32, 2), 1.01);
+    assert_eq!(floor_exact(-0.145_f32, 2), -0.15);
+    assert_eq!(round_exact(0.0049_f64, 2).to_bits(), 0.0_f64.to_bits());
+    assert_eq!(round_exact(-0.0049_f64, 2).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(ceil_exact(1e-300_f64, 0), 1.0);
+    assert_eq!(round_exact(1e300_f64, 2), 1e300);
+    assert_eq!(round_exact(123.456_f64, u32::MAX), 123.456);
+    assert!(round_exact(f64::NAN, 2).is_nan());
+    assert_eq!(ceil_exact(f64::INFINITY, 2), f64::INFINITY);
+}
+
+#[test]
+fn test_exact_matches_reference() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 20) as i32 - 10;
+        let digits = (rng.next_u64() % 8) as i32 + 1;
+        let decimal_places = (rng.next_u64() % 12) as u32;
+
+        // Short decimals are the interesting case as they often sit exactly on a tie
+        let steps = rng.next_u64() % 10_u64.pow(digits as u32);
+        let number: f64 = format!("{steps}e{}", exponent - digits).parse().unwrap();
+        let number = if rng.next_bool() { number } else { -number };
+
+        for (direction, result) in [
+            (0, round_exact(number, decimal_places)),
+            (1, ceil_exact(number, decimal_places)),
+            (-1, floor_exact(number, decimal_places)),
+        ] {
+            let expected = reference_exact(&number.to_string(), decimal_places as usize, direction);
+            assert_eq!(
+                result,
+                expected.parse::<f64>().unwrap(),
+                "{number} {decimal_places}"
+            );
+        }
+
+        let number = number as f32;
+        for (direction, result) in [
+            (0, round_exact(number, decimal_places)),
+            (1, ceil_exact(number, decimal_places)),
+            (-1, floor_exact(number, decimal_places)),
+        ] {
+            let expected = reference_exact(&number.to_string(), decimal_places as usize, direction);
+            assert_eq!(
+                result,
+                expected.parse::<f32>().unwrap(),
+                "{number} {decimal_places}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_round_to_match() {
+    assert_eq!(round_to_match(12.3456_f64, 0.023), 12.346);
+    assert_eq!(round_to_match(-12.3456_f64, 0.023), -12.346);
+    assert_eq!(round_to_match(12.3456_f64, -0.5), 12.3);
+    assert_eq!(round_to_match(12.3456_f32, 0.003), 12.346_f32);
+
+    // References of at least one
+    assert_eq!(round_to_match(12.3456_f64, 3.0), 12.0);
+    assert_eq!(round_to_match(12.3456_f64, 1.5), 12.3);
+    assert_eq!(round_to_match(1234.5_f64, 20.0), 1230.0);
+    assert_eq!(round_to_match(1234.5_f32, 2e3), 1e3_f32);
+
+    // References larger than the value
+    assert_eq!(round_to_match(12.3456_f64, 300.0), 0.0);
+    assert_eq!(round_to_match(0.012_f64, 0.5), 0.0);
+    assert_eq!(round_to_match(-0.012_f64, 0.5), -0.0);
+    assert_eq!(round_to_match(0.012_f64, 0.5).to_bits(), 0_f64.to_bits());
+
+    // References that don't give a position
+    assert_eq!(round_to_match(12.3456_f64, 0.0), 12.3456);
+    assert_eq!(round_to_match(12.3456_f64, -0.0), 12.3456);
+    assert_eq!(round_to_match(12.3456_f32, f32::INFINITY), 12.3456);
+    assert_eq!(round_to_match(12.3456_f64, f64::NAN), 12.3456);
+    assert!(round_to_match(f64::NAN, 0.1).is_nan());
+}
+
+#[test]
+fn test_round_pair_sf() {
+    assert_eq!(round_pair_sf(12.3456_f64, 0.0234, 2), (12.346, 0.023));
+    assert_eq!(round_pair_sf(12.3456_f64, 0.0234, 1), (12.35, 0.02));
+    assert_eq!(round_pair_sf(-12.3456_f64, 0.0234, 1), (-12.35, 0.02));
+    assert_eq!(round_pair_sf(12.3456_f32, 0.0234, 2), (12.346, 0.023));
+
+    // Rounding the uncertainty up a digit keeps the position of its significant figures
+    assert_eq!(round_pair_sf(12.3456_f64, 0.0996, 2), (12.35, 0.1));
+    assert_eq!(round_pair_sf(12.3456_f64, 0.096, 1), (12.3, 0.1));
+
+    // Uncertainties of at least one and larger than the value
+    assert_eq!(round_pair_sf(1234.5_f64, 27.0, 1), (1230.0, 30.0));
+    assert_eq!(round_pair_sf(1234.5_f64, 27.0, 2), (1235.0, 27.0));
+    assert_eq!(round_pair_sf(12.3456_f64, 270.0, 2), (10.0, 270.0));
+    assert_eq!(round_pair_sf(1.5_f64, 2e3, 1), (0.0, 2e3));
+
+    // More significant figures than the type holds keep both unchanged
+    assert_eq!(
+        round_pair_sf(12.3456_f64, 0.0234, u32::MAX),
+        (12.3456, 0.0234)
+    );
+
+    // Uncertainties that don't give a position
+    assert_eq!(round_pair_sf(12.3456_f64, 0.0, 2), (12.3456, 0.0));
+    assert_eq!(
+        round_pair_sf(12.3456_f64, f64::INFINITY, 2),
+        (12.3456, f64::INFINITY)
+    );
+}
+
+#[test]
+fn test_modes_at_midpoints() {
+    // The results for 2.5, -2.5, 3.5 and -3.5 rounded to an integer
+    let modes = [
+        (RoundingMode::HalfUp, [3, -2, 4, -3]),
+        (RoundingMode::HalfDown, [2, -3, 3, -4]),
+        (RoundingMode::HalfEven, [2, -2, 4, -4]),
+        (RoundingMode::HalfOdd, [3, -3, 3, -3]),
+        (RoundingMode::HalfAwayFromZero, [3, -3, 4, -4]),
+        (RoundingMode::HalfTowardZero, [2, -2, 3, -3]),
+        (RoundingMode::Up, [3, -2, 4, -3]),
+        (RoundingMode::Down, [2, -3, 3, -4]),
+        (RoundingMode::TowardZero, [2, -2, 3, -3]),
+        (RoundingMode::AwayFromZero, [3, -3, 4, -4]),
+    ];
+
+    for (mode, expected) in modes {
+        for (&number, &expected) in [2.5_f64, -2.5, 3.5, -3.5].iter().zip(&expected) {
+            // Midpoints at other positions, 0.125 and 0.375 are exactly representable
+            let offset = if number.abs() == 2.5 { 10_f64 } else { 34.0 }.copysign(number);
+            let small = (number + offset) / 100.0;
+            let (e, e_small) = (expected as f64, (expected as f64 + offset) / 100.0);
+
+            assert_eq!(round_dp_mode(number, 0, mode), e, "{mode:?} {number}");
+            assert_eq!(round_dp_mode(small, 2, mode), e_small, "{mode:?} {small}");
+            assert_eq!(round_dp_mode(number as f32, 0, mode), e as f32);
+            assert_eq!(round_dp_mode(small as f32, 2, mode), e_small as f32);
+
+            assert_eq!(round_zeros_mode(number * 10.0, 1, mode), e * 10.0);
+            assert_eq!(
+                round_zeros_mode(number as f32 * 100.0, 2, mode),
+                e as f32 * 100.0
+            );
+            assert_eq!(round_zeros_mode(number, 0, mode), e);
+
+            assert_eq!(round_sf_mode(number, 1, mode), e, "{mode:?} {number}");
+            assert_eq!(round_sf_mode(small, 2, mode), e_small, "{mode:?} {small}");
+            assert_eq!(round_sf_mode(number as f32 * 1e3, 1, mode), e as f32 * 1e3);
+
+            let integer = (number * 10.0) as i32;
+            assert_eq!(round_zeros_mode(integer, 1, mode), expected * 10);
+            assert_eq!(
+                round_zeros_mode(integer as i8, 1, mode),
+                expected as i8 * 10
+            );
+            assert_eq!(
+                round_sf_mode(integer as i64 * 100, 1, mode),
+                expected as i64 * 1000
+            );
+            if integer > 0 {
+                assert_eq!(
+                    round_zeros_mode(integer as u16, 1, mode),
+                    expected as u16 * 10
+                );
+                assert_eq!(round_sf_mode(integer as u8, 1, mode), expected as u8 * 10);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_modes_away_from_midpoints() {
+    for mode in [
+        RoundingMode::HalfUp,
+        RoundingMode::HalfDown,
+        RoundingMode::HalfEven,
+        RoundingMode::HalfOdd,
+        RoundingMode::HalfAwayFromZero,
+        RoundingMode::HalfTowardZero,
+    ] {
+        assert_eq!(round_dp_mode(2.4_f64, 0, mode), 2.0);
+        assert_eq!(round_dp_mode(-2.6_f64, 0, mode), -3.0);
+        assert_eq!(round_zeros_mode(-24_i32, 1, mode), -20);
+        assert_eq!(round_sf_mode(2.6_f32, 1, mode), 3.0);
+    }
+
+    // Already rounded numbers are unchanged by every mode
+    assert_eq!(round_dp_mode(1.15_f64, 2, RoundingMode::AwayFromZero), 1.15);
+    assert_eq!(round_zeros_mode(120_u8, 1, RoundingMode::AwayFromZero), 120);
+
+    // The existing functions are the matching modes
+    assert_eq!(
+        round(2.5_f64, 0),
+        round_dp_mode(2.5, 0, RoundingMode::HalfAwayFromZero)
+    );
+    assert_eq!(
+        ceil_zeros(-25_i32, 1),
+        round_zeros_mode(-25, 1, RoundingMode::Up)
+    );
+    assert_eq!(
+        floor_sf(-2.5_f32, 1),
+        round_sf_mode(-2.5, 1, RoundingMode::Down)
+    );
+
+    // Results that don't fit saturate
+    assert_eq!(
+        round_zeros_mode(251_u8, 1, RoundingMode::AwayFromZero),
+        u8::MAX
+    );
+    assert_eq!(
+        round_sf_mode(-115_i8, 1, RoundingMode::AwayFromZero),
+        i8::MIN
+    );
+}
+
+#[test]
+fn test_round_even() {
+    let cases = [
+        (0.5, 0, 0.0),
+        (1.5, 0, 2.0),
+        (2.5, 0, 2.0),
+        (3.5, 0, 4.0),
+        (2.4999, 0, 2.0),
+        (2.5001, 0, 3.0),
+        (0.25, 1, 0.2),
+        (0.35, 1, 0.4),
+        (2.125, 2, 2.12),
+        (2.135, 2, 2.14),
+        (2.145, 2, 2.14),
+        (2.675, 2, 2.68),
+        (2.665, 2, 2.66),
+        (1.005, 2, 1.0),
+        (0.0125, 3, 0.012),
+        (123.4565, 3, 123.456),
+        (123.4575, 3, 123.458),
+    ];
+    for (number, decimal_places, expected) in cases {
+        assert_eq!(round_dp_even(number, decimal_places), expected, "{number}");
+        assert_eq!(
+            round_dp_even(-number, decimal_places),
+            -expected,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_even(number as f32, decimal_places),
+            expected as f32,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_even(-number as f32, decimal_places),
+            -expected as f32,
+            "{number}"
+        );
+    }
+
+    // Scaling by a power of ten moves these onto or off a midpoint
+    assert_eq!(2.135_f64 * 100.0, 213.49999999999997);
+    assert_eq!(round_dp_mode(2.135_f64, 2, RoundingMode::HalfEven), 2.13);
+    assert_eq!(round_dp_even(2.135_f64, 2), 2.14);
+    assert_eq!(0.285_f64 * 100.0, 28.499999999999996);
+    assert_eq!(round_dp_even(0.285_f64, 2), 0.28);
+
+    // Zero results keep their sign
+    assert_eq!(round_dp_even(-0.5_f64, 0).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(round_dp_even(-0.05_f32, 1).to_bits(), (-0.0_f32).to_bits());
+
+    assert_eq!(round_dp_even(1.15_f64, 20), 1.15);
+    assert!(round_dp_even(f64::NAN, 2).is_nan());
+    assert_eq!(round_dp_even(f32::NEG_INFINITY, 2), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_round_zeros_even() {
+    let cases = [
+        (5, 1, 0),
+        (15, 1, 20),
+        (25, 1, 20),
+        (35, 1, 40),
+        (24, 1, 20),
+        (26, 1, 30),
+        (250, 2, 200),
+        (350, 2, 400),
+        (1250, 2, 1200),
+        (1350, 2, 1400),
+        (1251, 2, 1300),
+    ];
+    for (number, zeros, expected) in cases {
+        assert_eq!(round_zeros_even(number, zeros), expected, "{number}");
+        assert_eq!(round_zeros_even(-number, zeros), -expected, "{number}");
+        assert_eq!(round_zeros_even(number as i64, zeros), expected as i64);
+        assert_eq!(round_zeros_even(number as u16, zeros), expected as u16);
+        assert_eq!(round_zeros_even(number as f64, zeros), expected as f64);
+        assert_eq!(round_zeros_even(-number as f64, zeros), -expected as f64);
+        assert_eq!(round_zeros_even(number as f32, zeros), expected as f32);
+    }
+
+    assert_eq!(round_zeros_even(2.5e20_f64, 20), 2e20);
+    assert_eq!(round_zeros_even(3.5e20_f64, 20), 4e20);
+    assert_eq!(round_zeros_even(12.5_f64, 1), 10.0);
+    assert_eq!(round_zeros_even(-5_f64, 1).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(round_zeros_even(1e300_f64, u32::MAX), 0.0);
+    assert_eq!(round_zeros_even(255_u8, 1), u8::MAX);
+}
+
+#[test]
+fn test_round_sf_even() {
+    let cases = [
+        (2.5, 1, 2.0),
+        (3.5, 1, 4.0),
+        (0.125, 2, 0.12),
+        (0.135, 2, 0.14),
+        (1.25e-10, 2, 1.2e-10),
+        (1.35e10, 2, 1.4e10),
+        (2450.0, 2, 2400.0),
+        (2550.0, 2, 2600.0),
+        (2551.0, 2, 2600.0),
+        (2549.0, 2, 2500.0),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(round_sf_even(number, sig_figs), expected, "{number}");
+        assert_eq!(round_sf_even(-number, sig_figs), -expected, "{number}");
+        assert_eq!(round_sf_even(number as f32, sig_figs), expected as f32);
+        assert_eq!(round_sf_even(-number as f32, sig_figs), -expected as f32);
+    }
+
+    assert_eq!(round_sf_even(2450_i32, 2), 2400);
+    assert_eq!(round_sf_even(-2550_i32, 2), -2600);
+    assert_eq!(round_sf_even(125_u8, 2), 120);
+    assert_eq!(round_sf_even(9.5_f64, 1), 10.0);
+    assert_eq!(round_sf_even(1.5e-320_f64, 1), 2e-320);
+    assert_eq!(round_sf_even(0.0_f64, 1), 0.0);
+}
+
+#[test]
+fn test_round_half_down() {
+    // Midpoints that scale exactly, with the result for the number and its negative
+    let cases = [
+        (0.5, 0, 0.0, -1.0),
+        (1.5, 0, 1.0, -2.0),
+        (2.5, 0, 2.0, -3.0),
+        (2.25, 1, 2.2, -2.3),
+        (2.75, 1, 2.7, -2.8),
+        (0.125, 2, 0.12, -0.13),
+        (10.375, 2, 10.37, -10.38),
+        (2.26, 1, 2.3, -2.3),
+        (2.24, 1, 2.2, -2.2),
+        (2.5001, 0, 3.0, -3.0),
+    ];
+    for (number, decimal_places, expected, negative) in cases {
+        assert_eq!(
+            round_dp_half_down(number, decimal_places),
+            expected,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_half_down(-number, decimal_places),
+            negative,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_half_down(number as f32, decimal_places),
+            expected as f32
+        );
+        assert_eq!(
+            round_dp_half_down(-number as f32, decimal_places),
+            negative as f32
+        );
+    }
+
+    let cases = [
+        (25, 1, 20, -30),
+        (35, 1, 30, -40),
+        (150, 2, 100, -200),
+        (151, 2, 200, -200),
+    ];
+    for (number, zeros, expected, negative) in cases {
+        assert_eq!(round_zeros_half_down(number, zeros), expected, "{number}");
+        assert_eq!(round_zeros_half_down(-number, zeros), negative, "{number}");
+        assert_eq!(
+            round_zeros_half_down(-number as i16, zeros),
+            negative as i16
+        );
+        assert_eq!(round_zeros_half_down(number as u64, zeros), expected as u64);
+        assert_eq!(
+            round_zeros_half_down(-number as f64, zeros),
+            negative as f64
+        );
+        assert_eq!(
+            round_zeros_half_down(-number as f32, zeros),
+            negative as f32
+        );
+    }
+
+    let cases = [
+        (2.5, 1, 2.0, -3.0),
+        (0.125, 2, 0.12, -0.13),
+        (1250.0, 2, 1200.0, -1300.0),
+    ];
+    for (number, sig_figs, expected, negative) in cases {
+        assert_eq!(round_sf_half_down(number, sig_figs), expected, "{number}");
+        assert_eq!(round_sf_half_down(-number, sig_figs), negative, "{number}");
+        assert_eq!(
+            round_sf_half_down(-number as f32, sig_figs),
+            negative as f32
+        );
+    }
+    assert_eq!(round_sf_half_down(1250_i32, 2), 1200);
+    assert_eq!(round_sf_half_down(-1250_i32, 2), -1300);
+
+    assert_eq!(round_zeros_half_down(-25_i8, 1), -30);
+    assert_eq!(round_zeros_half_down(125_i8, 1), 120);
+    assert_eq!(round_zeros_half_down(-125_i8, 1), i8::MIN);
+
+    // Ties go towards negative infinity like RoundingMode::HalfDown
+    for number in [-2.5_f64, -0.5, 0.5, 2.5, -0.25] {
+        assert_eq!(
+            round_dp_half_down(number, 0).to_bits(),
+            round_dp_mode(number, 0, RoundingMode::HalfDown).to_bits()
+        );
+        assert_eq!(
+            round_zeros_half_down(number * 10.0, 1),
+            round_zeros_mode(number * 10.0, 1, RoundingMode::HalfDown)
+        );
+        assert_eq!(
+            round_sf_half_down(number, 1),
+            round_sf_mode(number, 1, RoundingMode::HalfDown)
+        );
+    }
+    assert_eq!(round_dp_half_down(0.5_f64, 0).to_bits(), 0.0_f64.to_bits());
+    assert_eq!(
+        round_dp_half_down(-0.25_f64, 0).to_bits(),
+        (-0.0_f64).to_bits()
+    );
+}
+
+#[test]
+fn test_round_half_away_midpoints() {
+    // Odd multiples of 2^-(dp + 1) are exact midpoints between steps of 10^-dp
+    for decimal_places in 0..8 {
+        let step = 10_f64.powi(decimal_places);
+        let half = 2_f64.powi(decimal_places + 1);
+        for m in 0..200 {
+            let number = (2 * m + 1) as f64 / half;
+            let above = ((number * step).floor() + 1.0) / step;
+
+            assert_eq!(round_dp_half_away(number, decimal_places as u32), above);
+            assert_eq!(round_dp_half_away(-number, decimal_places as u32), -above);
+
+            let sig_figs = (decimal_places + number.get_digits()) as u32;
+            assert_eq!(round_sf_half_away(number, sig_figs), above, "{number}");
+            assert_eq!(round_sf_half_away(-number, sig_figs), -above, "{number}");
+
+            if decimal_places < 4 {
+                let number = number as f32;
+                let above = above as f32;
+                assert_eq!(round_dp_half_away(number, decimal_places as u32), above);
+                assert_eq!(round_dp_half_away(-number, decimal_places as u32), -above);
+            }
+        }
+    }
+
+    // Midpoints between multiples of 10^zeros
+    for zeros in 1..6 {
+        let step = 10_i64.pow(zeros);
+        for k in 0..200 {
+            let number = k * step + step / 2;
+            let above = (k + 1) * step;
+
+            assert_eq!(round_zeros_half_away(number, zeros), above);
+            assert_eq!(round_zeros_half_away(-number, zeros), -above);
+            assert_eq!(round_zeros_half_away(number as f64, zeros), above as f64);
+            assert_eq!(round_zeros_half_away(-number as f64, zeros), -above as f64);
+            assert_eq!(round_zeros_half_away(-number as f32, zeros), -above as f32);
+        }
+    }
+
+    assert_eq!(round_sf_half_away(-125_i8, 2), -128);
+    assert_eq!(round_zeros_half_away(i8::MIN, 1), i8::MIN);
+    assert_eq!(
+        round_dp_half_away(-0.4_f64, 0).to_bits(),
+        (-0.0_f64).to_bits()
+    );
+    assert!(round_dp_half_away(f64::NAN, 2).is_nan());
+    assert_eq!(round_sf_half_away(f32::NEG_INFINITY, 2), f32::NEG_INFINITY);
+}
+
+#[test]
+fn test_round_half_away_symmetric() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 40) as i32 - 20;
+        let number = rng.next_f64() * 10_f64.powi(exponent);
+        let n = (rng.next_u64() % 18) as u32;
+
+        let positive = round_dp_half_away(number, n);
+        assert_eq!(
+            round_dp_half_away(-number, n).to_bits(),
+            (-positive).to_bits()
+        );
+        let positive = round_zeros_half_away(number, n);
+        assert_eq!(
+            round_zeros_half_away(-number, n).to_bits(),
+            (-positive).to_bits()
+        );
+        let positive = round_sf_half_away(number, n.max(1));
+        assert_eq!(
+            round_sf_half_away(-number, n.max(1)).to_bits(),
+            (-positive).to_bits()
+        );
+
+        let number = number as f32;
+        let positive = round_dp_half_away(number, n);
+        assert_eq!(
+            round_dp_half_away(-number, n).to_bits(),
+            (-positive).to_bits()
+        );
+        let positive = round_sf_half_away(number, n.max(1));
+        assert_eq!(
+            round_sf_half_away(-number, n.max(1)).to_bits(),
+            (-positive).to_bits()
+        );
+    }
+}
+
+#[test]
+fn test_round_half_odd() {
+    let cases = [
+        (0.5, 0, 1.0),
+        (1.5, 0, 1.0),
+        (2.5, 0, 3.0),
+        (2.4999, 0, 2.0),
+        (0.25, 1, 0.3),
+        (0.35, 1, 0.3),
+        (2.125, 2, 2.13),
+        (2.135, 2, 2.13),
+        (2.675, 2, 2.67),
+        (2.6751, 2, 2.68),
+    ];
+    for (number, decimal_places, expected) in cases {
+        assert_eq!(
+            round_dp_half_odd(number, decimal_places),
+            expected,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_half_odd(-number, decimal_places),
+            -expected,
+            "{number}"
+        );
+        assert_eq!(
+            round_dp_half_odd(number as f32, decimal_places),
+            expected as f32
+        );
+    }
+
+    for (number, zeros, expected) in [(15, 1, 10), (25, 1, 30), (1250, 2, 1300), (1350, 2, 1300)] {
+        assert_eq!(round_zeros_half_odd(number, zeros), expected, "{number}");
+        assert_eq!(round_zeros_half_odd(-number, zeros), -expected, "{number}");
+        assert_eq!(round_zeros_half_odd(number as f64, zeros), expected as f64);
+        assert_eq!(
+            round_zeros_half_odd(-number as f32, zeros),
+            -expected as f32
+        );
+    }
+
+    assert_eq!(round_sf_half_odd(0.125_f64, 2), 0.13);
+    assert_eq!(round_sf_half_odd(-9.5_f64, 1), -9.0);
+    assert_eq!(round_sf_half_odd(-0.5_f64, 1), -0.5);
+    assert_eq!(round_dp_half_odd(-0.05_f64, 1), -0.1);
+}
+
+#[test]
+fn test_round_half_odd_chained() {
+    // Rounding to 3 decimal places then 2 can create a tie that wasn't there.
+    // Half to even sends it down while the number was above the midpoint
+    let number = 2.4451_f64;
+    assert_eq!(round_dp_even(round_dp_even(number, 3), 2), 2.44);
+    assert_eq!(round_dp_half_odd(round_dp_half_odd(number, 3), 2), 2.45);
+    assert_eq!(round_dp_half_odd(number, 2), 2.45);
+
+    let number = 0.0651_f64;
+    assert_eq!(round_dp_even(round_dp_even(number, 3), 2), 0.06);
+    assert_eq!(round_dp_half_odd(round_dp_half_odd(number, 3), 2), 0.07);
+    assert_eq!(round_dp_half_odd(number, 2), 0.07);
+
+    let number = 2451_i32;
+    assert_eq!(round_zeros_even(round_zeros_even(number, 1), 2), 2400);
+    assert_eq!(
+        round_zeros_half_odd(round_zeros_half_odd(number, 1), 2),
+        2500
+    );
+    assert_eq!(round_zeros_half_odd(number, 2), 2500);
+
+    // Neither tie rule avoids every created tie, here the odd neighbour is the wrong one
+    let number = -6_551_i32;
+    assert_eq!(round_zeros_even(round_zeros_even(number, 1), 2), -6_600);
+    assert_eq!(
+        round_zeros_half_odd(round_zeros_half_odd(number, 1), 2),
+        -6_500
+    );
+    assert_eq!(round_zeros_half_odd(number, 2), -6_600);
+
+    // A tie that was already there still splits the difference the same way every time
+    assert_eq!(round_dp_half_odd(round_dp_half_odd(2.345_f64, 3), 2), 2.35);
+    assert_eq!(
+        round_zeros_half_odd(round_zeros_half_odd(-2_350_i32, 1), 2),
+        -2_300
+    );
+}
+
+#[test]
+fn test_round_stochastic_fixed_sequence() {
+    let sequence = [0.0, 0.3, 0.69, 0.71, 0.999];
+    let mut values = sequence.iter().copied().cycle();
+
+    let rounded: Vec<f64> = (0..5)
+        .map(|_| round_dp_stochastic(2.37_f64, 1, || values.next().unwrap()))
+        .collect();
+    assert_eq!(rounded, [2.4, 2.4, 2.4, 2.3, 2.3]);
+
+    let rounded: Vec<f64> = (0..5)
+        .map(|_| round_dp_stochastic(-2.37_f64, 1, || values.next().unwrap()))
+        .collect();
+    assert_eq!(rounded, [-2.3, -2.4, -2.4, -2.4, -2.4]);
+
+    let rounded: Vec<f32> = (0..5)
+        .map(|_| round_dp_stochastic(2.37_f32, 1, || values.next().unwrap()))
+        .collect();
+    assert_eq!(rounded, [2.4, 2.4, 2.4, 2.3, 2.3]);
+
+    let rounded: Vec<i32> = (0..5)
+        .map(|_| round_zeros_stochastic(237, 1, || values.next().unwrap()))
+        .collect();
+    assert_eq!(rounded, [240, 240, 240, 230, 230]);
+
+    let rounded: Vec<i64> = (0..5)
+        .map(|_| round_zeros_stochastic(-2_370, 2, || values.next().unwrap()))
+        .collect();
+    assert_eq!(rounded, [-2_300, -2_400, -2_400, -2_400, -2_400]);
+
+    // Numbers already on a step never call the random source
+    assert_eq!(round_dp_stochastic(2.3_f64, 1, || unreachable!()), 2.3);
+    assert_eq!(round_zeros_stochastic(230_u8, 1, || unreachable!()), 230);
+    assert_eq!(round_zeros_stochastic(-0.0_f64, 1, || unreachable!()), 0.0);
+    assert_eq!(
+        round_dp_stochastic(f64::INFINITY, 1, || unreachable!()),
+        f64::INFINITY
+    );
+    assert!(round_dp_stochastic(f64::NAN, 1, || 0.5).is_nan());
+
+    // Results that don't fit saturate
+    assert_eq!(round_zeros_stochastic(251_u8, 1, || 0.0), u8::MAX);
+    assert_eq!(round_zeros_stochastic(i64::MIN + 1, 2, || 0.999), i64::MIN);
+}
+
+#[test]
+fn test_round_stochastic_mean() {
+    let mut rng = XorShift(0x853c_49e6_748f_ea9b);
+    let count = 100_000;
+
+    let sum: f64 = (0..count)
+        .map(|_| round_dp_stochastic(2.37_f64, 1, || rng.next_f64()))
+        .sum();
+    assert!((sum / count as f64 - 2.37).abs() < 0.005);
+
+    let sum: f64 = (0..count)
+        .map(|_| round_dp_stochastic(-0.0123_f32, 2, || rng.next_f64()) as f64)
+        .sum();
+    assert!((sum / count as f64 + 0.0123).abs() < 0.0005);
+
+    let sum: i64 = (0..count)
+        .map(|_| round_zeros_stochastic(237_i64, 1, || rng.next_f64()))
+        .sum();
+    assert!((sum as f64 / count as f64 - 237.0).abs() < 0.05);
+}
+
+#[test]
+fn test_trunc() {
+    assert_eq!(trunc(123.456_f64, 2), 123.45_f64);
+    assert_eq!(trunc(-123.456_f64, 2), -123.45_f64);
+    assert_eq!(trunc(-123.456_f64, 0), -123_f64);
+    assert_eq!(trunc(-123.456_f32, 1), -123.4_f32);
+    assert_eq!(trunc(-0.456_f64, 0).to_bits(), (-0.0_f64).to_bits());
+    assert_eq!(trunc(-1.15_f64, 2), -1.15_f64);
+    assert!(trunc(f64::NAN, 2).is_nan());
+}
+
+#[test]
+fn test_trunc_zeros() {
+    assert_eq!(trunc_zeros(156_i32, 1), 150_i32);
+    assert_eq!(trunc_zeros(-156_i32, 1), -150_i32);
+    assert_eq!(trunc_zeros(-128_i8, 1), -120_i8);
+    assert_eq!(trunc_zeros(i64::MIN, 18), -9_000_000_000_000_000_000);
+    assert_eq!(trunc_zeros(-156.5_f64, 2), -100_f64);
+    assert_eq!(trunc_zeros(-156.5_f32, 0), -156_f32);
+    assert_eq!(trunc_zeros(-56_f64, 2).to_bits(), (-0.0_f64).to_bits());
+}
+
+#[test]
+fn test_trunc_sf() {
+    assert_eq!(trunc_sf(123.456_f64, 4), 123.4_f64);
+    assert_eq!(trunc_sf(-123.456_f64, 4), -123.4_f64);
+    assert_eq!(trunc_sf(-0.001239_f32, 3), -0.00123_f32);
+    assert_eq!(trunc_sf(-1567_i32, 2), -1500_i32);
+    assert_eq!(trunc_sf(-99_i8, 1), -90_i8);
+    assert_eq!(trunc_sf(1567_u16, 1), 1000_u16);
+}
+
+#[test]
+fn test_trunc_matches_floor_for_positive() {
+    let mut rng = XorShift(0x6a09_e667_f3bc_c908);
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 30) as i32 - 15;
+        let number = rng.next_f64() * 10_f64.powi(exponent);
+        let n = (rng.next_u64() % 16) as u32;
+
+        assert_eq!(trunc(number, n), floor(number, n));
+        assert_eq!(trunc_zeros(number, n), floor_zeros(number, n));
+        assert_eq!(trunc_sf(number, n.max(1)), floor_sf(number, n.max(1)));
+        assert_eq!(trunc(-number, n), -floor(number, n));
+        assert_eq!(trunc_sf(-number, n.max(1)), -floor_sf(number, n.max(1)));
+
+        let number = number as f32;
+        assert_eq!(trunc(number, n), floor(number, n));
+        assert_eq!(trunc_sf(number, n.max(1)), floor_sf(number, n.max(1)));
+
+        let integer = rng.next_u64() >> (rng.next_u64() % 64);
+        let zeros = n + 2;
+        assert_eq!(trunc_zeros(integer, zeros), floor_zeros(integer, zeros));
+        assert_eq!(trunc_sf(integer, n.max(1)), floor_sf(integer, n.max(1)));
+        let integer = (integer >> 1) as i64;
+        assert_eq!(trunc_zeros(-integer, zeros), -floor_zeros(integer, zeros));
+    }
+}
+
+#[test]
+fn test_away() {
+    assert_eq!(away(123.451_f64, 2), 123.46_f64);
+    assert_eq!(away(-123.451_f64, 2), -123.46_f64);
+    assert_eq!(away(-123.451_f32, 0), -124_f32);
+    assert_eq!(away(-1.15_f64, 2), -1.15_f64);
+    assert_eq!(away_zeros(-151_i32, 1), -160_i32);
+    assert_eq!(away_zeros(151_u32, 2), 200_u32);
+    assert_eq!(away_zeros(-121_i8, 1), i8::MIN);
+    assert_eq!(away_zeros(-0.01_f64, 2), -100_f64);
+    assert_eq!(away_sf(-123.412_f64, 4), -123.5_f64);
+    assert_eq!(away_sf(-0.001231_f32, 3), -0.00124_f32);
+    assert_eq!(away_sf(-1512_i32, 2), -1600_i32);
+    assert_eq!(away_sf(0_i32, 2), 0_i32);
+    assert!(away(f64::NAN, 2).is_nan());
+    assert_eq!(away_sf(f64::NEG_INFINITY, 2), f64::NEG_INFINITY);
+}
+
+#[test]
+fn test_away_and_trunc_bracket() {
+    let mut rng = XorShift(0xbb67_ae85_84ca_a73b);
+    for _ in 0..100_000 {
+        let exponent = (rng.next_u64() % 30) as i32 - 15;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        let n = (rng.next_u64() % 16) as u32;
+
+        for (toward, away) in [
+            (trunc(number, n), away(number, n)),
+            (trunc_zeros(number, n), away_zeros(number, n)),
+            (trunc_sf(number, n.max(1)), away_sf(number, n.max(1))),
+        ] {
+            assert!(toward.abs() <= number.abs() && number.abs() <= away.abs());
+            assert!(toward == 0.0 || toward.signum() == number.signum());
+            assert_eq!(away.signum(), number.signum());
+        }
+
+        let number = number as f32;
+        for (toward, away) in [
+            (trunc(number, n), away(number, n)),
+            (trunc_zeros(number, n), away_zeros(number, n)),
+            (trunc_sf(number, n.max(1)), away_sf(number, n.max(1))),
+        ] {
+            assert!(
+                toward.abs() <= number.abs() && number.abs() <= away.abs(),
+                "{number} {n} {toward} {away}"
+            );
+            assert_eq!(away.signum(), number.signum());
+        }
+
+        let integer = (rng.next_u64() >> 36) as i32 - (1 << 27);
+        let zeros = n % 8;
+        for (toward, away) in [
+            (trunc_zeros(integer, zeros), away_zeros(integer, zeros)),
+            (trunc_sf(integer, n.max(1)), away_sf(integer, n.max(1))),
+        ] {
+            if integer >= 0 {
+                assert!(toward <= integer && integer <= away);
+            } else {
+                assert!(away <= integer && integer <= toward);
+            }
+        }
+
+        let integer = integer.unsigned_abs();
+        assert!(trunc_zeros(integer, zeros) <= integer && integer <= away_zeros(integer, zeros));
+        assert!(trunc_sf(integer, n.max(1)) <= integer && integer <= away_sf(integer, n.max(1)));
+    }
+}
+
+#[test]
+fn test_directed_stay_on_side() {
+    // Near the limit of precision the scaled number can round onto the integer past it
+    assert!(floor(226_727.119_341_225_48_f64, 10) <= 226_727.119_341_225_48);
+    assert!(ceil(133_150_153_710_428.81_f64, 1) >= 133_150_153_710_428.81);
+    assert!(ceil(0.226_433_41_f32, 7) >= 0.226_433_41);
+    assert!(ceil_zeros(3.062_135_844_717_900_3e18_f64, 4) >= 3.062_135_844_717_900_3e18);
+
+    let mut rng = XorShift(0x3c6e_f372_fe94_f82b);
+    for _ in 0..200_000 {
+        let exponent = (rng.next_u64() % 40) as i32 - 20;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        let n = (rng.next_u64() % 20) as u32;
+
+        assert!(ceil(number, n) >= number && floor(number, n) <= number);
+        assert!(ceil_zeros(number, n) >= number && floor_zeros(number, n) <= number);
+        assert!(ceil_sf(number, n.max(1)) >= number && floor_sf(number, n.max(1)) <= number);
+
+        let number = number as f32;
+        assert!(ceil(number, n) >= number && floor(number, n) <= number);
+        assert!(ceil_zeros(number, n) >= number && floor_zeros(number, n) <= number);
+        assert!(ceil_sf(number, n.max(1)) >= number && floor_sf(number, n.max(1)) <= number);
+    }
+}
+
+#[cfg(test)]
+type Rounder<T> = fn(T, u32) -> T;
+
+#[test]
+fn test_round_dir_matches_standalone() {
+    let mut rng = XorShift(0xa54f_f53a_5f1d_36f1);
+    for _ in 0..20_000 {
+        let exponent = (rng.next_u64() % 30) as i32 - 15;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        let integer = (rng.next_u64() >> 40) as i32 - (1 << 23);
+        let n = (rng.next_u64() % 16) as u32;
+        let sig_figs = n.max(1);
+
+        let directions: [(Direction, Rounder<f64>); 5] = [
+            (Direction::Nearest, round),
+            (Direction::Up, ceil),
+            (Direction::Down, floor),
+            (Direction::TowardZero, trunc),
+            (Direction::AwayFromZero, away),
+        ];
+        for (direction, standalone) in directions {
+            assert_eq!(round_dir(number, n, direction), standalone(number, n));
+        }
+
+        let directions: [(Direction, Rounder<f32>, Rounder<i32>); 5] = [
+            (Direction::Nearest, round_zeros, round_zeros),
+            (Direction::Up, ceil_zeros, ceil_zeros),
+            (Direction::Down, floor_zeros, floor_zeros),
+            (Direction::TowardZero, trunc_zeros, trunc_zeros),
+            (Direction::AwayFromZero, away_zeros, away_zeros),
+        ];
+        for (direction, float, int) in directions {
+            let number = number as f32;
+            assert_eq!(round_zeros_dir(number, n, direction), float(number, n));
+            assert_eq!(round_zeros_dir(integer, n, direction), int(integer, n));
+        }
+
+        let directions: [(Direction, Rounder<f64>, Rounder<u64>); 5] = [
+            (Direction::Nearest, round_sf, round_sf),
+            (Direction::Up, ceil_sf, ceil_sf),
+            (Direction::Down, floor_sf, floor_sf),
+            (Direction::TowardZero, trunc_sf, trunc_sf),
+            (Direction::AwayFromZero, away_sf, away_sf),
+        ];
+        for (direction, float, int) in directions {
+            let unsigned = integer.unsigned_abs() as u64;
+            assert_eq!(
+                round_sf_dir(number, sig_figs, direction),
+                float(number, sig_figs)
+            );
+            assert_eq!(
+                round_sf_dir(unsigned, sig_figs, direction),
+                int(unsigned, sig_figs)
+            );
+        }
+    }
+
+    assert_eq!(Direction::default(), Direction::Nearest);
+    assert_eq!(round_dir(-1.25_f64, 1, Direction::Up), -1.2);
+    assert_eq!(round_dir(-1.25_f64, 1, Direction::Down), -1.3);
+    assert_eq!(round_zeros_dir(-125_i8, 1, Direction::AwayFromZero), -128);
+    assert_eq!(round_sf_dir(-125_i8, 2, Direction::TowardZero), -120);
+}
+
+#[test]
+fn test_round_to_multiple() {
+    // Quarter steps
+    assert_eq!(round_to_multiple(1.3_f64, 0.25), 1.25);
+    assert_eq!(round_to_multiple(1.375_f64, 0.25), 1.5);
+    assert_eq!(ceil_to_multiple(1.26_f64, 0.25), 1.5);
+    assert_eq!(floor_to_multiple(1.49_f32, 0.25), 1.25);
+    assert_eq!(round_to_multiple(-1.3_f64, 0.25), -1.25);
+    assert_eq!(round_to_multiple(-1.375_f64, 0.25), -1.5);
+    assert_eq!(ceil_to_multiple(-1.3_f64, 0.25), -1.25);
+    assert_eq!(floor_to_multiple(-1.3_f64, 0.25), -1.5);
+
+    // Step 5 on integers
+    assert_eq!(round_to_multiple(12_i32, 5), 10);
+    assert_eq!(round_to_multiple(13_i32, 5), 15);
+    assert_eq!(ceil_to_multiple(11_u32, 5), 15);
+    assert_eq!(floor_to_multiple(14_u64, 5), 10);
+    assert_eq!(round_to_multiple(-12_i16, 5), -10);
+    assert_eq!(round_to_multiple(-13_i64, 5), -15);
+    assert_eq!(ceil_to_multiple(-14_i8, 5), -10);
+    assert_eq!(floor_to_multiple(-11_i8, 5), -15);
+    assert_eq!(round_to_multiple(-15_i32, 5), -15);
+
+    // Steps larger than the value
+    assert_eq!(round_to_multiple(3_u8, 10), 0);
+    assert_eq!(round_to_multiple(7_i32, 10), 10);
+    assert_eq!(ceil_to_multiple(-3_i32, 10), 0);
+    assert_eq!(floor_to_multiple(-3_i32, 10), -10);
+    assert_eq!(round_to_multiple(0.3_f64, 5.0), 0.0);
+    assert_eq!(ceil_to_multiple(0.3_f64, 5.0), 5.0);
+    assert_eq!(floor_to_multiple(0.3_f32, 5.0), 0.0);
+
+    // Saturating integers
+    assert_eq!(ceil_to_multiple(251_u8, 10), u8::MAX);
+    assert_eq!(floor_to_multiple(-127_i8, 5), i8::MIN);
+}
+
+#[test]
+fn test_round_to_multiple_no_drift() {
+    assert_eq!(round_to_multiple(0.3_f64, 0.1), 0.3);
+    assert_eq!(ceil_to_multiple(0.3_f64, 0.1), 0.3);
+    assert_eq!(floor_to_multiple(0.3_f64, 0.1), 0.3);
+    assert_eq!(round_to_multiple(0.7_f32, 0.1), 0.7);
+    assert_eq!(round_to_multiple(1.23_f64, 0.05), 1.25);
+
+    // Every multiple comes out as the float closest to its decimal value
+    for multiple in -1000..=1000 {
+        let expected = format!("{}e-2", multiple * 5).parse::<f64>().unwrap();
+        let number = expected + 0.01;
+        assert_eq!(round_to_multiple(expected, 0.05), expected);
+        assert_eq!(floor_to_multiple(number, 0.05), expected);
+        assert_eq!(ceil_to_multiple(expected - 0.01, 0.05), expected);
+    }
+
+    let mut rng = XorShift(0x1234_5678);
+    for _ in 0..10_000 {
+        let number = (rng.next_f64() - 0.5) * 1e6;
+        let step = 0.001 * (1 + rng.next_u64() % 1000) as f64;
+        let below = floor_to_multiple(number, step);
+        let above = ceil_to_multiple(number, step);
+        assert!(below <= number && number <= above, "{number} {step}");
+        let rounded = round_to_multiple(number, step);
+        assert!(rounded == below || rounded == above, "{number} {step}");
+    }
+}
+
+#[test]
+fn test_try_round_to_multiple() {
+    assert_eq!(try_round_to_multiple(13_i32, 5), Ok(15));
+    assert_eq!(
+        try_round_to_multiple(13_i32, 0),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(
+        try_round_to_multiple(13_i32, -5),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(
+        try_ceil_to_multiple(251_u8, 10),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        try_floor_to_multiple(-127_i8, 5),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(try_round_to_multiple(1.3_f64, 0.25), Ok(1.25));
+    assert_eq!(
+        try_round_to_multiple(1.3_f64, f64::NAN),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(
+        try_floor_to_multiple(1.3_f32, f32::INFINITY),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(
+        try_ceil_to_multiple(f64::NAN, 0.25),
+        Err(RoundingError::NonFinite)
+    );
+    assert_eq!(
+        try_ceil_to_multiple(f64::MAX, f64::MAX * 0.75),
+        Err(RoundingError::Overflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "step must be positive")]
+fn test_round_to_multiple_zero_step() {
+    round_to_multiple(13_i32, 0);
+}
+
+#[test]
+#[should_panic(expected = "step must be positive and finite")]
+fn test_round_to_multiple_nan_step() {
+    round_to_multiple(1.3_f64, f64::NAN);
+}
+
+#[test]
+fn test_quantize() {
+    assert_eq!(quantize(103_u32, 10, 7), 107);
+    assert_eq!(quantize(101_u32, 10, 7), 97);
+    assert_eq!(ceil_quantize(98_i32, 10, 7), 107);
+    assert_eq!(floor_quantize(106_i64, 10, 7), 97);
+    assert_eq!(quantize(107_u16, 10, 7), 107);
+    assert_eq!(quantize(-3_i32, 10, 7), -3);
+    assert_eq!(quantize(-9_i32, 10, 7), -13);
+    assert_eq!(ceil_quantize(-12_i8, 10, 7), -3);
+    assert_eq!(floor_quantize(-4_i8, 10, 7), -13);
+
+    // Offsets larger than the step act the same as their remainder
+    assert_eq!(quantize(103_u32, 10, 57), 107);
+    assert_eq!(quantize(103_i32, 10, -3), 107);
+
+    // Prices ending in .99
+    assert_eq!(quantize(12.34_f64, 1.0, 0.99), 11.99);
+    assert_eq!(quantize(12.6_f64, 1.0, 0.99), 12.99);
+    assert_eq!(ceil_quantize(12.34_f64, 1.0, 0.99), 12.99);
+    assert_eq!(floor_quantize(12.34_f64, 1.0, 0.99), 11.99);
+    assert_eq!(quantize(11.99_f64, 1.0, 0.99), 11.99);
+    assert_eq!(quantize(-0.5_f64, 1.0, 0.99), -0.01);
+    assert_eq!(quantize(12.34_f32, 0.5, 0.25), 12.25);
+
+    // Minutes aligned to 7 past the hour
+    assert_eq!(ceil_quantize(3_u32 * 60 + 20, 60, 7), 4 * 60 + 7);
+}
+
+#[test]
+fn test_quantize_unsigned_below_offset() {
+    // Below the offset the grid continues below zero, which unsigned types can't hold
+    assert_eq!(checked_floor_quantize(3_u8, 10, 7), None);
+    assert_eq!(floor_quantize(3_u8, 10, 7), 0);
+    assert_eq!(checked_quantize(1_u8, 10, 7), None);
+    assert_eq!(quantize(1_u8, 10, 7), 0);
+    assert_eq!(checked_quantize(3_u8, 10, 7), Some(7));
+    assert_eq!(checked_ceil_quantize(0_u8, 10, 7), Some(7));
+    assert_eq!(checked_floor_quantize(0_u64, 10, u64::MAX), None);
+    assert_eq!(floor_quantize(0_u64, 10, u64::MAX), 0);
+
+    // Above the top of the type
+    assert_eq!(checked_ceil_quantize(250_u8, 10, 7), None);
+    assert_eq!(ceil_quantize(250_u8, 10, 7), u8::MAX);
+    assert_eq!(checked_ceil_quantize(u64::MAX, 10, 0), None);
+    assert_eq!(checked_floor_quantize(i64::MIN, 10, 1), None);
+    assert_eq!(floor_quantize(i64::MIN, 10, 1), i64::MIN);
+}
+
+#[test]
+fn test_quantize_brackets() {
+    let mut rng = XorShift(0x9abc_def0);
+    for _ in 0..10_000 {
+        let number = (rng.next_f64() - 0.5) * 1e4;
+        let step = 0.01 * (1 + rng.next_u64() % 1000) as f64;
+        let offset = (rng.next_f64() - 0.5) * 100.0;
+        let below = floor_quantize(number, step, offset);
+        let above = ceil_quantize(number, step, offset);
+        assert!(
+            below <= number && number <= above,
+            "{number} {step} {offset}"
+        );
+        let rounded = quantize(number, step, offset);
+        assert!(
+            rounded == below || rounded == above,
+            "{number} {step} {offset}"
+        );
+
+        let number = rng.next_u64() as i32;
+        let step = 1 + (rng.next_u64() % 1000) as i32;
+        let offset = rng.next_u64() as i32;
+        let rounded = quantize(number, step, offset);
+        assert_eq!((rounded as i64 - offset as i64).rem_euclid(step as i64), 0);
+        assert!((rounded as i64 - number as i64).abs() * 2 <= step as i64);
+    }
+}
+
+#[test]
+fn test_pow10_integers() {
+    assert_eq!(floor_pow10(3456_u32), 1000);
+    assert_eq!(ceil_pow10(3456_u32), 10_000);
+    assert_eq!(round_pow10(3456_u32), 1000);
+
+    // Exact powers and the values either side of them
+    for exponent in 0..=18 {
+        let power = 10_u64.pow(exponent);
+        assert_eq!(round_pow10(power), power);
+        assert_eq!(ceil_pow10(power), power);
+        assert_eq!(floor_pow10(power), power);
+        assert_eq!(order_of_magnitude(power), Some(exponent as i32));
+        assert_eq!(floor_pow10(power + 1), power);
+        assert_eq!(checked_ceil_pow10(power + 1), power.checked_mul(10));
+        if power > 1 {
+            assert_eq!(floor_pow10(power - 1), power / 10);
+            assert_eq!(ceil_pow10(power - 1), power);
+            assert_eq!(order_of_magnitude(power - 1), Some(exponent as i32 - 1));
+        }
+    }
+
+    // The halfway point between 10 and 100 is 55
+    assert_eq!(round_pow10(54_i32), 10);
+    assert_eq!(round_pow10(55_i32), 100);
+    assert_eq!(round_pow10(549_u16), 100);
+    assert_eq!(round_pow10(550_u16), 1000);
+
+    // Negative numbers go by their magnitude
+    assert_eq!(floor_pow10(-3456_i32), -1000);
+    assert_eq!(ceil_pow10(-3456_i32), -10_000);
+    assert_eq!(round_pow10(-55_i8), -100);
+    assert_eq!(order_of_magnitude(-3456_i64), Some(3));
+    assert_eq!(floor_pow10(i64::MIN), -1_000_000_000_000_000_000);
+
+    // Zero and overflow
+    assert_eq!(round_pow10(0_u8), 0);
+    assert_eq!(checked_floor_pow10(0_i32), None);
+    assert_eq!(order_of_magnitude(0_u64), None);
+    assert_eq!(ceil_pow10(101_u8), u8::MAX);
+    assert_eq!(ceil_pow10(-101_i8), i8::MIN);
+    assert_eq!(checked_ceil_pow10(101_u8), None);
+    assert_eq!(
+        checked_round_pow10(u64::MAX),
+        Some(10_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        checked_floor_pow10(u64::MAX),
+        Some(10_000_000_000_000_000_000)
+    );
+}
+
+#[test]
+fn test_pow10_floats() {
+    assert_eq!(ceil_pow10(0.034_f64), 0.1);
+    assert_eq!(floor_pow10(0.034_f64), 0.01);
+    assert_eq!(round_pow10(0.034_f64), 0.01);
+    assert_eq!(round_pow10(0.055_f64), 0.1);
+    assert_eq!(round_pow10(0.0549_f64), 0.01);
+    assert_eq!(order_of_magnitude(0.034_f64), Some(-2));
+    assert_eq!(order_of_magnitude(-0.034_f32), Some(-2));
+    assert_eq!(round_pow10(-550_f64), -1000.0);
+    assert_eq!(ceil_pow10(-0.2_f32), -1.0);
+
+    // Exact powers and the floats either side of them
+    for exponent in -300..=300 {
+        let power = format!("1e{exponent}").parse::<f64>().unwrap();
+        let above = f64::from_bits(power.to_bits() + 1);
+        let below = f64::from_bits(power.to_bits() - 1);
+        let next = format!("1e{}", exponent + 1).parse::<f64>().unwrap();
+        let previous = format!("1e{}", exponent - 1).parse::<f64>().unwrap();
+        assert_eq!(order_of_magnitude(power), Some(exponent));
+        assert_eq!(round_pow10(power), power);
+        assert_eq!(ceil_pow10(power), power);
+        assert_eq!(floor_pow10(power), power);
+        assert_eq!(floor_pow10(above), power);
+        assert_eq!(ceil_pow10(above), next);
+        assert_eq!(order_of_magnitude(below), Some(exponent - 1));
+        assert_eq!(floor_pow10(below), previous);
+        assert_eq!(ceil_pow10(below), power);
+        assert_eq!(round_pow10(below), power);
+    }
+    for exponent in -30..=30 {
+        let power = format!("1e{exponent}").parse::<f32>().unwrap();
+        let below = f32::from_bits(power.to_bits() - 1);
+        assert_eq!(order_of_magnitude(power), Some(exponent));
+        assert_eq!(order_of_magnitude(below), Some(exponent - 1));
+        assert_eq!(ceil_pow10(below), power);
+    }
+
+    // Zero, non finite numbers and overflow
+    assert_eq!(round_pow10(0.0_f64), 0.0);
+    assert_eq!(order_of_magnitude(0.0_f64), None);
+    assert_eq!(order_of_magnitude(f64::NAN), None);
+    assert_eq!(checked_floor_pow10(-0.0_f32), None);
+    assert!(floor_pow10(f64::NAN).is_nan());
+    assert_eq!(ceil_pow10(f64::MAX), f64::INFINITY);
+    assert_eq!(checked_ceil_pow10(f64::MAX), None);
+    assert_eq!(checked_floor_pow10(f64::MAX), Some(1e308));
+    assert_eq!(ceil_pow10(5e-324_f64), 1e-323);
+    assert_eq!(floor_pow10(5e-324_f64), 0.0);
+}
+
+#[test]
+fn test_round_frac() {
+    // Halves, thirds, sixteenths and thousandths
+    assert_eq!(round_frac(0.74_f64, 2), 0.5);
+    assert_eq!(round_frac(0.75_f64, 2), 1.0);
+    assert_eq!(round_frac(0.3_f64, 3), 1.0 / 3.0);
+    assert_eq!(round_frac(0.5_f64, 3), 2.0 / 3.0);
+    assert_eq!(ceil_frac(1.34_f64, 3), 5.0 / 3.0);
+    assert_eq!(floor_frac(1.34_f64, 3), 4.0 / 3.0);
+    assert_eq!(round_frac(0.06_f64, 16), 0.0625);
+    assert_eq!(ceil_frac(0.0625_f64, 16), 0.0625);
+    assert_eq!(floor_frac(0.0624_f64, 16), 0.0);
+    assert_eq!(round_frac(1.2346_f64, 1000), 1.235);
+    assert_eq!(floor_frac(1.2345_f64, 1000), 1.234);
+    assert_eq!(round_frac(2.675_f32, 1000), 2.675);
+
+    // Negative numbers
+    assert_eq!(round_frac(-0.3_f64, 3), -1.0 / 3.0);
+    assert_eq!(ceil_frac(-1.34_f64, 3), -4.0 / 3.0);
+    assert_eq!(floor_frac(-1.34_f64, 3), -5.0 / 3.0);
+    assert_eq!(round_frac(-0.75_f64, 2), -1.0);
+    assert!(round_frac(-0.1_f64, 2).is_sign_negative());
+
+    // Results that floats can hold are exact and the others are the closest float
+    let mut rng = XorShift(0x5151_5151);
+    for _ in 0..10_000 {
+        let denominator = 1 + (rng.next_u64() % 1000) as u32;
+        let number = (rng.next_f64() - 0.5) * 200.0;
+        let below = floor_frac(number, denominator);
+        let above = ceil_frac(number, denominator);
+        assert!(below <= number && number <= above, "{number} {denominator}");
+        let rounded = round_frac(number, denominator);
+        assert!(rounded == below || rounded == above);
+
+        let count = (rounded * denominator as f64).round();
+        assert_eq!(rounded, count / denominator as f64);
+    }
+
+    assert_eq!(round_frac(1e300_f64, 3), 1e300);
+    assert_eq!(round_frac(f64::MAX, u32::MAX), f64::MAX);
+    assert!(round_frac(f64::NAN, 3).is_nan());
+}
+
+#[test]
+fn test_round_frac_closest_float() {
+    // 0.1 is the closest float to 1/10, halfway between 0 and 1/5
+    assert_eq!(round_frac(0.1_f64, 5), 0.2);
+    assert_eq!(floor_frac(0.1_f64, 5), 0.0);
+    assert_eq!(ceil_frac(-0.1_f64, 5), -0.0);
+
+    // 0.7 is the closest float to 7/10 so it is already on the grid
+    assert_eq!(floor_frac(0.7_f64, 10), 0.7);
+    assert_eq!(ceil_frac(0.7_f64, 10), 0.7);
+    assert_eq!(floor_frac(-0.7_f64, 10), -0.7);
+    assert_eq!(ceil_frac(-0.7_f64, 10), -0.7);
+    assert_eq!(ceil_frac(0.3_f64, 10), 0.3);
+    assert_eq!(floor_frac(0.7_f32, 10), 0.7);
+    assert_eq!(floor_frac(1.0_f64 / 3.0, 3), 1.0 / 3.0);
+    assert_eq!(ceil_frac(2.0_f64 / 3.0, 3), 2.0 / 3.0);
+    assert_eq!(floor_frac(2.0_f32 / 3.0, 3), 2.0 / 3.0);
+
+    // The exact value of 2.675 is below the tie but it rounds like 2.675 to 2 decimal places
+    assert_eq!(round_frac(2.675_f64, 100), 2.68);
+    assert_eq!(round_frac(2.675_f64, 100), round(2.675_f64, 2));
+    assert_eq!(round_frac(-2.675_f64, 100), -2.68);
+    assert_eq!(round_frac(1.005_f64, 100), round(1.005_f64, 2));
+    assert_eq!(round_frac(2.675_f32, 100), round(2.675_f32, 2));
+    assert_eq!(round_frac(1.0_f64 / 6.0, 3), 1.0 / 3.0);
+    assert_eq!(round_frac(-1.0_f64 / 6.0, 3), -1.0 / 3.0);
+    assert_eq!(round_frac(1.0_f32 / 6.0, 3), 1.0 / 3.0);
+    assert_eq!(floor_frac(1.0_f64 / 6.0, 3), 0.0);
+}
+
+#[test]
+fn test_round_frac_matches_round() {
+    let mut rng = XorShift(0x1357_9bdf);
+    for _ in 0..10_000 {
+        let decimal_places = (rng.next_u64() % 7) as u32;
+        let number = (rng.next_f64() - 0.5) * 2e4;
+        let denominator = 10_u32.pow(decimal_places);
+        assert_eq!(
+            round_frac(number, denominator),
+            round(number, decimal_places),
+            "{number} {decimal_places}"
+        );
+        assert_eq!(ceil_frac(number, denominator), ceil(number, decimal_places));
+        assert_eq!(
+            floor_frac(number, denominator),
+            floor(number, decimal_places)
+        );
+    }
+
+    // Decimals on the grid stay put and ones halfway between it are ties
+    for _ in 0..10_000 {
+        let decimal_places = (rng.next_u64() % 7) as u32;
+        let denominator = 10_u32.pow(decimal_places);
+        let units = rng.next_u64() % 2_000_000;
+        let on_grid: f64 = format!("{units}e-{decimal_places}").parse().unwrap();
+        let tie: f64 = format!("{}e-{}", 10 * units + 5, decimal_places + 1)
+            .parse()
+            .unwrap();
+        assert_eq!(floor_frac(on_grid, denominator), on_grid, "{on_grid}");
+        assert_eq!(ceil_frac(-on_grid, denominator), -on_grid, "{on_grid}");
+        assert_eq!(
+            round_frac(tie, denominator),
+            round(tie, decimal_places),
+            "{tie}"
+        );
+        assert_eq!(round_frac(-tie, denominator), round(-tie, decimal_places));
+
+        let on_grid = on_grid as f32;
+        let tie = tie as f32;
+        assert_eq!(
+            floor_frac(on_grid, denominator),
+            floor(on_grid, decimal_places)
+        );
+        assert_eq!(round_frac(tie, denominator), round(tie, decimal_places));
+    }
+}
+
+#[test]
+fn test_try_round_frac() {
+    assert_eq!(try_round_frac(0.3_f64, 3), Ok(1.0 / 3.0));
+    assert_eq!(
+        try_round_frac(0.3_f64, 0),
+        Err(RoundingError::ZeroDenominator)
+    );
+    assert_eq!(try_ceil_frac(f32::NAN, 0), Err(RoundingError::NonFinite));
+    assert_eq!(try_floor_frac(-0.7_f32, 2), Ok(-1.0));
+}
+
+#[test]
+#[should_panic(expected = "denominator must be greater than zero")]
+fn test_round_frac_zero_denominator() {
+    round_frac(0.3_f64, 0);
+}
+
+#[test]
+fn test_round_bits_patterns() {
+    // 1 + 2^-20 + 2^-21 has 22 significant bits, at 21 bits the last one is a tie
+    let one = 0x3ff0_0000_0000_0000_u64;
+    let number = f64::from_bits(one | (0b11 << 31));
+    assert_eq!(round_bits(number, 22).to_bits(), number.to_bits());
+    assert_eq!(round_bits(number, 21).to_bits(), one | (1 << 33));
+    assert_eq!(trunc_bits(number, 21).to_bits(), one | (1 << 32));
+    assert_eq!(round_bits(number, 20).to_bits(), one | (1 << 33));
+    assert_eq!(trunc_bits(number, 20).to_bits(), one);
+
+    // Only the top bits of the mantissa are kept
+    let pi = std::f64::consts::PI;
+    assert_eq!(round_bits(pi, 20).to_bits() & ((1 << 33) - 1), 0);
+    assert_eq!(
+        trunc_bits(pi, 20).to_bits(),
+        pi.to_bits() & !((1 << 33) - 1)
+    );
+    assert_eq!(round_bits(std::f32::consts::PI, 8), 3.140625);
+    assert_eq!(trunc_bits(-1.999_f64, 3), -1.75);
+    assert_eq!(ceil_bits(-1.999_f64, 3), -1.75);
+    assert_eq!(ceil_bits(1.751_f64, 3), 2.0);
+
+    // Ties go to the even bit
+    assert_eq!(round_bits(2.5_f64, 2), 2.0);
+    assert_eq!(round_bits(3.5_f64, 2), 4.0);
+    assert_eq!(round_bits(-2.5_f32, 2), -2.0);
+    assert_eq!(round_bits(-3.5_f32, 2), -4.0);
+}
+
+#[test]
+fn test_round_bits_edges() {
+    // Rounding past the largest significand carries into the exponent
+    assert_eq!(round_bits(f64::MAX, 52), f64::INFINITY);
+    assert_eq!(
+        trunc_bits(f64::MAX, 52),
+        f64::from_bits(f64::MAX.to_bits() - 1)
+    );
+    assert_eq!(ceil_bits(f32::MAX, 1), f32::INFINITY);
+
+    // Subnormals have fewer bits to begin with
+    assert_eq!(
+        round_bits(f64::from_bits(0b1011), 4),
+        f64::from_bits(0b1011)
+    );
+    assert_eq!(
+        round_bits(f64::from_bits(0b1011), 3),
+        f64::from_bits(0b1100)
+    );
+    assert_eq!(
+        trunc_bits(f64::from_bits(0b1011), 1),
+        f64::from_bits(0b1000)
+    );
+    assert_eq!(round_bits(f32::from_bits(0b111), 2), f32::from_bits(0b1000));
+
+    assert_eq!(round_bits(0.0_f64, 1), 0.0);
+    assert!(round_bits(-0.0_f64, 1).is_sign_negative());
+    assert_eq!(round_bits(f64::NEG_INFINITY, 1), f64::NEG_INFINITY);
+    let nan = f64::from_bits(0x7ff8_0000_0000_1234);
+    assert_eq!(round_bits(nan, 10).to_bits(), nan.to_bits());
+}
+
+#[test]
+fn test_round_bits_random() {
+    let mut rng = XorShift(0xb175_b175);
+    for _ in 0..10_000 {
+        let number = f64::from_bits(rng.next_u64());
+        if !number.is_finite() {
+            continue;
+        }
+
+        assert_eq!(round_bits(number, 53).to_bits(), number.to_bits());
+        assert_eq!(trunc_bits(number, 53).to_bits(), number.to_bits());
+
+        let bits = 1 + (rng.next_u64() % 52) as u32;
+        let rounded = round_bits(number, bits);
+        let truncated = trunc_bits(number, bits);
+        let ceiled = ceil_bits(number, bits);
+        assert_eq!(round_bits(rounded, bits).to_bits(), rounded.to_bits());
+        assert_eq!(trunc_bits(truncated, bits).to_bits(), truncated.to_bits());
+        assert_eq!(ceil_bits(ceiled, bits).to_bits(), ceiled.to_bits());
+        assert!(truncated.abs() <= number.abs());
+        assert!(ceiled >= number);
+
+        // The result is one of the neighbours with that many bits either side of the number
+        let away = if number > 0.0 {
+            ceiled
+        } else {
+            -ceil_bits(-number, bits)
+        };
+        assert!(
+            rounded == truncated || rounded == away || rounded == ceiled,
+            "{number} {bits}"
+        );
+
+        let number = f32::from_bits(rng.next_u64() as u32);
+        if number.is_finite() {
+            assert_eq!(round_bits(number, 24).to_bits(), number.to_bits());
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "bits must be between 1 and 53")]
+fn test_round_bits_zero() {
+    round_bits(1.5_f64, 0);
+}
+
+#[test]
+#[should_panic(expected = "bits must be between 1 and 24")]
+fn test_round_bits_too_many() {
+    round_bits(1.5_f32, 25);
+}
+
+#[test]
+fn test_count_sig_figs_integers() {
+    assert_eq!(count_sig_figs(1500_u32), 2);
+    assert_eq!(count_sig_figs(1501_u32), 4);
+    assert_eq!(count_sig_figs(1_000_000_i64), 1);
+    assert_eq!(count_sig_figs(-1_020_i16), 3);
+    assert_eq!(count_sig_figs(7_u8), 1);
+    assert_eq!(count_sig_figs(0_i32), 0);
+    assert_eq!(count_sig_figs(u64::MAX), 20);
+    assert_eq!(count_sig_figs(i64::MIN), 19);
+    assert_eq!(count_sig_figs(i8::MIN), 3);
+
+    for exponent in 0..=19 {
+        assert_eq!(count_sig_figs(10_u64.pow(exponent)), 1);
+    }
+}
+
+#[test]
+fn test_count_sig_figs_floats() {
+    assert_eq!(count_sig_figs(1.20_f64), 2);
+    assert_eq!(count_sig_figs(0.00340_f64), 2);
+    assert_eq!(count_sig_figs(0.1_f64), 1);
+    assert_eq!(count_sig_figs(-0.000_105_f64), 3);
+    assert_eq!(count_sig_figs(123.456_f32), 6);
+    assert_eq!(count_sig_figs(1500_f64), 2);
+    assert_eq!(count_sig_figs(0.1_f64 + 0.2), 17);
+    assert_eq!(count_sig_figs(0.0_f64), 0);
+    assert_eq!(count_sig_figs(f64::NAN), 0);
+    assert_eq!(count_sig_figs(f32::INFINITY), 0);
+
+    for exponent in -300..=300 {
+        let power = format!("1e{exponent}").parse::<f64>().unwrap();
+        assert_eq!(count_sig_figs(power), 1);
+    }
+
+    // Rounding to fewer significant figures than a number has leaves that many
+    let mut rng = XorShift(0x51f5_51f5);
+    for _ in 0..10_000 {
+        let number = (rng.next_f64() - 0.5) * 1e6;
+        let sig_figs = 1 + (rng.next_u64() % 10) as u32;
+        assert!(count_sig_figs(round_sf(number, sig_figs)) <= sig_figs);
+    }
+}
+
+#[test]
+fn test_count_decimal_places() {
+    assert_eq!(count_decimal_places(0.1_f64), 1);
+    assert_eq!(count_decimal_places(0.1_f32), 1);
+    assert_eq!(count_decimal_places(0.25_f64), 2);
+    assert_eq!(count_decimal_places(1e-7_f64), 7);
+    assert_eq!(count_decimal_places(1.5e-7_f64), 8);
+    assert_eq!(count_decimal_places(123_456_789.123_f64), 3);
+    assert_eq!(count_decimal_places(-0.001_f32), 3);
+    assert_eq!(count_decimal_places(0.1_f64 + 0.2), 17);
+    assert_eq!(count_decimal_places(5e-324_f64), 324);
+
+    // Whole numbers, including ones written in scientific notation
+    assert_eq!(count_decimal_places(1.0_f64), 0);
+    assert_eq!(count_decimal_places(0.0_f64), 0);
+    assert_eq!(count_decimal_places(-42.0_f64), 0);
+    assert_eq!(count_decimal_places(1e300_f64), 0);
+    assert_eq!(count_decimal_places(16_777_216_f32), 0);
+    assert_eq!(count_decimal_places(f64::NAN), 0);
+    assert_eq!(count_decimal_places(f64::NEG_INFINITY), 0);
+}
+
+#[test]
+fn test_needs_rounding() {
+    assert!(needs_rounding(0.125_f64, 2));
+    assert!(!needs_rounding(0.125_f64, 3));
+    assert!(!needs_rounding(1e300_f64, 0));
+    assert!(!needs_rounding(f32::INFINITY, 0));
+
+    // Agrees with whether rounding changes the number
+    let mut rng = XorShift(0xdec1_dec1);
+    for _ in 0..10_000 {
+        let decimal_places = (rng.next_u64() % 8) as u32;
+        let number = round((rng.next_f64() - 0.5) * 1e4, (rng.next_u64() % 8) as u32);
+        assert_eq!(
+            needs_rounding(number, decimal_places),
+            round(number, decimal_places) != number,
+            "{number} {decimal_places}"
+        );
+    }
+}
+
+#[test]
+fn test_digit_count() {
+    assert_eq!(digit_count(0_u8), 1);
+    assert_eq!(digit_count(9_i32), 1);
+    assert_eq!(digit_count(10_u16), 2);
+    assert_eq!(digit_count(-10_i64), 2);
+    assert_eq!(digit_count(u64::MAX), 20);
+    assert_eq!(digit_count(i64::MIN), 19);
+    assert_eq!(digit_count(i8::MIN), 3);
+
+    assert_eq!(digit_count(0.0_f64), 1);
+    assert_eq!(digit_count(-0.0_f32), 1);
+    assert_eq!(digit_count(0.5_f64), 1);
+    assert_eq!(digit_count(9.999_f64), 1);
+    assert_eq!(digit_count(10_f32), 2);
+    assert_eq!(digit_count(123.456_f64), 3);
+    assert_eq!(digit_count(-123.456_f32), 3);
+    assert_eq!(digit_count(1e-300_f64), 1);
+    assert_eq!(digit_count(f64::MAX), 309);
+    assert_eq!(digit_count(f32::MAX), 39);
+    assert_eq!(digit_count(f64::INFINITY), 0);
+    assert_eq!(digit_count(f32::NAN), 0);
+}
+
+#[test]
+fn test_digits() {
+    assert!(digits(0_u32).eq([0]));
+    assert!(digits(9_u8).eq([9]));
+    assert!(digits(10_i16).eq([1, 0]));
+    assert!(digits(-120_i32).eq([1, 2, 0]));
+    assert!(digits(u64::MAX).eq([1, 8, 4, 4, 6, 7, 4, 4, 0, 7, 3, 7, 0, 9, 5, 5, 1, 6, 1, 5]));
+    assert!(digits(i64::MIN).eq([9, 2, 2, 3, 3, 7, 2, 0, 3, 6, 8, 5, 4, 7, 7, 5, 8, 0, 8]));
+
+    assert!(digits(0.0_f64).eq([0]));
+    assert!(digits(0.5_f64).eq([0]));
+    assert!(digits(-0.999_f32).eq([0]));
+    assert!(digits(123.456_f64).eq([1, 2, 3]));
+    assert!(digits(-123.456_f64).eq([1, 2, 3]));
+    assert!(digits(1e20_f64).eq([1].into_iter().chain([0; 20])));
+    assert!(digits(1.5e-20_f64).eq([0]));
+    assert_eq!(digits(f64::NAN).next(), None);
+    assert_eq!(digits(f32::NEG_INFINITY).len(), 0);
+
+    // The count of digits agrees with the digits yielded and the integer part of the number
+    let mut rng = XorShift(0xd191_7500);
+    for _ in 0..10_000 {
+        let integer = rng.next_u64() >> (rng.next_u64() % 64);
+        let mut expected = integer.to_string().into_bytes();
+        expected.iter_mut().for_each(|digit| *digit -= b'0');
+        assert!(digits(integer).eq(expected.iter().copied()), "{integer}");
+        assert_eq!(digit_count(integer) as usize, expected.len(), "{integer}");
+
+        let number = f64::from_bits(rng.next_u64());
+        if number.is_finite() {
+            let yielded = digits(number);
+            assert_eq!(yielded.len(), digit_count(number) as usize, "{number}");
+            if number.abs() < 1e15 {
+                let integer_part = number.abs().trunc() as u64;
+                assert!(yielded.eq(digits(integer_part)), "{number}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_format_dp() {
+    let cases = [
+        (1.5, 2, "1.50"),
+        (1.005, 2, "1.00"),
+        (2.675, 2, "2.68"),
+        (123.456, 0, "123"),
+        (0.5, 0, "1"),
+        (0.05, 1, "0.1"),
+        (0.001, 4, "0.0010"),
+        (-0.125, 2, "-0.13"),
+        (-0.001, 2, "-0.00"),
+        (0.0, 3, "0.000"),
+        (1e-7, 8, "0.00000010"),
+        (1e21, 2, "1000000000000000000000.00"),
+        (0.1, 25, "0.1000000000000000000000000"),
+        (f64::NAN, 2, "NaN"),
+        (f64::INFINITY, 2, "inf"),
+        (f64::NEG_INFINITY, 0, "-inf"),
+    ];
+    for (number, decimal_places, expected) in cases {
+        assert_eq!(format_dp(number, decimal_places), expected, "{number}");
+    }
+
+    assert_eq!(format_dp(1.5_f32, 3), "1.500");
+    assert_eq!(format_dp(0.1_f32, 12), "0.100000000000");
+    assert_eq!(format_dp(16_777_217_f32, 1), "16777216.0");
+}
+
+#[test]
+fn test_format_dp_agrees_with_round() {
+    let mut rng = XorShift(0xf0f0_0dd5);
+    for _ in 0..10_000 {
+        let decimal_places = (rng.next_u64() % 20) as u32;
+        let exponent = (rng.next_u64() % 40) as i32 - 20;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        let formatted = format_dp(number, decimal_places);
+
+        // Numbers with more digits than the type can hold at the decimal places aren't rounded,
+        // and the closest string with that many decimal places is within an ulp of them
+        let rounded = round(number, decimal_places);
+        let parsed = formatted.parse::<f64>().unwrap();
+        if count_decimal_places(rounded) <= decimal_places {
+            assert_eq!(parsed, rounded, "{number} {decimal_places}");
+        } else {
+            assert!(
+                (parsed - rounded).abs() <= f64::EPSILON * rounded.abs(),
+                "{number} {decimal_places}"
+            );
+        }
+        let fraction = formatted
+            .split_once('.')
+            .map_or("", |(_, fraction)| fraction);
+        assert_eq!(fraction.len(), decimal_places as usize, "{formatted}");
+
+        let number = number as f32;
+        let decimal_places = decimal_places.min(12);
+        let rounded = round(number, decimal_places);
+        let parsed = format_dp(number, decimal_places).parse::<f32>().unwrap();
+        if count_decimal_places(rounded) <= decimal_places {
+            assert_eq!(parsed, rounded, "{number} {decimal_places}");
+        } else {
+            assert!(
+                (parsed - rounded).abs() <= f32::EPSILON * rounded.abs(),
+                "{number} {decimal_places}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_format_sf() {
+    let cases = [
+        (1.203, 3, "1.20"),
+        (1.0, 4, "1.000"),
+        (0.0034, 3, "0.00340"),
+        (0.003_456, 2, "0.0035"),
+        (-0.0999, 2, "-0.10"),
+        (9.996, 3, "10.0"),
+        (123.456, 2, "120"),
+        (123_456.0, 4, "123500"),
+        (1e20, 2, "100000000000000000000"),
+        (1.5e-7, 3, "0.000000150"),
+        (0.0, 3, "0.00"),
+        (-0.0, 1, "-0"),
+        (42.0, 0, "0"),
+        (f64::INFINITY, 3, "inf"),
+        (f64::NEG_INFINITY, 3, "-inf"),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(format_sf(number, sig_figs), expected, "{number}");
+    }
+
+    assert_eq!(format_sf(1.203_f32, 3), "1.20");
+    assert_eq!(format_sf(-0.5_f32, 5), "-0.50000");
+
+    assert_eq!(format_sf(123_456_i32, 4), "123500");
+    assert_eq!(format_sf(-1250_i64, 2), "-1300");
+    assert_eq!(format_sf(7_u8, 3), "7.00");
+    assert_eq!(format_sf(0_i16, 2), "0.0");
+    assert_eq!(format_sf(250_u8, 1), "300");
+    assert_eq!(format_sf(i64::MIN, 3), "-9220000000000000000");
+    assert_eq!(format_sf(u64::MAX, 25), "18446744073709551615.00000");
+}
+
+#[test]
+fn test_format_sf_scientific() {
+    let cases = [
+        (1.203, 3, "1.20e0"),
+        (0.0034, 3, "3.40e-3"),
+        (-123_456.0, 4, "-1.235e5"),
+        (9.96, 2, "1.0e1"),
+        (5.0, 1, "5e0"),
+        (0.0, 3, "0.00e0"),
+        (f64::NAN, 3, "NaN"),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(
+            format_sf_notation(number, sig_figs, Notation::Scientific),
+            expected,
+            "{number}"
+        );
+    }
+
+    assert_eq!(
+        format_sf_notation(123_456_u32, 4, Notation::Scientific),
+        "1.235e5"
+    );
+    assert_eq!(format_sf_notation(-7_i8, 2, Notation::Scientific), "-7.0e0");
+    assert_eq!(format_sf_notation(0_u64, 1, Notation::Scientific), "0e0");
+}
+
+#[test]
+fn test_format_sf_agrees_with_round_sf() {
+    let mut rng = XorShift(0x5f5f_5f5f);
+    for _ in 0..10_000 {
+        let sig_figs = (rng.next_u64() % 15) as u32 + 1;
+        let exponent = (rng.next_u64() % 40) as i32 - 20;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+
+        for notation in [Notation::Fixed, Notation::Scientific] {
+            let formatted = format_sf_notation(number, sig_figs, notation);
+            assert_eq!(
+                formatted.parse::<f64>().unwrap(),
+                round_sf(number, sig_figs),
+                "{number} {sig_figs}"
+            );
+            if number != 0.0 {
+                let significant = formatted
+                    .split('e')
+                    .next()
+                    .unwrap()
+                    .trim_start_matches(['-', '0', '.'])
+                    .bytes()
+                    .filter(u8::is_ascii_digit)
+                    .count();
+                // Fixed notation can pad an integer part with zeros past the significant figures
+                assert!(
+                    significant == sig_figs as usize
+                        || notation == Notation::Fixed && significant > sig_figs as usize,
+                    "{formatted} {sig_figs}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_format_engineering() {
+    let cases = [
+        (0.00047, 2, "470e-6"),
+        (0.00047, 3, "470e-6"),
+        (0.000_471_2, 3, "471e-6"),
+        (12_345_678.0, 3, "12.3e6"),
+        (1234.5, 5, "1.2345e3"),
+        (1.0, 3, "1.00e0"),
+        (0.1, 2, "100e-3"),
+        (-45_600.0, 3, "-45.6e3"),
+        (1e-300, 1, "1e-300"),
+        (0.0, 1, "0e0"),
+        (-0.0, 3, "-0.00e0"),
+        (f64::NAN, 3, "NaN"),
+        (f64::NEG_INFINITY, 3, "-inf"),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(format_engineering(number, sig_figs), expected, "{number}");
+    }
+
+    assert_eq!(
+        format_sf_notation(-12_345_i32, 2, Notation::Engineering),
+        "-12e3"
+    );
+    assert_eq!(
+        format_sf_notation(0.0047_f32, 3, Notation::Engineering),
+        "4.70e-3"
+    );
+    assert_eq!(format_engineering_superscript(-0.00047, 2), "-470 × 10⁻⁶");
+    assert_eq!(format_engineering_superscript(1.5, 2), "1.5 × 10⁰");
+    assert_eq!(format_engineering_superscript(4.2e21, 2), "4.2 × 10²¹");
+}
+
+#[test]
+#[should_panic(expected = "sig_figs must be greater than zero")]
+fn test_format_engineering_zero_sig_figs() {
+    format_engineering(1.0, 0);
+}
+
+#[test]
+#[should_panic(expected = "sig_figs must be greater than zero")]
+fn test_format_engineering_superscript_zero_sig_figs() {
+    format_engineering_superscript(0.0, 0);
+}
+
+#[test]
+fn test_format_engineering_exponent_bump() {
+    // Rounding the mantissa up to 1000 moves on to the next multiple of three
+    assert_eq!(format_engineering(999.7, 3), "1.00e3");
+    assert_eq!(format_engineering(999.4, 3), "999e0");
+    assert_eq!(format_engineering(-999_950.0, 4), "-1.000e6");
+    assert_eq!(format_engineering(0.000_999_6, 3), "1.00e-3");
+    assert_eq!(format_engineering(0.000_999_6, 4), "999.6e-6");
+    assert_eq!(
+        format_sf_notation(99_960_u32, 3, Notation::Engineering),
+        "100e3"
+    );
+    assert_eq!(
+        format_sf_notation(999_600_u32, 3, Notation::Engineering),
+        "1.00e6"
+    );
+
+    // Every mantissa is in [1, 1000) with an exponent that is a multiple of three
+    let mut rng = XorShift(0xe9e9_0003);
+    for _ in 0..10_000 {
+        let sig_figs = (rng.next_u64() % 6) as u32 + 1;
+        let exponent = (rng.next_u64() % 40) as i32 - 20;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        if number == 0.0 {
+            continue;
+        }
+
+        let formatted = format_engineering(number, sig_figs);
+        let (mantissa, exponent) = formatted.split_once('e').unwrap();
+        let mantissa: f64 = mantissa.parse().unwrap();
+        let exponent: i32 = exponent.parse().unwrap();
+        assert!(
+            (1.0..1000.0).contains(&mantissa.abs()),
+            "{number} {formatted}"
+        );
+        assert_eq!(exponent % 3, 0, "{number} {formatted}");
+        assert_eq!(
+            formatted.parse::<f64>().unwrap(),
+            round_sf(number, sig_figs),
+            "{number} {formatted}"
+        );
+    }
+}
+
+#[test]
+fn test_to_scientific() {
+    let cases = [
+        (123_456.0, 3, (1.23, 5)),
+        (1.0, 1, (1.0, 0)),
+        (0.000_123_4, 2, (1.2, -4)),
+        (-0.5, 3, (-5.0, -1)),
+        (6.02214076e23, 4, (6.022, 23)),
+        (1.602e-19, 2, (1.6, -19)),
+        (1.7e308, 2, (1.7, 308)),
+        (f64::MAX, 17, (1.7976931348623157, 308)),
+        (5e-324, 1, (5.0, -324)),
+        (0.0, 2, (0.0, 0)),
+    ];
+    for (number, sig_figs, expected) in cases {
+        assert_eq!(to_scientific(number, sig_figs), expected, "{number}");
+    }
+
+    let (mantissa, exponent) = to_scientific(-0.0_f64, 2);
+    assert!(mantissa == 0.0 && mantissa.is_sign_negative() && exponent == 0);
+    let (mantissa, exponent) = to_scientific(f64::NAN, 2);
+    assert!(mantissa.is_nan() && exponent == 0);
+    assert_eq!(to_scientific(f32::NEG_INFINITY, 2), (f32::NEG_INFINITY, 0));
+    assert_eq!(to_scientific(1.5e-40_f32, 2), (1.5, -40));
+}
+
+#[test]
+fn test_to_scientific_carry() {
+    // Rounding the mantissa up to 10 moves on to the next power of ten
+    assert_eq!(to_scientific(9.97, 2), (1.0, 1));
+    assert_eq!(to_scientific(-9.97, 2), (-1.0, 1));
+    assert_eq!(to_scientific(0.000_999_7_f64, 3), (1.0, -3));
+    assert_eq!(to_scientific(99_950.0_f32, 3), (1.0, 5));
+    assert_eq!(to_scientific(9.94, 2), (9.9, 0));
+}
+
+#[test]
+fn test_to_scientific_round_trip() {
+    let mut rng = XorShift(0x5c1e_0e00);
+    for _ in 0..10_000 {
+        let sig_figs = (rng.next_u64() % 16) as u32 + 1;
+        let number = f64::from_bits(rng.next_u64());
+        if !number.is_finite() {
+            continue;
+        }
+
+        let (mantissa, exponent) = to_scientific(number, sig_figs);
+        let rounded = round_sf(number, sig_figs);
+        if rounded == 0.0 {
+            assert_eq!(exponent, 0);
+            continue;
+        }
+        assert!((1.0..10.0).contains(&mantissa.abs()), "{number} {mantissa}");
+        assert_eq!(mantissa.is_sign_negative(), number.is_sign_negative());
+
+        // Parsed so powers of ten beyond the range of an f64 are exact, leaving the error in
+        // rounding the mantissa to a float, which is relatively coarser than the rounded number
+        let scaled: f64 = format!("{mantissa}e{exponent}").parse().unwrap();
+        assert!(
+            (scaled - rounded).abs() <= 2.0 * f64::EPSILON * rounded.abs(),
+            "{number} {sig_figs}"
+        );
+        if (0..=22).contains(&exponent) {
+            let product = mantissa * 10_f64.powi(exponent);
+            assert!(
+                (product - rounded).abs() <= 2.0 * f64::EPSILON * rounded.abs(),
+                "{number} {sig_figs}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_format_grouped() {
+    let cases = [
+        (1_234_567.891, 2, "1,234,567.89"),
+        (-1_234_567.891, 2, "-1,234,567.89"),
+        (999.994, 2, "999.99"),
+        (999.995, 2, "1,000.00"),
+        (123.0, 1, "123.0"),
+        (-12.5, 0, "-13"),
+        (0.125, 2, "0.13"),
+        (-0.001, 2, "-0.00"),
+        (0.0, 0, "0"),
+        (1_000.0, 0, "1,000"),
+        (100_000.4, 0, "100,000"),
+        (1e20, 0, "100,000,000,000,000,000,000"),
+        (f64::NAN, 2, "NaN"),
+        (f64::NEG_INFINITY, 2, "-inf"),
+    ];
+    for (number, decimal_places, expected) in cases {
+        assert_eq!(
+            format_grouped(number, decimal_places, ',', '.'),
+            expected,
+            "{number}"
+        );
+    }
+
+    assert_eq!(format_grouped(1_234.5_f32, 1, ',', '.'), "1,234.5");
+    assert_eq!(format_grouped(0_u8, 0, ',', '.'), "0");
+    assert_eq!(format_grouped(-1_234_i32, 2, ',', '.'), "-1,234.00");
+    assert_eq!(
+        format_grouped(i64::MIN, 0, ',', '.'),
+        "-9,223,372,036,854,775,808"
+    );
+    assert_eq!(
+        format_grouped(u64::MAX, 0, '_', '.'),
+        "18_446_744_073_709_551_615"
+    );
+}
+
+#[test]
+fn test_format_grouped_locales() {
+    let number = -1_234_567.891;
+    let locales = [
+        (',', '.', "-1,234,567.89"),
+        ('.', ',', "-1.234.567,89"),
+        (' ', ',', "-1 234 567,89"),
+        ('\u{202f}', ',', "-1\u{202f}234\u{202f}567,89"),
+        ('\'', '.', "-1'234'567.89"),
+        ('\'', ',', "-1'234'567,89"),
+    ];
+    for (group_separator, decimal_separator, expected) in locales {
+        assert_eq!(
+            format_grouped(number, 2, group_separator, decimal_separator),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_format_grouped_agrees_with_format_dp() {
+    let mut rng = XorShift(0x9a0b_5e9d);
+    for _ in 0..10_000 {
+        let decimal_places = (rng.next_u64() % 10) as u32;
+        let exponent = (rng.next_u64() % 30) as i32 - 10;
+        let number = (rng.next_f64() - 0.5) * 10_f64.powi(exponent);
+        let formatted = format_dp(number, decimal_places);
+        let grouped = format_grouped(number, decimal_places, ' ', ',');
+        assert_eq!(
+            grouped.replace(' ', "").replace(',', "."),
+            formatted,
+            "{number}"
+        );
+
+        let integer = grouped.trim_start_matches('-');
+        let integer = integer.split(',').next().unwrap();
+        let groups: Vec<&str> = integer.split(' ').collect();
+        assert!((1..=3).contains(&groups[0].len()), "{grouped}");
+        assert!(
+            groups[1..].iter().all(|group| group.len() == 3),
+            "{grouped}"
+        );
+
+        let number = rng.next_u64() as i64;
+        assert_eq!(
+            format_grouped(number, 0, ' ', ',').replace(' ', ""),
+            number.to_string()
+        );
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn test_deterministic_vectors() {
+    // Each line is the type, the bits of a number, the precision and the bits of
+    // round, round_zeros and round_sf to that precision (at least one significant figure)
+    for line in include_str!("deterministic_vectors.txt").lines() {
+        let fields: Vec<&str> = line.split(' ').collect();
+        let n: u32 = fields[2].parse().unwrap();
+        let bits: Vec<u64> = [1, 3, 4, 5]
+            .iter()
+            .map(|&i| u64::from_str_radix(fields[i], 16).unwrap())
+            .collect();
+
+        if fields[0] == "f64" {
+            let number = f64::from_bits(bits[0]);
+            assert_eq!(round(number, n).to_bits(), bits[1], "{line}");
+            assert_eq!(round_zeros(number, n).to_bits(), bits[2], "{line}");
+            assert_eq!(round_sf(number, n.max(1)).to_bits(), bits[3], "{line}");
+        } else {
+            let number = f32::from_bits(bits[0] as u32);
+            assert_eq!(round(number, n).to_bits() as u64, bits[1], "{line}");
+            assert_eq!(round_zeros(number, n).to_bits() as u64, bits[2], "{line}");
+            assert_eq!(
+                round_sf(number, n.max(1)).to_bits() as u64,
+                bits[3],
+                "{line}"
+            );
+        }
+    }
+}
+
+/// A fixed point number in hundredths, implementing only the methods of `Roundable` without defaults
+#[cfg(test)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hundredths(i64);
+
+#[cfg(test)]
+impl Roundable for Hundredths {
+    fn to_decimal(&self) -> Option<(i128, i32)> {
+        Some((self.0 as i128, -2))
+    }
+
+    fn from_decimal(mantissa: i128, exponent: i32) -> Option<Self> {
+        let shift = exponent.checked_add(2)?;
+        let hundredths = if shift >= 0 {
+            mantissa.checked_mul(10_i128.checked_pow(shift as u32)?)?
+        } else {
+            let power = 10_i128.checked_pow(shift.unsigned_abs())?;
+            if mantissa % power != 0 {
+                return None;
+            }
+            mantissa / power
+        };
+        i64::try_from(hundredths).ok().map(Hundredths)
+    }
+}
+
+#[test]
+fn test_default_methods() {
+    assert_eq!(Hundredths(12_345).round_zeros(1), Hundredths(12_000));
+    assert_eq!(Hundredths(12_345).ceil_zeros(0), Hundredths(12_400));
+    assert_eq!(Hundredths(-12_345).floor_zeros(0), Hundredths(-12_400));
+    assert_eq!(Hundredths(-12_355).trunc_zeros(1), Hundredths(-12_000));
+    assert_eq!(Hundredths(12_345).round_sf(2), Hundredths(12_000));
+    assert_eq!(Hundredths(12_345).ceil_sf(4), Hundredths(12_350));
+    assert_eq!(Hundredths(12_345).floor_sf(4), Hundredths(12_340));
+    assert_eq!(Hundredths(12_250).round_sf_even(3), Hundredths(12_200));
+    assert_eq!(Hundredths(5).round_sf(3), Hundredths(5));
+    assert_eq!(Hundredths(5).get_digits(), -1);
+    assert_eq!(Hundredths(5).digit_count(), 1);
+    assert_eq!(round_sf(Hundredths(99_950), 3), Hundredths(100_000));
+
+    assert_eq!(Hundredths(i64::MAX).checked_round_zeros(1), None);
+    assert_eq!(
+        Hundredths(i64::MAX).checked_floor_sf(1),
+        Some(Hundredths(9 * 10_i64.pow(18)))
+    );
+    assert_eq!(
+        Hundredths(i64::MAX).try_ceil_sf(1),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(
+        Hundredths(150).try_round_sf(0),
+        Err(RoundingError::ZeroSigFigs)
+    );
+    assert_eq!(Hundredths(150).saturating_round_zeros(0), Hundredths(200));
+
+    // Results that don't fit saturate rather than panic
+    assert_eq!(Hundredths(i64::MAX).round_zeros(1), Hundredths(i64::MAX));
+    assert_eq!(Hundredths(i64::MAX).round_decimals(1), Hundredths(i64::MAX));
+    assert_eq!(Hundredths(i64::MIN).floor_sf(1), Hundredths(i64::MIN));
+    assert_eq!(Hundredths(12_345).round_decimals(1), Hundredths(12_350));
+    assert_eq!(Hundredths(-12_345).floor_decimals(0), Hundredths(-12_400));
+
+    let mut values = [Hundredths(149), Hundredths(-250)];
+    round_slice_zeros(&mut values, 0);
+    assert_eq!(values, [Hundredths(100), Hundredths(-300)]);
+    assert_eq!(
+        Hundredths(237).round_zeros_stochastic(0, || 0.5),
+        Hundredths(200)
+    );
+    assert_eq!(
+        Hundredths(237).round_zeros_stochastic(0, || 0.1),
+        Hundredths(300)
+    );
+    assert_eq!(
+        Hundredths(-237).round_zeros_stochastic(0, || 0.5),
+        Hundredths(-200)
+    );
+    assert_eq!(
+        Hundredths(200).round_zeros_stochastic(0, || unreachable!()),
+        Hundredths(200)
+    );
+
+    assert_eq!(
+        Hundredths(130).round_to_multiple(Hundredths(25)),
+        Hundredths(125)
+    );
+    assert_eq!(
+        Hundredths(130).ceil_to_multiple(Hundredths(25)),
+        Hundredths(150)
+    );
+    assert_eq!(
+        Hundredths(-130).floor_to_multiple(Hundredths(25)),
+        Hundredths(-150)
+    );
+    assert_eq!(
+        Hundredths(i64::MAX).ceil_to_multiple(Hundredths(1_000)),
+        Hundredths(i64::MAX)
+    );
+    assert_eq!(
+        Hundredths(130).try_round_to_multiple(Hundredths(0)),
+        Err(RoundingError::InvalidStep)
+    );
+    assert_eq!(
+        Hundredths(i64::MAX).try_ceil_to_multiple(Hundredths(1_000)),
+        Err(RoundingError::Overflow)
+    );
+
+    assert_eq!(
+        Hundredths(1_234).quantize(Hundredths(100), Hundredths(99)),
+        Hundredths(1_199)
+    );
+    assert_eq!(
+        Hundredths(1_234).ceil_quantize(Hundredths(100), Hundredths(99)),
+        Hundredths(1_299)
+    );
+    assert_eq!(
+        Hundredths(-1_234).checked_floor_quantize(Hundredths(50), Hundredths(1)),
+        Some(Hundredths(-1_249))
+    );
+    assert_eq!(
+        Hundredths(i64::MAX).checked_ceil_quantize(Hundredths(100), Hundredths(0)),
+        None
+    );
+
+    assert_eq!(Hundredths(3_456).round_pow10(), Hundredths(1_000));
+    assert_eq!(Hundredths(3_456).ceil_pow10(), Hundredths(10_000));
+    assert_eq!(Hundredths(-5).floor_pow10(), Hundredths(-1));
+    assert_eq!(Hundredths(0).round_pow10(), Hundredths(0));
+    assert_eq!(Hundredths(0).checked_round_pow10(), None);
+    assert_eq!(Hundredths(i64::MAX).checked_ceil_pow10(), None);
+    assert_eq!(Hundredths(i64::MAX).ceil_pow10(), Hundredths(i64::MAX));
+
+    assert_eq!(Hundredths(3_456).order_of_magnitude(), Some(1));
+    assert_eq!(Hundredths(5).order_of_magnitude(), Some(-2));
+    assert_eq!(Hundredths(0).order_of_magnitude(), None);
+    assert_eq!(Hundredths(12_300).count_sig_figs(), 3);
+    assert!(Hundredths(-12_345).digits().eq([1, 2, 3]));
+    assert!(Hundredths(5).digits().eq([0]));
+
+    assert!(Hundredths(125).tie_at(1));
+    assert!(!Hundredths(124).tie_at(1));
+    assert_eq!(
+        Hundredths(149).rounding_error(Hundredths(100)),
+        Hundredths(49)
+    );
+    assert!(Hundredths(151).nearer_above(Hundredths(100), Hundredths(200)));
+    assert!(!Hundredths(149).nearer_above(Hundredths(100), Hundredths(200)));
+
+    #[cfg(feature = "alloc")]
+    {
+        assert_eq!(Hundredths(12_345).format_sf(2), "120");
+        assert_eq!(
+            Hundredths(12_345).format_sf_notation(3, Notation::Scientific),
+            "1.23e2"
+        );
+        assert_eq!(Hundredths(-5).format_sf(2), "-0.050");
+        assert_eq!(
+            Hundredths(123_456_789).format_grouped(1, ',', '.'),
+            "1,234,567.9"
+        );
+        assert_eq!(Hundredths(150).format_locale(0, ',', None), "2");
+        assert_eq!(Hundredths(-150).format_locale(3, ',', None), "-1,500");
+    }
+}
+
+#[test]
+fn test_decimal_bridge() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..10_000 {
+        let bits = rng.next_u64();
+        let number = bits as i64 >> (bits % 64);
+        let (mantissa, exponent) = number.to_decimal().unwrap();
+        assert_eq!(i64::from_decimal(mantissa, exponent), Some(number));
+        assert_eq!(
+            i16::from_decimal(mantissa, exponent),
+            i16::try_from(number).ok()
+        );
+
+        let float = f64::from_bits(bits);
+        match float.to_decimal() {
+            Some((mantissa, exponent)) => {
+                assert_eq!(
+                    f64::from_decimal(mantissa, exponent),
+                    Some(float),
+                    "{float}"
+                );
+            }
+            None => assert!(!float.is_finite()),
+        }
+        let float = f32::from_bits(bits as u32);
+        if let Some((mantissa, exponent)) = float.to_decimal() {
+            assert_eq!(
+                f32::from_decimal(mantissa, exponent),
+                Some(float),
+                "{float}"
+            );
+        }
+    }
+
+    assert_eq!(u128::MAX.to_decimal(), None);
+    assert_eq!(i128::MIN.to_decimal(), Some((i128::MIN, 0)));
+    assert_eq!(u8::from_decimal(2_550, -1), Some(255));
+    assert_eq!(u8::from_decimal(-1, 0), None);
+    assert_eq!(i64::from_decimal(0, -100), Some(0));
+    assert_eq!(f64::from_decimal(i128::MIN, i32::MAX), None);
+    assert_eq!(f64::from_decimal(i128::MIN, i32::MIN), Some(-0.0));
+}
+
+/// Rounds to decimal places, zeros and significant figures with a single bound
+#[cfg(test)]
+fn round_all<T: Roundable + Copy>(number: T) -> [T; 6] {
+    [
+        number.round_decimals(1),
+        number.ceil_decimals(0),
+        number.floor_decimals(2),
+        number.round_zeros(1),
+        number.ceil_zeros(2),
+        number.round_sf(2),
+    ]
+}
+
+#[test]
+fn test_single_bound() {
+    assert_eq!(
+        round_all(1_234.567_f64),
+        [1_234.6, 1_235.0, 1_234.56, 1_230.0, 1_300.0, 1_200.0]
+    );
+    assert_eq!(
+        round_all(-1_234_i32),
+        [-1_234, -1_234, -1_234, -1_230, -1_200, -1_200]
+    );
+    assert_eq!(
+        round_all(1_250_u64),
+        [1_250, 1_250, 1_250, 1_250, 1_300, 1_300]
+    );
+    assert_eq!(round(1_234.567_f64, 1), 1_234.6);
+
+    // The decimal place methods of `Float` keep taking the number by value and can be called
+    // through a path with both traits in scope
+    assert_eq!(Float::round_dp(1_234.567_f64, 1), 1_234.6);
+    assert_eq!(f64::ceil_dp(1_234.567, 0), 1_235.0);
+    assert_eq!(<f32 as Float>::floor_dp(1_234.567, 2), 1_234.56);
+    let round_dp: fn(f64, u32) -> f64 = Float::round_dp;
+    assert_eq!(round_dp(2.675, 2), 2.68);
+    assert_eq!(1.005_f64.round_dp(2), 1.005_f64.round_decimals(2));
+}
+
+/// Calls the decimal place methods the way code written before `Roundable` had them does, with
+/// only `Float` imported
+#[cfg(test)]
+mod float_only {
+    use crate::rounding::Float;
+
+    #[test]
+    fn test_float_import() {
+        assert_eq!(123.456_f64.round_dp(2), 123.46);
+        assert_eq!(123.454_f64.ceil_dp(2), 123.46);
+        assert_eq!(123.456_f64.floor_dp(2), 123.45);
+        assert_eq!(123.456_f32.round_dp(2), 123.46);
+        assert_eq!((-0.25_f32).ceil_dp(0), -0.0);
+
+        let values = [1.04_f64, 2.06];
+        let rounded: Vec<f64> = values.iter().map(|value| value.round_dp(1)).collect();
+        assert_eq!(rounded, [1.0, 2.1]);
+        let rounded: Vec<f64> = values.into_iter().map(|value| value.floor_dp(1)).collect();
+        assert_eq!(rounded, [1.0, 2.0]);
+
+        fn generic<T: Float + Copy>(number: T) -> [T; 3] {
+            [number.round_dp(1), number.ceil_dp(1), number.floor_dp(1)]
+        }
+        assert_eq!(generic(1.25_f64), [1.3, 1.3, 1.2]);
+    }
+}
@@ -0,0 +1,106 @@
+use super::{multiple, RoundingMode};
+
+/// Rounds a price to the nearest multiple of `tick`, ties go away from zero
+///
+/// The price is divided on the decimal digits of the tick, so float drift doesn't move a price
+/// that is already on a tick, even when the float quotient is just off an integer
+///
+/// # Panics
+///
+/// Panics if `tick` isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::round_to_tick;
+///
+/// assert_eq!(round_to_tick(10.07, 0.05), 10.05);
+/// assert_eq!(round_to_tick(1.23456, 0.0001), 1.2346);
+/// assert_eq!(round_to_tick(-0.125, 0.05), -0.15);
+/// ```
+#[inline]
+pub fn round_to_tick(price: f64, tick: f64) -> f64 {
+    to_tick(price, tick, RoundingMode::HalfAwayFromZero)
+}
+
+/// Rounds a price up to a multiple of `tick`, such as the price of an ask
+///
+/// A price that is already on a tick is returned as is
+///
+/// # Panics
+///
+/// Panics if `tick` isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::ceil_to_tick;
+///
+/// assert_eq!(ceil_to_tick(10.01, 0.05), 10.05);
+/// assert_eq!(ceil_to_tick(10.05, 0.05), 10.05);
+/// assert_eq!(ceil_to_tick(-0.07, 0.05), -0.05);
+/// ```
+#[inline]
+pub fn ceil_to_tick(price: f64, tick: f64) -> f64 {
+    to_tick(price, tick, RoundingMode::Up)
+}
+
+/// Rounds a price down to a multiple of `tick`, such as the price of a bid
+///
+/// A price that is already on a tick is returned as is, so `10.05` stays on the `0.05` tick
+/// even though `10.05 / 0.05` is just below `201` as a float
+///
+/// # Panics
+///
+/// Panics if `tick` isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::floor_to_tick;
+///
+/// assert_eq!(floor_to_tick(10.05, 0.05), 10.05);
+/// assert_eq!(floor_to_tick(10.09, 0.05), 10.05);
+/// assert_eq!(floor_to_tick(-0.07, 0.05), -0.1);
+/// ```
+#[inline]
+pub fn floor_to_tick(price: f64, tick: f64) -> f64 {
+    to_tick(price, tick, RoundingMode::Down)
+}
+
+/// Whether a price is a multiple of `tick`, `false` if the price isn't finite
+///
+/// A price counts as on a tick when it is the closest float to a multiple of the tick
+///
+/// # Panics
+///
+/// Panics if `tick` isn't positive and finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::is_on_tick;
+///
+/// assert!(is_on_tick(10.05, 0.05));
+/// assert!(is_on_tick(0.3, 0.1));
+/// assert!(!is_on_tick(10.07, 0.05));
+/// ```
+#[inline]
+pub fn is_on_tick(price: f64, tick: f64) -> bool {
+    price.is_finite() && round_to_tick(price, tick) == price
+}
+
+#[inline]
+fn to_tick(price: f64, tick: f64, mode: RoundingMode) -> f64 {
+    assert!(
+        multiple::is_valid_step_f64(tick),
+        "tick must be positive and finite"
+    );
+    if !price.is_finite() {
+        return price;
+    }
+
+    multiple::multiple_f64(price, tick, mode)
+}
+
+mod tests;
@@ -0,0 +1,97 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_five_cent_tick() {
+    // 10.05 / 0.05 is 200.99999999999997 as a float
+    assert_eq!(floor_to_tick(10.05, 0.05), 10.05);
+    assert_eq!(ceil_to_tick(10.05, 0.05), 10.05);
+    assert_eq!(round_to_tick(10.05, 0.05), 10.05);
+    assert!(is_on_tick(10.05, 0.05));
+
+    assert_eq!(floor_to_tick(10.0999, 0.05), 10.05);
+    assert_eq!(ceil_to_tick(10.0001, 0.05), 10.05);
+    assert_eq!(round_to_tick(10.074, 0.05), 10.05);
+    assert_eq!(round_to_tick(10.076, 0.05), 10.1);
+
+    for cents in 0..100_000 {
+        let price = format!("{}e-2", cents * 5).parse::<f64>().unwrap();
+        assert_eq!(floor_to_tick(price, 0.05), price);
+        assert_eq!(ceil_to_tick(price, 0.05), price);
+        assert!(is_on_tick(price, 0.05));
+        assert!(!is_on_tick(price + 0.01, 0.05));
+    }
+}
+
+#[test]
+fn test_basis_point_tick() {
+    assert_eq!(floor_to_tick(1.0003, 0.0001), 1.0003);
+    assert_eq!(ceil_to_tick(1.0003, 0.0001), 1.0003);
+    assert_eq!(floor_to_tick(0.0007, 0.0001), 0.0007);
+    assert_eq!(floor_to_tick(1.23456, 0.0001), 1.2345);
+    assert_eq!(ceil_to_tick(1.23451, 0.0001), 1.2346);
+    assert!(is_on_tick(1.1111, 0.0001));
+    assert!(!is_on_tick(1.11111, 0.0001));
+
+    for pips in 0..100_000 {
+        let price = format!("{pips}e-4").parse::<f64>().unwrap();
+        assert_eq!(floor_to_tick(price, 0.0001), price);
+        assert_eq!(ceil_to_tick(price, 0.0001), price);
+    }
+}
+
+#[test]
+fn test_large_prices() {
+    assert_eq!(floor_to_tick(123_456_789.05, 0.05), 123_456_789.05);
+    assert_eq!(ceil_to_tick(123_456_789.06, 0.05), 123_456_789.1);
+    assert_eq!(floor_to_tick(9876543.2101, 0.0001), 9876543.2101);
+    assert_eq!(round_to_tick(1e15, 0.05), 1e15);
+    assert!(is_on_tick(1e20, 0.05));
+}
+
+#[test]
+fn test_negative_prices() {
+    assert_eq!(floor_to_tick(-10.05, 0.05), -10.05);
+    assert_eq!(ceil_to_tick(-10.05, 0.05), -10.05);
+    assert_eq!(floor_to_tick(-10.06, 0.05), -10.1);
+    assert_eq!(ceil_to_tick(-10.06, 0.05), -10.05);
+    assert_eq!(round_to_tick(-0.025, 0.05), -0.05);
+    assert_eq!(ceil_to_tick(-0.01, 0.05), 0.0);
+    assert!(is_on_tick(-0.0003, 0.0001));
+}
+
+#[test]
+fn test_brackets_price() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let ticks = [0.01, 0.05, 0.25, 0.0001, 0.0005, 1.0, 12.5];
+    for _ in 0..20_000 {
+        let tick = ticks[(next() % ticks.len() as u64) as usize];
+        let price = (next() % 2_000_000) as f64 / 1000.0 - 1000.0;
+        let below = floor_to_tick(price, tick);
+        let above = ceil_to_tick(price, tick);
+        assert!(below <= price && price <= above, "{price} {tick}");
+        assert!(is_on_tick(below, tick) && is_on_tick(above, tick));
+        let rounded = round_to_tick(price, tick);
+        assert!(rounded == below || rounded == above);
+    }
+}
+
+#[test]
+fn test_non_finite() {
+    assert!(floor_to_tick(f64::NAN, 0.05).is_nan());
+    assert_eq!(ceil_to_tick(f64::INFINITY, 0.05), f64::INFINITY);
+    assert!(!is_on_tick(f64::NAN, 0.05));
+    assert!(!is_on_tick(f64::INFINITY, 0.05));
+}
+
+#[test]
+#[should_panic(expected = "tick must be positive and finite")]
+fn test_zero_tick() {
+    round_to_tick(10.05, 0.0);
+}
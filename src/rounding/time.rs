@@ -0,0 +1,207 @@
+use core::cmp::Ordering;
+
+#[cfg(not(feature = "std"))]
+use super::math::FloatMath;
+use super::RoundingMode;
+
+/// The number of seconds in a day
+pub const SECONDS_PER_DAY: u32 = 86_400;
+
+/// The result of rounding a time of day
+///
+/// Rounding late in the day can land exactly on midnight, in which case `seconds` wraps
+/// back around to zero and `day_carry` is set so the caller can move on to the next day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeOfDayRounding<T = u32> {
+    /// The rounded time as seconds since midnight, always less than a whole day
+    pub seconds: T,
+    /// Whether rounding carried the time over midnight into the next day
+    pub day_carry: bool,
+}
+
+/// Rounds a time of day, given as seconds since midnight, to a multiple of `step_seconds`
+///
+/// The grid is anchored at midnight, so the times that can be returned are the multiples of
+/// `step_seconds` plus midnight itself. When `step_seconds` doesn't divide a day evenly the last
+/// interval of the day is shorter than the others and ends at midnight, for example a 7 minute step
+/// gives 23:55 followed by 00:00 the next day
+///
+/// # Panics
+///
+/// Panics if `step_seconds` is zero or `seconds_since_midnight` is not less than a day
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_time_of_day, RoundingMode, TimeOfDayRounding};
+///
+/// // 23:59:40 to the nearest minute is midnight the next day
+/// let rounded = round_time_of_day(86_380, 60, RoundingMode::HalfAwayFromZero);
+/// assert_eq!(rounded, TimeOfDayRounding { seconds: 0, day_carry: true });
+///
+/// // 12:29:30 to the nearest minute with ties going down
+/// let rounded = round_time_of_day(44_970, 60, RoundingMode::HalfDown);
+/// assert_eq!(rounded, TimeOfDayRounding { seconds: 44_940, day_carry: false });
+/// ```
+pub fn round_time_of_day(
+    seconds_since_midnight: u32,
+    step_seconds: u32,
+    mode: RoundingMode,
+) -> TimeOfDayRounding {
+    assert!(step_seconds > 0, "step_seconds must be greater than zero");
+    assert!(
+        seconds_since_midnight < SECONDS_PER_DAY,
+        "seconds_since_midnight must be less than a day"
+    );
+
+    let index = seconds_since_midnight / step_seconds;
+    let lower = index * step_seconds;
+    let upper = (lower + step_seconds.min(SECONDS_PER_DAY)).min(SECONDS_PER_DAY);
+    let discarded = seconds_since_midnight - lower;
+    let remaining = upper - seconds_since_midnight;
+
+    let away = mode.rounds_away(
+        false,
+        index % 2 == 1,
+        discarded == 0,
+        discarded.cmp(&remaining),
+    );
+    let rounded = if away { upper } else { lower };
+
+    TimeOfDayRounding {
+        seconds: rounded % SECONDS_PER_DAY,
+        day_carry: rounded == SECONDS_PER_DAY,
+    }
+}
+
+/// Rounds a time of day up to the next multiple of `step_seconds`
+///
+/// See [`round_time_of_day`] for how the grid is laid out
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{ceil_time_of_day, TimeOfDayRounding};
+///
+/// assert_eq!(ceil_time_of_day(86_399, 60), TimeOfDayRounding { seconds: 0, day_carry: true });
+/// assert_eq!(ceil_time_of_day(61, 60), TimeOfDayRounding { seconds: 120, day_carry: false });
+/// ```
+#[inline]
+pub fn ceil_time_of_day(seconds_since_midnight: u32, step_seconds: u32) -> TimeOfDayRounding {
+    round_time_of_day(seconds_since_midnight, step_seconds, RoundingMode::Up)
+}
+
+/// Rounds a time of day down to the previous multiple of `step_seconds`
+///
+/// See [`round_time_of_day`] for how the grid is laid out
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{floor_time_of_day, TimeOfDayRounding};
+///
+/// assert_eq!(floor_time_of_day(86_399, 60), TimeOfDayRounding { seconds: 86_340, day_carry: false });
+/// assert_eq!(floor_time_of_day(59, 60), TimeOfDayRounding { seconds: 0, day_carry: false });
+/// ```
+#[inline]
+pub fn floor_time_of_day(seconds_since_midnight: u32, step_seconds: u32) -> TimeOfDayRounding {
+    round_time_of_day(seconds_since_midnight, step_seconds, RoundingMode::Down)
+}
+
+/// Rounds a time of day with fractional seconds to a multiple of `step_seconds`
+///
+/// Uses the same midnight anchored grid as [`round_time_of_day`]
+///
+/// # Panics
+///
+/// Panics if `step_seconds` is not a positive finite number or `seconds_since_midnight`
+/// is not in the range `0.0..86400.0`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{round_time_of_day_f64, RoundingMode, TimeOfDayRounding};
+///
+/// let rounded = round_time_of_day_f64(86_399.5, 1.0, RoundingMode::HalfAwayFromZero);
+/// assert_eq!(rounded, TimeOfDayRounding { seconds: 0.0, day_carry: true });
+///
+/// let rounded = round_time_of_day_f64(12.25, 0.5, RoundingMode::HalfEven);
+/// assert_eq!(rounded, TimeOfDayRounding { seconds: 12.0, day_carry: false });
+/// ```
+pub fn round_time_of_day_f64(
+    seconds_since_midnight: f64,
+    step_seconds: f64,
+    mode: RoundingMode,
+) -> TimeOfDayRounding<f64> {
+    let day = SECONDS_PER_DAY as f64;
+    assert!(
+        step_seconds > 0.0 && step_seconds.is_finite(),
+        "step_seconds must be a positive finite number"
+    );
+    assert!(
+        (0.0..day).contains(&seconds_since_midnight),
+        "seconds_since_midnight must be within a day"
+    );
+
+    let index = (seconds_since_midnight / step_seconds).floor();
+    let lower = (index * step_seconds).min(seconds_since_midnight);
+    let upper = (lower + step_seconds).min(day);
+    let discarded = seconds_since_midnight - lower;
+    let remaining = upper - seconds_since_midnight;
+
+    let away = mode.rounds_away(
+        false,
+        index % 2.0 == 1.0,
+        discarded == 0.0,
+        discarded.partial_cmp(&remaining).unwrap_or(Ordering::Equal),
+    );
+    let rounded = if away { upper } else { lower };
+
+    if rounded >= day {
+        TimeOfDayRounding {
+            seconds: 0.0,
+            day_carry: true,
+        }
+    } else {
+        TimeOfDayRounding {
+            seconds: rounded,
+            day_carry: false,
+        }
+    }
+}
+
+/// Rounds a time of day with fractional seconds up to the next multiple of `step_seconds`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{ceil_time_of_day_f64, TimeOfDayRounding};
+///
+/// assert_eq!(ceil_time_of_day_f64(86_399.001, 1.0), TimeOfDayRounding { seconds: 0.0, day_carry: true });
+/// ```
+#[inline]
+pub fn ceil_time_of_day_f64(
+    seconds_since_midnight: f64,
+    step_seconds: f64,
+) -> TimeOfDayRounding<f64> {
+    round_time_of_day_f64(seconds_since_midnight, step_seconds, RoundingMode::Up)
+}
+
+/// Rounds a time of day with fractional seconds down to the previous multiple of `step_seconds`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::rounding::{floor_time_of_day_f64, TimeOfDayRounding};
+///
+/// assert_eq!(floor_time_of_day_f64(59.999, 60.0), TimeOfDayRounding { seconds: 0.0, day_carry: false });
+/// ```
+#[inline]
+pub fn floor_time_of_day_f64(
+    seconds_since_midnight: f64,
+    step_seconds: f64,
+) -> TimeOfDayRounding<f64> {
+    round_time_of_day_f64(seconds_since_midnight, step_seconds, RoundingMode::Down)
+}
+
+mod tests;
@@ -0,0 +1,223 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_round_time_of_day_midnight() {
+    let next_day = TimeOfDayRounding {
+        seconds: 0,
+        day_carry: true,
+    };
+    let same_day = |seconds| TimeOfDayRounding {
+        seconds,
+        day_carry: false,
+    };
+
+    assert_eq!(
+        round_time_of_day(86_380, 60, RoundingMode::HalfAwayFromZero),
+        next_day
+    );
+    assert_eq!(
+        round_time_of_day(86_369, 60, RoundingMode::HalfAwayFromZero),
+        same_day(86_340)
+    );
+    assert_eq!(
+        round_time_of_day(86_370, 60, RoundingMode::HalfDown),
+        same_day(86_340)
+    );
+    assert_eq!(
+        round_time_of_day(86_370, 60, RoundingMode::HalfUp),
+        next_day
+    );
+    assert_eq!(
+        round_time_of_day(20, 60, RoundingMode::HalfAwayFromZero),
+        same_day(0)
+    );
+    assert_eq!(round_time_of_day(0, 60, RoundingMode::Up), same_day(0));
+    assert_eq!(round_time_of_day(0, 60, RoundingMode::Down), same_day(0));
+}
+
+#[test]
+fn test_ceil_floor_time_of_day() {
+    assert_eq!(
+        ceil_time_of_day(86_341, 60),
+        TimeOfDayRounding {
+            seconds: 0,
+            day_carry: true
+        }
+    );
+    assert_eq!(
+        ceil_time_of_day(86_340, 60),
+        TimeOfDayRounding {
+            seconds: 86_340,
+            day_carry: false
+        }
+    );
+    assert_eq!(
+        floor_time_of_day(86_399, 3600),
+        TimeOfDayRounding {
+            seconds: 82_800,
+            day_carry: false
+        }
+    );
+    assert_eq!(
+        floor_time_of_day(1, 60),
+        TimeOfDayRounding {
+            seconds: 0,
+            day_carry: false
+        }
+    );
+    assert_eq!(
+        ceil_time_of_day(1, 60),
+        TimeOfDayRounding {
+            seconds: 60,
+            day_carry: false
+        }
+    );
+}
+
+#[test]
+fn test_round_time_of_day_noon_ties() {
+    // 12:30:00 to the nearest hour is exactly halfway between 12:00 and 13:00
+    let noon = 43_200;
+    let one = 46_800;
+    let tie = 45_000;
+    let same_day = |seconds| TimeOfDayRounding {
+        seconds,
+        day_carry: false,
+    };
+
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfAwayFromZero),
+        same_day(one)
+    );
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfUp),
+        same_day(one)
+    );
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfDown),
+        same_day(noon)
+    );
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfTowardZero),
+        same_day(noon)
+    );
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfEven),
+        same_day(noon)
+    );
+    assert_eq!(
+        round_time_of_day(tie, 3600, RoundingMode::HalfOdd),
+        same_day(one)
+    );
+    assert_eq!(
+        round_time_of_day(noon, 3600, RoundingMode::AwayFromZero),
+        same_day(noon)
+    );
+}
+
+#[test]
+fn test_round_time_of_day_uneven_step() {
+    // A 7 minute step doesn't divide a day, the last slot before midnight is 23:55
+    let step = 7 * 60;
+    let last = 86_100;
+    let same_day = |seconds| TimeOfDayRounding {
+        seconds,
+        day_carry: false,
+    };
+    let next_day = TimeOfDayRounding {
+        seconds: 0,
+        day_carry: true,
+    };
+
+    assert_eq!(last % step, 0);
+    assert_eq!(
+        round_time_of_day(last + 149, step, RoundingMode::HalfAwayFromZero),
+        same_day(last)
+    );
+    assert_eq!(
+        round_time_of_day(last + 150, step, RoundingMode::HalfAwayFromZero),
+        next_day
+    );
+    assert_eq!(
+        round_time_of_day(last + 150, step, RoundingMode::HalfDown),
+        same_day(last)
+    );
+    assert_eq!(ceil_time_of_day(last + 1, step), next_day);
+    assert_eq!(floor_time_of_day(86_399, step), same_day(last));
+    assert_eq!(
+        round_time_of_day(step + 209, step, RoundingMode::HalfAwayFromZero),
+        same_day(step)
+    );
+    assert_eq!(
+        round_time_of_day(step + 210, step, RoundingMode::HalfAwayFromZero),
+        same_day(2 * step)
+    );
+}
+
+#[test]
+fn test_round_time_of_day_large_step() {
+    let same_day = |seconds| TimeOfDayRounding {
+        seconds,
+        day_carry: false,
+    };
+
+    assert_eq!(
+        round_time_of_day(43_199, u32::MAX, RoundingMode::HalfAwayFromZero),
+        same_day(0)
+    );
+    assert_eq!(
+        ceil_time_of_day(1, u32::MAX),
+        TimeOfDayRounding {
+            seconds: 0,
+            day_carry: true
+        }
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_round_time_of_day_zero_step() {
+    round_time_of_day(100, 0, RoundingMode::HalfAwayFromZero);
+}
+
+#[test]
+#[should_panic]
+fn test_round_time_of_day_out_of_range() {
+    round_time_of_day(SECONDS_PER_DAY, 60, RoundingMode::HalfAwayFromZero);
+}
+
+#[test]
+fn test_round_time_of_day_f64() {
+    let same_day = |seconds| TimeOfDayRounding {
+        seconds,
+        day_carry: false,
+    };
+    let next_day = TimeOfDayRounding {
+        seconds: 0.0,
+        day_carry: true,
+    };
+
+    assert_eq!(
+        round_time_of_day_f64(86_379.9, 60.0, RoundingMode::HalfAwayFromZero),
+        next_day
+    );
+    assert_eq!(
+        round_time_of_day_f64(86_369.9, 60.0, RoundingMode::HalfAwayFromZero),
+        same_day(86_340.0)
+    );
+    assert_eq!(ceil_time_of_day_f64(86_399.5, 1.0), next_day);
+    assert_eq!(floor_time_of_day_f64(86_399.5, 1.0), same_day(86_399.0));
+    assert_eq!(
+        round_time_of_day_f64(45_000.0, 3600.0, RoundingMode::HalfEven),
+        same_day(43_200.0)
+    );
+    assert_eq!(
+        round_time_of_day_f64(86_250.0, 420.0, RoundingMode::HalfAwayFromZero),
+        next_day
+    );
+    assert_eq!(
+        round_time_of_day_f64(86_249.5, 420.0, RoundingMode::HalfAwayFromZero),
+        same_day(86_100.0)
+    );
+}
@@ -0,0 +1,277 @@
+//! [`Roundable`] for [`Wrapping`] integers, the one place rounding is allowed to overflow
+//!
+//! Rounding to zeros, significant figures, multiples, grid points and powers of ten wraps around
+//! the bounds of the type when the result doesn't fit, giving the exact result modulo `2^bits`
+//! like the arithmetic operators on [`Wrapping`]. So `ceil_zeros(Wrapping(250_u8), 2)` is `300`
+//! wrapped to `Wrapping(44)`. The `checked_*`, `saturating_*` and `try_*` methods keep their
+//! meaning and report or clamp an overflow like they do for the inner type
+
+use core::num::Wrapping;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use super::Notation;
+use super::{integer, Digits, Roundable, RoundingError, RoundingMode};
+
+macro_rules! impl_roundable_wrapping {
+    ($($t:ty),*) => {
+        $(
+            impl Roundable for Wrapping<$t> {
+                #[inline]
+                fn round_zeros_mode(self, zeros: u32, mode: RoundingMode) -> Self {
+                    Wrapping(integer::wrapping_round_zeros(self.0, zeros, mode))
+                }
+
+                #[inline]
+                fn round_sf_mode(self, sig_figs: u32, mode: RoundingMode) -> Self {
+                    Wrapping(integer::wrapping_round_sf(self.0, sig_figs, mode))
+                }
+
+                #[inline]
+                fn saturating_round_zeros(self, zeros: u32) -> Self {
+                    Wrapping(self.0.saturating_round_zeros(zeros))
+                }
+
+                #[inline]
+                fn saturating_ceil_zeros(self, zeros: u32) -> Self {
+                    Wrapping(self.0.saturating_ceil_zeros(zeros))
+                }
+
+                #[inline]
+                fn saturating_floor_zeros(self, zeros: u32) -> Self {
+                    Wrapping(self.0.saturating_floor_zeros(zeros))
+                }
+
+                #[inline]
+                fn saturating_round_sf(self, sig_figs: u32) -> Self {
+                    Wrapping(self.0.saturating_round_sf(sig_figs))
+                }
+
+                #[inline]
+                fn saturating_ceil_sf(self, sig_figs: u32) -> Self {
+                    Wrapping(self.0.saturating_ceil_sf(sig_figs))
+                }
+
+                #[inline]
+                fn saturating_floor_sf(self, sig_figs: u32) -> Self {
+                    Wrapping(self.0.saturating_floor_sf(sig_figs))
+                }
+
+                #[inline]
+                fn round_zeros_stochastic<R: FnMut() -> f64>(self, zeros: u32, mut random: R) -> Self {
+                    // The chance of rounding up comes from the distances to the unwrapped results
+                    let fraction = integer::fraction_to_zeros(self.0, zeros);
+                    if fraction == 0.0 {
+                        self
+                    } else if random() < fraction {
+                        self.ceil_zeros(zeros)
+                    } else {
+                        self.floor_zeros(zeros)
+                    }
+                }
+
+                #[inline]
+                fn round_to_multiple(self, step: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_round_multiple(self.0, step.0, RoundingMode::HalfAwayFromZero))
+                }
+
+                #[inline]
+                fn ceil_to_multiple(self, step: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_round_multiple(self.0, step.0, RoundingMode::Up))
+                }
+
+                #[inline]
+                fn floor_to_multiple(self, step: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_round_multiple(self.0, step.0, RoundingMode::Down))
+                }
+
+                #[inline]
+                fn try_round_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+                    self.0.try_round_to_multiple(step.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn try_ceil_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+                    self.0.try_ceil_to_multiple(step.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn try_floor_to_multiple(self, step: Self) -> Result<Self, RoundingError> {
+                    self.0.try_floor_to_multiple(step.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn quantize(self, step: Self, offset: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_quantize(self.0, step.0, offset.0, RoundingMode::HalfAwayFromZero))
+                }
+
+                #[inline]
+                fn ceil_quantize(self, step: Self, offset: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_quantize(self.0, step.0, offset.0, RoundingMode::Up))
+                }
+
+                #[inline]
+                fn floor_quantize(self, step: Self, offset: Self) -> Self {
+                    assert!(integer::is_valid_step(step.0), "step must be positive");
+                    Wrapping(integer::wrapping_quantize(self.0, step.0, offset.0, RoundingMode::Down))
+                }
+
+                #[inline]
+                fn checked_quantize(self, step: Self, offset: Self) -> Option<Self> {
+                    self.0.checked_quantize(step.0, offset.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn checked_ceil_quantize(self, step: Self, offset: Self) -> Option<Self> {
+                    self.0.checked_ceil_quantize(step.0, offset.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn checked_floor_quantize(self, step: Self, offset: Self) -> Option<Self> {
+                    self.0.checked_floor_quantize(step.0, offset.0).map(Wrapping)
+                }
+
+                #[inline]
+                fn round_pow10(self) -> Self {
+                    Wrapping(integer::wrapping_power_of_ten(self.0, RoundingMode::HalfAwayFromZero))
+                }
+
+                #[inline]
+                fn ceil_pow10(self) -> Self {
+                    Wrapping(integer::wrapping_power_of_ten(self.0, RoundingMode::AwayFromZero))
+                }
+
+                #[inline]
+                fn floor_pow10(self) -> Self {
+                    Wrapping(integer::wrapping_power_of_ten(self.0, RoundingMode::TowardZero))
+                }
+
+                #[inline]
+                fn checked_round_pow10(self) -> Option<Self> {
+                    self.0.checked_round_pow10().map(Wrapping)
+                }
+
+                #[inline]
+                fn checked_ceil_pow10(self) -> Option<Self> {
+                    self.0.checked_ceil_pow10().map(Wrapping)
+                }
+
+                #[inline]
+                fn checked_floor_pow10(self) -> Option<Self> {
+                    self.0.checked_floor_pow10().map(Wrapping)
+                }
+
+                #[inline]
+                fn order_of_magnitude(self) -> Option<i32> {
+                    self.0.order_of_magnitude()
+                }
+
+                #[inline]
+                fn count_sig_figs(self) -> u32 {
+                    self.0.count_sig_figs()
+                }
+
+                #[inline]
+                fn digit_count(self) -> u32 {
+                    self.0.digit_count()
+                }
+
+                #[inline]
+                fn digits(self) -> Digits {
+                    self.0.digits()
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_sf(self, sig_figs: u32) -> String {
+                    self.0.format_sf(sig_figs)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_sf_notation(self, sig_figs: u32, notation: Notation) -> String {
+                    self.0.format_sf_notation(sig_figs, notation)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_grouped(
+                    self,
+                    decimal_places: u32,
+                    group_separator: char,
+                    decimal_separator: char,
+                ) -> String {
+                    self.0.format_grouped(decimal_places, group_separator, decimal_separator)
+                }
+
+                #[inline]
+                #[cfg(feature = "alloc")]
+                fn format_locale(
+                    self,
+                    decimal_places: u32,
+                    decimal_separator: char,
+                    group_separator: Option<char>,
+                ) -> String {
+                    self.0.format_locale(decimal_places, decimal_separator, group_separator)
+                }
+
+                #[inline]
+                fn round_to_exponent(self, exponent: i32, mode: RoundingMode) -> Option<Self> {
+                    self.0.round_to_exponent(exponent, mode).map(Wrapping)
+                }
+
+                #[inline]
+                fn get_digits(&self) -> i32 {
+                    self.0.get_digits()
+                }
+
+                #[inline]
+                fn to_decimal(&self) -> Option<(i128, i32)> {
+                    self.0.to_decimal()
+                }
+
+                #[inline]
+                fn from_decimal(mantissa: i128, exponent: i32) -> Option<Self> {
+                    <$t>::from_decimal(mantissa, exponent).map(Wrapping)
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn tie_at(self, decimal_places: i32) -> bool {
+                    self.0.tie_at(decimal_places)
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn rounding_error(self, rounded: Self) -> Self {
+                    self - rounded
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn round_slice_zeros_mode(values: &mut [Self], zeros: u32, mode: RoundingMode) {
+                    for value in values {
+                        *value = value.round_zeros_mode(zeros, mode);
+                    }
+                }
+
+                #[doc(hidden)]
+                #[inline]
+                fn nearer_above(self, below: Self, above: Self) -> bool {
+                    self.0.nearer_above(below.0, above.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_roundable_wrapping!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+mod tests;
@@ -0,0 +1,164 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+use crate::rounding::{ceil_zeros, round_sf};
+
+#[test]
+fn test_wraps_on_overflow_u8() {
+    assert_eq!(ceil_zeros(Wrapping(250_u8), 2), Wrapping(44));
+    assert_eq!(Wrapping(250_u8).round_zeros(1), Wrapping(250));
+    assert_eq!(Wrapping(255_u8).round_zeros(1), Wrapping(4));
+    assert_eq!(Wrapping(251_u8).ceil_sf(1), Wrapping(44));
+    assert_eq!(round_sf(Wrapping(251_u8), 2), Wrapping(250));
+    assert_eq!(Wrapping(255_u8).round_sf(2), Wrapping(4));
+    assert_eq!(Wrapping(1_u8).ceil_zeros(3), Wrapping(232));
+    assert_eq!(Wrapping(1_u8).ceil_zeros(8), Wrapping(0));
+    assert_eq!(Wrapping(1_u8).ceil_zeros(50), Wrapping(0));
+    assert_eq!(
+        Wrapping(200_u8).ceil_to_multiple(Wrapping(150)),
+        Wrapping(44)
+    );
+    assert_eq!(
+        Wrapping(250_u8).ceil_quantize(Wrapping(20), Wrapping(5)),
+        Wrapping(9)
+    );
+    assert_eq!(Wrapping(200_u8).ceil_pow10(), Wrapping(232));
+    assert_eq!(Wrapping(0_u8).ceil_pow10(), Wrapping(0));
+}
+
+#[test]
+fn test_wraps_on_overflow_i8() {
+    assert_eq!(Wrapping(125_i8).round_zeros(1), Wrapping(-126));
+    assert_eq!(Wrapping(-125_i8).round_zeros(1), Wrapping(126));
+    assert_eq!(Wrapping(-121_i8).floor_zeros(1), Wrapping(126));
+    assert_eq!(Wrapping(-128_i8).round_zeros(0), Wrapping(-128));
+    assert_eq!(Wrapping(-128_i8).round_zeros(1), Wrapping(126));
+    assert_eq!(Wrapping(-1_i8).floor_zeros(3), Wrapping(24));
+    assert_eq!(Wrapping(-1_i8).floor_zeros(40), Wrapping(0));
+    assert_eq!(Wrapping(100_i8).ceil_sf(1), Wrapping(100));
+    assert_eq!(Wrapping(101_i8).ceil_sf(1), Wrapping(-56));
+    assert_eq!(
+        Wrapping(-120_i8).floor_to_multiple(Wrapping(50)),
+        Wrapping(106)
+    );
+    assert_eq!(Wrapping(-60_i8).round_pow10(), Wrapping(-100));
+    assert_eq!(Wrapping(-60_i8).ceil_pow10(), Wrapping(-100));
+    assert_eq!(Wrapping(-110_i8).ceil_pow10(), Wrapping(24));
+}
+
+#[test]
+fn test_overflow_reporting_methods_keep_their_meaning() {
+    assert_eq!(Wrapping(250_u8).checked_ceil_zeros(2), None);
+    assert_eq!(Wrapping(250_u8).saturating_ceil_zeros(2), Wrapping(u8::MAX));
+    assert_eq!(
+        Wrapping(250_u8).try_ceil_zeros(2),
+        Err(RoundingError::Overflow)
+    );
+    assert_eq!(Wrapping(-125_i8).checked_round_zeros(1), None);
+    assert_eq!(
+        Wrapping(-125_i8).saturating_round_zeros(1),
+        Wrapping(i8::MIN)
+    );
+    assert_eq!(Wrapping(200_u8).checked_ceil_pow10(), None);
+    assert_eq!(Wrapping(120_u8).checked_round_zeros(1), Some(Wrapping(120)));
+}
+
+#[test]
+fn test_passes_through_without_overflow() {
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    for _ in 0..2_000 {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        // Small enough that rounding never overflows
+        let number = (state >> 40) as i64 - (1 << 23);
+        let places = (state % 8) as u32;
+
+        assert_eq!(
+            Wrapping(number).round_zeros(places).0,
+            number.round_zeros(places)
+        );
+        assert_eq!(
+            Wrapping(number).ceil_zeros(places).0,
+            number.ceil_zeros(places)
+        );
+        assert_eq!(
+            Wrapping(number).floor_zeros(places).0,
+            number.floor_zeros(places)
+        );
+        assert_eq!(Wrapping(number).trunc_sf(places).0, number.trunc_sf(places));
+        assert_eq!(
+            Wrapping(number).round_sf_even(places).0,
+            number.round_sf_even(places)
+        );
+        assert_eq!(
+            Wrapping(number as i32).away_sf(places).0,
+            (number as i32).away_sf(places)
+        );
+        assert_eq!(
+            Wrapping(number.unsigned_abs())
+                .round_zeros_half_odd(places)
+                .0,
+            number.unsigned_abs().round_zeros_half_odd(places)
+        );
+
+        let step = (state % 1000 + 1) as i64;
+        assert_eq!(
+            Wrapping(number).round_to_multiple(Wrapping(step)).0,
+            number.round_to_multiple(step)
+        );
+        assert_eq!(
+            Wrapping(number)
+                .floor_quantize(Wrapping(step), Wrapping(7))
+                .0,
+            number.floor_quantize(step, 7)
+        );
+        assert_eq!(Wrapping(number).round_pow10().0, number.round_pow10());
+        assert_eq!(Wrapping(number).count_sig_figs(), number.count_sig_figs());
+
+        let random = (state >> 11) as f64 / (1_u64 << 53) as f64;
+        assert_eq!(
+            Wrapping(number).round_zeros_stochastic(places, || random).0,
+            number.round_zeros_stochastic(places, || random)
+        );
+    }
+}
+
+#[test]
+fn test_stochastic_wraps() {
+    assert_eq!(
+        Wrapping(250_u8).round_zeros_stochastic(2, || 0.0),
+        Wrapping(44)
+    );
+    assert_eq!(
+        Wrapping(250_u8).round_zeros_stochastic(2, || 0.6),
+        Wrapping(200)
+    );
+    assert_eq!(
+        Wrapping(200_u8).round_zeros_stochastic(2, || panic!()),
+        Wrapping(200)
+    );
+    assert_eq!(
+        Wrapping(-125_i8).round_zeros_stochastic(1, || 0.49),
+        Wrapping(-120)
+    );
+    assert_eq!(
+        Wrapping(-125_i8).round_zeros_stochastic(1, || 0.5),
+        Wrapping(126)
+    );
+}
+
+#[test]
+fn test_format_matches_inner() {
+    assert_eq!(Wrapping(1234_u16).format_sf(2), 1234_u16.format_sf(2));
+    assert_eq!(Wrapping(-1234_i32).format_grouped(0, ',', '.'), "-1,234");
+    assert_eq!(Wrapping(250_u8).digit_count(), 3);
+}
+
+#[test]
+fn test_decimal_bridge() {
+    assert_eq!(Wrapping(-7_i8).to_decimal(), Some((-7, 0)));
+    assert_eq!(Wrapping::<u8>::from_decimal(250, 0), Some(Wrapping(250)));
+    assert_eq!(Wrapping::<u8>::from_decimal(300, 0), None);
+}
@@ -0,0 +1,164 @@
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use crate::rounding::math::FloatMath;
+use crate::rounding::{floor_nice, floor_to_multiple, round_nice};
+
+use super::summary::iqr_sorted;
+use super::{histogram, std_dev_population, Histogram};
+
+/// A rule for choosing the width of histogram bins from the data, used by [`auto_bins`]
+///
+/// Each rule gives the same width as the option of the same name for `bins` in NumPy's
+/// `histogram_bin_edges`, before it is rounded to a nice value
+///
+/// ```rust
+/// use common_math::statistics::{auto_bins, BinRule};
+///
+/// // The outlier widens the range and standard deviation, but not the interquartile range
+/// let data = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0, 50.0];
+/// assert_eq!(auto_bins(&data, BinRule::Sturges).bin_width(), 10.0);
+/// assert_eq!(auto_bins(&data, BinRule::Scott).bin_width(), 20.0);
+/// assert_eq!(auto_bins(&data, BinRule::FreedmanDiaconis).bin_width(), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BinRule {
+    /// The range of the data divided into `log2(n) + 1` bins, which suits small samples of data
+    /// that is roughly normal but gives too few bins for large ones
+    Sturges,
+    /// `3.49 σ / ∛n`, from the standard deviation `σ`, which suits data that is roughly normal
+    Scott,
+    /// `2 IQR / ∛n`, from the interquartile range, which is robust to outliers. Data with more
+    /// than half its values equal has no interquartile range and falls back to [`BinRule::Scott`]
+    #[default]
+    FreedmanDiaconis,
+}
+
+/// The width and origin of histogram bins, chosen by [`auto_bins`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BinSpec {
+    bin_width: f64,
+    origin: f64,
+}
+
+impl BinSpec {
+    /// The width of each bin, a value in the 1, 2, 5 series
+    #[inline]
+    pub fn bin_width(&self) -> f64 {
+        self.bin_width
+    }
+
+    /// The lower edge of the first bin, usually a multiple of the bin width at or below the
+    /// smallest value
+    #[inline]
+    pub fn origin(&self) -> f64 {
+        self.origin
+    }
+}
+
+/// Chooses a width and origin for histogram bins of the data using the given rule
+///
+/// The width from the rule is rounded to the nearest value in the 1, 2, 5 series with
+/// [`round_nice`], and the origin is the multiple of the width at or below the smallest value, so
+/// every bin edge is a round number. NaN and infinities are left out when choosing, and when the
+/// multiple would be past the largest float the origin is the smallest value itself
+///
+/// Data that has fewer than two finite values, or has them all equal, has no spread to choose a
+/// width from, so it gets bins of width 1 starting at its smallest value rounded down to a whole
+/// number, or at zero if it has no finite values
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{auto_bins, BinRule};
+///
+/// let spec = auto_bins(&[0.13, 0.42, 0.58, 0.61, 0.97], BinRule::Sturges);
+/// assert_eq!((spec.bin_width(), spec.origin()), (0.2, 0.0));
+///
+/// let spec = auto_bins(&[7.5, 7.5, 7.5], BinRule::Scott);
+/// assert_eq!((spec.bin_width(), spec.origin()), (1.0, 7.0));
+/// ```
+pub fn auto_bins(data: &[f64], rule: BinRule) -> BinSpec {
+    let mut finite: Vec<f64> = data
+        .iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect();
+    finite.sort_unstable_by(f64::total_cmp);
+    let (min, max) = match (finite.first(), finite.last()) {
+        (Some(&min), Some(&max)) if min < max => (min, max),
+        (Some(&min), _) => {
+            return BinSpec {
+                bin_width: 1.0,
+                origin: min.floor(),
+            }
+        }
+        _ => {
+            return BinSpec {
+                bin_width: 1.0,
+                origin: 0.0,
+            }
+        }
+    };
+
+    let count = finite.len() as f64;
+    // Divided before subtracting so the range of the largest floats doesn't overflow
+    let sturges = || max / (count.log2() + 1.0) - min / (count.log2() + 1.0);
+    let scott = || {
+        std_dev_population(&finite).map_or(0.0, |std_dev| {
+            (24.0 * core::f64::consts::PI.sqrt() / count).cbrt() * std_dev
+        })
+    };
+    let width = match rule {
+        BinRule::Sturges => sturges(),
+        BinRule::Scott => scott(),
+        BinRule::FreedmanDiaconis => {
+            let iqr = iqr_sorted(&finite).unwrap_or(0.0);
+            match 2.0 * iqr / count.cbrt() {
+                0.0 => scott(),
+                width => width,
+            }
+        }
+    };
+    // A spread too large to hold as a float falls back to the range, which can always be held
+    let width = if width > 0.0 && width.is_finite() {
+        width
+    } else {
+        sturges()
+    };
+
+    let bin_width = match round_nice(width) {
+        nice if nice.is_finite() => nice,
+        _ => floor_nice(width),
+    };
+    // Rounding down the smallest of the largest floats can go past them
+    let origin = match floor_to_multiple(min, bin_width) {
+        origin if origin.is_finite() => origin,
+        _ => min,
+    };
+    BinSpec { bin_width, origin }
+}
+
+/// Counts the data into histogram bins with a width and origin chosen by [`auto_bins`]
+///
+/// # Panics
+///
+/// Panics if the data is spread over more than [`Histogram::MAX_BINS`] bins of the chosen width,
+/// which can happen with [`BinRule::FreedmanDiaconis`] when a few values are far from the rest
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{histogram_auto, BinRule};
+///
+/// let counted = histogram_auto(&[0.13, 0.42, 0.58, 0.61, 0.97], BinRule::Sturges);
+/// assert_eq!(counted.edges(), [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+/// assert_eq!(counted.counts(), [1, 0, 2, 1, 1]);
+/// ```
+#[inline]
+pub fn histogram_auto(data: &[f64], rule: BinRule) -> Histogram {
+    let spec = auto_bins(data, rule);
+    histogram(data, spec.bin_width, spec.origin)
+}
+
+mod tests;
@@ -0,0 +1,124 @@
+#[cfg(test)]
+use super::*;
+
+/// Sixty values drawn from a normal distribution with a mean of 10 and standard deviation of 2
+#[cfg(test)]
+const NORMAL: [f64; 60] = [
+    9.49, 11.02, 9.55, 9.37, 8.14, 9.57, 12.22, 10.85, 12.07, 10.5, 10.79, 10.37, 6.67, 11.71,
+    11.01, 11.0, 6.62, 6.51, 8.22, 9.06, 10.61, 9.91, 11.04, 8.72, 10.62, 10.79, 8.68, 13.44,
+    11.11, 12.39, 8.76, 8.52, 9.31, 9.79, 11.26, 10.5, 9.11, 8.09, 8.96, 12.44, 8.38, 10.49, 10.85,
+    7.02, 10.1, 12.61, 5.97, 9.36, 9.79, 8.37, 10.99, 9.88, 7.07, 11.66, 11.34, 11.89, 12.88,
+    10.72, 10.24, 7.4,
+];
+
+#[test]
+fn test_reference_counts() {
+    // Widths from numpy.histogram_bin_edges rounded to the 1, 2, 5 series: Sturges gives 1.08,
+    // Scott 1.52 and Freedman-Diaconis 1.16
+    let spec = auto_bins(&NORMAL, BinRule::Sturges);
+    assert_eq!((spec.bin_width(), spec.origin()), (1.0, 5.0));
+    assert_eq!(
+        histogram_auto(&NORMAL, BinRule::Sturges).counts(),
+        [1, 3, 3, 10, 12, 14, 10, 6, 1]
+    );
+
+    let spec = auto_bins(&NORMAL, BinRule::Scott);
+    assert_eq!((spec.bin_width(), spec.origin()), (2.0, 4.0));
+    assert_eq!(
+        histogram_auto(&NORMAL, BinRule::Scott).counts(),
+        [1, 6, 22, 24, 7]
+    );
+
+    let spec = auto_bins(&NORMAL, BinRule::FreedmanDiaconis);
+    assert_eq!((spec.bin_width(), spec.origin()), (1.0, 5.0));
+    assert_eq!(
+        histogram_auto(&NORMAL, BinRule::FreedmanDiaconis).counts(),
+        [1, 3, 3, 10, 12, 14, 10, 6, 1]
+    );
+
+    for rule in [BinRule::Sturges, BinRule::Scott, BinRule::FreedmanDiaconis] {
+        assert_eq!(histogram_auto(&NORMAL, rule).total(), 60);
+    }
+}
+
+#[test]
+fn test_nice_edges() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    for _ in 0..100 {
+        let (offset, scale) = (
+            next() * 2000.0 - 1000.0,
+            10_f64.powi((next() * 10.0) as i32 - 5),
+        );
+        let data: Vec<f64> = (0..50).map(|_| offset + next() * scale).collect();
+        for rule in [BinRule::Sturges, BinRule::Scott, BinRule::FreedmanDiaconis] {
+            let spec = auto_bins(&data, rule);
+            assert_eq!(round_nice(spec.bin_width()), spec.bin_width());
+            assert_eq!(
+                floor_to_multiple(spec.origin(), spec.bin_width()),
+                spec.origin()
+            );
+
+            // Every value is in a bin, the first starting at or below the smallest
+            let counted = histogram_auto(&data, rule);
+            assert_eq!(counted.total(), 50);
+            assert_eq!(counted.underflow(), 0);
+            assert_ne!(counted.counts()[0], 0);
+        }
+    }
+
+    let edges = histogram_auto(&[0.13, 0.42, 0.58, 0.61, 0.97], BinRule::Sturges).edges();
+    assert_eq!(edges, [0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+}
+
+#[test]
+fn test_degenerate_data() {
+    // No spread gives bins of width 1 starting at a whole number
+    for rule in [BinRule::Sturges, BinRule::Scott, BinRule::FreedmanDiaconis] {
+        let spec = auto_bins(&[7.5; 10], rule);
+        assert_eq!((spec.bin_width(), spec.origin()), (1.0, 7.0));
+        assert_eq!(histogram_auto(&[7.5; 10], rule).counts(), [10]);
+
+        let spec = auto_bins(&[-2.5], rule);
+        assert_eq!((spec.bin_width(), spec.origin()), (1.0, -3.0));
+
+        let spec = auto_bins(&[], rule);
+        assert_eq!((spec.bin_width(), spec.origin()), (1.0, 0.0));
+        assert!(histogram_auto(&[], rule).counts().is_empty());
+
+        let spec = auto_bins(&[f64::NAN, 4.0, f64::INFINITY], rule);
+        assert_eq!((spec.bin_width(), spec.origin()), (1.0, 4.0));
+        let counted = histogram_auto(&[f64::NAN, 4.0, f64::INFINITY], rule);
+        assert_eq!(
+            (counted.total(), counted.nan(), counted.overflow()),
+            (1, 1, 1)
+        );
+    }
+
+    // More than half the values equal has no interquartile range, so Scott's rule is used
+    let data = [1.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 9.0];
+    assert_eq!(
+        auto_bins(&data, BinRule::FreedmanDiaconis),
+        auto_bins(&data, BinRule::Scott)
+    );
+
+    // The widest range of floats doesn't overflow
+    for rule in [BinRule::Sturges, BinRule::Scott, BinRule::FreedmanDiaconis] {
+        let spec = auto_bins(&[-f64::MAX, 0.0, f64::MAX], rule);
+        assert!(spec.bin_width().is_finite() && spec.origin().is_finite());
+    }
+}
+
+#[test]
+#[should_panic(expected = "too many bins between the origin and the largest value")]
+fn test_histogram_auto_outlier_panics() {
+    // The interquartile range of the close values gives bins far too narrow for the outlier
+    let mut data: Vec<f64> = (0..100).map(|index| index as f64 * 1e-6).collect();
+    data.push(1e12);
+    histogram_auto(&data, BinRule::FreedmanDiaconis);
+}
@@ -0,0 +1,181 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::StatsError;
+
+/// The exponential moving average of the data, smoothing each value into the average so far with
+/// the smoothing factor `alpha`
+///
+/// The first value starts the average, then each value after moves it to
+/// `alpha * value + (1 - alpha) * average`, so an `alpha` near 1 follows the data closely and one
+/// near 0 smooths it heavily. There is one average for each value, the same as
+/// `ewm(alpha=alpha, adjust=False, ignore_na=True).mean()` in pandas. See [`EmaState`] to work the
+/// average out one value at a time
+///
+/// NaN in the data is skipped, leaving the average as it was, so the average given for it is the
+/// one before, or NaN if it is before any other value
+///
+/// # Errors
+///
+/// [`StatsError::InvalidAlpha`] if `alpha` isn't above 0 and at most 1
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{ema, StatsError};
+///
+/// assert_eq!(ema(&[10.0, 12.0, 11.0, 15.0], 0.5), Ok(vec![10.0, 11.0, 11.0, 13.0]));
+/// assert_eq!(ema(&[10.0, f64::NAN, 12.0], 0.5), Ok(vec![10.0, 10.0, 11.0]));
+/// assert_eq!(ema(&[10.0, 12.0], 1.0), Ok(vec![10.0, 12.0]));
+/// assert_eq!(ema(&[10.0, 12.0], 0.0), Err(StatsError::InvalidAlpha));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn ema(data: &[f64], alpha: f64) -> Result<Vec<f64>, StatsError> {
+    let mut state = EmaState::new(alpha)?;
+    Ok(data.iter().map(|&value| state.update(value)).collect())
+}
+
+/// The exponential moving average of the data like [`ema`], with the smoothing factor given by a
+/// span of values as `alpha = 2 / (span + 1)`
+///
+/// This is how pandas converts a `span` and how a number of periods is usually turned into a
+/// smoothing factor, so a span of 1 follows the data exactly and a span of 9 uses an `alpha`
+/// of 0.2
+///
+/// # Errors
+///
+/// [`StatsError::InvalidAlpha`] if `span` is below 1 or NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{ema_span, StatsError};
+///
+/// assert_eq!(ema_span(&[10.0, 12.0, 11.0, 15.0], 3.0), Ok(vec![10.0, 11.0, 11.0, 13.0]));
+/// assert_eq!(ema_span(&[10.0, 12.0], 0.5), Err(StatsError::InvalidAlpha));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn ema_span(data: &[f64], span: f64) -> Result<Vec<f64>, StatsError> {
+    ema(data, span_to_alpha(span)?)
+}
+
+/// Converts a span of values to a smoothing factor, checking it is at least 1
+#[inline]
+fn span_to_alpha(span: f64) -> Result<f64, StatsError> {
+    if span >= 1.0 {
+        Ok(2.0 / (span + 1.0))
+    } else {
+        Err(StatsError::InvalidAlpha)
+    }
+}
+
+/// An exponential moving average worked out one value at a time, for streams of data that
+/// aren't all held at once
+///
+/// Each update gives the same average as [`ema`] would for the values so far
+///
+/// ```rust
+/// use common_math::statistics::{EmaState, StatsError};
+///
+/// let mut state = EmaState::new(0.5)?;
+/// assert_eq!(state.value(), None);
+/// assert_eq!(state.update(10.0), 10.0);
+/// assert_eq!(state.update(12.0), 11.0);
+/// assert_eq!(state.update(f64::NAN), 11.0);
+/// assert_eq!(state.value(), Some(11.0));
+/// # Ok::<(), StatsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmaState {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl EmaState {
+    /// Starts an exponential moving average with the smoothing factor `alpha` and no values yet
+    ///
+    /// # Errors
+    ///
+    /// [`StatsError::InvalidAlpha`] if `alpha` isn't above 0 and at most 1
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::{EmaState, StatsError};
+    ///
+    /// assert_eq!(EmaState::new(0.25).map(|state| state.alpha()), Ok(0.25));
+    /// assert_eq!(EmaState::new(1.5), Err(StatsError::InvalidAlpha));
+    /// assert_eq!(EmaState::new(f64::NAN), Err(StatsError::InvalidAlpha));
+    /// ```
+    #[inline]
+    pub fn new(alpha: f64) -> Result<EmaState, StatsError> {
+        if alpha > 0.0 && alpha <= 1.0 {
+            Ok(EmaState { alpha, value: None })
+        } else {
+            Err(StatsError::InvalidAlpha)
+        }
+    }
+
+    /// Starts an exponential moving average with the smoothing factor given by a span of values
+    /// as `alpha = 2 / (span + 1)`, like [`ema_span`]
+    ///
+    /// # Errors
+    ///
+    /// [`StatsError::InvalidAlpha`] if `span` is below 1 or NaN
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::{EmaState, StatsError};
+    ///
+    /// assert_eq!(EmaState::from_span(9.0).map(|state| state.alpha()), Ok(0.2));
+    /// assert_eq!(EmaState::from_span(0.0), Err(StatsError::InvalidAlpha));
+    /// ```
+    #[inline]
+    pub fn from_span(span: f64) -> Result<EmaState, StatsError> {
+        EmaState::new(span_to_alpha(span)?)
+    }
+
+    /// Smooths a value into the average and gives the new average
+    ///
+    /// The first value that isn't NaN starts the average. NaN is skipped, leaving the average
+    /// as it was, so it gives the average before it or NaN if there isn't one yet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::{EmaState, StatsError};
+    ///
+    /// let mut state = EmaState::new(0.25)?;
+    /// assert!(state.update(f64::NAN).is_nan());
+    /// assert_eq!(state.update(8.0), 8.0);
+    /// assert_eq!(state.update(16.0), 10.0);
+    /// # Ok::<(), StatsError>(())
+    /// ```
+    #[inline]
+    pub fn update(&mut self, value: f64) -> f64 {
+        if !value.is_nan() {
+            self.value = Some(match self.value {
+                Some(average) => self.alpha * value + (1.0 - self.alpha) * average,
+                None => value,
+            });
+        }
+        self.value.unwrap_or(f64::NAN)
+    }
+
+    /// The average so far, `None` if no values other than NaN have been smoothed into it
+    #[inline]
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// The smoothing factor, the weight given to each new value
+    #[inline]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+}
+
+mod tests;
@@ -0,0 +1,113 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+const SERIES: [f64; 8] = [10.0, 12.0, 11.0, 15.0, 14.0, 13.0, 18.0, 17.0];
+
+#[cfg(test)]
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert!((actual - expected).abs() < 1e-12, "{actual} != {expected}");
+    }
+}
+
+#[test]
+fn test_reference_values() {
+    // From pandas Series(SERIES).ewm(..., adjust=False).mean()
+    assert_eq!(
+        ema(&SERIES, 0.5).unwrap(),
+        [10.0, 11.0, 11.0, 13.0, 13.5, 13.25, 15.625, 16.3125]
+    );
+    assert_close(
+        &ema(&SERIES, 0.3).unwrap(),
+        &[
+            10.0, 10.6, 10.72, 12.004, 12.6028, 12.72196, 14.305372, 15.1137604,
+        ],
+    );
+    assert_close(
+        &ema_span(&SERIES, 4.0).unwrap(),
+        &[
+            10.0, 10.8, 10.88, 12.528, 13.1168, 13.07008, 15.042048, 15.8252288,
+        ],
+    );
+    assert_eq!(ema_span(&SERIES, 3.0), ema(&SERIES, 0.5));
+
+    assert_eq!(ema(&SERIES, 1.0).unwrap(), SERIES);
+    assert_eq!(ema_span(&SERIES, 1.0).unwrap(), SERIES);
+    assert!(ema(&[], 0.5).unwrap().is_empty());
+}
+
+#[test]
+fn test_nan_skipped() {
+    // From pandas with ignore_na=True, which is the same as leaving the NaN out
+    assert_eq!(
+        ema(&[f64::NAN, 10.0, f64::NAN, 12.0, 11.0], 0.5).unwrap()[1..],
+        [10.0, 10.0, 11.0, 11.0]
+    );
+    assert!(ema(&[f64::NAN, 10.0], 0.5).unwrap()[0].is_nan());
+
+    // Infinities aren't skipped
+    assert_eq!(
+        ema(&[1.0, f64::INFINITY, 2.0], 0.5).unwrap(),
+        [1.0, f64::INFINITY, f64::INFINITY]
+    );
+}
+
+#[test]
+fn test_streaming_matches_batch() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let data: Vec<f64> = (0..500)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if state.is_multiple_of(17) {
+                f64::NAN
+            } else {
+                (state >> 11) as f64 / (1_u64 << 53) as f64 * 100.0 - 50.0
+            }
+        })
+        .collect();
+    for alpha in [0.01, 0.1, 0.5, 0.9, 1.0] {
+        let batch = ema(&data, alpha).unwrap();
+        let mut ema_state = EmaState::new(alpha).unwrap();
+        for (&value, &average) in data.iter().zip(&batch) {
+            let streamed = ema_state.update(value);
+            assert!(streamed == average || (streamed.is_nan() && average.is_nan()));
+            assert_eq!(
+                ema_state.value(),
+                Some(streamed).filter(|value| !value.is_nan())
+            );
+        }
+    }
+
+    let mut from_span = EmaState::from_span(4.0).unwrap();
+    let streamed: Vec<f64> = SERIES
+        .iter()
+        .map(|&value| from_span.update(value))
+        .collect();
+    assert_eq!(streamed, ema_span(&SERIES, 4.0).unwrap());
+}
+
+#[test]
+fn test_invalid_alpha() {
+    for alpha in [0.0, -0.5, 1.0 + f64::EPSILON, 2.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(ema(&SERIES, alpha), Err(StatsError::InvalidAlpha));
+        assert_eq!(EmaState::new(alpha), Err(StatsError::InvalidAlpha));
+    }
+    for span in [0.0, 0.999, -1.0, f64::NAN, f64::NEG_INFINITY] {
+        assert_eq!(ema_span(&SERIES, span), Err(StatsError::InvalidAlpha));
+        assert_eq!(EmaState::from_span(span), Err(StatsError::InvalidAlpha));
+    }
+
+    assert_eq!(
+        EmaState::new(f64::MIN_POSITIVE).unwrap().alpha(),
+        f64::MIN_POSITIVE
+    );
+    assert_eq!(EmaState::from_span(1.0).unwrap().alpha(), 1.0);
+    assert_eq!(
+        EmaState::from_span(f64::INFINITY),
+        Err(StatsError::InvalidAlpha)
+    );
+}
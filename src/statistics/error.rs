@@ -0,0 +1,68 @@
+use core::error::Error;
+use core::fmt;
+
+/// The reasons a statistic can fail to be taken of some values
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{weighted_mean, StatsError};
+///
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, 3.0]), Ok(1.75));
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), Err(StatsError::LengthMismatch));
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, -3.0]), Err(StatsError::InvalidWeights));
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[0.0, 0.0]), Err(StatsError::ZeroTotalWeight));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatsError {
+    /// There weren't the same number of weights as values
+    LengthMismatch,
+    /// A weight was negative or not finite
+    InvalidWeights,
+    /// The weights added up to zero, so there was nothing to take the statistic of
+    ZeroTotalWeight,
+    /// There were too few values, or too little weight, for the statistic to be defined
+    TooFewValues,
+    /// A quantile wasn't between 0 and 1, or a percentile wasn't between 0 and 100
+    InvalidQuantile,
+    /// The values contained NaN, which can't be put in order
+    NanValues,
+    /// The values were meant to be sorted in ascending order but weren't
+    UnsortedValues,
+    /// A moving window was zero long, or shorter than the number of values it needed to have
+    InvalidWindow,
+    /// A smoothing factor wasn't above 0 and at most 1, or a span was below 1
+    InvalidAlpha,
+    /// The values had no variance, as they were all equal
+    ZeroVariance,
+    /// A bin width wasn't positive and finite, or the origin of the bins wasn't finite
+    InvalidBins,
+    /// A value was too many bins from the origin to count
+    TooManyBins,
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            StatsError::LengthMismatch => "values and weights have different lengths",
+            StatsError::InvalidWeights => "weights must not be negative or non-finite",
+            StatsError::ZeroTotalWeight => "weights add up to zero",
+            StatsError::TooFewValues => "too few values for the statistic to be defined",
+            StatsError::InvalidQuantile => "quantile or percentile is out of range",
+            StatsError::NanValues => "values contain NaN, which can't be ordered",
+            StatsError::UnsortedValues => "values aren't sorted in ascending order",
+            StatsError::InvalidWindow => {
+                "window is zero or shorter than its minimum number of values"
+            }
+            StatsError::InvalidAlpha => "smoothing factor isn't above 0 and at most 1",
+            StatsError::ZeroVariance => "values are all equal",
+            StatsError::InvalidBins => "bin width isn't positive and finite or origin isn't finite",
+            StatsError::TooManyBins => "value is too many bins from the origin",
+        };
+        f.write_str(message)
+    }
+}
+
+impl Error for StatsError {}
+
+mod tests;
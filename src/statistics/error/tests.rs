@@ -0,0 +1,57 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_display() {
+    assert_eq!(
+        StatsError::LengthMismatch.to_string(),
+        "values and weights have different lengths"
+    );
+    assert_eq!(
+        StatsError::InvalidWeights.to_string(),
+        "weights must not be negative or non-finite"
+    );
+    assert_eq!(
+        StatsError::ZeroTotalWeight.to_string(),
+        "weights add up to zero"
+    );
+    assert_eq!(
+        StatsError::TooFewValues.to_string(),
+        "too few values for the statistic to be defined"
+    );
+    assert_eq!(
+        StatsError::InvalidQuantile.to_string(),
+        "quantile or percentile is out of range"
+    );
+    assert_eq!(
+        StatsError::NanValues.to_string(),
+        "values contain NaN, which can't be ordered"
+    );
+    assert_eq!(
+        StatsError::UnsortedValues.to_string(),
+        "values aren't sorted in ascending order"
+    );
+    assert_eq!(
+        StatsError::InvalidWindow.to_string(),
+        "window is zero or shorter than its minimum number of values"
+    );
+    assert_eq!(
+        StatsError::InvalidAlpha.to_string(),
+        "smoothing factor isn't above 0 and at most 1"
+    );
+    assert_eq!(StatsError::ZeroVariance.to_string(), "values are all equal");
+    assert_eq!(
+        StatsError::InvalidBins.to_string(),
+        "bin width isn't positive and finite or origin isn't finite"
+    );
+    assert_eq!(
+        StatsError::TooManyBins.to_string(),
+        "value is too many bins from the origin"
+    );
+}
+
+#[test]
+fn test_error_source() {
+    let error: Box<dyn Error> = Box::new(StatsError::ZeroTotalWeight);
+    assert!(error.source().is_none());
+}
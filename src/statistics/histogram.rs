@@ -0,0 +1,289 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+use super::StatsError;
+use crate::rounding::{bucket_bounds, try_bucket_index};
+
+/// Counts the data into bins of `bin_width` starting at `origin`, so bin `i` holds the values
+/// from `origin + i * bin_width` up to but not including `origin + (i + 1) * bin_width`
+///
+/// The bins run from the origin up to the one holding the largest value, so there is no upper
+/// edge for finite values to fall past. Each value is put in its bin by
+/// [`bucket_index`](crate::rounding::bucket_index), so a value that is the closest float to an
+/// edge always counts in the bin to its right. Values below the origin, including negative
+/// infinity, are counted as underflow, positive infinity as overflow, and NaN on its own, so no
+/// value is dropped
+///
+/// # Panics
+///
+/// Panics if `bin_width` isn't positive and finite, if `origin` isn't finite or if a value would
+/// need more than [`Histogram::MAX_BINS`] bins, see [`try_histogram`] to handle this instead
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::histogram;
+///
+/// let histogram = histogram(&[0.1, 0.3, 0.35, 0.5, -1.0, f64::NAN], 0.2, 0.0);
+/// assert_eq!(histogram.counts(), [1, 2, 1]);
+/// assert_eq!(histogram.edges(), [0.0, 0.2, 0.4, 0.6]);
+/// assert_eq!((histogram.underflow(), histogram.overflow(), histogram.nan()), (1, 0, 1));
+/// ```
+#[inline]
+pub fn histogram(data: &[f64], bin_width: f64, origin: f64) -> Histogram {
+    match try_histogram(data, bin_width, origin) {
+        Ok(histogram) => histogram,
+        Err(StatsError::InvalidBins) => {
+            panic!("bin width must be positive and finite and origin must be finite")
+        }
+        Err(_) => panic!("too many bins between the origin and the largest value"),
+    }
+}
+
+/// Counts the data into bins of `bin_width` starting at `origin`, like [`histogram`]
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidBins`] if `bin_width` isn't positive and finite or `origin` isn't finite
+/// - [`StatsError::TooManyBins`] if a value would need more than [`Histogram::MAX_BINS`] bins,
+///   so data far from the origin can't allocate without bound
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{try_histogram, StatsError};
+///
+/// assert_eq!(try_histogram(&[1.0, 2.5], 1.0, 0.0).map(|histogram| histogram.total()), Ok(2));
+/// assert_eq!(try_histogram(&[], 1.0, f64::NAN), Err(StatsError::InvalidBins));
+/// assert_eq!(try_histogram(&[1.0], 0.0, 0.0), Err(StatsError::InvalidBins));
+/// assert_eq!(try_histogram(&[1e300], 1.0, 0.0), Err(StatsError::TooManyBins));
+/// assert_eq!(try_histogram(&[1e13], 1.0, 0.0), Err(StatsError::TooManyBins));
+/// ```
+pub fn try_histogram(data: &[f64], bin_width: f64, origin: f64) -> Result<Histogram, StatsError> {
+    if bin_width <= 0.0 || !bin_width.is_finite() || !origin.is_finite() {
+        return Err(StatsError::InvalidBins);
+    }
+
+    let mut histogram = Histogram {
+        bin_width,
+        origin,
+        counts: Vec::new(),
+        underflow: 0,
+        overflow: 0,
+        nan: 0,
+    };
+    for &value in data {
+        if value.is_nan() {
+            histogram.nan += 1;
+        } else if value == f64::INFINITY {
+            histogram.overflow += 1;
+        } else if value < origin {
+            // Only the origin itself is on the lower edge of the first bin
+            histogram.underflow += 1;
+        } else {
+            let index = try_bucket_index(value, origin, bin_width)
+                .ok()
+                .and_then(|index| usize::try_from(index).ok())
+                .filter(|&index| index < Histogram::MAX_BINS)
+                .ok_or(StatsError::TooManyBins)?;
+            if index >= histogram.counts.len() {
+                histogram.counts.resize(index + 1, 0);
+            }
+            histogram.counts[index] += 1;
+        }
+    }
+    Ok(histogram)
+}
+
+/// The number of values in each bin of equal width, made by [`histogram`]
+///
+/// ```rust
+/// use common_math::statistics::histogram;
+///
+/// let histogram = histogram(&[1.0, 2.0, 2.5, 4.0], 1.0, 1.0);
+/// let bins: Vec<_> = histogram.bins().collect();
+/// assert_eq!(bins, [(1.0..2.0, 1), (2.0..3.0, 2), (3.0..4.0, 0), (4.0..5.0, 1)]);
+/// assert_eq!(histogram.normalize(), [0.25, 0.5, 0.0, 0.25]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    bin_width: f64,
+    origin: f64,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+    nan: u64,
+}
+
+impl Histogram {
+    /// The most bins a histogram can have, 2^24 of them taking 128 MiB of counts
+    pub const MAX_BINS: usize = 1 << 24;
+
+    /// The width of each bin
+    #[inline]
+    pub fn bin_width(&self) -> f64 {
+        self.bin_width
+    }
+
+    /// The lower edge of the first bin
+    #[inline]
+    pub fn origin(&self) -> f64 {
+        self.origin
+    }
+
+    /// The number of values in each bin, from the one starting at the origin to the one holding
+    /// the largest value
+    #[inline]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The edges of the bins, one more than the number of bins, so bin `i` runs from edge `i` up
+    /// to edge `i + 1`
+    ///
+    /// Each edge is the closest float to its decimal, like
+    /// [`bucket_bounds`](crate::rounding::bucket_bounds), so `0.3` rather than
+    /// `0.30000000000000004` for the third edge of bins of width `0.1`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::histogram;
+    ///
+    /// assert_eq!(histogram(&[0.0, 0.25], 0.1, 0.0).edges(), [0.0, 0.1, 0.2, 0.3]);
+    /// assert_eq!(histogram(&[], 0.1, 0.0).edges(), [0.0]);
+    /// ```
+    #[inline]
+    pub fn edges(&self) -> Vec<f64> {
+        (0..=self.counts.len())
+            .map(|index| self.edge(index))
+            .collect()
+    }
+
+    /// The bins in order, each with the range of values it holds and the number of them
+    #[inline]
+    pub fn bins(&self) -> Bins<'_> {
+        Bins {
+            histogram: self,
+            front: 0,
+            back: self.counts.len(),
+        }
+    }
+
+    /// The number of values below the origin, which aren't in any bin
+    #[inline]
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// The number of values that were positive infinity, which aren't in any bin
+    ///
+    /// The bins reach the largest finite value, so no finite value is counted here
+    #[inline]
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// The number of values that were NaN, which aren't in any bin
+    #[inline]
+    pub fn nan(&self) -> u64 {
+        self.nan
+    }
+
+    /// The number of values in the bins, leaving out the underflow, overflow and NaN
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// The density of values in each bin, its count divided by the total in the bins and by the bin
+    /// width, so the densities times the bin width add up to 1 like a probability density
+    ///
+    /// The underflow, overflow and NaN aren't counted in the total
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::histogram;
+    ///
+    /// let histogram = histogram(&[0.0, 0.5, 0.75, 1.0], 0.5, 0.0);
+    /// assert_eq!(histogram.normalize(), [0.5, 1.0, 0.5]);
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Vec<f64> {
+        // The last bin always has a value in it, so the total is only zero when there are no bins
+        let area = self.total() as f64 * self.bin_width;
+        self.counts
+            .iter()
+            .map(|&count| count as f64 / area)
+            .collect()
+    }
+
+    /// The lower edge of a bin, or the upper edge of the last bin for the index after it
+    #[inline]
+    fn edge(&self, index: usize) -> f64 {
+        bucket_bounds(index as i64, self.origin, self.bin_width).0
+    }
+}
+
+/// An iterator over the bins of a [`Histogram`], with the range of values each holds and the number
+/// of them, made by [`Histogram::bins`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Bins<'a> {
+    histogram: &'a Histogram,
+    front: usize,
+    back: usize,
+}
+
+impl Bins<'_> {
+    /// The range and count of a bin
+    #[inline]
+    fn bin(&self, index: usize) -> (Range<f64>, u64) {
+        let (start, end) = bucket_bounds(
+            index as i64,
+            self.histogram.origin,
+            self.histogram.bin_width,
+        );
+        (start..end, self.histogram.counts[index])
+    }
+}
+
+impl Iterator for Bins<'_> {
+    type Item = (Range<f64>, u64);
+
+    #[inline]
+    fn next(&mut self) -> Option<(Range<f64>, u64)> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(self.bin(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Bins<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(Range<f64>, u64)> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(self.bin(self.back))
+    }
+}
+
+impl ExactSizeIterator for Bins<'_> {}
+
+impl FusedIterator for Bins<'_> {}
+
+mod tests;
@@ -0,0 +1,195 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_histogram() {
+    let data = [1.0, 2.0, 2.5, 4.0, 4.75];
+    let counted = histogram(&data, 1.0, 1.0);
+    assert_eq!(counted.counts(), [1, 2, 0, 2]);
+    assert_eq!(counted.edges(), [1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(counted.total(), 5);
+    assert_eq!(counted.bin_width(), 1.0);
+    assert_eq!(counted.origin(), 1.0);
+    assert_eq!(
+        (counted.underflow(), counted.overflow(), counted.nan()),
+        (0, 0, 0)
+    );
+
+    let bins: Vec<_> = counted.bins().collect();
+    assert_eq!(
+        bins,
+        [(1.0..2.0, 1), (2.0..3.0, 2), (3.0..4.0, 0), (4.0..5.0, 2)]
+    );
+    assert_eq!(counted.bins().len(), 4);
+    assert_eq!(counted.bins().next_back(), Some((4.0..5.0, 2)));
+    assert_eq!(counted.bins().rev().nth(3), Some((1.0..2.0, 1)));
+
+    // The first bin starts at the origin even when the data starts well after it
+    assert_eq!(histogram(&[25.0], 10.0, 0.0).counts(), [0, 0, 1]);
+}
+
+#[test]
+fn test_values_on_edges() {
+    // Divided as floats 0.3 / 0.1 is just below 3, but 0.3 is on the edge so it is in bin 3
+    let counted = histogram(&[0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7], 0.1, 0.0);
+    assert_eq!(counted.counts(), [1; 8]);
+    assert_eq!(
+        counted.edges(),
+        [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]
+    );
+
+    // The floats either side of an edge land in the bins either side of it
+    let counted = histogram(&[0.3_f64.next_down(), 0.3, 0.3_f64.next_up()], 0.1, 0.0);
+    assert_eq!(counted.counts(), [0, 0, 1, 2]);
+
+    // Every value counts in the bin whose range holds it
+    let data: Vec<f64> = (0..200).map(|index| index as f64 / 20.0 - 3.0).collect();
+    let counted = histogram(&data, 0.25, -3.0);
+    assert!(counted.counts().iter().all(|&count| count == 5));
+    for (range, count) in counted.bins() {
+        let inside = data.iter().filter(|value| range.contains(value)).count();
+        assert_eq!(inside as u64, count, "{range:?}");
+    }
+}
+
+#[test]
+fn test_negative_data() {
+    let data = [-5.0, -4.5, -3.2, -0.1, -0.0, 0.0, 1.0];
+    let counted = histogram(&data, 2.0, -6.0);
+    assert_eq!(counted.counts(), [2, 1, 1, 3]);
+    assert_eq!(counted.edges(), [-6.0, -4.0, -2.0, 0.0, 2.0]);
+
+    let counted = histogram(&data, 1.5, -4.0);
+    assert_eq!(counted.underflow(), 2);
+    assert_eq!(counted.counts(), [1, 0, 3, 1]);
+    assert_eq!(counted.edges(), [-4.0, -2.5, -1.0, 0.5, 2.0]);
+}
+
+#[test]
+fn test_identical_data() {
+    let counted = histogram(&[3.7; 10], 0.5, 0.0);
+    assert_eq!(counted.counts(), [0, 0, 0, 0, 0, 0, 0, 10]);
+
+    let counted = histogram(&[3.7; 10], 0.5, 3.5);
+    assert_eq!(counted.counts(), [10]);
+    assert_eq!(counted.edges(), [3.5, 4.0]);
+    assert_eq!(counted.normalize(), [2.0]);
+}
+
+#[test]
+fn test_empty_and_non_finite() {
+    let counted = histogram(&[], 1.0, 0.0);
+    assert!(counted.counts().is_empty());
+    assert_eq!(counted.edges(), [0.0]);
+    assert_eq!(counted.bins().count(), 0);
+    assert!(counted.normalize().is_empty());
+
+    let data = [
+        f64::NAN,
+        1.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        -1.0,
+        f64::NAN,
+    ];
+    let counted = histogram(&data, 1.0, 0.0);
+    assert_eq!(counted.counts(), [0, 1]);
+    assert_eq!(counted.underflow(), 2);
+    assert_eq!(counted.overflow(), 1);
+    assert_eq!(counted.nan(), 2);
+    assert_eq!(counted.total(), 1);
+
+    // With nothing to put in the bins there are none
+    let counted = histogram(&[-1.0, f64::NAN], 1.0, 0.0);
+    assert!(counted.counts().is_empty());
+    assert!(counted.normalize().is_empty());
+    assert_eq!(counted.underflow(), 1);
+}
+
+#[test]
+fn test_normalize() {
+    let counted = histogram(&[0.0, 0.5, 0.75, 1.0], 0.5, 0.0);
+    assert_eq!(counted.normalize(), [0.5, 1.0, 0.5]);
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let data: Vec<f64> = (0..1000)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1_u64 << 53) as f64 * 7.0 - 2.0
+        })
+        .collect();
+    for bin_width in [0.1, 0.25, 0.3, 1.0, 3.0] {
+        let counted = histogram(&data, bin_width, -2.5);
+        let area: f64 = counted
+            .normalize()
+            .iter()
+            .map(|density| density * bin_width)
+            .sum();
+        assert!((area - 1.0).abs() < 1e-12, "{area}");
+    }
+
+    // Values outside the bins aren't part of the total
+    let counted = histogram(&[0.5, 1.5, -1.0, f64::NAN], 1.0, 0.0);
+    assert_eq!(counted.normalize(), [0.5, 0.5]);
+}
+
+#[test]
+fn test_errors() {
+    for bin_width in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert_eq!(
+            try_histogram(&[1.0], bin_width, 0.0),
+            Err(StatsError::InvalidBins)
+        );
+        assert_eq!(
+            try_histogram(&[], bin_width, 0.0),
+            Err(StatsError::InvalidBins)
+        );
+    }
+    assert_eq!(
+        try_histogram(&[1.0], 1.0, f64::NAN),
+        Err(StatsError::InvalidBins)
+    );
+    assert_eq!(
+        try_histogram(&[1.0], 1.0, f64::NEG_INFINITY),
+        Err(StatsError::InvalidBins)
+    );
+    assert_eq!(
+        try_histogram(&[1e300], 1.0, 0.0),
+        Err(StatsError::TooManyBins)
+    );
+
+    // A value far above the origin would need too many bins to allocate
+    assert_eq!(
+        try_histogram(&[0.5, 1e13], 1.0, 0.0),
+        Err(StatsError::TooManyBins)
+    );
+    let first_past = Histogram::MAX_BINS as f64;
+    assert_eq!(
+        try_histogram(&[first_past], 1.0, 0.0),
+        Err(StatsError::TooManyBins)
+    );
+    assert_eq!(
+        try_histogram(&[first_past * 0.5], 0.5, 0.0),
+        Err(StatsError::TooManyBins)
+    );
+    let counted = try_histogram(&[first_past - 1.0], 1024.0, 0.0).unwrap();
+    assert_eq!(counted.counts().len(), Histogram::MAX_BINS / 1024);
+
+    // Far below the origin is still underflow
+    let counted = try_histogram(&[-1e300], 1.0, 0.0).unwrap();
+    assert_eq!(counted.underflow(), 1);
+}
+
+#[test]
+#[should_panic(expected = "bin width must be positive and finite and origin must be finite")]
+fn test_invalid_width_panics() {
+    histogram(&[1.0], 0.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "too many bins between the origin and the largest value")]
+fn test_too_many_bins_panics() {
+    histogram(&[1e13], 1.0, 0.0);
+}
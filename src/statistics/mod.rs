@@ -0,0 +1,489 @@
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+use crate::rounding::math::FloatMath;
+
+#[cfg(feature = "alloc")]
+mod bins;
+mod ema;
+mod error;
+#[cfg(feature = "alloc")]
+mod histogram;
+mod moving;
+#[cfg(feature = "alloc")]
+mod outliers;
+mod quantile;
+mod regression;
+mod standardize;
+mod summary;
+
+#[cfg(feature = "alloc")]
+pub use bins::{auto_bins, histogram_auto, BinRule, BinSpec};
+pub use ema::EmaState;
+#[cfg(feature = "alloc")]
+pub use ema::{ema, ema_span};
+pub use error::StatsError;
+#[cfg(feature = "alloc")]
+pub use histogram::{histogram, try_histogram, Bins, Histogram};
+pub use moving::{moving_average, moving_average_centered, moving_average_partial, MovingAverage};
+#[cfg(feature = "alloc")]
+pub use outliers::{
+    is_outlier_iqr, is_outlier_zscore, outliers_iqr, outliers_tukey, outliers_zscore,
+};
+pub use quantile::{
+    percentile, percentile_sorted, percentile_with, quantile, quantile_sorted, quantile_with,
+    Interpolation,
+};
+pub use regression::{linear_regression, linear_regression_indexed, LinearFit};
+#[cfg(feature = "alloc")]
+pub use standardize::z_scores;
+pub use standardize::{from_z_score, standardize, z_score};
+pub use summary::{five_number_summary, iqr, FiveNumberSummary};
+
+/// A number that descriptive statistics can be taken of, implemented for the primitive integers
+/// up to 64 bits and the floats
+///
+/// Statistics that aren't one of the numbers, like the mean, are given as an `f64`. Integers are
+/// added up exactly, so a mean of large `i64` values never overflows
+pub trait Sample: Copy {
+    /// The number as an `f64`, rounded to the closest one if it has more digits than an `f64` holds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::Sample;
+    ///
+    /// assert_eq!(3_u8.to_f64(), 3.0);
+    /// assert_eq!(1.5_f32.to_f64(), 1.5);
+    /// ```
+    fn to_f64(self) -> f64;
+
+    /// Orders two numbers, using [`f64::total_cmp`] for floats so `-0.0` is below `0.0` and NaN
+    /// is above infinity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use common_math::statistics::Sample;
+    ///
+    /// assert_eq!(Sample::total_cmp(&2_i32, &3), Ordering::Less);
+    /// assert_eq!(Sample::total_cmp(&f64::NAN, &f64::INFINITY), Ordering::Greater);
+    /// ```
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    #[doc(hidden)]
+    fn mean_of(values: &[Self]) -> f64;
+
+    #[doc(hidden)]
+    fn midpoint_of(self, other: Self) -> f64;
+}
+
+macro_rules! impl_sample_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn total_cmp(&self, other: &$t) -> Ordering {
+                    self.cmp(other)
+                }
+
+                #[inline]
+                fn mean_of(values: &[$t]) -> f64 {
+                    // Every value fits in 64 bits, so there would have to be more than 2^63 of
+                    // them for the sum to overflow
+                    let sum: i128 = values.iter().map(|&value| value as i128).sum();
+                    let count = values.len() as i128;
+                    // Splitting off the whole part keeps the digits a large sum would lose
+                    let (whole, left) = (sum / count, sum % count);
+                    whole as f64 + left as f64 / count as f64
+                }
+
+                #[inline]
+                fn midpoint_of(self, other: $t) -> f64 {
+                    (self as i128 + other as i128) as f64 / 2.0
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+macro_rules! impl_sample_float {
+    ($($t:ty),*) => {
+        $(
+            impl Sample for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                #[inline]
+                fn total_cmp(&self, other: &$t) -> Ordering {
+                    <$t>::total_cmp(self, other)
+                }
+
+                #[inline]
+                fn mean_of(values: &[$t]) -> f64 {
+                    let count = values.len() as f64;
+                    let sum: f64 = values.iter().map(|&value| value as f64).sum();
+                    if sum.is_infinite() {
+                        // The sum of finite values can overflow where their mean doesn't
+                        values.iter().map(|&value| value as f64 / count).sum()
+                    } else {
+                        sum / count
+                    }
+                }
+
+                #[inline]
+                fn midpoint_of(self, other: $t) -> f64 {
+                    f64::midpoint(self as f64, other as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_float!(f32, f64);
+
+/// The arithmetic mean of the values, `None` if there are none
+///
+/// Integers are added up exactly, so the mean of large `i64` values doesn't overflow. Floats are
+/// added up as `f64`, and the mean is NaN if any of them is NaN
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::mean;
+///
+/// assert_eq!(mean(&[1, 2, 3, 4]), Some(2.5));
+/// assert_eq!(mean(&[i64::MAX, i64::MAX]), Some(i64::MAX as f64));
+/// assert_eq!(mean(&[0.5_f32, 1.5]), Some(1.0));
+/// assert_eq!(mean::<f64>(&[]), None);
+/// ```
+#[inline]
+pub fn mean<T: Sample>(values: &[T]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(T::mean_of(values))
+    }
+}
+
+/// The median of the values, `None` if there are none
+///
+/// With an even number of values it is the mean of the middle two. The values are partly
+/// reordered to find the middle, without allocating, and floats are ordered by
+/// [`total_cmp`](Sample::total_cmp) so NaN counts as larger than every other value
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::median;
+///
+/// assert_eq!(median(&mut [3, 1, 2]), Some(2.0));
+/// assert_eq!(median(&mut [4, 1, 3, 2]), Some(2.5));
+/// assert_eq!(median(&mut [2.5, -1.0, f64::NAN]), Some(2.5));
+/// assert_eq!(median::<u8>(&mut []), None);
+/// ```
+#[inline]
+pub fn median<T: Sample>(values: &mut [T]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let length = values.len();
+    let (below, &mut upper, _) = values.select_nth_unstable_by(length / 2, T::total_cmp);
+    if !length.is_multiple_of(2) {
+        return Some(upper.to_f64());
+    }
+
+    // The values below the middle one are all ordered before it, so the largest is next to it
+    let lower = *below
+        .iter()
+        .max_by(|a, b| a.total_cmp(b))
+        .expect("an even number of values has one below the middle");
+    Some(lower.midpoint_of(upper))
+}
+
+/// The most common of the values, `None` if there are none
+///
+/// When several values are equally common the smallest of them is returned. Floats are compared
+/// by [`total_cmp`](Sample::total_cmp), so `-0.0` and `0.0` are counted apart and NaN with the
+/// same bits are counted together
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::mode;
+///
+/// assert_eq!(mode(&[1, 3, 3, 2, 1, 3]), Some(3));
+/// assert_eq!(mode(&[5, 4, 4, 5]), Some(4));
+/// assert_eq!(mode(&[0.5, 1.5, 0.5]), Some(0.5));
+/// assert_eq!(mode::<i32>(&[]), None);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn mode<T: Sample>(values: &[T]) -> Option<T> {
+    let mut sorted: Vec<T> = values.to_vec();
+    sorted.sort_unstable_by(T::total_cmp);
+
+    // Only a strictly longer run replaces the best, so the first and smallest wins a tie
+    let mut best: Option<(T, usize)> = None;
+    for run in sorted.chunk_by(|a, b| a.total_cmp(b) == Ordering::Equal) {
+        if best.is_none_or(|(_, count)| run.len() > count) {
+            best = Some((run[0], run.len()));
+        }
+    }
+    best.map(|(value, _)| value)
+}
+
+/// The sample variance of the values, `None` if there are fewer than two
+///
+/// The squared deviations from the mean are divided by `n - 1`, Bessel's correction, which makes
+/// it an unbiased estimate of the variance of the population the values were sampled from. Use
+/// [`variance_population`] when the values are the whole population
+///
+/// The deviations are taken from the mean in a second pass, so values with a large offset like
+/// `1e9 + 4.0` don't lose their digits the way `E[x²] - E[x]²` does
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::variance;
+///
+/// assert_eq!(variance(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(32.0 / 7.0));
+/// assert_eq!(variance(&[1_000_000_004_u64, 1_000_000_007, 1_000_000_013, 1_000_000_016]), Some(30.0));
+/// assert_eq!(variance(&[3.0]), None);
+/// ```
+#[inline]
+pub fn variance<T: Sample>(values: &[T]) -> Option<f64> {
+    if values.len() < 2 {
+        None
+    } else {
+        Some(squared_deviations(values) / (values.len() - 1) as f64)
+    }
+}
+
+/// The population variance of the values, `None` if there are none
+///
+/// The squared deviations from the mean are divided by `n`, which is the variance when the values
+/// are the whole population. It is `(n - 1) / n` times the sample [`variance`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::variance_population;
+///
+/// assert_eq!(variance_population(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(4.0));
+/// assert_eq!(variance_population(&[3.0]), Some(0.0));
+/// assert_eq!(variance_population::<i32>(&[]), None);
+/// ```
+#[inline]
+pub fn variance_population<T: Sample>(values: &[T]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(squared_deviations(values) / values.len() as f64)
+    }
+}
+
+/// The sample standard deviation of the values, the square root of their sample [`variance`],
+/// `None` if there are fewer than two
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::std_dev;
+///
+/// assert_eq!(std_dev(&[1, 3]), Some(2_f64.sqrt()));
+/// assert_eq!(std_dev(&[3]), None);
+/// ```
+#[inline]
+pub fn std_dev<T: Sample>(values: &[T]) -> Option<f64> {
+    variance(values).map(f64::sqrt)
+}
+
+/// The population standard deviation of the values, the square root of their
+/// [`variance_population`], `None` if there are none
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::std_dev_population;
+///
+/// assert_eq!(std_dev_population(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(2.0));
+/// assert_eq!(std_dev_population(&[1, 3]), Some(1.0));
+/// ```
+#[inline]
+pub fn std_dev_population<T: Sample>(values: &[T]) -> Option<f64> {
+    variance_population(values).map(f64::sqrt)
+}
+
+/// The sum of the squared deviations of some values from their mean
+///
+/// The deviations are added up on a second pass over the values, and the sum is corrected by
+/// the square of their own sum, which would be zero without the rounding error in the mean
+#[inline]
+fn squared_deviations<T: Sample>(values: &[T]) -> f64 {
+    let mean = T::mean_of(values);
+    let (sum, sum_squares) = values.iter().fold((0.0, 0.0), |(sum, sum_squares), value| {
+        let deviation = value.to_f64() - mean;
+        (sum + deviation, sum_squares + deviation * deviation)
+    });
+    let squares = sum_squares - sum * sum / values.len() as f64;
+    // Rounding error can leave the correction slightly larger than the sum of squares, and NaN is
+    // kept rather than clamped
+    if squares < 0.0 {
+        0.0
+    } else {
+        squares
+    }
+}
+
+/// The mean of the values with each one counted in proportion to its weight
+///
+/// A value with a weight of zero is left out, so the weights only have to be positive in total.
+/// The weights are normalised by their total, so scaling them all by the same factor gives the same
+/// mean. Values that aren't finite give a mean that isn't either
+///
+/// # Errors
+///
+/// - [`StatsError::LengthMismatch`] if there aren't the same number of weights as values
+/// - [`StatsError::InvalidWeights`] if a weight is negative or not finite
+/// - [`StatsError::ZeroTotalWeight`] if the weights add up to zero, including when there are none
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{weighted_mean, StatsError};
+///
+/// assert_eq!(weighted_mean(&[80.0, 90.0, 70.0], &[0.5, 0.25, 0.25]), Ok(80.0));
+/// assert_eq!(weighted_mean(&[1.0, 2.0, 100.0], &[1.0, 1.0, 0.0]), Ok(1.5));
+/// assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0]), Err(StatsError::LengthMismatch));
+/// assert_eq!(weighted_mean(&[], &[]), Err(StatsError::ZeroTotalWeight));
+/// ```
+#[inline]
+pub fn weighted_mean(values: &[f64], weights: &[f64]) -> Result<f64, StatsError> {
+    let total = total_weight(values, weights)?;
+    Ok(weighted_mean_of(values, weights, total))
+}
+
+/// The sample variance of the values with each one counted as many times as its weight, treating
+/// the weights as frequencies
+///
+/// The weighted squared deviations from the [`weighted_mean`] are divided by the total weight less
+/// one, so whole number weights give the same result as [`variance`] of the values repeated that
+/// many times. Like [`variance`], the deviations are taken on a second pass so a large offset
+/// doesn't lose their digits
+///
+/// # Errors
+///
+/// - [`StatsError::LengthMismatch`] if there aren't the same number of weights as values
+/// - [`StatsError::InvalidWeights`] if a weight is negative or not finite
+/// - [`StatsError::ZeroTotalWeight`] if the weights add up to zero, including when there are none
+/// - [`StatsError::TooFewValues`] if the weights add up to one or less, as a frequency weighted
+///   sample of one value has no variance
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{variance, weighted_variance, StatsError};
+///
+/// assert_eq!(weighted_variance(&[1.0, 4.0], &[2.0, 1.0]), Ok(3.0));
+/// assert_eq!(variance(&[1.0, 1.0, 4.0]), Some(3.0));
+/// assert_eq!(weighted_variance(&[1.0, 4.0], &[0.5, 0.5]), Err(StatsError::TooFewValues));
+/// ```
+#[inline]
+pub fn weighted_variance(values: &[f64], weights: &[f64]) -> Result<f64, StatsError> {
+    let total = total_weight(values, weights)?;
+    if total <= 1.0 {
+        return Err(StatsError::TooFewValues);
+    }
+
+    let mean = weighted_mean_of(values, weights, total);
+    let (sum, sum_squares) =
+        values
+            .iter()
+            .zip(weights)
+            .fold((0.0, 0.0), |(sum, sum_squares), (&value, &weight)| {
+                // A value left out by its weight mustn't turn the sums into NaN if it is infinite
+                if weight == 0.0 {
+                    return (sum, sum_squares);
+                }
+                let deviation = value - mean;
+                (
+                    sum + weight * deviation,
+                    sum_squares + weight * deviation * deviation,
+                )
+            });
+    let squares = sum_squares - sum * sum / total;
+    if squares < 0.0 {
+        Ok(0.0)
+    } else {
+        Ok(squares / (total - 1.0))
+    }
+}
+
+/// Checks the weights are valid for the values, giving their total
+#[inline]
+fn total_weight(values: &[f64], weights: &[f64]) -> Result<f64, StatsError> {
+    if values.len() != weights.len() {
+        return Err(StatsError::LengthMismatch);
+    } else if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return Err(StatsError::InvalidWeights);
+    }
+
+    let total: f64 = weights.iter().sum();
+    if total == 0.0 {
+        Err(StatsError::ZeroTotalWeight)
+    } else {
+        Ok(total)
+    }
+}
+
+/// The weighted mean of the values, given the total of the weights, which is positive
+#[inline]
+fn weighted_mean_of(values: &[f64], weights: &[f64], total: f64) -> f64 {
+    let weighted = |(&value, &weight): (&f64, &f64)| {
+        if weight == 0.0 {
+            0.0
+        } else {
+            value * weight
+        }
+    };
+    let sum: f64 = values.iter().zip(weights).map(weighted).sum();
+    if sum.is_finite() && total.is_finite() {
+        sum / total
+    } else {
+        // The total or the sum of finite values can overflow where the mean doesn't, so each value
+        // is weighted by its share of the total instead, scaling the weights down by the largest
+        // first if their total overflowed
+        let scale = if total.is_finite() {
+            1.0
+        } else {
+            weights
+                .iter()
+                .fold(0.0, |largest: f64, &weight| largest.max(weight))
+        };
+        let total: f64 = weights.iter().map(|weight| weight / scale).sum();
+        values
+            .iter()
+            .zip(weights)
+            .map(|(&value, &weight)| weighted((&value, &(weight / scale / total))))
+            .sum()
+    }
+}
+
+mod tests;
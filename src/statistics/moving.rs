@@ -0,0 +1,208 @@
+use core::iter::FusedIterator;
+
+use super::StatsError;
+
+/// Makes an iterator over the simple moving average of the data, the mean of each run of `window`
+/// values in turn
+///
+/// The first average is of the first `window` values and each one after moves along by one, so
+/// there are `window - 1` fewer averages than values, and none if the window is longer than the
+/// data. See [`moving_average_partial`] to also average the shorter windows at the start, and
+/// [`moving_average_centered`] to centre each window on a value
+///
+/// The sum of the window is kept up to date in constant time as it moves, and is added up afresh
+/// each time the window has moved along its own length, so rounding error doesn't build up over a
+/// long run of data. NaN in the data gives NaN for each window it is in
+///
+/// # Errors
+///
+/// [`StatsError::InvalidWindow`] if `window` is zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{moving_average, StatsError};
+///
+/// let averages: Vec<f64> = moving_average(&[1.0, 2.0, 6.0, 4.0, 8.0], 3)?.collect();
+/// assert_eq!(averages, [3.0, 4.0, 6.0]);
+/// assert_eq!(moving_average(&[1.0, 2.0], 3)?.count(), 0);
+/// assert!(matches!(moving_average(&[1.0], 0), Err(StatsError::InvalidWindow)));
+/// # Ok::<(), StatsError>(())
+/// ```
+#[inline]
+pub fn moving_average(data: &[f64], window: usize) -> Result<MovingAverage<'_>, StatsError> {
+    moving_average_partial(data, window, window)
+}
+
+/// Makes an iterator over the simple moving average of the data like [`moving_average`], also
+/// averaging the windows at the start that have at least `min_periods` values but aren't full
+///
+/// The first average is of the first `min_periods` values, and the windows grow by one value at
+/// a time until they are `window` long. With `min_periods` equal to `window` this is the same as
+/// [`moving_average`], and with `min_periods` of 1 there is an average for every value
+///
+/// # Errors
+///
+/// [`StatsError::InvalidWindow`] if `window` is zero, or `min_periods` is zero or more than
+/// `window`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{moving_average_partial, StatsError};
+///
+/// let averages: Vec<f64> = moving_average_partial(&[1.0, 2.0, 6.0, 4.0, 8.0], 3, 1)?.collect();
+/// assert_eq!(averages, [1.0, 1.5, 3.0, 4.0, 6.0]);
+/// let averages: Vec<f64> = moving_average_partial(&[1.0, 2.0], 3, 2)?.collect();
+/// assert_eq!(averages, [1.5]);
+/// # Ok::<(), StatsError>(())
+/// ```
+#[inline]
+pub fn moving_average_partial(
+    data: &[f64],
+    window: usize,
+    min_periods: usize,
+) -> Result<MovingAverage<'_>, StatsError> {
+    MovingAverage::new(data, window, window.saturating_sub(1), 0, min_periods)
+}
+
+/// Makes an iterator over the simple moving average of the data with each window centred on a
+/// value, averaging the windows at either end that have at least `min_periods` values but aren't
+/// full
+///
+/// The window for the value at index `i` runs from `i - (window - 1) / 2` to `i + window / 2`,
+/// so a window of even length has one more value after the centre than before it, like
+/// `rolling(window, center=True)` in pandas. The first average is for the first value whose
+/// window has at least `min_periods` values in the data, and the last for the last such value.
+/// With `min_periods` equal to `window` only full windows are averaged, and with `min_periods` of
+/// 1 there is an average for every value
+///
+/// # Errors
+///
+/// [`StatsError::InvalidWindow`] if `window` is zero, or `min_periods` is zero or more than
+/// `window`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{moving_average_centered, StatsError};
+///
+/// let data = [1.0, 2.0, 6.0, 4.0, 8.0];
+/// let averages: Vec<f64> = moving_average_centered(&data, 3, 3)?.collect();
+/// assert_eq!(averages, [3.0, 4.0, 6.0]);
+/// let averages: Vec<f64> = moving_average_centered(&data, 3, 1)?.collect();
+/// assert_eq!(averages, [1.5, 3.0, 4.0, 6.0, 6.0]);
+/// # Ok::<(), StatsError>(())
+/// ```
+#[inline]
+pub fn moving_average_centered(
+    data: &[f64],
+    window: usize,
+    min_periods: usize,
+) -> Result<MovingAverage<'_>, StatsError> {
+    MovingAverage::new(
+        data,
+        window,
+        window.saturating_sub(1) / 2,
+        window / 2,
+        min_periods,
+    )
+}
+
+/// An iterator over the simple moving average of some data, made by [`moving_average`],
+/// [`moving_average_partial`] or [`moving_average_centered`]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MovingAverage<'a> {
+    data: &'a [f64],
+    window: usize,
+    before: usize,
+    after: usize,
+    index: usize,
+    end: usize,
+    sum: f64,
+    start: usize,
+    stop: usize,
+    moves: usize,
+}
+
+impl<'a> MovingAverage<'a> {
+    /// Makes the iterator for windows that take `before` values before each index and `after`
+    /// values after it, as well as the value at the index
+    #[inline]
+    fn new(
+        data: &'a [f64],
+        window: usize,
+        before: usize,
+        after: usize,
+        min_periods: usize,
+    ) -> Result<MovingAverage<'a>, StatsError> {
+        if window == 0 || min_periods == 0 || min_periods > window {
+            return Err(StatsError::InvalidWindow);
+        }
+
+        // The windows get longer up to the first with enough values, then shorter after the last
+        let (index, end) = if min_periods > data.len() {
+            (0, 0)
+        } else {
+            (
+                (min_periods - 1).saturating_sub(after),
+                data.len().min(data.len() + before + 1 - min_periods),
+            )
+        };
+        Ok(MovingAverage {
+            data,
+            window,
+            before,
+            after,
+            index,
+            end,
+            sum: 0.0,
+            start: 0,
+            stop: 0,
+            // The first window is always added up afresh
+            moves: window,
+        })
+    }
+}
+
+impl Iterator for MovingAverage<'_> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<f64> {
+        if self.index == self.end {
+            return None;
+        }
+
+        let start = self.index.saturating_sub(self.before);
+        let stop = self.data.len().min(self.index + self.after + 1);
+        // An infinite or NaN value leaving the window would leave NaN behind in the sum
+        let leaving = &self.data[self.start..start.max(self.start)];
+        if self.moves >= self.window || leaving.iter().any(|value| !value.is_finite()) {
+            self.sum = self.data[start..stop].iter().sum();
+            self.moves = 0;
+        } else {
+            self.sum += self.data[self.stop..stop].iter().sum::<f64>();
+            self.sum -= leaving.iter().sum::<f64>();
+            self.moves += 1;
+        }
+
+        self.start = start;
+        self.stop = stop;
+        self.index += 1;
+        Some(self.sum / (stop - start) as f64)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MovingAverage<'_> {}
+
+impl FusedIterator for MovingAverage<'_> {}
+
+mod tests;
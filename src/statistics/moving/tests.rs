@@ -0,0 +1,175 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+fn collect(averages: Result<MovingAverage<'_>, StatsError>) -> Vec<f64> {
+    averages.unwrap().collect()
+}
+
+/// Averages each window by adding it up afresh
+#[cfg(test)]
+fn expected(data: &[f64], before: usize, after: usize, min_periods: usize) -> Vec<f64> {
+    (0..data.len())
+        .map(|index| &data[index.saturating_sub(before)..data.len().min(index + after + 1)])
+        .filter(|window| window.len() >= min_periods)
+        .map(|window| window.iter().sum::<f64>() / window.len() as f64)
+        .collect()
+}
+
+#[test]
+fn test_moving_average() {
+    let data = [1.0, 2.0, 6.0, 4.0, 8.0];
+    assert_eq!(collect(moving_average(&data, 1)), data);
+    assert_eq!(collect(moving_average(&data, 2)), [1.5, 4.0, 5.0, 6.0]);
+    assert_eq!(collect(moving_average(&data, 3)), [3.0, 4.0, 6.0]);
+    assert_eq!(collect(moving_average(&data, 4)), [3.25, 5.0]);
+    assert_eq!(collect(moving_average(&data, 5)), [4.2]);
+    assert!(collect(moving_average(&data, 6)).is_empty());
+    assert!(collect(moving_average(&[], 1)).is_empty());
+
+    let averages = moving_average(&data, 3).unwrap();
+    assert_eq!(averages.len(), 3);
+    assert_eq!(averages.skip(1).size_hint(), (2, Some(2)));
+}
+
+#[test]
+fn test_partial_windows() {
+    let data = [1.0, 2.0, 6.0, 4.0, 8.0];
+    assert_eq!(
+        collect(moving_average_partial(&data, 3, 1)),
+        [1.0, 1.5, 3.0, 4.0, 6.0]
+    );
+    assert_eq!(
+        collect(moving_average_partial(&data, 3, 2)),
+        [1.5, 3.0, 4.0, 6.0]
+    );
+    assert_eq!(
+        collect(moving_average_partial(&data, 3, 3)),
+        collect(moving_average(&data, 3))
+    );
+    assert_eq!(
+        collect(moving_average_partial(&data, 10, 1)),
+        [1.0, 1.5, 3.0, 3.25, 4.2]
+    );
+    assert_eq!(collect(moving_average_partial(&data, 10, 5)), [4.2]);
+    assert!(collect(moving_average_partial(&data, 10, 6)).is_empty());
+
+    assert_eq!(
+        moving_average_partial(&data, 3, 1).unwrap().len(),
+        data.len()
+    );
+}
+
+#[test]
+fn test_centered() {
+    let data = [1.0, 2.0, 6.0, 4.0, 8.0];
+    assert_eq!(
+        collect(moving_average_centered(&data, 3, 3)),
+        [3.0, 4.0, 6.0]
+    );
+    assert_eq!(
+        collect(moving_average_centered(&data, 3, 1)),
+        [1.5, 3.0, 4.0, 6.0, 6.0]
+    );
+    assert_eq!(collect(moving_average_centered(&data, 1, 1)), data);
+
+    // An even window has one more value after the centre, like pandas
+    assert_eq!(collect(moving_average_centered(&data, 4, 4)), [3.25, 5.0]);
+    assert_eq!(
+        collect(moving_average_centered(&data, 4, 1)),
+        [3.0, 3.25, 5.0, 6.0, 6.0]
+    );
+    assert_eq!(
+        collect(moving_average_centered(&data, 4, 3)),
+        [3.0, 3.25, 5.0, 6.0]
+    );
+    assert_eq!(collect(moving_average_centered(&data, 9, 5)), [4.2; 5]);
+    assert!(collect(moving_average_centered(&data, 9, 6)).is_empty());
+}
+
+#[test]
+fn test_matches_fresh_sums() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let data: Vec<f64> = (0..200)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 2000) as f64 / 8.0 - 125.0
+        })
+        .collect();
+    for window in 1..30 {
+        for min_periods in 1..=window {
+            // Eighths add up exactly, so the running sum matches a fresh one
+            assert_eq!(
+                collect(moving_average_partial(&data, window, min_periods)),
+                expected(&data, window - 1, 0, min_periods)
+            );
+            assert_eq!(
+                collect(moving_average_centered(&data, window, min_periods)),
+                expected(&data, (window - 1) / 2, window / 2, min_periods)
+            );
+            for length in 0..window + 2 {
+                let data = &data[..length];
+                let averages = moving_average_centered(data, window, min_periods).unwrap();
+                assert_eq!(
+                    averages.len(),
+                    expected(data, (window - 1) / 2, window / 2, min_periods).len()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_no_drift() {
+    let data = vec![0.1; 10_000_000];
+    let mut count = 0;
+    for average in moving_average(&data, 7).unwrap() {
+        assert!((average - 0.1).abs() < 1e-15, "{average} after {count}");
+        count += 1;
+    }
+    assert_eq!(count, data.len() - 6);
+}
+
+#[test]
+fn test_non_finite() {
+    let data = [1.0, f64::INFINITY, 2.0, 3.0, 4.0];
+    assert_eq!(
+        collect(moving_average(&data, 2)),
+        [f64::INFINITY, f64::INFINITY, 2.5, 3.5]
+    );
+
+    let averages = collect(moving_average(&[1.0, f64::NAN, 2.0, 3.0, 5.0], 2));
+    assert!(averages[0].is_nan() && averages[1].is_nan());
+    assert_eq!(averages[2..], [2.5, 4.0]);
+}
+
+#[test]
+fn test_errors() {
+    let data = [1.0, 2.0];
+    assert!(matches!(
+        moving_average(&data, 0),
+        Err(StatsError::InvalidWindow)
+    ));
+    assert!(matches!(
+        moving_average_partial(&data, 0, 0),
+        Err(StatsError::InvalidWindow)
+    ));
+    assert!(matches!(
+        moving_average_partial(&data, 3, 0),
+        Err(StatsError::InvalidWindow)
+    ));
+    assert!(matches!(
+        moving_average_partial(&data, 3, 4),
+        Err(StatsError::InvalidWindow)
+    ));
+    assert!(matches!(
+        moving_average_centered(&data, 0, 1),
+        Err(StatsError::InvalidWindow)
+    ));
+    assert!(matches!(
+        moving_average_centered(&data, 2, 3),
+        Err(StatsError::InvalidWindow)
+    ));
+}
@@ -0,0 +1,176 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use super::{five_number_summary, mean, std_dev_population, z_score};
+
+/// The indices of the values outside Tukey's fences, more than `k` interquartile ranges below the
+/// lower quartile or above the upper quartile
+///
+/// The quartiles are found like [`five_number_summary`] does, from a sorted copy of the finite
+/// values, so the data itself isn't reordered. NaN is never an outlier and plays no part in the
+/// quartiles, while infinities are left out of the quartiles and are always outliers. A value on a
+/// fence isn't an outlier, so data that is all equal has none. See [`outliers_tukey`] for the
+/// usual `k` of 1.5 and [`is_outlier_iqr`] to mark each value instead
+///
+/// # Panics
+///
+/// Panics if `k` isn't finite and non-negative
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::outliers_iqr;
+///
+/// // Quartiles 2.5 and 7.5 give fences at -5 and 15
+/// let data = [-20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 16.0];
+/// assert_eq!(outliers_iqr(&data, 1.5), [0, 10]);
+/// // Fences at -12.5 and 22.5 with a k of 3
+/// assert_eq!(outliers_iqr(&data, 3.0), [0]);
+/// assert!(outliers_iqr(&[4.0; 5], 1.5).is_empty());
+/// ```
+pub fn outliers_iqr(data: &[f64], k: f64) -> Vec<usize> {
+    indices(&is_outlier_iqr(data, k))
+}
+
+/// The indices of the values outside Tukey's fences with the usual `k` of 1.5, like
+/// [`outliers_iqr`]
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::outliers_tukey;
+///
+/// let data = [-20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 16.0];
+/// assert_eq!(outliers_tukey(&data), [0, 10]);
+/// ```
+#[inline]
+pub fn outliers_tukey(data: &[f64]) -> Vec<usize> {
+    outliers_iqr(data, 1.5)
+}
+
+/// Whether each value is outside Tukey's fences, more than `k` interquartile ranges below the
+/// lower quartile or above the upper quartile, like [`outliers_iqr`]
+///
+/// # Panics
+///
+/// Panics if `k` isn't finite and non-negative
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::is_outlier_iqr;
+///
+/// let data = [1.0, 2.0, 3.0, 4.0, 100.0, f64::NAN];
+/// assert_eq!(is_outlier_iqr(&data, 1.5), [false, false, false, false, true, false]);
+/// ```
+pub fn is_outlier_iqr(data: &[f64], k: f64) -> Vec<bool> {
+    assert!(
+        k >= 0.0 && k.is_finite(),
+        "k must be finite and non-negative"
+    );
+
+    let mut finite = finite_values(data);
+    let Some(summary) = five_number_summary(&mut finite) else {
+        // With no finite values, only the infinities are outliers
+        return data.iter().map(|value| value.is_infinite()).collect();
+    };
+    // An interquartile range too wide for a float is only NaN when scaled by zero
+    let spread = match k * summary.iqr() {
+        spread if spread.is_nan() => 0.0,
+        spread => spread,
+    };
+    // A fence beyond the largest float is infinite
+    let fence = |quartile: f64, sign: f64| match quartile + sign * spread {
+        fence if fence.is_finite() => fence,
+        _ => sign * f64::INFINITY,
+    };
+    let (lower, upper) = (
+        fence(summary.lower_quartile(), -1.0),
+        fence(summary.upper_quartile(), 1.0),
+    );
+    data.iter()
+        .map(|&value| value < lower || value > upper || value.is_infinite())
+        .collect()
+}
+
+/// The indices of the values whose z-score is further than `threshold` from zero, that are more
+/// than `threshold` standard deviations from the mean
+///
+/// The mean and population standard deviation are those of the finite values, and each score comes
+/// from [`z_score`], so the data itself isn't changed. NaN is never an outlier, while infinities
+/// are outliers whenever the finite values have some spread. Data that is all equal has no
+/// standard deviation to score by, so it has no outliers. See [`is_outlier_zscore`] to mark each
+/// value instead
+///
+/// A single outlier raises the standard deviation it is scored by, so in a small sample it may not
+/// reach the threshold, where [`outliers_iqr`] is less affected
+///
+/// # Panics
+///
+/// Panics if `threshold` isn't finite and non-negative
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::outliers_zscore;
+///
+/// // Mean 5 and population standard deviation 2
+/// let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(outliers_zscore(&data, 1.0), [0, 7]);
+/// assert_eq!(outliers_zscore(&data, 1.5), [7]);
+/// assert!(outliers_zscore(&data, 2.0).is_empty());
+/// ```
+pub fn outliers_zscore(data: &[f64], threshold: f64) -> Vec<usize> {
+    indices(&is_outlier_zscore(data, threshold))
+}
+
+/// Whether each value has a z-score further than `threshold` from zero, like [`outliers_zscore`]
+///
+/// # Panics
+///
+/// Panics if `threshold` isn't finite and non-negative
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::is_outlier_zscore;
+///
+/// let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(is_outlier_zscore(&data, 1.5), [false, false, false, false, false, false, false, true]);
+/// assert_eq!(is_outlier_zscore(&[3.0, 3.0], 1.5), [false, false]);
+/// ```
+pub fn is_outlier_zscore(data: &[f64], threshold: f64) -> Vec<bool> {
+    assert!(
+        threshold >= 0.0 && threshold.is_finite(),
+        "threshold must be finite and non-negative"
+    );
+
+    let finite = finite_values(data);
+    let (Some(mean), Some(std_dev)) = (mean(&finite), std_dev_population(&finite)) else {
+        return vec![false; data.len()];
+    };
+    data.iter()
+        .map(|&value| z_score(value, mean, std_dev).is_some_and(|z| z.abs() > threshold))
+        .collect()
+}
+
+/// A copy of the finite values
+#[inline]
+fn finite_values(data: &[f64]) -> Vec<f64> {
+    data.iter()
+        .copied()
+        .filter(|value| value.is_finite())
+        .collect()
+}
+
+/// The indices of the values marked as outliers
+#[inline]
+fn indices(outliers: &[bool]) -> Vec<usize> {
+    outliers
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &outlier)| outlier.then_some(index))
+        .collect()
+}
+
+mod tests;
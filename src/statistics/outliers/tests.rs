@@ -0,0 +1,173 @@
+#[cfg(test)]
+use super::*;
+
+/// Readings around 12 with outliers planted at both ends, far out at indices 2 and 10 and just
+/// past the inner fences at indices 15 and 16
+#[cfg(test)]
+const PLANTED: [f64; 17] = [
+    12.1, 11.4, -35.0, 10.8, 12.9, 11.7, 13.2, 10.5, 11.9, 12.4, 64.0, 11.1, 12.6, 11.5, 12.0, 8.0,
+    15.5,
+];
+
+/// The readings around 12 without the outliers
+#[cfg(test)]
+const CLEAN: [f64; 13] = [
+    10.5, 10.8, 11.1, 11.4, 11.5, 11.7, 11.9, 12.0, 12.1, 12.4, 12.6, 12.9, 13.2,
+];
+
+#[test]
+fn test_iqr_planted() {
+    // Quartiles 11.1 and 12.6 give fences at 8.85 and 14.85
+    assert_eq!(outliers_iqr(&PLANTED, 1.5), [2, 10, 15, 16]);
+    assert_eq!(outliers_tukey(&PLANTED), [2, 10, 15, 16]);
+
+    let mask = is_outlier_iqr(&PLANTED, 1.5);
+    assert_eq!(mask.len(), PLANTED.len());
+    for (index, &outlier) in mask.iter().enumerate() {
+        assert_eq!(outlier, [2, 10, 15, 16].contains(&index));
+    }
+}
+
+#[test]
+fn test_iqr_k() {
+    // Fences at 6.6 and 17.1 only leave the far outliers
+    assert_eq!(outliers_iqr(&PLANTED, 3.0), [2, 10]);
+    // Fences at the quartiles themselves
+    assert_eq!(outliers_iqr(&[1.0, 2.0, 3.0, 4.0, 5.0], 0.0), [0, 4]);
+    // Wider fences only ever find fewer outliers
+    let mut previous = PLANTED.len();
+    for k in [0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 10.0, 100.0] {
+        let found = outliers_iqr(&PLANTED, k).len();
+        assert!(found <= previous);
+        previous = found;
+    }
+    assert!(outliers_iqr(&PLANTED, 100.0).is_empty());
+}
+
+#[test]
+fn test_zscore_planted() {
+    // Mean 12.15 and population standard deviation 17.06 give scores of -2.76 and 3.04
+    assert_eq!(outliers_zscore(&PLANTED, 2.5), [2, 10]);
+    assert_eq!(outliers_zscore(&PLANTED, 3.0), [10]);
+    assert!(outliers_zscore(&PLANTED, 3.5).is_empty());
+
+    let mask = is_outlier_zscore(&PLANTED, 2.5);
+    assert_eq!(mask.len(), PLANTED.len());
+    for (index, &outlier) in mask.iter().enumerate() {
+        assert_eq!(outlier, [2, 10].contains(&index));
+    }
+}
+
+#[test]
+fn test_clean() {
+    assert!(outliers_tukey(&CLEAN).is_empty());
+    assert!(outliers_iqr(&CLEAN, 3.0).is_empty());
+    assert!(is_outlier_iqr(&CLEAN, 1.5).iter().all(|&outlier| !outlier));
+    // No reading is as much as 1.76 standard deviations from the mean
+    assert!(outliers_zscore(&CLEAN, 2.0).is_empty());
+    assert!(is_outlier_zscore(&CLEAN, 3.0)
+        .iter()
+        .all(|&outlier| !outlier));
+}
+
+#[test]
+fn test_constant() {
+    for length in 1..10 {
+        let data = [4.5; 10];
+        let data = &data[..length];
+        assert!(outliers_iqr(data, 1.5).is_empty());
+        assert!(outliers_iqr(data, 0.0).is_empty());
+        assert!(outliers_zscore(data, 0.0).is_empty());
+        assert!(outliers_zscore(data, 3.0).is_empty());
+        assert_eq!(is_outlier_iqr(data, 1.5), vec![false; length]);
+        assert_eq!(is_outlier_zscore(data, 3.0), vec![false; length]);
+    }
+
+    // One value apart from the rest is outside fences that have closed up to the quartiles
+    assert_eq!(outliers_tukey(&[3.0, 3.0, 3.0, 3.0, 3.0, 3.5]), [5]);
+}
+
+#[test]
+fn test_empty() {
+    assert!(outliers_tukey(&[]).is_empty());
+    assert!(outliers_zscore(&[], 3.0).is_empty());
+    assert!(is_outlier_iqr(&[], 1.5).is_empty());
+    assert!(is_outlier_zscore(&[], 3.0).is_empty());
+}
+
+#[test]
+fn test_nan_and_infinities() {
+    let mut data = PLANTED.to_vec();
+    data.insert(4, f64::NAN);
+    data.push(f64::NAN);
+    // NaN is never an outlier and doesn't move the others
+    assert_eq!(outliers_tukey(&data), [2, 11, 16, 17]);
+    assert_eq!(outliers_zscore(&data, 2.5), [2, 11]);
+    assert!(outliers_tukey(&[f64::NAN; 3]).is_empty());
+    assert!(outliers_zscore(&[f64::NAN; 3], 0.0).is_empty());
+
+    // Infinities are outliers without moving the quartiles or the mean
+    let mut data = CLEAN.to_vec();
+    data.insert(0, f64::INFINITY);
+    data.push(f64::NEG_INFINITY);
+    assert_eq!(outliers_tukey(&data), [0, 14]);
+    assert_eq!(outliers_iqr(&data, 100.0), [0, 14]);
+    assert_eq!(outliers_zscore(&data, 2.0), [0, 14]);
+    assert_eq!(outliers_tukey(&[f64::INFINITY, 1.0, f64::NAN]), [0]);
+    assert_eq!(outliers_tukey(&[f64::INFINITY, f64::NEG_INFINITY]), [0, 1]);
+    // Without any spread in the finite values there is nothing to score by
+    assert!(outliers_zscore(&[f64::INFINITY, 1.0], 2.0).is_empty());
+
+    // Fences past the largest float
+    let data = [-f64::MAX, 0.0, f64::MAX, f64::INFINITY];
+    assert_eq!(outliers_tukey(&data), [3]);
+    assert_eq!(outliers_iqr(&data, 0.0), [0, 2, 3]);
+}
+
+#[test]
+fn test_data_unchanged() {
+    let data = PLANTED;
+    outliers_tukey(&data);
+    outliers_zscore(&data, 3.0);
+    assert_eq!(data, PLANTED);
+}
+
+#[test]
+fn test_indices_match_mask() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 0..60 {
+        let data: Vec<f64> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                // Mostly small values with the odd large one
+                match state % 16 {
+                    0 => (state % 1000) as f64,
+                    _ => (state % 100) as f64 / 10.0,
+                }
+            })
+            .collect();
+        for k in [0.0, 1.5, 3.0] {
+            let mask = is_outlier_iqr(&data, k);
+            let expected: Vec<usize> = (0..length).filter(|&index| mask[index]).collect();
+            assert_eq!(outliers_iqr(&data, k), expected);
+
+            let mask = is_outlier_zscore(&data, k);
+            let expected: Vec<usize> = (0..length).filter(|&index| mask[index]).collect();
+            assert_eq!(outliers_zscore(&data, k), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "k must be finite and non-negative")]
+fn test_negative_k() {
+    outliers_iqr(&CLEAN, -1.0);
+}
+
+#[test]
+#[should_panic(expected = "threshold must be finite and non-negative")]
+fn test_nan_threshold() {
+    outliers_zscore(&CLEAN, f64::NAN);
+}
@@ -0,0 +1,313 @@
+use super::StatsError;
+
+/// How a quantile that falls between two of the sorted values is worked out from them, as
+/// different tools follow different conventions
+///
+/// The position of quantile `q` in `n` sorted values is `(n - 1) * q`, counting from zero. When
+/// it isn't a whole number the quantile is between the values either side, `lower` and `higher`.
+/// The modes match the `method` of the same name in NumPy's `quantile` and `percentile`
+///
+/// ```rust
+/// use common_math::statistics::{percentile_with, Interpolation};
+///
+/// let mut data = [15.0, 20.0, 35.0, 40.0, 50.0];
+/// assert_eq!(Interpolation::default(), Interpolation::Linear);
+/// assert_eq!(percentile_with(&mut data, 40.0, Interpolation::Linear), Ok(29.0));
+/// assert_eq!(percentile_with(&mut data, 40.0, Interpolation::Nearest), Ok(35.0));
+/// assert_eq!(percentile_with(&mut data, 40.0, Interpolation::Lower), Ok(20.0));
+/// assert_eq!(percentile_with(&mut data, 40.0, Interpolation::Higher), Ok(35.0));
+/// assert_eq!(percentile_with(&mut data, 40.0, Interpolation::Midpoint), Ok(27.5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Interpolation {
+    /// Interpolates linearly from `lower` to `higher` by how far the position is between them,
+    /// the definition used by default in R (type 7), NumPy and Excel's `PERCENTILE.INC`
+    #[default]
+    Linear,
+    /// The value at the nearest position, with a position halfway between going to the even one
+    Nearest,
+    /// The value at the position rounded down, `lower`
+    Lower,
+    /// The value at the position rounded up, `higher`
+    Higher,
+    /// Halfway between `lower` and `higher`, whenever the position is between them
+    Midpoint,
+}
+
+impl Interpolation {
+    /// The index of the lower value a quantile is taken from in `len` sorted values, and how far
+    /// towards the next value it is, which is zero if it is just the lower value
+    #[inline]
+    fn position(self, len: usize, q: f64) -> (usize, f64) {
+        let position = (len - 1) as f64 * q;
+        // The position isn't negative so the conversion rounds it down
+        let lower = position as usize;
+        let fraction = position - lower as f64;
+        if fraction == 0.0 {
+            return (lower, 0.0);
+        }
+
+        match self {
+            Interpolation::Linear => (lower, fraction),
+            Interpolation::Nearest => {
+                if fraction > 0.5 || (fraction == 0.5 && !lower.is_multiple_of(2)) {
+                    (lower + 1, 0.0)
+                } else {
+                    (lower, 0.0)
+                }
+            }
+            Interpolation::Lower => (lower, 0.0),
+            Interpolation::Higher => (lower + 1, 0.0),
+            Interpolation::Midpoint => (lower, 0.5),
+        }
+    }
+}
+
+/// The quantile `q` of the data, from 0 for the smallest value to 1 for the largest, interpolated
+/// linearly between the values either side like R, NumPy and Excel do by default
+///
+/// The data is partly reordered to find the values either side, without allocating. See
+/// [`quantile_with`] for the other ways of interpolating, and [`quantile_sorted`] for data that is
+/// already sorted
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `q` isn't between 0 and 1
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{quantile, StatsError};
+///
+/// assert_eq!(quantile(&mut [4.0, 1.0, 3.0, 2.0], 0.5), Ok(2.5));
+/// assert_eq!(quantile(&mut [4.0, 1.0, 3.0, 2.0], 0.25), Ok(1.75));
+/// assert_eq!(quantile(&mut [4.0, 1.0], 1.5), Err(StatsError::InvalidQuantile));
+/// assert_eq!(quantile(&mut [4.0, f64::NAN], 0.5), Err(StatsError::NanValues));
+/// ```
+#[inline]
+pub fn quantile(data: &mut [f64], q: f64) -> Result<f64, StatsError> {
+    quantile_with(data, q, Interpolation::Linear)
+}
+
+/// The quantile `q` of the data, from 0 for the smallest value to 1 for the largest, using the
+/// given interpolation between the values either side
+///
+/// The data is partly reordered to find the values either side, without allocating
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `q` isn't between 0 and 1
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{quantile_with, Interpolation};
+///
+/// let mut data = [10.0, 40.0, 20.0, 30.0];
+/// assert_eq!(quantile_with(&mut data, 0.5, Interpolation::Linear), Ok(25.0));
+/// assert_eq!(quantile_with(&mut data, 0.5, Interpolation::Lower), Ok(20.0));
+/// assert_eq!(quantile_with(&mut data, 0.5, Interpolation::Higher), Ok(30.0));
+/// ```
+#[inline]
+pub fn quantile_with(
+    data: &mut [f64],
+    q: f64,
+    interpolation: Interpolation,
+) -> Result<f64, StatsError> {
+    check(data, q)?;
+
+    let (index, fraction) = interpolation.position(data.len(), q);
+    let (_, &mut lower, above) = data.select_nth_unstable_by(index, f64::total_cmp);
+    if fraction == 0.0 {
+        return Ok(lower);
+    }
+
+    // The values above the lower one are all ordered after it, so the smallest is next to it
+    let higher = *above
+        .iter()
+        .min_by(|a, b| a.total_cmp(b))
+        .expect("a position between two values has one above the lower");
+    Ok(interpolate(lower, higher, fraction))
+}
+
+/// The quantile `q` of data that is already sorted in ascending order, from 0 for the smallest
+/// value to 1 for the largest, using the given interpolation between the values either side
+///
+/// Works like [`quantile_with`] without reordering the data, for taking several quantiles of the
+/// same data or when it is borrowed
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `q` isn't between 0 and 1
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+/// - [`StatsError::UnsortedValues`] if the data isn't sorted in ascending order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{quantile_sorted, Interpolation, StatsError};
+///
+/// let data = [1.0, 2.0, 4.0, 8.0];
+/// assert_eq!(quantile_sorted(&data, 0.5, Interpolation::Linear), Ok(3.0));
+/// assert_eq!(quantile_sorted(&data, 0.5, Interpolation::Midpoint), Ok(3.0));
+/// assert_eq!(quantile_sorted(&data, 0.9, Interpolation::Nearest), Ok(8.0));
+/// assert_eq!(
+///     quantile_sorted(&[2.0, 1.0], 0.5, Interpolation::Linear),
+///     Err(StatsError::UnsortedValues)
+/// );
+/// ```
+#[inline]
+pub fn quantile_sorted(
+    sorted: &[f64],
+    q: f64,
+    interpolation: Interpolation,
+) -> Result<f64, StatsError> {
+    check(sorted, q)?;
+    if !sorted.windows(2).all(|pair| pair[0] <= pair[1]) {
+        return Err(StatsError::UnsortedValues);
+    }
+
+    let (index, fraction) = interpolation.position(sorted.len(), q);
+    if fraction == 0.0 {
+        Ok(sorted[index])
+    } else {
+        Ok(interpolate(sorted[index], sorted[index + 1], fraction))
+    }
+}
+
+/// The percentile `p` of the data, from 0 for the smallest value to 100 for the largest,
+/// interpolated linearly between the values either side like R, NumPy and Excel do by default
+///
+/// This is the [`quantile`] `p / 100`, and the data is partly reordered in the same way
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `p` isn't between 0 and 100
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{percentile, StatsError};
+///
+/// let mut data = [15.0, 20.0, 35.0, 40.0, 50.0];
+/// assert_eq!(percentile(&mut data, 40.0), Ok(29.0));
+/// assert_eq!(percentile(&mut data, 100.0), Ok(50.0));
+/// assert_eq!(percentile(&mut data, 101.0), Err(StatsError::InvalidQuantile));
+/// ```
+#[inline]
+pub fn percentile(data: &mut [f64], p: f64) -> Result<f64, StatsError> {
+    percentile_with(data, p, Interpolation::Linear)
+}
+
+/// The percentile `p` of the data, from 0 for the smallest value to 100 for the largest, using
+/// the given interpolation between the values either side
+///
+/// This is the [`quantile_with`] `p / 100`, and the data is partly reordered in the same way
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `p` isn't between 0 and 100
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{percentile_with, Interpolation};
+///
+/// let mut data = [15.0, 20.0, 35.0, 40.0, 50.0];
+/// assert_eq!(percentile_with(&mut data, 30.0, Interpolation::Linear), Ok(23.0));
+/// assert_eq!(percentile_with(&mut data, 30.0, Interpolation::Nearest), Ok(20.0));
+/// ```
+#[inline]
+pub fn percentile_with(
+    data: &mut [f64],
+    p: f64,
+    interpolation: Interpolation,
+) -> Result<f64, StatsError> {
+    quantile_with(data, to_quantile(p)?, interpolation)
+}
+
+/// The percentile `p` of data that is already sorted in ascending order, from 0 for the smallest
+/// value to 100 for the largest, using the given interpolation between the values either side
+///
+/// This is the [`quantile_sorted`] `p / 100`
+///
+/// # Errors
+///
+/// - [`StatsError::InvalidQuantile`] if `p` isn't between 0 and 100
+/// - [`StatsError::TooFewValues`] if there is no data
+/// - [`StatsError::NanValues`] if the data contains NaN, which has no place in the order
+/// - [`StatsError::UnsortedValues`] if the data isn't sorted in ascending order
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{percentile_sorted, Interpolation};
+///
+/// let data = [15.0, 20.0, 35.0, 40.0, 50.0];
+/// assert_eq!(percentile_sorted(&data, 0.0, Interpolation::Linear), Ok(15.0));
+/// assert_eq!(percentile_sorted(&data, 62.5, Interpolation::Linear), Ok(37.5));
+/// assert_eq!(percentile_sorted(&data, 62.5, Interpolation::Higher), Ok(40.0));
+/// ```
+#[inline]
+pub fn percentile_sorted(
+    sorted: &[f64],
+    p: f64,
+    interpolation: Interpolation,
+) -> Result<f64, StatsError> {
+    quantile_sorted(sorted, to_quantile(p)?, interpolation)
+}
+
+/// Converts a percentile to a quantile, checking it is between 0 and 100
+#[inline]
+fn to_quantile(p: f64) -> Result<f64, StatsError> {
+    if (0.0..=100.0).contains(&p) {
+        Ok(p / 100.0)
+    } else {
+        Err(StatsError::InvalidQuantile)
+    }
+}
+
+/// Checks a quantile can be taken of the data
+#[inline]
+fn check(data: &[f64], q: f64) -> Result<(), StatsError> {
+    if !(0.0..=1.0).contains(&q) {
+        Err(StatsError::InvalidQuantile)
+    } else if data.is_empty() {
+        Err(StatsError::TooFewValues)
+    } else if data.iter().any(|value| value.is_nan()) {
+        Err(StatsError::NanValues)
+    } else {
+        Ok(())
+    }
+}
+
+/// Interpolates linearly from `lower` towards `higher`, working back from `higher` past halfway
+/// like NumPy so the result is exactly `higher` when the fraction is 1
+#[inline]
+fn interpolate(lower: f64, higher: f64, fraction: f64) -> f64 {
+    if lower == higher {
+        // Also keeps an infinite value rather than subtracting it from itself
+        return lower;
+    }
+
+    let difference = higher - lower;
+    if difference.is_infinite() && lower.is_finite() && higher.is_finite() {
+        // The difference of two large values overflowed, but the result lies between them
+        lower * (1.0 - fraction) + higher * fraction
+    } else if fraction >= 0.5 {
+        higher - difference * (1.0 - fraction)
+    } else {
+        lower + difference * fraction
+    }
+}
+
+mod tests;
@@ -0,0 +1,162 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+const MODES: [Interpolation; 5] = [
+    Interpolation::Linear,
+    Interpolation::Nearest,
+    Interpolation::Lower,
+    Interpolation::Higher,
+    Interpolation::Midpoint,
+];
+
+#[test]
+fn test_reference_values() {
+    // From numpy.percentile([15, 20, 35, 40, 50], p, method=...) for each mode in order
+    let expected = [
+        (0.0, [15.0, 15.0, 15.0, 15.0, 15.0]),
+        (10.0, [17.0, 15.0, 15.0, 20.0, 17.5]),
+        (25.0, [20.0, 20.0, 20.0, 20.0, 20.0]),
+        (30.0, [23.0, 20.0, 20.0, 35.0, 27.5]),
+        (40.0, [29.0, 35.0, 20.0, 35.0, 27.5]),
+        (50.0, [35.0, 35.0, 35.0, 35.0, 35.0]),
+        (62.5, [37.5, 35.0, 35.0, 40.0, 37.5]),
+        (87.5, [45.0, 50.0, 40.0, 50.0, 45.0]),
+        (90.0, [46.0, 50.0, 40.0, 50.0, 45.0]),
+        (100.0, [50.0, 50.0, 50.0, 50.0, 50.0]),
+    ];
+    let sorted = [15.0, 20.0, 35.0, 40.0, 50.0];
+    for (p, values) in expected {
+        for (mode, value) in MODES.into_iter().zip(values) {
+            let mut data = [35.0, 50.0, 15.0, 40.0, 20.0];
+            assert_eq!(
+                percentile_with(&mut data, p, mode),
+                Ok(value),
+                "{p} {mode:?}"
+            );
+            assert_eq!(
+                percentile_sorted(&sorted, p, mode),
+                Ok(value),
+                "{p} {mode:?}"
+            );
+            assert_eq!(
+                quantile_sorted(&sorted, p / 100.0, mode),
+                Ok(value),
+                "{p} {mode:?}"
+            );
+        }
+
+        let mut data = [40.0, 15.0, 50.0, 20.0, 35.0];
+        assert_eq!(percentile(&mut data, p), Ok(values[0]));
+        assert_eq!(quantile(&mut data, p / 100.0), Ok(values[0]));
+    }
+
+    // From R's quantile(1:10, q), which interpolates linearly by default
+    let mut data: [f64; 10] = core::array::from_fn(|index| (10 - index) as f64);
+    assert_eq!(quantile(&mut data, 0.25), Ok(3.25));
+    assert_eq!(quantile(&mut data, 0.5), Ok(5.5));
+    assert_eq!(quantile(&mut data, 0.75), Ok(7.75));
+    assert_eq!(quantile(&mut data, 0.9), Ok(9.1));
+}
+
+#[test]
+fn test_nearest_ties() {
+    // A position halfway between goes to the even one, like NumPy
+    let sorted = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let nearest = |q| quantile_sorted(&sorted, q, Interpolation::Nearest);
+    assert_eq!(nearest(0.125), Ok(0.0));
+    assert_eq!(nearest(0.375), Ok(2.0));
+    assert_eq!(nearest(0.625), Ok(2.0));
+    assert_eq!(nearest(0.875), Ok(4.0));
+    assert_eq!(nearest(0.126), Ok(1.0));
+    assert_eq!(nearest(0.624), Ok(2.0));
+}
+
+#[test]
+fn test_matches_sorted() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    for length in 1..60 {
+        let data: Vec<f64> = (0..length)
+            .map(|_| (next() % 1000) as f64 / 10.0 - 50.0)
+            .collect();
+        let mut sorted = data.clone();
+        sorted.sort_by(f64::total_cmp);
+        for _ in 0..10 {
+            let q = (next() >> 11) as f64 / (1_u64 << 53) as f64;
+            for mode in MODES {
+                let expected = quantile_sorted(&sorted, q, mode).unwrap();
+                assert!(expected >= sorted[0] && expected <= sorted[length - 1]);
+                let mut data = data.clone();
+                assert_eq!(quantile_with(&mut data, q, mode), Ok(expected));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_single_and_equal_values() {
+    for mode in MODES {
+        assert_eq!(quantile_with(&mut [7.0], 0.3, mode), Ok(7.0));
+        assert_eq!(quantile_sorted(&[2.0, 2.0, 2.0], 0.7, mode), Ok(2.0));
+        assert_eq!(
+            quantile_sorted(&[f64::INFINITY, f64::INFINITY], 0.5, mode),
+            Ok(f64::INFINITY)
+        );
+    }
+
+    // Interpolating between values that are too far apart to subtract
+    assert_eq!(quantile(&mut [f64::MAX, -f64::MAX], 0.5), Ok(0.0));
+    let quarter = quantile(&mut [f64::MAX, -f64::MAX], 0.75).unwrap();
+    assert!((quarter - f64::MAX / 2.0).abs() <= f64::MAX * 1e-15);
+    assert_eq!(quantile(&mut [-0.0, 0.0], 0.0), Ok(-0.0));
+}
+
+#[test]
+fn test_errors() {
+    for q in [-0.1, 1.1, f64::NAN, f64::INFINITY] {
+        assert_eq!(quantile(&mut [1.0], q), Err(StatsError::InvalidQuantile));
+        assert_eq!(
+            quantile_sorted(&[1.0], q, Interpolation::Linear),
+            Err(StatsError::InvalidQuantile)
+        );
+    }
+    for p in [-1.0, 100.5, f64::NAN, f64::NEG_INFINITY] {
+        assert_eq!(percentile(&mut [1.0], p), Err(StatsError::InvalidQuantile));
+        assert_eq!(
+            percentile_sorted(&[1.0], p, Interpolation::Lower),
+            Err(StatsError::InvalidQuantile)
+        );
+    }
+
+    assert_eq!(quantile(&mut [], 0.5), Err(StatsError::TooFewValues));
+    assert_eq!(percentile(&mut [], 50.0), Err(StatsError::TooFewValues));
+    assert_eq!(
+        quantile_sorted(&[], 0.5, Interpolation::Linear),
+        Err(StatsError::TooFewValues)
+    );
+
+    assert_eq!(quantile(&mut [f64::NAN], 0.5), Err(StatsError::NanValues));
+    assert_eq!(
+        percentile(&mut [1.0, 2.0, -f64::NAN], 10.0),
+        Err(StatsError::NanValues)
+    );
+    assert_eq!(
+        quantile_sorted(&[1.0, f64::NAN], 0.0, Interpolation::Linear),
+        Err(StatsError::NanValues)
+    );
+
+    assert_eq!(
+        quantile_sorted(&[1.0, 3.0, 2.0], 0.0, Interpolation::Linear),
+        Err(StatsError::UnsortedValues)
+    );
+    assert_eq!(
+        percentile_sorted(&[2.0, 1.0], 100.0, Interpolation::Higher),
+        Err(StatsError::UnsortedValues)
+    );
+}
@@ -0,0 +1,156 @@
+use super::{mean, StatsError};
+
+/// A straight line fitted through some points by least squares, made by [`linear_regression`] or
+/// [`linear_regression_indexed`]
+///
+/// ```rust
+/// use common_math::statistics::{linear_regression, StatsError};
+///
+/// let fit = linear_regression(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0, 4.0, 5.0, 4.0, 5.0])?;
+/// assert_eq!(fit.slope(), 0.6);
+/// assert_eq!(fit.intercept(), 2.2);
+/// assert_eq!(fit.r_squared(), 0.6);
+/// assert_eq!(fit.predict(10.0), 8.2);
+/// # Ok::<(), StatsError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearFit {
+    slope: f64,
+    intercept: f64,
+    r_squared: f64,
+}
+
+impl LinearFit {
+    /// How much `y` goes up on the line for each step of 1 in `x`
+    #[inline]
+    pub fn slope(&self) -> f64 {
+        self.slope
+    }
+
+    /// The value of `y` on the line where `x` is zero
+    #[inline]
+    pub fn intercept(&self) -> f64 {
+        self.intercept
+    }
+
+    /// The coefficient of determination, the share of the variance in `y` that the line
+    /// accounts for, from 0 for no linear relationship to 1 for points that all lie on the line
+    ///
+    /// When the `y` values are all equal the line goes through every point, so this is 1
+    #[inline]
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// The value of `y` on the line at `x`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use common_math::statistics::{linear_regression_indexed, StatsError};
+    ///
+    /// let trend = linear_regression_indexed(&[3.0, 5.0, 7.0])?;
+    /// assert_eq!(trend.predict(1.0), 5.0);
+    /// assert_eq!(trend.predict(3.0), 9.0);
+    /// # Ok::<(), StatsError>(())
+    /// ```
+    #[inline]
+    pub fn predict(&self, x: f64) -> f64 {
+        self.intercept + self.slope * x
+    }
+}
+
+/// Fits a straight line through the points `(x[i], y[i])` by ordinary least squares, giving its
+/// slope, intercept and coefficient of determination
+///
+/// The sums are taken of the deviations from the means of `x` and `y` on a second pass, so points
+/// far from the origin don't lose their digits the way the textbook sums of `x * y` and `x²` do.
+/// Points with NaN or infinities give a line that isn't finite
+///
+/// # Errors
+///
+/// - [`StatsError::LengthMismatch`] if there aren't the same number of `y` values as `x` values
+/// - [`StatsError::TooFewValues`] if there are fewer than two points
+/// - [`StatsError::ZeroVariance`] if the `x` values are all equal, so the line would be vertical
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{linear_regression, StatsError};
+///
+/// let fit = linear_regression(&[0.0, 1.0, 2.0], &[1.0, 3.0, 5.0])?;
+/// assert_eq!((fit.slope(), fit.intercept(), fit.r_squared()), (2.0, 1.0, 1.0));
+///
+/// assert_eq!(linear_regression(&[1.0, 2.0], &[1.0]), Err(StatsError::LengthMismatch));
+/// assert_eq!(linear_regression(&[1.0], &[1.0]), Err(StatsError::TooFewValues));
+/// assert_eq!(linear_regression(&[2.0, 2.0], &[1.0, 3.0]), Err(StatsError::ZeroVariance));
+/// # Ok::<(), StatsError>(())
+/// ```
+#[inline]
+pub fn linear_regression(x: &[f64], y: &[f64]) -> Result<LinearFit, StatsError> {
+    if x.len() != y.len() {
+        return Err(StatsError::LengthMismatch);
+    } else if x.len() < 2 {
+        return Err(StatsError::TooFewValues);
+    }
+
+    let mean_x = mean(x).ok_or(StatsError::TooFewValues)?;
+    fit(|index| x[index], mean_x, y)
+}
+
+/// Fits a straight line through the values by ordinary least squares like [`linear_regression`],
+/// with the index of each value as its `x`, for the trend of a series over time
+///
+/// # Errors
+///
+/// [`StatsError::TooFewValues`] if there are fewer than two values
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{linear_regression_indexed, StatsError};
+///
+/// let trend = linear_regression_indexed(&[10.0, 12.0, 13.0, 17.0])?;
+/// assert_eq!(trend.slope(), 2.2);
+/// assert_eq!(trend.predict(4.0), 18.5);
+/// # Ok::<(), StatsError>(())
+/// ```
+#[inline]
+pub fn linear_regression_indexed(y: &[f64]) -> Result<LinearFit, StatsError> {
+    if y.len() < 2 {
+        return Err(StatsError::TooFewValues);
+    }
+
+    fit(|index| index as f64, (y.len() - 1) as f64 / 2.0, y)
+}
+
+/// Fits a line through points with at least two `y` values and their `x` values given by index
+#[inline]
+fn fit(x: impl Fn(usize) -> f64, mean_x: f64, y: &[f64]) -> Result<LinearFit, StatsError> {
+    let mean_y = mean(y).ok_or(StatsError::TooFewValues)?;
+    let (mut xx, mut xy, mut yy) = (0.0, 0.0, 0.0);
+    for (index, &y) in y.iter().enumerate() {
+        let (dx, dy) = (x(index) - mean_x, y - mean_y);
+        xx += dx * dx;
+        xy += dx * dy;
+        yy += dy * dy;
+    }
+    if xx == 0.0 {
+        return Err(StatsError::ZeroVariance);
+    }
+
+    let slope = xy / xx;
+    // Divided one at a time so large sums don't overflow
+    let r_squared = if yy == 0.0 {
+        1.0
+    } else {
+        (slope * (xy / yy)).min(1.0)
+    };
+    Ok(LinearFit {
+        slope,
+        intercept: mean_y - slope * mean_x,
+        r_squared,
+    })
+}
+
+mod tests;
@@ -0,0 +1,156 @@
+#[cfg(test)]
+use super::*;
+
+#[cfg(test)]
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+        "{actual} != {expected}"
+    );
+}
+
+#[test]
+fn test_reference_values() {
+    // From scipy.stats.linregress and R's lm
+    let fit = linear_regression(&[1.0, 2.0, 3.0, 4.0, 5.0], &[2.0, 4.0, 5.0, 4.0, 5.0]).unwrap();
+    assert_close(fit.slope(), 0.6);
+    assert_close(fit.intercept(), 2.2);
+    assert_close(fit.r_squared(), 0.6);
+
+    let heights = [
+        1.47, 1.50, 1.52, 1.55, 1.57, 1.60, 1.63, 1.65, 1.68, 1.70, 1.73, 1.75, 1.78, 1.80, 1.83,
+    ];
+    let weights = [
+        52.21, 53.12, 54.48, 55.84, 57.20, 58.57, 59.93, 61.29, 63.11, 64.47, 66.28, 68.10, 69.92,
+        72.19, 74.46,
+    ];
+    let fit = linear_regression(&heights, &weights).unwrap();
+    assert_close(fit.slope(), 61.272_186_542_110_63);
+    assert_close(fit.intercept(), -39.061_955_918_843_95);
+    assert_close(fit.r_squared(), 0.989_196_922_445_796_8);
+}
+
+#[test]
+fn test_exact_lines() {
+    let fit = linear_regression(&[0.0, 1.0, 2.0], &[1.0, 3.0, 5.0]).unwrap();
+    assert_eq!(fit.slope(), 2.0);
+    assert_eq!(fit.intercept(), 1.0);
+    assert_eq!(fit.r_squared(), 1.0);
+
+    let fit = linear_regression(&[-2.0, 0.0, 2.0], &[4.0, 3.0, 2.0]).unwrap();
+    assert_eq!(fit.slope(), -0.5);
+    assert_eq!(fit.intercept(), 3.0);
+
+    // A horizontal line goes through every point
+    let fit = linear_regression(&[1.0, 2.0, 3.0], &[4.0, 4.0, 4.0]).unwrap();
+    assert_eq!(
+        (fit.slope(), fit.intercept(), fit.r_squared()),
+        (0.0, 4.0, 1.0)
+    );
+
+    // No linear relationship
+    let fit = linear_regression(&[-1.0, 0.0, 1.0], &[1.0, 0.0, 1.0]).unwrap();
+    assert_eq!(fit.slope(), 0.0);
+    assert_eq!(fit.r_squared(), 0.0);
+}
+
+#[test]
+fn test_predict_reproduces_points() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1_u64 << 53) as f64
+    };
+    for length in 2..50 {
+        let (slope, intercept) = (next() * 20.0 - 10.0, next() * 200.0 - 100.0);
+        let x: Vec<f64> = (0..length).map(|_| next() * 100.0).collect();
+        let y: Vec<f64> = x.iter().map(|x| intercept + slope * x).collect();
+        let fit = linear_regression(&x, &y).unwrap();
+        assert!((fit.slope() - slope).abs() < 1e-9);
+        assert!((fit.intercept() - intercept).abs() < 1e-9);
+        assert!(fit.r_squared() > 1.0 - 1e-12 && fit.r_squared() <= 1.0);
+        for (&x, &y) in x.iter().zip(&y) {
+            assert!((fit.predict(x) - y).abs() < 1e-9);
+        }
+
+        // Noise lowers r² but keeps it in range
+        let noisy: Vec<f64> = y.iter().map(|y| y + next() * 50.0).collect();
+        let r_squared = linear_regression(&x, &noisy).unwrap().r_squared();
+        assert!((0.0..=1.0).contains(&r_squared));
+    }
+}
+
+#[test]
+fn test_steep_and_offset() {
+    // Nearly vertical, with x only a few floats apart
+    let x = [1.0, 1.0 + 1e-9, 1.0 + 2e-9];
+    let fit = linear_regression(&x, &[0.0, 1.0, 2.0]).unwrap();
+    assert!((fit.slope() - 1e9).abs() < 1e9 * 1e-6, "{}", fit.slope());
+    assert!(fit.r_squared() > 1.0 - 1e-9);
+    for (index, &x) in x.iter().enumerate() {
+        assert!((fit.predict(x) - index as f64).abs() < 1e-6);
+    }
+
+    // Points far from the origin keep their digits
+    let x = [1e9 + 1.0, 1e9 + 2.0, 1e9 + 3.0];
+    let fit = linear_regression(&x, &[5.0, 7.0, 9.0]).unwrap();
+    assert_eq!(fit.slope(), 2.0);
+    assert_eq!(fit.predict(1e9 + 4.0), 11.0);
+    assert_eq!(fit.r_squared(), 1.0);
+}
+
+#[test]
+fn test_indexed() {
+    let fit = linear_regression_indexed(&[10.0, 12.0, 13.0, 17.0]).unwrap();
+    assert_eq!(
+        fit,
+        linear_regression(&[0.0, 1.0, 2.0, 3.0], &[10.0, 12.0, 13.0, 17.0]).unwrap()
+    );
+    assert_close(fit.slope(), 2.2);
+    assert_close(fit.intercept(), 9.7);
+
+    let fit = linear_regression_indexed(&[3.0, 5.0, 7.0, 9.0, 11.0]).unwrap();
+    assert_eq!(
+        (fit.slope(), fit.intercept(), fit.r_squared()),
+        (2.0, 3.0, 1.0)
+    );
+    assert_eq!(
+        linear_regression_indexed(&[4.0, 1.0]).unwrap().slope(),
+        -3.0
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        linear_regression(&[1.0, 2.0], &[1.0]),
+        Err(StatsError::LengthMismatch)
+    );
+    assert_eq!(
+        linear_regression(&[], &[1.0]),
+        Err(StatsError::LengthMismatch)
+    );
+    assert_eq!(
+        linear_regression(&[1.0], &[1.0]),
+        Err(StatsError::TooFewValues)
+    );
+    assert_eq!(linear_regression(&[], &[]), Err(StatsError::TooFewValues));
+    assert_eq!(
+        linear_regression(&[2.0, 2.0, 2.0], &[1.0, 2.0, 3.0]),
+        Err(StatsError::ZeroVariance)
+    );
+
+    assert_eq!(
+        linear_regression_indexed(&[1.0]),
+        Err(StatsError::TooFewValues)
+    );
+    assert_eq!(
+        linear_regression_indexed(&[]),
+        Err(StatsError::TooFewValues)
+    );
+
+    let fit = linear_regression(&[1.0, 2.0, 3.0], &[1.0, f64::NAN, 3.0]).unwrap();
+    assert!(fit.slope().is_nan() && fit.intercept().is_nan());
+}
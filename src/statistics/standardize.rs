@@ -0,0 +1,113 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use super::{mean, std_dev_population};
+
+/// The z-score of each value, how many standard deviations it is above the mean of the data
+///
+/// The population standard deviation is used, dividing by the number of values like
+/// `scipy.stats.zscore` does by default, so the scores have a mean of 0 and a population standard
+/// deviation of 1. The mean and standard deviation come from [`mean`] and [`std_dev_population`].
+/// See [`standardize`] to replace the data with its scores
+///
+/// Returns `None` if there are no values or their standard deviation is zero, as when they are all
+/// equal, rather than dividing by zero. Data with NaN or an infinity has no standard deviation and
+/// also gives `None`
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::z_scores;
+///
+/// assert_eq!(z_scores(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]), Some(vec![-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]));
+/// assert_eq!(z_scores(&[3.0, 3.0]), None);
+/// assert_eq!(z_scores(&[]), None);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn z_scores(data: &[f64]) -> Option<Vec<f64>> {
+    let (mean, std_dev) = mean_and_std_dev(data)?;
+    Some(data.iter().map(|&value| (value - mean) / std_dev).collect())
+}
+
+/// Replaces each value with its z-score like [`z_scores`], giving the mean and standard deviation
+/// they were scored by so [`from_z_score`] can turn them back
+///
+/// Returns `None` without changing the data if there are no values or their standard deviation is
+/// zero, or isn't finite
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{from_z_score, standardize};
+///
+/// let mut data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(standardize(&mut data), Some((5.0, 2.0)));
+/// assert_eq!(data, [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]);
+/// assert_eq!(from_z_score(data[7], 5.0, 2.0), 9.0);
+///
+/// let mut data = [3.0, 3.0];
+/// assert_eq!(standardize(&mut data), None);
+/// assert_eq!(data, [3.0, 3.0]);
+/// ```
+#[inline]
+pub fn standardize(data: &mut [f64]) -> Option<(f64, f64)> {
+    let (mean, std_dev) = mean_and_std_dev(data)?;
+    for value in data.iter_mut() {
+        *value = (*value - mean) / std_dev;
+    }
+    Some((mean, std_dev))
+}
+
+/// The z-score of a value, how many standard deviations it is above the mean
+///
+/// Returns `None` if the standard deviation isn't positive and finite, rather than dividing by
+/// zero
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::z_score;
+///
+/// assert_eq!(z_score(130.0, 100.0, 15.0), Some(2.0));
+/// assert_eq!(z_score(85.0, 100.0, 15.0), Some(-1.0));
+/// assert_eq!(z_score(85.0, 100.0, 0.0), None);
+/// ```
+#[inline]
+pub fn z_score(value: f64, mean: f64, std_dev: f64) -> Option<f64> {
+    if std_dev > 0.0 && std_dev.is_finite() {
+        Some((value - mean) / std_dev)
+    } else {
+        None
+    }
+}
+
+/// The value with a given z-score, the inverse of [`z_score`], which is `z` standard deviations
+/// above the mean
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::{from_z_score, z_score};
+///
+/// assert_eq!(from_z_score(2.0, 100.0, 15.0), 130.0);
+/// assert_eq!(from_z_score(z_score(85.0, 100.0, 15.0).unwrap(), 100.0, 15.0), 85.0);
+/// ```
+#[inline]
+pub fn from_z_score(z: f64, mean: f64, std_dev: f64) -> f64 {
+    mean + z * std_dev
+}
+
+/// The mean and population standard deviation of the data, if the standard deviation is positive
+/// and finite
+#[inline]
+fn mean_and_std_dev(data: &[f64]) -> Option<(f64, f64)> {
+    let std_dev = std_dev_population(data)?;
+    if std_dev > 0.0 && std_dev.is_finite() {
+        Some((mean(data)?, std_dev))
+    } else {
+        None
+    }
+}
+
+mod tests;
@@ -0,0 +1,97 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_z_scores() {
+    // Mean 5, population standard deviation 2
+    let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    assert_eq!(
+        z_scores(&data),
+        Some(vec![-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0])
+    );
+
+    // Mean 2, population standard deviation sqrt(2 / 3)
+    let scale = 1.5_f64.sqrt();
+    let scores = z_scores(&[1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(scores, [-scale, 0.0, scale]);
+
+    // Values with a large offset keep their digits
+    let scores = z_scores(&[1e9 + 1.0, 1e9 + 3.0]).unwrap();
+    assert_eq!(scores, [-1.0, 1.0]);
+
+    assert_eq!(z_scores(&[-4.0, 4.0]), Some(vec![-1.0, 1.0]));
+}
+
+#[test]
+fn test_scores_are_standard() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 2..100 {
+        let data: Vec<f64> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 11) as f64 / (1_u64 << 53) as f64 * 1000.0 - 200.0
+            })
+            .collect();
+        let scores = z_scores(&data).unwrap();
+        assert!(mean(&scores).unwrap().abs() < 1e-12);
+        assert!((std_dev_population(&scores).unwrap() - 1.0).abs() < 1e-12);
+
+        let mut standardized = data.clone();
+        let (mean, std_dev) = standardize(&mut standardized).unwrap();
+        assert_eq!(standardized, scores);
+        for (&score, &value) in scores.iter().zip(&data) {
+            assert_eq!(z_score(value, mean, std_dev), Some(score));
+            let value_back = from_z_score(score, mean, std_dev);
+            assert!((value_back - value).abs() <= 1e-12 * value.abs().max(1.0));
+        }
+    }
+}
+
+#[test]
+fn test_constant_and_empty() {
+    assert_eq!(z_scores(&[3.0, 3.0, 3.0]), None);
+    assert_eq!(z_scores(&[0.1; 10]), None);
+    assert_eq!(z_scores(&[7.0]), None);
+    assert_eq!(z_scores(&[]), None);
+
+    let mut data = [3.0, 3.0, 3.0];
+    assert_eq!(standardize(&mut data), None);
+    assert_eq!(data, [3.0, 3.0, 3.0]);
+    assert_eq!(standardize(&mut []), None);
+
+    assert_eq!(z_score(1.0, 0.0, 0.0), None);
+    assert_eq!(z_score(1.0, 0.0, -1.0), None);
+    assert_eq!(z_score(1.0, 0.0, f64::NAN), None);
+    assert_eq!(z_score(1.0, 0.0, f64::INFINITY), None);
+}
+
+#[test]
+fn test_non_finite() {
+    assert_eq!(z_scores(&[1.0, f64::NAN]), None);
+    assert_eq!(z_scores(&[1.0, f64::INFINITY]), None);
+
+    let mut data = [1.0, 2.0, f64::NAN];
+    assert_eq!(standardize(&mut data), None);
+    assert_eq!(data[..2], [1.0, 2.0]);
+
+    assert!(z_score(f64::NAN, 0.0, 1.0).unwrap().is_nan());
+    assert_eq!(z_score(f64::INFINITY, 0.0, 1.0), Some(f64::INFINITY));
+}
+
+#[test]
+fn test_round_trip() {
+    for (value, mean, std_dev) in [
+        (130.0, 100.0, 15.0),
+        (-3.5, 2.0, 0.5),
+        (0.1, 0.3, 0.2),
+        (1e9 + 7.0, 1e9, 4.0),
+    ] {
+        let z = z_score(value, mean, std_dev).unwrap();
+        assert!((from_z_score(z, mean, std_dev) - value).abs() <= 1e-15 * value.abs().max(1.0));
+    }
+
+    assert_eq!(from_z_score(0.0, 12.5, 3.0), 12.5);
+    assert_eq!(from_z_score(-2.0, 12.5, 3.0), 6.5);
+}
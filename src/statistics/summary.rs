@@ -0,0 +1,160 @@
+use core::fmt;
+
+use super::{quantile_sorted, Interpolation};
+use crate::rounding::round_sf;
+
+/// The smallest value, lower quartile, median, upper quartile and largest value of some data, made
+/// by [`five_number_summary`]
+///
+/// It is displayed as `min | Q1 | median | Q3 | max`, with each number rounded to the precision of
+/// the format as a number of significant figures like [`round_sf`], or in full if no precision is
+/// given
+///
+/// ```rust
+/// use common_math::statistics::five_number_summary;
+///
+/// let summary = five_number_summary(&mut [7.0, 15.0, 36.0, 39.0, 40.0, 41.0]).unwrap();
+/// assert_eq!(summary.to_string(), "7 | 20.25 | 37.5 | 39.75 | 41");
+/// assert_eq!(format!("{summary:.3}"), "7 | 20.3 | 37.5 | 39.8 | 41");
+/// assert_eq!(format!("{summary:.1}"), "7 | 20 | 40 | 40 | 40");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FiveNumberSummary {
+    min: f64,
+    lower_quartile: f64,
+    median: f64,
+    upper_quartile: f64,
+    max: f64,
+}
+
+impl FiveNumberSummary {
+    /// The smallest value
+    #[inline]
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    /// The lower quartile, the quantile 0.25
+    #[inline]
+    pub fn lower_quartile(&self) -> f64 {
+        self.lower_quartile
+    }
+
+    /// The median, the quantile 0.5
+    #[inline]
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// The upper quartile, the quantile 0.75
+    #[inline]
+    pub fn upper_quartile(&self) -> f64 {
+        self.upper_quartile
+    }
+
+    /// The largest value
+    #[inline]
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+
+    /// The interquartile range, the upper quartile less the lower
+    #[inline]
+    pub fn iqr(&self) -> f64 {
+        self.upper_quartile - self.lower_quartile
+    }
+}
+
+impl fmt::Display for FiveNumberSummary {
+    /// Writes the five numbers as `min | Q1 | median | Q3 | max`, such as `7 | 20.3 | 37.5 | 39.8 | 41`.
+    /// The precision of the format is the number of significant figures of each number, which are
+    /// written in full if it isn't given
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let numbers = [
+            self.min,
+            self.lower_quartile,
+            self.median,
+            self.upper_quartile,
+            self.max,
+        ];
+        for (index, number) in numbers.into_iter().enumerate() {
+            if index > 0 {
+                f.write_str(" | ")?;
+            }
+            match f.precision() {
+                // More significant figures than an f64 holds would show digits it doesn't have
+                Some(sig_figs) => {
+                    // A number that rounds to zero from below isn't shown as negative
+                    let rounded = round_sf(number, sig_figs.clamp(1, 17) as u32) + 0.0;
+                    write!(f, "{rounded}")?;
+                }
+                None => write!(f, "{number}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The five-number summary of the data, its smallest value, quartiles and largest value, `None`
+/// if there is no data or it contains NaN
+///
+/// The quartiles are the [`quantile`](super::quantile)s 0.25, 0.5 and 0.75 with
+/// [`Interpolation::Linear`], the same as R's `summary` and `quantile` and NumPy's `percentile`
+/// give by default. R's `fivenum` uses Tukey's hinges instead, which can differ. NaN has no place
+/// in the order, as for the quantile functions. The data is sorted in place
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::five_number_summary;
+///
+/// let summary = five_number_summary(&mut [49.0, 6.0, 15.0, 36.0, 39.0, 40.0, 41.0]).unwrap();
+/// assert_eq!(summary.min(), 6.0);
+/// assert_eq!(summary.lower_quartile(), 25.5);
+/// assert_eq!(summary.median(), 39.0);
+/// assert_eq!(summary.upper_quartile(), 40.5);
+/// assert_eq!(summary.max(), 49.0);
+/// assert_eq!(five_number_summary(&mut []), None);
+/// assert_eq!(five_number_summary(&mut [1.0, f64::NAN]), None);
+/// ```
+pub fn five_number_summary(data: &mut [f64]) -> Option<FiveNumberSummary> {
+    data.sort_unstable_by(f64::total_cmp);
+    let quantile = |q| quantile_sorted(data, q, Interpolation::Linear).ok();
+    Some(FiveNumberSummary {
+        min: quantile(0.0)?,
+        lower_quartile: quantile(0.25)?,
+        median: quantile(0.5)?,
+        upper_quartile: quantile(0.75)?,
+        max: quantile(1.0)?,
+    })
+}
+
+/// The interquartile range of the data, the upper quartile less the lower, `None` if there is no
+/// data or it contains NaN
+///
+/// The quartiles are found like [`five_number_summary`] does, and the data is sorted in place
+///
+/// # Examples
+///
+/// ```
+/// use common_math::statistics::iqr;
+///
+/// assert_eq!(iqr(&mut [7.0, 15.0, 36.0, 39.0, 40.0, 41.0]), Some(19.5));
+/// assert_eq!(iqr(&mut [3.0]), Some(0.0));
+/// assert_eq!(iqr(&mut []), None);
+/// ```
+#[inline]
+pub fn iqr(data: &mut [f64]) -> Option<f64> {
+    data.sort_unstable_by(f64::total_cmp);
+    iqr_sorted(data)
+}
+
+/// The interquartile range of data that is already sorted, `None` if there is no data or it
+/// contains NaN
+#[inline]
+pub(crate) fn iqr_sorted(sorted: &[f64]) -> Option<f64> {
+    let quantile = |q| quantile_sorted(sorted, q, Interpolation::Linear).ok();
+    Some(quantile(0.75)? - quantile(0.25)?)
+}
+
+mod tests;
@@ -0,0 +1,104 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_reference_values() {
+    // From R's summary(c(7, 15, 36, 39, 40, 41)), an even number of values
+    let summary = five_number_summary(&mut [39.0, 7.0, 41.0, 15.0, 40.0, 36.0]).unwrap();
+    assert_eq!(
+        [
+            summary.min(),
+            summary.lower_quartile(),
+            summary.median(),
+            summary.upper_quartile(),
+            summary.max()
+        ],
+        [7.0, 20.25, 37.5, 39.75, 41.0]
+    );
+    assert_eq!(summary.iqr(), 19.5);
+
+    // From R's summary(c(6, 7, 15, 36, 39, 40, 41, 42, 43, 47, 49)), an odd number of values
+    let mut data = [
+        43.0, 6.0, 49.0, 7.0, 15.0, 47.0, 36.0, 39.0, 42.0, 40.0, 41.0,
+    ];
+    let summary = five_number_summary(&mut data).unwrap();
+    assert_eq!(
+        [
+            summary.min(),
+            summary.lower_quartile(),
+            summary.median(),
+            summary.upper_quartile(),
+            summary.max()
+        ],
+        [6.0, 25.5, 40.0, 42.5, 49.0]
+    );
+    assert_eq!(data[0], 6.0);
+    assert_eq!(data[10], 49.0);
+
+    // From R's summary(1:10)
+    let mut data: [f64; 10] = core::array::from_fn(|index| (10 - index) as f64);
+    let summary = five_number_summary(&mut data).unwrap();
+    assert_eq!(summary.to_string(), "1 | 3.25 | 5.5 | 7.75 | 10");
+    assert_eq!(iqr(&mut data), Some(4.5));
+}
+
+#[test]
+fn test_matches_quantiles() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 1..60 {
+        let data: Vec<f64> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state % 1000) as f64 / 8.0 - 60.0
+            })
+            .collect();
+        let summary = five_number_summary(&mut data.clone()).unwrap();
+        let quantile = |q| super::super::quantile(&mut data.clone(), q).unwrap();
+        assert_eq!(summary.min(), quantile(0.0));
+        assert_eq!(summary.lower_quartile(), quantile(0.25));
+        assert_eq!(summary.median(), quantile(0.5));
+        assert_eq!(summary.upper_quartile(), quantile(0.75));
+        assert_eq!(summary.max(), quantile(1.0));
+        assert_eq!(iqr(&mut data.clone()), Some(summary.iqr()));
+    }
+}
+
+#[test]
+fn test_single_and_empty() {
+    let summary = five_number_summary(&mut [3.5]).unwrap();
+    assert_eq!(summary.to_string(), "3.5 | 3.5 | 3.5 | 3.5 | 3.5");
+    assert_eq!(summary.iqr(), 0.0);
+
+    assert_eq!(five_number_summary(&mut []), None);
+    assert_eq!(iqr(&mut []), None);
+    assert_eq!(five_number_summary(&mut [1.0, f64::NAN, 2.0]), None);
+    assert_eq!(iqr(&mut [f64::NAN]), None);
+
+    let summary =
+        five_number_summary(&mut [f64::INFINITY, 1.0, 2.0, 3.0, f64::NEG_INFINITY]).unwrap();
+    assert_eq!(summary.to_string(), "-inf | 1 | 2 | 3 | inf");
+}
+
+#[test]
+fn test_display() {
+    let summary = five_number_summary(&mut [7.0, 15.0, 36.0, 39.0, 40.0, 41.0]).unwrap();
+    assert_eq!(summary.to_string(), "7 | 20.25 | 37.5 | 39.75 | 41");
+    assert_eq!(format!("{summary:.3}"), "7 | 20.3 | 37.5 | 39.8 | 41");
+    assert_eq!(format!("{summary:.2}"), "7 | 20 | 38 | 40 | 41");
+    assert_eq!(format!("{summary:.1}"), "7 | 20 | 40 | 40 | 40");
+    assert_eq!(format!("{summary:.0}"), format!("{summary:.1}"));
+    assert_eq!(format!("{summary:.30}"), summary.to_string());
+
+    let summary =
+        five_number_summary(&mut [0.001_234_5, 0.012_345, 123.45, 12_345.0, 1_234_567.0]).unwrap();
+    assert_eq!(
+        format!("{summary:.2}"),
+        "0.0012 | 0.012 | 120 | 12000 | 1200000"
+    );
+
+    // Rounding to zero doesn't show a sign
+    let summary = five_number_summary(&mut [-0.0, 0.0]).unwrap();
+    assert_eq!(format!("{summary:.1}"), "0 | 0 | 0 | 0 | 0");
+}
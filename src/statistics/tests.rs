@@ -0,0 +1,299 @@
+#[cfg(test)]
+use super::*;
+
+#[test]
+fn test_mean() {
+    assert_eq!(mean(&[1, 2, 3, 4]), Some(2.5));
+    assert_eq!(mean(&[-3_i8, 3]), Some(0.0));
+    assert_eq!(mean(&[7_u64]), Some(7.0));
+    assert_eq!(mean(&[1.0, 2.0, 4.0]), Some(7.0 / 3.0));
+    assert_eq!(mean(&[0.25_f32, 0.75]), Some(0.5));
+    assert_eq!(mean::<i32>(&[]), None);
+    assert_eq!(mean::<f64>(&[]), None);
+
+    // Integer sums don't overflow
+    assert_eq!(mean(&[i64::MAX; 1_000]), Some(i64::MAX as f64));
+    assert_eq!(mean(&[i64::MIN, i64::MIN]), Some(i64::MIN as f64));
+    assert_eq!(mean(&[u64::MAX, u64::MAX - 2]), Some((u64::MAX - 1) as f64));
+    assert_eq!(mean(&[i8::MAX, i8::MAX, i8::MAX]), Some(127.0));
+    assert_eq!(mean(&[i64::MAX, i64::MIN]), Some(-0.5));
+
+    // A sum of floats that overflows where the mean doesn't
+    assert_eq!(mean(&[f64::MAX, f64::MAX]), Some(f64::MAX));
+    assert_eq!(mean(&[f64::MAX, f64::INFINITY]), Some(f64::INFINITY));
+    assert!(mean(&[f64::INFINITY, f64::NEG_INFINITY]).unwrap().is_nan());
+    assert!(mean(&[1.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_median() {
+    assert_eq!(median(&mut [5]), Some(5.0));
+    assert_eq!(median(&mut [3, 1, 2]), Some(2.0));
+    assert_eq!(median(&mut [4, 1, 3, 2]), Some(2.5));
+    assert_eq!(median(&mut [-7_i16, 10]), Some(1.5));
+    assert_eq!(median(&mut [2, 2, 2, 9]), Some(2.0));
+    assert_eq!(median(&mut [0.5_f32, -0.5, 3.0]), Some(0.5));
+    assert_eq!(median::<u8>(&mut []), None);
+    assert_eq!(median::<f64>(&mut []), None);
+
+    // The middle two are averaged without overflowing
+    assert_eq!(median(&mut [u64::MAX, u64::MAX]), Some(u64::MAX as f64));
+    assert_eq!(median(&mut [i64::MIN, i64::MIN]), Some(i64::MIN as f64));
+    assert_eq!(median(&mut [f64::MAX, f64::MAX]), Some(f64::MAX));
+
+    // NaN sorts above infinity
+    assert_eq!(median(&mut [2.5, -1.0, f64::NAN]), Some(2.5));
+    assert_eq!(
+        median(&mut [f64::NAN, 1.0, f64::INFINITY]),
+        Some(f64::INFINITY)
+    );
+    assert_eq!(median(&mut [-0.0, 0.0]), Some(0.0));
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 1..200 {
+        let mut values: Vec<i32> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state % 100) as i32 - 50
+            })
+            .collect();
+        let mut sorted = values.clone();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let expected = if sorted.len() % 2 == 1 {
+            sorted[middle] as f64
+        } else {
+            (sorted[middle - 1] + sorted[middle]) as f64 / 2.0
+        };
+        assert_eq!(median(&mut values), Some(expected), "{sorted:?}");
+    }
+}
+
+#[test]
+fn test_mode() {
+    assert_eq!(mode(&[4]), Some(4));
+    assert_eq!(mode(&[1, 3, 3, 2, 1, 3]), Some(3));
+    assert_eq!(mode(&[-2_i64, 7, 7, -2, 7]), Some(7));
+    assert_eq!(mode(&[0.5, 1.5, 0.5]), Some(0.5));
+    assert_eq!(mode::<i32>(&[]), None);
+    assert_eq!(mode::<f32>(&[]), None);
+
+    // Ties go to the smallest value
+    assert_eq!(mode(&[5, 4, 4, 5]), Some(4));
+    assert_eq!(mode(&[9, 8, 7]), Some(7));
+    assert_eq!(mode(&[u8::MAX, 0, u8::MAX, 0]), Some(0));
+    assert_eq!(mode(&[2.0, -1.0, 2.0, -1.0]), Some(-1.0));
+
+    // Floats are compared by their bits
+    assert_eq!(
+        mode(&[0.0, -0.0, 0.0]).map(f64::to_bits),
+        Some(0_f64.to_bits())
+    );
+    assert_eq!(
+        mode(&[0.0, -0.0, -0.0]).map(f64::to_bits),
+        Some((-0_f64).to_bits())
+    );
+    assert!(mode(&[f64::NAN, 1.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_variance() {
+    let values = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+    assert_eq!(variance_population(&values), Some(4.0));
+    assert_eq!(std_dev_population(&values), Some(2.0));
+    assert_eq!(variance(&values), Some(32.0 / 7.0));
+    assert_eq!(std_dev(&values), Some((32.0_f64 / 7.0).sqrt()));
+
+    assert_eq!(variance(&[1, 2, 3, 4]), Some(5.0 / 3.0));
+    assert_eq!(variance_population(&[1_u8, 2, 3, 4]), Some(1.25));
+    assert_eq!(variance(&[-1.5_f32, 1.5]), Some(4.5));
+    assert_eq!(variance(&[7; 10]), Some(0.0));
+    assert_eq!(std_dev(&[0.1; 3]), Some(0.0));
+    assert_eq!(variance(&[i64::MIN, i64::MAX]), Some(2_f64.powi(127)));
+}
+
+#[test]
+fn test_variance_divisor() {
+    // Squared deviations of 2, 0 and 2 add up to 4, divided by n - 1 for a sample and n for the
+    // population
+    let values = [1.0, 2.0, 3.0].map(|value| value * 2_f64.sqrt());
+    assert!((variance(&values).unwrap() - 2.0).abs() < 1e-15);
+    assert!((variance_population(&values).unwrap() - 4.0 / 3.0).abs() < 1e-15);
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 2..100 {
+        let values: Vec<f64> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 11) as f64 / (1_u64 << 53) as f64 * 100.0 - 50.0
+            })
+            .collect();
+        let n = length as f64;
+        let sample = variance(&values).unwrap();
+        let population = variance_population(&values).unwrap();
+        assert!((sample * (n - 1.0) / n - population).abs() <= 1e-12 * population);
+        assert!((std_dev(&values).unwrap() - sample.sqrt()).abs() <= 1e-15 * sample.sqrt());
+    }
+}
+
+#[test]
+fn test_variance_large_offset() {
+    // The naive E[x²] - E[x]² loses every digit of the small deviations next to the offset
+    let values = [4.0, 7.0, 13.0, 16.0].map(|noise| 1e9 + noise);
+    let naive = values.iter().map(|value| value * value).sum::<f64>() / 4.0
+        - (values.iter().sum::<f64>() / 4.0).powi(2);
+    assert_ne!(naive, 22.5);
+    assert_eq!(variance_population(&values), Some(22.5));
+    assert_eq!(variance(&values), Some(30.0));
+
+    let values = [
+        1_000_000_004_i64,
+        1_000_000_007,
+        1_000_000_013,
+        1_000_000_016,
+    ];
+    assert_eq!(variance(&values), Some(30.0));
+}
+
+#[test]
+fn test_variance_empty() {
+    assert_eq!(variance::<f64>(&[]), None);
+    assert_eq!(variance(&[3.0]), None);
+    assert_eq!(std_dev(&[3]), None);
+    assert_eq!(variance_population::<f64>(&[]), None);
+    assert_eq!(std_dev_population::<u8>(&[]), None);
+    assert_eq!(variance_population(&[3.0]), Some(0.0));
+    assert_eq!(std_dev_population(&[-3]), Some(0.0));
+    assert!(variance(&[1.0, f64::NAN]).unwrap().is_nan());
+}
+
+#[test]
+fn test_weighted_mean() {
+    assert_eq!(weighted_mean(&[1.0, 2.0], &[1.0, 3.0]), Ok(1.75));
+    assert_eq!(
+        weighted_mean(&[80.0, 90.0, 70.0], &[2.0, 1.0, 1.0]),
+        Ok(80.0)
+    );
+    assert_eq!(weighted_mean(&[-1.0, 3.0], &[0.75, 0.25]), Ok(0.0));
+    assert_eq!(weighted_mean(&[5.0], &[0.1]), Ok(5.0));
+
+    // Scaling every weight gives the same mean
+    assert_eq!(
+        weighted_mean(&[3.0, 9.0], &[1.0, 2.0]),
+        weighted_mean(&[3.0, 9.0], &[1000.0, 2000.0])
+    );
+
+    // A zero weight leaves the value out, even if it isn't finite
+    assert_eq!(weighted_mean(&[1.0, 2.0, 100.0], &[1.0, 1.0, 0.0]), Ok(1.5));
+    assert_eq!(weighted_mean(&[1.0, f64::NAN], &[1.0, 0.0]), Ok(1.0));
+    assert_eq!(weighted_mean(&[f64::INFINITY, 1.0], &[0.0, 2.0]), Ok(1.0));
+    assert!(weighted_mean(&[1.0, f64::NAN], &[1.0, 1.0])
+        .unwrap()
+        .is_nan());
+
+    // A total weight or weighted sum that overflows
+    assert_eq!(weighted_mean(&[1.0, 3.0], &[f64::MAX, f64::MAX]), Ok(2.0));
+    assert_eq!(
+        weighted_mean(&[f64::MAX, f64::MAX], &[1.0, 3.0]),
+        Ok(f64::MAX)
+    );
+    assert_eq!(
+        weighted_mean(&[f64::MAX, 0.0], &[2.0, 2.0]),
+        Ok(f64::MAX / 2.0)
+    );
+}
+
+#[test]
+fn test_weighted_mean_equal_weights() {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    for length in 1..100 {
+        let values: Vec<f64> = (0..length)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 11) as f64 / (1_u64 << 53) as f64 * 100.0 - 50.0
+            })
+            .collect();
+        let weights = vec![1.0; length];
+        assert_eq!(weighted_mean(&values, &weights).ok(), mean(&values));
+        assert_eq!(weighted_variance(&values, &weights).ok(), variance(&values));
+
+        let weights = vec![0.25; length];
+        let weighted = weighted_mean(&values, &weights).unwrap();
+        assert!((weighted - mean(&values).unwrap()).abs() <= 1e-12);
+    }
+}
+
+#[test]
+fn test_weighted_mean_errors() {
+    assert_eq!(
+        weighted_mean(&[1.0, 2.0], &[1.0]),
+        Err(StatsError::LengthMismatch)
+    );
+    assert_eq!(weighted_mean(&[], &[1.0]), Err(StatsError::LengthMismatch));
+    assert_eq!(
+        weighted_mean(&[1.0, 2.0], &[1.0, -1.0]),
+        Err(StatsError::InvalidWeights)
+    );
+    assert_eq!(
+        weighted_mean(&[1.0, 2.0], &[1.0, f64::NAN]),
+        Err(StatsError::InvalidWeights)
+    );
+    assert_eq!(
+        weighted_mean(&[1.0, 2.0], &[f64::INFINITY, 1.0]),
+        Err(StatsError::InvalidWeights)
+    );
+    assert_eq!(
+        weighted_mean(&[1.0, 2.0], &[0.0, -0.0]),
+        Err(StatsError::ZeroTotalWeight)
+    );
+    assert_eq!(weighted_mean(&[], &[]), Err(StatsError::ZeroTotalWeight));
+}
+
+#[test]
+fn test_weighted_variance() {
+    // Weights of 2 and 1 count the values as 1, 1 and 4
+    assert_eq!(weighted_variance(&[1.0, 4.0], &[2.0, 1.0]), Ok(3.0));
+    assert_eq!(
+        weighted_variance(&[2.0, 4.0, 5.0, 7.0, 9.0], &[1.0, 3.0, 2.0, 1.0, 1.0]),
+        variance(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).ok_or(StatsError::TooFewValues)
+    );
+    assert_eq!(weighted_variance(&[3.0, 3.0], &[5.0, 1.0]), Ok(0.0));
+    assert_eq!(
+        weighted_variance(&[1.0, 9.0, 1e300], &[1.0, 1.0, 0.0]),
+        Ok(32.0)
+    );
+
+    // Frequencies that aren't whole numbers
+    assert_eq!(weighted_variance(&[0.0, 3.0], &[1.5, 1.5]), Ok(6.75 / 2.0));
+
+    // Values with a large offset keep their digits
+    let values = [4.0, 7.0, 13.0, 16.0].map(|noise| 1e9 + noise);
+    assert_eq!(weighted_variance(&values, &[1.0; 4]), Ok(30.0));
+
+    assert_eq!(
+        weighted_variance(&[1.0, 4.0], &[0.5, 0.5]),
+        Err(StatsError::TooFewValues)
+    );
+    assert_eq!(
+        weighted_variance(&[1.0, 4.0], &[1.0, 0.0]),
+        Err(StatsError::TooFewValues)
+    );
+    assert_eq!(
+        weighted_variance(&[1.0], &[1.0, 1.0]),
+        Err(StatsError::LengthMismatch)
+    );
+    assert_eq!(
+        weighted_variance(&[1.0, 4.0], &[-1.0, 3.0]),
+        Err(StatsError::InvalidWeights)
+    );
+    assert_eq!(
+        weighted_variance(&[1.0, 4.0], &[0.0, 0.0]),
+        Err(StatsError::ZeroTotalWeight)
+    );
+}